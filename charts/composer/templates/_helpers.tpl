@@ -30,7 +30,7 @@ app: {{ include "composer.appName" . }}
 Single entry of rollup names
 */}}
 {{- define "composer.rollupDefinition" }}
-{{ .name }}::{{ .wsRpc }}
+{{ .name }}::{{ with .collector }}{{ . }}::{{ end }}{{ .wsRpc }}
 {{- end}}
 
 {{/*
@@ -42,6 +42,15 @@ Rollup config string
 {{- end }}
 {{- end }}
 
+{{/*
+Rollup weights config string
+*/}}
+{{- define "composer.rollupWeights" }}
+{{- range $index, $element := .Values.config.rollups }}
+{{- if $index }},{{- end }}{{ $element.name }}::{{ $element.weight | default 1 }}
+{{- end }}
+{{- end }}
+
 {{- define "composer.image" -}}
 {{ .Values.images.composer.repo }}:{{ if .Values.global.dev }}{{ .Values.images.composer.devTag }}{{ else }}{{ .Values.images.composer.tag }}{{ end }}
 {{- end }}
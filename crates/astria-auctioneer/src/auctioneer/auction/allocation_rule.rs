@@ -8,7 +8,32 @@ use tracing::{
 };
 
 use super::Bid;
+use crate::config;
 
+/// A mechanism to determine the winner of an auction and the fee it pays.
+pub(super) trait AllocationRule: Send {
+    /// Submits a candidate bid to the auction.
+    fn bid(&mut self, candidate: &Arc<Bid>);
+
+    /// Returns the number of bids submitted to the auction.
+    fn bids_seen(&self) -> usize;
+
+    /// Returns the winner of the auction, if one exists.
+    ///
+    /// The fee of the returned bid is the fee that the winner pays.
+    fn take_winner(&mut self) -> Option<Arc<Bid>>;
+}
+
+/// Constructs the allocation rule selected by `rule`.
+pub(super) fn new(rule: config::AllocationRule, reserve_price: u64) -> Box<dyn AllocationRule> {
+    match rule {
+        config::AllocationRule::FirstPrice => Box::new(FirstPrice::new()),
+        config::AllocationRule::SecondPrice => Box::new(SecondPrice::new()),
+        config::AllocationRule::ReservePrice => Box::new(ReservePrice::new(reserve_price)),
+    }
+}
+
+/// The highest bid wins and pays its own bid.
 pub(super) struct FirstPrice {
     highest_bid: Option<Arc<Bid>>,
     bids_seen: usize,
@@ -21,16 +46,15 @@ impl FirstPrice {
             bids_seen: 0,
         }
     }
+}
 
-    /// Submit a bid with a bid.
-    ///
-    /// Returns `true` if the bid is accepted as the highest bid.
+impl AllocationRule for FirstPrice {
     // TODO: identify the incumbant and candidate by their hash?
     #[instrument(skip_all, fields(
         current_winner.bid = self.highest_bid.as_ref().map(|bid| bid.bid()),
         candidate.bid = candidate.bid(),
     ))]
-    pub(super) fn bid(&mut self, candidate: &Arc<Bid>) {
+    fn bid(&mut self, candidate: &Arc<Bid>) {
         self.bids_seen = self.bids_seen.saturating_add(1);
         let winner = if let Some(current) = self.highest_bid.as_mut() {
             if candidate.bid() > current.bid() {
@@ -46,12 +70,177 @@ impl FirstPrice {
         info!("highest bidder is {winner}");
     }
 
-    pub(super) fn bids_seen(&self) -> usize {
+    fn bids_seen(&self) -> usize {
         self.bids_seen
     }
 
-    /// Returns the winner of the auction, if one exists.
-    pub(super) fn take_winner(&mut self) -> Option<Arc<Bid>> {
+    fn take_winner(&mut self) -> Option<Arc<Bid>> {
         self.highest_bid.take()
     }
 }
+
+/// The highest bid wins and pays the second highest bid.
+///
+/// If only a single bid was received, the winner pays its own bid.
+pub(super) struct SecondPrice {
+    highest_bid: Option<Arc<Bid>>,
+    runner_up_fee: Option<u64>,
+    bids_seen: usize,
+}
+
+impl SecondPrice {
+    pub(super) fn new() -> Self {
+        Self {
+            highest_bid: None,
+            runner_up_fee: None,
+            bids_seen: 0,
+        }
+    }
+}
+
+impl AllocationRule for SecondPrice {
+    #[instrument(skip_all, fields(
+        current_winner.bid = self.highest_bid.as_ref().map(|bid| bid.bid()),
+        runner_up.bid = self.runner_up_fee,
+        candidate.bid = candidate.bid(),
+    ))]
+    fn bid(&mut self, candidate: &Arc<Bid>) {
+        self.bids_seen = self.bids_seen.saturating_add(1);
+        let winner = match self.highest_bid.as_mut() {
+            Some(current) if candidate.bid() > current.bid() => {
+                self.runner_up_fee = Some(current.bid());
+                *current = candidate.clone();
+                "candidate"
+            }
+            Some(_) => {
+                self.runner_up_fee = self.runner_up_fee.max(Some(candidate.bid()));
+                "incumbant"
+            }
+            None => {
+                self.highest_bid = Some(candidate.clone());
+                "candidate"
+            }
+        };
+        info!("highest bidder is {winner}");
+    }
+
+    fn bids_seen(&self) -> usize {
+        self.bids_seen
+    }
+
+    fn take_winner(&mut self) -> Option<Arc<Bid>> {
+        let winner = self.highest_bid.take()?;
+        let Some(runner_up_fee) = self.runner_up_fee.take() else {
+            return Some(winner);
+        };
+        Some(Arc::new(
+            Arc::unwrap_or_clone(winner).with_fee(runner_up_fee),
+        ))
+    }
+}
+
+/// Like [`FirstPrice`], but bids below the reserve price are rejected.
+pub(super) struct ReservePrice {
+    floor: u64,
+    inner: FirstPrice,
+    bids_rejected: usize,
+}
+
+impl ReservePrice {
+    pub(super) fn new(reserve_price: u64) -> Self {
+        Self {
+            floor: reserve_price,
+            inner: FirstPrice::new(),
+            bids_rejected: 0,
+        }
+    }
+}
+
+impl AllocationRule for ReservePrice {
+    fn bid(&mut self, candidate: &Arc<Bid>) {
+        if candidate.bid() < self.floor {
+            self.bids_rejected = self.bids_rejected.saturating_add(1);
+            info!(
+                candidate.bid = candidate.bid(),
+                reserve_price = self.floor,
+                "rejecting bid below reserve price"
+            );
+            return;
+        }
+        self.inner.bid(candidate);
+    }
+
+    fn bids_seen(&self) -> usize {
+        self.inner.bids_seen().saturating_add(self.bids_rejected)
+    }
+
+    fn take_winner(&mut self) -> Option<Arc<Bid>> {
+        self.inner.take_winner()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use astria_core::generated::astria::auction::v1alpha1 as raw;
+
+    use super::*;
+
+    fn bid(fee: u64) -> Arc<Bid> {
+        Arc::new(
+            Bid::try_from_raw(raw::Bid {
+                fee,
+                transactions: vec![],
+                sequencer_parent_block_hash: vec![0; 32].into(),
+                rollup_parent_block_hash: vec![1; 32].into(),
+            })
+            .unwrap(),
+        )
+    }
+
+    fn winning_fee(mut rule: Box<dyn AllocationRule>, fees: &[u64]) -> Option<u64> {
+        for fee in fees {
+            rule.bid(&bid(*fee));
+        }
+        assert_eq!(fees.len(), rule.bids_seen());
+        rule.take_winner().map(|winner| winner.bid())
+    }
+
+    #[test]
+    fn first_price_winner_pays_its_bid() {
+        let rule = new(config::AllocationRule::FirstPrice, 0);
+        assert_eq!(Some(30), winning_fee(rule, &[10, 30, 20]));
+    }
+
+    #[test]
+    fn second_price_winner_pays_runner_up_bid() {
+        let rule = new(config::AllocationRule::SecondPrice, 0);
+        assert_eq!(Some(20), winning_fee(rule, &[10, 30, 20]));
+        let rule = new(config::AllocationRule::SecondPrice, 0);
+        assert_eq!(Some(10), winning_fee(rule, &[10, 30]));
+    }
+
+    #[test]
+    fn second_price_single_bid_pays_its_bid() {
+        let rule = new(config::AllocationRule::SecondPrice, 0);
+        assert_eq!(Some(10), winning_fee(rule, &[10]));
+    }
+
+    #[test]
+    fn reserve_price_rejects_bids_below_floor() {
+        let rule = new(config::AllocationRule::ReservePrice, 15);
+        assert_eq!(Some(20), winning_fee(rule, &[10, 20]));
+        let rule = new(config::AllocationRule::ReservePrice, 15);
+        assert_eq!(None, winning_fee(rule, &[10, 14]));
+    }
+
+    #[test]
+    fn no_bids_means_no_winner() {
+        for rule in [
+            config::AllocationRule::FirstPrice,
+            config::AllocationRule::SecondPrice,
+            config::AllocationRule::ReservePrice,
+        ] {
+            assert_eq!(None, winning_fee(new(rule, 0), &[]));
+        }
+    }
+}
@@ -13,6 +13,7 @@ use tokio::sync::{
 use tokio_util::sync::CancellationToken;
 
 use super::{
+    allocation_rule,
     Auction,
     SequencerKey,
     Worker,
@@ -43,6 +44,9 @@ pub(in crate::auctioneer) struct Factory {
     /// is set externally via `Factory::set_last_succesful_nonce`.
     pub(in crate::auctioneer) last_successful_nonce: Option<u32>,
     pub(in crate::auctioneer) metrics: &'static crate::Metrics,
+    pub(in crate::auctioneer) allocation_rule: crate::config::AllocationRule,
+    /// The minimum fee of a bid, used by the reserve-price allocation rule.
+    pub(in crate::auctioneer) reserve_price: u64,
 }
 
 impl Factory {
@@ -72,6 +76,7 @@ impl Factory {
             cancellation_token: cancellation_token.clone(),
             last_successful_nonce: self.last_successful_nonce,
             metrics: self.metrics,
+            allocation_rule: allocation_rule::new(self.allocation_rule, self.reserve_price),
         };
 
         Auction {
@@ -41,7 +41,7 @@ use tracing::{
 };
 
 use super::{
-    allocation_rule::FirstPrice,
+    allocation_rule::AllocationRule,
     Summary,
 };
 use crate::{
@@ -80,6 +80,8 @@ pub(super) struct Worker {
     /// at the beginning of the program).
     pub(super) last_successful_nonce: Option<u32>,
     pub(super) metrics: &'static crate::Metrics,
+    /// The rule by which the winner of the auction is determined.
+    pub(super) allocation_rule: Box<dyn AllocationRule>,
 }
 
 impl Worker {
@@ -187,8 +189,6 @@ impl Worker {
 
     async fn run_auction_loop(&mut self) -> Result<AuctionItems, Error> {
         let mut latency_margin_timer = pin!(None::<Sleep>);
-        // TODO: do we want to make this configurable to allow for more complex allocation rules?
-        let mut allocation_rule = FirstPrice::new();
         let mut auction_is_open = false;
 
         let mut nonce_fetch = None;
@@ -209,9 +209,9 @@ impl Worker {
                     info!("timer is up; bids left unprocessed: {}", self.bids.len());
 
                     self.metrics.record_bids_per_auction_dropped_histogram(self.bids.len());
-                    self.metrics.record_bids_per_auction_processed_histogram(allocation_rule.bids_seen());
+                    self.metrics.record_bids_per_auction_processed_histogram(self.allocation_rule.bids_seen());
 
-                    let winner = allocation_rule.take_winner();
+                    let winner = self.allocation_rule.take_winner();
                     if let Some(winner) = &winner {
                         self.metrics.record_auction_winning_bid_histogram(winner.bid());
                     }
@@ -263,7 +263,7 @@ impl Worker {
 
                 // TODO: this is an unbounded channel. Can we process multiple bids at a time?
                 Some(bid) = self.bids.recv(), if auction_is_open => {
-                    allocation_rule.bid(&bid);
+                    self.allocation_rule.bid(&bid);
                 }
 
                 else => {
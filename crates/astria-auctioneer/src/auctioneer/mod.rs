@@ -77,6 +77,8 @@ impl Auctioneer {
             sequencer_private_key_path,
             sequencer_address_prefix,
             fee_asset_denomination,
+            allocation_rule,
+            reserve_price,
             ..
         } = config;
 
@@ -95,6 +97,7 @@ impl Auctioneer {
             sequencer_client::HttpClient::new(sequencer_abci_endpoint.as_str())
                 .wrap_err("failed constructing sequencer abci client")?;
 
+        info!(%allocation_rule, reserve_price, "using auction allocation rule");
         let auction_factory = auction::Factory {
             sequencer_abci_client,
             sequencer_channel: sequencer_channel.clone(),
@@ -106,6 +109,8 @@ impl Auctioneer {
             cancellation_token: shutdown_token.child_token(),
             last_successful_nonce: None,
             metrics,
+            allocation_rule,
+            reserve_price,
         };
 
         Ok(Self {
@@ -141,6 +141,17 @@ impl Bid {
         self.fee
     }
 
+    /// Returns the bid with its fee replaced by `fee`.
+    ///
+    /// Used by allocation rules where the winner pays a different fee than it bid.
+    #[must_use]
+    pub(crate) fn with_fee(self, fee: u64) -> Self {
+        Self {
+            fee,
+            ..self
+        }
+    }
+
     pub(crate) fn rollup_parent_block_hash(&self) -> &RollupBlockHash {
         &self.rollup_parent_block_hash
     }
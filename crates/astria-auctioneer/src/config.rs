@@ -4,6 +4,30 @@ use serde::{
     Serialize,
 };
 
+/// The mechanism by which an auction selects its winner from the bids it received.
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub enum AllocationRule {
+    /// The highest bid wins and pays its own bid.
+    FirstPrice,
+    /// The highest bid wins and pays the second highest bid (a Vickrey auction). If only a single
+    /// bid was received, the winner pays its own bid.
+    SecondPrice,
+    /// Like [`AllocationRule::FirstPrice`], but bids below the configured reserve price are
+    /// rejected.
+    ReservePrice,
+}
+
+impl std::fmt::Display for AllocationRule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            Self::FirstPrice => "first-price",
+            Self::SecondPrice => "second-price",
+            Self::ReservePrice => "reserve-price",
+        };
+        f.write_str(s)
+    }
+}
+
 #[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
 /// The single config for creating an astria-auctioneer service.
 pub struct Config {
@@ -29,6 +53,11 @@ pub struct Config {
     /// The amount of time in miliseconds to wait after a commit before closing the auction for
     /// bids and submitting the result to the sequencer.
     pub latency_margin_ms: u64,
+    /// The rule by which the winner of an auction and the fee it pays are determined.
+    pub allocation_rule: AllocationRule,
+    /// The minimum fee a bid must offer to be considered for an auction. Only used by
+    /// [`AllocationRule::ReservePrice`].
+    pub reserve_price: u64,
     /// Log level for the service.
     pub log: String,
     /// Forces writing trace data to stdout no matter if connected to a tty or not.
@@ -0,0 +1,202 @@
+use std::net::SocketAddr;
+
+use astria_core::generated::astria::signer::v1::frost_participant_service_server::FrostParticipantServiceServer;
+use astria_eyre::eyre::{
+    self,
+    WrapErr as _,
+};
+use tokio::{
+    net::TcpListener,
+    select,
+};
+use tokio_stream::wrappers::TcpListenerStream;
+use tokio_util::sync::{
+    CancellationToken,
+    WaitForCancellationFuture,
+};
+use tracing::{
+    error,
+    info,
+};
+
+use crate::{
+    config::Config,
+    metrics::Metrics,
+    participant::{
+        self,
+        Participant,
+    },
+    service::ParticipantService,
+    verifier,
+};
+
+pub struct BridgeSigner {
+    listener: TcpListener,
+    participant: Participant,
+    verifier_builder: verifier::Builder,
+    metrics: &'static Metrics,
+    shutdown_handle: ShutdownHandle,
+}
+
+impl BridgeSigner {
+    /// Instantiates a new `BridgeSigner`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the FROST secret key package could not be read, or if the gRPC
+    /// socket address could not be bound.
+    pub async fn new(
+        cfg: Config,
+        metrics: &'static Metrics,
+    ) -> eyre::Result<(Self, ShutdownHandle)> {
+        let shutdown_handle = ShutdownHandle::new();
+        let Config {
+            grpc_addr,
+            frost_secret_key_package_path,
+            sequencer_chain_id,
+            sequencer_bridge_address,
+            fee_asset_denomination,
+            rollup_asset_denomination,
+            use_compat_address,
+            ethereum_contract_address,
+            ethereum_rpc_endpoint,
+            ..
+        } = cfg;
+
+        let key_package = participant::read_key_package(&frost_secret_key_package_path)
+            .wrap_err_with(|| {
+                format!(
+                    "failed reading frost secret key package from file \
+                     `{frost_secret_key_package_path}`"
+                )
+            })?;
+        let participant = Participant::new(key_package);
+        info!(
+            identifier = participant::display_identifier(participant.identifier()),
+            "loaded frost secret key package"
+        );
+
+        let listener = TcpListener::bind(&grpc_addr)
+            .await
+            .wrap_err_with(|| format!("failed to bind gRPC server to `{grpc_addr}`"))?;
+
+        let bridge_signer = Self {
+            listener,
+            participant,
+            verifier_builder: verifier::Builder {
+                sequencer_chain_id,
+                sequencer_bridge_address,
+                fee_asset_denomination,
+                rollup_asset_denomination,
+                use_compat_address,
+                ethereum_contract_address,
+                ethereum_rpc_endpoint,
+            },
+            metrics,
+            shutdown_handle: shutdown_handle.clone(),
+        };
+        Ok((bridge_signer, shutdown_handle))
+    }
+
+    /// Returns the socket address the gRPC server is bound to.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the address of the bound socket cannot be read.
+    #[must_use]
+    pub fn local_addr(&self) -> SocketAddr {
+        self.listener
+            .local_addr()
+            .expect("a bound TCP listener must have a local address")
+    }
+
+    /// Runs the bridge signer.
+    ///
+    /// The gRPC server is only started once the rollup is reachable, so that no signing requests
+    /// are accepted that could not be verified.
+    pub async fn run(self) {
+        let Self {
+            listener,
+            participant,
+            verifier_builder,
+            metrics,
+            shutdown_handle,
+        } = self;
+        let token = shutdown_handle.token.clone();
+        match serve(listener, participant, verifier_builder, metrics, token).await {
+            Ok(()) => info!("shutting down"),
+            Err(error) => error!(%error, "bridge signer failed; shutting down"),
+        }
+        shutdown_handle.shutdown();
+    }
+}
+
+async fn serve(
+    listener: TcpListener,
+    participant: Participant,
+    verifier_builder: verifier::Builder,
+    metrics: &'static Metrics,
+    shutdown_token: CancellationToken,
+) -> eyre::Result<()> {
+    let verifier = select!(
+        () = shutdown_token.cancelled() => return Ok(()),
+        verifier = verifier_builder.connect() => {
+            verifier.wrap_err("failed to set up the verifier of signing requests")?
+        }
+    );
+    let service = ParticipantService::new(participant, verifier, metrics);
+    info!(
+        grpc_addr = %listener.local_addr().wrap_err("failed to read the local address")?,
+        "starting gRPC server"
+    );
+    tonic::transport::Server::builder()
+        .add_service(FrostParticipantServiceServer::new(service))
+        .serve_with_incoming_shutdown(
+            TcpListenerStream::new(listener),
+            shutdown_token.cancelled_owned(),
+        )
+        .await
+        .wrap_err("gRPC server failed")
+}
+
+/// A handle for instructing the [`BridgeSigner`] to shut down.
+///
+/// It is returned along with its related `BridgeSigner` from [`BridgeSigner::new`].  The
+/// `BridgeSigner` will begin to shut down as soon as [`ShutdownHandle::shutdown`] is called or
+/// when the `ShutdownHandle` is dropped.
+#[derive(Clone)]
+pub struct ShutdownHandle {
+    token: CancellationToken,
+}
+
+impl ShutdownHandle {
+    #[must_use]
+    fn new() -> Self {
+        Self {
+            token: CancellationToken::new(),
+        }
+    }
+
+    /// Returns a `Future` that gets fulfilled when cancellation is requested.
+    ///
+    /// See [`CancellationToken::cancelled`] for further details.
+    pub fn cancelled(&self) -> WaitForCancellationFuture<'_> {
+        self.token.cancelled()
+    }
+
+    /// Consumes `self` and cancels the wrapped cancellation token.
+    ///
+    /// See [`CancellationToken::cancel`] for further details.
+    pub fn shutdown(self) {
+        self.token.cancel();
+    }
+}
+
+impl Drop for ShutdownHandle {
+    fn drop(&mut self) {
+        if !self.token.is_cancelled() {
+            info!("shutdown handle dropped, issuing shutdown to all services");
+        }
+        self.token.cancel();
+    }
+}
@@ -0,0 +1,59 @@
+use astria_core::primitive::v1::asset;
+use serde::{
+    Deserialize,
+    Serialize,
+};
+
+#[expect(
+    clippy::struct_excessive_bools,
+    reason = "This is used as a container for deserialization. Making this a builder-pattern is \
+              not actionable"
+)]
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+/// The single config for creating an astria-bridge-signer service.
+pub struct Config {
+    /// The socket address at which the FROST participant gRPC service is served.
+    pub grpc_addr: String,
+    /// The path to the json-encoded FROST secret key package of this participant, as written by
+    /// `astria-cli sequencer threshold dkg`.
+    pub frost_secret_key_package_path: String,
+    /// The chain ID of the sequencer network. Transactions for other chains are not signed.
+    pub sequencer_chain_id: String,
+    /// The bridge address corresponding to the bridged rollup asset on the sequencer.
+    pub sequencer_bridge_address: String,
+    /// The fee asset denomination the bridge withdrawer pays fees in.
+    pub fee_asset_denomination: asset::Denom,
+    /// The asset denomination being withdrawn from the rollup.
+    pub rollup_asset_denomination: asset::denom::TracePrefixed,
+    /// Whether the bridge withdrawer uses compat addresses for `Ics20Withdrawal`s.
+    pub use_compat_address: bool,
+    /// The address of the `AstriaWithdrawer` contract on the evm rollup.
+    pub ethereum_contract_address: String,
+    /// The rpc endpoint of the evm rollup, used to re-derive the withdrawal actions to sign.
+    pub ethereum_rpc_endpoint: String,
+    pub log: String,
+    /// Forces writing trace data to stdout no matter if connected to a tty or not.
+    pub force_stdout: bool,
+    /// Disables writing trace data to an opentelemetry endpoint.
+    pub no_otel: bool,
+    /// Set to true to disable the metrics server
+    pub no_metrics: bool,
+    /// The endpoint which will be listened on for serving prometheus metrics
+    pub metrics_http_listener_addr: String,
+}
+
+impl config::Config for Config {
+    const PREFIX: &'static str = "ASTRIA_BRIDGE_SIGNER_";
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Config;
+
+    const EXAMPLE_ENV: &str = include_str!("../local.env.example");
+
+    #[test]
+    fn example_env_config_is_up_to_date() {
+        config::tests::example_env_config_is_up_to_date::<Config>(EXAMPLE_ENV);
+    }
+}
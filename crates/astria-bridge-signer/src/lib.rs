@@ -0,0 +1,15 @@
+pub mod bridge_signer;
+mod build_info;
+pub mod config;
+pub(crate) mod metrics;
+pub(crate) mod participant;
+pub(crate) mod service;
+pub(crate) mod verifier;
+
+pub use bridge_signer::{
+    BridgeSigner,
+    ShutdownHandle,
+};
+pub use build_info::BUILD_INFO;
+pub use config::Config;
+pub use metrics::Metrics;
@@ -0,0 +1,78 @@
+use std::process::ExitCode;
+
+use astria_bridge_signer::{
+    BridgeSigner,
+    Config,
+    BUILD_INFO,
+};
+use astria_eyre::eyre::WrapErr as _;
+use tokio::signal::unix::{
+    signal,
+    SignalKind,
+};
+use tracing::{
+    error,
+    info,
+    warn,
+};
+
+#[tokio::main]
+async fn main() -> ExitCode {
+    astria_eyre::install().expect("astria eyre hook must be the first hook installed");
+
+    eprintln!("{}", telemetry::display::json(&BUILD_INFO),);
+
+    let cfg: Config = config::get().expect("failed to read configuration");
+    eprintln!("{}", telemetry::display::json(&cfg),);
+
+    let mut telemetry_conf = telemetry::configure()
+        .set_no_otel(cfg.no_otel)
+        .set_force_stdout(cfg.force_stdout)
+        .set_filter_directives(&cfg.log);
+
+    if !cfg.no_metrics {
+        telemetry_conf =
+            telemetry_conf.set_metrics(&cfg.metrics_http_listener_addr, env!("CARGO_PKG_NAME"));
+    }
+
+    let (metrics, _telemetry_guard) = match telemetry_conf
+        .try_init(&())
+        .wrap_err("failed to setup telemetry")
+    {
+        Err(e) => {
+            eprintln!("initializing bridge signer failed:\n{e:?}");
+            return ExitCode::FAILURE;
+        }
+        Ok(metrics_and_guard) => metrics_and_guard,
+    };
+
+    info!(
+        config = %telemetry::display::json(&cfg),
+        "initializing bridge signer"
+    );
+
+    let mut sigterm = signal(SignalKind::terminate())
+        .expect("setting a SIGTERM listener should always work on Unix");
+    let (bridge_signer, shutdown_handle) = BridgeSigner::new(cfg, metrics)
+        .await
+        .expect("could not initialize bridge signer");
+    let bridge_signer_handle = tokio::spawn(bridge_signer.run());
+
+    tokio::select!(
+        _ = sigterm.recv() => {
+            // We don't care about the result (i.e. whether there could be more SIGTERM signals
+            // incoming); we just want to shut down as soon as we receive the first `SIGTERM`.
+            info!("received SIGTERM, issuing shutdown to all services");
+            shutdown_handle.shutdown();
+        }
+        () = shutdown_handle.cancelled() => {
+            warn!("stopped waiting for SIGTERM");
+        }
+    );
+
+    if let Err(error) = bridge_signer_handle.await {
+        error!(%error, "failed to join main bridge signer task");
+    }
+
+    ExitCode::SUCCESS
+}
@@ -0,0 +1,112 @@
+use std::time::Duration;
+
+use telemetry::{
+    metric_names,
+    metrics::{
+        Counter,
+        Histogram,
+        RegisteringBuilder,
+    },
+};
+
+pub struct Metrics {
+    round_one_count: Counter,
+    signature_share_count: Counter,
+    rejected_signing_request_count: Counter,
+    verification_latency: Histogram,
+}
+
+impl Metrics {
+    pub(crate) fn increment_round_one_count(&self) {
+        self.round_one_count.increment(1);
+    }
+
+    pub(crate) fn increment_signature_share_count(&self) {
+        self.signature_share_count.increment(1);
+    }
+
+    pub(crate) fn increment_rejected_signing_request_count(&self) {
+        self.rejected_signing_request_count.increment(1);
+    }
+
+    pub(crate) fn record_verification_latency(&self, latency: Duration) {
+        self.verification_latency.record(latency);
+    }
+}
+
+impl telemetry::Metrics for Metrics {
+    type Config = ();
+
+    fn register(
+        builder: &mut RegisteringBuilder,
+        _config: &Self::Config,
+    ) -> Result<Self, telemetry::metrics::Error> {
+        let round_one_count = builder
+            .new_counter_factory(
+                ROUND_ONE_COUNT,
+                "The number of signing commitments handed out in round one",
+            )?
+            .register()?;
+
+        let signature_share_count = builder
+            .new_counter_factory(
+                SIGNATURE_SHARE_COUNT,
+                "The number of signature shares produced in round two",
+            )?
+            .register()?;
+
+        let rejected_signing_request_count = builder
+            .new_counter_factory(
+                REJECTED_SIGNING_REQUEST_COUNT,
+                "The number of round two requests refused because their transaction did not match \
+                 the withdrawals derived from the rollup",
+            )?
+            .register()?;
+
+        let verification_latency = builder
+            .new_histogram_factory(
+                VERIFICATION_LATENCY,
+                "The latency of re-deriving and validating the actions of a transaction against \
+                 the rollup",
+            )?
+            .register()?;
+
+        Ok(Self {
+            round_one_count,
+            signature_share_count,
+            rejected_signing_request_count,
+            verification_latency,
+        })
+    }
+}
+
+metric_names!(const METRICS_NAMES:
+    ROUND_ONE_COUNT,
+    SIGNATURE_SHARE_COUNT,
+    REJECTED_SIGNING_REQUEST_COUNT,
+    VERIFICATION_LATENCY
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[track_caller]
+    fn assert_const(actual: &'static str, suffix: &str) {
+        // XXX: hard-code this so the crate name isn't accidentally changed.
+        const CRATE_NAME: &str = "astria_bridge_signer";
+        let expected = format!("{CRATE_NAME}_{suffix}");
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn metrics_are_as_expected() {
+        assert_const(ROUND_ONE_COUNT, "round_one_count");
+        assert_const(SIGNATURE_SHARE_COUNT, "signature_share_count");
+        assert_const(
+            REJECTED_SIGNING_REQUEST_COUNT,
+            "rejected_signing_request_count",
+        );
+        assert_const(VERIFICATION_LATENCY, "verification_latency");
+    }
+}
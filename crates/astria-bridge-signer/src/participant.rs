@@ -0,0 +1,272 @@
+use std::{
+    collections::{
+        BTreeMap,
+        VecDeque,
+    },
+    path::Path,
+    sync::Mutex,
+};
+
+use astria_core::generated::astria::signer::v1::CommitmentWithIdentifier;
+use astria_eyre::eyre::{
+    self,
+    WrapErr as _,
+};
+use frost_ed25519::{
+    keys::KeyPackage,
+    round1::{
+        self,
+        SigningCommitments,
+        SigningNonces,
+    },
+    round2,
+    Identifier,
+    SigningPackage,
+};
+
+/// The number of round one nonces kept around waiting for their round two request.
+///
+/// If the coordinator keeps starting round one without finishing round two, the oldest nonces are
+/// dropped.
+const MAX_PENDING_NONCES: usize = 256;
+
+/// Reads the json-encoded FROST secret key package written by `astria-cli sequencer threshold
+/// dkg`.
+pub(crate) fn read_key_package<P: AsRef<Path>>(path: P) -> eyre::Result<KeyPackage> {
+    let key_str =
+        std::fs::read_to_string(path).wrap_err("failed to read frost secret key package")?;
+    serde_json::from_str::<KeyPackage>(&key_str)
+        .wrap_err("failed to deserialize frost secret key package")
+}
+
+/// A participant in the FROST threshold signing scheme.
+///
+/// Holds the participant's key share and the nonces handed out in round one until they are used
+/// in round two. Every nonce is used at most once.
+pub(crate) struct Participant {
+    key_package: KeyPackage,
+    pending: Mutex<Pending>,
+}
+
+struct Pending {
+    next_request_identifier: u32,
+    nonces: VecDeque<(u32, SigningNonces)>,
+}
+
+impl Participant {
+    pub(crate) fn new(key_package: KeyPackage) -> Self {
+        Self {
+            key_package,
+            pending: Mutex::new(Pending {
+                next_request_identifier: 0,
+                nonces: VecDeque::new(),
+            }),
+        }
+    }
+
+    pub(crate) fn identifier(&self) -> &Identifier {
+        self.key_package.identifier()
+    }
+
+    /// Returns the serialized verifying share of the participant.
+    pub(crate) fn verifying_share(&self) -> eyre::Result<Vec<u8>> {
+        self.key_package
+            .verifying_share()
+            .serialize()
+            .wrap_err("failed to serialize verifying share")
+    }
+
+    /// Generates fresh signing nonces and returns the identifier under which they are stored
+    /// together with the serialized commitments to them.
+    pub(crate) fn round_one(&self) -> eyre::Result<(u32, Vec<u8>)> {
+        let (nonces, commitments) =
+            round1::commit(self.key_package.signing_share(), &mut rand::thread_rng());
+        let commitments = commitments
+            .serialize()
+            .wrap_err("failed to serialize signing commitments")?;
+
+        let mut pending = self
+            .pending
+            .lock()
+            .expect("the lock is never held across a panic");
+        let request_identifier = pending.next_request_identifier;
+        pending.next_request_identifier = request_identifier.wrapping_add(1);
+        if pending.nonces.len() >= MAX_PENDING_NONCES {
+            pending.nonces.pop_front();
+        }
+        pending.nonces.push_back((request_identifier, nonces));
+        Ok((request_identifier, commitments))
+    }
+
+    /// Removes and returns the nonces generated in round one for `request_identifier`.
+    ///
+    /// Returns `None` if no round one request has this identifier, if its nonces were already
+    /// taken, or if they were dropped to make room for newer ones.
+    pub(crate) fn take_nonces(&self, request_identifier: u32) -> Option<SigningNonces> {
+        let mut pending = self
+            .pending
+            .lock()
+            .expect("the lock is never held across a panic");
+        let position = pending
+            .nonces
+            .iter()
+            .position(|(identifier, _)| *identifier == request_identifier)?;
+        pending.nonces.remove(position).map(|(_, nonces)| nonces)
+    }
+
+    /// Signs `message` with `nonces` given the round one commitments of all signing participants,
+    /// returning the serialized signature share.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the commitments cannot be deserialized or do not contain this
+    /// participant's commitment to `nonces`.
+    pub(crate) fn round_two(
+        &self,
+        nonces: &SigningNonces,
+        message: &[u8],
+        commitments: &[CommitmentWithIdentifier],
+    ) -> eyre::Result<Vec<u8>> {
+        let signing_commitments = commitments
+            .iter()
+            .map(|commitment| {
+                let identifier = Identifier::deserialize(&commitment.participant_identifier)
+                    .wrap_err("failed to deserialize participant identifier")?;
+                let signing_commitments = SigningCommitments::deserialize(&commitment.commitment)
+                    .wrap_err_with(|| {
+                    format!(
+                        "failed to deserialize signing commitments of participant `{}`",
+                        display_identifier(&identifier)
+                    )
+                })?;
+                Ok((identifier, signing_commitments))
+            })
+            .collect::<eyre::Result<BTreeMap<_, _>>>()?;
+        let signing_package = SigningPackage::new(signing_commitments, message);
+        let signature_share = round2::sign(&signing_package, nonces, &self.key_package)
+            .wrap_err("failed to create signature share")?;
+        Ok(signature_share.serialize())
+    }
+}
+
+/// Formats a FROST identifier as hex, the same way `astria-cli sequencer threshold dkg` does.
+pub(crate) fn display_identifier(identifier: &Identifier) -> String {
+    hex::encode(identifier.serialize())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use frost_ed25519::keys::{
+        generate_with_dealer,
+        IdentifierList,
+        KeyPackage,
+        PublicKeyPackage,
+    };
+
+    use super::*;
+
+    fn make_participants() -> (Vec<Participant>, PublicKeyPackage) {
+        let (shares, public_key_package) =
+            generate_with_dealer(3, 2, IdentifierList::Default, rand::thread_rng()).unwrap();
+        let participants = shares
+            .into_values()
+            .map(|share| Participant::new(KeyPackage::try_from(share).unwrap()))
+            .collect();
+        (participants, public_key_package)
+    }
+
+    #[test]
+    fn participants_produce_valid_threshold_signature() {
+        let (participants, public_key_package) = make_participants();
+        let signers = &participants[..2];
+        let message = b"withdrawal";
+
+        let round_one: Vec<_> = signers
+            .iter()
+            .map(|participant| participant.round_one().unwrap())
+            .collect();
+        let commitments: Vec<_> = signers
+            .iter()
+            .zip(&round_one)
+            .map(|(participant, (_, commitment))| CommitmentWithIdentifier {
+                participant_identifier: participant.identifier().serialize().into(),
+                commitment: commitment.clone().into(),
+            })
+            .collect();
+
+        let mut signature_shares = BTreeMap::new();
+        for (participant, (request_identifier, _)) in signers.iter().zip(&round_one) {
+            let nonces = participant.take_nonces(*request_identifier).unwrap();
+            let share = participant
+                .round_two(&nonces, message, &commitments)
+                .unwrap();
+            signature_shares.insert(
+                *participant.identifier(),
+                round2::SignatureShare::deserialize(&share).unwrap(),
+            );
+        }
+
+        let signing_package = SigningPackage::new(
+            commitments
+                .iter()
+                .map(|commitment| {
+                    (
+                        Identifier::deserialize(&commitment.participant_identifier).unwrap(),
+                        SigningCommitments::deserialize(&commitment.commitment).unwrap(),
+                    )
+                })
+                .collect(),
+            message,
+        );
+        let signature =
+            frost_ed25519::aggregate(&signing_package, &signature_shares, &public_key_package)
+                .unwrap();
+        public_key_package
+            .verifying_key()
+            .verify(message, &signature)
+            .unwrap();
+    }
+
+    #[test]
+    fn nonces_are_taken_at_most_once() {
+        let (participants, _) = make_participants();
+        let participant = &participants[0];
+        let (first, _) = participant.round_one().unwrap();
+        let (second, _) = participant.round_one().unwrap();
+        assert_ne!(first, second);
+
+        assert!(participant.take_nonces(first).is_some());
+        assert!(participant.take_nonces(first).is_none());
+        assert!(participant.take_nonces(second).is_some());
+        assert!(participant.take_nonces(second.wrapping_add(1)).is_none());
+    }
+
+    #[test]
+    fn oldest_nonces_are_dropped() {
+        let (participants, _) = make_participants();
+        let participant = &participants[0];
+        let (oldest, _) = participant.round_one().unwrap();
+        for _ in 0..MAX_PENDING_NONCES {
+            participant.round_one().unwrap();
+        }
+        assert!(participant.take_nonces(oldest).is_none());
+        assert!(participant.take_nonces(oldest.wrapping_add(1)).is_some());
+    }
+
+    #[test]
+    fn round_two_without_own_commitment_is_rejected() {
+        let (participants, _) = make_participants();
+        let (request_identifier, _) = participants[0].round_one().unwrap();
+        let (_, other_commitment) = participants[1].round_one().unwrap();
+        let nonces = participants[0].take_nonces(request_identifier).unwrap();
+        let commitments = [CommitmentWithIdentifier {
+            participant_identifier: participants[1].identifier().serialize().into(),
+            commitment: other_commitment.into(),
+        }];
+        assert!(participants[0]
+            .round_two(&nonces, b"withdrawal", &commitments)
+            .is_err());
+    }
+}
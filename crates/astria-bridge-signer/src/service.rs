@@ -0,0 +1,141 @@
+use std::{
+    sync::Arc,
+    time::Instant,
+};
+
+use astria_core::generated::astria::signer::v1::{
+    frost_participant_service_server::FrostParticipantService,
+    ExecuteRoundOneRequest,
+    ExecuteRoundTwoRequest,
+    GetVerifyingShareRequest,
+    RoundOneResponse,
+    RoundTwoResponse,
+    VerifyingShare,
+};
+use tonic::{
+    Request,
+    Response,
+    Status,
+};
+use tracing::{
+    info,
+    instrument,
+    warn,
+};
+
+use crate::{
+    metrics::Metrics,
+    participant::Participant,
+    verifier::{
+        self,
+        Verifier,
+    },
+};
+
+/// Serves the FROST participant API to the bridge withdrawer acting as coordinator.
+pub(crate) struct ParticipantService {
+    participant: Participant,
+    verifier: Verifier,
+    metrics: &'static Metrics,
+}
+
+impl ParticipantService {
+    pub(crate) fn new(
+        participant: Participant,
+        verifier: Verifier,
+        metrics: &'static Metrics,
+    ) -> Self {
+        Self {
+            participant,
+            verifier,
+            metrics,
+        }
+    }
+}
+
+#[tonic::async_trait]
+impl FrostParticipantService for ParticipantService {
+    #[instrument(skip_all)]
+    async fn get_verifying_share(
+        self: Arc<Self>,
+        _request: Request<GetVerifyingShareRequest>,
+    ) -> Result<Response<VerifyingShare>, Status> {
+        let verifying_share = self
+            .participant
+            .verifying_share()
+            .map_err(|error| Status::internal(format!("{error:#}")))?;
+        Ok(Response::new(VerifyingShare {
+            verifying_share: verifying_share.into(),
+        }))
+    }
+
+    #[instrument(skip_all)]
+    async fn execute_round_one(
+        self: Arc<Self>,
+        _request: Request<ExecuteRoundOneRequest>,
+    ) -> Result<Response<RoundOneResponse>, Status> {
+        let (request_identifier, commitment) = self
+            .participant
+            .round_one()
+            .map_err(|error| Status::internal(format!("{error:#}")))?;
+        self.metrics.increment_round_one_count();
+        Ok(Response::new(RoundOneResponse {
+            request_identifier,
+            commitment: commitment.into(),
+        }))
+    }
+
+    #[instrument(skip_all, fields(request_identifier = request.get_ref().request_identifier))]
+    async fn execute_round_two(
+        self: Arc<Self>,
+        request: Request<ExecuteRoundTwoRequest>,
+    ) -> Result<Response<RoundTwoResponse>, Status> {
+        let ExecuteRoundTwoRequest {
+            request_identifier,
+            message,
+            commitments,
+        } = request.into_inner();
+
+        // Taking the nonces before verifying ensures that they are never used twice, even if
+        // verification fails and the coordinator retries with a different message.
+        let nonces = self
+            .participant
+            .take_nonces(request_identifier)
+            .ok_or_else(|| {
+                Status::failed_precondition(format!(
+                    "no pending round one request with identifier `{request_identifier}`; it was \
+                     either already used or never issued"
+                ))
+            })?;
+
+        let start = Instant::now();
+        let verified = self.verifier.verify(&message).await;
+        self.metrics.record_verification_latency(start.elapsed());
+        match verified {
+            Ok(()) => {}
+            Err(verifier::Error::Rejected(error)) => {
+                self.metrics.increment_rejected_signing_request_count();
+                warn!(%error, "refusing to sign transaction");
+                return Err(Status::permission_denied(format!(
+                    "refusing to sign transaction: {error:#}"
+                )));
+            }
+            Err(verifier::Error::Rollup(error)) => {
+                warn!(%error, "failed verifying transaction against the rollup");
+                return Err(Status::unavailable(format!(
+                    "failed verifying transaction against the rollup: {error:#}"
+                )));
+            }
+        }
+
+        let signature_share = self
+            .participant
+            .round_two(&nonces, &message, &commitments)
+            .map_err(|error| Status::invalid_argument(format!("{error:#}")))?;
+        self.metrics.increment_signature_share_count();
+        info!("produced signature share for verified transaction");
+        Ok(Response::new(RoundTwoResponse {
+            signature_share: signature_share.into(),
+        }))
+    }
+}
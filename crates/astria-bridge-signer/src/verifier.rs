@@ -0,0 +1,629 @@
+use std::{
+    collections::BTreeSet,
+    sync::Arc,
+    time::Duration,
+};
+
+use astria_bridge_contracts::{
+    GetWithdrawalActions,
+    GetWithdrawalActionsBuilder,
+};
+use astria_core::{
+    generated::astria::protocol::{
+        memos::v1::Ics20WithdrawalFromRollup,
+        transaction::v1::TransactionBody as RawTransactionBody,
+    },
+    primitive::v1::{
+        asset,
+        Address,
+    },
+    protocol::transaction::v1::{
+        action::ActionName as _,
+        Action,
+        TransactionBody,
+    },
+    Protobuf as _,
+};
+use astria_eyre::eyre::{
+    self,
+    bail,
+    ensure,
+    eyre,
+    OptionExt as _,
+    WrapErr as _,
+};
+use ethers::{
+    providers::{
+        Middleware as _,
+        Provider,
+        ProviderError,
+        Ws,
+    },
+    utils::hex,
+};
+use prost::Message as _;
+use tokio::sync::Mutex;
+use tracing::{
+    debug,
+    info,
+    instrument,
+    warn,
+};
+
+/// Why a transaction was not verified.
+#[derive(Debug)]
+pub(crate) enum Error {
+    /// The transaction is malformed or contains actions that do not match the withdrawals on the
+    /// rollup.
+    Rejected(eyre::Report),
+    /// The withdrawals could not be fetched from the rollup.
+    Rollup(eyre::Report),
+}
+
+pub(crate) struct Builder {
+    pub(crate) sequencer_chain_id: String,
+    pub(crate) sequencer_bridge_address: String,
+    pub(crate) fee_asset_denomination: asset::Denom,
+    pub(crate) rollup_asset_denomination: asset::TracePrefixed,
+    pub(crate) use_compat_address: bool,
+    pub(crate) ethereum_contract_address: String,
+    pub(crate) ethereum_rpc_endpoint: String,
+}
+
+impl Builder {
+    /// Connects to the rollup and constructs the [`Verifier`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the configured addresses cannot be parsed, if the rollup cannot be
+    /// connected to after several retries, or if the withdrawer contract could not be queried.
+    #[instrument(skip_all, err)]
+    pub(crate) async fn connect(self) -> eyre::Result<Verifier> {
+        let Self {
+            sequencer_chain_id,
+            sequencer_bridge_address,
+            fee_asset_denomination,
+            rollup_asset_denomination,
+            use_compat_address,
+            ethereum_contract_address,
+            ethereum_rpc_endpoint,
+        } = self;
+
+        let bridge_address: Address = sequencer_bridge_address
+            .parse()
+            .wrap_err("failed to parse sequencer bridge address")?;
+        let contract_address = address_from_string(&ethereum_contract_address)
+            .wrap_err("failed to parse ethereum contract address")?;
+
+        let connector = RollupConnector {
+            ethereum_rpc_endpoint,
+            contract_address,
+            bridge_address,
+            fee_asset_denomination,
+            rollup_asset_denomination,
+            use_compat_address,
+        };
+        let rollup = connector.connect(1024).await?;
+        info!(%bridge_address, "connected to rollup");
+
+        Ok(Verifier {
+            sequencer_chain_id,
+            connector,
+            rollup: Mutex::new(Some(Arc::new(rollup))),
+        })
+    }
+}
+
+/// The parameters to connect to the rollup, kept to reconnect after the connection was lost.
+struct RollupConnector {
+    ethereum_rpc_endpoint: String,
+    contract_address: ethers::types::Address,
+    bridge_address: Address,
+    fee_asset_denomination: asset::Denom,
+    rollup_asset_denomination: asset::TracePrefixed,
+    use_compat_address: bool,
+}
+
+impl RollupConnector {
+    /// Connects to the rollup, retrying up to `max_retries` times to establish the websocket
+    /// connection.
+    async fn connect(&self, max_retries: u32) -> eyre::Result<Rollup> {
+        let retry_config = tryhard::RetryFutureConfig::new(max_retries)
+            .exponential_backoff(Duration::from_millis(500))
+            .max_delay(Duration::from_secs(60))
+            .on_retry(
+                |attempt, next_delay: Option<Duration>, error: &ProviderError| {
+                    let wait_duration = next_delay
+                        .map(telemetry::display::format_duration)
+                        .map(tracing::field::display);
+                    warn!(
+                        attempt,
+                        wait_duration,
+                        error = error as &dyn std::error::Error,
+                        "attempt to connect to rollup node failed; retrying after backoff",
+                    );
+                    futures::future::ready(())
+                },
+            );
+        let provider = tryhard::retry_fn(|| {
+            let url = self.ethereum_rpc_endpoint.clone();
+            async move {
+                let websocket_client = Ws::connect_with_reconnects(url, 0).await?;
+                Ok(Provider::new(websocket_client))
+            }
+        })
+        .with_config(retry_config)
+        .await
+        .wrap_err("failed connecting to rollup")?;
+        let provider = Arc::new(provider);
+
+        let ics20_asset_to_withdraw = self
+            .rollup_asset_denomination
+            .leading_channel()
+            .is_some()
+            .then(|| self.rollup_asset_denomination.clone());
+        let action_fetcher = GetWithdrawalActionsBuilder::new()
+            .provider(provider.clone())
+            .fee_asset(self.fee_asset_denomination.clone())
+            .contract_address(self.contract_address)
+            .bridge_address(self.bridge_address)
+            .sequencer_asset_to_withdraw(self.rollup_asset_denomination.clone().into())
+            .set_ics20_asset_to_withdraw(ics20_asset_to_withdraw)
+            .use_compat_address(self.use_compat_address)
+            .try_build()
+            .await
+            .wrap_err("failed to construct contract event to sequencer action fetcher")?;
+
+        Ok(Rollup {
+            provider,
+            action_fetcher,
+        })
+    }
+}
+
+/// A connection to the rollup.
+struct Rollup {
+    provider: Arc<Provider<Ws>>,
+    action_fetcher: GetWithdrawalActions<Provider<Ws>>,
+}
+
+impl Rollup {
+    /// Derives the withdrawal actions emitted by the rollup blocks at `rollup_block_numbers`.
+    async fn derive_actions_for_blocks(
+        &self,
+        rollup_block_numbers: &BTreeSet<u64>,
+    ) -> eyre::Result<Vec<Action>> {
+        let mut derived = Vec::new();
+        for rollup_block_number in rollup_block_numbers {
+            derived.extend(self.derive_actions(*rollup_block_number).await?);
+        }
+        Ok(derived)
+    }
+
+    /// Derives the withdrawal actions emitted by the rollup block at `rollup_block_number`.
+    ///
+    /// Events that cannot be converted to actions are dropped, just like the bridge withdrawer
+    /// drops them.
+    #[instrument(skip(self), err)]
+    async fn derive_actions(&self, rollup_block_number: u64) -> eyre::Result<Vec<Action>> {
+        let block = self
+            .provider
+            .get_block(rollup_block_number)
+            .await
+            .map_err(eyre::Report::new)
+            .and_then(|block| block.ok_or_eyre("block is missing"))
+            .wrap_err_with(|| {
+                format!("failed to get block at rollup height `{rollup_block_number}`")
+            })?;
+        let block_hash = block.hash.ok_or_eyre("block did not contain a hash")?;
+        let actions: Vec<_> = self
+            .action_fetcher
+            .get_for_block_hash(block_hash)
+            .await
+            .wrap_err("failed getting actions for block")?
+            .into_iter()
+            .filter_map(|action| {
+                action
+                    .map_err(|error| {
+                        warn!(
+                            error = %eyre::Report::new(error),
+                            "failed to convert rollup withdrawal event to sequencer action; \
+                             dropping"
+                        );
+                    })
+                    .ok()
+            })
+            .collect();
+        debug!(
+            %block_hash,
+            action_count = actions.len(),
+            "derived withdrawal actions from rollup block"
+        );
+        Ok(actions)
+    }
+}
+
+/// Verifies transactions before they are signed by re-deriving their actions from the withdrawal
+/// events on the rollup.
+pub(crate) struct Verifier {
+    sequencer_chain_id: String,
+    connector: RollupConnector,
+    /// The current connection to the rollup. Reset if a request to the rollup failed so that
+    /// the connection is re-established.
+    rollup: Mutex<Option<Arc<Rollup>>>,
+}
+
+impl Verifier {
+    /// Verifies that `message` is an encoded transaction body for the configured sequencer chain
+    /// whose actions are all withdrawals emitted by the rollup blocks they reference.
+    ///
+    /// The actions of the rollup blocks are derived the same way the bridge withdrawer derives
+    /// them, so that a coordinator cannot get a signature for a withdrawal that did not happen on
+    /// the rollup.
+    #[instrument(skip_all, err(Debug))]
+    pub(crate) async fn verify(&self, message: &[u8]) -> Result<(), Error> {
+        let body = decode_transaction_body(message).map_err(Error::Rejected)?;
+        if body.chain_id() != self.sequencer_chain_id {
+            return Err(Error::Rejected(eyre!(
+                "transaction is for sequencer chain `{}`, but `{}` is configured",
+                body.chain_id(),
+                self.sequencer_chain_id
+            )));
+        }
+        let requested = body.into_actions();
+        let rollup_block_numbers = rollup_block_numbers(&requested).map_err(Error::Rejected)?;
+        let derived = self
+            .derive_actions(&rollup_block_numbers)
+            .await
+            .map_err(Error::Rollup)?;
+        match_actions(&requested, derived).map_err(Error::Rejected)
+    }
+
+    /// Derives the withdrawal actions emitted by the rollup blocks at `rollup_block_numbers`.
+    ///
+    /// If deriving the actions fails (for example because the websocket connection to the
+    /// rollup was dropped), the connection is re-established and the actions are derived once
+    /// more.
+    async fn derive_actions(
+        &self,
+        rollup_block_numbers: &BTreeSet<u64>,
+    ) -> eyre::Result<Vec<Action>> {
+        let rollup = self.rollup().await?;
+        match rollup.derive_actions_for_blocks(rollup_block_numbers).await {
+            Ok(derived) => Ok(derived),
+            Err(error) => {
+                warn!(
+                    %error,
+                    "failed deriving actions from rollup; reconnecting and trying again",
+                );
+                self.disconnect(&rollup).await;
+                self.rollup()
+                    .await?
+                    .derive_actions_for_blocks(rollup_block_numbers)
+                    .await
+            }
+        }
+    }
+
+    /// Returns the current connection to the rollup, connecting to it if there is none.
+    async fn rollup(&self) -> eyre::Result<Arc<Rollup>> {
+        let mut rollup = self.rollup.lock().await;
+        if let Some(rollup) = &*rollup {
+            return Ok(rollup.clone());
+        }
+        let connected = Arc::new(
+            self.connector
+                .connect(0)
+                .await
+                .wrap_err("failed to reconnect to rollup")?,
+        );
+        info!("reconnected to rollup");
+        *rollup = Some(connected.clone());
+        Ok(connected)
+    }
+
+    /// Drops the connection `stale` unless it was already replaced by a new connection.
+    async fn disconnect(&self, stale: &Arc<Rollup>) {
+        let mut rollup = self.rollup.lock().await;
+        if rollup
+            .as_ref()
+            .is_some_and(|current| Arc::ptr_eq(current, stale))
+        {
+            *rollup = None;
+        }
+    }
+}
+
+fn decode_transaction_body(message: &[u8]) -> eyre::Result<TransactionBody> {
+    let raw = RawTransactionBody::decode(message)
+        .wrap_err("failed to decode message as a protobuf transaction body")?;
+    TransactionBody::try_from_raw(raw).wrap_err("failed to validate transaction body")
+}
+
+/// Returns the rollup block numbers referenced by `actions`, which must all be withdrawals.
+fn rollup_block_numbers(actions: &[Action]) -> eyre::Result<BTreeSet<u64>> {
+    ensure!(!actions.is_empty(), "transaction contains no actions");
+    actions
+        .iter()
+        .enumerate()
+        .map(|(index, action)| {
+            rollup_block_number(action)
+                .wrap_err_with(|| format!("action at index `{index}` is not a rollup withdrawal"))
+        })
+        .collect()
+}
+
+fn rollup_block_number(action: &Action) -> eyre::Result<u64> {
+    match action {
+        Action::BridgeUnlock(unlock) => Ok(unlock.rollup_block_number),
+        Action::Ics20Withdrawal(withdrawal) => {
+            serde_json::from_str::<Ics20WithdrawalFromRollup>(&withdrawal.memo)
+                .map(|memo| memo.rollup_block_number)
+                .wrap_err("failed to parse ics20 withdrawal memo as a rollup withdrawal memo")
+        }
+        other => bail!("unexpected action `{}`", other.name()),
+    }
+}
+
+/// Checks that every action in `requested` matches a distinct action in `derived`.
+///
+/// The timeout time of ICS20 withdrawals is ignored because it is set relative to the time the
+/// action was derived.
+fn match_actions(requested: &[Action], mut derived: Vec<Action>) -> eyre::Result<()> {
+    for (index, action) in requested.iter().enumerate() {
+        let position = derived
+            .iter()
+            .position(|candidate| is_same_withdrawal(action, candidate))
+            .ok_or_else(|| {
+                eyre!(
+                    "action at index `{index}` does not match any withdrawal event of the rollup \
+                     block it references"
+                )
+            })?;
+        derived.swap_remove(position);
+    }
+    Ok(())
+}
+
+fn is_same_withdrawal(requested: &Action, derived: &Action) -> bool {
+    match (requested, derived) {
+        (Action::BridgeUnlock(requested), Action::BridgeUnlock(derived)) => {
+            requested.clone().into_raw() == derived.clone().into_raw()
+        }
+        (Action::Ics20Withdrawal(requested), Action::Ics20Withdrawal(derived)) => {
+            let mut derived = derived.clone();
+            derived.timeout_time = requested.timeout_time;
+            requested.clone().into_raw() == derived.into_raw()
+        }
+        _ => false,
+    }
+}
+
+// converts an ethereum address string to an `ethers::types::Address`.
+// the input string may be prefixed with "0x" or not.
+fn address_from_string(s: &str) -> eyre::Result<ethers::types::Address> {
+    let s = s.strip_prefix("0x").unwrap_or(s);
+    let bytes = hex::decode(s).wrap_err("failed to parse ethereum address as hex")?;
+    let address: [u8; 20] = bytes.try_into().map_err(|bytes: Vec<u8>| {
+        eyre!(
+            "invalid length for {} ethereum address, must be 20 bytes",
+            bytes.len()
+        )
+    })?;
+    Ok(address.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use astria_core::{
+        primitive::v1::asset::Denom,
+        protocol::transaction::v1::action::{
+            BridgeUnlock,
+            Ics20Withdrawal,
+            Transfer,
+        },
+    };
+    use ethers::types::{
+        Block,
+        H256,
+    };
+    use jsonrpsee::{
+        server::{
+            Server,
+            ServerHandle,
+        },
+        RpcModule,
+    };
+    use prost::Message as _;
+
+    use super::*;
+
+    const CHAIN_ID: &str = "astria";
+
+    fn bridge_address() -> Address {
+        Address::builder()
+            .array([1; 20])
+            .prefix("astria")
+            .try_build()
+            .unwrap()
+    }
+
+    fn fee_asset() -> Denom {
+        "nria".parse().unwrap()
+    }
+
+    fn bridge_unlock(rollup_block_number: u64, amount: u128) -> Action {
+        Action::BridgeUnlock(BridgeUnlock {
+            to: Address::builder()
+                .array([2; 20])
+                .prefix("astria")
+                .try_build()
+                .unwrap(),
+            amount,
+            memo: String::new(),
+            fee_asset: fee_asset(),
+            bridge_address: bridge_address(),
+            rollup_block_number,
+            rollup_withdrawal_event_id: format!("0x{rollup_block_number:064x}.0x00"),
+        })
+    }
+
+    fn ics20_withdrawal(rollup_block_number: u64, timeout_time: u64) -> Action {
+        let memo = serde_json::to_string(&Ics20WithdrawalFromRollup {
+            rollup_block_number,
+            rollup_withdrawal_event_id: "0x01.0x00".to_string(),
+            rollup_return_address: "0x02".to_string(),
+            memo: String::new(),
+        })
+        .unwrap();
+        Action::Ics20Withdrawal(Ics20Withdrawal {
+            amount: 1,
+            denom: "transfer/channel-0/utia".parse().unwrap(),
+            destination_chain_address: "celestia1".to_string(),
+            return_address: bridge_address(),
+            timeout_height: ibc_types::core::client::Height::new(u64::MAX, u64::MAX).unwrap(),
+            timeout_time,
+            source_channel: "channel-0".parse().unwrap(),
+            fee_asset: fee_asset(),
+            memo,
+            bridge_address: Some(bridge_address()),
+            use_compat_address: false,
+        })
+    }
+
+    #[test]
+    fn transaction_body_is_decoded() {
+        let body = TransactionBody::builder()
+            .actions(vec![bridge_unlock(5, 10)])
+            .chain_id(CHAIN_ID)
+            .nonce(1)
+            .try_build()
+            .unwrap();
+        let decoded = decode_transaction_body(&body.to_raw().encode_to_vec()).unwrap();
+        assert_eq!(CHAIN_ID, decoded.chain_id());
+        assert_eq!(1, decoded.actions().len());
+
+        assert!(decode_transaction_body(b"not a transaction body").is_err());
+    }
+
+    #[test]
+    fn rollup_block_numbers_are_read_from_withdrawals() {
+        let numbers = rollup_block_numbers(&[
+            bridge_unlock(5, 10),
+            ics20_withdrawal(7, 0),
+            bridge_unlock(5, 3),
+        ])
+        .unwrap();
+        assert_eq!(BTreeSet::from([5, 7]), numbers);
+    }
+
+    #[test]
+    fn non_withdrawal_actions_are_rejected() {
+        let transfer = Action::Transfer(Transfer {
+            to: bridge_address(),
+            amount: 1,
+            asset: fee_asset(),
+            fee_asset: fee_asset(),
+        });
+        assert!(rollup_block_numbers(&[bridge_unlock(5, 10), transfer]).is_err());
+        assert!(rollup_block_numbers(&[]).is_err());
+    }
+
+    #[test]
+    fn matching_actions_are_accepted() {
+        match_actions(
+            &[bridge_unlock(5, 10), ics20_withdrawal(5, 100)],
+            vec![ics20_withdrawal(5, 200), bridge_unlock(5, 10)],
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn subset_of_derived_actions_is_accepted() {
+        match_actions(
+            &[bridge_unlock(5, 10)],
+            vec![bridge_unlock(5, 10), bridge_unlock(5, 20)],
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn tampered_action_is_rejected() {
+        assert!(match_actions(&[bridge_unlock(5, 11)], vec![bridge_unlock(5, 10)]).is_err());
+    }
+
+    #[test]
+    fn duplicated_action_is_rejected() {
+        assert!(match_actions(
+            &[bridge_unlock(5, 10), bridge_unlock(5, 10)],
+            vec![bridge_unlock(5, 10)],
+        )
+        .is_err());
+    }
+
+    /// Spawns a websocket JSON-RPC server bound to `addr` that mocks a rollup without any
+    /// withdrawal events.
+    async fn spawn_rollup(addr: &str) -> (std::net::SocketAddr, ServerHandle) {
+        let server = Server::builder().ws_only().build(addr).await.unwrap();
+        let local_addr = server.local_addr().unwrap();
+        let mut module = RpcModule::new(());
+        // Answers the queries for the asset precision and decimals of the withdrawer contract.
+        module
+            .register_method("eth_call", |_, (), _| format!("0x{:064x}", 18))
+            .unwrap();
+        module
+            .register_method("eth_getBlockByNumber", |_, (), _| {
+                serde_json::to_value(Block::<H256> {
+                    hash: Some(H256::repeat_byte(1)),
+                    ..Block::default()
+                })
+                .unwrap()
+            })
+            .unwrap();
+        module
+            .register_method("eth_getLogs", |_, (), _| serde_json::json!([]))
+            .unwrap();
+        (local_addr, server.start(module))
+    }
+
+    #[tokio::test]
+    async fn verifier_recovers_after_rollup_connection_drops() {
+        let (rollup_addr, rollup) = spawn_rollup("127.0.0.1:0").await;
+        let verifier = Builder {
+            sequencer_chain_id: CHAIN_ID.to_string(),
+            sequencer_bridge_address: bridge_address().to_string(),
+            fee_asset_denomination: fee_asset(),
+            rollup_asset_denomination: "nria".parse().unwrap(),
+            use_compat_address: false,
+            ethereum_contract_address: format!("0x{}", "03".repeat(20)),
+            ethereum_rpc_endpoint: format!("ws://{rollup_addr}"),
+        }
+        .connect()
+        .await
+        .unwrap();
+        let message = TransactionBody::builder()
+            .actions(vec![bridge_unlock(5, 10)])
+            .chain_id(CHAIN_ID)
+            .nonce(1)
+            .try_build()
+            .unwrap()
+            .to_raw()
+            .encode_to_vec();
+
+        // The mocked rollup emitted no withdrawals, so reaching it means the action is rejected.
+        assert!(matches!(
+            verifier.verify(&message).await,
+            Err(Error::Rejected(_))
+        ));
+
+        rollup.stop().unwrap();
+        rollup.stopped().await;
+        let (_, _rollup) = spawn_rollup(&rollup_addr.to_string()).await;
+
+        let result = verifier.verify(&message).await;
+        assert!(
+            matches!(result, Err(Error::Rejected(_))),
+            "verifier should have reconnected to the rollup, but got: {result:?}",
+        );
+    }
+}
@@ -383,6 +383,7 @@ impl Frost {
                     .serialize()
                     .wrap_err("failed to serialize verifying key of public key package")?
                     .into(),
+                multisig: None,
             };
         let transaction = Transaction::try_from_raw(raw_transaction)
             .wrap_err("failed to convert raw transaction to transaction")?;
@@ -17,6 +17,7 @@ use astria_core::{
             GetPendingNonceResponse,
             GetSequencerBlockRequest,
             SequencerBlock as RawSequencerBlock,
+            StreamFilteredSequencerBlocksRequest,
         },
         sequencerblock::v1::{
             GetUpgradesInfoRequest,
@@ -107,6 +108,9 @@ struct SequencerServiceImpl(MockServer);
 
 #[tonic::async_trait]
 impl SequencerService for SequencerServiceImpl {
+    type StreamFilteredSequencerBlocksStream =
+        tonic::codegen::tokio_stream::Empty<Result<RawFilteredSequencerBlock, Status>>;
+
     async fn get_sequencer_block(
         self: Arc<Self>,
         _request: Request<GetSequencerBlockRequest>,
@@ -121,6 +125,13 @@ impl SequencerService for SequencerServiceImpl {
         unimplemented!()
     }
 
+    async fn stream_filtered_sequencer_blocks(
+        self: Arc<Self>,
+        _request: Request<StreamFilteredSequencerBlocksRequest>,
+    ) -> Result<Response<Self::StreamFilteredSequencerBlocksStream>, Status> {
+        unimplemented!()
+    }
+
     async fn get_pending_nonce(
         self: Arc<Self>,
         request: Request<GetPendingNonceRequest>,
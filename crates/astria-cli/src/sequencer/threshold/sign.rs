@@ -425,6 +425,7 @@ fn output_transaction(
             .serialize()
             .wrap_err("failed to serialize verifying key")?
             .into(),
+        multisig: None,
     };
 
     let serialized_tx =
@@ -62,6 +62,7 @@ use tracing::{
     warn,
 };
 
+use super::Status;
 use crate::{
     collectors::EXECUTOR_SEND_TIMEOUT,
     executor::{
@@ -100,23 +101,6 @@ pub(crate) struct Geth {
     fee_asset: asset::Denom,
 }
 
-#[derive(Debug)]
-pub(crate) struct Status {
-    pub(crate) is_connected: bool,
-}
-
-impl Status {
-    fn new() -> Self {
-        Self {
-            is_connected: false,
-        }
-    }
-
-    pub(crate) fn is_connected(&self) -> bool {
-        self.is_connected
-    }
-}
-
 pub(crate) struct Builder {
     pub(crate) chain_name: String,
     pub(crate) url: String,
@@ -296,7 +280,7 @@ impl Geth {
 }
 
 #[instrument(skip_all)]
-async fn forward_geth_tx(
+pub(super) async fn forward_geth_tx(
     executor_handle: &Handle,
     seq_action: RollupDataSubmission,
     tx_hash: ethers::types::H256,
@@ -329,7 +313,7 @@ async fn forward_geth_tx(
 }
 
 #[instrument(skip_all)]
-async fn unsubscribe_from_rollup(tx_stream: &Provider<Ws>, subscription_id: &U256) {
+pub(super) async fn unsubscribe_from_rollup(tx_stream: &Provider<Ws>, subscription_id: &U256) {
     // give 2s for the websocket connection to be unsubscribed as we want to avoid having
     // this hang for too long
     match tokio::time::timeout(
@@ -350,9 +334,9 @@ async fn unsubscribe_from_rollup(tx_stream: &Provider<Ws>, subscription_id: &U25
 }
 
 #[instrument(skip_all)]
-fn txs_received_counter(metrics: &'static Metrics, chain_name: &String) -> Counter {
+pub(super) fn txs_received_counter(metrics: &'static Metrics, chain_name: &String) -> Counter {
     metrics
-        .geth_txs_received(chain_name)
+        .node_txs_received(chain_name)
         .cloned()
         .unwrap_or_else(|| {
             error!(
@@ -364,9 +348,9 @@ fn txs_received_counter(metrics: &'static Metrics, chain_name: &String) -> Count
 }
 
 #[instrument(skip_all)]
-fn txs_dropped_counter(metrics: &'static Metrics, chain_name: &String) -> Counter {
+pub(super) fn txs_dropped_counter(metrics: &'static Metrics, chain_name: &String) -> Counter {
     metrics
-        .geth_txs_dropped(chain_name)
+        .node_txs_dropped(chain_name)
         .cloned()
         .unwrap_or_else(|| {
             error!(
@@ -378,7 +362,7 @@ fn txs_dropped_counter(metrics: &'static Metrics, chain_name: &String) -> Counte
 }
 
 #[instrument(skip_all, err)]
-async fn connect_to_geth_node(url: String) -> eyre::Result<Provider<Ws>> {
+pub(super) async fn connect_to_geth_node(url: String) -> eyre::Result<Provider<Ws>> {
     let retry_config = tryhard::RetryFutureConfig::new(1024)
         .exponential_backoff(Duration::from_millis(500))
         .max_delay(Duration::from_secs(60))
@@ -5,6 +5,9 @@ use std::sync::Arc;
 use astria_core::{
     generated::astria::composer::v1::{
         grpc_collector_service_server::GrpcCollectorService,
+        submission_receipt,
+        GetSubmissionReceiptRequest,
+        SubmissionReceipt,
         SubmitRollupTransactionRequest,
         SubmitRollupTransactionResponse,
     },
@@ -14,24 +17,38 @@ use astria_core::{
     },
     protocol::transaction::v1::action::RollupDataSubmission,
 };
+use sequencer_client::tendermint_rpc::Client as _;
+use tendermint::Hash;
 use tokio::sync::mpsc::error::SendTimeoutError;
 use tonic::{
     Request,
     Response,
     Status,
 };
+use tracing::debug;
 
 use crate::{
     collectors::EXECUTOR_SEND_TIMEOUT,
     executor,
     metrics::Metrics,
+    receipts::{
+        ReceiptId,
+        ReceiptStatus,
+        Receipts,
+    },
 };
 
 /// Implements the `GrpcCollectorService` which listens for incoming gRPC requests and
 /// sends the Rollup transactions to the Executor. The Executor then sends the transactions
 /// to the Astria Shared Sequencer.
+///
+/// Identical transactions submitted while a receipt for them is retained are only forwarded
+/// once. The status of a submission is tracked by its receipt, with inclusion of a bundled
+/// transaction looked up from the sequencer on request.
 pub(crate) struct Grpc {
     executor: executor::Handle,
+    abci_client: sequencer_client::HttpClient,
+    receipts: Receipts,
     metrics: &'static Metrics,
     fee_asset: asset::Denom,
 }
@@ -39,15 +56,53 @@ pub(crate) struct Grpc {
 impl Grpc {
     pub(crate) fn new(
         executor: executor::Handle,
+        abci_client: sequencer_client::HttpClient,
+        receipts: Receipts,
         metrics: &'static Metrics,
         fee_asset: asset::Denom,
     ) -> Self {
         Self {
             executor,
+            abci_client,
+            receipts,
             metrics,
             fee_asset,
         }
     }
+
+    /// Looks up whether the sequencer transaction with `hash` was executed, returning the
+    /// bundled status if it was not found.
+    async fn bundled_or_executed_status(&self, hash: [u8; 32]) -> submission_receipt::Status {
+        let sequencer_transaction_hash = hash.to_vec().into();
+        match self.abci_client.tx(Hash::Sha256(hash), false).await {
+            Ok(rsp) if rsp.tx_result.code.is_ok() => {
+                submission_receipt::Status::Included(submission_receipt::Included {
+                    sequencer_transaction_hash,
+                    height: rsp.height.value(),
+                })
+            }
+            Ok(rsp) => submission_receipt::Status::Dropped(submission_receipt::Dropped {
+                reason: format!(
+                    "sequencer transaction `{}` failed execution at height {} with abci code {}: \
+                     {}",
+                    hex::encode(hash),
+                    rsp.height,
+                    rsp.tx_result.code.value(),
+                    rsp.tx_result.log,
+                ),
+            }),
+            Err(error) => {
+                debug!(
+                    error = &error as &dyn std::error::Error,
+                    sequencer_transaction_hash = %hex::encode(hash),
+                    "failed to find bundled transaction on sequencer; reporting it as bundled",
+                );
+                submission_receipt::Status::Bundled(submission_receipt::Bundled {
+                    sequencer_transaction_hash,
+                })
+            }
+        }
+    }
 }
 
 #[async_trait::async_trait]
@@ -70,8 +125,16 @@ impl GrpcCollectorService for Grpc {
             data: submit_rollup_tx_request.data,
             fee_asset: self.fee_asset.clone(),
         };
+        let receipt_id = ReceiptId::for_submission(&rollup_data_submission);
 
         self.metrics.increment_grpc_txs_received(&rollup_id);
+        if !self.receipts.try_create(receipt_id) {
+            debug!(%receipt_id, "transaction was already submitted; not forwarding it again");
+            return Ok(Response::new(SubmitRollupTransactionResponse {
+                receipt_id: receipt_id.get().to_vec().into(),
+                duplicate: true,
+            }));
+        }
         match self
             .executor
             .send_timeout(rollup_data_submission, EXECUTOR_SEND_TIMEOUT)
@@ -80,14 +143,59 @@ impl GrpcCollectorService for Grpc {
             Ok(()) => {}
             Err(SendTimeoutError::Timeout(_seq_action)) => {
                 self.metrics.increment_grpc_txs_dropped(&rollup_id);
+                self.receipts
+                    .set_dropped([receipt_id], "timeout while sending txs to composer");
                 return Err(Status::unavailable("timeout while sending txs to composer"));
             }
             Err(SendTimeoutError::Closed(_seq_action)) => {
                 self.metrics.increment_grpc_txs_dropped(&rollup_id);
+                self.receipts
+                    .set_dropped([receipt_id], "composer is not available");
                 return Err(Status::failed_precondition("composer is not available"));
             }
         }
 
-        Ok(Response::new(SubmitRollupTransactionResponse {}))
+        Ok(Response::new(SubmitRollupTransactionResponse {
+            receipt_id: receipt_id.get().to_vec().into(),
+            duplicate: false,
+        }))
+    }
+
+    async fn get_submission_receipt(
+        self: Arc<Self>,
+        request: Request<GetSubmissionReceiptRequest>,
+    ) -> Result<Response<SubmissionReceipt>, Status> {
+        let request = request.into_inner();
+        let receipt_id = ReceiptId::try_from_slice(&request.receipt_id).ok_or_else(|| {
+            Status::invalid_argument(format!(
+                "receipt ID must be 32 bytes, but was {} bytes",
+                request.receipt_id.len()
+            ))
+        })?;
+        let status = match self.receipts.get(&receipt_id) {
+            None => {
+                return Err(Status::not_found(format!(
+                    "no receipt retained for ID `{receipt_id}`"
+                )));
+            }
+            Some(ReceiptStatus::Queued) => {
+                submission_receipt::Status::Queued(submission_receipt::Queued {})
+            }
+            Some(ReceiptStatus::Bundled {
+                sequencer_transaction_hash,
+            }) => {
+                self.bundled_or_executed_status(sequencer_transaction_hash)
+                    .await
+            }
+            Some(ReceiptStatus::Dropped {
+                reason,
+            }) => submission_receipt::Status::Dropped(submission_receipt::Dropped {
+                reason,
+            }),
+        };
+        Ok(Response::new(SubmissionReceipt {
+            receipt_id: request.receipt_id,
+            status: Some(status),
+        }))
     }
 }
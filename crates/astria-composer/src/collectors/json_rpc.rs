@@ -0,0 +1,416 @@
+//! [`JsonRpc`] fetches pending transactions from an Ethereum rollup node over the standard
+//! JSON-RPC API.
+//!
+//! Unlike [`Geth`](super::Geth), it does not rely on go-ethereum's extension of `eth_subscribe`
+//! that streams full pending transactions. This allows feeding the composer from rollups
+//! built on other clients such as Reth, Erigon, or Nethermind. The pending transactions are read
+//! in one of the following ways:
+//!
+//! + [`Mode::Txpool`] polls the node's tx pool via `txpool_content`;
+//! + [`Mode::PendingBlock`] polls the node's pending block via `eth_getBlockByNumber`;
+//! + [`Mode::PendingHashes`] subscribes to the hashes of new pending transactions via
+//!   `eth_subscribe` and fetches each transaction via `eth_getTransactionByHash`.
+//!
+//! The polling modes accept both HTTP and websocket URLs, while subscribing requires a websocket
+//! URL.
+
+use std::{
+    collections::{
+        BTreeMap,
+        HashSet,
+    },
+    time::Duration,
+};
+
+use astria_core::{
+    primitive::v1::{
+        asset,
+        RollupId,
+    },
+    protocol::transaction::v1::action::RollupDataSubmission,
+};
+use astria_eyre::eyre::{
+    self,
+    bail,
+    eyre,
+    WrapErr as _,
+};
+use ethers::{
+    providers::{
+        Http,
+        JsonRpcClient,
+        Middleware as _,
+        Provider,
+        Ws,
+    },
+    types::{
+        BlockNumber,
+        Transaction,
+        H256,
+    },
+};
+use futures::StreamExt as _;
+use itertools::Itertools as _;
+use telemetry::metrics::Counter;
+use tokio::{
+    select,
+    sync::watch,
+    time::MissedTickBehavior,
+};
+use tokio_util::sync::CancellationToken;
+use tracing::{
+    debug,
+    info,
+    warn,
+};
+
+use super::{
+    geth::{
+        connect_to_geth_node,
+        forward_geth_tx,
+        txs_dropped_counter,
+        txs_received_counter,
+        unsubscribe_from_rollup,
+    },
+    Status,
+};
+use crate::{
+    executor,
+    metrics::Metrics,
+    utils::report_exit_reason,
+};
+
+/// The interval at which the polling modes read the node's pending transactions.
+const POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+/// The number of consecutive failed polls after which the collector exits.
+const MAX_CONSECUTIVE_POLL_FAILURES: u32 = 20;
+
+/// How [`JsonRpc`] reads pending transactions from the rollup node.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Mode {
+    /// Poll `txpool_content`.
+    Txpool,
+    /// Poll `eth_getBlockByNumber("pending", true)`.
+    PendingBlock,
+    /// Subscribe to `newPendingTransactions` hashes and fetch each via
+    /// `eth_getTransactionByHash`.
+    PendingHashes,
+}
+
+impl Mode {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Txpool => "txpool",
+            Self::PendingBlock => "pending-block",
+            Self::PendingHashes => "pending-hashes",
+        }
+    }
+}
+
+/// The source of pending transactions polled by [`Mode::Txpool`] and [`Mode::PendingBlock`].
+#[derive(Clone, Copy, Debug)]
+enum PollTarget {
+    Txpool,
+    PendingBlock,
+}
+
+/// Collects transactions submitted to a rollup node over the standard Ethereum JSON-RPC API and
+/// passes them downstream for further processing.
+pub(crate) struct JsonRpc {
+    // Chain ID to identify in the astria sequencer block which rollup a serialized sequencer
+    // action belongs to. Created from `chain_name`.
+    rollup_id: RollupId,
+    // Name of the chain the transactions are read from.
+    chain_name: String,
+    // How pending transactions are read from the rollup node.
+    mode: Mode,
+    // The channel on which the collector sends new txs to the executor.
+    executor_handle: executor::Handle,
+    // The status of this collector instance.
+    status: watch::Sender<Status>,
+    // Rollup URL
+    url: String,
+    // Token to signal the collector to stop upon shutdown.
+    shutdown_token: CancellationToken,
+    metrics: &'static Metrics,
+    fee_asset: asset::Denom,
+}
+
+pub(crate) struct Builder {
+    pub(crate) chain_name: String,
+    pub(crate) mode: Mode,
+    pub(crate) url: String,
+    pub(crate) executor_handle: executor::Handle,
+    pub(crate) shutdown_token: CancellationToken,
+    pub(crate) metrics: &'static Metrics,
+    pub(crate) fee_asset: asset::Denom,
+}
+
+impl Builder {
+    /// Builds the collector.
+    ///
+    /// # Errors
+    /// Returns an error if `mode` is [`Mode::PendingHashes`] but `url` is not a websocket URL.
+    pub(crate) fn build(self) -> eyre::Result<JsonRpc> {
+        let Self {
+            chain_name,
+            mode,
+            url,
+            executor_handle,
+            shutdown_token,
+            metrics,
+            fee_asset,
+        } = self;
+        if mode == Mode::PendingHashes && !is_websocket_url(&url) {
+            bail!(
+                "collector `{}` of rollup `{chain_name}` subscribes to pending transactions and \
+                 requires a websocket URL, got `{url}`",
+                mode.as_str(),
+            );
+        }
+        let (status, _) = watch::channel(Status::new());
+        let rollup_id = RollupId::from_unhashed_bytes(&chain_name);
+        info!(
+            rollup_name = %chain_name,
+            rollup_id = %rollup_id,
+            mode = mode.as_str(),
+            "created new json-rpc collector for rollup",
+        );
+        Ok(JsonRpc {
+            rollup_id,
+            chain_name,
+            mode,
+            executor_handle,
+            status,
+            url,
+            shutdown_token,
+            metrics,
+            fee_asset,
+        })
+    }
+}
+
+impl JsonRpc {
+    /// Subscribe to the collector's status.
+    pub(crate) fn subscribe(&self) -> watch::Receiver<Status> {
+        self.status.subscribe()
+    }
+
+    /// Starts the collector instance and runs until failure or until explicitly closed.
+    pub(crate) async fn run_until_stopped(self) -> eyre::Result<()> {
+        let forwarder = Forwarder {
+            rollup_id: self.rollup_id,
+            fee_asset: self.fee_asset.clone(),
+            executor_handle: self.executor_handle.clone(),
+            txs_received_counter: txs_received_counter(self.metrics, &self.chain_name),
+            txs_dropped_counter: txs_dropped_counter(self.metrics, &self.chain_name),
+        };
+
+        let poll_target = match self.mode {
+            Mode::Txpool => Some(PollTarget::Txpool),
+            Mode::PendingBlock => Some(PollTarget::PendingBlock),
+            Mode::PendingHashes => None,
+        };
+        let reason = match poll_target {
+            Some(target) if is_websocket_url(&self.url) => {
+                let client = connect_to_geth_node(self.url.clone())
+                    .await
+                    .wrap_err("failed to connect to rollup node")?;
+                self.poll_pending_txs(&client, target, &forwarder).await
+            }
+            Some(target) => {
+                let client = Provider::<Http>::try_from(self.url.as_str())
+                    .wrap_err("failed to construct http client for rollup node")?;
+                self.poll_pending_txs(&client, target, &forwarder).await
+            }
+            None => {
+                let client = connect_to_geth_node(self.url.clone())
+                    .await
+                    .wrap_err("failed to connect to rollup node")?;
+                self.subscribe_pending_tx_hashes(&client, &forwarder).await
+            }
+        };
+
+        self.status
+            .send_modify(|status| status.is_connected = false);
+
+        report_exit_reason(reason.as_deref());
+
+        reason.map(|_| ())
+    }
+
+    /// Polls the pending transactions of the rollup node, forwarding each transaction once.
+    ///
+    /// Only the hashes of transactions still pending are remembered, so that the memory used
+    /// for deduplication is bounded by the size of the node's pool.
+    async fn poll_pending_txs<P: JsonRpcClient>(
+        &self,
+        client: &Provider<P>,
+        target: PollTarget,
+        forwarder: &Forwarder,
+    ) -> eyre::Result<&'static str> {
+        let mut forwarded_hashes = HashSet::new();
+        let mut consecutive_failures: u32 = 0;
+        let mut interval = tokio::time::interval(POLL_INTERVAL);
+        interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+        loop {
+            select! {
+                biased;
+                () = self.shutdown_token.cancelled() => {
+                    break Ok("shutdown signal received");
+                },
+                _ = interval.tick() => {
+                    let pending_txs = match fetch_pending_txs(client, target).await {
+                        Ok(pending_txs) => pending_txs,
+                        Err(error) => {
+                            consecutive_failures = consecutive_failures.saturating_add(1);
+                            if consecutive_failures >= MAX_CONSECUTIVE_POLL_FAILURES {
+                                break Err(error.wrap_err(format!(
+                                    "failed to fetch pending transactions from rollup node \
+                                     {consecutive_failures} times in a row",
+                                )));
+                            }
+                            warn!(
+                                %error,
+                                consecutive_failures,
+                                "failed to fetch pending transactions from rollup node; \
+                                 retrying at next poll",
+                            );
+                            continue;
+                        }
+                    };
+                    consecutive_failures = 0;
+                    self.status.send_if_modified(|status| {
+                        !std::mem::replace(&mut status.is_connected, true)
+                    });
+                    let still_pending = pending_txs
+                        .iter()
+                        .map(|tx| tx.hash)
+                        .collect::<HashSet<_>>();
+                    for tx in pending_txs
+                        .into_iter()
+                        .filter(|tx| !forwarded_hashes.contains(&tx.hash))
+                        .unique_by(|tx| tx.hash)
+                    {
+                        forwarder.forward(&tx).await?;
+                    }
+                    forwarded_hashes = still_pending;
+                }
+            }
+        }
+    }
+
+    /// Subscribes to the hashes of new pending transactions and forwards the transaction fetched
+    /// for each hash.
+    async fn subscribe_pending_tx_hashes(
+        &self,
+        client: &Provider<Ws>,
+        forwarder: &Forwarder,
+    ) -> eyre::Result<&'static str> {
+        let mut tx_hash_stream = client
+            .subscribe_pending_txs()
+            .await
+            .wrap_err("failed to subscribe eth client to pending transaction hashes")?;
+        let subscription_id = tx_hash_stream.id;
+
+        self.status.send_modify(|status| status.is_connected = true);
+
+        let reason = loop {
+            select! {
+                biased;
+                () = self.shutdown_token.cancelled() => {
+                    break Ok("shutdown signal received");
+                },
+                tx_hash = tx_hash_stream.next() => {
+                    let Some(tx_hash) = tx_hash else {
+                        break Err(eyre!("pending transaction hash stream ended"));
+                    };
+                    match fetch_tx(client, tx_hash).await {
+                        Ok(Some(tx)) => {
+                            if let Err(error) = forwarder.forward(&tx).await {
+                                break Err(error);
+                            }
+                        }
+                        Ok(None) => debug!(
+                            transaction.hash = %tx_hash,
+                            "pending transaction no longer known to rollup node; skipping",
+                        ),
+                        Err(error) => break Err(error),
+                    }
+                }
+            }
+        };
+
+        unsubscribe_from_rollup(client, &subscription_id).await;
+
+        reason
+    }
+}
+
+/// Wraps the pending transactions read from the rollup node and sends them to the executor.
+struct Forwarder {
+    rollup_id: RollupId,
+    fee_asset: asset::Denom,
+    executor_handle: executor::Handle,
+    txs_received_counter: Counter,
+    txs_dropped_counter: Counter,
+}
+
+impl Forwarder {
+    async fn forward(&self, tx: &Transaction) -> eyre::Result<()> {
+        self.txs_received_counter.increment(1);
+        let seq_action = RollupDataSubmission {
+            rollup_id: self.rollup_id,
+            data: tx.rlp().to_vec().into(),
+            fee_asset: self.fee_asset.clone(),
+        };
+        forward_geth_tx(
+            &self.executor_handle,
+            seq_action,
+            tx.hash,
+            &self.txs_dropped_counter,
+        )
+        .await
+    }
+}
+
+/// Reads the pending transactions of the rollup node, ordered by nonce.
+async fn fetch_pending_txs<P: JsonRpcClient>(
+    client: &Provider<P>,
+    target: PollTarget,
+) -> eyre::Result<Vec<Transaction>> {
+    let pending_txs = match target {
+        PollTarget::Txpool => client
+            .txpool_content()
+            .await
+            .wrap_err("failed to get current tx pool")?
+            .pending
+            .into_values()
+            .flat_map(BTreeMap::into_values)
+            .collect(),
+        PollTarget::PendingBlock => client
+            .get_block_with_txs(BlockNumber::Pending)
+            .await
+            .wrap_err("failed to get pending block")?
+            .map(|block| block.transactions)
+            .unwrap_or_default(),
+    };
+    // Using `sorted_by_key` instead of `sorted_unstable_by_key` to ensure that the order of the
+    // transactions is deterministic.
+    Ok(pending_txs
+        .into_iter()
+        .sorted_by_key(|tx| tx.nonce)
+        .collect())
+}
+
+async fn fetch_tx(client: &Provider<Ws>, tx_hash: H256) -> eyre::Result<Option<Transaction>> {
+    client
+        .get_transaction(tx_hash)
+        .await
+        .wrap_err_with(|| format!("failed to get pending transaction `{tx_hash}`"))
+}
+
+fn is_websocket_url(url: &str) -> bool {
+    url.starts_with("ws://") || url.starts_with("wss://")
+}
@@ -1,9 +1,125 @@
 pub(crate) mod geth;
 pub(crate) mod grpc;
+pub(crate) mod json_rpc;
 
 use std::time::Duration;
 
+use astria_core::primitive::v1::asset;
+use astria_eyre::eyre;
+use tokio::sync::watch;
+use tokio_util::sync::CancellationToken;
+
+use crate::{
+    executor,
+    metrics::Metrics,
+    rollup::{
+        CollectorKind,
+        Endpoint,
+    },
+};
+
 const EXECUTOR_SEND_TIMEOUT: Duration = Duration::from_millis(500);
 
 pub(crate) use geth::Geth;
 pub(crate) use grpc::Grpc;
+pub(crate) use json_rpc::JsonRpc;
+
+/// The status of a collector reading pending transactions from a rollup node.
+#[derive(Debug)]
+pub(crate) struct Status {
+    pub(crate) is_connected: bool,
+}
+
+impl Status {
+    fn new() -> Self {
+        Self {
+            is_connected: false,
+        }
+    }
+
+    pub(crate) fn is_connected(&self) -> bool {
+        self.is_connected
+    }
+}
+
+/// A collector reading pending transactions from a rollup node, as configured for the rollup.
+pub(crate) enum RollupCollector {
+    Geth(Geth),
+    JsonRpc(JsonRpc),
+}
+
+impl RollupCollector {
+    /// Subscribe to the collector's status.
+    pub(crate) fn subscribe(&self) -> watch::Receiver<Status> {
+        match self {
+            Self::Geth(collector) => collector.subscribe(),
+            Self::JsonRpc(collector) => collector.subscribe(),
+        }
+    }
+
+    /// Starts the collector instance and runs until failure or until explicitly closed.
+    pub(crate) async fn run_until_stopped(self) -> eyre::Result<()> {
+        match self {
+            Self::Geth(collector) => collector.run_until_stopped().await,
+            Self::JsonRpc(collector) => collector.run_until_stopped().await,
+        }
+    }
+}
+
+pub(crate) struct RollupCollectorBuilder {
+    pub(crate) chain_name: String,
+    pub(crate) endpoint: Endpoint,
+    pub(crate) executor_handle: executor::Handle,
+    pub(crate) shutdown_token: CancellationToken,
+    pub(crate) metrics: &'static Metrics,
+    pub(crate) fee_asset: asset::Denom,
+}
+
+impl RollupCollectorBuilder {
+    /// Builds the collector of the kind configured in `endpoint`.
+    ///
+    /// # Errors
+    /// Returns an error if the URL in `endpoint` is not supported by the collector.
+    pub(crate) fn build(self) -> eyre::Result<RollupCollector> {
+        let Self {
+            chain_name,
+            endpoint: Endpoint {
+                collector,
+                url,
+            },
+            executor_handle,
+            shutdown_token,
+            metrics,
+            fee_asset,
+        } = self;
+        let mode = match collector {
+            CollectorKind::Geth => {
+                return Ok(RollupCollector::Geth(
+                    geth::Builder {
+                        chain_name,
+                        url,
+                        executor_handle,
+                        shutdown_token,
+                        metrics,
+                        fee_asset,
+                    }
+                    .build(),
+                ));
+            }
+            CollectorKind::Txpool => json_rpc::Mode::Txpool,
+            CollectorKind::PendingBlock => json_rpc::Mode::PendingBlock,
+            CollectorKind::PendingHashes => json_rpc::Mode::PendingHashes,
+        };
+        json_rpc::Builder {
+            chain_name,
+            mode,
+            url,
+            executor_handle,
+            shutdown_token,
+            metrics,
+            fee_asset,
+        }
+        .build()
+        .map(RollupCollector::JsonRpc)
+    }
+}
@@ -4,7 +4,10 @@ use std::{
     time::Duration,
 };
 
-use astria_core::primitive::v1::asset;
+use astria_core::primitive::v1::{
+    asset,
+    RollupId,
+};
 use astria_eyre::eyre::{
     self,
     WrapErr as _,
@@ -41,23 +44,25 @@ use tracing::{
 use crate::{
     api,
     collectors,
-    collectors::geth,
+    collectors::RollupCollectorBuilder,
     composer,
     executor,
     executor::Executor,
     grpc,
     grpc::GrpcServer,
     metrics::Metrics,
+    receipts::Receipts,
+    rollup::Endpoint,
     Config,
 };
 
 const API_SERVER_SHUTDOWN_DURATION: Duration = Duration::from_secs(2);
 const GRPC_SERVER_SHUTDOWN_DURATION: Duration = Duration::from_secs(5);
 const EXECUTOR_SHUTDOWN_DURATION: Duration = Duration::from_secs(17);
-const GETH_COLLECTOR_SHUTDOWN_DURATION: Duration = Duration::from_secs(5);
+const ROLLUP_COLLECTOR_SHUTDOWN_DURATION: Duration = Duration::from_secs(5);
 
-/// `Composer` is a service responsible for spinning up `GethCollectors` which are responsible
-/// for fetching pending transactions submitted to the rollup Geth nodes and then passing them
+/// `Composer` is a service responsible for spinning up rollup collectors which are responsible
+/// for fetching pending transactions submitted to the rollup nodes and then passing them
 /// downstream for the executor to process. Thus, a composer can have multiple collectors running
 /// at the same time funneling data from multiple rollup nodes.
 pub struct Composer {
@@ -75,15 +80,15 @@ pub struct Composer {
     /// responsible for signing and submitting sequencer transactions.
     /// The sequencer transactions are received from various collectors.
     executor: Executor,
-    /// The collection of geth collectors and their rollup names.
-    geth_collectors: HashMap<String, collectors::Geth>,
-    /// The collection of the status of each geth collector.
-    geth_collector_statuses: HashMap<String, watch::Receiver<collectors::geth::Status>>,
-    /// The set of tasks tracking if the geth collectors are still running and to receive
-    /// the final result of each geth collector.
-    geth_collector_tasks: JoinMap<String, eyre::Result<()>>,
-    /// The map of chain ID to the URLs to which geth collectors should connect.
-    rollups: HashMap<String, String>,
+    /// The collection of rollup collectors and their rollup names.
+    rollup_collectors: HashMap<String, collectors::RollupCollector>,
+    /// The collection of the status of each rollup collector.
+    rollup_collector_statuses: HashMap<String, watch::Receiver<collectors::Status>>,
+    /// The set of tasks tracking if the rollup collectors are still running and to receive
+    /// the final result of each rollup collector.
+    rollup_collector_tasks: JoinMap<String, eyre::Result<()>>,
+    /// The map of chain ID to the endpoints to which rollup collectors should connect.
+    rollups: HashMap<String, Endpoint>,
     /// The gRPC server that listens for incoming requests from the collectors via the
     /// `GrpcCollector` service. It also exposes a health service.
     grpc_server: GrpcServer,
@@ -127,6 +132,8 @@ impl Composer {
         let (composer_status_sender, _) = watch::channel(Status::default());
         let shutdown_token = CancellationToken::new();
 
+        let rollup_weights = rollup_weights(cfg)?;
+        let receipts = Receipts::new(Duration::from_millis(cfg.receipt_retention_ms));
         let (executor, executor_handle) = executor::Builder {
             sequencer_abci_endpoint: cfg.sequencer_abci_endpoint.clone(),
             sequencer_grpc_endpoint: cfg.sequencer_grpc_endpoint.clone(),
@@ -136,7 +143,9 @@ impl Composer {
             block_time_ms: cfg.block_time_ms,
             max_bytes_per_bundle: cfg.max_bytes_per_bundle,
             bundle_queue_capacity: cfg.bundle_queue_capacity,
+            rollup_weights,
             shutdown_token: shutdown_token.clone(),
+            receipts: receipts.clone(),
             metrics,
         }
         .build()
@@ -145,6 +154,8 @@ impl Composer {
         let grpc_server = grpc::Builder {
             grpc_addr: cfg.grpc_addr,
             executor: executor_handle.clone(),
+            sequencer_abci_endpoint: cfg.sequencer_abci_endpoint.clone(),
+            receipts,
             shutdown_token: shutdown_token.clone(),
             metrics,
             fee_asset: cfg.fee_asset.clone(),
@@ -173,23 +184,26 @@ impl Composer {
         );
 
         let rollups = cfg.parse_rollups()?;
-        let geth_collectors = rollups
+        let rollup_collectors = rollups
             .iter()
-            .map(|(rollup_name, url)| {
-                let collector = geth::Builder {
+            .map(|(rollup_name, endpoint)| {
+                let collector = RollupCollectorBuilder {
                     chain_name: rollup_name.clone(),
-                    url: url.clone(),
+                    endpoint: endpoint.clone(),
                     executor_handle: executor_handle.clone(),
                     shutdown_token: shutdown_token.clone(),
                     metrics,
                     fee_asset: cfg.fee_asset.clone(),
                 }
-                .build();
-                (rollup_name.clone(), collector)
+                .build()
+                .wrap_err_with(|| {
+                    format!("failed to build collector for rollup `{rollup_name}`")
+                })?;
+                Ok((rollup_name.clone(), collector))
             })
-            .collect::<HashMap<_, _>>();
-        let geth_collector_statuses: HashMap<String, watch::Receiver<geth::Status>> =
-            geth_collectors
+            .collect::<eyre::Result<HashMap<_, _>>>()?;
+        let rollup_collector_statuses: HashMap<String, watch::Receiver<collectors::Status>> =
+            rollup_collectors
                 .iter()
                 .map(|(rollup_name, collector)| (rollup_name.clone(), collector.subscribe()))
                 .collect();
@@ -201,9 +215,9 @@ impl Composer {
             executor_handle,
             executor,
             rollups,
-            geth_collectors,
-            geth_collector_statuses,
-            geth_collector_tasks: JoinMap::new(),
+            rollup_collectors,
+            rollup_collector_statuses,
+            rollup_collector_tasks: JoinMap::new(),
             grpc_server,
             shutdown_token,
             metrics,
@@ -226,7 +240,7 @@ impl Composer {
     /// Runs the composer.
     ///
     /// # Errors
-    /// It errors out if the API Server, Executor or any of the Geth Collectors fail to start.
+    /// It errors out if the API Server, Executor or any of the rollup collectors fail to start.
     ///
     /// # Panics
     /// It panics if the Composer cannot set the SIGTERM listener.
@@ -242,10 +256,10 @@ impl Composer {
             composer_status_sender,
             executor,
             executor_handle,
-            mut geth_collector_tasks,
-            mut geth_collectors,
+            mut rollup_collector_tasks,
+            mut rollup_collectors,
             rollups,
-            mut geth_collector_statuses,
+            mut rollup_collector_statuses,
             grpc_server,
             shutdown_token,
             metrics,
@@ -258,22 +272,22 @@ impl Composer {
             tokio::spawn(async move { api.await.wrap_err("API server exited with error") });
 
         // run the collectors and executor
-        spawn_geth_collectors(&mut geth_collectors, &mut geth_collector_tasks);
+        spawn_rollup_collectors(&mut rollup_collectors, &mut rollup_collector_tasks);
 
         let executor_status = executor.subscribe().clone();
         let mut executor_task = tokio::spawn(executor.run_until_stopped());
 
         // wait for collectors and executor to come online
         let collectors_startup_fut =
-            wait_for_collectors(&geth_collector_statuses, composer_status_sender.clone());
+            wait_for_collectors(&rollup_collector_statuses, composer_status_sender.clone());
         let executor_startup_fut = wait_for_executor(executor_status, composer_status_sender);
 
         match join!(collectors_startup_fut, executor_startup_fut) {
             (Ok(()), Ok(())) => {}
-            (Err(e), Ok(())) => error!(%e, "geth collectors failed to become ready"),
+            (Err(e), Ok(())) => error!(%e, "rollup collectors failed to become ready"),
             (Ok(()), Err(e)) => error!(%e, "executor failed to become ready"),
             (Err(collector_err), Err(executor_err)) => {
-                error!(%collector_err, %executor_err, "geth collectors and executor failed to become ready");
+                error!(%collector_err, %executor_err, "rollup collectors and executor failed to become ready");
             }
         };
 
@@ -300,7 +314,7 @@ impl Composer {
                         api_task_handle: Some(api_task),
                         executor_task_handle: Some(executor_task),
                         grpc_server_task_handle: Some(grpc_server_handle),
-                        geth_collector_tasks,
+                        rollup_collector_tasks,
                     };
             },
             o = &mut api_task => {
@@ -311,7 +325,7 @@ impl Composer {
                         api_task_handle: None,
                         executor_task_handle: Some(executor_task),
                         grpc_server_task_handle: Some(grpc_server_handle),
-                        geth_collector_tasks,
+                        rollup_collector_tasks,
                     };
             },
             o = &mut executor_task => {
@@ -322,7 +336,7 @@ impl Composer {
                         api_task_handle: Some(api_task),
                         executor_task_handle: None,
                         grpc_server_task_handle: Some(grpc_server_handle),
-                        geth_collector_tasks,
+                        rollup_collector_tasks,
                     };
             },
             o = &mut grpc_server_handle => {
@@ -333,27 +347,35 @@ impl Composer {
                         api_task_handle: Some(api_task),
                         executor_task_handle: Some(executor_task),
                         grpc_server_task_handle: None,
-                        geth_collector_tasks,
+                        rollup_collector_tasks,
                     };
             },
-            Some((rollup, collector_exit)) = geth_collector_tasks.join_next() => {
+            Some((rollup, collector_exit)) = rollup_collector_tasks.join_next() => {
                 report_exit("collector", collector_exit, &exit_err);
-                if let Some(url) = rollups.get(&rollup) {
-                    let collector = geth::Builder {
+                if let Some(endpoint) = rollups.get(&rollup) {
+                    let collector = RollupCollectorBuilder {
                         chain_name: rollup.clone(),
-                        url: url.clone(),
+                        endpoint: endpoint.clone(),
                         executor_handle: executor_handle.clone(),
                         shutdown_token: shutdown_token.clone(),
                         metrics,
                         fee_asset: fee_asset.clone(),
                     }
                     .build();
-                    geth_collector_statuses.insert(rollup.clone(), collector.subscribe());
-                    geth_collector_tasks.spawn(rollup, collector.run_until_stopped());
+                    match collector {
+                        Ok(collector) => {
+                            rollup_collector_statuses.insert(rollup.clone(), collector.subscribe());
+                            rollup_collector_tasks.spawn(rollup, collector.run_until_stopped());
+                        }
+                        Err(error) => error!(
+                            %error,
+                            "failed to rebuild collector for rollup; not reconnecting it"
+                        ),
+                    }
                 } else {
                     error!(
-                        "rollup should have had an entry in the rollup->url map but doesn't; not reconnecting \
-                         it"
+                        "rollup should have had an entry in the rollup->endpoint map but doesn't; not \
+                         reconnecting it"
                     );
                 }
             });
@@ -373,7 +395,7 @@ struct ShutdownInfo {
     api_task_handle: Option<JoinHandle<eyre::Result<()>>>,
     executor_task_handle: Option<JoinHandle<eyre::Result<()>>>,
     grpc_server_task_handle: Option<JoinHandle<eyre::Result<()>>>,
-    geth_collector_tasks: JoinMap<String, eyre::Result<()>>,
+    rollup_collector_tasks: JoinMap<String, eyre::Result<()>>,
 }
 
 impl ShutdownInfo {
@@ -384,7 +406,7 @@ impl ShutdownInfo {
             api_task_handle,
             executor_task_handle,
             grpc_server_task_handle,
-            mut geth_collector_tasks,
+            mut rollup_collector_tasks,
         } = self;
 
         // if the composer is shutting down because of an unexpected shutdown from any one of the
@@ -423,7 +445,7 @@ impl ShutdownInfo {
         };
 
         let shutdown_loop = async {
-            while let Some((name, res)) = geth_collector_tasks.join_next().await {
+            while let Some((name, res)) = rollup_collector_tasks.join_next().await {
                 let message = "task shut down";
                 match flatten_result(res) {
                     Ok(()) => info!(name, message),
@@ -432,21 +454,21 @@ impl ShutdownInfo {
             }
         };
 
-        // we give 5s to shut down all the other geth collectors. geth collectors shouldn't take
-        // too long to shutdown since they just need to unsubscribe to their WSS
-        // streams.
-        if timeout(GETH_COLLECTOR_SHUTDOWN_DURATION, shutdown_loop)
+        // we give 5s to shut down all the other rollup collectors. rollup collectors shouldn't take
+        // too long to shutdown since they just need to unsubscribe from their WSS streams or stop
+        // polling.
+        if timeout(ROLLUP_COLLECTOR_SHUTDOWN_DURATION, shutdown_loop)
             .await
             .is_err()
         {
-            let tasks = geth_collector_tasks.keys().join(", ");
+            let tasks = rollup_collector_tasks.keys().join(", ");
             warn!(
                 tasks = format_args!("[{tasks}]"),
-                "aborting all geth collector tasks that have not yet shut down",
+                "aborting all rollup collector tasks that have not yet shut down",
             );
-            geth_collector_tasks.abort_all();
+            rollup_collector_tasks.abort_all();
         } else {
-            info!("all geth collector tasks shut down regularly");
+            info!("all rollup collector tasks shut down regularly");
         }
 
         // cancel the api server at the end
@@ -470,12 +492,28 @@ impl ShutdownInfo {
     }
 }
 
-fn spawn_geth_collectors(
-    geth_collectors: &mut HashMap<String, collectors::Geth>,
-    geth_collector_tasks: &mut JoinMap<String, eyre::Result<()>>,
+/// Returns the weights of the configured rollups, defaulting to 1 for those without a configured
+/// weight.
+fn rollup_weights(cfg: &Config) -> eyre::Result<HashMap<RollupId, u32>> {
+    let rollups = cfg.parse_rollups()?;
+    let mut weights = cfg
+        .parse_rollup_weights()
+        .wrap_err("failed to parse rollup weights")?;
+    for rollup_name in rollups.into_keys() {
+        weights.entry(rollup_name).or_insert(1);
+    }
+    Ok(weights
+        .into_iter()
+        .map(|(rollup_name, weight)| (RollupId::from_unhashed_bytes(rollup_name), weight))
+        .collect())
+}
+
+fn spawn_rollup_collectors(
+    rollup_collectors: &mut HashMap<String, collectors::RollupCollector>,
+    rollup_collector_tasks: &mut JoinMap<String, eyre::Result<()>>,
 ) {
-    for (chain_id, collector) in geth_collectors.drain() {
-        geth_collector_tasks.spawn(chain_id, collector.run_until_stopped());
+    for (chain_id, collector) in rollup_collectors.drain() {
+        rollup_collector_tasks.spawn(chain_id, collector.run_until_stopped());
     }
 }
 
@@ -499,7 +537,7 @@ async fn wait_for_executor(
 /// Waits for all collectors to come online.
 #[instrument(skip_all, err)]
 async fn wait_for_collectors(
-    collector_statuses: &HashMap<String, watch::Receiver<collectors::geth::Status>>,
+    collector_statuses: &HashMap<String, watch::Receiver<collectors::Status>>,
     composer_status_sender: watch::Sender<composer::Status>,
 ) -> eyre::Result<()> {
     use futures::{
@@ -514,10 +552,7 @@ async fn wait_for_collectors(
         .map(|(chain_id, status)| {
             let mut status = status.clone();
             async move {
-                match status
-                    .wait_for(collectors::geth::Status::is_connected)
-                    .await
-                {
+                match status.wait_for(collectors::Status::is_connected).await {
                     // `wait_for` returns a reference to status; throw it
                     // away because this future cannot return a reference to
                     // a stack local object.
@@ -9,8 +9,11 @@ use serde::{
 };
 
 use crate::rollup::{
+    Endpoint,
     ParseError,
+    ParseWeightError,
     Rollup,
+    RollupWeight,
 };
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -31,10 +34,16 @@ pub struct Config {
     /// The chain ID of the sequencer chain
     pub sequencer_chain_id: String,
 
-    /// A list of `<rollup_name>::<url>` pairs
+    /// A list of `<rollup_name>::[<collector>::]<url>` rollup definitions. The optional
+    /// `<collector>` is one of `geth` (the default), `txpool`, `pending-block`, or
+    /// `pending-hashes`.
     pub rollups: String,
 
-    /// Path to private key for the sequencer account used for signing transactions
+    /// A list of `<rollup_name>::<weight>` pairs. Rollups not listed have a weight of 1.
+    pub rollup_weights: String,
+
+    /// Comma-separated paths to private keys for the sequencer accounts used for signing
+    /// transactions. Each key submits bundles over its own nonce lane.
     pub private_key_file: String,
 
     /// The address prefix to use when constructing sequencer addresses using the signing key.
@@ -48,7 +57,8 @@ pub struct Config {
     /// public key, nonce. This is the sum of the sizes of all the sequence actions.
     pub max_bytes_per_bundle: usize,
 
-    /// Max amount of `SizedBundle`s to allow to accrue in the `BundleFactory`'s finished queue.
+    /// Max amount of `SizedBundle`s worth of bytes to allow to accrue in the `BundleFactory`'s
+    /// rollup queues.
     pub bundle_queue_capacity: usize,
 
     /// Forces writing trace data to stdout no matter if connected to a tty or not.
@@ -66,29 +76,61 @@ pub struct Config {
     /// The address at which the gRPC server is listening
     pub grpc_addr: SocketAddr,
 
+    /// Milliseconds for which receipts of transactions submitted via the gRPC collector are
+    /// retained. Identical transactions submitted within this period are deduplicated.
+    pub receipt_retention_ms: u64,
+
     /// The IBC asset to pay for transactions submiited to the sequencer.
     pub fee_asset: astria_core::primitive::v1::asset::Denom,
 }
 
 impl Config {
-    /// Returns a map of rollup names to rollup URLs.
+    /// Returns a map of rollup names to rollup node endpoints.
     ///
     /// # Errors
     ///
     /// Returns an error if parsing fails.
-    pub fn parse_rollups(&self) -> Result<HashMap<String, String>, ParseError> {
+    pub fn parse_rollups(&self) -> Result<HashMap<String, Endpoint>, ParseError> {
         self.rollups
             .split(',')
             .filter(|s| !s.is_empty())
             .map(|s| Rollup::parse(s).map(Rollup::into_parts))
             .collect::<Result<HashMap<_, _>, _>>()
     }
+
+    /// Returns the paths to the private key files, one per nonce lane.
+    pub(crate) fn private_key_files(&self) -> Vec<&str> {
+        split_private_key_files(&self.private_key_file)
+    }
+
+    /// Returns a map of rollup names to the weights used to schedule their transactions into
+    /// bundles.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if parsing fails.
+    pub fn parse_rollup_weights(&self) -> Result<HashMap<String, u32>, ParseWeightError> {
+        self.rollup_weights
+            .split(',')
+            .filter(|s| !s.is_empty())
+            .map(|s| RollupWeight::parse(s).map(RollupWeight::into_parts))
+            .collect::<Result<HashMap<_, _>, _>>()
+    }
 }
 
 impl config::Config for Config {
     const PREFIX: &'static str = "ASTRIA_COMPOSER_";
 }
 
+/// Splits a comma-separated list of private key file paths, skipping empty entries.
+pub(crate) fn split_private_key_files(private_key_file: &str) -> Vec<&str> {
+    private_key_file
+        .split(',')
+        .map(str::trim)
+        .filter(|path| !path.is_empty())
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::Config;
@@ -1,4 +1,5 @@
 use std::{
+    collections::HashMap,
     fs,
     path::Path,
     time::Duration,
@@ -7,11 +8,15 @@ use std::{
 use astria_core::{
     crypto::SigningKey,
     generated::astria::sequencerblock::v1::sequencer_service_client::SequencerServiceClient,
-    primitive::v1::Address,
+    primitive::v1::{
+        Address,
+        RollupId,
+    },
     protocol::transaction::v1::action::RollupDataSubmission,
 };
 use astria_eyre::eyre::{
     self,
+    ensure,
     eyre,
     WrapErr as _,
 };
@@ -20,8 +25,12 @@ use tokio_util::sync::CancellationToken;
 
 use crate::{
     executor,
-    executor::Status,
+    executor::{
+        Signer,
+        Status,
+    },
     metrics::Metrics,
+    receipts::Receipts,
 };
 
 pub(crate) struct Builder {
@@ -33,7 +42,9 @@ pub(crate) struct Builder {
     pub(crate) block_time_ms: u64,
     pub(crate) max_bytes_per_bundle: usize,
     pub(crate) bundle_queue_capacity: usize,
+    pub(crate) rollup_weights: HashMap<RollupId, u32>,
     pub(crate) shutdown_token: CancellationToken,
+    pub(crate) receipts: Receipts,
     pub(crate) metrics: &'static Metrics,
 }
 
@@ -48,7 +59,9 @@ impl Builder {
             block_time_ms,
             max_bytes_per_bundle,
             bundle_queue_capacity,
+            rollup_weights,
             shutdown_token,
+            receipts,
             metrics,
         } = self;
         let abci_client = sequencer_client::HttpClient::new(sequencer_abci_endpoint.as_str())
@@ -61,15 +74,14 @@ impl Builder {
 
         let (status, _) = watch::channel(Status::new());
 
-        let sequencer_key = read_signing_key_from_file(&private_key_file).wrap_err_with(|| {
-            format!("failed reading signing key from file at path `{private_key_file}`")
-        })?;
-
-        let sequencer_address = Address::builder()
-            .prefix(sequencer_address_prefix)
-            .array(*sequencer_key.verification_key().address_bytes())
-            .try_build()
-            .wrap_err("failed constructing a sequencer address from private key")?;
+        let signers = crate::config::split_private_key_files(&private_key_file)
+            .into_iter()
+            .map(|path| read_signer(path, &sequencer_address_prefix))
+            .collect::<eyre::Result<Vec<_>>>()?;
+        ensure!(
+            !signers.is_empty(),
+            "at least one private key file must be provided"
+        );
 
         let (serialized_rollup_transaction_tx, serialized_rollup_transaction_rx) =
             tokio::sync::mpsc::channel::<RollupDataSubmission>(256);
@@ -81,12 +93,13 @@ impl Builder {
                 abci_client,
                 grpc_client,
                 sequencer_chain_id,
-                sequencer_key,
-                address: sequencer_address,
+                signers,
                 block_time: Duration::from_millis(block_time_ms),
                 max_bytes_per_bundle,
                 bundle_queue_capacity,
+                rollup_weights,
                 shutdown_token,
+                receipts,
                 metrics,
             },
             executor::Handle::new(serialized_rollup_transaction_tx),
@@ -94,6 +107,21 @@ impl Builder {
     }
 }
 
+fn read_signer(private_key_file: &str, sequencer_address_prefix: &str) -> eyre::Result<Signer> {
+    let key = read_signing_key_from_file(private_key_file).wrap_err_with(|| {
+        format!("failed reading signing key from file at path `{private_key_file}`")
+    })?;
+    let address = Address::builder()
+        .prefix(sequencer_address_prefix)
+        .array(*key.verification_key().address_bytes())
+        .try_build()
+        .wrap_err("failed constructing a sequencer address from private key")?;
+    Ok(Signer {
+        key,
+        address,
+    })
+}
+
 fn read_signing_key_from_file<P: AsRef<Path>>(path: P) -> eyre::Result<SigningKey> {
     let private_key_hex = fs::read_to_string(path)?;
     let private_key_bytes: [u8; 32] = hex::decode(private_key_hex.trim())?
@@ -1,11 +1,14 @@
 //! This module is responsible for bundling sequence actions into bundles that can be
 //! submitted to the sequencer.
-use std::{
-    collections::{
-        HashMap,
-        VecDeque,
-    },
-    mem,
+//!
+//! Sequence actions are queued per rollup and only packed into a bundle once the bundle is
+//! requested. Rollups are visited in a weighted deficit round-robin: on each visit a rollup is
+//! credited a quantum of `1 / QUANTA_PER_BUNDLE` of the max bundle size times its weight, and may
+//! add actions to the bundle until its credit is exhausted. A rollup submitting many transactions
+//! therefore only delays the transactions of the others by its weighted share of each bundle.
+use std::collections::{
+    HashMap,
+    VecDeque,
 };
 
 use astria_core::{
@@ -23,9 +26,14 @@ use serde::ser::{
 };
 use tracing::trace;
 
+use crate::receipts::ReceiptId;
+
 #[cfg(test)]
 mod tests;
 
+/// The number of scheduling rounds it takes a rollup of weight 1 to be credited a full bundle.
+const QUANTA_PER_BUNDLE: usize = 64;
+
 #[derive(Debug, thiserror::Error)]
 enum SizedBundleError {
     #[error("bundle does not have enough space left for the given sequence action")]
@@ -49,8 +57,8 @@ impl Serialize for SizedBundleReport<'_> {
 }
 
 /// A bundle sequence actions to be submitted to the sequencer. Maintains the total size of the
-/// bytes pushed to it and enforces a max size in bytes passed in the constructor. If an incoming
-/// `seq_action` won't fit in the buffer it is flushed and a new bundle is started.
+/// bytes pushed to it and enforces a max size in bytes passed in the constructor. An incoming
+/// `seq_action` which won't fit in the buffer is rejected.
 #[derive(Clone)]
 pub(super) struct SizedBundle {
     /// The buffer of actions
@@ -62,6 +70,8 @@ pub(super) struct SizedBundle {
     max_size: usize,
     /// Mapping of rollup id to the number of sequence actions for that rollup id in the bundle.
     rollup_counts: HashMap<RollupId, usize>,
+    /// Mapping of rollup id to the bytes of sequence actions for that rollup id in the bundle.
+    rollup_bytes: HashMap<RollupId, usize>,
 }
 
 impl SizedBundle {
@@ -72,6 +82,7 @@ impl SizedBundle {
             curr_size: 0,
             max_size,
             rollup_counts: HashMap::new(),
+            rollup_bytes: HashMap::new(),
         }
     }
 
@@ -114,17 +125,16 @@ impl SizedBundle {
             .entry(seq_action.rollup_id)
             .and_modify(|count| *count = count.saturating_add(1))
             .or_insert(1);
+        self.rollup_bytes
+            .entry(seq_action.rollup_id)
+            .and_modify(|bytes| *bytes = bytes.saturating_add(seq_action_size))
+            .or_insert(seq_action_size);
         self.buffer.push(Action::RollupDataSubmission(seq_action));
         self.curr_size = new_size;
 
         Ok(())
     }
 
-    /// Replace self with a new empty bundle, returning the old bundle.
-    fn flush(&mut self) -> SizedBundle {
-        mem::replace(self, Self::new(self.max_size))
-    }
-
     /// Returns the current size of the bundle.
     pub(super) fn get_size(&self) -> usize {
         self.curr_size
@@ -139,6 +149,24 @@ impl SizedBundle {
     pub(super) fn is_empty(&self) -> bool {
         self.buffer.is_empty()
     }
+
+    /// Returns the bytes of sequence actions in the bundle per rollup.
+    pub(super) fn rollup_bytes(&self) -> impl Iterator<Item = (&RollupId, usize)> {
+        self.rollup_bytes.iter().map(|(id, bytes)| (id, *bytes))
+    }
+
+    /// Returns the receipt IDs of the sequence actions in the bundle.
+    pub(super) fn receipt_ids(&self) -> impl Iterator<Item = ReceiptId> + '_ {
+        self.buffer.iter().filter_map(|action| match action {
+            Action::RollupDataSubmission(seq_action) => Some(ReceiptId::for_submission(seq_action)),
+            _ => None,
+        })
+    }
+
+    /// Returns true if a sequence action of `size` bytes fits into the remaining space.
+    fn has_space_for(&self, size: usize) -> bool {
+        self.curr_size.saturating_add(size) <= self.max_size
+    }
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -146,53 +174,103 @@ pub(super) enum BundleFactoryError {
     #[error("sequence action is larger than the max bundle size. seq_action size: {size}")]
     SequenceActionTooLarge { size: usize, max_size: usize },
     #[error(transparent)]
-    FinishedQueueFull(Box<FinishedQueueFull>),
+    QueueFull(Box<QueueFull>),
+    #[error(transparent)]
+    RollupQueueFull(Box<RollupQueueFull>),
 }
 
 #[derive(Debug, thiserror::Error)]
 #[error(
-    "finished bundle queue is at capacity and the sequence action does not fit in the current \
-     bundle. finished queue capacity: {finished_queue_capacity}, curr bundle size: \
-     {curr_bundle_size}, sequence action size: {sequence_action_size}"
+    "queue is at capacity and does not have space left for the sequence action. queue capacity: \
+     {capacity} bytes, queued: {queued_bytes} bytes, sequence action size: {sequence_action_size}"
 )]
-pub(super) struct FinishedQueueFull {
-    curr_bundle_size: usize,
-    finished_queue_capacity: usize,
+pub(super) struct QueueFull {
+    queued_bytes: usize,
+    capacity: usize,
     sequence_action_size: usize,
     seq_action: RollupDataSubmission,
 }
 
-impl From<FinishedQueueFull> for BundleFactoryError {
-    fn from(value: FinishedQueueFull) -> Self {
-        Self::FinishedQueueFull(Box::new(value))
+impl From<QueueFull> for BundleFactoryError {
+    fn from(value: QueueFull) -> Self {
+        Self::QueueFull(Box::new(value))
     }
 }
 
-/// Manages the bundling of sequence actions into `SizedBundle`s. A `Vec<Action>` is flushed and
-/// added to the `finished` queue when an incoming `SequenceAction` won't fit in the current bundle.
-/// The `finished` queue operates in FIFO order, where `Vec<Action>`s are added to the back and
-/// taken off from the front.
+#[derive(Debug, thiserror::Error)]
+#[error(
+    "queue of rollup `{rollup_id}` is at its quota and does not have space left for the sequence \
+     action. rollup quota: {quota} bytes, queued: {queued_bytes} bytes, sequence action size: \
+     {sequence_action_size}"
+)]
+pub(super) struct RollupQueueFull {
+    rollup_id: RollupId,
+    queued_bytes: usize,
+    quota: usize,
+    sequence_action_size: usize,
+    seq_action: RollupDataSubmission,
+}
+
+impl From<RollupQueueFull> for BundleFactoryError {
+    fn from(value: RollupQueueFull) -> Self {
+        Self::RollupQueueFull(Box::new(value))
+    }
+}
+
+/// The sequence actions of a single rollup waiting to be bundled, in FIFO order.
+struct RollupQueue {
+    /// The queued sequence actions and their encoded sizes.
+    actions: VecDeque<(RollupDataSubmission, usize)>,
+    /// The sum of the sizes of the queued sequence actions.
+    bytes: usize,
+    /// The bytes the rollup may still add to bundles in the current scheduling round.
+    deficit: usize,
+}
+
+/// Manages the bundling of sequence actions into `SizedBundle`s.
+///
+/// Sequence actions are queued per rollup in FIFO order. A bundle is only built when requested
+/// through [`BundleFactory::next_finished`] or [`BundleFactory::pop_now`], at which point the
+/// rollups are scheduled into it in a weighted deficit round-robin (see the module docs). A
+/// bundle is finished once more bytes are queued than fit into a single bundle.
 pub(super) struct BundleFactory {
-    /// The current bundle being built.
-    curr_bundle: SizedBundle,
-    /// The queue of bundles that have been built but not yet sent to the sequencer.
-    finished: VecDeque<SizedBundle>,
-    /// Max amount of `SizedBundle`s that can be in the `finished` queue.
-    finished_queue_capacity: usize,
+    /// The max bundle size in bytes.
+    max_bytes_per_bundle: usize,
+    /// Max amount of `SizedBundle`s worth of bytes that can be queued.
+    bundle_queue_capacity: usize,
+    /// The weights of the rollups. Rollups not contained have a weight of 1.
+    rollup_weights: HashMap<RollupId, u32>,
+    /// The queues of the rollups with queued sequence actions.
+    queues: HashMap<RollupId, RollupQueue>,
+    /// The rollups with queued sequence actions, in the order they are scheduled.
+    schedule: VecDeque<RollupId>,
+    /// The sum of the sizes of all queued sequence actions.
+    queued_bytes: usize,
 }
 
 impl BundleFactory {
-    pub(super) fn new(max_bytes_per_bundle: usize, finished_queue_capacity: usize) -> Self {
+    pub(super) fn new(max_bytes_per_bundle: usize, bundle_queue_capacity: usize) -> Self {
         Self {
-            curr_bundle: SizedBundle::new(max_bytes_per_bundle),
-            finished: VecDeque::new(),
-            finished_queue_capacity,
+            max_bytes_per_bundle,
+            bundle_queue_capacity,
+            rollup_weights: HashMap::new(),
+            queues: HashMap::new(),
+            schedule: VecDeque::new(),
+            queued_bytes: 0,
         }
     }
 
-    /// Buffer `seq_action` into the current bundle. If the bundle won't fit `seq_action`, flush
-    /// `curr_bundle` into the `finished` queue and start a new bundle, unless the `finished` queue
-    /// is at capacity.
+    /// Sets the weights of the rollups, which determine their share of the bundles and the
+    /// queue capacity.
+    pub(super) fn with_rollup_weights(self, rollup_weights: HashMap<RollupId, u32>) -> Self {
+        Self {
+            rollup_weights,
+            ..self
+        }
+    }
+
+    /// Queue `seq_action` for bundling, unless the queue is at capacity or the queue of its rollup
+    /// is at its quota.
     pub(super) fn try_push(
         &mut self,
         seq_action: RollupDataSubmission,
@@ -200,76 +278,189 @@ impl BundleFactory {
         let seq_action = with_ibc_prefixed(seq_action);
         let seq_action_size = encoded_len(&seq_action);
 
-        match self.curr_bundle.try_push(seq_action) {
-            Err(SizedBundleError::SequenceActionTooLarge(_seq_action)) => {
-                // reject the sequence action if it is larger than the max bundle size
-                Err(BundleFactoryError::SequenceActionTooLarge {
-                    size: seq_action_size,
-                    max_size: self.curr_bundle.max_size,
-                })
-            }
-            Err(SizedBundleError::NotEnoughSpace(seq_action)) => {
-                if self.finished.len() >= self.finished_queue_capacity {
-                    Err(FinishedQueueFull {
-                        curr_bundle_size: self.curr_bundle.curr_size,
-                        finished_queue_capacity: self.finished_queue_capacity,
-                        sequence_action_size: seq_action_size,
-                        seq_action,
-                    }
-                    .into())
-                } else {
-                    // if the bundle is full, flush it and start a new one
-                    self.finished.push_back(self.curr_bundle.flush());
-                    self.curr_bundle.try_push(seq_action).expect(
-                        "seq_action should not be larger than max bundle size, this is a bug",
-                    );
-                    trace!(
-                        new_bundle_size = self.curr_bundle.curr_size,
-                        seq_action_size = seq_action_size,
-                        finished_queue.current_size = self.finished.len(),
-                        finished_queue.capacity = self.finished_queue_capacity,
-                        "created new bundle and bundled new sequence action"
-                    );
-                    Ok(())
-                }
+        if seq_action_size > self.max_bytes_per_bundle {
+            // reject the sequence action if it is larger than the max bundle size
+            return Err(BundleFactoryError::SequenceActionTooLarge {
+                size: seq_action_size,
+                max_size: self.max_bytes_per_bundle,
+            });
+        }
+
+        let capacity = self.capacity();
+        if self.queued_bytes.saturating_add(seq_action_size) > capacity {
+            return Err(QueueFull {
+                queued_bytes: self.queued_bytes,
+                capacity,
+                sequence_action_size: seq_action_size,
+                seq_action,
             }
-            Ok(()) => {
-                trace!(
-                    new_bundle_size = self.curr_bundle.curr_size,
-                    seq_action_size = seq_action_size,
-                    "bundled new sequence action"
-                );
-                Ok(())
+            .into());
+        }
+
+        let rollup_id = seq_action.rollup_id;
+        let quota = self.rollup_quota(&rollup_id);
+        let rollup_queued_bytes = self.queue_bytes(&rollup_id);
+        if rollup_queued_bytes.saturating_add(seq_action_size) > quota {
+            return Err(RollupQueueFull {
+                rollup_id,
+                queued_bytes: rollup_queued_bytes,
+                quota,
+                sequence_action_size: seq_action_size,
+                seq_action,
             }
+            .into());
         }
+
+        let queue = self.queues.entry(rollup_id).or_insert_with(|| {
+            self.schedule.push_back(rollup_id);
+            RollupQueue {
+                actions: VecDeque::new(),
+                bytes: 0,
+                deficit: 0,
+            }
+        });
+        queue.actions.push_back((seq_action, seq_action_size));
+        queue.bytes = queue.bytes.saturating_add(seq_action_size);
+        self.queued_bytes = self.queued_bytes.saturating_add(seq_action_size);
+        trace!(
+            %rollup_id,
+            seq_action_size,
+            rollup_queue.depth = queue.actions.len(),
+            queued_bytes = self.queued_bytes,
+            "queued new sequence action"
+        );
+        Ok(())
     }
 
     /// Returns a handle to the next finished bundle if it exists.
     ///
-    /// The bundle is only removed from the factory on calling [`NextFinishedBundle::pop`].
-    /// This method primarily exists to work around async cancellation.
+    /// The bundle is only built and removed from the factory on calling
+    /// [`NextFinishedBundle::pop`]. This method primarily exists to work around async
+    /// cancellation.
     pub(super) fn next_finished(&mut self) -> Option<NextFinishedBundle> {
-        if self.finished.is_empty() {
-            None
-        } else {
+        if self.queued_bytes > self.max_bytes_per_bundle {
             Some(NextFinishedBundle {
                 bundle_factory: self,
             })
+        } else {
+            None
         }
     }
 
-    /// Immediately the currently aggregating bundle.
+    /// Immediately builds a bundle from the queued sequence actions.
     ///
-    /// Returns an empty bundle if there are no bundled transactions.
+    /// Returns an empty bundle if there are no queued sequence actions.
     pub(super) fn pop_now(&mut self) -> SizedBundle {
-        self.finished
-            .pop_front()
-            .or_else(|| Some(self.curr_bundle.flush()))
-            .unwrap_or(SizedBundle::new(self.curr_bundle.max_size))
+        self.build_bundle()
     }
 
+    /// Returns true if the queue might not have space left for another sequence action.
     pub(super) fn is_full(&self) -> bool {
-        self.finished.len() >= self.finished_queue_capacity
+        self.queued_bytes.saturating_add(self.max_bytes_per_bundle) > self.capacity()
+    }
+
+    /// Returns the number of sequence actions queued for `rollup_id`.
+    pub(super) fn queue_depth(&self, rollup_id: &RollupId) -> usize {
+        self.queues
+            .get(rollup_id)
+            .map_or(0, |queue| queue.actions.len())
+    }
+
+    fn queue_bytes(&self, rollup_id: &RollupId) -> usize {
+        self.queues.get(rollup_id).map_or(0, |queue| queue.bytes)
+    }
+
+    fn rollup_weight(&self, rollup_id: &RollupId) -> u32 {
+        self.rollup_weights.get(rollup_id).copied().unwrap_or(1)
+    }
+
+    /// The max amount of bytes that can be queued.
+    fn capacity(&self) -> usize {
+        self.bundle_queue_capacity
+            .saturating_mul(self.max_bytes_per_bundle)
+    }
+
+    /// The max amount of bytes that can be queued for `rollup_id`: its weighted share of the
+    /// capacity among the rollups with configured weights, but at least a full bundle.
+    fn rollup_quota(&self, rollup_id: &RollupId) -> usize {
+        let weight = u128::from(self.rollup_weight(rollup_id));
+        let total_weight = self
+            .rollup_weights
+            .values()
+            .map(|weight| u128::from(*weight))
+            .sum::<u128>()
+            .max(weight);
+        let share = u128::try_from(self.capacity())
+            .expect("usize should always fit into a u128")
+            .saturating_mul(weight)
+            .checked_div(total_weight)
+            .expect("total weight is at least the rollup's weight, which is at least 1");
+        usize::try_from(share)
+            .expect("share is at most the capacity, which is a usize")
+            .max(self.max_bytes_per_bundle)
+    }
+
+    /// Builds a bundle by scheduling the queued sequence actions in a weighted deficit
+    /// round-robin.
+    ///
+    /// Rollups whose next sequence action does not fit into the remaining space of the bundle
+    /// are skipped without being credited. The bundle is complete once all rollups in the
+    /// schedule were skipped in a row.
+    fn build_bundle(&mut self) -> SizedBundle {
+        let mut bundle = SizedBundle::new(self.max_bytes_per_bundle);
+        let mut skipped_in_a_row = 0;
+        while skipped_in_a_row < self.schedule.len() {
+            let rollup_id = self
+                .schedule
+                .pop_front()
+                .expect("schedule is not empty. this is a bug.");
+            let weight = self.rollup_weight(&rollup_id);
+            let queue = self
+                .queues
+                .get_mut(&rollup_id)
+                .expect("every scheduled rollup has a queue. this is a bug.");
+
+            let next_size = queue.actions.front().map_or(0, |(_, size)| *size);
+            if !bundle.has_space_for(next_size) {
+                self.schedule.push_back(rollup_id);
+                skipped_in_a_row = skipped_in_a_row.saturating_add(1);
+                continue;
+            }
+            skipped_in_a_row = 0;
+
+            let quantum = (self.max_bytes_per_bundle / QUANTA_PER_BUNDLE)
+                .max(1)
+                .saturating_mul(usize::try_from(weight).unwrap_or(usize::MAX));
+            queue.deficit = queue.deficit.saturating_add(quantum);
+            while let Some((_, size)) = queue.actions.front() {
+                let size = *size;
+                if size > queue.deficit || !bundle.has_space_for(size) {
+                    break;
+                }
+                let (seq_action, _) = queue
+                    .actions
+                    .pop_front()
+                    .expect("front action exists. this is a bug.");
+                bundle
+                    .try_push(seq_action)
+                    .expect("bundle has space for the sequence action. this is a bug.");
+                queue.deficit = queue.deficit.saturating_sub(size);
+                queue.bytes = queue.bytes.saturating_sub(size);
+                self.queued_bytes = self.queued_bytes.saturating_sub(size);
+            }
+
+            if queue.actions.is_empty() {
+                self.queues.remove(&rollup_id);
+            } else {
+                self.schedule.push_back(rollup_id);
+            }
+        }
+        trace!(
+            bundle_size = bundle.curr_size,
+            queued_bytes = self.queued_bytes,
+            "built new bundle"
+        );
+        bundle
     }
 }
 
@@ -279,10 +470,7 @@ pub(super) struct NextFinishedBundle<'a> {
 
 impl NextFinishedBundle<'_> {
     pub(super) fn pop(self) -> SizedBundle {
-        self.bundle_factory
-            .finished
-            .pop_front()
-            .expect("next bundle exists. this is a bug.")
+        self.bundle_factory.build_bundle()
     }
 }
 
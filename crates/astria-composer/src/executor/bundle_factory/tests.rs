@@ -56,33 +56,12 @@ mod sized_bundle {
             && actual_seq_action.data == empty_sequence_action().data
         ));
     }
-
-    #[test]
-    fn flush_sanity_check() {
-        // create a bundle with 100 bytes of max space
-        let mut bundle = SizedBundle::new(200);
-
-        // push a sequence action successfully
-        let seq_action = sequence_action_of_max_size(200);
-        bundle.try_push(seq_action.clone()).unwrap();
-
-        // flush the bundle
-        let flushed_bundle = bundle.flush();
-
-        // assert that the initial bundle is empty
-        assert!(bundle.buffer.is_empty());
-
-        // assert that the flushed bundle has just the sequence action pushed earlier
-        let actions = flushed_bundle.buffer;
-        assert_eq!(actions.len(), 1);
-        let actual_seq_action = actions[0].as_rollup_data_submission().unwrap();
-        assert_eq!(actual_seq_action.rollup_id, seq_action.rollup_id);
-        assert_eq!(actual_seq_action.data, seq_action.data);
-    }
 }
 
 #[cfg(test)]
 mod bundle_factory {
+    use std::collections::HashMap;
+
     use super::*;
     use crate::{
         executor::bundle_factory::{
@@ -95,16 +74,31 @@ mod bundle_factory {
         },
     };
 
+    fn sequence_action_for_rollup(rollup: u8, max: usize) -> RollupDataSubmission {
+        RollupDataSubmission {
+            rollup_id: RollupId::new([rollup; ROLLUP_ID_LEN]),
+            ..sequence_action_of_max_size(max)
+        }
+    }
+
+    fn count_for_rollup(bundle: &super::super::SizedBundle, rollup: u8) -> usize {
+        bundle
+            .rollup_counts
+            .get(&RollupId::new([rollup; ROLLUP_ID_LEN]))
+            .copied()
+            .unwrap_or(0)
+    }
+
     #[test]
-    fn try_push_works_no_flush() {
+    fn try_push_works_no_finished_bundle() {
         // create a bundle factory with max bundle size as 100 bytes
         let mut bundle_factory = BundleFactory::new(200, 10);
 
         let seq_action = sequence_action_of_max_size(200);
         bundle_factory.try_push(seq_action).unwrap();
 
-        // assert that the bundle factory has no bundles in the finished queue
-        assert!(bundle_factory.finished.is_empty());
+        // assert that the bundle factory has no finished bundle
+        assert!(bundle_factory.next_finished().is_none());
     }
 
     #[test]
@@ -122,57 +116,48 @@ mod bundle_factory {
     }
 
     #[test]
-    fn try_push_flushes_and_pop_finished_works() {
+    fn try_push_finishes_bundle_and_pop_finished_works() {
         let mut bundle_factory = BundleFactory::new(200, 10);
 
         let seq_action0 = sequence_action_of_max_size(200);
         bundle_factory.try_push(seq_action0.clone()).unwrap();
 
-        // push another sequence action that is <100 bytes total to force the current bundle to
-        // flush
+        // push another sequence action that does not fit into the same bundle to finish a bundle
         let seq_action1 = sequence_action_of_max_size(150);
         bundle_factory.try_push(seq_action1).unwrap();
 
-        // assert that the bundle factory has one bundle in the finished queue
-        assert_eq!(bundle_factory.finished.len(), 1);
-        // assert `pop_finished()` will return `seq_action0`
+        // assert `next_finished().pop()` will return `seq_action0`
         let next_actions = bundle_factory.next_finished();
         let actions = next_actions.unwrap().pop().buffer;
+        assert_eq!(actions.len(), 1);
         let actual_seq_action = actions[0].as_rollup_data_submission().unwrap();
         assert_eq!(actual_seq_action.rollup_id, seq_action0.rollup_id);
         assert_eq!(actual_seq_action.data, seq_action0.data);
+
+        // assert that the remaining sequence action does not make up a finished bundle
+        assert!(bundle_factory.next_finished().is_none());
     }
 
     #[test]
     fn try_push_full_sanity_check() {
-        let mut bundle_factory = BundleFactory::new(200, 1);
+        let mut bundle_factory = BundleFactory::new(200, 2);
 
-        // push a sequence action that is 100 bytes total
+        // push two sequence actions filling the capacity of two bundles
         let seq_action = sequence_action_of_max_size(200);
         bundle_factory.try_push(seq_action.clone()).unwrap();
-
-        // push another sequence action that is <100 bytes total to force the current bundle to
-        // flush
         bundle_factory.try_push(seq_action.clone()).unwrap();
 
-        // try to push a third bundle that wouldn't fit in `curr_bundle`, forcing the factory to
-        // flush it into `finished` this shouldn't work since the `finished` queue's
-        // capacity is 1.
+        // try to push a third sequence action, which shouldn't work since the queue's capacity is
+        // two bundles
         let err = bundle_factory
             .try_push(seq_action.clone())
             .expect_err("the action should be rejected");
 
-        // assert that the bundle factory has one bundle in the finished queue, that the factory is
-        // full and that err was returned
-        #[expect(
-            clippy::match_wildcard_for_single_variants,
-            reason = "this is intended to match all possible variants"
-        )]
+        // assert that err was returned and that the factory is full
         match err {
-            BundleFactoryError::FinishedQueueFull(_) => {}
-            other => panic!("expected a FinishedQueueFull variant, but got {other:?}"),
+            BundleFactoryError::QueueFull(_) => {}
+            other => panic!("expected a QueueFull variant, but got {other:?}"),
         }
-        assert_eq!(bundle_factory.finished.len(), 1);
         assert!(bundle_factory.is_full());
     }
 
@@ -183,50 +168,34 @@ mod bundle_factory {
         let seq_action = sequence_action_of_max_size(200);
         bundle_factory.try_push(seq_action.clone()).unwrap();
 
-        // assert that the finished queue is empty
-        assert_eq!(bundle_factory.finished.len(), 0);
-        // assert `pop_finished()` returns an empty vec
+        // assert `next_finished()` returns nothing
         let next_bundle = bundle_factory.next_finished();
         assert!(next_bundle.is_none());
     }
 
     #[test]
     fn pop_finished_no_longer_full() {
-        let mut bundle_factory = BundleFactory::new(200, 1);
+        let mut bundle_factory = BundleFactory::new(200, 2);
 
         let seq_action = sequence_action_of_max_size(200);
         bundle_factory.try_push(seq_action.clone()).unwrap();
-
-        // push another sequence action to force the current bundle to flush
         bundle_factory.try_push(seq_action.clone()).unwrap();
 
-        // try to push a third bundle that wouldn't fit in `curr_bundle`, forcing the factory to
-        // flush it into `finished` this shouldn't work since the `finished` queue's
-        // capacity is 1.
-        let seq_action1 = RollupDataSubmission {
-            rollup_id: RollupId::new([1; ROLLUP_ID_LEN]),
-            ..sequence_action_of_max_size(200)
-        };
+        // try to push a third sequence action of another rollup, which shouldn't work since the
+        // queue's capacity is two bundles
         let err = bundle_factory
-            .try_push(seq_action1.clone())
+            .try_push(sequence_action_for_rollup(1, 200))
             .expect_err("the action should have been rejected");
 
-        // assert that the bundle factory has one bundle in the finished queue, that the factory is
-        // full and that err was returned
-        #[expect(
-            clippy::match_wildcard_for_single_variants,
-            reason = "this is intended to match all possible variants"
-        )]
+        // assert that err was returned and that the factory is full
         match err {
-            BundleFactoryError::FinishedQueueFull(_) => {}
-            other => panic!("expected a FinishedQueueFull variant, but got {other:?}"),
+            BundleFactoryError::QueueFull(_) => {}
+            other => panic!("expected a QueueFull variant, but got {other:?}"),
         }
-        assert_eq!(bundle_factory.finished.len(), 1);
         assert!(bundle_factory.is_full());
 
         // assert `next_finished().pop()` will change the status back to not full
         let _next_bundle = bundle_factory.next_finished().unwrap().pop();
-        assert_eq!(bundle_factory.finished.len(), 0);
         assert!(!bundle_factory.is_full());
     }
 
@@ -237,8 +206,6 @@ mod bundle_factory {
         let seq_action = sequence_action_of_max_size(200);
         bundle_factory.try_push(seq_action.clone()).unwrap();
 
-        // assert that the finished queue is empty (curr wasn't flushed)
-        assert_eq!(bundle_factory.finished.len(), 0);
         // assert `pop_now()` returns `seq_action`
         let actions = bundle_factory.pop_now().buffer;
         let actual_seq_action = actions[0].as_rollup_data_submission().unwrap();
@@ -246,73 +213,37 @@ mod bundle_factory {
         assert_eq!(actual_seq_action.data, seq_action.data);
     }
 
-    #[test]
-    fn pop_now_finished_not_empty() {
-        // create a bundle factory with max bundle size as 100 bytes
-        let mut bundle_factory = BundleFactory::new(200, 10);
-
-        let seq_action0 = sequence_action_of_max_size(200);
-        bundle_factory.try_push(seq_action0.clone()).unwrap();
-
-        // push another sequence action that is <100 bytes total to force the current bundle to
-        // flush
-        let seq_action1 = RollupDataSubmission {
-            rollup_id: RollupId::new([1; ROLLUP_ID_LEN]),
-            ..sequence_action_of_max_size(200)
-        };
-        bundle_factory.try_push(seq_action1).unwrap();
-
-        // assert that the bundle factory has one bundle in the finished queue
-        assert_eq!(bundle_factory.finished.len(), 1);
-        // assert `pop_now()` will return `seq_action0`
-        let actions = bundle_factory.pop_now().buffer;
-        let actual_seq_action = actions[0].as_rollup_data_submission().unwrap();
-        assert_eq!(actual_seq_action.rollup_id, seq_action0.rollup_id);
-        assert_eq!(actual_seq_action.data, seq_action0.data);
-    }
-
     #[test]
     fn pop_now_all_empty() {
         // create a bundle factory with max bundle size as 100 bytes
         let mut bundle_factory = BundleFactory::new(100, 10);
 
-        // assert that the finished queue is empty
-        assert_eq!(bundle_factory.finished.len(), 0);
         // assert `pop_now()` returns an empty vec
         let actions = bundle_factory.pop_now();
         assert!(actions.is_empty());
     }
 
     #[test]
-    fn pop_now_finished_then_curr_then_empty() {
+    fn pop_now_in_arrival_order_then_empty() {
         let mut bundle_factory = BundleFactory::new(200, 10);
 
         let seq_action0 = sequence_action_of_max_size(200);
         bundle_factory.try_push(seq_action0.clone()).unwrap();
 
-        let seq_action1 = RollupDataSubmission {
-            rollup_id: RollupId::new([1; ROLLUP_ID_LEN]),
-            ..sequence_action_of_max_size(200)
-        };
+        let seq_action1 = sequence_action_for_rollup(1, 200);
         bundle_factory.try_push(seq_action1.clone()).unwrap();
 
-        // assert that the bundle factory has one bundle in the finished queue
-        assert_eq!(bundle_factory.finished.len(), 1);
-
         // assert `pop_now()` will return `seq_action0` on the first call
-        let actions_finished = bundle_factory.pop_now().buffer;
-        assert_eq!(actions_finished.len(), 1);
-        let actual_seq_action = actions_finished[0].as_rollup_data_submission().unwrap();
+        let actions_first = bundle_factory.pop_now().buffer;
+        assert_eq!(actions_first.len(), 1);
+        let actual_seq_action = actions_first[0].as_rollup_data_submission().unwrap();
         assert_eq!(actual_seq_action.rollup_id, seq_action0.rollup_id);
         assert_eq!(actual_seq_action.data, seq_action0.data);
 
-        // assert that the finished queue is empty now
-        assert_eq!(bundle_factory.finished.len(), 0);
-
-        // assert `pop_now()` will return `seq_action1` on the second call (i.e. from curr)
-        let actions_curr = bundle_factory.pop_now().buffer;
-        assert_eq!(actions_curr.len(), 1);
-        let actual_seq_action = actions_curr[0].as_rollup_data_submission().unwrap();
+        // assert `pop_now()` will return `seq_action1` on the second call
+        let actions_second = bundle_factory.pop_now().buffer;
+        assert_eq!(actions_second.len(), 1);
+        let actual_seq_action = actions_second[0].as_rollup_data_submission().unwrap();
         assert_eq!(actual_seq_action.rollup_id, seq_action1.rollup_id);
         assert_eq!(actual_seq_action.data, seq_action1.data);
 
@@ -323,21 +254,98 @@ mod bundle_factory {
 
     #[test]
     fn pop_now_full() {
-        let mut bundle_factory = BundleFactory::new(200, 1);
+        let mut bundle_factory = BundleFactory::new(200, 2);
 
-        // push a sequence action that is 100 bytes total
         let seq_action = sequence_action_of_max_size(200);
         bundle_factory.try_push(seq_action.clone()).unwrap();
-
-        // push another sequence action that is to force the current bundle to flush
         bundle_factory.try_push(seq_action.clone()).unwrap();
+        assert!(bundle_factory.is_full());
 
         // assert `pop_now()` will set the factory to no longer full
         let _actions_finished = bundle_factory.pop_now();
-        assert_eq!(bundle_factory.finished.len(), 0);
         assert!(!bundle_factory.is_full());
     }
 
+    #[test]
+    fn busy_rollup_does_not_starve_others() {
+        let mut bundle_factory = BundleFactory::new(2000, 10);
+
+        // rollup 0 queues enough transactions to fill several bundles before rollup 1 queues two
+        for _ in 0..30 {
+            bundle_factory
+                .try_push(sequence_action_for_rollup(0, 200))
+                .unwrap();
+        }
+        for _ in 0..2 {
+            bundle_factory
+                .try_push(sequence_action_for_rollup(1, 200))
+                .unwrap();
+        }
+
+        // assert both transactions of rollup 1 are in the first bundle
+        let bundle = bundle_factory.next_finished().unwrap().pop();
+        assert_eq!(count_for_rollup(&bundle, 1), 2);
+        assert_eq!(count_for_rollup(&bundle, 0), 8);
+        assert_eq!(
+            bundle_factory.queue_depth(&RollupId::new([0; ROLLUP_ID_LEN])),
+            22
+        );
+        assert_eq!(
+            bundle_factory.queue_depth(&RollupId::new([1; ROLLUP_ID_LEN])),
+            0
+        );
+    }
+
+    #[test]
+    fn rollups_are_scheduled_by_weight() {
+        let mut bundle_factory = BundleFactory::new(2000, 10).with_rollup_weights(HashMap::from([
+            (RollupId::new([0; ROLLUP_ID_LEN]), 1),
+            (RollupId::new([1; ROLLUP_ID_LEN]), 3),
+        ]));
+
+        for _ in 0..20 {
+            bundle_factory
+                .try_push(sequence_action_for_rollup(0, 200))
+                .unwrap();
+            bundle_factory
+                .try_push(sequence_action_for_rollup(1, 200))
+                .unwrap();
+        }
+
+        // assert rollup 1 is given three times the share of rollup 0
+        let bundle = bundle_factory.next_finished().unwrap().pop();
+        assert_eq!(count_for_rollup(&bundle, 0), 2);
+        assert_eq!(count_for_rollup(&bundle, 1), 8);
+    }
+
+    #[test]
+    fn rollup_queue_is_limited_to_its_quota() {
+        let mut bundle_factory = BundleFactory::new(2000, 4).with_rollup_weights(HashMap::from([
+            (RollupId::new([0; ROLLUP_ID_LEN]), 1),
+            (RollupId::new([1; ROLLUP_ID_LEN]), 1),
+        ]));
+
+        // rollup 0 may queue half the capacity, i.e. two bundles
+        for _ in 0..20 {
+            bundle_factory
+                .try_push(sequence_action_for_rollup(0, 200))
+                .unwrap();
+        }
+        let err = bundle_factory
+            .try_push(sequence_action_for_rollup(0, 200))
+            .expect_err("the action should be rejected");
+        match err {
+            BundleFactoryError::RollupQueueFull(_) => {}
+            other => panic!("expected a RollupQueueFull variant, but got {other:?}"),
+        }
+
+        // assert the factory is not full and rollup 1 can still queue transactions
+        assert!(!bundle_factory.is_full());
+        bundle_factory
+            .try_push(sequence_action_for_rollup(1, 200))
+            .unwrap();
+    }
+
     #[test]
     fn transaction_construction_does_not_panic() {
         let mut bundle_factory = BundleFactory::new(1000, 10);
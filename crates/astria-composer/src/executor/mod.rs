@@ -4,7 +4,10 @@
 /// - Managing the connection to the sequencer
 /// - Submitting transactions to the sequencer
 use std::{
-    collections::VecDeque,
+    collections::{
+        HashMap,
+        VecDeque,
+    },
     pin::Pin,
     task::Poll,
     time::Duration,
@@ -19,6 +22,10 @@ use astria_core::{
         },
         GetPendingNonceRequest,
     },
+    primitive::v1::{
+        RollupId,
+        TransactionId,
+    },
     protocol::{
         abci::AbciErrorCode,
         transaction::v1::{
@@ -35,12 +42,13 @@ use astria_eyre::eyre::{
 use futures::{
     future::{
         self,
-        Fuse,
-        FusedFuture as _,
+        BoxFuture,
         FutureExt as _,
     },
     ready,
+    stream::FuturesUnordered,
     Future,
+    StreamExt as _,
 };
 use pin_project_lite::pin_project;
 use prost::Message as _;
@@ -88,9 +96,14 @@ use self::bundle_factory::SizedBundle;
 use crate::{
     executor::bundle_factory::{
         BundleFactory,
+        BundleFactoryError,
         SizedBundleReport,
     },
     metrics::Metrics,
+    receipts::{
+        ReceiptId,
+        Receipts,
+    },
     utils::report_exit_reason,
 };
 
@@ -120,8 +133,12 @@ pub(crate) enum EnsureChainIdError {
 /// The `Executor` interfaces with the sequencer. It handles account nonces, transaction signing,
 /// and transaction submission.
 /// The `Executor` receives `Vec<Action>` from the bundling logic, packages them with a nonce into
-/// an `Unsigned`, then signs them with the sequencer key and submits to the sequencer.
+/// an `Unsigned`, then signs them with one of the sequencer keys and submits to the sequencer.
 /// Its `status` field indicates that connection to the sequencer node has been established.
+///
+/// Each sequencer key is a separate nonce lane: a lane has at most one bundle in flight, but
+/// bundles on different lanes are submitted concurrently. Bundles are assigned to idle lanes in
+/// round-robin order, so bundles signed by different keys may be included out of order.
 pub(super) struct Executor {
     // The status of this executor
     status: watch::Sender<Status>,
@@ -134,21 +151,30 @@ pub(super) struct Executor {
     grpc_client: sequencer_service_client::SequencerServiceClient<Channel>,
     // The chain id used for submission of transactions to the sequencer.
     sequencer_chain_id: String,
-    // Private key used to sign sequencer transactions
-    sequencer_key: SigningKey,
-    // The sequencer address associated with the private key
-    address: Address,
+    // Keys used to sign sequencer transactions, one per nonce lane. Never empty.
+    signers: Vec<Signer>,
     // Milliseconds for bundle timer to make sure bundles are submitted at least once per block.
     block_time: tokio::time::Duration,
     // Max bytes in a sequencer action bundle
     max_bytes_per_bundle: usize,
-    // Max amount of `SizedBundle`s that can be in the `BundleFactory`'s `finished` queue.
+    // Max amount of `SizedBundle`s worth of bytes that can be queued in the `BundleFactory`.
     bundle_queue_capacity: usize,
+    // The weights with which the rollups are scheduled into bundles.
+    rollup_weights: HashMap<RollupId, u32>,
     // Token to signal the executor to stop upon shutdown.
     shutdown_token: CancellationToken,
+    // The receipts of the transactions submitted via the gRPC collector.
+    receipts: Receipts,
     metrics: &'static Metrics,
 }
 
+/// A private key used to sign sequencer transactions and its sequencer address.
+#[derive(Clone)]
+struct Signer {
+    key: SigningKey,
+    address: Address,
+}
+
 #[derive(Clone)]
 pub(super) struct Handle {
     serialized_rollup_transactions_tx: mpsc::Sender<RollupDataSubmission>,
@@ -196,27 +222,48 @@ impl Executor {
         self.status.subscribe()
     }
 
-    /// Create a future to submit a bundle to the sequencer.
-    #[instrument(skip_all, fields(nonce.initial = %nonce))]
+    /// Create a future to submit a bundle to the sequencer, signed by the key of nonce lane `lane`.
+    #[instrument(
+        skip_all,
+        fields(lane = lane, address = %self.signers[lane].address, nonce.initial = %nonce),
+    )]
     fn submit_bundle(
         &self,
+        lane: usize,
         nonce: u32,
         bundle: SizedBundle,
         metrics: &'static Metrics,
-    ) -> Fuse<Instrumented<SubmitFut>> {
+    ) -> Instrumented<SubmitFut> {
+        let signer = &self.signers[lane];
         SubmitFut {
             abci_client: self.abci_client.clone(),
             grpc_client: self.grpc_client.clone(),
-            address: self.address,
+            address: signer.address,
+            lane,
             nonce,
             chain_id: self.sequencer_chain_id.clone(),
-            signing_key: self.sequencer_key.clone(),
+            signing_key: signer.key.clone(),
             state: SubmitState::NotStarted,
             bundle,
+            receipts: self.receipts.clone(),
             metrics,
         }
         .in_current_span()
-        .fuse()
+    }
+
+    /// Submits `bundle` on the next idle nonce lane, tracking the submission in `in_flight`.
+    fn submit_on_idle_lane(
+        &self,
+        bundle: SizedBundle,
+        nonces: &[u32],
+        idle_lanes: &mut VecDeque<usize>,
+        in_flight: &mut FuturesUnordered<LaneSubmission>,
+    ) {
+        let lane = idle_lanes
+            .pop_front()
+            .expect("bundles must only be submitted if a lane is idle");
+        let submission = self.submit_bundle(lane, nonces[lane], bundle, self.metrics);
+        in_flight.push(submission.map(move |rsp| (lane, rsp)).boxed());
     }
 
     /// Run the Executor loop, calling `process_bundle` on each bundle received from the channel.
@@ -224,27 +271,31 @@ impl Executor {
     /// # Errors
     /// An error is returned if connecting to the sequencer fails.
     pub(super) async fn run_until_stopped(mut self) -> eyre::Result<()> {
-        let mut nonce = select!(
+        let mut nonces = select!(
             biased;
             () = self.shutdown_token.cancelled() => {
                 report_exit_reason(Ok("received shutdown signal while running initialization routines; exiting"));
                 return Ok(())
             }
 
-            nonce = self.init() => {
-                nonce.wrap_err("initialization failed").inspect_err(|err| report_exit_reason(Err(err)))?
+            nonces = self.init() => {
+                nonces.wrap_err("initialization failed").inspect_err(|err| report_exit_reason(Err(err)))?
             }
         );
-        let mut submission_fut: Fuse<Instrumented<SubmitFut>> = Fuse::terminated();
+        let mut idle_lanes: VecDeque<usize> = (0..self.signers.len()).collect();
+        let mut in_flight: FuturesUnordered<LaneSubmission> = FuturesUnordered::new();
 
-        self.metrics.set_current_nonce(nonce);
+        for (lane, nonce) in nonces.iter().enumerate() {
+            self.metrics.set_current_nonce(lane, *nonce);
+        }
 
         self.status.send_modify(|status| status.is_connected = true);
 
         let block_timer = time::sleep(self.block_time);
         tokio::pin!(block_timer);
         let mut bundle_factory =
-            BundleFactory::new(self.max_bytes_per_bundle, self.bundle_queue_capacity);
+            BundleFactory::new(self.max_bytes_per_bundle, self.bundle_queue_capacity)
+                .with_rollup_weights(self.rollup_weights.clone());
 
         let reset_time = || {
             Instant::now()
@@ -259,17 +310,19 @@ impl Executor {
                 () = self.shutdown_token.cancelled() => {
                     break Ok("received shutdown signal");
                 }
-                // process submission result and update nonce
-                rsp = &mut submission_fut, if !submission_fut.is_terminated() => {
-                    if let Err(err) = process_result_update_nonce(&mut nonce, rsp, &mut block_timer, reset_time) {
+                // process submission result, update the lane's nonce and mark it idle
+                Some((lane, rsp)) = in_flight.next(), if !in_flight.is_empty() => {
+                    if let Err(err) = process_result_update_nonce(&mut nonces[lane], rsp, &mut block_timer, reset_time) {
                         break Err(err).wrap_err("failed submitting bundle to sequencer");
                     };
+                    idle_lanes.push_back(lane);
                 }
 
-                Some(next_bundle) = future::ready(bundle_factory.next_finished()), if submission_fut.is_terminated() => {
+                Some(next_bundle) = future::ready(bundle_factory.next_finished()), if !idle_lanes.is_empty() => {
                     let bundle = next_bundle.pop();
+                    self.report_rollup_queue_depths(&bundle, &bundle_factory);
                     if !bundle.is_empty() {
-                        submission_fut = self.submit_bundle(nonce, bundle, self.metrics);
+                        self.submit_on_idle_lane(bundle, &nonces, &mut idle_lanes, &mut in_flight);
                     }
                 }
 
@@ -279,12 +332,13 @@ impl Executor {
                 }
 
                 // try to preempt current bundle if the timer has ticked without submitting the next bundle
-                () = &mut block_timer, if submission_fut.is_terminated() => {
+                () = &mut block_timer, if !idle_lanes.is_empty() => {
                     let bundle = bundle_factory.pop_now();
+                    self.report_rollup_queue_depths(&bundle, &bundle_factory);
                     if bundle.is_empty() {
                         block_timer.as_mut().reset(reset_time());
                     } else {
-                        submission_fut = self.submit_bundle(nonce, bundle, self.metrics);
+                        self.submit_on_idle_lane(bundle, &nonces, &mut idle_lanes, &mut in_flight);
                     }
                 }
             }
@@ -322,8 +376,8 @@ impl Executor {
         }
 
         let shutdown_logic = self.run_shutdown_logic(
-            submission_fut,
-            nonce,
+            in_flight,
+            nonces,
             &mut bundles_to_drain,
             &mut bundles_drained,
         );
@@ -337,14 +391,16 @@ impl Executor {
 
     /// Performs initialization checks prior to running the executor
     #[instrument(skip_all, err)]
-    async fn init(&self) -> eyre::Result<u32> {
+    async fn init(&self) -> eyre::Result<Vec<u32>> {
         self.ensure_chain_id_is_correct()
             .await
             .wrap_err("failed to validate chain id")?;
-        let nonce = get_pending_nonce(self.grpc_client.clone(), self.address, self.metrics)
-            .await
-            .wrap_err("failed getting initial nonce from sequencer")?;
-        Ok(nonce)
+        let nonces = future::try_join_all(self.signers.iter().map(|signer| {
+            get_pending_nonce(self.grpc_client.clone(), signer.address, self.metrics)
+        }))
+        .await
+        .wrap_err("failed getting initial nonces from sequencer")?;
+        Ok(nonces)
     }
 
     /// Performs check to ensure the configured chain ID matches the remote chain ID
@@ -394,27 +450,42 @@ impl Executor {
     }
 
     /// Creates shutdown logic for submitting remaining bundles to the sequencer.
+    ///
+    /// The bundles still in flight are awaited first, each on its own nonce lane. The remaining
+    /// queued bundles are then drained on nonce lane 0 only, submitted one after another, so
+    /// shutdown throughput is limited to that of a single lane regardless of how many keys are
+    /// configured.
     #[instrument(skip_all, err)]
     async fn run_shutdown_logic(
         &self,
-        submission_fut: Fuse<Instrumented<SubmitFut>>,
-        mut nonce: u32,
+        mut in_flight: FuturesUnordered<LaneSubmission>,
+        mut nonces: Vec<u32>,
         bundles_to_drain: &mut VecDeque<SizedBundle>,
         bundles_drained: &mut Option<u64>,
     ) -> eyre::Result<()> {
+        const DRAIN_LANE: usize = 0;
+
         info!(
             no_of_bundles_to_drain = bundles_to_drain.len(),
             "submitting remaining transaction bundles to sequencer"
         );
 
-        // wait for the last bundle to be submitted
-        if !submission_fut.is_terminated() {
-            info!("waiting for the last bundle of transactions to be submitted to the sequencer");
-            match submission_fut.await {
+        // wait for the last bundles to be submitted
+        if !in_flight.is_empty() {
+            info!(
+                no_of_bundles_in_flight = in_flight.len(),
+                "waiting for the last bundles of transactions to be submitted to the sequencer"
+            );
+        }
+        while let Some((lane, rsp)) = in_flight.next().await {
+            match rsp {
                 Ok(new_nonce) => {
-                    debug!(new_nonce, "successfully submitted bundle of transactions");
+                    debug!(
+                        lane,
+                        new_nonce, "successfully submitted bundle of transactions"
+                    );
 
-                    nonce = new_nonce;
+                    nonces[lane] = new_nonce;
                 }
                 Err(error) => {
                     return Err(error.wrap_err(
@@ -425,7 +496,7 @@ impl Executor {
         }
         while let Some(bundle) = bundles_to_drain.pop_front() {
             match self
-                .submit_bundle(nonce, bundle.clone(), self.metrics)
+                .submit_bundle(DRAIN_LANE, nonces[DRAIN_LANE], bundle.clone(), self.metrics)
                 .await
             {
                 Ok(new_nonce) => {
@@ -435,7 +506,7 @@ impl Executor {
                         "successfully submitted transaction bundle"
                     );
 
-                    nonce = new_nonce;
+                    nonces[DRAIN_LANE] = new_nonce;
                     *bundles_drained = bundles_drained.and_then(|value| value.checked_add(1));
                 }
                 Err(error) => {
@@ -452,7 +523,7 @@ impl Executor {
         Ok(())
     }
 
-    /// Pushes sequence action into current bundle, warning and dropping on failure.
+    /// Pushes sequence action into its rollup's queue, warning and dropping on failure.
     #[instrument(skip_all)]
     fn bundle_seq_action(
         &self,
@@ -460,18 +531,44 @@ impl Executor {
         bundle_factory: &mut BundleFactory,
     ) {
         let rollup_id = seq_action.rollup_id;
+        let receipt_id = ReceiptId::for_submission(&seq_action);
+
+        match bundle_factory.try_push(seq_action) {
+            Ok(()) => self
+                .metrics
+                .set_rollup_queue_depth(&rollup_id, bundle_factory.queue_depth(&rollup_id)),
+            Err(e) => {
+                match e {
+                    BundleFactoryError::SequenceActionTooLarge {
+                        ..
+                    } => self.metrics.increment_txs_dropped_too_large(&rollup_id),
+                    BundleFactoryError::RollupQueueFull(_) => {
+                        self.metrics.increment_txs_dropped_queue_full(&rollup_id);
+                    }
+                    BundleFactoryError::QueueFull(_) => {}
+                }
+                warn!(
+                    rollup_id = %rollup_id,
+                    error = &e as &StdError,
+                    "failed to bundle transaction, dropping it."
+                );
+                self.receipts.set_dropped([receipt_id], &e.to_string());
+            }
+        }
+    }
 
-        if let Err(e) = bundle_factory.try_push(seq_action) {
-            self.metrics.increment_txs_dropped_too_large(&rollup_id);
-            warn!(
-                rollup_id = %rollup_id,
-                error = &e as &StdError,
-                "failed to bundle transaction, dropping it."
-            );
+    /// Reports the queue depths of the rollups whose transactions were taken into `bundle`.
+    fn report_rollup_queue_depths(&self, bundle: &SizedBundle, bundle_factory: &BundleFactory) {
+        for (rollup_id, _) in bundle.rollup_bytes() {
+            self.metrics
+                .set_rollup_queue_depth(rollup_id, bundle_factory.queue_depth(rollup_id));
         }
     }
 }
 
+/// A bundle submission in flight, resolving to its nonce lane and the lane's next nonce.
+type LaneSubmission = BoxFuture<'static, (usize, eyre::Result<u32>)>;
+
 /// Queries the sequencer for the latest pending nonce with an exponential backoff
 #[instrument(name = "get pending nonce", skip_all, fields(%address), err)]
 async fn get_pending_nonce(
@@ -537,10 +634,11 @@ async fn get_pending_nonce(
 async fn submit_tx(
     client: sequencer_client::HttpClient,
     tx: Transaction,
+    lane: usize,
     metrics: &Metrics,
 ) -> eyre::Result<tx_sync::Response> {
     let nonce = tx.nonce();
-    metrics.set_current_nonce(nonce);
+    metrics.set_current_nonce(lane, nonce);
 
     // TODO: change to info and log tx hash (to match info log in `SubmitFut`'s response handling
     // logic)
@@ -659,12 +757,14 @@ pin_project! {
         abci_client: sequencer_client::HttpClient,
         grpc_client: SequencerServiceClient<tonic::transport::Channel>,
         address: Address,
+        lane: usize,
         chain_id: String,
         nonce: u32,
         signing_key: SigningKey,
         #[pin]
         state: SubmitState,
         bundle: SizedBundle,
+        receipts: Receipts,
         metrics: &'static Metrics,
     }
 }
@@ -676,6 +776,7 @@ pin_project! {
         WaitingForSend {
             #[pin]
             fut: Pin<Box<dyn Future<Output = eyre::Result<tx_sync::Response>> + Send>>,
+            transaction_id: TransactionId,
         },
         WaitingForNonce {
             #[pin]
@@ -688,6 +789,10 @@ impl Future for SubmitFut {
     type Output = eyre::Result<u32>;
 
     // FIXME (https://github.com/astriaorg/astria/issues/1572): This function is too long and should be refactored.
+    #[expect(
+        clippy::too_many_lines,
+        reason = "to be refactored as part of https://github.com/astriaorg/astria/issues/1572"
+    )]
     fn poll(mut self: Pin<&mut Self>, cx: &mut std::task::Context<'_>) -> Poll<Self::Output> {
         const INVALID_NONCE: Code = Code::Err(AbciErrorCode::INVALID_NONCE.value());
         const NONCE_TAKEN: Code = Code::Err(AbciErrorCode::NONCE_TAKEN.value());
@@ -708,12 +813,15 @@ impl Future for SubmitFut {
                         "submitting transaction to sequencer",
                     );
                     SubmitState::WaitingForSend {
-                        fut: submit_tx(this.abci_client.clone(), tx, self.metrics).boxed(),
+                        transaction_id: tx.id(),
+                        fut: submit_tx(this.abci_client.clone(), tx, *this.lane, self.metrics)
+                            .boxed(),
                     }
                 }
 
                 SubmitStateProj::WaitingForSend {
                     fut,
+                    transaction_id,
                 } => match ready!(fut.poll(cx)) {
                     Ok(rsp) => match rsp.code {
                         tendermint::abci::Code::Ok => {
@@ -725,6 +833,14 @@ impl Future for SubmitFut {
                             this.metrics
                                 .record_txs_per_submission(this.bundle.actions_count());
 
+                            for (rollup_id, byte_count) in this.bundle.rollup_bytes() {
+                                this.metrics
+                                    .increment_rollup_bytes_submitted(rollup_id, byte_count);
+                            }
+
+                            this.receipts
+                                .set_bundled(this.bundle.receipt_ids(), transaction_id.get());
+
                             return Poll::Ready(Ok(this
                                 .nonce
                                 .checked_add(1)
@@ -753,12 +869,27 @@ impl Future for SubmitFut {
 
                             this.metrics.increment_sequencer_submission_failure_count();
 
+                            this.receipts.set_dropped(
+                                this.bundle.receipt_ids(),
+                                &format!(
+                                    "sequencer rejected transaction `{transaction_id}` with abci \
+                                     code {}: {}",
+                                    rsp.code.value(),
+                                    rsp.log,
+                                ),
+                            );
+
                             return Poll::Ready(Ok(*this.nonce));
                         }
                     },
                     Err(error) => {
                         error!(%error, "failed sending transaction to sequencer");
 
+                        this.receipts.set_dropped(
+                            this.bundle.receipt_ids(),
+                            "failed sending transaction to sequencer",
+                        );
+
                         return Poll::Ready(
                             Err(error).wrap_err("failed sending transaction to sequencer"),
                         );
@@ -781,7 +912,9 @@ impl Future for SubmitFut {
                             "resubmitting transaction to sequencer with new nonce",
                         );
                         SubmitState::WaitingForSend {
-                            fut: submit_tx(this.abci_client.clone(), tx, self.metrics).boxed(),
+                            transaction_id: tx.id(),
+                            fut: submit_tx(this.abci_client.clone(), tx, *this.lane, self.metrics)
+                                .boxed(),
                         }
                     }
                     Err(error) => {
@@ -27,6 +27,7 @@ use crate::{
     collectors,
     executor,
     metrics::Metrics,
+    receipts::Receipts,
 };
 
 /// Listens for incoming gRPC requests and sends the Rollup transactions to the
@@ -42,6 +43,8 @@ pub(crate) struct GrpcServer {
 pub(crate) struct Builder {
     pub(crate) grpc_addr: SocketAddr,
     pub(crate) executor: executor::Handle,
+    pub(crate) sequencer_abci_endpoint: String,
+    pub(crate) receipts: Receipts,
     pub(crate) shutdown_token: CancellationToken,
     pub(crate) metrics: &'static Metrics,
     pub(crate) fee_asset: asset::Denom,
@@ -53,6 +56,8 @@ impl Builder {
         let Self {
             grpc_addr,
             executor,
+            sequencer_abci_endpoint,
+            receipts,
             shutdown_token,
             metrics,
             fee_asset,
@@ -61,7 +66,10 @@ impl Builder {
         let listener = TcpListener::bind(grpc_addr)
             .await
             .wrap_err("failed to bind socket address")?;
-        let grpc_collector = collectors::Grpc::new(executor.clone(), metrics, fee_asset);
+        let abci_client = sequencer_client::HttpClient::new(sequencer_abci_endpoint.as_str())
+            .wrap_err("failed constructing sequencer http client")?;
+        let grpc_collector =
+            collectors::Grpc::new(executor.clone(), abci_client, receipts, metrics, fee_asset);
 
         Ok(GrpcServer {
             listener,
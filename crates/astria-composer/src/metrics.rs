@@ -16,23 +16,29 @@ use telemetry::{
 };
 use tracing::error;
 
-type GethCounters = HashMap<String, Counter>;
+use crate::rollup::Endpoint;
+
+type NodeCounters = HashMap<String, Counter>;
 type GrpcCounters = HashMap<RollupId, Counter>;
 
 const ROLLUP_CHAIN_NAME_LABEL: &str = "rollup_chain_name";
 const ROLLUP_ID_LABEL: &str = "rollup_id";
 const COLLECTOR_TYPE_LABEL: &str = "collector_type";
+const NONCE_LANE_LABEL: &str = "nonce_lane";
 
 pub struct Metrics {
-    geth_txs_received: GethCounters,
-    geth_txs_dropped: GethCounters,
+    node_txs_received: NodeCounters,
+    node_txs_dropped: NodeCounters,
     grpc_txs_received: GrpcCounters,
     grpc_txs_dropped: GrpcCounters,
     txs_dropped_too_large: HashMap<RollupId, Counter>,
+    txs_dropped_queue_full: HashMap<RollupId, Counter>,
+    rollup_queue_depth: HashMap<RollupId, Gauge>,
+    rollup_bytes_submitted: HashMap<RollupId, Counter>,
     nonce_fetch_count: Counter,
     nonce_fetch_failure_count: Counter,
     nonce_fetch_latency: Histogram,
-    current_nonce: Gauge,
+    current_nonce: Vec<Gauge>,
     sequencer_submission_latency: Histogram,
     sequencer_submission_failure_count: Counter,
     txs_per_submission: Histogram,
@@ -40,12 +46,12 @@ pub struct Metrics {
 }
 
 impl Metrics {
-    pub(crate) fn geth_txs_received(&self, id: &String) -> Option<&Counter> {
-        self.geth_txs_received.get(id)
+    pub(crate) fn node_txs_received(&self, id: &String) -> Option<&Counter> {
+        self.node_txs_received.get(id)
     }
 
-    pub(crate) fn geth_txs_dropped(&self, id: &String) -> Option<&Counter> {
-        self.geth_txs_dropped.get(id)
+    pub(crate) fn node_txs_dropped(&self, id: &String) -> Option<&Counter> {
+        self.node_txs_dropped.get(id)
     }
 
     pub(crate) fn increment_grpc_txs_received(&self, id: &RollupId) {
@@ -72,6 +78,30 @@ impl Metrics {
         counter.increment(1);
     }
 
+    pub(crate) fn increment_txs_dropped_queue_full(&self, id: &RollupId) {
+        let Some(counter) = self.txs_dropped_queue_full.get(id) else {
+            error!(rollup_id = %id, "failed to get transactions_dropped_queue_full counter");
+            return;
+        };
+        counter.increment(1);
+    }
+
+    pub(crate) fn set_rollup_queue_depth(&self, id: &RollupId, depth: usize) {
+        let Some(gauge) = self.rollup_queue_depth.get(id) else {
+            error!(rollup_id = %id, "failed to get rollup_queue_depth gauge");
+            return;
+        };
+        gauge.set(depth);
+    }
+
+    pub(crate) fn increment_rollup_bytes_submitted(&self, id: &RollupId, byte_count: usize) {
+        let Some(counter) = self.rollup_bytes_submitted.get(id) else {
+            error!(rollup_id = %id, "failed to get rollup_bytes_submitted counter");
+            return;
+        };
+        counter.increment(u64::try_from(byte_count).unwrap_or(u64::MAX));
+    }
+
     pub(crate) fn increment_nonce_fetch_count(&self) {
         self.nonce_fetch_count.increment(1);
     }
@@ -84,8 +114,12 @@ impl Metrics {
         self.nonce_fetch_latency.record(latency);
     }
 
-    pub(crate) fn set_current_nonce(&self, nonce: u32) {
-        self.current_nonce.set(nonce);
+    pub(crate) fn set_current_nonce(&self, lane: usize, nonce: u32) {
+        let Some(gauge) = self.current_nonce.get(lane) else {
+            error!(lane, "failed to get current_nonce gauge");
+            return;
+        };
+        gauge.set(nonce);
     }
 
     pub(crate) fn record_sequencer_submission_latency(&self, latency: Duration) {
@@ -115,10 +149,12 @@ impl telemetry::Metrics for Metrics {
         let rollups = config
             .parse_rollups()
             .map_err(|error| Error::External(Box::new(error)))?;
-        let (geth_txs_received, grpc_txs_received) =
-            register_txs_received(builder, rollups.keys())?;
-        let (geth_txs_dropped, grpc_txs_dropped) = register_txs_dropped(builder, rollups.keys())?;
+        let (node_txs_received, grpc_txs_received) = register_txs_received(builder, &rollups)?;
+        let (node_txs_dropped, grpc_txs_dropped) = register_txs_dropped(builder, &rollups)?;
         let txs_dropped_too_large = register_txs_dropped_too_large(builder, rollups.keys())?;
+        let txs_dropped_queue_full = register_txs_dropped_queue_full(builder, rollups.keys())?;
+        let rollup_queue_depth = register_rollup_queue_depth(builder, rollups.keys())?;
+        let rollup_bytes_submitted = register_rollup_bytes_submitted(builder, rollups.keys())?;
 
         let nonce_fetch_count = builder
             .new_counter_factory(
@@ -141,9 +177,7 @@ impl telemetry::Metrics for Metrics {
             )?
             .register()?;
 
-        let current_nonce = builder
-            .new_gauge_factory(CURRENT_NONCE, "The current nonce")?
-            .register()?;
+        let current_nonce = register_current_nonce(builder, config.private_key_files().len())?;
 
         let sequencer_submission_latency = builder
             .new_histogram_factory(
@@ -175,11 +209,14 @@ impl telemetry::Metrics for Metrics {
             .register()?;
 
         Ok(Self {
-            geth_txs_received,
-            geth_txs_dropped,
+            node_txs_received,
+            node_txs_dropped,
             grpc_txs_received,
             grpc_txs_dropped,
             txs_dropped_too_large,
+            txs_dropped_queue_full,
+            rollup_queue_depth,
+            rollup_bytes_submitted,
             nonce_fetch_count,
             nonce_fetch_failure_count,
             nonce_fetch_latency,
@@ -192,28 +229,28 @@ impl telemetry::Metrics for Metrics {
     }
 }
 
-fn register_txs_received<'a>(
+fn register_txs_received(
     builder: &mut RegisteringBuilder,
-    rollup_chain_names: impl Iterator<Item = &'a String>,
-) -> Result<(GethCounters, GrpcCounters), Error> {
+    rollups: &HashMap<String, Endpoint>,
+) -> Result<(NodeCounters, GrpcCounters), Error> {
     let mut factory = builder.new_counter_factory(
         TRANSACTIONS_RECEIVED,
         "The number of transactions successfully received from collectors and bundled, labelled \
          by rollup and collector type",
     )?;
 
-    let mut geth_counters = HashMap::new();
+    let mut node_counters = HashMap::new();
     let mut grpc_counters = HashMap::new();
 
-    for chain_name in rollup_chain_names {
+    for (chain_name, endpoint) in rollups {
         let rollup_id = RollupId::from_unhashed_bytes(chain_name.as_bytes());
 
-        let geth_counter = factory.register_with_labels(&[
+        let node_counter = factory.register_with_labels(&[
             (ROLLUP_CHAIN_NAME_LABEL, chain_name.clone()),
             (ROLLUP_ID_LABEL, rollup_id.to_string()),
-            (COLLECTOR_TYPE_LABEL, "geth".to_string()),
+            (COLLECTOR_TYPE_LABEL, endpoint.collector.to_string()),
         ])?;
-        geth_counters.insert(chain_name.clone(), geth_counter);
+        node_counters.insert(chain_name.clone(), node_counter);
 
         let grpc_counter = factory.register_with_labels(&[
             (ROLLUP_CHAIN_NAME_LABEL, chain_name.clone()),
@@ -222,31 +259,31 @@ fn register_txs_received<'a>(
         ])?;
         grpc_counters.insert(rollup_id, grpc_counter);
     }
-    Ok((geth_counters, grpc_counters))
+    Ok((node_counters, grpc_counters))
 }
 
-fn register_txs_dropped<'a>(
+fn register_txs_dropped(
     builder: &mut RegisteringBuilder,
-    rollup_chain_names: impl Iterator<Item = &'a String>,
-) -> Result<(GethCounters, GrpcCounters), Error> {
+    rollups: &HashMap<String, Endpoint>,
+) -> Result<(NodeCounters, GrpcCounters), Error> {
     let mut factory = builder.new_counter_factory(
         TRANSACTIONS_DROPPED,
         "The number of transactions dropped by the collectors before bundling, labelled by rollup \
          and collector type",
     )?;
 
-    let mut geth_counters = HashMap::new();
+    let mut node_counters = HashMap::new();
     let mut grpc_counters = HashMap::new();
 
-    for chain_name in rollup_chain_names {
+    for (chain_name, endpoint) in rollups {
         let rollup_id = RollupId::from_unhashed_bytes(chain_name.as_bytes());
 
-        let geth_counter = factory.register_with_labels(&[
+        let node_counter = factory.register_with_labels(&[
             (ROLLUP_CHAIN_NAME_LABEL, chain_name.clone()),
             (ROLLUP_ID_LABEL, rollup_id.to_string()),
-            (COLLECTOR_TYPE_LABEL, "geth".to_string()),
+            (COLLECTOR_TYPE_LABEL, endpoint.collector.to_string()),
         ])?;
-        geth_counters.insert(chain_name.clone(), geth_counter);
+        node_counters.insert(chain_name.clone(), node_counter);
 
         let grpc_counter = factory.register_with_labels(&[
             (ROLLUP_CHAIN_NAME_LABEL, chain_name.clone()),
@@ -255,7 +292,7 @@ fn register_txs_dropped<'a>(
         ])?;
         grpc_counters.insert(rollup_id, grpc_counter);
     }
-    Ok((geth_counters, grpc_counters))
+    Ok((node_counters, grpc_counters))
 }
 
 fn register_txs_dropped_too_large<'a>(
@@ -281,10 +318,97 @@ fn register_txs_dropped_too_large<'a>(
     Ok(counters)
 }
 
+fn register_txs_dropped_queue_full<'a>(
+    builder: &mut RegisteringBuilder,
+    rollup_chain_names: impl Iterator<Item = &'a String>,
+) -> Result<HashMap<RollupId, Counter>, Error> {
+    let mut factory = builder.new_counter_factory(
+        TRANSACTIONS_DROPPED_QUEUE_FULL,
+        "The number of transactions dropped because their rollup's queue was full, labelled by \
+         rollup",
+    )?;
+
+    let mut counters = HashMap::new();
+
+    for chain_name in rollup_chain_names {
+        let rollup_id = RollupId::from_unhashed_bytes(chain_name.as_bytes());
+
+        let counter = factory.register_with_labels(&[
+            (ROLLUP_CHAIN_NAME_LABEL, chain_name.clone()),
+            (ROLLUP_ID_LABEL, rollup_id.to_string()),
+        ])?;
+        counters.insert(rollup_id, counter);
+    }
+    Ok(counters)
+}
+
+fn register_rollup_queue_depth<'a>(
+    builder: &mut RegisteringBuilder,
+    rollup_chain_names: impl Iterator<Item = &'a String>,
+) -> Result<HashMap<RollupId, Gauge>, Error> {
+    let mut factory = builder.new_gauge_factory(
+        ROLLUP_QUEUE_DEPTH,
+        "The number of transactions queued for bundling, labelled by rollup",
+    )?;
+
+    let mut gauges = HashMap::new();
+
+    for chain_name in rollup_chain_names {
+        let rollup_id = RollupId::from_unhashed_bytes(chain_name.as_bytes());
+
+        let gauge = factory.register_with_labels(&[
+            (ROLLUP_CHAIN_NAME_LABEL, chain_name.clone()),
+            (ROLLUP_ID_LABEL, rollup_id.to_string()),
+        ])?;
+        gauges.insert(rollup_id, gauge);
+    }
+    Ok(gauges)
+}
+
+fn register_rollup_bytes_submitted<'a>(
+    builder: &mut RegisteringBuilder,
+    rollup_chain_names: impl Iterator<Item = &'a String>,
+) -> Result<HashMap<RollupId, Counter>, Error> {
+    let mut factory = builder.new_counter_factory(
+        ROLLUP_BYTES_SUBMITTED,
+        "The total bytes of transactions successfully sent to the sequencer, labelled by rollup",
+    )?;
+
+    let mut counters = HashMap::new();
+
+    for chain_name in rollup_chain_names {
+        let rollup_id = RollupId::from_unhashed_bytes(chain_name.as_bytes());
+
+        let counter = factory.register_with_labels(&[
+            (ROLLUP_CHAIN_NAME_LABEL, chain_name.clone()),
+            (ROLLUP_ID_LABEL, rollup_id.to_string()),
+        ])?;
+        counters.insert(rollup_id, counter);
+    }
+    Ok(counters)
+}
+
+fn register_current_nonce(
+    builder: &mut RegisteringBuilder,
+    lanes: usize,
+) -> Result<Vec<Gauge>, Error> {
+    let mut factory = builder.new_gauge_factory(
+        CURRENT_NONCE,
+        "The current nonce of each signer, labelled by nonce lane",
+    )?;
+
+    (0..lanes)
+        .map(|lane| factory.register_with_labels(&[(NONCE_LANE_LABEL, lane.to_string())]))
+        .collect()
+}
+
 metric_names!(pub const METRICS_NAMES:
     TRANSACTIONS_RECEIVED,
     TRANSACTIONS_DROPPED,
     TRANSACTIONS_DROPPED_TOO_LARGE,
+    TRANSACTIONS_DROPPED_QUEUE_FULL,
+    ROLLUP_QUEUE_DEPTH,
+    ROLLUP_BYTES_SUBMITTED,
     NONCE_FETCH_COUNT,
     NONCE_FETCH_FAILURE_COUNT,
     NONCE_FETCH_LATENCY,
@@ -303,9 +427,12 @@ mod tests {
         NONCE_FETCH_COUNT,
         NONCE_FETCH_FAILURE_COUNT,
         NONCE_FETCH_LATENCY,
+        ROLLUP_BYTES_SUBMITTED,
+        ROLLUP_QUEUE_DEPTH,
         SEQUENCER_SUBMISSION_FAILURE_COUNT,
         SEQUENCER_SUBMISSION_LATENCY,
         TRANSACTIONS_DROPPED,
+        TRANSACTIONS_DROPPED_QUEUE_FULL,
         TRANSACTIONS_DROPPED_TOO_LARGE,
         TRANSACTIONS_PER_SUBMISSION,
         TRANSACTIONS_RECEIVED,
@@ -327,6 +454,12 @@ mod tests {
             TRANSACTIONS_DROPPED_TOO_LARGE,
             "transactions_dropped_too_large",
         );
+        assert_const(
+            TRANSACTIONS_DROPPED_QUEUE_FULL,
+            "transactions_dropped_queue_full",
+        );
+        assert_const(ROLLUP_QUEUE_DEPTH, "rollup_queue_depth");
+        assert_const(ROLLUP_BYTES_SUBMITTED, "rollup_bytes_submitted");
         assert_const(NONCE_FETCH_COUNT, "nonce_fetch_count");
         assert_const(NONCE_FETCH_FAILURE_COUNT, "nonce_fetch_failure_count");
         assert_const(NONCE_FETCH_LATENCY, "nonce_fetch_latency");
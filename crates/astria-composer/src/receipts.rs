@@ -0,0 +1,249 @@
+//! Receipts tracking the transactions submitted via the gRPC collector.
+//!
+//! A receipt is identified by the sha256 hash of a transaction's rollup ID and data, so that
+//! identical transactions share a receipt. [`Receipts`] retains each receipt for a configured
+//! period, within which resubmissions of the same transaction are rejected as duplicates unless
+//! it was dropped.
+
+use std::{
+    collections::{
+        HashMap,
+        VecDeque,
+    },
+    sync::{
+        Arc,
+        Mutex,
+        MutexGuard,
+    },
+    time::Duration,
+};
+
+use astria_core::{
+    primitive::v1::RollupId,
+    protocol::transaction::v1::action::RollupDataSubmission,
+};
+use sha2::{
+    Digest as _,
+    Sha256,
+};
+use tokio::time::Instant;
+
+/// The ID of a receipt: the sha256 hash of a rollup ID and a transaction's data.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub(crate) struct ReceiptId([u8; 32]);
+
+impl ReceiptId {
+    pub(crate) fn new(rollup_id: &RollupId, data: &[u8]) -> Self {
+        let mut hasher = Sha256::new();
+        hasher.update(rollup_id.as_bytes());
+        hasher.update(data);
+        Self(hasher.finalize().into())
+    }
+
+    pub(crate) fn for_submission(submission: &RollupDataSubmission) -> Self {
+        Self::new(&submission.rollup_id, &submission.data)
+    }
+
+    pub(crate) fn try_from_slice(bytes: &[u8]) -> Option<Self> {
+        bytes.try_into().ok().map(Self)
+    }
+
+    pub(crate) fn get(self) -> [u8; 32] {
+        self.0
+    }
+}
+
+impl std::fmt::Display for ReceiptId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&hex::encode(self.0))
+    }
+}
+
+/// The status of a transaction tracked by a receipt.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) enum ReceiptStatus {
+    /// The transaction is waiting to be bundled.
+    Queued,
+    /// The transaction was bundled into the sequencer transaction with the given hash, which was
+    /// accepted by the sequencer.
+    Bundled {
+        sequencer_transaction_hash: [u8; 32],
+    },
+    /// The transaction was dropped and will not reach the sequencer.
+    Dropped { reason: String },
+}
+
+/// The receipts of the transactions submitted via the gRPC collector, shared between the
+/// collector and the executor.
+#[derive(Clone)]
+pub(crate) struct Receipts {
+    inner: Arc<Mutex<Inner>>,
+    retention: Duration,
+}
+
+#[derive(Default)]
+struct Inner {
+    /// The status of each retained receipt and when it was created.
+    receipts: HashMap<ReceiptId, (Instant, ReceiptStatus)>,
+    /// The receipts in order of creation. May contain receipts that were recreated after being
+    /// dropped, which are skipped when expiring.
+    created: VecDeque<(Instant, ReceiptId)>,
+}
+
+impl Receipts {
+    pub(crate) fn new(retention: Duration) -> Self {
+        Self {
+            inner: Arc::default(),
+            retention,
+        }
+    }
+
+    /// Creates a queued receipt for `id`.
+    ///
+    /// Returns `false` without modifying the receipt if one is retained for `id` and the
+    /// transaction was not dropped, i.e. if the transaction is a duplicate.
+    pub(crate) fn try_create(&self, id: ReceiptId) -> bool {
+        let now = Instant::now();
+        let mut inner = self.lock();
+        inner.expire(now, self.retention);
+        if matches!(
+            inner.receipts.get(&id),
+            Some((_, status)) if !matches!(status, ReceiptStatus::Dropped { .. })
+        ) {
+            return false;
+        }
+        inner.receipts.insert(id, (now, ReceiptStatus::Queued));
+        inner.created.push_back((now, id));
+        true
+    }
+
+    /// Returns the status of the receipt `id`, if retained.
+    pub(crate) fn get(&self, id: &ReceiptId) -> Option<ReceiptStatus> {
+        let mut inner = self.lock();
+        inner.expire(Instant::now(), self.retention);
+        inner.receipts.get(id).map(|(_, status)| status.clone())
+    }
+
+    /// Marks the retained receipts among `ids` as bundled into the sequencer transaction
+    /// `sequencer_transaction_hash`.
+    pub(crate) fn set_bundled(
+        &self,
+        ids: impl IntoIterator<Item = ReceiptId>,
+        sequencer_transaction_hash: [u8; 32],
+    ) {
+        self.update(ids, || ReceiptStatus::Bundled {
+            sequencer_transaction_hash,
+        });
+    }
+
+    /// Marks the retained receipts among `ids` as dropped for `reason`.
+    pub(crate) fn set_dropped(&self, ids: impl IntoIterator<Item = ReceiptId>, reason: &str) {
+        self.update(ids, || ReceiptStatus::Dropped {
+            reason: reason.to_string(),
+        });
+    }
+
+    fn update(&self, ids: impl IntoIterator<Item = ReceiptId>, status: impl Fn() -> ReceiptStatus) {
+        let mut inner = self.lock();
+        // avoid hashing the transactions of other collectors if no receipts are retained
+        if inner.receipts.is_empty() {
+            return;
+        }
+        for id in ids {
+            if let Some((_, current)) = inner.receipts.get_mut(&id) {
+                *current = status();
+            }
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Inner> {
+        self.inner
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+}
+
+impl Inner {
+    fn expire(&mut self, now: Instant, retention: Duration) {
+        while let Some(&(created, id)) = self.created.front() {
+            if now.saturating_duration_since(created) < retention {
+                break;
+            }
+            self.created.pop_front();
+            if self
+                .receipts
+                .get(&id)
+                .is_some_and(|(receipt_created, _)| *receipt_created == created)
+            {
+                self.receipts.remove(&id);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RETENTION: Duration = Duration::from_secs(10);
+
+    fn receipt_id(data: &[u8]) -> ReceiptId {
+        ReceiptId::new(&RollupId::from_unhashed_bytes("rollup"), data)
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn duplicates_are_rejected_until_dropped() {
+        let receipts = Receipts::new(RETENTION);
+        let id = receipt_id(b"tx");
+        assert!(receipts.try_create(id));
+        assert!(!receipts.try_create(id));
+
+        receipts.set_bundled([id], [1; 32]);
+        assert!(!receipts.try_create(id));
+        assert_eq!(
+            receipts.get(&id),
+            Some(ReceiptStatus::Bundled {
+                sequencer_transaction_hash: [1; 32]
+            })
+        );
+
+        receipts.set_dropped([id], "rejected");
+        assert!(receipts.try_create(id));
+        assert_eq!(receipts.get(&id), Some(ReceiptStatus::Queued));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn receipts_expire_after_retention() {
+        let receipts = Receipts::new(RETENTION);
+        let first = receipt_id(b"first");
+        let second = receipt_id(b"second");
+        assert!(receipts.try_create(first));
+        tokio::time::advance(RETENTION / 2).await;
+        assert!(receipts.try_create(second));
+
+        tokio::time::advance(RETENTION / 2).await;
+        assert_eq!(receipts.get(&first), None);
+        assert_eq!(receipts.get(&second), Some(ReceiptStatus::Queued));
+        assert!(receipts.try_create(first));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn recreated_receipt_is_not_expired_by_its_earlier_creation() {
+        let receipts = Receipts::new(RETENTION);
+        let id = receipt_id(b"tx");
+        assert!(receipts.try_create(id));
+        receipts.set_dropped([id], "rejected");
+        tokio::time::advance(RETENTION / 2).await;
+        assert!(receipts.try_create(id));
+
+        tokio::time::advance(RETENTION / 2).await;
+        assert_eq!(receipts.get(&id), Some(ReceiptStatus::Queued));
+    }
+
+    #[test]
+    fn untracked_receipts_are_not_updated() {
+        let receipts = Receipts::new(RETENTION);
+        receipts.set_bundled([receipt_id(b"tx")], [1; 32]);
+        assert_eq!(receipts.get(&receipt_id(b"tx")), None);
+    }
+}
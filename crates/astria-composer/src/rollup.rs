@@ -1,4 +1,5 @@
-//! Parsing strings of the form `<rollup_name>::<url>`
+//! Parsing strings of the form `<rollup_name>::[<collector>::]<url>` and
+//! `<rollup_name>::<weight>`
 
 use std::{
     fmt,
@@ -10,7 +11,55 @@ use regex::Regex;
 #[derive(Debug)]
 pub(super) struct Rollup {
     rollup_name: String,
-    url: String,
+    endpoint: Endpoint,
+}
+
+/// The URL of a rollup node and the collector used to read its pending transactions.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Endpoint {
+    pub(crate) collector: CollectorKind,
+    pub(crate) url: String,
+}
+
+/// How pending transactions are read from a rollup node.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub(crate) enum CollectorKind {
+    /// Subscribe to full pending transactions via go-ethereum's `eth_subscribe`.
+    #[default]
+    Geth,
+    /// Poll the node's `txpool_content`.
+    Txpool,
+    /// Poll the node's pending block via `eth_getBlockByNumber`.
+    PendingBlock,
+    /// Subscribe to pending transaction hashes and fetch each transaction by its hash.
+    PendingHashes,
+}
+
+impl CollectorKind {
+    fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "geth" => Some(Self::Geth),
+            "txpool" => Some(Self::Txpool),
+            "pending-block" => Some(Self::PendingBlock),
+            "pending-hashes" => Some(Self::PendingHashes),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn as_str(self) -> &'static str {
+        match self {
+            Self::Geth => "geth",
+            Self::Txpool => "txpool",
+            Self::PendingBlock => "pending-block",
+            Self::PendingHashes => "pending-hashes",
+        }
+    }
+}
+
+impl fmt::Display for CollectorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(self.as_str())
+    }
 }
 
 #[derive(Debug)]
@@ -25,14 +74,66 @@ impl ParseError {
 impl fmt::Display for ParseError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.pad(
-            "invalid rollup definition, must be `<chainid>::<url>, with <chainid> being \
-             alphanumeric ascii and -",
+            "invalid rollup definition, must be `<chainid>::[<collector>::]<url>, with <chainid> \
+             being alphanumeric ascii and - and the optional <collector> one of `geth`, `txpool`, \
+             `pending-block`, or `pending-hashes`",
         )
     }
 }
 
 impl std::error::Error for ParseError {}
 
+#[derive(Debug)]
+pub struct ParseWeightError {}
+
+impl fmt::Display for ParseWeightError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(
+            "invalid rollup weight, must be `<chainid>::<weight>`, with <chainid> being \
+             alphanumeric ascii and - and <weight> a positive integer",
+        )
+    }
+}
+
+impl std::error::Error for ParseWeightError {}
+
+/// The weight of a rollup when scheduling its transactions into bundles.
+#[derive(Debug)]
+pub(super) struct RollupWeight {
+    rollup_name: String,
+    weight: u32,
+}
+
+impl RollupWeight {
+    pub(super) fn parse(from: &str) -> Result<Self, ParseWeightError> {
+        let (rollup_name, weight) = from.split_once("::").ok_or(ParseWeightError {})?;
+        if rollup_name.is_empty()
+            || !rollup_name
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-')
+        {
+            return Err(ParseWeightError {});
+        }
+        let weight = weight
+            .parse()
+            .ok()
+            .filter(|weight| *weight > 0)
+            .ok_or(ParseWeightError {})?;
+        Ok(Self {
+            rollup_name: rollup_name.to_lowercase(),
+            weight,
+        })
+    }
+
+    pub(super) fn into_parts(self) -> (String, u32) {
+        let Self {
+            rollup_name,
+            weight,
+        } = self;
+        (rollup_name, weight)
+    }
+}
+
 impl Rollup {
     pub(super) fn parse(from: &str) -> Result<Self, ParseError> {
         static ROLLUP_RE: LazyLock<Regex> = LazyLock::new(|| {
@@ -42,6 +143,9 @@ impl Rollup {
                     # lazily match all alphanumeric ascii and dash;
                     # case insignificant, but we will lowercase later
                 ::
+                (?:(?P<collector>geth|txpool|pending-block|pending-hashes)::)?
+                    # optionally match the collector reading the rollup's transactions;
+                    # defaults to geth
                 (?P<url>.+)
                     # treat all following chars as the url without any verification;
                     # if there are bad chars, the downstream URL parser should
@@ -56,19 +160,26 @@ impl Rollup {
         // accesses will always be correct because the regex will only
         // match when these capture groups match.
         let rollup_name = caps["rollup_name"].to_string().to_lowercase();
+        let collector = caps
+            .name("collector")
+            .and_then(|collector| CollectorKind::from_name(collector.as_str()))
+            .unwrap_or_default();
         let url = caps["url"].to_string();
         Ok(Self {
             rollup_name,
-            url,
+            endpoint: Endpoint {
+                collector,
+                url,
+            },
         })
     }
 
-    pub(super) fn into_parts(self) -> (String, String) {
+    pub(super) fn into_parts(self) -> (String, Endpoint) {
         let Self {
             rollup_name,
-            url,
+            endpoint,
         } = self;
-        (rollup_name, url)
+        (rollup_name, endpoint)
     }
 }
 
@@ -92,7 +203,7 @@ mod tests {
         let rollups = expect_parse_rollups("chain-1::http://some.url");
         assert_eq!(rollups.len(), 1, "\nparsed: {rollups:#?}");
         assert_eq!(rollups[0].rollup_name, "chain-1");
-        assert_eq!(rollups[0].url, "http://some.url");
+        assert_eq!(rollups[0].endpoint.url, "http://some.url");
     }
 
     #[test]
@@ -100,7 +211,7 @@ mod tests {
         let rollups = expect_parse_rollups("ChAiN-1::http://some.url");
         assert_eq!(rollups.len(), 1, "\nparsed: {rollups:#?}");
         assert_eq!(rollups[0].rollup_name, "chain-1");
-        assert_eq!(rollups[0].url, "http://some.url");
+        assert_eq!(rollups[0].endpoint.url, "http://some.url");
     }
 
     #[test]
@@ -109,11 +220,11 @@ mod tests {
             expect_parse_rollups("chain-1::http://some.url,another::ws://ws.domain,last::foo.bar");
         assert_eq!(rollups.len(), 3, "\nparsed: {rollups:#?}");
         assert_eq!(rollups[0].rollup_name, "chain-1");
-        assert_eq!(rollups[0].url, "http://some.url");
+        assert_eq!(rollups[0].endpoint.url, "http://some.url");
         assert_eq!(rollups[1].rollup_name, "another");
-        assert_eq!(rollups[1].url, "ws://ws.domain");
+        assert_eq!(rollups[1].endpoint.url, "ws://ws.domain");
         assert_eq!(rollups[2].rollup_name, "last");
-        assert_eq!(rollups[2].url, "foo.bar");
+        assert_eq!(rollups[2].endpoint.url, "foo.bar");
     }
 
     #[should_panic(expected = "rollup 'chain_1::http://some.url' should parse: ParseError")]
@@ -128,10 +239,62 @@ mod tests {
         expect_parse_rollups("chain-1:http://some.url");
     }
 
+    #[test]
+    fn parse_rollup_weight_valid() {
+        let (rollup_name, weight) = RollupWeight::parse("ChAiN-1::3").unwrap().into_parts();
+        assert_eq!(rollup_name, "chain-1");
+        assert_eq!(weight, 3);
+    }
+
+    #[test]
+    fn parse_rollup_weight_invalid() {
+        for invalid in [
+            "chain-1::0",
+            "chain-1::-1",
+            "chain-1:2",
+            "chain_1::2",
+            "::2",
+        ] {
+            assert!(
+                RollupWeight::parse(invalid).is_err(),
+                "`{invalid}` should not parse"
+            );
+        }
+    }
+
     #[test]
     fn parse_with_triple_colon_is_valid() {
         let rollups = expect_parse_rollups("chain-1:::http://some.url");
         assert_eq!(rollups[0].rollup_name, "chain-1");
-        assert_eq!(rollups[0].url, ":http://some.url");
+        assert_eq!(rollups[0].endpoint.url, ":http://some.url");
+    }
+
+    #[test]
+    fn parse_rollup_collectors() {
+        let rollups = expect_parse_rollups(
+            "a::ws://a.url,b::geth::ws://b.url,c::txpool::http://c.url,d::pending-block::http://d.\
+             url,e::pending-hashes::ws://[::1]:8546",
+        );
+        let collectors = rollups
+            .iter()
+            .map(|rollup| (rollup.endpoint.collector, rollup.endpoint.url.as_str()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            collectors,
+            [
+                (CollectorKind::Geth, "ws://a.url"),
+                (CollectorKind::Geth, "ws://b.url"),
+                (CollectorKind::Txpool, "http://c.url"),
+                (CollectorKind::PendingBlock, "http://d.url"),
+                (CollectorKind::PendingHashes, "ws://[::1]:8546"),
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_unknown_collector_is_part_of_url() {
+        let rollups = expect_parse_rollups("chain-1::erigon::http://some.url");
+        assert_eq!(rollups[0].endpoint.collector, CollectorKind::Geth);
+        assert_eq!(rollups[0].endpoint.url, "erigon::http://some.url");
     }
 }
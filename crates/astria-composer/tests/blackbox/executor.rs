@@ -19,9 +19,12 @@ use tokio::time;
 
 use crate::helper::{
     mount_broadcast_tx_sync_rollup_data_submissions_mock,
+    mount_broadcast_tx_sync_rollup_data_submissions_mock_with_expected_calls,
     signed_tx_from_request,
     spawn_composer,
+    spawn_composer_with_signing_keys,
     TEST_CHAIN_ID,
+    TEST_SIGNING_KEY,
 };
 
 /// Test to check that the executor sends a signed transaction to the sequencer after its
@@ -218,6 +221,76 @@ async fn two_rollup_data_submissions_single_bundle() {
     }
 }
 
+/// Test to check that the executor submits bundles concurrently over the nonce lanes of its
+/// signing keys.
+#[tokio::test]
+async fn bundles_are_submitted_over_separate_nonce_lanes() {
+    let test_composer = spawn_composer_with_signing_keys(
+        &["test1"],
+        None,
+        vec![],
+        true,
+        &[
+            TEST_SIGNING_KEY,
+            "0a1b2c3d4e5f60718293a4b5c6d7e8f90a1b2c3d4e5f60718293a4b5c6d7e8f9",
+        ],
+    )
+    .await;
+    let mut composer_client = GrpcCollectorServiceClient::connect(format!(
+        "http://{}",
+        test_composer.grpc_collector_addr
+    ))
+    .await
+    .unwrap();
+
+    let response_guard = mount_broadcast_tx_sync_rollup_data_submissions_mock_with_expected_calls(
+        &test_composer.sequencer,
+        2,
+    )
+    .await;
+
+    // send two sequence actions to the executor which are too large to fit in a single bundle,
+    // so that the first bundle is submitted on the first lane once the second is received, and
+    // the second bundle on the second lane once the block timer ticks
+    // the actions differ so that the second is not rejected as a duplicate of the first
+    let rollup_id = RollupId::new([0; ROLLUP_ID_LEN]);
+
+    for byte in 0..2u8 {
+        composer_client
+            .submit_rollup_transaction(SubmitRollupTransactionRequest {
+                rollup_id: Some(rollup_id.into_raw()),
+                data: vec![byte; 150_000].into(),
+            })
+            .await
+            .expect(
+                "rollup transactions should have been submitted successfully to grpc collector",
+            );
+    }
+
+    // wait for the mock sequencer to receive both signed transactions; the block timer is reset
+    // once the first submission completes
+    tokio::time::timeout(
+        Duration::from_millis(test_composer.cfg.block_time_ms.saturating_mul(2)),
+        response_guard.wait_until_satisfied(),
+    )
+    .await
+    .unwrap();
+
+    // verify the transactions were signed by different keys, each starting at its own nonce
+    let requests = response_guard.received_requests().await;
+    assert_eq!(requests.len(), 2);
+    let signed_txs: Vec<_> = requests.iter().map(signed_tx_from_request).collect();
+    assert_ne!(
+        signed_txs[0].address_bytes(),
+        signed_txs[1].address_bytes(),
+        "bundles should have been signed by different keys"
+    );
+    for signed_tx in signed_txs {
+        assert_eq!(signed_tx.nonce(), 0);
+        assert_eq!(signed_tx.actions().len(), 1);
+    }
+}
+
 /// Test to check that executor's chain ID check is properly checked against the sequencer's chain
 /// ID
 #[tokio::test]
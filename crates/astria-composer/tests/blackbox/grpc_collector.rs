@@ -3,6 +3,8 @@ use std::time::Duration;
 use astria_core::{
     generated::astria::composer::v1::{
         grpc_collector_service_client::GrpcCollectorServiceClient,
+        submission_receipt,
+        GetSubmissionReceiptRequest,
         SubmitRollupTransactionRequest,
     },
     primitive::v1::RollupId,
@@ -144,3 +146,70 @@ async fn single_rollup_tx_payload_integrity() {
          from composer",
     );
 }
+
+#[tokio::test]
+async fn duplicate_tx_is_submitted_once_and_tracked_by_receipt() {
+    let rollup_id = RollupId::from_unhashed_bytes("test1");
+    let test_composer = spawn_composer(&[], None, vec![], true).await;
+    let mock_guard =
+        mount_broadcast_tx_sync_mock(&test_composer.sequencer, vec![rollup_id], vec![0]).await;
+
+    let mut composer_client = GrpcCollectorServiceClient::connect(format!(
+        "http://{}",
+        test_composer.grpc_collector_addr
+    ))
+    .await
+    .unwrap();
+    let request = SubmitRollupTransactionRequest {
+        rollup_id: Some(rollup_id.into_raw()),
+        data: Bytes::copy_from_slice(&Transaction::default().rlp()),
+    };
+    let first = composer_client
+        .submit_rollup_transaction(request.clone())
+        .await
+        .expect("rollup transaction should have been submitted successfully to grpc collector")
+        .into_inner();
+    let second = composer_client
+        .submit_rollup_transaction(request)
+        .await
+        .expect("duplicate rollup transaction should have been accepted by grpc collector")
+        .into_inner();
+    assert!(!first.duplicate);
+    assert!(second.duplicate);
+    assert_eq!(first.receipt_id, second.receipt_id);
+
+    tokio::time::timeout(
+        Duration::from_millis(test_composer.cfg.block_time_ms * 2),
+        mock_guard.wait_until_satisfied(),
+    )
+    .await
+    .expect("mocked sequencer should have received exactly one broadcast message from composer");
+
+    // the mocked sequencer does not serve the submitted transaction, so it stays bundled
+    tokio::time::timeout(Duration::from_secs(1), async {
+        loop {
+            let receipt = composer_client
+                .get_submission_receipt(GetSubmissionReceiptRequest {
+                    receipt_id: first.receipt_id.clone(),
+                })
+                .await
+                .expect("receipt of submitted transaction should be retained")
+                .into_inner();
+            if let Some(submission_receipt::Status::Bundled(bundled)) = receipt.status {
+                assert_eq!(bundled.sequencer_transaction_hash.len(), 32);
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+    })
+    .await
+    .expect("receipt should have been marked as bundled");
+
+    let error = composer_client
+        .get_submission_receipt(GetSubmissionReceiptRequest {
+            receipt_id: vec![0; 32].into(),
+        })
+        .await
+        .expect_err("unknown receipt should not be found");
+    assert_eq!(error.code(), tonic::Code::NotFound);
+}
@@ -68,6 +68,10 @@ pub mod mock_grpc_sequencer;
 
 pub const TEST_CHAIN_ID: &str = "test-chain-1";
 
+/// The hex-encoded private key composer signs sequencer transactions with by default.
+pub const TEST_SIGNING_KEY: &str =
+    "2bd806c97f0e00af1a1fc3328fa763a9269723c8db8fac4f93af71db186d6e90";
+
 static TELEMETRY: LazyLock<()> = LazyLock::new(|| {
     // This config can be meaningless - it's only used inside `try_init` to init the metrics, but we
     // haven't configured telemetry to provide metrics here.
@@ -78,6 +82,7 @@ static TELEMETRY: LazyLock<()> = LazyLock::new(|| {
         sequencer_grpc_endpoint: String::new(),
         sequencer_chain_id: String::new(),
         rollups: String::new(),
+        rollup_weights: String::new(),
         private_key_file: String::new(),
         sequencer_address_prefix: String::new(),
         block_time_ms: 0,
@@ -88,6 +93,7 @@ static TELEMETRY: LazyLock<()> = LazyLock::new(|| {
         no_metrics: false,
         metrics_http_listener_addr: String::new(),
         grpc_addr: SocketAddr::new(IpAddr::from([0, 0, 0, 0]), 0),
+        receipt_retention_ms: 0,
         fee_asset: Denom::IbcPrefixed(IbcPrefixed::new([0; 32])),
     };
     if std::env::var_os("TEST_LOG").is_some() {
@@ -127,6 +133,70 @@ pub async fn spawn_composer(
     sequencer_chain_id: Option<&str>,
     txs_in_pool: Vec<EthersTransaction>,
     loop_until_ready: bool,
+) -> TestComposer {
+    spawn_composer_with_signing_keys(
+        rollup_ids,
+        sequencer_chain_id,
+        txs_in_pool,
+        loop_until_ready,
+        &[TEST_SIGNING_KEY],
+    )
+    .await
+}
+
+/// Spawns composer in a test environment, signing sequencer transactions with the hex-encoded
+/// `signing_keys`, one nonce lane per key.
+///
+/// # Panics
+/// There is no explicit error handling in favour of panicking loudly
+/// and early.
+pub async fn spawn_composer_with_signing_keys(
+    rollup_ids: &[&str],
+    sequencer_chain_id: Option<&str>,
+    txs_in_pool: Vec<EthersTransaction>,
+    loop_until_ready: bool,
+    signing_keys: &[&str],
+) -> TestComposer {
+    spawn_composer_with_config(
+        rollup_ids,
+        None,
+        sequencer_chain_id,
+        txs_in_pool,
+        loop_until_ready,
+        signing_keys,
+    )
+    .await
+}
+
+/// Spawns composer in a test environment, reading the pending transactions of each rollup node
+/// with the given `collector`.
+///
+/// # Panics
+/// There is no explicit error handling in favour of panicking loudly
+/// and early.
+pub async fn spawn_composer_with_collector(
+    rollup_ids: &[&str],
+    collector: &str,
+    txs_in_pool: Vec<EthersTransaction>,
+) -> TestComposer {
+    spawn_composer_with_config(
+        rollup_ids,
+        Some(collector),
+        None,
+        txs_in_pool,
+        true,
+        &[TEST_SIGNING_KEY],
+    )
+    .await
+}
+
+async fn spawn_composer_with_config(
+    rollup_ids: &[&str],
+    collector: Option<&str>,
+    sequencer_chain_id: Option<&str>,
+    txs_in_pool: Vec<EthersTransaction>,
+    loop_until_ready: bool,
+    signing_keys: &[&str],
 ) -> TestComposer {
     LazyLock::force(&TELEMETRY);
 
@@ -142,23 +212,35 @@ pub async fn spawn_composer(
         let geth = Geth::spawn_with_pending_txs(pending_map).await;
         let execution_url = format!("ws://{}", geth.local_addr());
         rollup_nodes.insert((*id).to_string(), geth);
-        rollups.push_str(&format!("{id}::{execution_url},"));
+        match collector {
+            Some(collector) => rollups.push_str(&format!("{id}::{collector}::{execution_url},")),
+            None => rollups.push_str(&format!("{id}::{execution_url},")),
+        }
     }
     let sequencer = mock_abci_sequencer::start(sequencer_chain_id).await;
     let grpc_server = MockGrpcSequencer::spawn().await;
     let sequencer_url = sequencer.uri();
-    let keyfile = NamedTempFile::new().unwrap();
-    (&keyfile)
-        .write_all("2bd806c97f0e00af1a1fc3328fa763a9269723c8db8fac4f93af71db186d6e90".as_bytes())
-        .unwrap();
+    let keyfiles: Vec<_> = signing_keys
+        .iter()
+        .map(|key| {
+            let keyfile = NamedTempFile::new().unwrap();
+            (&keyfile).write_all(key.as_bytes()).unwrap();
+            keyfile
+        })
+        .collect();
     let config = Config {
         log: String::new(),
         api_listen_addr: "127.0.0.1:0".parse().unwrap(),
         sequencer_chain_id: TEST_CHAIN_ID.to_string(),
         rollups,
+        rollup_weights: String::new(),
         sequencer_abci_endpoint: sequencer_url.to_string(),
         sequencer_grpc_endpoint: format!("http://{}", grpc_server.local_addr),
-        private_key_file: keyfile.path().to_string_lossy().to_string(),
+        private_key_file: keyfiles
+            .iter()
+            .map(|keyfile| keyfile.path().to_string_lossy())
+            .collect::<Vec<_>>()
+            .join(","),
         sequencer_address_prefix: "astria".into(),
         block_time_ms: 2000,
         max_bytes_per_bundle: 200_000,
@@ -168,6 +250,7 @@ pub async fn spawn_composer(
         no_metrics: true,
         metrics_http_listener_addr: String::new(),
         grpc_addr: "127.0.0.1:0".parse().unwrap(),
+        receipt_retention_ms: 600_000,
         fee_asset: "nria".parse().unwrap(),
     };
 
@@ -179,14 +262,16 @@ pub async fn spawn_composer(
 
     let expected_get_nonce_requests = loop_until_ready.into();
 
-    // prepare get nonce response
-    grpc_server
-        .mount_pending_nonce_response(
-            0,
-            "startup::wait_for_mempool()",
-            expected_get_nonce_requests,
-        )
-        .await;
+    // prepare get nonce response, one per nonce lane
+    for _ in signing_keys {
+        grpc_server
+            .mount_pending_nonce_response(
+                0,
+                "startup::wait_for_mempool()",
+                expected_get_nonce_requests,
+            )
+            .await;
+    }
 
     let (composer_addr, grpc_collector_addr, composer_handle) = {
         let composer = Composer::from_config(&config, metrics).await.unwrap();
@@ -416,6 +501,15 @@ pub async fn mount_broadcast_tx_sync_nonce_taken_mock(
 /// `expected_rollup_ids` and `expected_nonces`.
 pub async fn mount_broadcast_tx_sync_rollup_data_submissions_mock(
     server: &MockServer,
+) -> MockGuard {
+    mount_broadcast_tx_sync_rollup_data_submissions_mock_with_expected_calls(server, 1).await
+}
+
+/// Like [`mount_broadcast_tx_sync_rollup_data_submissions_mock`], but expecting
+/// `expected_calls` transactions.
+pub async fn mount_broadcast_tx_sync_rollup_data_submissions_mock_with_expected_calls(
+    server: &MockServer,
+    expected_calls: u64,
 ) -> MockGuard {
     let matcher = move |request: &Request| {
         let signed_tx = signed_tx_from_request(request);
@@ -440,8 +534,8 @@ pub async fn mount_broadcast_tx_sync_rollup_data_submissions_mock(
 
     Mock::given(matcher)
         .respond_with(ResponseTemplate::new(200).set_body_json(&jsonrpc_rsp))
-        .up_to_n_times(1)
-        .expect(1)
+        .up_to_n_times(expected_calls)
+        .expect(expected_calls)
         .mount_as_scoped(server)
         .await
 }
@@ -0,0 +1,53 @@
+use std::{
+    collections::HashSet,
+    time::Duration,
+};
+
+use astria_core::primitive::v1::RollupId;
+use ethers::types::Transaction;
+
+use crate::helper::{
+    mount_broadcast_tx_sync_mock,
+    spawn_composer_with_collector,
+    TEST_ETH_TX_JSON,
+};
+
+#[tokio::test]
+async fn txpool_collector_submits_each_pending_tx_once() {
+    // Spawn a composer polling the tx pool of a mock rollup node which contains a single
+    // transaction. Initial nonce is 0
+    let tx: Transaction = serde_json::from_str(TEST_ETH_TX_JSON).unwrap();
+    let test_composer = spawn_composer_with_collector(&["test1"], "txpool", vec![tx]).await;
+
+    let expected_rollup_ids = vec![RollupId::from_unhashed_bytes("test1")];
+    let mock_guard =
+        mount_broadcast_tx_sync_mock(&test_composer.sequencer, expected_rollup_ids, vec![0]).await;
+
+    // wait for 1 sequencer block time to make sure the bundle is preempted
+    tokio::time::timeout(
+        Duration::from_millis(test_composer.cfg.block_time_ms + 1000),
+        mock_guard.wait_until_satisfied(),
+    )
+    .await
+    .expect("mocked sequencer should have received a broadcast message from composer");
+
+    // the transaction remains in the polled tx pool, but must not be submitted again. Retries
+    // of the same sequencer transaction are not counted.
+    tokio::time::sleep(Duration::from_millis(test_composer.cfg.block_time_ms * 2)).await;
+    let broadcast_txs = test_composer
+        .sequencer
+        .received_requests()
+        .await
+        .unwrap()
+        .into_iter()
+        .filter_map(|request| {
+            let body: serde_json::Value = serde_json::from_slice(&request.body).unwrap();
+            (body["method"] == "broadcast_tx_sync").then(|| body["params"]["tx"].clone())
+        })
+        .collect::<HashSet<_>>();
+    assert_eq!(
+        broadcast_txs.len(),
+        1,
+        "the pending transaction should have been submitted in exactly one sequencer transaction"
+    );
+}
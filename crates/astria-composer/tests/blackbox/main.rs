@@ -3,3 +3,4 @@ mod executor;
 pub mod geth_collector;
 mod grpc_collector;
 pub mod helper;
+mod json_rpc_collector;
@@ -0,0 +1,106 @@
+//! Conductor's HTTP API, reporting the status of the executor.
+use std::{
+    future::{
+        Future,
+        IntoFuture as _,
+    },
+    net::SocketAddr,
+};
+
+use astria_eyre::eyre::{
+    self,
+    WrapErr as _,
+};
+use axum::{
+    extract::State,
+    routing::get,
+    Json,
+    Router,
+};
+use futures::FutureExt as _;
+use serde::Serialize;
+use tokio::sync::watch;
+use tokio_util::sync::CancellationToken;
+use tracing::instrument;
+
+use crate::executor::Status;
+
+/// Binds the API server to `socket_addr`.
+///
+/// The returned future serves requests until `shutdown_token` is cancelled. Binding happens
+/// synchronously so that conductor fails to start if the address is unavailable.
+pub(crate) fn serve(
+    socket_addr: &str,
+    status: watch::Receiver<Status>,
+    shutdown_token: CancellationToken,
+) -> eyre::Result<Serve> {
+    let app = Router::new()
+        .route("/status", get(get_status))
+        .with_state(status);
+    let listener = std::net::TcpListener::bind(socket_addr)
+        .wrap_err_with(|| format!("failed to bind TCP socket at `{socket_addr}`"))?;
+    listener
+        .set_nonblocking(true)
+        .wrap_err("failed to set TCP socket to non-blocking mode")?;
+    let listener = tokio::net::TcpListener::from_std(listener)
+        .wrap_err("failed to register TCP socket with the tokio runtime")?;
+    let serve = axum::serve(listener, app).with_graceful_shutdown(shutdown_token.cancelled_owned());
+    let local_addr = serve
+        .local_addr()
+        .wrap_err("bound TCP listener failed to yield local address")?;
+    Ok(Serve {
+        local_addr,
+        fut: serve.into_future().boxed(),
+    })
+}
+
+/// A wrapper around a type-erased [`axum::serve::Serve`] future.
+pub(crate) struct Serve {
+    local_addr: SocketAddr,
+    fut: futures::future::BoxFuture<'static, std::io::Result<()>>,
+}
+
+impl Serve {
+    pub(crate) fn local_addr(&self) -> SocketAddr {
+        self.local_addr
+    }
+}
+
+impl Future for Serve {
+    type Output = std::io::Result<()>;
+
+    fn poll(
+        mut self: std::pin::Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<Self::Output> {
+        self.fut.as_mut().poll(cx)
+    }
+}
+
+/// The response body of `GET /status`.
+#[derive(Debug, Serialize)]
+struct StatusBody {
+    soft_number: Option<u64>,
+    firm_number: Option<u64>,
+    soft_sequencer_height: Option<u64>,
+    firm_sequencer_height: Option<u64>,
+    spread: u64,
+    firm_lag_ms: u128,
+    firm_lag_exceeded: bool,
+    soft_execution_halted: bool,
+}
+
+#[instrument(skip_all)]
+async fn get_status(State(status): State<watch::Receiver<Status>>) -> Json<StatusBody> {
+    let status = status.borrow();
+    Json(StatusBody {
+        soft_number: status.soft_number,
+        firm_number: status.firm_number,
+        soft_sequencer_height: status.soft_sequencer_height,
+        firm_sequencer_height: status.firm_sequencer_height,
+        spread: status.spread,
+        firm_lag_ms: status.firm_lag().as_millis(),
+        firm_lag_exceeded: status.is_firm_lag_exceeded,
+        soft_execution_halted: status.is_soft_execution_halted,
+    })
+}
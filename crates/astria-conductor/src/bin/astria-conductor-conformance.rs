@@ -0,0 +1,75 @@
+use std::{
+    process::ExitCode,
+    time::Duration,
+};
+
+use astria_conductor::conformance;
+use clap::Parser;
+
+/// Checks a rollup's implementation of `astria.execution.v2.ExecutionService` against the
+/// execution API's contract.
+///
+/// The checks execute blocks and update commitments on the rollup, so they must only be run
+/// against a disposable rollup node.
+#[derive(Debug, Parser)]
+#[command(version, about, long_about)]
+struct Args {
+    /// The URL of the rollup's execution service.
+    #[arg(
+        long,
+        value_name = "URL",
+        env = "ASTRIA_CONDUCTOR_EXECUTION_RPC_URL",
+        default_value = "http://127.0.0.1:50051"
+    )]
+    execution_rpc_url: String,
+
+    /// The maximum number of blocks to execute as soft commitments.
+    #[arg(long, value_name = "COUNT", default_value_t = 3)]
+    blocks: u64,
+
+    /// The timeout in milliseconds after which a request to the execution service fails.
+    #[arg(long, value_name = "MILLISECONDS", default_value_t = 5000)]
+    request_timeout_ms: u64,
+
+    /// Print the report as JSON.
+    #[arg(long)]
+    json: bool,
+}
+
+#[tokio::main(flavor = "current_thread")]
+async fn main() -> ExitCode {
+    astria_eyre::install()
+        .expect("the astria eyre install hook must be called before eyre reports are constructed");
+    let args = Args::parse();
+
+    let harness = match (conformance::Builder {
+        execution_rpc_url: args.execution_rpc_url,
+        blocks: args.blocks,
+        request_timeout: Duration::from_millis(args.request_timeout_ms),
+    })
+    .build()
+    {
+        Ok(harness) => harness,
+        Err(error) => {
+            eprintln!("failed to construct conformance harness:\n{error:?}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let report = harness.run().await;
+    if args.json {
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&report)
+                .expect("the report contains only strings and serializable enums")
+        );
+    } else {
+        print!("{report}");
+    }
+
+    if report.is_conformant() {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
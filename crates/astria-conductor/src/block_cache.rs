@@ -75,6 +75,11 @@ impl<T> BlockCache<T> {
         }
     }
 
+    /// Returns the cached blocks in order of their heights.
+    pub(crate) fn blocks(&self) -> impl Iterator<Item = &T> {
+        self.inner.values()
+    }
+
     /// Returns the next block height that will be popped from the cache.
     ///
     /// Note, this does not pop the next block, nor does it advance the height.
@@ -1,6 +1,10 @@
 //! Boilerplate to construct a [`super::Reader`] via a type-state builder.
 
-use std::time::Duration;
+use std::{
+    path::PathBuf,
+    sync::Arc,
+    time::Duration,
+};
 
 use astria_eyre::eyre::{
     self,
@@ -11,11 +15,17 @@ use tendermint_rpc::HttpClient as SequencerClient;
 use tokio_util::sync::CancellationToken;
 
 use super::{
+    data_availability::{
+        DaClient,
+        LocalDaClient,
+    },
     Reader,
     ReconstructedBlock,
 };
 use crate::{
+    config::DaBackend,
     metrics::Metrics,
+    persisted_state::StateFile,
     state::StateReceiver,
 };
 
@@ -23,10 +33,13 @@ pub(crate) struct Builder {
     pub(crate) celestia_block_time: Duration,
     pub(crate) celestia_http_endpoint: String,
     pub(crate) celestia_token: Option<String>,
+    pub(crate) da_backend: DaBackend,
+    pub(crate) local_da_path: PathBuf,
     pub(crate) firm_blocks: tokio::sync::mpsc::Sender<Box<ReconstructedBlock>>,
     pub(crate) rollup_state: StateReceiver,
     pub(crate) sequencer_cometbft_client: SequencerClient,
     pub(crate) sequencer_requests_per_second: u32,
+    pub(crate) state_file: Option<StateFile>,
     pub(crate) shutdown: CancellationToken,
     pub(crate) metrics: &'static Metrics,
 }
@@ -38,24 +51,33 @@ impl Builder {
             celestia_block_time,
             celestia_http_endpoint,
             celestia_token,
+            da_backend,
+            local_da_path,
             sequencer_cometbft_client,
             sequencer_requests_per_second,
+            state_file,
             shutdown,
             metrics,
             firm_blocks,
             rollup_state,
         } = self;
 
-        let celestia_client = create_celestia_client(celestia_http_endpoint, celestia_token)
-            .wrap_err("failed initializing client for Celestia HTTP RPC")?;
+        let da_client: Arc<dyn DaClient> = match da_backend {
+            DaBackend::Celestia => Arc::new(
+                create_celestia_client(celestia_http_endpoint, celestia_token)
+                    .wrap_err("failed initializing client for Celestia HTTP RPC")?,
+            ),
+            DaBackend::Local => Arc::new(LocalDaClient::new(local_da_path)),
+        };
 
         Ok(Reader {
             celestia_block_time,
-            celestia_client,
+            da_client,
             firm_blocks,
             rollup_state,
             sequencer_cometbft_client,
             sequencer_requests_per_second,
+            state_file,
             shutdown,
             metrics,
         })
@@ -0,0 +1,100 @@
+//! The abstraction over the data availability layer from which the firm blocks are read.
+//!
+//! [`super::Reader`] reads either from a Celestia node via its JSON-RPC API, or from a local
+//! directory written by sequencer-relayer via [`LocalDaClient`].
+use std::{
+    io,
+    path::PathBuf,
+};
+
+use async_trait::async_trait;
+use celestia_rpc::{
+    BlobClient as _,
+    HeaderClient as _,
+};
+use celestia_types::{
+    nmt::Namespace,
+    Blob,
+};
+use jsonrpsee::http_client::HttpClient as CelestiaClient;
+
+pub(super) use super::local_client::LocalDaClient;
+
+/// A client reading blobs from a data availability layer.
+#[async_trait]
+pub(super) trait DaClient: Send + Sync + 'static {
+    /// Returns the chain ID of the data availability network.
+    async fn chain_id(&self) -> Result<String, DaClientError>;
+
+    /// Returns the latest height of the data availability network.
+    async fn latest_height(&self) -> Result<u64, DaClientError>;
+
+    /// Returns all blobs at `height` under `namespace`.
+    async fn fetch_blobs(
+        &self,
+        height: u64,
+        namespace: Namespace,
+    ) -> Result<Vec<Blob>, DaClientError>;
+}
+
+/// An error returned by a [`DaClient`].
+#[derive(Debug, thiserror::Error)]
+pub(super) enum DaClientError {
+    #[error(transparent)]
+    Celestia(#[from] jsonrpsee::core::ClientError),
+    #[error("failed to access `{}` in the local data availability store", path.display())]
+    LocalIo { path: PathBuf, source: io::Error },
+    #[error("failed to parse `{height}` read from `{}` as a height", path.display())]
+    LocalInvalidHeight { path: PathBuf, height: String },
+    #[error("failed to construct blob from `{}`", path.display())]
+    LocalInvalidBlob {
+        path: PathBuf,
+        source: celestia_types::Error,
+    },
+}
+
+impl DaClientError {
+    /// Returns whether the request that failed with this error should be retried.
+    pub(super) fn is_transient(&self) -> bool {
+        match self {
+            Self::Celestia(error) => matches!(
+                error,
+                jsonrpsee::core::ClientError::Transport(_)
+                    | jsonrpsee::core::ClientError::RequestTimeout,
+            ),
+            Self::LocalIo {
+                ..
+            } => true,
+            Self::LocalInvalidHeight {
+                ..
+            }
+            | Self::LocalInvalidBlob {
+                ..
+            } => false,
+        }
+    }
+}
+
+#[async_trait]
+impl DaClient for CelestiaClient {
+    async fn chain_id(&self) -> Result<String, DaClientError> {
+        let network_head = self.header_network_head().await?;
+        Ok(network_head.chain_id().to_string())
+    }
+
+    async fn latest_height(&self) -> Result<u64, DaClientError> {
+        let network_head = self.header_network_head().await?;
+        Ok(network_head.height().value())
+    }
+
+    async fn fetch_blobs(
+        &self,
+        height: u64,
+        namespace: Namespace,
+    ) -> Result<Vec<Blob>, DaClientError> {
+        Ok(self
+            .blob_get_all(height, &[namespace])
+            .await?
+            .unwrap_or_default())
+    }
+}
@@ -1,5 +1,8 @@
 use std::{
-    sync::atomic::AtomicU32,
+    sync::{
+        atomic::AtomicU32,
+        Arc,
+    },
     time::Duration,
 };
 
@@ -11,10 +14,6 @@ use celestia_types::{
     nmt::Namespace,
     Blob,
 };
-use jsonrpsee::{
-    self,
-    http_client::HttpClient as CelestiaClient,
-};
 use telemetry::display::base64;
 use tokio::try_join;
 use tracing::{
@@ -26,6 +25,10 @@ use tryhard::{
     RetryPolicy,
 };
 
+use super::data_availability::{
+    DaClient,
+    DaClientError,
+};
 use crate::metrics::Metrics;
 
 pub(super) struct RawBlobs {
@@ -44,7 +47,7 @@ impl RawBlobs {
     }
 }
 
-/// Fetch Celestia blobs at `celestia_height` matching `sequencer_namespace` and `rollup_namespace`.
+/// Fetch blobs at `celestia_height` matching `sequencer_namespace` and `rollup_namespace`.
 ///
 /// Retries indefinitely if the underlying transport failed. Immediately returns with an error in
 /// all other cases.
@@ -55,7 +58,7 @@ impl RawBlobs {
     err,
 ))]
 pub(super) async fn fetch_new_blobs(
-    client: CelestiaClient,
+    client: Arc<dyn DaClient>,
     celestia_height: u64,
     rollup_namespace: Namespace,
     sequencer_namespace: Namespace,
@@ -88,19 +91,17 @@ pub(super) async fn fetch_new_blobs(
 
 #[instrument(skip_all, err)]
 async fn fetch_blobs_with_retry(
-    client: CelestiaClient,
+    client: Arc<dyn DaClient>,
     height: u64,
     namespace: Namespace,
     metrics: &'static Metrics,
 ) -> eyre::Result<Vec<Blob>> {
-    use celestia_rpc::BlobClient as _;
-
     let number_attempts = AtomicU32::new(0);
     let retry_config = tryhard::RetryFutureConfig::new(u32::MAX)
         .custom_backoff(FetchBlobsRetryStrategy::new(Duration::from_millis(100)))
         .max_delay(Duration::from_secs(20))
         .on_retry(
-            |attempt: u32, next_delay: Option<Duration>, error: &jsonrpsee::core::ClientError| {
+            |attempt: u32, next_delay: Option<Duration>, error: &DaClientError| {
                 number_attempts.store(attempt, std::sync::atomic::Ordering::Relaxed);
                 let wait_duration = next_delay
                     .map(telemetry::display::format_duration)
@@ -118,13 +119,7 @@ async fn fetch_blobs_with_retry(
 
     tryhard::retry_fn(move || {
         let client = client.clone();
-        async move {
-            match client.blob_get_all(height, &[namespace]).await {
-                Ok(Some(blobs)) => Ok(blobs),
-                Ok(None) => Ok(vec![]),
-                Err(err) => Err(err),
-            }
-        }
+        async move { client.fetch_blobs(height, namespace).await }
     })
     .with_config(retry_config)
     .await
@@ -143,11 +138,11 @@ impl FetchBlobsRetryStrategy {
     }
 }
 
-impl<'a> BackoffStrategy<'a, jsonrpsee::core::ClientError> for FetchBlobsRetryStrategy {
+impl<'a> BackoffStrategy<'a, DaClientError> for FetchBlobsRetryStrategy {
     type Output = RetryPolicy;
 
-    fn delay(&mut self, _attempt: u32, error: &'a jsonrpsee::core::ClientError) -> Self::Output {
-        if should_retry(error) {
+    fn delay(&mut self, _attempt: u32, error: &'a DaClientError) -> Self::Output {
+        if error.is_transient() {
             let prev_delay = self.delay;
             self.delay = self.delay.saturating_mul(2);
             RetryPolicy::Delay(prev_delay)
@@ -156,10 +151,3 @@ impl<'a> BackoffStrategy<'a, jsonrpsee::core::ClientError> for FetchBlobsRetrySt
         }
     }
 }
-
-fn should_retry(error: &jsonrpsee::core::ClientError) -> bool {
-    matches!(
-        error,
-        jsonrpsee::core::ClientError::Transport(_) | jsonrpsee::core::ClientError::RequestTimeout,
-    )
-}
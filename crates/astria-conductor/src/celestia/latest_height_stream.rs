@@ -1,5 +1,6 @@
 use std::{
     pin::Pin,
+    sync::Arc,
     time::Duration,
 };
 
@@ -7,28 +8,27 @@ use astria_eyre::eyre::{
     Result,
     WrapErr as _,
 };
-use celestia_rpc::HeaderClient as _;
 use futures::{
     Future,
     FutureExt as _,
     Stream,
     StreamExt as _,
 };
-use jsonrpsee::http_client::HttpClient;
 use tokio_stream::wrappers::IntervalStream;
 
+use super::data_availability::DaClient;
+
 pub(super) fn stream_latest_heights(
-    client: HttpClient,
+    client: Arc<dyn DaClient>,
     poll_period: Duration,
 ) -> LatestHeightStream {
     let f = Box::new(move |_: tokio::time::Instant| {
         let client = client.clone();
         async move {
             client
-                .header_network_head()
+                .latest_height()
                 .await
                 .wrap_err("failed to fetch network head")
-                .map(|header| header.height().value())
         }
         .boxed()
     });
@@ -0,0 +1,135 @@
+//! A data availability client reading blobs from a local directory written by sequencer-relayer.
+//!
+//! The directory follows the layout described in [`astria_core::celestia::local`].
+use std::{
+    io,
+    path::{
+        Path,
+        PathBuf,
+    },
+};
+
+use astria_core::celestia::local;
+use async_trait::async_trait;
+use celestia_types::{
+    nmt::Namespace,
+    AppVersion,
+    Blob,
+};
+
+use super::data_availability::{
+    DaClient,
+    DaClientError,
+};
+
+/// A client reading blobs from a local directory.
+#[derive(Clone, Debug)]
+pub(crate) struct LocalDaClient {
+    /// The root directory of the local data availability store.
+    root: PathBuf,
+}
+
+impl LocalDaClient {
+    pub(super) fn new(root: PathBuf) -> Self {
+        Self {
+            root,
+        }
+    }
+}
+
+#[async_trait]
+impl DaClient for LocalDaClient {
+    async fn chain_id(&self) -> Result<String, DaClientError> {
+        read_to_string(&local::chain_id_path(&self.root)).await
+    }
+
+    /// Returns the latest height at which blobs were stored, or 0 if none were.
+    async fn latest_height(&self) -> Result<u64, DaClientError> {
+        let path = local::latest_height_path(&self.root);
+        match read_to_string(&path).await {
+            Ok(height) => height
+                .trim()
+                .parse()
+                .map_err(|_| DaClientError::LocalInvalidHeight {
+                    path,
+                    height,
+                }),
+            Err(DaClientError::LocalIo {
+                source, ..
+            }) if source.kind() == io::ErrorKind::NotFound => Ok(0),
+            Err(error) => Err(error),
+        }
+    }
+
+    async fn fetch_blobs(
+        &self,
+        height: u64,
+        namespace: Namespace,
+    ) -> Result<Vec<Blob>, DaClientError> {
+        let dir = local::blobs_dir(&self.root, &namespace, height);
+        let mut blobs = Vec::new();
+        loop {
+            let path = dir.join(blobs.len().to_string());
+            let data = match tokio::fs::read(&path).await {
+                Ok(data) => data,
+                Err(error) if error.kind() == io::ErrorKind::NotFound => break,
+                Err(source) => {
+                    return Err(DaClientError::LocalIo {
+                        path,
+                        source,
+                    });
+                }
+            };
+            let blob = Blob::new(namespace, data, AppVersion::V3).map_err(|source| {
+                DaClientError::LocalInvalidBlob {
+                    path,
+                    source,
+                }
+            })?;
+            blobs.push(blob);
+        }
+        Ok(blobs)
+    }
+}
+
+async fn read_to_string(path: &Path) -> Result<String, DaClientError> {
+    tokio::fs::read_to_string(path)
+        .await
+        .map_err(|source| DaClientError::LocalIo {
+            path: path.to_path_buf(),
+            source,
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn should_read_blobs_in_order() {
+        let root = tempfile::tempdir().unwrap();
+        let namespace = Namespace::const_v0([1; 10]);
+        let dir = local::blobs_dir(root.path(), &namespace, 3);
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("0"), b"first").unwrap();
+        std::fs::write(dir.join("1"), b"second").unwrap();
+        std::fs::write(local::latest_height_path(root.path()), "3").unwrap();
+
+        let client = LocalDaClient::new(root.path().to_path_buf());
+        assert_eq!(client.latest_height().await.unwrap(), 3);
+        let blobs = client.fetch_blobs(3, namespace).await.unwrap();
+        assert_eq!(
+            blobs.iter().map(|blob| &blob.data[..]).collect::<Vec<_>>(),
+            [&b"first"[..], &b"second"[..]],
+        );
+        assert!(client.fetch_blobs(2, namespace).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn latest_height_should_be_zero_for_empty_store() {
+        let root = tempfile::tempdir().unwrap();
+        let client = LocalDaClient::new(root.path().to_path_buf());
+        assert_eq!(client.latest_height().await.unwrap(), 0);
+        assert!(client.chain_id().await.unwrap_err().is_transient());
+    }
+}
@@ -1,5 +1,9 @@
 use std::{
-    cmp::max,
+    cmp::{
+        max,
+        min,
+    },
+    collections::BTreeMap,
     sync::Arc,
     time::Duration,
 };
@@ -19,7 +23,6 @@ use astria_eyre::eyre::{
     WrapErr as _,
 };
 use bytes::Bytes;
-use celestia_rpc::HeaderClient as _;
 use celestia_types::nmt::Namespace;
 use futures::{
     future::{
@@ -29,7 +32,6 @@ use futures::{
     },
     FutureExt as _,
 };
-use jsonrpsee::http_client::HttpClient as CelestiaClient;
 use sequencer_client::{
     tendermint,
     tendermint::block::Height as SequencerHeight,
@@ -44,6 +46,11 @@ use tokio::{
     select,
     sync::mpsc,
     task::spawn_blocking,
+    time::{
+        interval,
+        Interval,
+        MissedTickBehavior,
+    },
     try_join,
 };
 use tokio_stream::StreamExt as _;
@@ -65,6 +72,11 @@ use tracing::{
 use crate::{
     block_cache::GetSequencerHeight,
     metrics::Metrics,
+    persisted_state::{
+        FirmProgress,
+        PersistedState,
+        StateFile,
+    },
     state::StateReceiver,
     utils::flatten,
 };
@@ -72,8 +84,10 @@ use crate::{
 mod block_verifier;
 mod builder;
 mod convert;
+mod data_availability;
 mod fetch;
 mod latest_height_stream;
+mod local_client;
 mod reconstruct;
 mod reporting;
 mod verify;
@@ -85,6 +99,10 @@ use reporting::ReportReconstructedBlocks;
 use self::{
     block_verifier::ensure_commit_has_quorum,
     convert::decode_raw_blobs,
+    data_availability::{
+        DaClient,
+        DaClientError,
+    },
     fetch::fetch_new_blobs,
     latest_height_stream::stream_latest_heights,
     reconstruct::reconstruct_blocks_from_verified_blobs,
@@ -131,8 +149,8 @@ pub(super) struct ReconstructedBlocks {
 pub(crate) struct Reader {
     celestia_block_time: Duration,
 
-    /// Client to fetch heights and blocks from Celestia.
-    celestia_client: CelestiaClient,
+    /// Client to fetch heights and blocks from the data availability layer.
+    da_client: Arc<dyn DaClient>,
 
     /// The channel to forward firm blocks to the executor.
     firm_blocks: mpsc::Sender<Box<ReconstructedBlock>>,
@@ -147,6 +165,9 @@ pub(crate) struct Reader {
     /// (usually to verify block data retrieved from Celestia blobs).
     sequencer_requests_per_second: u32,
 
+    /// The file to which the reader's progress is persisted, if any.
+    state_file: Option<StateFile>,
+
     /// Token to listen for Conductor being shut down.
     shutdown: CancellationToken,
 
@@ -168,7 +189,12 @@ impl Reader {
             }
         );
 
-        RunningReader::from_parts(self, sequencer_chain_id)
+        let persisted_state = match &self.state_file {
+            Some(state_file) => state_file.persisted_state().await,
+            None => None,
+        };
+
+        RunningReader::from_parts(self, sequencer_chain_id, persisted_state)
             .wrap_err("failed entering run loop")?
             .run_until_stopped()
             .await
@@ -178,7 +204,7 @@ impl Reader {
     async fn initialize(&mut self) -> eyre::Result<tendermint::chain::Id> {
         let expected_celestia_chain_id = self.rollup_state.celestia_chain_id();
         let validate_celestia_chain_id = async {
-            let actual_celestia_chain_id = get_celestia_chain_id(&self.celestia_client)
+            let actual_celestia_chain_id = get_celestia_chain_id(&*self.da_client)
                 .await
                 .wrap_err("failed to fetch Celestia chain ID")?;
             ensure!(
@@ -214,14 +240,12 @@ impl Reader {
 }
 
 #[instrument(skip_all, err, ret(Display))]
-async fn get_celestia_chain_id(
-    celestia_client: &CelestiaClient,
-) -> eyre::Result<tendermint::chain::Id> {
+async fn get_celestia_chain_id(da_client: &dyn DaClient) -> eyre::Result<String> {
     let retry_config = tryhard::RetryFutureConfig::new(u32::MAX)
         .exponential_backoff(Duration::from_millis(100))
         .max_delay(Duration::from_secs(20))
         .on_retry(
-            |attempt: u32, next_delay: Option<Duration>, error: &jsonrpsee::core::ClientError| {
+            |attempt: u32, next_delay: Option<Duration>, error: &DaClientError| {
                 let wait_duration = next_delay
                     .map(telemetry::display::format_duration)
                     .map(tracing::field::display);
@@ -234,10 +258,10 @@ async fn get_celestia_chain_id(
                 futures::future::ready(())
             },
         );
-    let network_head = tryhard::retry_fn(|| celestia_client.header_network_head())
+    let chain_id = tryhard::retry_fn(|| da_client.chain_id())
         .with_config(retry_config)
         .await?;
-    Ok(network_head.chain_id().clone())
+    Ok(chain_id)
 }
 
 struct RunningReader {
@@ -245,8 +269,8 @@ struct RunningReader {
 
     blob_verifier: Arc<BlobVerifier>,
 
-    // Client to fetch heights and blocks from Celestia.
-    celestia_client: CelestiaClient,
+    // Client to fetch heights and blocks from the data availability layer.
+    da_client: Arc<dyn DaClient>,
 
     /// The channel to forward firm blocks to the executor.
     firm_blocks: mpsc::Sender<Box<ReconstructedBlock>>,
@@ -257,6 +281,17 @@ struct RunningReader {
     /// Token to listen for Conductor being shut down.
     shutdown: CancellationToken,
 
+    /// The file to which the reader's progress is persisted, if any.
+    state_file: Option<StateFile>,
+
+    /// Ticks whenever the reader's progress should be persisted to `state_file`.
+    persist_interval: Interval,
+
+    /// The Celestia heights of the blocks forwarded to the executor, keyed by their sequencer
+    /// heights. Used to resume reading Celestia below the blocks that were forwarded but not yet
+    /// executed against the rollup.
+    forwarded_blocks: BTreeMap<u64, u64>,
+
     /// Tasks reconstructing Sequencer block information from Celestia blobs.
     reconstruction_tasks: JoinMap<u64, eyre::Result<ReconstructedBlocks>>,
 
@@ -313,13 +348,15 @@ impl RunningReader {
     fn from_parts(
         exposed_reader: Reader,
         sequencer_chain_id: tendermint::chain::Id,
+        persisted_state: Option<PersistedState>,
     ) -> eyre::Result<Self> {
         let Reader {
             celestia_block_time,
-            celestia_client,
+            da_client,
             sequencer_cometbft_client,
             shutdown,
             sequencer_requests_per_second,
+            state_file,
             metrics,
             firm_blocks,
             rollup_state,
@@ -329,7 +366,7 @@ impl RunningReader {
             BlockCache::with_next_height(rollup_state.next_expected_firm_sequencer_height())
                 .wrap_err("failed constructing sequential block cache")?;
 
-        let latest_heights = stream_latest_heights(celestia_client.clone(), celestia_block_time);
+        let latest_heights = stream_latest_heights(da_client.clone(), celestia_block_time);
         let rollup_id = rollup_state.rollup_id();
         let rollup_namespace = astria_core::celestia::namespace_v0_from_rollup_id(rollup_id);
         let sequencer_namespace =
@@ -340,18 +377,24 @@ impl RunningReader {
         let celestia_search_height_max_look_ahead =
             rollup_state.celestia_search_height_max_look_ahead();
 
-        Ok(Self {
+        let mut persist_interval = interval(celestia_block_time);
+        persist_interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        let mut reader = Self {
             block_cache,
             blob_verifier: Arc::new(
                 BlobVerifier::try_new(sequencer_cometbft_client, sequencer_requests_per_second)
                     .wrap_err("failed to construct blob verifier")?,
             ),
-            celestia_client,
+            da_client,
             enqueued_block: Fuse::terminated(),
             firm_blocks,
             rollup_state,
             latest_heights,
             shutdown,
+            state_file,
+            persist_interval,
+            forwarded_blocks: BTreeMap::new(),
             reconstruction_tasks: JoinMap::new(),
 
             celestia_head_height: None,
@@ -364,7 +407,103 @@ impl RunningReader {
             sequencer_chain_id,
             sequencer_namespace,
             metrics,
-        })
+        };
+        if let Some(persisted_state) = persisted_state {
+            reader.resume_from(persisted_state);
+        }
+        Ok(reader)
+    }
+
+    /// Resumes reading Celestia from `persisted_state` if it was recorded for the same rollup and
+    /// networks, and if the rollup has not fallen behind it (for example because its database was
+    /// reset).
+    #[instrument(skip_all)]
+    fn resume_from(&mut self, persisted_state: PersistedState) {
+        if let Err(reason) = persisted_state.ensure_resumable(&self.rollup_state) {
+            warn!(%reason, "ignoring persisted state; reading Celestia from the rollup state");
+            return;
+        }
+        let Some(progress) = persisted_state.firm else {
+            info!("no Celestia progress was persisted; reading Celestia from the rollup state");
+            return;
+        };
+        self.celestia_next_height = max(self.celestia_next_height, progress.celestia_next_height);
+        self.advance_reference_celestia_height(progress.celestia_reference_height);
+        let next_height_to_pop = self.block_cache.next_height_to_pop();
+        let mut restored_blocks = 0usize;
+        for block in progress.cached_blocks {
+            if block.sequencer_height().value() >= next_height_to_pop
+                && self.block_cache.insert(block).is_ok()
+            {
+                restored_blocks = restored_blocks.saturating_add(1);
+            }
+        }
+        info!(
+            celestia_next_height = self.celestia_next_height,
+            celestia_reference_height = self.celestia_reference_height,
+            restored_blocks,
+            "resuming from persisted state",
+        );
+    }
+
+    /// Returns the reader's current progress.
+    ///
+    /// Reading resumes at the lowest Celestia height that is still being fetched, or from which a
+    /// block was read that was not yet executed against the rollup. Cached blocks read from
+    /// heights below it are retained.
+    fn current_progress(&self) -> FirmProgress {
+        let next_firm_height = self
+            .rollup_state
+            .next_expected_firm_sequencer_height()
+            .value();
+        let celestia_next_height = self
+            .reconstruction_tasks
+            .keys()
+            .copied()
+            .chain(
+                self.forwarded_blocks
+                    .range(next_firm_height..)
+                    .map(|(_, celestia_height)| *celestia_height),
+            )
+            .fold(self.celestia_next_height, min);
+        let cached_blocks = self
+            .block_cache
+            .blocks()
+            .filter(|block| block.celestia_height < celestia_next_height)
+            .cloned()
+            .collect();
+        FirmProgress {
+            celestia_next_height,
+            celestia_reference_height: self.celestia_reference_height,
+            cached_blocks,
+        }
+    }
+
+    /// Writes the reader's current progress to the state file, if configured.
+    #[instrument(skip_all)]
+    async fn persist_state(&mut self) {
+        let Some(state_file) = &self.state_file else {
+            return;
+        };
+        let next_firm_height = self
+            .rollup_state
+            .next_expected_firm_sequencer_height()
+            .value();
+        self.forwarded_blocks = self.forwarded_blocks.split_off(&next_firm_height);
+        let progress = self.current_progress();
+        let celestia_next_height = progress.celestia_next_height;
+        let cached_blocks = progress.cached_blocks.len();
+        match state_file
+            .save_firm_progress(&self.rollup_state, progress)
+            .await
+        {
+            Ok(()) => trace!(celestia_next_height, cached_blocks, "persisted state"),
+            Err(error) => warn!(
+                %error,
+                path = %state_file.path().display(),
+                "failed persisting state; will try again on the next tick",
+            ),
+        }
     }
 
     async fn run_until_stopped(mut self) -> eyre::Result<()> {
@@ -427,9 +566,15 @@ impl RunningReader {
                     self.handle_latest_height(res);
                 }
 
+                _ = self.persist_interval.tick(), if self.state_file.is_some() => {
+                    self.persist_state().await;
+                }
+
             );
         };
 
+        self.persist_state().await;
+
         // XXX: explicitly setting the event message (usually implicitly set by tracing)
         let message = "shutting down";
         report_exit(reason, message)
@@ -500,7 +645,7 @@ impl RunningReader {
             self.celestia_next_height = self.celestia_next_height.saturating_add(1);
             let task = FetchConvertVerifyAndReconstruct {
                 blob_verifier: self.blob_verifier.clone(),
-                celestia_client: self.celestia_client.clone(),
+                da_client: self.da_client.clone(),
                 celestia_height: height,
                 rollup_id: self.rollup_id,
                 rollup_namespace: self.rollup_namespace,
@@ -527,6 +672,10 @@ impl RunningReader {
     #[instrument(skip_all)]
     fn forward_block_to_executor(&mut self, block: ReconstructedBlock) -> eyre::Result<()> {
         let celestia_height = block.celestia_height;
+        if self.state_file.is_some() {
+            self.forwarded_blocks
+                .insert(block.sequencer_height().value(), celestia_height);
+        }
         match self.firm_blocks.try_send(block.into()) {
             Ok(()) => self.advance_reference_celestia_height(celestia_height),
             Err(mpsc::error::TrySendError::Full(block)) => {
@@ -568,7 +717,7 @@ impl RunningReader {
 
 struct FetchConvertVerifyAndReconstruct {
     blob_verifier: Arc<BlobVerifier>,
-    celestia_client: CelestiaClient,
+    da_client: Arc<dyn DaClient>,
     celestia_height: u64,
     rollup_id: RollupId,
     rollup_namespace: Namespace,
@@ -587,7 +736,7 @@ impl FetchConvertVerifyAndReconstruct {
     async fn execute(self) -> eyre::Result<ReconstructedBlocks> {
         let Self {
             blob_verifier,
-            celestia_client,
+            da_client,
             celestia_height,
             rollup_id,
             rollup_namespace,
@@ -597,7 +746,7 @@ impl FetchConvertVerifyAndReconstruct {
         } = self;
 
         let new_blobs = fetch_new_blobs(
-            celestia_client,
+            da_client,
             celestia_height,
             rollup_namespace,
             sequencer_namespace,
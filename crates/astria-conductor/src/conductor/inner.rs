@@ -9,6 +9,7 @@ use astria_eyre::eyre::{
 };
 use tokio::{
     select,
+    sync::watch,
     task::JoinHandle,
     time::timeout,
 };
@@ -24,6 +25,7 @@ use tracing::{
 use crate::{
     executor::{
         self,
+        Status,
     },
     state::State,
     Config,
@@ -32,9 +34,13 @@ use crate::{
 
 /// Exit value of the inner conductor impl to signal to the outer task whether to restart or
 /// shutdown
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug)]
 pub(super) enum RestartOrShutdown {
+    /// Restart with a new execution session.
     Restart,
+    /// Restart after the execution session ended at its stop height, with the execution session
+    /// succeeding it.
+    NextSession(Box<State>),
     Shutdown,
 }
 
@@ -42,6 +48,7 @@ impl std::fmt::Display for RestartOrShutdown {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let msg = match self {
             RestartOrShutdown::Restart => "restarting",
+            RestartOrShutdown::NextSession(_) => "restarting with next execution session",
             RestartOrShutdown::Shutdown => "shutting down",
         };
         f.write_str(msg)
@@ -70,11 +77,15 @@ impl Inner {
     /// This usually happens if the actors failed to connect to their respective endpoints.
     pub(super) fn new(
         config: Config,
+        ended_session: Option<State>,
+        status: watch::Sender<Status>,
         metrics: &'static Metrics,
         shutdown_token: CancellationToken,
     ) -> eyre::Result<Self> {
         let executor = executor::Builder {
             config: config.clone(),
+            ended_session,
+            status,
             shutdown: shutdown_token.clone(),
             metrics,
         }
@@ -138,7 +149,7 @@ impl Inner {
             }
 
             match exit_result {
-                Ok(state_opt) => should_restart_or_shutdown(&self.config, state_opt.as_ref()),
+                Ok(state_opt) => should_restart_or_shutdown(&self.config, state_opt),
                 Err(error) => {
                     if should_restart_despite_error(&error) {
                         Ok(RestartOrShutdown::Restart)
@@ -185,7 +196,7 @@ fn should_restart_despite_error(err: &eyre::Report) -> bool {
 
 fn should_restart_or_shutdown(
     config: &Config,
-    state_opt: Option<&State>,
+    state_opt: Option<State>,
 ) -> eyre::Result<RestartOrShutdown> {
     let Some(state) = state_opt else {
         return Ok(RestartOrShutdown::Shutdown);
@@ -200,7 +211,7 @@ fn should_restart_or_shutdown(
 
     if config.execution_commit_level.is_with_firm() {
         if state.has_firm_number_reached_stop_height() {
-            Ok(RestartOrShutdown::Restart)
+            Ok(RestartOrShutdown::NextSession(Box::new(state)))
         } else {
             Err(eyre!(
                 "executor exited with a success value, but the stop height was not reached
@@ -216,7 +227,7 @@ fn should_restart_or_shutdown(
             ))
         }
     } else if state.has_soft_number_reached_stop_height() {
-        Ok(RestartOrShutdown::Restart)
+        Ok(RestartOrShutdown::NextSession(Box::new(state)))
     } else {
         Err(eyre!(
             "executor exited with a success value, but the stop height was not reached
@@ -249,32 +260,13 @@ mod tests {
         state::State,
         test_utils::{
             make_commitment_state,
+            make_config,
             make_execution_session_parameters,
             make_rollup_state,
         },
         Config,
     };
 
-    fn make_config() -> crate::Config {
-        crate::Config {
-            celestia_block_time_ms: 0,
-            celestia_node_http_url: String::new(),
-            no_celestia_auth: false,
-            celestia_bearer_token: String::new(),
-            sequencer_grpc_url: String::new(),
-            sequencer_cometbft_url: String::new(),
-            sequencer_block_time_ms: 0,
-            sequencer_requests_per_second: 0,
-            execution_rpc_url: String::new(),
-            log: String::new(),
-            execution_commit_level: CommitLevel::SoftAndFirm,
-            force_stdout: false,
-            no_otel: false,
-            no_metrics: false,
-            metrics_http_listener_addr: String::new(),
-        }
-    }
-
     #[track_caller]
     fn should_restart_despite_error_test(code: tonic::Code) {
         let tonic_error: Result<&str, tonic::Status> = Err(tonic::Status::new(code, "error"));
@@ -289,25 +281,22 @@ mod tests {
     }
 
     #[track_caller]
-    fn assert_restart_or_shutdown(
-        config: &Config,
-        state: &State,
-        restart_or_shutdown: &RestartOrShutdown,
-    ) {
-        assert_eq!(
-            &super::should_restart_or_shutdown(config, Some(state)).unwrap(),
-            restart_or_shutdown,
+    fn assert_next_session(config: &Config, state: State) {
+        let restart_or_shutdown = super::should_restart_or_shutdown(config, Some(state)).unwrap();
+        assert!(
+            matches!(restart_or_shutdown, RestartOrShutdown::NextSession(_)),
+            "expected to restart with the next execution session, got `{restart_or_shutdown}`",
         );
     }
 
     #[test]
     fn restart_or_shutdown_on_firm_height_reached() {
-        assert_restart_or_shutdown(
+        assert_next_session(
             &Config {
                 execution_commit_level: CommitLevel::SoftAndFirm,
                 ..make_config()
             },
-            &make_rollup_state(
+            make_rollup_state(
                 "test_execution_session".to_string(),
                 ExecutionSessionParameters {
                     sequencer_start_block_height: 10,
@@ -333,18 +322,17 @@ mod tests {
                     ..make_commitment_state()
                 },
             ),
-            &RestartOrShutdown::Restart,
         );
     }
 
     #[test]
     fn restart_or_shutdown_on_soft_height_reached() {
-        assert_restart_or_shutdown(
+        assert_next_session(
             &Config {
                 execution_commit_level: CommitLevel::SoftOnly,
                 ..make_config()
             },
-            &make_rollup_state(
+            make_rollup_state(
                 "test_execution_session".to_string(),
                 ExecutionSessionParameters {
                     sequencer_start_block_height: 10,
@@ -370,7 +358,6 @@ mod tests {
                     ..make_commitment_state()
                 },
             ),
-            &RestartOrShutdown::Restart,
         );
     }
 }
@@ -2,6 +2,7 @@ mod inner;
 
 use std::{
     future::Future,
+    net::SocketAddr,
     task::ready,
 };
 
@@ -15,15 +16,25 @@ use inner::{
     RestartOrShutdown,
 };
 use pin_project_lite::pin_project;
-use tokio::task::{
-    JoinError,
-    JoinHandle,
+use tokio::{
+    sync::watch,
+    task::{
+        JoinError,
+        JoinHandle,
+    },
 };
 use tokio_util::sync::CancellationToken;
-use tracing::instrument;
+use tracing::{
+    info,
+    instrument,
+    warn,
+};
 
 use crate::{
+    api,
+    executor::Status,
     metrics::Metrics,
+    state::State,
     Config,
 };
 
@@ -85,29 +96,74 @@ pub struct Conductor {
 
     /// Metrics used by tasks, necessary upon a restart.
     metrics: &'static Metrics,
+
+    /// The channel on which the executor reports its status, shared across restarts.
+    status: watch::Sender<Status>,
+
+    /// The task serving the HTTP API.
+    api: JoinHandle<std::io::Result<()>>,
+
+    /// The address at which the HTTP API is served.
+    api_addr: SocketAddr,
 }
 
 impl Conductor {
     /// Creates a new `Conductor` from a [`Config`].
     ///
     /// # Errors
-    /// Returns an error if [`ConductorInner`] could not be created.
+    /// Returns an error if [`ConductorInner`] could not be created or if the HTTP API could not
+    /// be bound to its configured address.
     pub fn new(cfg: Config, metrics: &'static Metrics) -> eyre::Result<Self> {
         let shutdown_token = CancellationToken::new();
-        let inner = Inner::new(cfg.clone(), metrics, shutdown_token.child_token())?;
+        cfg.parse_sequencer_networks()
+            .wrap_err("failed to parse sequencer networks")?;
+        let (status, _) = watch::channel(Status::default());
+        let api = api::serve(&cfg.api_addr, status.subscribe(), shutdown_token.clone())
+            .wrap_err("failed to start HTTP API")?;
+        let api_addr = api.local_addr();
+        let inner = Inner::new(
+            cfg.clone(),
+            None,
+            status.clone(),
+            metrics,
+            shutdown_token.child_token(),
+        )?;
         Ok(Self {
             shutdown_token,
             inner: tokio::spawn(inner.run_until_stopped()),
             cfg,
             metrics,
+            status,
+            api: tokio::spawn(api),
+            api_addr,
         })
     }
 
+    /// Returns the address at which the HTTP API is served.
+    #[must_use]
+    pub fn api_addr(&self) -> SocketAddr {
+        self.api_addr
+    }
+
     async fn run_until_stopped(mut self) -> eyre::Result<()> {
+        let result = self.run_sessions_until_stopped().await;
+        self.shutdown_token.cancel();
+        match self.api.await {
+            Ok(Ok(())) => info!("HTTP API shut down"),
+            Ok(Err(error)) => warn!(%error, "HTTP API exited with an error"),
+            Err(error) => warn!(%error, "HTTP API panicked"),
+        }
+        result
+    }
+
+    async fn run_sessions_until_stopped(&mut self) -> eyre::Result<()> {
         loop {
             let exit_reason = (&mut self.inner).await;
             match self.restart_or_shutdown(exit_reason).await? {
-                RestartOrShutdown::Restart => self.restart()?,
+                RestartOrShutdown::Restart => self.restart(None)?,
+                RestartOrShutdown::NextSession(ended_session) => {
+                    self.restart(Some(*ended_session))?;
+                }
                 RestartOrShutdown::Shutdown => break Ok(()),
             }
         }
@@ -116,11 +172,15 @@ impl Conductor {
     /// Creates and spawns a new [`ConductorInner`] task with the same configuration, replacing
     /// the previous one. This function should only be called after a graceful shutdown of the
     /// inner conductor task.
+    ///
+    /// If `ended_session` is set, the new task only runs an execution session succeeding it.
     #[instrument(skip_all, err)]
-    fn restart(&mut self) -> eyre::Result<()> {
+    fn restart(&mut self, ended_session: Option<State>) -> eyre::Result<()> {
         self.inner = tokio::spawn(
             Inner::new(
                 self.cfg.clone(),
+                ended_session,
+                self.status.clone(),
                 self.metrics,
                 self.shutdown_token.child_token(),
             )
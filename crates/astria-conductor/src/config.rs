@@ -1,5 +1,15 @@
 //! The conductor configuration.
 
+use std::{
+    collections::HashMap,
+    path::PathBuf,
+};
+
+use astria_eyre::eyre::{
+    self,
+    bail,
+    ensure,
+};
 use serde::{
     Deserialize,
     Serialize,
@@ -22,6 +32,38 @@ impl CommitLevel {
     }
 }
 
+/// What conductor does while firm commitments lag behind soft commitments by more than the
+/// configured thresholds.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub enum FirmLagPolicy {
+    /// Stop executing soft commitments until firm commitments catch up.
+    HaltSoft,
+    /// Keep executing soft commitments, only reporting the lag.
+    Continue,
+    /// Shut down gracefully with an error.
+    Shutdown,
+}
+
+impl std::fmt::Display for FirmLagPolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            Self::HaltSoft => "halt-soft",
+            Self::Continue => "continue",
+            Self::Shutdown => "shutdown",
+        };
+        f.write_str(s)
+    }
+}
+
+/// The data availability layer from which firm blocks are read.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub enum DaBackend {
+    /// Read blobs from a Celestia node.
+    Celestia,
+    /// Read blobs from a local directory written by sequencer-relayer, for devnets and tests.
+    Local,
+}
+
 impl std::fmt::Display for CommitLevel {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let s = match self {
@@ -52,12 +94,27 @@ pub struct Config {
     /// The JWT bearer token supplied with each jsonrpc call
     pub celestia_bearer_token: String,
 
+    /// The data availability layer from which firm blocks are read.
+    pub da_backend: DaBackend,
+
+    /// The directory from which blobs are read if `da_backend` is `Local`.
+    pub local_da_path: PathBuf,
+
+    /// The file to which the progress of reading firm and soft blocks is persisted and from which
+    /// it is resumed after a restart. Progress is not persisted if empty.
+    pub state_path: PathBuf,
+
     /// URL of the Sequencer Cometbft gRPC service.
     pub sequencer_grpc_url: String,
 
     /// URL of the Sequencer Cometbft HTTP RPC.
     pub sequencer_cometbft_url: String,
 
+    /// The endpoints of the sequencer networks that execution sessions may move to, as a
+    /// comma-separated list of `<chain_id>::<grpc_url>::<cometbft_url>`. Sessions on any other
+    /// sequencer network use `sequencer_grpc_url` and `sequencer_cometbft_url`.
+    pub sequencer_networks: String,
+
     pub sequencer_block_time_ms: u64,
 
     /// The number of requests per second that will be sent to Sequencer.
@@ -73,6 +130,21 @@ pub struct Config {
     /// the execution layer.
     pub execution_commit_level: CommitLevel,
 
+    /// The maximum number of blocks by which firm commitments may lag behind soft commitments
+    /// before `firm_lag_policy` is applied. If 0, the Celestia search height max look ahead of
+    /// the execution session is used.
+    pub firm_lag_max_blocks: u64,
+
+    /// The maximum duration in milliseconds for which a soft commitment may wait to be executed
+    /// as a firm commitment before `firm_lag_policy` is applied. Not limited if 0.
+    pub firm_lag_max_duration_ms: u64,
+
+    /// What conductor does while firm commitments lag behind by more than the thresholds.
+    pub firm_lag_policy: FirmLagPolicy,
+
+    /// The address at which conductor serves its HTTP API.
+    pub api_addr: String,
+
     /// Forces writing trace data to stdout no matter if connected to a tty or not.
     pub force_stdout: bool,
 
@@ -94,6 +166,71 @@ impl Config {
     pub(crate) fn is_with_soft(&self) -> bool {
         self.execution_commit_level.is_with_soft()
     }
+
+    /// Returns the file to which progress is persisted, if configured.
+    pub(crate) fn state_path(&self) -> Option<PathBuf> {
+        (!self.state_path.as_os_str().is_empty()).then(|| self.state_path.clone())
+    }
+
+    /// Parses `sequencer_networks` into a map of sequencer chain IDs to their endpoints.
+    ///
+    /// # Errors
+    /// Returns an error if an entry is malformed or a chain ID is listed more than once.
+    pub(crate) fn parse_sequencer_networks(
+        &self,
+    ) -> eyre::Result<HashMap<String, SequencerEndpoints>> {
+        let mut networks = HashMap::new();
+        for entry in self
+            .sequencer_networks
+            .split(',')
+            .map(str::trim)
+            .filter(|entry| !entry.is_empty())
+        {
+            let mut parts = entry.split("::").map(str::trim);
+            let (Some(chain_id), Some(grpc_url), Some(cometbft_url), None) =
+                (parts.next(), parts.next(), parts.next(), parts.next())
+            else {
+                bail!(
+                    "sequencer network `{entry}` is not of the form \
+                     `<chain_id>::<grpc_url>::<cometbft_url>`"
+                );
+            };
+            ensure!(
+                !chain_id.is_empty() && !grpc_url.is_empty() && !cometbft_url.is_empty(),
+                "sequencer network `{entry}` must not have empty fields"
+            );
+            let endpoints = SequencerEndpoints {
+                grpc_url: grpc_url.to_string(),
+                cometbft_url: cometbft_url.to_string(),
+            };
+            ensure!(
+                networks.insert(chain_id.to_string(), endpoints).is_none(),
+                "sequencer network `{chain_id}` is listed more than once"
+            );
+        }
+        Ok(networks)
+    }
+
+    /// Returns the endpoints to use for the sequencer network with `chain_id`.
+    ///
+    /// # Errors
+    /// Returns an error if `sequencer_networks` cannot be parsed.
+    pub(crate) fn sequencer_endpoints(&self, chain_id: &str) -> eyre::Result<SequencerEndpoints> {
+        Ok(self
+            .parse_sequencer_networks()?
+            .remove(chain_id)
+            .unwrap_or_else(|| SequencerEndpoints {
+                grpc_url: self.sequencer_grpc_url.clone(),
+                cometbft_url: self.sequencer_cometbft_url.clone(),
+            }))
+    }
+}
+
+/// The endpoints through which a sequencer network is read.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct SequencerEndpoints {
+    pub(crate) grpc_url: String,
+    pub(crate) cometbft_url: String,
 }
 
 impl config::Config for Config {
@@ -105,7 +242,9 @@ mod tests {
     use super::{
         CommitLevel,
         Config,
+        SequencerEndpoints,
     };
+    use crate::test_utils::make_config;
 
     const EXAMPLE_ENV: &str = include_str!("../local.env.example");
 
@@ -114,6 +253,54 @@ mod tests {
         config::tests::example_env_config_is_up_to_date::<Config>(EXAMPLE_ENV);
     }
 
+    fn config_with_sequencer_networks(sequencer_networks: &str) -> Config {
+        Config {
+            sequencer_grpc_url: "http://127.0.0.1:8080".to_string(),
+            sequencer_cometbft_url: "http://127.0.0.1:26657".to_string(),
+            sequencer_networks: sequencer_networks.to_string(),
+            ..make_config()
+        }
+    }
+
+    #[test]
+    fn sequencer_endpoints_are_selected_by_chain_id() {
+        let config = config_with_sequencer_networks(
+            "next-chain::http://next-grpc:8080::http://next-cometbft:26657, \
+             other-chain::http://other-grpc:8080::http://other-cometbft:26657",
+        );
+        assert_eq!(
+            config.sequencer_endpoints("next-chain").unwrap(),
+            SequencerEndpoints {
+                grpc_url: "http://next-grpc:8080".to_string(),
+                cometbft_url: "http://next-cometbft:26657".to_string(),
+            }
+        );
+        assert_eq!(
+            config.sequencer_endpoints("unlisted-chain").unwrap(),
+            SequencerEndpoints {
+                grpc_url: config.sequencer_grpc_url.clone(),
+                cometbft_url: config.sequencer_cometbft_url.clone(),
+            }
+        );
+    }
+
+    #[test]
+    fn malformed_sequencer_networks_are_rejected() {
+        for sequencer_networks in [
+            "chain::http://grpc:8080",
+            "chain::http://grpc:8080::http://cometbft:26657::extra",
+            "::http://grpc:8080::http://cometbft:26657",
+            "chain::http://a:1::http://b:2,chain::http://c:3::http://d:4",
+        ] {
+            assert!(
+                config_with_sequencer_networks(sequencer_networks)
+                    .parse_sequencer_networks()
+                    .is_err(),
+                "`{sequencer_networks}` should have been rejected",
+            );
+        }
+    }
+
     #[test]
     fn do_commit_levels_correctly_report_mode() {
         use CommitLevel::{
@@ -0,0 +1,703 @@
+//! A conformance harness for rollups implementing the `astria.execution.v2.ExecutionService`.
+//!
+//! The harness drives a rollup's execution service through a fixed sequence of scenarios the
+//! same way conductor would: it creates an execution session, executes soft blocks on top of the
+//! soft commitment, advances the firm commitment, and checks that the rollup rejects requests
+//! violating the execution API's contract. Each scenario reports the invariants the rollup broke.
+//!
+//! Scenarios build on each other and mutate the rollup's chain. The harness must only be run
+//! against a disposable rollup node, for example one started for a CI job.
+mod report;
+#[cfg(test)]
+mod tests;
+
+use std::time::{
+    Duration,
+    SystemTime,
+};
+
+use astria_core::{
+    execution::v2::{
+        ExecutedBlockMetadata,
+        ExecutionSession,
+    },
+    generated::astria::execution::v2::{
+        self as raw,
+        execution_service_client::ExecutionServiceClient,
+    },
+    Protobuf as _,
+};
+use astria_eyre::eyre::{
+    self,
+    WrapErr as _,
+};
+use pbjson_types::Timestamp;
+use sha2::{
+    Digest as _,
+    Sha256,
+};
+use tonic::{
+    transport::{
+        Channel,
+        Endpoint,
+        Uri,
+    },
+    Code,
+};
+use tracing::{
+    info,
+    instrument,
+};
+
+pub use self::report::{
+    Outcome,
+    Report,
+    Scenario,
+    Violation,
+};
+
+/// Constructs a [`Harness`].
+pub struct Builder {
+    /// The URL of the rollup's execution service.
+    pub execution_rpc_url: String,
+    /// The maximum number of blocks to execute as soft commitments.
+    pub blocks: u64,
+    /// The timeout after which a request to the execution service is considered failed.
+    pub request_timeout: Duration,
+}
+
+impl Builder {
+    /// Builds a [`Harness`], connecting lazily to the execution service.
+    ///
+    /// # Errors
+    /// Returns an error if `execution_rpc_url` is not a valid URI.
+    pub fn build(self) -> eyre::Result<Harness> {
+        let Self {
+            execution_rpc_url,
+            blocks,
+            request_timeout,
+        } = self;
+        let uri: Uri = execution_rpc_url
+            .parse()
+            .wrap_err("failed to parse execution RPC URL as URI")?;
+        let channel = Endpoint::from(uri.clone())
+            .timeout(request_timeout)
+            .connect_lazy();
+        Ok(Harness {
+            uri,
+            client: ExecutionServiceClient::new(channel),
+            blocks,
+        })
+    }
+}
+
+/// Runs the conformance scenarios against a rollup's execution service.
+pub struct Harness {
+    uri: Uri,
+    client: ExecutionServiceClient<Channel>,
+    blocks: u64,
+}
+
+/// The scenarios in the order they are run.
+const SCENARIOS: [ScenarioKind; 6] = [
+    ScenarioKind::CreateExecutionSession,
+    ScenarioKind::ExecuteSoftBlocks,
+    ScenarioKind::UpdateFirmCommitment,
+    ScenarioKind::RejectInvalidCommitments,
+    ScenarioKind::RejectInvalidExecution,
+    ScenarioKind::StopAtEndBlockNumber,
+];
+
+#[derive(Clone, Copy)]
+enum ScenarioKind {
+    CreateExecutionSession,
+    ExecuteSoftBlocks,
+    UpdateFirmCommitment,
+    RejectInvalidCommitments,
+    RejectInvalidExecution,
+    StopAtEndBlockNumber,
+}
+
+impl ScenarioKind {
+    fn name(self) -> &'static str {
+        match self {
+            Self::CreateExecutionSession => "create_execution_session",
+            Self::ExecuteSoftBlocks => "execute_soft_blocks",
+            Self::UpdateFirmCommitment => "update_firm_commitment",
+            Self::RejectInvalidCommitments => "reject_invalid_commitments",
+            Self::RejectInvalidExecution => "reject_invalid_execution",
+            Self::StopAtEndBlockNumber => "stop_at_end_block_number",
+        }
+    }
+}
+
+/// The execution session and commitment state the scenarios build on.
+struct Session {
+    id: String,
+    parameters: raw::ExecutionSessionParameters,
+    commitment_state: raw::CommitmentState,
+}
+
+impl Session {
+    fn soft(&self) -> &raw::ExecutedBlockMetadata {
+        self.commitment_state
+            .soft_executed_block_metadata
+            .as_ref()
+            .expect("the commitment state of a validated execution session has a soft block")
+    }
+
+    fn firm(&self) -> &raw::ExecutedBlockMetadata {
+        self.commitment_state
+            .firm_executed_block_metadata
+            .as_ref()
+            .expect("the commitment state of a validated execution session has a firm block")
+    }
+
+    /// Returns the number of blocks that can be executed on top of the soft commitment before
+    /// reaching the end of the session, if the session has an end.
+    fn remaining_blocks(&self) -> Option<u64> {
+        (self.parameters.rollup_end_block_number > 0).then(|| {
+            self.parameters
+                .rollup_end_block_number
+                .saturating_sub(self.soft().number)
+        })
+    }
+
+    fn can_execute(&self) -> bool {
+        self.remaining_blocks()
+            .map_or(true, |remaining| remaining > 0)
+    }
+}
+
+/// How a scenario ended if it did not abort with a violation.
+enum End {
+    Completed,
+    Skipped(String),
+}
+
+/// The violations found while running a single scenario.
+#[derive(Default)]
+struct Checks {
+    violations: Vec<Violation>,
+}
+
+impl Checks {
+    /// Records a violation of `invariant` if `holds` is false.
+    fn ensure(
+        &mut self,
+        invariant: &'static str,
+        holds: bool,
+        details: impl FnOnce() -> String,
+    ) -> bool {
+        if !holds {
+            self.violations.push(Violation::new(invariant, details()));
+        }
+        holds
+    }
+}
+
+impl Harness {
+    /// Runs all scenarios in order and reports their outcomes.
+    ///
+    /// Scenarios requiring an execution session are skipped if none could be created.
+    #[instrument(skip_all, fields(uri = %self.uri))]
+    pub async fn run(mut self) -> Report {
+        let mut session = None;
+        let mut scenarios = Vec::with_capacity(SCENARIOS.len());
+        for scenario in SCENARIOS {
+            let mut checks = Checks::default();
+            let result = match (scenario, session.as_mut()) {
+                (ScenarioKind::CreateExecutionSession, _) => self
+                    .create_execution_session(&mut checks)
+                    .await
+                    .map(|created| {
+                        session = Some(created);
+                        End::Completed
+                    }),
+                (_, None) => Ok(End::Skipped(
+                    "requires an execution session, which could not be created".to_string(),
+                )),
+                (ScenarioKind::ExecuteSoftBlocks, Some(session)) => {
+                    self.execute_soft_blocks(session, &mut checks).await
+                }
+                (ScenarioKind::UpdateFirmCommitment, Some(session)) => {
+                    self.update_firm_commitment(session, &mut checks).await
+                }
+                (ScenarioKind::RejectInvalidCommitments, Some(session)) => {
+                    self.reject_invalid_commitments(session, &mut checks).await
+                }
+                (ScenarioKind::RejectInvalidExecution, Some(session)) => {
+                    self.reject_invalid_execution(session, &mut checks).await
+                }
+                (ScenarioKind::StopAtEndBlockNumber, Some(session)) => {
+                    self.stop_at_end_block_number(session, &mut checks).await
+                }
+            };
+            let outcome = match result {
+                Err(violation) => {
+                    checks.violations.push(violation);
+                    Outcome::Failed(checks.violations)
+                }
+                Ok(_) if !checks.violations.is_empty() => Outcome::Failed(checks.violations),
+                Ok(End::Completed) => Outcome::Passed,
+                Ok(End::Skipped(reason)) => Outcome::Skipped(reason),
+            };
+            info!(scenario = scenario.name(), %outcome, "finished scenario");
+            scenarios.push(Scenario::new(scenario.name(), outcome));
+        }
+        Report::new(scenarios)
+    }
+
+    async fn create_execution_session(
+        &mut self,
+        checks: &mut Checks,
+    ) -> Result<Session, Violation> {
+        let raw_session = self
+            .client
+            .create_execution_session(raw::CreateExecutionSessionRequest {})
+            .await
+            .map_err(|status| rpc_failed("CreateExecutionSession", &status))?
+            .into_inner();
+        ExecutionSession::try_from_raw(raw_session.clone()).map_err(|error| {
+            Violation::new(
+                "execution session is well formed",
+                format!("{:#}", eyre::Report::new(error)),
+            )
+        })?;
+        let raw::ExecutionSession {
+            session_id,
+            execution_session_parameters,
+            commitment_state,
+        } = raw_session;
+        let session = Session {
+            id: session_id,
+            parameters: execution_session_parameters
+                .expect("a validated execution session has parameters"),
+            commitment_state: commitment_state
+                .expect("a validated execution session has a commitment state"),
+        };
+
+        checks.ensure("session ID is not empty", !session.id.is_empty(), || {
+            "the session ID is empty".to_string()
+        });
+        let start = session.parameters.rollup_start_block_number;
+        let end = session.parameters.rollup_end_block_number;
+        checks.ensure(
+            "rollup start block number is at least 1",
+            start >= 1,
+            || format!("rollup start block number is `{start}`"),
+        );
+        checks.ensure(
+            "rollup end block number is unset or not below the start block number",
+            end == 0 || end >= start,
+            || format!("rollup end block number `{end}` is below start block number `{start}`"),
+        );
+        let (firm, soft) = (session.firm().number, session.soft().number);
+        checks.ensure(
+            "firm commitment does not exceed soft commitment",
+            firm <= soft,
+            || format!("firm number `{firm}` exceeds soft number `{soft}`"),
+        );
+        checks.ensure(
+            "commitment state is not below the block preceding the session's start block",
+            firm.saturating_add(1) >= start,
+            || format!("firm number `{firm}` is below `rollup_start_block_number - 1`"),
+        );
+
+        for (kind, block) in [("firm", session.firm()), ("soft", session.soft())] {
+            self.check_block_is_retrievable(kind, block, checks).await?;
+        }
+        Ok(session)
+    }
+
+    async fn execute_soft_blocks(
+        &mut self,
+        session: &mut Session,
+        checks: &mut Checks,
+    ) -> Result<End, Violation> {
+        let blocks = session
+            .remaining_blocks()
+            .map_or(self.blocks, |remaining| remaining.min(self.blocks));
+        if blocks == 0 {
+            return Ok(End::Skipped(
+                "the execution session has no blocks left to execute".to_string(),
+            ));
+        }
+        for _ in 0..blocks {
+            let parent = session.soft().clone();
+            let request = execute_block_request(&session.id, &parent);
+            let executed = self
+                .execute_block(request.clone())
+                .await
+                .map_err(|status| rpc_failed("ExecuteBlock", &status))?;
+            let expected = parent.number.saturating_add(1);
+            if !checks.ensure(
+                "executed block number is the soft number plus one",
+                executed.number == expected,
+                || {
+                    format!(
+                        "executed on top of soft number `{}`, expected block number `{expected}`, \
+                         got `{}`",
+                        parent.number, executed.number,
+                    )
+                },
+            ) {
+                return Ok(End::Completed);
+            }
+            checks.ensure(
+                "executed block's parent is the requested parent",
+                executed.parent_hash == parent.hash,
+                || {
+                    format!(
+                        "requested parent `{}`, got `{}`",
+                        parent.hash, executed.parent_hash
+                    )
+                },
+            );
+            checks.ensure(
+                "executed block's timestamp is the requested timestamp",
+                executed.timestamp == request.timestamp,
+                || {
+                    format!(
+                        "requested timestamp `{:?}`, got `{:?}`",
+                        request.timestamp, executed.timestamp
+                    )
+                },
+            );
+
+            let commitment_state = raw::CommitmentState {
+                soft_executed_block_metadata: Some(executed.clone()),
+                ..session.commitment_state.clone()
+            };
+            self.update_commitment_state(session, commitment_state, checks)
+                .await?;
+            self.check_block_is_retrievable("soft", &executed, checks)
+                .await?;
+        }
+        Ok(End::Completed)
+    }
+
+    async fn update_firm_commitment(
+        &mut self,
+        session: &mut Session,
+        checks: &mut Checks,
+    ) -> Result<End, Violation> {
+        let (firm, soft) = (session.firm().number, session.soft().number);
+        if firm >= soft {
+            return Ok(End::Skipped(
+                "there are no soft blocks to commit as firm".to_string(),
+            ));
+        }
+        for number in firm.saturating_add(1)..=soft {
+            let block = self
+                .get_executed_block_metadata(block_identifier_by_number(number))
+                .await
+                .map_err(|status| rpc_failed("GetExecutedBlockMetadata", &status))?;
+            let commitment_state = raw::CommitmentState {
+                firm_executed_block_metadata: Some(block),
+                lowest_celestia_search_height: session
+                    .commitment_state
+                    .lowest_celestia_search_height
+                    .saturating_add(1),
+                ..session.commitment_state.clone()
+            };
+            self.update_commitment_state(session, commitment_state, checks)
+                .await?;
+        }
+        Ok(End::Completed)
+    }
+
+    async fn reject_invalid_commitments(
+        &mut self,
+        session: &mut Session,
+        checks: &mut Checks,
+    ) -> Result<End, Violation> {
+        let soft = session.soft().clone();
+        let firm = session.firm().clone();
+        if soft.number == 0 {
+            return Ok(End::Skipped(
+                "the soft commitment is the genesis block, so commitments cannot decrease"
+                    .to_string(),
+            ));
+        }
+        let previous = self
+            .get_executed_block_metadata(block_identifier_by_number(soft.number.saturating_sub(1)))
+            .await
+            .map_err(|status| rpc_failed("GetExecutedBlockMetadata", &status))?;
+
+        let mut invalid = vec![(
+            "soft commitment does not decrease",
+            raw::CommitmentState {
+                soft_executed_block_metadata: Some(previous.clone()),
+                firm_executed_block_metadata: Some(if firm.number < soft.number {
+                    firm.clone()
+                } else {
+                    previous.clone()
+                }),
+                ..session.commitment_state.clone()
+            },
+        )];
+        if firm.number == soft.number {
+            invalid.push((
+                "firm commitment does not decrease",
+                raw::CommitmentState {
+                    firm_executed_block_metadata: Some(previous),
+                    ..session.commitment_state.clone()
+                },
+            ));
+        }
+        if session.can_execute() {
+            let ahead = self
+                .execute_block(execute_block_request(&session.id, &soft))
+                .await
+                .map_err(|status| rpc_failed("ExecuteBlock", &status))?;
+            invalid.push((
+                "firm commitment does not exceed soft commitment",
+                raw::CommitmentState {
+                    firm_executed_block_metadata: Some(ahead),
+                    ..session.commitment_state.clone()
+                },
+            ));
+        }
+
+        for (invariant, commitment_state) in invalid {
+            let request = raw::UpdateCommitmentStateRequest {
+                session_id: session.id.clone(),
+                commitment_state: Some(commitment_state),
+            };
+            if self.client.update_commitment_state(request).await.is_ok() {
+                checks.ensure(invariant, false, || {
+                    "the rollup accepted a commitment state violating it".to_string()
+                });
+                // Restore the commitment state so that the following checks start from it.
+                let restore = raw::UpdateCommitmentStateRequest {
+                    session_id: session.id.clone(),
+                    commitment_state: Some(session.commitment_state.clone()),
+                };
+                self.client
+                    .update_commitment_state(restore)
+                    .await
+                    .map_err(|status| rpc_failed("UpdateCommitmentState", &status))?;
+            }
+        }
+        Ok(End::Completed)
+    }
+
+    async fn reject_invalid_execution(
+        &mut self,
+        session: &mut Session,
+        checks: &mut Checks,
+    ) -> Result<End, Violation> {
+        if !session.can_execute() {
+            return Ok(End::Skipped(
+                "the soft commitment is at the session's end block number".to_string(),
+            ));
+        }
+        let soft = session.soft().clone();
+
+        let wrong_session = raw::ExecuteBlockRequest {
+            session_id: format!("{}-invalid", session.id),
+            ..execute_block_request(&session.id, &soft)
+        };
+        let unknown_parent = raw::ExecuteBlockRequest {
+            parent_hash: hex::encode(Sha256::digest(b"unknown parent")),
+            ..execute_block_request(&session.id, &soft)
+        };
+        for (invariant, request) in [
+            (
+                "blocks are only executed within the current session",
+                wrong_session,
+            ),
+            (
+                "blocks are only executed on top of known parents",
+                unknown_parent,
+            ),
+        ] {
+            if let Ok(executed) = self.execute_block(request).await {
+                checks.ensure(invariant, false, || {
+                    format!(
+                        "the rollup executed block number `{}` with hash `{}`",
+                        executed.number, executed.hash
+                    )
+                });
+            }
+        }
+        Ok(End::Completed)
+    }
+
+    async fn stop_at_end_block_number(
+        &mut self,
+        session: &mut Session,
+        checks: &mut Checks,
+    ) -> Result<End, Violation> {
+        let end = session.parameters.rollup_end_block_number;
+        if end == 0 {
+            return Ok(End::Skipped(
+                "the execution session has no end block number".to_string(),
+            ));
+        }
+        let soft = session.soft().clone();
+        if soft.number < end {
+            return Ok(End::Skipped(format!(
+                "the soft commitment at `{}` did not reach end block number `{end}` within the \
+                 executed blocks",
+                soft.number
+            )));
+        }
+        let invariant = "blocks past the end block number are rejected with OUT_OF_RANGE";
+        match self
+            .execute_block(execute_block_request(&session.id, &soft))
+            .await
+        {
+            Ok(executed) => {
+                checks.ensure(invariant, false, || {
+                    format!("the rollup executed block number `{}`", executed.number)
+                });
+            }
+            Err(status) => {
+                checks.ensure(invariant, status.code() == Code::OutOfRange, || {
+                    format!("the rollup rejected the block with `{}`", status.code())
+                });
+            }
+        }
+        Ok(End::Completed)
+    }
+
+    /// Updates the rollup's commitment state and checks that it echoes the new state.
+    async fn update_commitment_state(
+        &mut self,
+        session: &mut Session,
+        commitment_state: raw::CommitmentState,
+        checks: &mut Checks,
+    ) -> Result<(), Violation> {
+        let request = raw::UpdateCommitmentStateRequest {
+            session_id: session.id.clone(),
+            commitment_state: Some(commitment_state.clone()),
+        };
+        let response = self
+            .client
+            .update_commitment_state(request)
+            .await
+            .map_err(|status| rpc_failed("UpdateCommitmentState", &status))?
+            .into_inner();
+        checks.ensure(
+            "updated commitment state is the requested commitment state",
+            response == commitment_state,
+            || format!("requested `{commitment_state:?}`, got `{response:?}`"),
+        );
+        session.commitment_state = commitment_state;
+        Ok(())
+    }
+
+    /// Checks that `block` is returned when requesting it by number and by hash.
+    async fn check_block_is_retrievable(
+        &mut self,
+        kind: &str,
+        block: &raw::ExecutedBlockMetadata,
+        checks: &mut Checks,
+    ) -> Result<(), Violation> {
+        for identifier in [
+            block_identifier_by_number(block.number),
+            block_identifier_by_hash(block.hash.clone()),
+        ] {
+            let retrieved = self
+                .get_executed_block_metadata(identifier.clone())
+                .await
+                .map_err(|status| rpc_failed("GetExecutedBlockMetadata", &status))?;
+            checks.ensure(
+                "retrieved block metadata matches the committed block metadata",
+                &retrieved == block,
+                || format!("{kind} block is `{block:?}`, got `{retrieved:?}` for `{identifier:?}`"),
+            );
+        }
+        Ok(())
+    }
+
+    async fn execute_block(
+        &mut self,
+        request: raw::ExecuteBlockRequest,
+    ) -> Result<raw::ExecutedBlockMetadata, tonic::Status> {
+        let response = self.client.execute_block(request).await?.into_inner();
+        let metadata = response.executed_block_metadata.ok_or_else(|| {
+            tonic::Status::internal("response is missing executed block metadata")
+        })?;
+        validate_block_metadata(metadata)
+    }
+
+    async fn get_executed_block_metadata(
+        &mut self,
+        identifier: raw::ExecutedBlockIdentifier,
+    ) -> Result<raw::ExecutedBlockMetadata, tonic::Status> {
+        let request = raw::GetExecutedBlockMetadataRequest {
+            identifier: Some(identifier),
+        };
+        let metadata = self
+            .client
+            .get_executed_block_metadata(request)
+            .await?
+            .into_inner();
+        validate_block_metadata(metadata)
+    }
+}
+
+/// Returns `metadata` if it is well formed, or an error status describing why it is not.
+fn validate_block_metadata(
+    metadata: raw::ExecutedBlockMetadata,
+) -> Result<raw::ExecutedBlockMetadata, tonic::Status> {
+    ExecutedBlockMetadata::try_from_raw(metadata.clone()).map_err(|error| {
+        tonic::Status::internal(format!(
+            "response contains malformed block metadata: {:#}",
+            eyre::Report::new(error)
+        ))
+    })?;
+    Ok(metadata)
+}
+
+/// Constructs a request executing an empty block on top of `parent`.
+///
+/// The timestamp is the current time, but strictly after the parent's timestamp, and the
+/// sequencer block hash is derived from the block number.
+fn execute_block_request(
+    session_id: &str,
+    parent: &raw::ExecutedBlockMetadata,
+) -> raw::ExecuteBlockRequest {
+    let now = SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .map_or(0, |since_epoch| {
+            i64::try_from(since_epoch.as_secs()).unwrap_or(i64::MAX)
+        });
+    let after_parent = parent
+        .timestamp
+        .as_ref()
+        .map_or(0, |timestamp| timestamp.seconds.saturating_add(1));
+    let number = parent.number.saturating_add(1);
+    raw::ExecuteBlockRequest {
+        session_id: session_id.to_string(),
+        parent_hash: parent.hash.clone(),
+        transactions: vec![],
+        timestamp: Some(Timestamp {
+            seconds: now.max(after_parent),
+            nanos: 0,
+        }),
+        sequencer_block_hash: hex::encode(Sha256::digest(number.to_le_bytes())),
+    }
+}
+
+fn block_identifier_by_number(number: u64) -> raw::ExecutedBlockIdentifier {
+    raw::ExecutedBlockIdentifier {
+        identifier: Some(raw::executed_block_identifier::Identifier::Number(number)),
+    }
+}
+
+fn block_identifier_by_hash(hash: String) -> raw::ExecutedBlockIdentifier {
+    raw::ExecutedBlockIdentifier {
+        identifier: Some(raw::executed_block_identifier::Identifier::Hash(hash)),
+    }
+}
+
+fn rpc_failed(rpc: &str, status: &tonic::Status) -> Violation {
+    Violation::new(
+        "requests following the contract succeed",
+        format!("astria.execution.v2.{rpc} failed: {status}"),
+    )
+}
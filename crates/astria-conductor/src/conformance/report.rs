@@ -0,0 +1,139 @@
+use std::fmt::{
+    self,
+    Display,
+    Formatter,
+};
+
+use serde::Serialize;
+
+/// The outcomes of all conformance scenarios run against a rollup.
+#[derive(Debug, Serialize)]
+pub struct Report {
+    scenarios: Vec<Scenario>,
+}
+
+impl Report {
+    pub(super) fn new(scenarios: Vec<Scenario>) -> Self {
+        Self {
+            scenarios,
+        }
+    }
+
+    /// Returns the reports of the individual scenarios in the order they were run.
+    #[must_use]
+    pub fn scenarios(&self) -> &[Scenario] {
+        &self.scenarios
+    }
+
+    /// Returns whether the rollup broke none of the invariants checked by the scenarios.
+    #[must_use]
+    pub fn is_conformant(&self) -> bool {
+        !self
+            .scenarios
+            .iter()
+            .any(|scenario| matches!(scenario.outcome, Outcome::Failed(_)))
+    }
+}
+
+impl Display for Report {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for scenario in &self.scenarios {
+            match &scenario.outcome {
+                Outcome::Passed => writeln!(f, "PASS {}", scenario.name)?,
+                Outcome::Skipped(reason) => writeln!(f, "SKIP {}: {reason}", scenario.name)?,
+                Outcome::Failed(violations) => {
+                    writeln!(f, "FAIL {}", scenario.name)?;
+                    for violation in violations {
+                        writeln!(f, "     - {violation}")?;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The outcome of a single conformance scenario.
+#[derive(Debug, Serialize)]
+pub struct Scenario {
+    name: &'static str,
+    outcome: Outcome,
+}
+
+impl Scenario {
+    pub(super) fn new(name: &'static str, outcome: Outcome) -> Self {
+        Self {
+            name,
+            outcome,
+        }
+    }
+
+    /// Returns the name of the scenario.
+    #[must_use]
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Returns how the scenario ended.
+    #[must_use]
+    pub fn outcome(&self) -> &Outcome {
+        &self.outcome
+    }
+}
+
+/// How a conformance scenario ended.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "snake_case", tag = "result", content = "details")]
+pub enum Outcome {
+    /// The rollup upheld all invariants checked by the scenario.
+    Passed,
+    /// The scenario could not be run against the rollup, for example because its execution
+    /// session has no end block number.
+    Skipped(String),
+    /// The rollup broke the contained invariants.
+    Failed(Vec<Violation>),
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Passed => f.write_str("passed"),
+            Self::Skipped(_) => f.write_str("skipped"),
+            Self::Failed(_) => f.write_str("failed"),
+        }
+    }
+}
+
+/// An invariant of the execution API broken by the rollup.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct Violation {
+    invariant: &'static str,
+    details: String,
+}
+
+impl Violation {
+    pub(super) fn new(invariant: &'static str, details: String) -> Self {
+        Self {
+            invariant,
+            details,
+        }
+    }
+
+    /// Returns the invariant that was broken.
+    #[must_use]
+    pub fn invariant(&self) -> &'static str {
+        self.invariant
+    }
+
+    /// Returns how the invariant was broken.
+    #[must_use]
+    pub fn details(&self) -> &str {
+        &self.details
+    }
+}
+
+impl Display for Violation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.invariant, self.details)
+    }
+}
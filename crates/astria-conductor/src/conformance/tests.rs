@@ -0,0 +1,338 @@
+use std::{
+    collections::BTreeMap,
+    net::SocketAddr,
+    sync::{
+        Arc,
+        Mutex,
+    },
+    time::Duration,
+};
+
+use astria_core::generated::astria::execution::v2::{
+    self as raw,
+    execution_service_server::{
+        ExecutionService,
+        ExecutionServiceServer,
+    },
+};
+use pbjson_types::Timestamp;
+use sha2::{
+    Digest as _,
+    Sha256,
+};
+use tokio_stream::wrappers::TcpListenerStream;
+use tonic::{
+    transport::Server,
+    Request,
+    Response,
+    Status,
+};
+
+use super::{
+    Builder,
+    Outcome,
+    Report,
+};
+use crate::test_utils::make_execution_session_parameters;
+
+const SESSION_ID: &str = "test-session";
+
+/// Ways in which the in-memory rollup breaks the execution API's contract.
+#[derive(Clone, Copy, Default)]
+struct Faults {
+    skips_block_numbers: bool,
+    accepts_firm_ahead_of_soft: bool,
+    ignores_end_block_number: bool,
+}
+
+/// An in-memory rollup implementing the execution API, starting from a genesis block.
+struct Rollup {
+    end_block_number: u64,
+    faults: Faults,
+    state: Mutex<RollupState>,
+}
+
+struct RollupState {
+    blocks: BTreeMap<u64, raw::ExecutedBlockMetadata>,
+    commitment_state: raw::CommitmentState,
+}
+
+impl Rollup {
+    fn new(end_block_number: u64, faults: Faults) -> Self {
+        let genesis = raw::ExecutedBlockMetadata {
+            number: 0,
+            hash: hex::encode(Sha256::digest(b"genesis")),
+            parent_hash: String::new(),
+            timestamp: Some(Timestamp::default()),
+            sequencer_block_hash: String::new(),
+        };
+        Self {
+            end_block_number,
+            faults,
+            state: Mutex::new(RollupState {
+                blocks: BTreeMap::from([(0, genesis.clone())]),
+                commitment_state: raw::CommitmentState {
+                    soft_executed_block_metadata: Some(genesis.clone()),
+                    firm_executed_block_metadata: Some(genesis),
+                    lowest_celestia_search_height: 1,
+                },
+            }),
+        }
+    }
+}
+
+fn ensure_session(session_id: &str) -> Result<(), Status> {
+    if session_id == SESSION_ID {
+        Ok(())
+    } else {
+        Err(Status::permission_denied("unknown execution session"))
+    }
+}
+
+#[tonic::async_trait]
+impl ExecutionService for Rollup {
+    async fn create_execution_session(
+        self: Arc<Self>,
+        _request: Request<raw::CreateExecutionSessionRequest>,
+    ) -> Result<Response<raw::ExecutionSession>, Status> {
+        let state = self.state.lock().unwrap();
+        Ok(Response::new(raw::ExecutionSession {
+            session_id: SESSION_ID.to_string(),
+            execution_session_parameters: Some(raw::ExecutionSessionParameters {
+                rollup_end_block_number: self.end_block_number,
+                ..make_execution_session_parameters()
+            }),
+            commitment_state: Some(state.commitment_state.clone()),
+        }))
+    }
+
+    async fn get_executed_block_metadata(
+        self: Arc<Self>,
+        request: Request<raw::GetExecutedBlockMetadataRequest>,
+    ) -> Result<Response<raw::ExecutedBlockMetadata>, Status> {
+        use raw::executed_block_identifier::Identifier;
+
+        let state = self.state.lock().unwrap();
+        let block = match request.into_inner().identifier.and_then(|id| id.identifier) {
+            Some(Identifier::Number(number)) => state.blocks.get(&number),
+            Some(Identifier::Hash(hash)) => state.blocks.values().find(|block| block.hash == hash),
+            None => return Err(Status::invalid_argument("identifier is not set")),
+        };
+        block
+            .cloned()
+            .map(Response::new)
+            .ok_or_else(|| Status::not_found("block not found"))
+    }
+
+    async fn execute_block(
+        self: Arc<Self>,
+        request: Request<raw::ExecuteBlockRequest>,
+    ) -> Result<Response<raw::ExecuteBlockResponse>, Status> {
+        let request = request.into_inner();
+        ensure_session(&request.session_id)?;
+        let mut state = self.state.lock().unwrap();
+        let parent = state
+            .blocks
+            .values()
+            .find(|block| block.hash == request.parent_hash)
+            .cloned()
+            .ok_or_else(|| Status::failed_precondition("parent block not found"))?;
+        let mut number = parent.number.saturating_add(1);
+        if self.faults.skips_block_numbers {
+            number = number.saturating_add(1);
+        }
+        if self.end_block_number > 0
+            && number > self.end_block_number
+            && !self.faults.ignores_end_block_number
+        {
+            return Err(Status::out_of_range("block is past the end block number"));
+        }
+        let mut hasher = Sha256::new();
+        hasher.update(parent.hash.as_bytes());
+        hasher.update(number.to_le_bytes());
+        hasher.update(request.sequencer_block_hash.as_bytes());
+        let block = raw::ExecutedBlockMetadata {
+            number,
+            hash: hex::encode(hasher.finalize()),
+            parent_hash: parent.hash,
+            timestamp: request.timestamp,
+            sequencer_block_hash: request.sequencer_block_hash,
+        };
+        state.blocks.split_off(&number);
+        state.blocks.insert(number, block.clone());
+        Ok(Response::new(raw::ExecuteBlockResponse {
+            executed_block_metadata: Some(block),
+        }))
+    }
+
+    async fn update_commitment_state(
+        self: Arc<Self>,
+        request: Request<raw::UpdateCommitmentStateRequest>,
+    ) -> Result<Response<raw::CommitmentState>, Status> {
+        let request = request.into_inner();
+        ensure_session(&request.session_id)?;
+        let commitment_state = request
+            .commitment_state
+            .ok_or_else(|| Status::invalid_argument("commitment state is not set"))?;
+        let (Some(soft), Some(firm)) = (
+            commitment_state.soft_executed_block_metadata.as_ref(),
+            commitment_state.firm_executed_block_metadata.as_ref(),
+        ) else {
+            return Err(Status::invalid_argument("commitment state is incomplete"));
+        };
+        let mut state = self.state.lock().unwrap();
+        let current = &state.commitment_state;
+        let current_soft = current.soft_executed_block_metadata.as_ref().unwrap();
+        let current_firm = current.firm_executed_block_metadata.as_ref().unwrap();
+        if firm.number > soft.number && !self.faults.accepts_firm_ahead_of_soft {
+            return Err(Status::invalid_argument("firm exceeds soft"));
+        }
+        if soft.number < current_soft.number || firm.number < current_firm.number {
+            return Err(Status::invalid_argument("commitments must not decrease"));
+        }
+        if [soft, firm]
+            .into_iter()
+            .any(|block| state.blocks.get(&block.number) != Some(block))
+        {
+            return Err(Status::invalid_argument("unknown block"));
+        }
+        state.commitment_state = commitment_state.clone();
+        Ok(Response::new(commitment_state))
+    }
+}
+
+async fn spawn_rollup(rollup: Rollup) -> SocketAddr {
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let local_addr = listener.local_addr().unwrap();
+    tokio::spawn(
+        Server::builder()
+            .add_service(ExecutionServiceServer::new(rollup))
+            .serve_with_incoming(TcpListenerStream::new(listener)),
+    );
+    local_addr
+}
+
+async fn run_against(rollup: Rollup) -> Report {
+    let addr = spawn_rollup(rollup).await;
+    Builder {
+        execution_rpc_url: format!("http://{addr}"),
+        blocks: 3,
+        request_timeout: Duration::from_secs(5),
+    }
+    .build()
+    .unwrap()
+    .run()
+    .await
+}
+
+#[track_caller]
+fn assert_outcome(report: &Report, scenario: &str, expected: &str) {
+    let outcome = report
+        .scenarios()
+        .iter()
+        .find(|report| report.name() == scenario)
+        .unwrap_or_else(|| panic!("report contains no scenario `{scenario}`"))
+        .outcome();
+    assert_eq!(
+        expected,
+        outcome.to_string(),
+        "unexpected outcome of `{scenario}`:\n{report}"
+    );
+}
+
+#[track_caller]
+fn assert_violated(report: &Report, scenario: &str, invariant: &str) {
+    let outcome = report
+        .scenarios()
+        .iter()
+        .find(|report| report.name() == scenario)
+        .unwrap_or_else(|| panic!("report contains no scenario `{scenario}`"))
+        .outcome();
+    let Outcome::Failed(violations) = outcome else {
+        panic!("expected `{scenario}` to fail, but it {outcome}:\n{report}");
+    };
+    assert!(
+        violations
+            .iter()
+            .any(|violation| violation.invariant() == invariant),
+        "expected `{scenario}` to violate `{invariant}`:\n{report}"
+    );
+}
+
+#[tokio::test]
+async fn conformant_rollup_passes_all_scenarios() {
+    let report = run_against(Rollup::new(0, Faults::default())).await;
+    assert!(report.is_conformant(), "{report}");
+    for scenario in [
+        "create_execution_session",
+        "execute_soft_blocks",
+        "update_firm_commitment",
+        "reject_invalid_commitments",
+        "reject_invalid_execution",
+    ] {
+        assert_outcome(&report, scenario, "passed");
+    }
+    assert_outcome(&report, "stop_at_end_block_number", "skipped");
+}
+
+#[tokio::test]
+async fn conformant_rollup_stops_at_end_block_number() {
+    let report = run_against(Rollup::new(3, Faults::default())).await;
+    assert!(report.is_conformant(), "{report}");
+    assert_outcome(&report, "execute_soft_blocks", "passed");
+    assert_outcome(&report, "stop_at_end_block_number", "passed");
+}
+
+#[tokio::test]
+async fn rollup_skipping_block_numbers_is_reported() {
+    let report = run_against(Rollup::new(
+        0,
+        Faults {
+            skips_block_numbers: true,
+            ..Faults::default()
+        },
+    ))
+    .await;
+    assert!(!report.is_conformant());
+    assert_violated(
+        &report,
+        "execute_soft_blocks",
+        "executed block number is the soft number plus one",
+    );
+}
+
+#[tokio::test]
+async fn rollup_accepting_firm_ahead_of_soft_is_reported() {
+    let report = run_against(Rollup::new(
+        0,
+        Faults {
+            accepts_firm_ahead_of_soft: true,
+            ..Faults::default()
+        },
+    ))
+    .await;
+    assert!(!report.is_conformant());
+    assert_violated(
+        &report,
+        "reject_invalid_commitments",
+        "firm commitment does not exceed soft commitment",
+    );
+}
+
+#[tokio::test]
+async fn rollup_executing_past_end_block_number_is_reported() {
+    let report = run_against(Rollup::new(
+        3,
+        Faults {
+            ignores_end_block_number: true,
+            ..Faults::default()
+        },
+    ))
+    .await;
+    assert!(!report.is_conformant());
+    assert_violated(
+        &report,
+        "stop_at_end_block_number",
+        "blocks past the end block number are rejected with OUT_OF_RANGE",
+    );
+}
@@ -2,13 +2,23 @@ use astria_eyre::eyre::{
     self,
     WrapErr as _,
 };
+use tokio::sync::watch;
 use tokio_util::sync::CancellationToken;
 
-use super::Executor;
-use crate::metrics::Metrics;
+use super::{
+    Executor,
+    Status,
+};
+use crate::{
+    metrics::Metrics,
+    state::State,
+};
 
 pub(crate) struct Builder {
     pub(crate) config: crate::Config,
+    /// The state of the previous execution session if it ended at its stop height.
+    pub(crate) ended_session: Option<State>,
+    pub(crate) status: watch::Sender<Status>,
     pub(crate) shutdown: CancellationToken,
     pub(crate) metrics: &'static Metrics,
 }
@@ -17,6 +27,8 @@ impl Builder {
     pub(crate) fn build(self) -> eyre::Result<Executor> {
         let Self {
             config,
+            ended_session,
+            status,
             shutdown,
             metrics,
         } = self;
@@ -32,6 +44,8 @@ impl Builder {
         let executor = Executor {
             config,
             client,
+            ended_session,
+            status,
             shutdown,
             metrics,
         };
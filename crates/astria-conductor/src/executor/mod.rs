@@ -1,5 +1,8 @@
 use std::{
-    collections::HashMap,
+    collections::{
+        BTreeMap,
+        HashMap,
+    },
     time::Duration,
 };
 
@@ -7,6 +10,7 @@ use astria_core::{
     execution::v2::{
         CommitmentState,
         ExecutedBlockMetadata,
+        ExecutionSession,
     },
     primitive::v1::RollupId,
     protocol::price_feed::v1::ExtendedCommitInfoWithCurrencyPairMapping,
@@ -35,8 +39,12 @@ use sequencer_client::{
 };
 use tokio::{
     select,
-    sync::mpsc,
+    sync::{
+        mpsc,
+        watch,
+    },
     task::JoinError,
+    time::Instant,
 };
 use tokio_util::{
     sync::CancellationToken,
@@ -54,8 +62,12 @@ use tracing::{
 
 use crate::{
     celestia::ReconstructedBlock,
-    config::CommitLevel,
+    config::{
+        CommitLevel,
+        FirmLagPolicy,
+    },
     metrics::Metrics,
+    persisted_state::StateFile,
     state::{
         self,
         State,
@@ -68,18 +80,31 @@ mod builder;
 pub(crate) use builder::Builder;
 
 mod client;
+mod status;
 #[cfg(test)]
 mod tests;
 pub(super) use client::Client;
+pub(crate) use status::Status;
 
 type CelestiaHeight = u64;
 
+/// How long to wait before asking the rollup again for an execution session succeeding the
+/// ended one.
+const SUCCEEDING_SESSION_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
 pub(crate) struct Executor {
     config: crate::Config,
 
     /// The execution client driving the rollup.
     client: Client,
 
+    /// The state of the previous execution session if it ended at its stop height. The executor
+    /// only runs an execution session succeeding it.
+    ended_session: Option<State>,
+
+    /// The channel to report the executor's status on.
+    status: watch::Sender<Status>,
+
     /// Token to listen for Conductor being shut down.
     shutdown: CancellationToken,
 
@@ -140,7 +165,19 @@ impl Executor {
             .await
             .wrap_err("failed setting initial rollup node state")?;
 
-        let sequencer_cometbft_client = HttpClient::new(&*self.config.sequencer_cometbft_url)
+        let sequencer_chain_id = state.sequencer_chain_id();
+        let sequencer_endpoints = self
+            .config
+            .sequencer_endpoints(&sequencer_chain_id)
+            .wrap_err("failed to determine the endpoints of the sequencer network")?;
+        info!(
+            sequencer.chain_id = %sequencer_chain_id,
+            sequencer.grpc_url = %sequencer_endpoints.grpc_url,
+            sequencer.cometbft_url = %sequencer_endpoints.cometbft_url,
+            "reading sequencer network of execution session",
+        );
+
+        let sequencer_cometbft_client = HttpClient::new(&*sequencer_endpoints.cometbft_url)
             .wrap_err("failed constructing sequencer cometbft RPC client")?;
 
         let reader_cancellation_token = self.shutdown.child_token();
@@ -153,6 +190,11 @@ impl Executor {
         } = create_block_channels(self.config.execution_commit_level, &state)
             .wrap_err("failed to create channels")?;
 
+        let state_file = match self.config.state_path() {
+            Some(path) => Some(StateFile::open(path).await),
+            None => None,
+        };
+
         let mut reader_tasks = JoinMap::new();
         if self.config.is_with_firm() {
             let celestia_token = if self.config.no_celestia_auth {
@@ -164,11 +206,14 @@ impl Executor {
             let reader = crate::celestia::Builder {
                 celestia_http_endpoint: self.config.celestia_node_http_url.clone(),
                 celestia_token,
+                da_backend: self.config.da_backend,
+                local_da_path: self.config.local_da_path.clone(),
                 celestia_block_time: Duration::from_millis(self.config.celestia_block_time_ms),
                 firm_blocks: firm_blocks_tx,
                 rollup_state: state.subscribe(),
                 sequencer_cometbft_client: sequencer_cometbft_client.clone(),
                 sequencer_requests_per_second: self.config.sequencer_requests_per_second,
+                state_file: state_file.clone(),
                 shutdown: reader_cancellation_token.child_token(),
                 metrics: self.metrics,
             }
@@ -179,7 +224,7 @@ impl Executor {
 
         if self.config.is_with_soft() {
             let sequencer_grpc_client =
-                crate::sequencer::SequencerGrpcClient::new(&self.config.sequencer_grpc_url)
+                crate::sequencer::SequencerGrpcClient::new(&sequencer_endpoints.grpc_url)
                     .wrap_err("failed constructing grpc client for Sequencer")?;
 
             let sequencer_reader = crate::sequencer::Builder {
@@ -189,11 +234,19 @@ impl Executor {
                 shutdown: reader_cancellation_token.child_token(),
                 soft_blocks: soft_blocks_tx,
                 rollup_state: state.subscribe(),
+                state_file,
             }
             .build();
             reader_tasks.spawn(ReaderKind::Soft, sequencer_reader.run_until_stopped());
         };
 
+        // The time at which the blocks ahead of the firm commitment were executed as soft
+        // commitments is unknown, so the firm lag is measured from now.
+        let mut soft_executed_at = BTreeMap::new();
+        if state.soft_number() > state.firm_number() {
+            soft_executed_at.insert(state.firm_number().saturating_add(1), Instant::now());
+        }
+
         Ok(Initialized {
             config: self.config,
             client: self.client,
@@ -202,6 +255,8 @@ impl Executor {
             shutdown: self.shutdown,
             state,
             blocks_pending_finalization: HashMap::new(),
+            soft_executed_at,
+            status: self.status,
             metrics: self.metrics,
             reader_tasks,
             reader_cancellation_token,
@@ -211,9 +266,7 @@ impl Executor {
     #[instrument(skip_all, err, ret(Display))]
     async fn create_initial_node_state(&self) -> eyre::Result<StateSender> {
         let execution_session = self
-            .client
-            .clone()
-            .create_execution_session_with_retry()
+            .create_succeeding_execution_session()
             .await
             .wrap_err("failed creating execution session")?;
 
@@ -234,6 +287,49 @@ impl Executor {
 
         Ok(state)
     }
+
+    /// Creates an execution session, waiting for the rollup to offer one succeeding the ended
+    /// execution session if there is one.
+    ///
+    /// The rollup may not have moved on to the next execution session yet when the previous one
+    /// reached its stop height, in which case it keeps offering the ended one.
+    async fn create_succeeding_execution_session(&self) -> eyre::Result<ExecutionSession> {
+        loop {
+            let execution_session = self
+                .client
+                .clone()
+                .create_execution_session_with_retry()
+                .await?;
+            let Some(ended_session) = &self.ended_session else {
+                return Ok(execution_session);
+            };
+            if ended_session.is_succeeded_by(&execution_session) {
+                let parameters = execution_session.execution_session_parameters();
+                info!(
+                    ended_session.id = %ended_session.execution_session_id(),
+                    ended_session.rollup_id = %ended_session.rollup_id(),
+                    ended_session.sequencer_chain_id = %ended_session.sequencer_chain_id(),
+                    session.id = %execution_session.session_id(),
+                    session.rollup_id = %parameters.rollup_id(),
+                    session.sequencer_chain_id = %parameters.sequencer_chain_id(),
+                    session.rollup_start_block_number = parameters.rollup_start_block_number(),
+                    "rollup opened execution session succeeding the ended one",
+                );
+                return Ok(execution_session);
+            }
+            info!(
+                ended_session.id = %ended_session.execution_session_id(),
+                session.id = %execution_session.session_id(),
+                session.rollup_start_block_number = execution_session
+                    .execution_session_parameters()
+                    .rollup_start_block_number(),
+                wait_duration = %telemetry::display::format_duration(SUCCEEDING_SESSION_POLL_INTERVAL),
+                "rollup has not yet opened an execution session succeeding the ended one; \
+                 retrying after wait",
+            );
+            tokio::time::sleep(SUCCEEDING_SESSION_POLL_INTERVAL).await;
+        }
+    }
 }
 
 struct Channels {
@@ -314,6 +410,13 @@ struct Initialized {
     /// without re-executing on top of the rollup node.
     blocks_pending_finalization: HashMap<u64, ExecutedBlockMetadata>,
 
+    /// When the blocks that are not yet firm commitments were executed as soft commitments, keyed
+    /// by their rollup block numbers.
+    soft_executed_at: BTreeMap<u64, Instant>,
+
+    /// The channel to report the executor's status on.
+    status: watch::Sender<Status>,
+
     metrics: &'static Metrics,
 
     /// The tasks reading block data off Celestia or Sequencer.
@@ -343,6 +446,10 @@ impl Initialized {
 
     async fn run_event_loop(&mut self) -> eyre::Result<Option<State>> {
         loop {
+            let firm_lag = self
+                .check_firm_lag()
+                .wrap_err("firm commitments lag too far behind soft commitments")?;
+
             select!(
                 biased;
 
@@ -356,7 +463,7 @@ impl Initialized {
                     self.execute_firm(block).await.wrap_err("failed executing firm block")?;
                 }
 
-                Some(block) = self.soft_blocks.recv(), if !self.is_spread_too_large() =>
+                Some(block) = self.soft_blocks.recv(), if !firm_lag.halt_soft =>
                 {
                     debug_span!("conductor::Executor::run_until_stopped").in_scope(||debug!(
                         block.height = %block.height(),
@@ -370,36 +477,117 @@ impl Initialized {
                     self.handle_task_exit(task, res)?;
                 }
 
+                // wakes up the loop to apply the firm lag policy once the duration is exceeded
+                () = sleep_until_deadline(firm_lag.deadline), if firm_lag.deadline.is_some() => {}
+
                 else => break Ok(Some(self.state.get().clone()))
             );
         }
     }
 
-    /// Returns if the spread between firm and soft commitment heights in the tracked state is too
-    /// large.
+    /// Reports the executor's status and applies the firm lag policy if firm commitments lag
+    /// behind soft commitments by more than the configured thresholds.
     ///
-    /// Always returns `false` if this executor was configured to run without firm commitments.
+    /// Firm commitments can only lag behind if this executor was configured to run with both
+    /// soft and firm commitments.
     ///
-    /// # Panics
+    /// # Errors
     ///
-    /// Panics if called before [`Executor::init`] because `max_spread` must be set.
-    fn is_spread_too_large(&self) -> bool {
-        if !self.config.is_with_firm() {
-            return false;
-        }
-        let (next_firm, next_soft) = {
+    /// Returns an error if the thresholds are exceeded and the policy is to shut down.
+    fn check_firm_lag(&mut self) -> eyre::Result<FirmLag> {
+        let firm_number = self.state.firm_number();
+        self.soft_executed_at
+            .retain(|&number, _| number > firm_number);
+
+        let spread = if self.config.execution_commit_level == CommitLevel::SoftAndFirm {
             let next_firm = self.state.next_expected_firm_sequencer_height().value();
             let next_soft = self.state.next_expected_soft_sequencer_height().value();
-            (next_firm, next_soft)
+            next_soft.saturating_sub(next_firm)
+        } else {
+            0
         };
+        let firm_lagging_since = if spread > 0 {
+            self.soft_executed_at.values().next().copied()
+        } else {
+            None
+        };
+        let lag = firm_lagging_since.map_or(Duration::ZERO, |since| since.elapsed());
 
-        let is_too_far_ahead = next_soft.saturating_sub(next_firm)
-            >= self.state.celestia_search_height_max_look_ahead();
-
-        if is_too_far_ahead {
+        let thresholds = FirmLagThresholds::from_config(
+            &self.config,
+            self.state.celestia_search_height_max_look_ahead(),
+        );
+        let is_exceeded = thresholds.is_exceeded(spread, lag);
+        let policy = self.config.firm_lag_policy;
+        let was_exceeded = self.status.borrow().is_firm_lag_exceeded;
+        if is_exceeded && !was_exceeded {
+            warn!(
+                spread,
+                lag = %telemetry::display::format_duration(lag),
+                %policy,
+                "firm commitments lag behind soft commitments by more than the configured \
+                 thresholds",
+            );
+        } else if !is_exceeded && was_exceeded {
+            info!(
+                spread,
+                lag = %telemetry::display::format_duration(lag),
+                "firm commitments caught up with soft commitments to within the configured \
+                 thresholds",
+            );
+        }
+        let halt_soft = is_exceeded && policy == FirmLagPolicy::HaltSoft;
+        if halt_soft {
             debug!("soft blocks are too far ahead of firm; skipping soft blocks");
         }
-        is_too_far_ahead
+
+        self.report_status(spread, firm_lagging_since, is_exceeded, halt_soft);
+
+        if is_exceeded && policy == FirmLagPolicy::Shutdown {
+            bail!(
+                "firm commitments lag behind soft commitments by `{spread}` blocks and `{}`, \
+                 exceeding the configured thresholds; shutting down as configured by the firm lag \
+                 policy",
+                telemetry::display::format_duration(lag),
+            );
+        }
+
+        let deadline = match (firm_lagging_since, thresholds.max_duration) {
+            (Some(since), Some(max_duration)) if !is_exceeded => since.checked_add(max_duration),
+            _ => None,
+        };
+        Ok(FirmLag {
+            halt_soft,
+            deadline,
+        })
+    }
+
+    fn report_status(
+        &self,
+        spread: u64,
+        firm_lagging_since: Option<Instant>,
+        is_firm_lag_exceeded: bool,
+        is_soft_execution_halted: bool,
+    ) {
+        let state = self.state.get();
+        let status = Status {
+            soft_number: Some(state.soft_number()),
+            firm_number: Some(state.firm_number()),
+            soft_sequencer_height: self
+                .config
+                .is_with_soft()
+                .then(|| state.soft_block_number_as_sequencer_height().value()),
+            firm_sequencer_height: self
+                .config
+                .is_with_firm()
+                .then(|| state.firm_block_number_as_sequencer_height().value()),
+            spread,
+            firm_lagging_since,
+            is_firm_lag_exceeded,
+            is_soft_execution_halted,
+        };
+        drop(state);
+        self.status.send_replace(status);
     }
 
     #[instrument(skip_all, fields(
@@ -455,6 +643,7 @@ impl Initialized {
 
         self.blocks_pending_finalization
             .insert(block_number, executed_block);
+        self.soft_executed_at.insert(block_number, Instant::now());
 
         // XXX: We set an absolute number value here to avoid any potential issues of the remote
         // rollup state and the local state falling out of lock-step.
@@ -763,6 +952,55 @@ impl Initialized {
     }
 }
 
+/// The outcome of checking by how much firm commitments lag behind soft commitments.
+struct FirmLag {
+    /// Whether soft blocks must not be executed.
+    halt_soft: bool,
+    /// When the duration threshold will be exceeded, if firm commitments are lagging behind but
+    /// not yet by more than the thresholds.
+    deadline: Option<Instant>,
+}
+
+/// The thresholds by which firm commitments may lag behind soft commitments.
+#[derive(Debug, PartialEq, Eq)]
+struct FirmLagThresholds {
+    max_blocks: u64,
+    max_duration: Option<Duration>,
+}
+
+impl FirmLagThresholds {
+    /// Reads the thresholds from `config`, falling back to the Celestia search height max look
+    /// ahead of the execution session if the maximum number of blocks is not set.
+    fn from_config(config: &crate::Config, celestia_search_height_max_look_ahead: u64) -> Self {
+        let max_blocks = if config.firm_lag_max_blocks == 0 {
+            celestia_search_height_max_look_ahead
+        } else {
+            config.firm_lag_max_blocks
+        };
+        let max_duration = (config.firm_lag_max_duration_ms > 0)
+            .then(|| Duration::from_millis(config.firm_lag_max_duration_ms));
+        Self {
+            max_blocks,
+            max_duration,
+        }
+    }
+
+    fn is_exceeded(&self, spread: u64, lag: Duration) -> bool {
+        spread > 0
+            && (spread >= self.max_blocks
+                || self
+                    .max_duration
+                    .is_some_and(|max_duration| lag >= max_duration))
+    }
+}
+
+async fn sleep_until_deadline(deadline: Option<Instant>) {
+    match deadline {
+        Some(deadline) => tokio::time::sleep_until(deadline).await,
+        None => std::future::pending().await,
+    }
+}
+
 enum Update {
     OnlyFirm(ExecutedBlockMetadata, CelestiaHeight),
     OnlySoft(ExecutedBlockMetadata),
@@ -0,0 +1,35 @@
+//! The status of the executor, reported by conductor's HTTP API.
+use std::time::Duration;
+
+use tokio::time::Instant;
+
+/// A snapshot of the executor's soft and firm commitments and by how much firm commitments lag
+/// behind soft commitments.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct Status {
+    /// The rollup number of the soft commitment, if an execution session was established.
+    pub(crate) soft_number: Option<u64>,
+    /// The rollup number of the firm commitment, if an execution session was established.
+    pub(crate) firm_number: Option<u64>,
+    /// The sequencer height of the block executed as the soft commitment.
+    pub(crate) soft_sequencer_height: Option<u64>,
+    /// The sequencer height of the block executed as the firm commitment.
+    pub(crate) firm_sequencer_height: Option<u64>,
+    /// The number of blocks executed as soft but not yet as firm commitments.
+    pub(crate) spread: u64,
+    /// When the oldest block executed as a soft but not yet as a firm commitment was executed.
+    pub(crate) firm_lagging_since: Option<Instant>,
+    /// Whether firm commitments lag behind by more than the configured thresholds.
+    pub(crate) is_firm_lag_exceeded: bool,
+    /// Whether soft execution is halted because of the firm lag.
+    pub(crate) is_soft_execution_halted: bool,
+}
+
+impl Status {
+    /// Returns for how long the oldest block executed as a soft commitment has been waiting to
+    /// be executed as a firm commitment.
+    pub(crate) fn firm_lag(&self) -> Duration {
+        self.firm_lagging_since
+            .map_or(Duration::ZERO, |since| since.elapsed())
+    }
+}
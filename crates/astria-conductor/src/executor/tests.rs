@@ -1,3 +1,5 @@
+use std::time::Duration;
+
 use astria_core::{
     self,
     execution::v2::{
@@ -30,10 +32,15 @@ use super::{
         StateReceiver,
         StateSender,
     },
+    FirmLagThresholds,
 };
 use crate::{
     config::CommitLevel,
-    test_utils::make_execution_session_parameters,
+    test_utils::{
+        make_config,
+        make_execution_session_parameters,
+    },
+    Config,
 };
 
 fn make_block_metadata(number: u64) -> raw::ExecutedBlockMetadata {
@@ -273,3 +280,50 @@ fn should_not_prepend_missing_price_feed_data_to_txs() {
     let bytes = super::prepend_transactions_by_price_feed_if_exists(txs.clone(), None);
     assert_eq!(bytes, txs);
 }
+
+#[test]
+fn firm_lag_max_blocks_falls_back_to_celestia_max_look_ahead() {
+    let config = Config {
+        firm_lag_max_blocks: 0,
+        firm_lag_max_duration_ms: 0,
+        ..make_config()
+    };
+    assert_eq!(
+        FirmLagThresholds {
+            max_blocks: 10,
+            max_duration: None,
+        },
+        FirmLagThresholds::from_config(&config, 10),
+    );
+
+    let config = Config {
+        firm_lag_max_blocks: 3,
+        firm_lag_max_duration_ms: 500,
+        ..make_config()
+    };
+    assert_eq!(
+        FirmLagThresholds {
+            max_blocks: 3,
+            max_duration: Some(Duration::from_millis(500)),
+        },
+        FirmLagThresholds::from_config(&config, 10),
+    );
+}
+
+#[test]
+fn firm_lag_is_exceeded_by_spread_or_duration() {
+    let thresholds = FirmLagThresholds {
+        max_blocks: 3,
+        max_duration: Some(Duration::from_millis(500)),
+    };
+    assert!(!thresholds.is_exceeded(0, Duration::from_secs(10)));
+    assert!(!thresholds.is_exceeded(2, Duration::from_millis(499)));
+    assert!(thresholds.is_exceeded(3, Duration::ZERO));
+    assert!(thresholds.is_exceeded(1, Duration::from_millis(500)));
+
+    let thresholds = FirmLagThresholds {
+        max_blocks: 3,
+        max_duration: None,
+    };
+    assert!(!thresholds.is_exceeded(2, Duration::from_secs(3600)));
+}
@@ -12,13 +12,16 @@
 //! blockchain. It's marked as a soft commitment; the block is not regarded as finalized on the
 //! execution layer until it's received from the data availability layer. In the second case, the
 //! execution layer is notified to mark the block as finalized.
+mod api;
 pub(crate) mod block_cache;
 mod build_info;
 pub(crate) mod celestia;
 pub mod conductor;
 pub mod config;
+pub mod conformance;
 pub(crate) mod executor;
 pub(crate) mod metrics;
+pub(crate) mod persisted_state;
 pub(crate) mod sequencer;
 pub(crate) mod state;
 #[cfg(test)]
@@ -0,0 +1,727 @@
+//! Conductor state persisted to a local file to resume from after a restart.
+//!
+//! Without a persisted state, conductor derives where to start reading Celestia and Sequencer
+//! from the rollup's commitment state, so that after a restart it searches Celestia again
+//! starting at the height from which the rollup's firm block was read, and fetches all Sequencer
+//! blocks after the rollup's soft block again. If configured with a state path, the readers
+//! instead periodically record their progress and pick up from there:
+//!
+//! - the Celestia reader records the Celestia height up to which it read all blobs, together with
+//!   the blocks it reconstructed from these heights but which were not yet executed;
+//! - the Sequencer reader records the Sequencer height up to which it fetched all blocks, together
+//!   with the blocks it fetched but which were not yet executed.
+//!
+//! Both readers write to the same file through a shared [`StateFile`], each replacing only its
+//! own progress. The file is replaced atomically by writing the new state to a temporary file
+//! next to it and renaming it, so that a crash while writing leaves the previous state intact.
+use std::{
+    ffi::OsString,
+    io,
+    path::{
+        Path,
+        PathBuf,
+    },
+    sync::Arc,
+};
+
+use astria_core::{
+    generated::astria::{
+        protocol::price_feed::v1 as raw_price_feed,
+        sequencerblock::v1 as raw,
+    },
+    protocol::price_feed::v1::ExtendedCommitInfoWithCurrencyPairMapping,
+    sequencerblock::v1::block::{
+        self,
+        FilteredSequencerBlock,
+        SequencerBlockHeader,
+    },
+};
+use astria_eyre::eyre::{
+    self,
+    ensure,
+    WrapErr as _,
+};
+use base64::{
+    engine::general_purpose::STANDARD,
+    Engine as _,
+};
+use bytes::Bytes;
+use prost::Message as _;
+use serde::{
+    Deserialize,
+    Serialize,
+};
+use tokio::sync::Mutex;
+use tracing::{
+    info,
+    warn,
+};
+
+use crate::{
+    celestia::ReconstructedBlock,
+    state::StateReceiver,
+};
+
+/// A handle to the file to which the Celestia and Sequencer readers persist their progress.
+///
+/// Cloning the handle shares the underlying state, so that each reader only replaces its own
+/// progress while retaining that of the other.
+#[derive(Clone, Debug)]
+pub(crate) struct StateFile {
+    path: Arc<Path>,
+    state: Arc<Mutex<Option<PersistedState>>>,
+}
+
+impl StateFile {
+    /// Opens the state file at `path`, loading the state persisted to it.
+    ///
+    /// A state that cannot be read is ignored so that the readers fall back to the rollup state.
+    pub(crate) async fn open(path: PathBuf) -> Self {
+        let state = match PersistedState::load(&path).await {
+            Ok(Some(state)) => Some(state),
+            Ok(None) => {
+                info!(
+                    path = %path.display(),
+                    "no persisted state found; reading blocks from the rollup state",
+                );
+                None
+            }
+            Err(error) => {
+                warn!(
+                    %error,
+                    path = %path.display(),
+                    "failed loading persisted state; reading blocks from the rollup state",
+                );
+                None
+            }
+        };
+        Self {
+            path: path.into(),
+            state: Arc::new(Mutex::new(state)),
+        }
+    }
+
+    /// Returns the most recently persisted state, if any.
+    pub(crate) async fn persisted_state(&self) -> Option<PersistedState> {
+        self.state.lock().await.clone()
+    }
+
+    /// Records the progress of reading firm blocks from Celestia and writes it to the file.
+    ///
+    /// # Errors
+    /// Returns an error if the state could not be written to the file system.
+    pub(crate) async fn save_firm_progress(
+        &self,
+        rollup_state: &StateReceiver,
+        progress: FirmProgress,
+    ) -> eyre::Result<()> {
+        self.save_with(rollup_state, |state| state.firm = Some(progress))
+            .await
+    }
+
+    /// Records the progress of reading soft blocks from Sequencer and writes it to the file.
+    ///
+    /// # Errors
+    /// Returns an error if the state could not be written to the file system.
+    pub(crate) async fn save_soft_progress(
+        &self,
+        rollup_state: &StateReceiver,
+        progress: SoftProgress,
+    ) -> eyre::Result<()> {
+        self.save_with(rollup_state, |state| state.soft = Some(progress))
+            .await
+    }
+
+    /// Updates the state with the current rollup state and `update`, and writes it to the file.
+    ///
+    /// The progress recorded by the other reader is retained unless it was recorded for a
+    /// different rollup or network.
+    async fn save_with(
+        &self,
+        rollup_state: &StateReceiver,
+        update: impl FnOnce(&mut PersistedState),
+    ) -> eyre::Result<()> {
+        let mut guard = self.state.lock().await;
+        let mut current = PersistedState::from_rollup_state(rollup_state);
+        if let Some(previous) = guard.take() {
+            if previous.is_for_same_rollup_and_networks(&current) {
+                current.firm = previous.firm;
+                current.soft = previous.soft;
+            }
+        }
+        update(&mut current);
+        let state = guard.insert(current);
+        state.save(&self.path).await
+    }
+
+    /// Returns the path of the state file.
+    pub(crate) fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+/// The progress of conductor reading blocks from Celestia and Sequencer.
+#[derive(Clone, Debug)]
+pub(crate) struct PersistedState {
+    /// The ID of the rollup for which the state was recorded.
+    pub(crate) rollup_id: String,
+    /// The chain ID of the sequencer network from which the blocks were read.
+    pub(crate) sequencer_chain_id: String,
+    /// The chain ID of the Celestia network from which the blocks were read.
+    pub(crate) celestia_chain_id: String,
+    /// The rollup's soft number when the state was recorded.
+    pub(crate) soft_number: u64,
+    /// The rollup's firm number when the state was recorded.
+    pub(crate) firm_number: u64,
+    /// The progress of reading firm blocks from Celestia, if recorded.
+    pub(crate) firm: Option<FirmProgress>,
+    /// The progress of reading soft blocks from Sequencer, if recorded.
+    pub(crate) soft: Option<SoftProgress>,
+}
+
+/// The progress of the Celestia reader.
+#[derive(Clone, Debug)]
+pub(crate) struct FirmProgress {
+    /// The Celestia height from which to resume reading. All blobs below it were read.
+    pub(crate) celestia_next_height: u64,
+    /// The Celestia height relative to which the search window is determined.
+    pub(crate) celestia_reference_height: u64,
+    /// The blocks reconstructed from Celestia heights below `celestia_next_height` that were not
+    /// yet executed against the rollup.
+    pub(crate) cached_blocks: Vec<ReconstructedBlock>,
+}
+
+/// The progress of the Sequencer reader.
+#[derive(Clone, Debug)]
+pub(crate) struct SoftProgress {
+    /// The Sequencer height from which to resume fetching blocks.
+    pub(crate) sequencer_next_height: u64,
+    /// The blocks below `sequencer_next_height` that were fetched but not yet executed against
+    /// the rollup, in order of their heights.
+    pub(crate) cached_blocks: Vec<FilteredSequencerBlock>,
+}
+
+impl PersistedState {
+    fn from_rollup_state(rollup_state: &StateReceiver) -> Self {
+        Self {
+            rollup_id: rollup_state.rollup_id().to_string(),
+            sequencer_chain_id: rollup_state.sequencer_chain_id(),
+            celestia_chain_id: rollup_state.celestia_chain_id(),
+            soft_number: rollup_state.soft_number(),
+            firm_number: rollup_state.firm_number(),
+            firm: None,
+            soft: None,
+        }
+    }
+
+    fn is_for_same_rollup_and_networks(&self, other: &Self) -> bool {
+        self.rollup_id == other.rollup_id
+            && self.sequencer_chain_id == other.sequencer_chain_id
+            && self.celestia_chain_id == other.celestia_chain_id
+    }
+
+    /// Ensures that the readers can resume from this state given the current `rollup_state`.
+    ///
+    /// # Errors
+    /// Returns an error if the state was recorded for a different rollup or network, or if the
+    /// rollup has fallen behind it (for example because its database was reset).
+    pub(crate) fn ensure_resumable(&self, rollup_state: &StateReceiver) -> eyre::Result<()> {
+        let rollup_id = rollup_state.rollup_id().to_string();
+        ensure!(
+            self.rollup_id == rollup_id,
+            "persisted state is for rollup `{}`, not `{rollup_id}`",
+            self.rollup_id,
+        );
+        let sequencer_chain_id = rollup_state.sequencer_chain_id();
+        ensure!(
+            self.sequencer_chain_id == sequencer_chain_id,
+            "persisted state is for sequencer network `{}`, not `{sequencer_chain_id}`",
+            self.sequencer_chain_id,
+        );
+        let celestia_chain_id = rollup_state.celestia_chain_id();
+        ensure!(
+            self.celestia_chain_id == celestia_chain_id,
+            "persisted state is for Celestia network `{}`, not `{celestia_chain_id}`",
+            self.celestia_chain_id,
+        );
+        let firm_number = rollup_state.firm_number();
+        let soft_number = rollup_state.soft_number();
+        ensure!(
+            self.firm_number <= firm_number && self.soft_number <= soft_number,
+            "rollup is behind the persisted state: persisted firm number `{}` and soft number \
+             `{}`, rollup firm number `{firm_number}` and soft number `{soft_number}`",
+            self.firm_number,
+            self.soft_number,
+        );
+        Ok(())
+    }
+
+    /// Reads the persisted state from `path`.
+    ///
+    /// Returns `None` if no state was persisted at `path` yet.
+    ///
+    /// # Errors
+    /// Returns an error if `path` could not be read or does not contain a valid state.
+    pub(crate) async fn load(path: &Path) -> eyre::Result<Option<Self>> {
+        let bytes = match tokio::fs::read(path).await {
+            Ok(bytes) => bytes,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(None),
+            Err(error) => {
+                return Err(error).wrap_err_with(|| format!("failed reading `{}`", path.display()));
+            }
+        };
+        let raw: RawPersistedState = serde_json::from_slice(&bytes)
+            .wrap_err_with(|| format!("failed parsing contents of `{}`", path.display()))?;
+        Self::try_from_raw(raw)
+            .wrap_err_with(|| format!("`{}` contains an invalid state", path.display()))
+            .map(Some)
+    }
+
+    /// Atomically replaces the state persisted at `path` by this state.
+    ///
+    /// # Errors
+    /// Returns an error if the state could not be written to the file system.
+    pub(crate) async fn save(&self, path: &Path) -> eyre::Result<()> {
+        let bytes =
+            serde_json::to_vec(&self.to_raw()).wrap_err("failed serializing state as JSON")?;
+        let temp_path = temp_path(path);
+        let file = tokio::fs::File::create(&temp_path)
+            .await
+            .wrap_err_with(|| format!("failed creating `{}`", temp_path.display()))?;
+        let mut file = tokio::io::BufWriter::new(file);
+        tokio::io::AsyncWriteExt::write_all(&mut file, &bytes)
+            .await
+            .wrap_err_with(|| format!("failed writing to `{}`", temp_path.display()))?;
+        tokio::io::AsyncWriteExt::flush(&mut file)
+            .await
+            .wrap_err_with(|| format!("failed flushing `{}`", temp_path.display()))?;
+        file.into_inner()
+            .sync_all()
+            .await
+            .wrap_err_with(|| format!("failed syncing `{}` to disk", temp_path.display()))?;
+        tokio::fs::rename(&temp_path, path).await.wrap_err_with(|| {
+            format!(
+                "failed renaming `{}` to `{}`",
+                temp_path.display(),
+                path.display()
+            )
+        })
+    }
+
+    fn to_raw(&self) -> RawPersistedState {
+        RawPersistedState {
+            rollup_id: self.rollup_id.clone(),
+            sequencer_chain_id: self.sequencer_chain_id.clone(),
+            celestia_chain_id: self.celestia_chain_id.clone(),
+            soft_number: self.soft_number,
+            firm_number: self.firm_number,
+            firm: self.firm.as_ref().map(|firm| RawFirmProgress {
+                celestia_next_height: firm.celestia_next_height,
+                celestia_reference_height: firm.celestia_reference_height,
+                cached_blocks: firm
+                    .cached_blocks
+                    .iter()
+                    .cloned()
+                    .map(RawReconstructedBlock::from_block)
+                    .collect(),
+            }),
+            soft: self.soft.as_ref().map(|soft| RawSoftProgress {
+                sequencer_next_height: soft.sequencer_next_height,
+                sequencer_cached_blocks: soft
+                    .cached_blocks
+                    .iter()
+                    .cloned()
+                    .map(FilteredSequencerBlock::into_raw)
+                    .collect(),
+            }),
+        }
+    }
+
+    fn try_from_raw(raw: RawPersistedState) -> eyre::Result<Self> {
+        let RawPersistedState {
+            rollup_id,
+            sequencer_chain_id,
+            celestia_chain_id,
+            soft_number,
+            firm_number,
+            firm,
+            soft,
+        } = raw;
+        let firm = firm
+            .map(|firm| -> eyre::Result<_> {
+                let cached_blocks = firm
+                    .cached_blocks
+                    .into_iter()
+                    .map(RawReconstructedBlock::try_into_block)
+                    .collect::<eyre::Result<_>>()
+                    .wrap_err("invalid cached block")?;
+                Ok(FirmProgress {
+                    celestia_next_height: firm.celestia_next_height,
+                    celestia_reference_height: firm.celestia_reference_height,
+                    cached_blocks,
+                })
+            })
+            .transpose()?;
+        let soft = soft
+            .map(|soft| -> eyre::Result<_> {
+                let cached_blocks = soft
+                    .sequencer_cached_blocks
+                    .into_iter()
+                    .map(FilteredSequencerBlock::try_from_raw)
+                    .collect::<Result<_, _>>()
+                    .wrap_err("invalid cached sequencer block")?;
+                Ok(SoftProgress {
+                    sequencer_next_height: soft.sequencer_next_height,
+                    cached_blocks,
+                })
+            })
+            .transpose()?;
+        Ok(Self {
+            rollup_id,
+            sequencer_chain_id,
+            celestia_chain_id,
+            soft_number,
+            firm_number,
+            firm,
+            soft,
+        })
+    }
+}
+
+/// Returns the path of the temporary file to which the state at `path` is written before
+/// replacing it.
+fn temp_path(path: &Path) -> PathBuf {
+    let mut temp_path = OsString::from(path.as_os_str());
+    temp_path.push(".tmp");
+    PathBuf::from(temp_path)
+}
+
+/// The on-disk representation of [`PersistedState`].
+#[derive(Deserialize, Serialize)]
+struct RawPersistedState {
+    rollup_id: String,
+    sequencer_chain_id: String,
+    celestia_chain_id: String,
+    soft_number: u64,
+    firm_number: u64,
+    #[serde(flatten)]
+    firm: Option<RawFirmProgress>,
+    #[serde(flatten)]
+    soft: Option<RawSoftProgress>,
+}
+
+/// The on-disk representation of [`FirmProgress`].
+#[derive(Deserialize, Serialize)]
+struct RawFirmProgress {
+    celestia_next_height: u64,
+    celestia_reference_height: u64,
+    cached_blocks: Vec<RawReconstructedBlock>,
+}
+
+/// The on-disk representation of [`SoftProgress`].
+#[derive(Deserialize, Serialize)]
+struct RawSoftProgress {
+    sequencer_next_height: u64,
+    sequencer_cached_blocks: Vec<raw::FilteredSequencerBlock>,
+}
+
+/// The on-disk representation of a [`ReconstructedBlock`].
+#[derive(Deserialize, Serialize)]
+struct RawReconstructedBlock {
+    celestia_height: u64,
+    /// The hex-encoded block hash.
+    block_hash: String,
+    header: raw::SequencerBlockHeader,
+    /// The base64-encoded transactions.
+    transactions: Vec<String>,
+    /// The base64-encoded protobuf of the extended commit info.
+    extended_commit_info: Option<String>,
+}
+
+impl RawReconstructedBlock {
+    fn from_block(block: ReconstructedBlock) -> Self {
+        let ReconstructedBlock {
+            celestia_height,
+            block_hash,
+            header,
+            transactions,
+            extended_commit_info,
+        } = block;
+        Self {
+            celestia_height,
+            block_hash: hex::encode(block_hash.as_bytes()),
+            header: header.into_raw(),
+            transactions: transactions
+                .iter()
+                .map(|transaction| STANDARD.encode(transaction))
+                .collect(),
+            extended_commit_info: extended_commit_info
+                .map(|info| STANDARD.encode(info.into_raw().encode_to_vec())),
+        }
+    }
+
+    fn try_into_block(self) -> eyre::Result<ReconstructedBlock> {
+        let Self {
+            celestia_height,
+            block_hash,
+            header,
+            transactions,
+            extended_commit_info,
+        } = self;
+        let block_hash = hex::decode(&block_hash)
+            .wrap_err("block hash is not hex encoded")
+            .and_then(|bytes| {
+                block::Hash::try_from(&*bytes).wrap_err("block hash has invalid length")
+            })?;
+        let header = SequencerBlockHeader::try_from_raw(header)
+            .wrap_err("invalid sequencer block header")?;
+        let transactions = transactions
+            .iter()
+            .map(|transaction| STANDARD.decode(transaction).map(Bytes::from))
+            .collect::<Result<_, _>>()
+            .wrap_err("transaction is not base64 encoded")?;
+        let extended_commit_info = extended_commit_info
+            .map(|info| {
+                let bytes = STANDARD
+                    .decode(info)
+                    .wrap_err("extended commit info is not base64 encoded")?;
+                let raw =
+                    raw_price_feed::ExtendedCommitInfoWithCurrencyPairMapping::decode(&*bytes)
+                        .wrap_err("failed decoding extended commit info as protobuf")?;
+                ExtendedCommitInfoWithCurrencyPairMapping::try_from_raw(raw)
+                    .wrap_err("invalid extended commit info")
+            })
+            .transpose()?;
+        Ok(ReconstructedBlock {
+            celestia_height,
+            block_hash,
+            header,
+            transactions,
+            extended_commit_info,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use astria_core::{
+        primitive::v1::RollupId,
+        protocol::test_utils::ConfigureSequencerBlock,
+    };
+
+    use super::*;
+    use crate::test_utils::{
+        make_commitment_state,
+        make_execution_session_parameters,
+        make_rollup_state,
+    };
+
+    fn make_block(height: u32) -> ReconstructedBlock {
+        let rollup_id = RollupId::from_unhashed_bytes("rollup");
+        let block = ConfigureSequencerBlock {
+            height,
+            sequence_data: vec![(rollup_id, height.to_le_bytes().to_vec())],
+            ..ConfigureSequencerBlock::default()
+        }
+        .make();
+        ReconstructedBlock {
+            celestia_height: u64::from(height).saturating_add(100),
+            block_hash: *block.block_hash(),
+            header: block.header().clone(),
+            transactions: block
+                .rollup_transactions()
+                .get(&rollup_id)
+                .unwrap()
+                .transactions()
+                .to_vec(),
+            extended_commit_info: None,
+        }
+    }
+
+    fn make_filtered_block(height: u32) -> FilteredSequencerBlock {
+        let rollup_id = RollupId::from_unhashed_bytes("rollup");
+        ConfigureSequencerBlock {
+            height,
+            sequence_data: vec![(rollup_id, height.to_le_bytes().to_vec())],
+            ..ConfigureSequencerBlock::default()
+        }
+        .make()
+        .into_filtered_block([rollup_id])
+    }
+
+    fn make_firm_progress() -> FirmProgress {
+        FirmProgress {
+            celestia_next_height: 110,
+            celestia_reference_height: 104,
+            cached_blocks: vec![make_block(4), make_block(5)],
+        }
+    }
+
+    fn make_soft_progress() -> SoftProgress {
+        SoftProgress {
+            sequencer_next_height: 8,
+            cached_blocks: vec![make_filtered_block(6), make_filtered_block(7)],
+        }
+    }
+
+    fn make_state() -> PersistedState {
+        PersistedState {
+            rollup_id: RollupId::from_unhashed_bytes("rollup").to_string(),
+            sequencer_chain_id: "sequencer".to_string(),
+            celestia_chain_id: "celestia".to_string(),
+            soft_number: 5,
+            firm_number: 3,
+            firm: Some(make_firm_progress()),
+            soft: Some(make_soft_progress()),
+        }
+    }
+
+    fn make_rollup_state_receiver() -> StateReceiver {
+        let (_, rx) = crate::state::channel(make_rollup_state(
+            "session".to_string(),
+            make_execution_session_parameters(),
+            make_commitment_state(),
+        ));
+        rx
+    }
+
+    #[tokio::test]
+    async fn saved_state_is_loaded() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("state.json");
+        assert!(PersistedState::load(&path).await.unwrap().is_none());
+
+        let state = make_state();
+        state.save(&path).await.unwrap();
+        let loaded = PersistedState::load(&path).await.unwrap().unwrap();
+        assert!(!temp_path(&path).exists());
+
+        assert_eq!(loaded.rollup_id, state.rollup_id);
+        assert_eq!(loaded.sequencer_chain_id, state.sequencer_chain_id);
+        assert_eq!(loaded.celestia_chain_id, state.celestia_chain_id);
+        assert_eq!(loaded.soft_number, state.soft_number);
+        assert_eq!(loaded.firm_number, state.firm_number);
+
+        let (loaded_firm, expected_firm) = (loaded.firm.unwrap(), state.firm.unwrap());
+        assert_eq!(
+            loaded_firm.celestia_next_height,
+            expected_firm.celestia_next_height
+        );
+        assert_eq!(
+            loaded_firm.celestia_reference_height,
+            expected_firm.celestia_reference_height
+        );
+        assert_eq!(
+            loaded_firm.cached_blocks.len(),
+            expected_firm.cached_blocks.len()
+        );
+        for (loaded, expected) in loaded_firm
+            .cached_blocks
+            .iter()
+            .zip(&expected_firm.cached_blocks)
+        {
+            assert_eq!(loaded.celestia_height, expected.celestia_height);
+            assert_eq!(loaded.block_hash, expected.block_hash);
+            assert_eq!(loaded.header, expected.header);
+            assert_eq!(loaded.transactions, expected.transactions);
+        }
+
+        let (loaded_soft, expected_soft) = (loaded.soft.unwrap(), state.soft.unwrap());
+        assert_eq!(
+            loaded_soft.sequencer_next_height,
+            expected_soft.sequencer_next_height
+        );
+        assert_eq!(loaded_soft.cached_blocks, expected_soft.cached_blocks);
+    }
+
+    #[tokio::test]
+    async fn state_without_progress_is_loaded() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("state.json");
+        let state = PersistedState {
+            firm: None,
+            soft: None,
+            ..make_state()
+        };
+        state.save(&path).await.unwrap();
+        let loaded = PersistedState::load(&path).await.unwrap().unwrap();
+        assert!(loaded.firm.is_none());
+        assert!(loaded.soft.is_none());
+    }
+
+    #[tokio::test]
+    async fn saving_replaces_previous_state() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("state.json");
+        make_state().save(&path).await.unwrap();
+
+        let state = PersistedState {
+            firm: Some(FirmProgress {
+                celestia_next_height: 120,
+                cached_blocks: vec![],
+                ..make_firm_progress()
+            }),
+            soft: None,
+            ..make_state()
+        };
+        state.save(&path).await.unwrap();
+        let loaded = PersistedState::load(&path).await.unwrap().unwrap();
+        let firm = loaded.firm.unwrap();
+        assert_eq!(firm.celestia_next_height, 120);
+        assert!(firm.cached_blocks.is_empty());
+        assert!(loaded.soft.is_none());
+    }
+
+    #[tokio::test]
+    async fn corrupted_state_is_an_error() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("state.json");
+        std::fs::write(&path, b"{\"rollup_id\":").unwrap();
+        assert!(PersistedState::load(&path).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn state_file_retains_progress_of_other_reader() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("state.json");
+        let rollup_state = make_rollup_state_receiver();
+
+        let state_file = StateFile::open(path.clone()).await;
+        assert!(state_file.persisted_state().await.is_none());
+        state_file
+            .save_firm_progress(&rollup_state, make_firm_progress())
+            .await
+            .unwrap();
+        state_file
+            .clone()
+            .save_soft_progress(&rollup_state, make_soft_progress())
+            .await
+            .unwrap();
+
+        let loaded = StateFile::open(path).await.persisted_state().await.unwrap();
+        loaded.ensure_resumable(&rollup_state).unwrap();
+        assert_eq!(loaded.firm.unwrap().celestia_next_height, 110);
+        assert_eq!(loaded.soft.unwrap().sequencer_next_height, 8);
+    }
+
+    #[tokio::test]
+    async fn state_file_drops_progress_recorded_for_other_rollup() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("state.json");
+        make_state().save(&path).await.unwrap();
+        let rollup_state = make_rollup_state_receiver();
+
+        let state_file = StateFile::open(path.clone()).await;
+        let persisted_state = state_file.persisted_state().await.unwrap();
+        assert!(persisted_state.ensure_resumable(&rollup_state).is_err());
+        state_file
+            .save_soft_progress(&rollup_state, make_soft_progress())
+            .await
+            .unwrap();
+
+        let loaded = PersistedState::load(&path).await.unwrap().unwrap();
+        loaded.ensure_resumable(&rollup_state).unwrap();
+        assert!(loaded.firm.is_none());
+        assert!(loaded.soft.is_some());
+    }
+}
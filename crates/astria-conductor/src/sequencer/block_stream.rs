@@ -147,6 +147,16 @@ impl BlocksFromHeightStream {
         }
     }
 
+    /// Skips fetching all heights below `height`.
+    ///
+    /// Used when resuming from persisted blocks so that they are not fetched again.
+    pub(super) fn skip_heights_below(&mut self, height: Height) {
+        if let Some(greatest_skipped_height) = height.value().checked_sub(1) {
+            self.heights
+                .set_greatest_if_greater(greatest_skipped_height);
+        }
+    }
+
     /// Returns a stream of Sequencer Blocks for `rollup_id` and starting from `first_height`.
     ///
     /// Note that [`BlocksFromHeightStream::set_latest_observed_height_if_greater`] needs to
@@ -5,7 +5,10 @@ use tokio::sync::mpsc;
 use tokio_util::sync::CancellationToken;
 
 use super::SequencerGrpcClient;
-use crate::state::StateReceiver;
+use crate::{
+    persisted_state::StateFile,
+    state::StateReceiver,
+};
 
 pub(crate) struct Builder {
     pub(crate) sequencer_grpc_client: SequencerGrpcClient,
@@ -14,6 +17,7 @@ pub(crate) struct Builder {
     pub(crate) shutdown: CancellationToken,
     pub(crate) rollup_state: StateReceiver,
     pub(crate) soft_blocks: mpsc::Sender<FilteredSequencerBlock>,
+    pub(crate) state_file: Option<StateFile>,
 }
 
 impl Builder {
@@ -25,6 +29,7 @@ impl Builder {
             shutdown,
             rollup_state,
             soft_blocks,
+            state_file,
         } = self;
         super::Reader {
             rollup_state,
@@ -32,6 +37,7 @@ impl Builder {
             sequencer_grpc_client,
             sequencer_cometbft_client,
             sequencer_block_time,
+            state_file,
             shutdown,
         }
     }
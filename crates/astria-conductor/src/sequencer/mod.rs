@@ -1,6 +1,9 @@
 //! [`Reader`] reads reads blocks from sequencer and forwards them to [`crate::executor::Executor`].
 
-use std::time::Duration;
+use std::{
+    collections::BTreeMap,
+    time::Duration,
+};
 
 use astria_core::sequencerblock::v1::block::FilteredSequencerBlock;
 use astria_eyre::eyre::{
@@ -29,6 +32,11 @@ use sequencer_client::{
 use tokio::{
     select,
     sync::mpsc,
+    time::{
+        interval,
+        Interval,
+        MissedTickBehavior,
+    },
 };
 use tokio_util::sync::CancellationToken;
 use tracing::{
@@ -44,6 +52,11 @@ use tracing::{
 
 use crate::{
     block_cache::BlockCache,
+    persisted_state::{
+        PersistedState,
+        SoftProgress,
+        StateFile,
+    },
     sequencer::block_stream::BlocksFromHeightStream,
     state::StateReceiver,
 };
@@ -74,6 +87,9 @@ pub(crate) struct Reader {
     /// height.
     sequencer_block_time: Duration,
 
+    /// The file to which the reader's progress is persisted, if any.
+    state_file: Option<StateFile>,
+
     /// Token to listen for Conductor being shut down.
     shutdown: CancellationToken,
 }
@@ -88,7 +104,11 @@ impl Reader {
                 res?;
             }
         );
-        RunningReader::try_from_parts(self)
+        let persisted_state = match &self.state_file {
+            Some(state_file) => state_file.persisted_state().await,
+            None => None,
+        };
+        RunningReader::try_from_parts(self, persisted_state)
             .wrap_err("failed entering run loop")?
             .run_until_stopped()
             .await
@@ -132,16 +152,31 @@ struct RunningReader {
     enqueued_block:
         Fuse<BoxFuture<'static, Result<(), mpsc::error::SendError<FilteredSequencerBlock>>>>,
 
+    /// The file to which the reader's progress is persisted, if any.
+    state_file: Option<StateFile>,
+
+    /// Ticks whenever the reader's progress should be persisted to `state_file`.
+    persist_interval: Interval,
+
+    /// The blocks forwarded to the executor, keyed by their heights. Retained until the rollup
+    /// executed them so that they are persisted together with the cached blocks. Only recorded
+    /// if `state_file` is set.
+    forwarded_blocks: BTreeMap<u64, FilteredSequencerBlock>,
+
     /// Token to listen for Conductor being shut down.
     shutdown: CancellationToken,
 }
 
 impl RunningReader {
-    fn try_from_parts(reader: Reader) -> eyre::Result<Self> {
+    fn try_from_parts(
+        reader: Reader,
+        persisted_state: Option<PersistedState>,
+    ) -> eyre::Result<Self> {
         let Reader {
             sequencer_grpc_client,
             sequencer_cometbft_client,
             sequencer_block_time,
+            state_file,
             shutdown,
             rollup_state,
             soft_blocks,
@@ -164,21 +199,136 @@ impl RunningReader {
             sequencer_grpc_client,
         );
 
+        let mut persist_interval = interval(sequencer_block_time);
+        persist_interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
         let enqueued_block: Fuse<BoxFuture<Result<_, _>>> = future::Fuse::terminated();
-        Ok(RunningReader {
+        let mut reader = RunningReader {
             rollup_state,
             soft_blocks,
             block_cache,
             latest_height_stream,
             blocks_from_heights,
             enqueued_block,
+            state_file,
+            persist_interval,
+            forwarded_blocks: BTreeMap::new(),
             shutdown,
-        })
+        };
+        if let Some(persisted_state) = persisted_state {
+            reader.resume_from(persisted_state);
+        }
+        Ok(reader)
+    }
+
+    /// Restores the blocks fetched but not yet executed before a restart from `persisted_state`,
+    /// and resumes fetching blocks after them.
+    ///
+    /// The persisted state is ignored if it was recorded for a different rollup or network, or if
+    /// the rollup has fallen behind it.
+    #[instrument(skip_all)]
+    fn resume_from(&mut self, persisted_state: PersistedState) {
+        if let Err(reason) = persisted_state.ensure_resumable(&self.rollup_state) {
+            warn!(%reason, "ignoring persisted state; fetching blocks from the rollup state");
+            return;
+        }
+        let Some(progress) = persisted_state.soft else {
+            info!("no Sequencer progress was persisted; fetching blocks from the rollup state");
+            return;
+        };
+        let stop_height = self.rollup_state.sequencer_stop_height();
+        let mut next_height = self.block_cache.next_height_to_pop();
+        for block in progress.cached_blocks {
+            let height = block.height().value();
+            if height < next_height {
+                continue;
+            }
+            if height > next_height
+                || height >= progress.sequencer_next_height
+                || stop_height.is_some_and(|stop_height| height > stop_height.get())
+            {
+                break;
+            }
+            if self.block_cache.insert(block).is_err() {
+                break;
+            }
+            next_height = next_height.saturating_add(1);
+        }
+        let restored_blocks = next_height.saturating_sub(self.block_cache.next_height_to_pop());
+        if let Ok(height) = Height::try_from(next_height) {
+            self.blocks_from_heights.skip_heights_below(height);
+        }
+        info!(
+            sequencer_next_height = next_height,
+            restored_blocks, "resuming from persisted state",
+        );
+    }
+
+    /// Returns the reader's current progress.
+    ///
+    /// These are the blocks starting at the height next expected by the rollup that were either
+    /// forwarded to the executor but not yet executed, or are still cached, up to the first
+    /// height that was not yet fetched.
+    fn current_progress(&self) -> SoftProgress {
+        let mut sequencer_next_height = self
+            .rollup_state
+            .next_expected_soft_sequencer_height()
+            .value();
+        let mut cached_blocks = vec![];
+        for block in self
+            .forwarded_blocks
+            .range(sequencer_next_height..)
+            .map(|(_, block)| block)
+            .chain(self.block_cache.blocks())
+        {
+            let height = block.height().value();
+            if height < sequencer_next_height {
+                continue;
+            }
+            if height > sequencer_next_height {
+                break;
+            }
+            cached_blocks.push(block.clone());
+            sequencer_next_height = sequencer_next_height.saturating_add(1);
+        }
+        SoftProgress {
+            sequencer_next_height,
+            cached_blocks,
+        }
+    }
+
+    /// Writes the reader's current progress to the state file, if configured.
+    #[instrument(skip_all)]
+    async fn persist_state(&mut self) {
+        let Some(state_file) = &self.state_file else {
+            return;
+        };
+        let next_soft_height = self
+            .rollup_state
+            .next_expected_soft_sequencer_height()
+            .value();
+        self.forwarded_blocks = self.forwarded_blocks.split_off(&next_soft_height);
+        let progress = self.current_progress();
+        let sequencer_next_height = progress.sequencer_next_height;
+        let cached_blocks = progress.cached_blocks.len();
+        match state_file
+            .save_soft_progress(&self.rollup_state, progress)
+            .await
+        {
+            Ok(()) => trace!(sequencer_next_height, cached_blocks, "persisted state"),
+            Err(error) => warn!(
+                %error,
+                path = %state_file.path().display(),
+                "failed persisting state; will try again on the next tick",
+            ),
+        }
     }
 
     async fn run_until_stopped(mut self) -> eyre::Result<()> {
         let stop_reason = self.run_loop().await;
 
+        self.persist_state().await;
+
         // XXX: explicitly setting the message (usually implicitly set by tracing)
         let message = "shutting down";
         report_exit(stop_reason, message)
@@ -232,6 +382,10 @@ impl RunningReader {
                 Some(res) = self.latest_height_stream.next() => {
                     self.handle_latest_height(res);
                 }
+
+                _ = self.persist_interval.tick(), if self.state_file.is_some() => {
+                    self.persist_state().await;
+                }
             }
         }
     }
@@ -258,6 +412,10 @@ impl RunningReader {
     /// Enqueues the block is the channel to the executor is full, sending it once
     /// it frees up.
     fn send_to_executor(&mut self, block: FilteredSequencerBlock) -> eyre::Result<()> {
+        if self.state_file.is_some() {
+            self.forwarded_blocks
+                .insert(block.height().value(), block.clone());
+        }
         if let Err(err) = self.soft_blocks.try_send(block) {
             match err {
                 mpsc::error::TrySendError::Full(block) => {
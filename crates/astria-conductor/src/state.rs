@@ -224,6 +224,7 @@ forward_impls!(
     [firm_hash -> String],
     [soft_hash -> String],
     [rollup_id -> RollupId],
+    [sequencer_chain_id -> String],
     [sequencer_start_block_height -> u64],
     [lowest_celestia_search_height -> u64],
     [celestia_search_height_max_look_ahead -> u64],
@@ -235,6 +236,8 @@ forward_impls!(
 
 forward_impls!(
     StateReceiver:
+    [firm_number -> u64],
+    [soft_number -> u64],
     [lowest_celestia_search_height -> u64],
     [celestia_search_height_max_look_ahead -> u64],
     [rollup_id -> RollupId],
@@ -307,6 +310,20 @@ impl State {
         self.commitment_state.soft().number() >= rollup_end_block_number.get()
     }
 
+    /// Returns if `execution_session` succeeds the execution session of this state, i.e. if it
+    /// starts after the rollup stop block number.
+    ///
+    /// Always returns `false` if the execution session of this state has no stop block number.
+    pub(crate) fn is_succeeded_by(&self, execution_session: &ExecutionSession) -> bool {
+        let Some(rollup_end_block_number) = self.rollup_end_block_number() else {
+            return false;
+        };
+        execution_session
+            .execution_session_parameters()
+            .rollup_start_block_number()
+            > rollup_end_block_number.get()
+    }
+
     /// Sets the inner commitment state.
     fn set_commitment_state(&mut self, commitment_state: CommitmentState) {
         self.commitment_state = commitment_state;
@@ -316,7 +333,7 @@ impl State {
         &self.execution_session_parameters
     }
 
-    fn execution_session_id(&self) -> String {
+    pub(crate) fn execution_session_id(&self) -> String {
         self.execution_session_id.clone()
     }
 
@@ -358,7 +375,7 @@ impl State {
             .sequencer_start_block_height()
     }
 
-    fn sequencer_chain_id(&self) -> String {
+    pub(crate) fn sequencer_chain_id(&self) -> String {
         self.execution_session_parameters
             .sequencer_chain_id()
             .to_string()
@@ -370,7 +387,7 @@ impl State {
             .to_string()
     }
 
-    fn rollup_id(&self) -> RollupId {
+    pub(crate) fn rollup_id(&self) -> RollupId {
         self.execution_session_parameters.rollup_id()
     }
 
@@ -463,6 +480,8 @@ pub(super) fn try_map_sequencer_height_to_rollup_height(
 
 #[cfg(test)]
 mod tests {
+    use astria_core::Protobuf as _;
+
     use super::*;
     use crate::test_utils::{
         make_commitment_state,
@@ -520,6 +539,34 @@ mod tests {
         map_rollup_number_to_sequencer_height(10, 11, 9).unwrap();
     }
 
+    #[test]
+    fn only_sessions_starting_after_stop_number_are_successors() {
+        let ended = make_rollup_state(
+            "ended_session".to_string(),
+            make_execution_session_parameters(),
+            make_commitment_state(),
+        );
+        let make_session = |rollup_start_block_number| {
+            ExecutionSession::try_from_raw(
+                astria_core::generated::astria::execution::v2::ExecutionSession {
+                    session_id: "next_session".to_string(),
+                    execution_session_parameters: Some(
+                        astria_core::generated::astria::execution::v2::ExecutionSessionParameters {
+                            rollup_start_block_number,
+                            rollup_end_block_number: 0,
+                            ..make_execution_session_parameters()
+                        },
+                    ),
+                    commitment_state: Some(make_commitment_state()),
+                },
+            )
+            .unwrap()
+        };
+        assert!(!ended.is_succeeded_by(&make_session(1)));
+        assert!(!ended.is_succeeded_by(&make_session(10)));
+        assert!(ended.is_succeeded_by(&make_session(11)));
+    }
+
     #[test]
     fn mapping_rollup_height_to_sequencer_height_works() {
         assert_height_is_correct(0, 0, 0, 0);
@@ -1,3 +1,5 @@
+use std::path::PathBuf;
+
 use astria_core::{
     execution::v2::ExecutionSession,
     generated::astria::execution::v2::{
@@ -8,7 +10,43 @@ use astria_core::{
     Protobuf as _,
 };
 
-use crate::state::State;
+use crate::{
+    config::{
+        CommitLevel,
+        DaBackend,
+        FirmLagPolicy,
+    },
+    state::State,
+    Config,
+};
+
+pub(crate) fn make_config() -> Config {
+    Config {
+        celestia_block_time_ms: 0,
+        celestia_node_http_url: String::new(),
+        no_celestia_auth: false,
+        celestia_bearer_token: String::new(),
+        da_backend: DaBackend::Celestia,
+        local_da_path: PathBuf::new(),
+        state_path: PathBuf::new(),
+        sequencer_grpc_url: String::new(),
+        sequencer_cometbft_url: String::new(),
+        sequencer_networks: String::new(),
+        sequencer_block_time_ms: 0,
+        sequencer_requests_per_second: 0,
+        execution_rpc_url: String::new(),
+        log: String::new(),
+        execution_commit_level: CommitLevel::SoftAndFirm,
+        firm_lag_max_blocks: 0,
+        firm_lag_max_duration_ms: 0,
+        firm_lag_policy: FirmLagPolicy::HaltSoft,
+        api_addr: "127.0.0.1:0".into(),
+        force_stdout: false,
+        no_otel: false,
+        no_metrics: false,
+        metrics_http_listener_addr: String::new(),
+    }
+}
 
 pub(crate) fn make_commitment_state() -> CommitmentState {
     let firm = astria_core::generated::astria::execution::v2::ExecutedBlockMetadata {
@@ -1,11 +1,20 @@
-use std::time::Duration;
+use std::{
+    sync::LazyLock,
+    time::Duration,
+};
 
 use astria_conductor::{
-    config::CommitLevel,
+    config::{
+        CommitLevel,
+        DaBackend,
+    },
     Conductor,
     Config,
 };
-use astria_core::generated::astria::execution::v2::CreateExecutionSessionRequest;
+use astria_core::{
+    celestia::local,
+    generated::astria::execution::v2::CreateExecutionSessionRequest,
+};
 use futures::future::{
     join,
     join4,
@@ -26,12 +35,16 @@ use crate::{
     celestia_network_head,
     execution_session,
     helpers::{
+        make_blobs,
         make_config,
         spawn_conductor,
+        spawn_conductor_with_config,
         MockGrpc,
         CELESTIA_BEARER_TOKEN,
         CELESTIA_CHAIN_ID,
+        ROLLUP_ID,
         SEQUENCER_CHAIN_ID,
+        TELEMETRY,
     },
     mount_celestia_blobs,
     mount_celestia_header_network_head,
@@ -126,6 +139,231 @@ async fn simple() {
     );
 }
 
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn simple_with_local_data_availability() {
+    let local_da = tempfile::tempdir().unwrap();
+    let root = local_da.path();
+    std::fs::write(local::chain_id_path(root), CELESTIA_CHAIN_ID).unwrap();
+    let blobs = make_blobs(&[3]);
+    for blob in [blobs.header, blobs.rollup] {
+        let dir = local::blobs_dir(root, &blob.namespace, 1);
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("0"), &blob.data).unwrap();
+    }
+    std::fs::write(local::latest_height_path(root), "1").unwrap();
+
+    let test_conductor = spawn_conductor_with_config(CommitLevel::FirmOnly, |config| Config {
+        da_backend: DaBackend::Local,
+        local_da_path: root.to_path_buf(),
+        ..config
+    })
+    .await;
+
+    mount_create_execution_session!(
+        test_conductor,
+        execution_session_parameters: (
+            rollup_start_block_number: 2,
+            rollup_end_block_number: 9,
+            sequencer_start_block_height: 3,
+            celestia_max_look_ahead: 10,
+        ),
+        commitment_state: (
+            firm: (
+                number: 1,
+                hash: "1",
+                parent: "0",
+            ),
+            soft: (
+                number: 1,
+                hash: "1",
+                parent: "0",
+            ),
+            lowest_celestia_search_height: 1,
+        )
+    );
+
+    mount_sequencer_genesis!(test_conductor);
+
+    mount_sequencer_commit!(
+        test_conductor,
+        height: 3u32,
+    );
+
+    mount_sequencer_validator_set!(test_conductor, height: 2u32);
+
+    let execute_block = mount_execute_block!(
+        test_conductor,
+        number: 2,
+        hash: "2",
+        parent: "1",
+    );
+
+    let update_commitment_state = mount_update_commitment_state!(
+        test_conductor,
+        firm: (
+            number: 2,
+            hash: "2",
+            parent: "1",
+        ),
+        soft: (
+            number: 2,
+            hash: "2",
+            parent: "1",
+        ),
+        lowest_celestia_search_height: 1,
+    );
+
+    timeout(
+        Duration::from_millis(2000),
+        join(
+            execute_block.wait_until_satisfied(),
+            update_commitment_state.wait_until_satisfied(),
+        ),
+    )
+    .await
+    .expect(
+        "conductor should have executed the firm block read from the local data availability \
+         store and updated the firm commitment state within 2000ms",
+    );
+}
+
+#[expect(clippy::too_many_lines, reason = "All lines reasonably necessary")]
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn resumes_reading_celestia_from_persisted_state() {
+    let state_dir = tempfile::tempdir().unwrap();
+    let state_path = state_dir.path().join("state.json");
+    std::fs::write(
+        &state_path,
+        serde_json::to_vec(&json!({
+            "rollup_id": ROLLUP_ID.to_string(),
+            "sequencer_chain_id": SEQUENCER_CHAIN_ID,
+            "celestia_chain_id": CELESTIA_CHAIN_ID,
+            "soft_number": 1,
+            "firm_number": 1,
+            "celestia_next_height": 2,
+            "celestia_reference_height": 1,
+            "cached_blocks": [],
+        }))
+        .unwrap(),
+    )
+    .unwrap();
+
+    let test_conductor = spawn_conductor_with_config(CommitLevel::FirmOnly, |config| Config {
+        celestia_block_time_ms: 100,
+        state_path: state_path.clone(),
+        ..config
+    })
+    .await;
+
+    mount_create_execution_session!(
+        test_conductor,
+        execution_session_parameters: (
+            rollup_start_block_number: 2,
+            rollup_end_block_number: 9,
+            sequencer_start_block_height: 3,
+            celestia_max_look_ahead: 10,
+        ),
+        commitment_state: (
+            firm: (
+                number: 1,
+                hash: "1",
+                parent: "0",
+            ),
+            soft: (
+                number: 1,
+                hash: "1",
+                parent: "0",
+            ),
+            lowest_celestia_search_height: 1,
+        )
+    );
+
+    mount_sequencer_genesis!(test_conductor);
+
+    mount_celestia_header_network_head!(
+        test_conductor,
+        height: 2u32,
+    );
+
+    mount_celestia_blobs!(
+        test_conductor,
+        celestia_height: 2,
+        sequencer_heights: [3],
+    );
+
+    mount_sequencer_commit!(
+        test_conductor,
+        height: 3u32,
+    );
+
+    mount_sequencer_validator_set!(test_conductor, height: 2u32);
+
+    let execute_block = mount_execute_block!(
+        test_conductor,
+        number: 2,
+        hash: "2",
+        parent: "1",
+    );
+
+    let update_commitment_state = mount_update_commitment_state!(
+        test_conductor,
+        firm: (
+            number: 2,
+            hash: "2",
+            parent: "1",
+        ),
+        soft: (
+            number: 2,
+            hash: "2",
+            parent: "1",
+        ),
+        lowest_celestia_search_height: 2,
+    );
+
+    timeout(
+        Duration::from_millis(2000),
+        join(
+            execute_block.wait_until_satisfied(),
+            update_commitment_state.wait_until_satisfied(),
+        ),
+    )
+    .await
+    .expect(
+        "conductor should have executed the firm block and updated the firm commitment state \
+         within 2000ms",
+    );
+
+    let requested_celestia_heights: Vec<_> = test_conductor
+        .mock_http
+        .received_requests()
+        .await
+        .unwrap()
+        .iter()
+        .filter_map(|request| {
+            let body: serde_json::Value = serde_json::from_slice(&request.body).ok()?;
+            (body["method"] == "blob.GetAll").then(|| body["params"][0].clone())
+        })
+        .collect();
+    assert!(
+        requested_celestia_heights.iter().all(|height| *height == 2),
+        "conductor should have resumed reading Celestia at height 2, but requested blobs at \
+         heights {requested_celestia_heights:?}",
+    );
+
+    timeout(Duration::from_millis(2000), async {
+        loop {
+            let state: serde_json::Value =
+                serde_json::from_slice(&std::fs::read(&state_path).unwrap()).unwrap();
+            if state["firm_number"] == 2 && state["celestia_next_height"] == 3 {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(50)).await;
+        }
+    })
+    .await
+    .expect("conductor should have persisted its progress past Celestia height 2 within 2000ms");
+}
+
 #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
 async fn submits_two_heights_in_succession() {
     let test_conductor = spawn_conductor(CommitLevel::FirmOnly).await;
@@ -423,6 +661,8 @@ async fn exits_on_celestia_chain_id_mismatch() {
     // implements the `Drop` trait, which disallows us from taking ownership of its tasks and
     // awaiting their completion.
 
+    LazyLock::force(&TELEMETRY);
+
     let mock_grpc = MockGrpc::spawn().await;
     let mock_http = wiremock::MockServer::start().await;
 
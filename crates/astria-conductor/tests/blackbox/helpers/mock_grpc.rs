@@ -30,6 +30,7 @@ use astria_core::generated::{
             GetPendingNonceResponse,
             GetSequencerBlockRequest,
             SequencerBlock,
+            StreamFilteredSequencerBlocksRequest,
         },
     },
     sequencerblock::v1::{
@@ -103,6 +104,9 @@ impl SequencerServiceImpl {
 // `serde::Serialize`.
 #[tonic::async_trait]
 impl SequencerService for SequencerServiceImpl {
+    type StreamFilteredSequencerBlocksStream =
+        tonic::codegen::tokio_stream::Empty<Result<FilteredSequencerBlock, tonic::Status>>;
+
     async fn get_sequencer_block(
         self: Arc<Self>,
         _request: Request<GetSequencerBlockRequest>,
@@ -119,6 +123,13 @@ impl SequencerService for SequencerServiceImpl {
             .await
     }
 
+    async fn stream_filtered_sequencer_blocks(
+        self: Arc<Self>,
+        _request: Request<StreamFilteredSequencerBlocksRequest>,
+    ) -> tonic::Result<Response<Self::StreamFilteredSequencerBlocksStream>> {
+        unimplemented!()
+    }
+
     async fn get_pending_nonce(
         self: Arc<Self>,
         _request: Request<GetPendingNonceRequest>,
@@ -1,4 +1,5 @@
 use std::{
+    path::PathBuf,
     sync::LazyLock,
     time::Duration,
 };
@@ -58,7 +59,7 @@ pub const EXECUTION_SESSION_ID: &str = "test_execution_session";
 pub const INITIAL_SOFT_HASH: [u8; 64] = [1; 64];
 pub const INITIAL_FIRM_HASH: [u8; 64] = [1; 64];
 
-static TELEMETRY: LazyLock<()> = LazyLock::new(|| {
+pub(crate) static TELEMETRY: LazyLock<()> = LazyLock::new(|| {
     astria_eyre::install().unwrap();
     if std::env::var_os("TEST_LOG").is_some() {
         let filter_directives = std::env::var("RUST_LOG").unwrap_or_else(|_| "info".into());
@@ -79,6 +80,14 @@ static TELEMETRY: LazyLock<()> = LazyLock::new(|| {
 });
 
 pub async fn spawn_conductor(execution_commit_level: CommitLevel) -> TestConductor {
+    spawn_conductor_with_config(execution_commit_level, |config| config).await
+}
+
+/// Like [`spawn_conductor`], but allows overriding the test config with `customize_config`.
+pub async fn spawn_conductor_with_config(
+    execution_commit_level: CommitLevel,
+    customize_config: impl FnOnce(Config) -> Config,
+) -> TestConductor {
     assert_ne!(
         tokio::runtime::Handle::current().runtime_flavor(),
         tokio::runtime::RuntimeFlavor::CurrentThread,
@@ -91,14 +100,14 @@ pub async fn spawn_conductor(execution_commit_level: CommitLevel) -> TestConduct
     let mock_grpc = MockGrpc::spawn().await;
     let mock_http = wiremock::MockServer::start().await;
 
-    let config = Config {
+    let config = customize_config(Config {
         celestia_node_http_url: mock_http.uri(),
         execution_rpc_url: format!("http://{}", mock_grpc.local_addr),
         sequencer_cometbft_url: mock_http.uri(),
         sequencer_grpc_url: format!("http://{}", mock_grpc.local_addr),
         execution_commit_level,
         ..make_config()
-    };
+    });
 
     let (metrics, metrics_handle) = metrics::ConfigBuilder::new()
         .set_global_recorder(false)
@@ -106,13 +115,13 @@ pub async fn spawn_conductor(execution_commit_level: CommitLevel) -> TestConduct
         .unwrap();
     let metrics = Box::leak(Box::new(metrics));
 
-    let conductor = {
-        let conductor = Conductor::new(config, metrics).unwrap();
-        conductor.spawn()
-    };
+    let conductor = Conductor::new(config, metrics).unwrap();
+    let api_addr = conductor.api_addr();
+    let conductor = conductor.spawn();
 
     TestConductor {
         conductor,
+        api_addr,
         mock_grpc,
         mock_http,
         metrics_handle,
@@ -121,6 +130,7 @@ pub async fn spawn_conductor(execution_commit_level: CommitLevel) -> TestConduct
 
 pub struct TestConductor {
     pub conductor: conductor::Handle,
+    pub api_addr: std::net::SocketAddr,
     pub mock_grpc: MockGrpc,
     pub mock_http: wiremock::MockServer,
     pub metrics_handle: metrics::Handle,
@@ -519,13 +529,21 @@ pub(crate) fn make_config() -> Config {
         celestia_node_http_url: "http://127.0.0.1:26658".into(),
         no_celestia_auth: false,
         celestia_bearer_token: CELESTIA_BEARER_TOKEN.into(),
+        da_backend: astria_conductor::config::DaBackend::Celestia,
+        local_da_path: PathBuf::new(),
+        state_path: PathBuf::new(),
         sequencer_grpc_url: "http://127.0.0.1:8080".into(),
         sequencer_cometbft_url: "http://127.0.0.1:26657".into(),
+        sequencer_networks: String::new(),
         sequencer_requests_per_second: 500,
         sequencer_block_time_ms: 2000,
         execution_rpc_url: "http://127.0.0.1:50051".into(),
         log: "info".into(),
         execution_commit_level: astria_conductor::config::CommitLevel::SoftAndFirm,
+        firm_lag_max_blocks: 0,
+        firm_lag_max_duration_ms: 0,
+        firm_lag_policy: astria_conductor::config::FirmLagPolicy::HaltSoft,
+        api_addr: "127.0.0.1:0".into(),
         force_stdout: false,
         no_otel: false,
         no_metrics: true,
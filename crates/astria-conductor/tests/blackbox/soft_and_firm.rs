@@ -1,6 +1,12 @@
 use std::time::Duration;
 
-use astria_conductor::config::CommitLevel;
+use astria_conductor::{
+    config::{
+        CommitLevel,
+        FirmLagPolicy,
+    },
+    Config,
+};
 use futures::future::{
     join,
     join3,
@@ -8,7 +14,10 @@ use futures::future::{
 use tokio::time::timeout;
 
 use crate::{
-    helpers::spawn_conductor,
+    helpers::{
+        spawn_conductor,
+        spawn_conductor_with_config,
+    },
     mount_abci_info,
     mount_celestia_blobs,
     mount_celestia_header_network_head,
@@ -1031,3 +1040,120 @@ async fn restarts_after_reaching_firm_stop_height_first() {
     .await
     .expect("conductor should have updated the firm commitment state within 1000ms");
 }
+
+/// Tests that soft execution halts once firm commitments lag behind by the configured number of
+/// blocks, and that the lag is reported by conductor's `/status` endpoint.
+///
+/// The following steps occur:
+/// 1. Conductor is configured to halt soft execution once the spread between soft and firm
+///    commitments reaches 1 block.
+/// 2. A soft block at rollup number 2, sequencer height 3 is executed, but no firm block is
+///    available on Celestia.
+/// 3. `/status` is polled until it reports soft execution as halted, with a spread of 1.
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn halts_soft_execution_when_firm_lag_is_exceeded() {
+    let test_conductor = spawn_conductor_with_config(CommitLevel::SoftAndFirm, |config| Config {
+        firm_lag_max_blocks: 1,
+        firm_lag_policy: FirmLagPolicy::HaltSoft,
+        ..config
+    })
+    .await;
+
+    mount_create_execution_session!(
+        test_conductor,
+        execution_session_parameters: (
+            rollup_start_block_number: 2,
+            rollup_end_block_number: 9,
+            sequencer_start_block_height: 3,
+            celestia_max_look_ahead: 10,
+        ),
+        commitment_state: (
+            firm: (
+                number: 1,
+                hash: "1",
+                parent: "0",
+            ),
+            soft: (
+                number: 1,
+                hash: "1",
+                parent: "0",
+            ),
+            lowest_celestia_search_height: 1,
+        )
+    );
+
+    mount_abci_info!(
+        test_conductor,
+        latest_sequencer_height: 3,
+    );
+
+    mount_sequencer_genesis!(test_conductor);
+
+    mount_celestia_header_network_head!(
+        test_conductor,
+        height: 1u32,
+    );
+
+    mount_get_filtered_sequencer_block!(
+        test_conductor,
+        sequencer_height: 3,
+    );
+
+    let execute_block = mount_execute_block!(
+        test_conductor,
+        number: 2,
+        hash: "2",
+        parent: "1",
+    );
+
+    let update_commitment_state_soft = mount_update_commitment_state!(
+        test_conductor,
+        firm: (
+            number: 1,
+            hash: "1",
+            parent: "0",
+        ),
+        soft: (
+            number: 2,
+            hash: "2",
+            parent: "1",
+        ),
+        lowest_celestia_search_height: 1,
+    );
+
+    timeout(
+        Duration::from_millis(1000),
+        join(
+            execute_block.wait_until_satisfied(),
+            update_commitment_state_soft.wait_until_satisfied(),
+        ),
+    )
+    .await
+    .expect(
+        "Conductor should have executed the block and updated the soft commitment state within \
+         1000ms",
+    );
+
+    let status_url = format!("http://{}/status", test_conductor.api_addr);
+    let status = timeout(Duration::from_millis(1000), async {
+        loop {
+            let status: serde_json::Value = reqwest::get(&status_url)
+                .await
+                .unwrap()
+                .json()
+                .await
+                .unwrap();
+            if status["soft_execution_halted"] == serde_json::json!(true) {
+                break status;
+            }
+            tokio::time::sleep(Duration::from_millis(50)).await;
+        }
+    })
+    .await
+    .expect("conductor should have reported soft execution as halted within 1000ms");
+
+    assert_eq!(status["soft_number"], serde_json::json!(2));
+    assert_eq!(status["firm_number"], serde_json::json!(1));
+    assert_eq!(status["spread"], serde_json::json!(1));
+    assert_eq!(status["firm_lag_exceeded"], serde_json::json!(true));
+}
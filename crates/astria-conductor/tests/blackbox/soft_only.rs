@@ -1,4 +1,7 @@
-use std::time::Duration;
+use std::{
+    sync::LazyLock,
+    time::Duration,
+};
 
 use astria_conductor::{
     config::CommitLevel,
@@ -10,16 +13,22 @@ use futures::future::{
     join,
     join4,
 };
+use serde_json::json;
 use telemetry::metrics;
 use tokio::time::timeout;
 
 use crate::{
     execution_session,
+    filtered_sequencer_block,
     helpers::{
         make_config,
         mount_genesis,
         spawn_conductor,
+        spawn_conductor_with_config,
         MockGrpc,
+        CELESTIA_CHAIN_ID,
+        ROLLUP_ID,
+        TELEMETRY,
     },
     mount_abci_info,
     mount_create_execution_session,
@@ -288,6 +297,118 @@ async fn skips_already_executed_heights() {
     );
 }
 
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn resumes_from_persisted_sequencer_blocks() {
+    let state_dir = tempfile::tempdir().unwrap();
+    let state_path = state_dir.path().join("state.json");
+    std::fs::write(
+        &state_path,
+        serde_json::to_vec(&json!({
+            "rollup_id": ROLLUP_ID.to_string(),
+            "sequencer_chain_id": SEQUENCER_CHAIN_ID,
+            "celestia_chain_id": CELESTIA_CHAIN_ID,
+            "soft_number": 1,
+            "firm_number": 1,
+            "sequencer_next_height": 4,
+            "sequencer_cached_blocks": [
+                filtered_sequencer_block!(sequencer_height: 3),
+            ],
+        }))
+        .unwrap(),
+    )
+    .unwrap();
+
+    let test_conductor = spawn_conductor_with_config(CommitLevel::SoftOnly, |config| Config {
+        sequencer_block_time_ms: 100,
+        state_path: state_path.clone(),
+        ..config
+    })
+    .await;
+
+    mount_create_execution_session!(
+        test_conductor,
+        execution_session_parameters: (
+            rollup_start_block_number: 2,
+            rollup_end_block_number: 9,
+            sequencer_start_block_height: 3,
+            celestia_max_look_ahead: 10,
+        ),
+        commitment_state: (
+            firm: (
+                number: 1,
+                hash: "1",
+                parent: "0",
+            ),
+            soft: (
+                number: 1,
+                hash: "1",
+                parent: "0",
+            ),
+            lowest_celestia_search_height: 1,
+        )
+    );
+
+    mount_sequencer_genesis!(test_conductor);
+
+    // The block at sequencer height 3 is only available from the persisted state: it is not
+    // mounted on the mock sequencer.
+    mount_abci_info!(
+        test_conductor,
+        latest_sequencer_height: 3,
+    );
+
+    let execute_block = mount_execute_block!(
+        test_conductor,
+        number: 2,
+        hash: "2",
+        parent: "1",
+    );
+
+    let update_commitment_state = mount_update_commitment_state!(
+        test_conductor,
+        firm: (
+            number: 1,
+            hash: "1",
+            parent: "0",
+        ),
+        soft: (
+            number: 2,
+            hash: "2",
+            parent: "1",
+        ),
+        lowest_celestia_search_height: 1,
+    );
+
+    timeout(
+        Duration::from_millis(1000),
+        join(
+            execute_block.wait_until_satisfied(),
+            update_commitment_state.wait_until_satisfied(),
+        ),
+    )
+    .await
+    .expect(
+        "conductor should have executed the persisted soft block and updated the soft commitment \
+         state within 1000ms",
+    );
+
+    timeout(Duration::from_millis(2000), async {
+        loop {
+            let state: serde_json::Value =
+                serde_json::from_slice(&std::fs::read(&state_path).unwrap()).unwrap();
+            if state["soft_number"] == 2
+                && state["sequencer_next_height"] == 4
+                && state["sequencer_cached_blocks"] == json!([])
+            {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(50)).await;
+        }
+    })
+    .await
+    .expect("conductor should have persisted its progress past sequencer height 3 within 2000ms");
+}
+
 #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
 async fn requests_from_later_genesis_height() {
     let test_conductor = spawn_conductor(CommitLevel::SoftOnly).await;
@@ -376,6 +497,8 @@ async fn exits_on_sequencer_chain_id_mismatch() {
     // implements the `Drop` trait, which disallows us from taking ownership of its tasks and
     // awaiting their completion.
 
+    LazyLock::force(&TELEMETRY);
+
     let mock_grpc = MockGrpc::spawn().await;
     let mock_http = wiremock::MockServer::start().await;
 
@@ -618,3 +741,172 @@ async fn restarts_after_reaching_stop_block_height() {
          commitment state within 1000ms",
     );
 }
+
+/// Tests that the conductor waits for the rollup to open an execution session succeeding the one
+/// that ended at its stop height, instead of running the ended session again.
+///
+/// It consists of the following steps:
+/// 1. Mount execution session with a rollup stop number of 2 (sequencer height 3), responding up to
+///    2 times so that the ended session is offered again after restarting.
+/// 2. Mount sequencer genesis, ABCI info, and sequencer blocks for heights 3 and 4.
+/// 3. Mount `execute_block` and `update_commitment_state` mocks for the soft block at height 3,
+///    expecting only 1 call and timing out after 1000ms.
+/// 4. Wait for the ended session to be offered again, ensuring that no block is executed for it.
+/// 5. Mount the succeeding execution session with a rollup stop number of 9 and a start block
+///    number of 3.
+/// 6. Mount `execute_block` and `update_commitment_state` mocks for the soft block at height 4,
+///    awaiting their satisfaction.
+#[expect(
+    clippy::too_many_lines,
+    reason = "All lines reasonably necessary for the thoroughness of this test"
+)]
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn waits_for_succeeding_execution_session_after_stop_block_height() {
+    let test_conductor = spawn_conductor(CommitLevel::SoftOnly).await;
+
+    mount_create_execution_session!(
+        test_conductor,
+        execution_session_parameters: (
+            rollup_start_block_number: 2,
+            rollup_end_block_number: 2,
+            sequencer_start_block_height: 3,
+            celestia_max_look_ahead: 10,
+        ),
+        commitment_state: (
+            firm: (
+                number: 1,
+                hash: "1",
+                parent: "0",
+            ),
+            soft: (
+                number: 1,
+                hash: "1",
+                parent: "0",
+            ),
+            lowest_celestia_search_height: 1,
+        ),
+        expected_calls: 2,
+        up_to_n_times: 2, // The ended execution session is offered again after restart
+    );
+
+    mount_sequencer_genesis!(test_conductor);
+
+    mount_abci_info!(
+        test_conductor,
+        latest_sequencer_height: 4,
+    );
+
+    mount_get_filtered_sequencer_block!(
+        test_conductor,
+        sequencer_height: 3,
+    );
+
+    mount_get_filtered_sequencer_block!(
+        test_conductor,
+        sequencer_height: 4,
+    );
+
+    let execute_block_1 = mount_execute_block!(
+        test_conductor,
+        mock_name: "execute_block_1",
+        number: 2,
+        hash: "2",
+        parent: "1",
+        expected_calls: 1,
+    );
+
+    let update_commitment_state_1 = mount_update_commitment_state!(
+        test_conductor,
+        mock_name: "update_commitment_state_1",
+        firm: (
+            number: 1,
+            hash: "1",
+            parent: "0",
+        ),
+        soft: (
+            number: 2,
+            hash: "2",
+            parent: "1",
+        ),
+        lowest_celestia_search_height: 1,
+        expected_calls: 1,
+    );
+
+    timeout(
+        Duration::from_millis(1000),
+        join(
+            execute_block_1.wait_until_satisfied(),
+            update_commitment_state_1.wait_until_satisfied(),
+        ),
+    )
+    .await
+    .expect(
+        "conductor should have executed the first soft block and updated the first soft \
+         commitment state within 1000ms",
+    );
+
+    // give conductor time to restart and be offered the ended execution session again
+    tokio::time::sleep(Duration::from_millis(500)).await;
+
+    mount_create_execution_session!(
+        test_conductor,
+        execution_session_parameters: (
+            rollup_start_block_number: 3,
+            rollup_end_block_number: 9,
+            sequencer_start_block_height: 4,
+            celestia_max_look_ahead: 10,
+        ),
+        commitment_state: (
+            firm: (
+                number: 1,
+                hash: "1",
+                parent: "0",
+            ),
+            soft: (
+                number: 2,
+                hash: "2",
+                parent: "1",
+            ),
+            lowest_celestia_search_height: 1,
+        ),
+    );
+
+    let execute_block_2 = mount_execute_block!(
+        test_conductor,
+        mock_name: "execute_block_2",
+        number: 3,
+        hash: "3",
+        parent: "2",
+        expected_calls: 1,
+    );
+
+    let update_commitment_state_2 = mount_update_commitment_state!(
+        test_conductor,
+        mock_name: "update_commitment_state_2",
+        firm: (
+            number: 1,
+            hash: "1",
+            parent: "0",
+        ),
+        soft: (
+            number: 3,
+            hash: "3",
+            parent: "2",
+        ),
+        lowest_celestia_search_height: 1,
+        expected_calls: 1,
+    );
+
+    timeout(
+        Duration::from_millis(2000),
+        join(
+            execute_block_2.wait_until_satisfied(),
+            update_commitment_state_2.wait_until_satisfied(),
+        ),
+    )
+    .await
+    .expect(
+        "conductor should have executed the second soft block and updated the second soft \
+         commitment state within 2000ms",
+    );
+}
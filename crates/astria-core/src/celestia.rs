@@ -33,3 +33,80 @@ pub fn namespace_v0_from_sha256_of_bytes<T: AsRef<[u8]>>(bytes: T) -> Namespace
     };
     namespace_v0_from_first_10_bytes(&Sha256::digest(bytes))
 }
+
+/// The on-disk layout of a local data availability backend standing in for Celestia.
+///
+/// It is written by sequencer-relayer and read by conductor so that the firm commitment path can
+/// be exercised without running a Celestia node. All paths are relative to a root directory shared
+/// by both:
+///
+/// - `chain_id`: the chain ID of the local data availability network, as UTF-8 text.
+/// - `latest_height`: the latest height at which blobs were stored, as decimal text. Heights start
+///   at 1, and blobs at a height must only be read once it is covered by this file.
+/// - `blobs/<namespace>/<height>/<index>`: the raw data of the blobs stored for the hex-encoded
+///   `<namespace>` at `<height>`, in submission order starting from index 0.
+/// - `submissions/<hash>`: the height at which the submission identified by the hex-encoded
+///   `<hash>` was stored, as decimal text.
+pub mod local {
+    use std::path::{
+        Path,
+        PathBuf,
+    };
+
+    use super::Namespace;
+
+    /// Returns the path of the file holding the chain ID of the local data availability network.
+    #[must_use]
+    pub fn chain_id_path(root: &Path) -> PathBuf {
+        root.join("chain_id")
+    }
+
+    /// Returns the path of the file holding the latest height at which blobs were stored.
+    #[must_use]
+    pub fn latest_height_path(root: &Path) -> PathBuf {
+        root.join("latest_height")
+    }
+
+    /// Returns the path of the directory holding the blobs of all namespaces.
+    #[must_use]
+    pub fn blobs_root(root: &Path) -> PathBuf {
+        root.join("blobs")
+    }
+
+    /// Returns the path of the directory holding the blobs stored for `namespace` at `height`.
+    #[must_use]
+    pub fn blobs_dir(root: &Path, namespace: &Namespace, height: u64) -> PathBuf {
+        blobs_root(root)
+            .join(hex::encode(namespace.as_bytes()))
+            .join(height.to_string())
+    }
+
+    /// Returns the path of the file recording the height at which the submission identified by
+    /// `hash` was stored.
+    #[must_use]
+    pub fn submission_path(root: &Path, hash: &[u8]) -> PathBuf {
+        root.join("submissions").join(hex::encode(hash))
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn paths_should_not_change() {
+            let root = Path::new("/da");
+            assert_eq!(chain_id_path(root), Path::new("/da/chain_id"));
+            assert_eq!(latest_height_path(root), Path::new("/da/latest_height"));
+            assert_eq!(
+                blobs_dir(root, &Namespace::const_v0([1; 10]), 42),
+                Path::new(
+                    "/da/blobs/0000000000000000000000000000000000000001010101010101010101/42"
+                )
+            );
+            assert_eq!(
+                submission_path(root, &[0xab; 4]),
+                Path::new("/da/submissions/abababab")
+            );
+        }
+    }
+}
@@ -21,9 +21,17 @@ impl ::prost::Name for SubmitRollupTransactionRequest {
     }
 }
 /// SubmitRollupTransactionResponse is a message that represents a response to a request to submit a rollup.
-/// It's currently an empty response which can be evolved in the future to include more information
-#[derive(Clone, Copy, PartialEq, ::prost::Message)]
-pub struct SubmitRollupTransactionResponse {}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SubmitRollupTransactionResponse {
+    /// the ID of the receipt tracking the transaction, the sha256 hash of the
+    /// rollup ID and the transaction data. Identical transactions have the same ID.
+    #[prost(bytes = "bytes", tag = "1")]
+    pub receipt_id: ::prost::bytes::Bytes,
+    /// whether the transaction is a duplicate of one submitted within the
+    /// deduplication window, in which case it was not queued again
+    #[prost(bool, tag = "2")]
+    pub duplicate: bool,
+}
 impl ::prost::Name for SubmitRollupTransactionResponse {
     const NAME: &'static str = "SubmitRollupTransactionResponse";
     const PACKAGE: &'static str = "astria.composer.v1";
@@ -34,6 +42,124 @@ impl ::prost::Name for SubmitRollupTransactionResponse {
         "/astria.composer.v1.SubmitRollupTransactionResponse".into()
     }
 }
+/// GetSubmissionReceiptRequest requests the receipt of a transaction submitted
+/// via `SubmitRollupTransaction`.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetSubmissionReceiptRequest {
+    /// the receipt ID returned by `SubmitRollupTransaction`
+    #[prost(bytes = "bytes", tag = "1")]
+    pub receipt_id: ::prost::bytes::Bytes,
+}
+impl ::prost::Name for GetSubmissionReceiptRequest {
+    const NAME: &'static str = "GetSubmissionReceiptRequest";
+    const PACKAGE: &'static str = "astria.composer.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "astria.composer.v1.GetSubmissionReceiptRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/astria.composer.v1.GetSubmissionReceiptRequest".into()
+    }
+}
+/// SubmissionReceipt is the status of a transaction submitted via `SubmitRollupTransaction`.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SubmissionReceipt {
+    #[prost(bytes = "bytes", tag = "1")]
+    pub receipt_id: ::prost::bytes::Bytes,
+    #[prost(oneof = "submission_receipt::Status", tags = "2, 3, 4, 5")]
+    pub status: ::core::option::Option<submission_receipt::Status>,
+}
+/// Nested message and enum types in `SubmissionReceipt`.
+pub mod submission_receipt {
+    /// the transaction is queued in the composer waiting to be bundled
+    #[derive(Clone, Copy, PartialEq, ::prost::Message)]
+    pub struct Queued {}
+    impl ::prost::Name for Queued {
+        const NAME: &'static str = "Queued";
+        const PACKAGE: &'static str = "astria.composer.v1";
+        fn full_name() -> ::prost::alloc::string::String {
+            "astria.composer.v1.SubmissionReceipt.Queued".into()
+        }
+        fn type_url() -> ::prost::alloc::string::String {
+            "/astria.composer.v1.SubmissionReceipt.Queued".into()
+        }
+    }
+    /// the transaction was bundled into a sequencer transaction which was accepted
+    /// into the sequencer mempool
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct Bundled {
+        /// the hash of the sequencer transaction
+        #[prost(bytes = "bytes", tag = "1")]
+        pub sequencer_transaction_hash: ::prost::bytes::Bytes,
+    }
+    impl ::prost::Name for Bundled {
+        const NAME: &'static str = "Bundled";
+        const PACKAGE: &'static str = "astria.composer.v1";
+        fn full_name() -> ::prost::alloc::string::String {
+            "astria.composer.v1.SubmissionReceipt.Bundled".into()
+        }
+        fn type_url() -> ::prost::alloc::string::String {
+            "/astria.composer.v1.SubmissionReceipt.Bundled".into()
+        }
+    }
+    /// the transaction was included in a sequencer block
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct Included {
+        /// the hash of the sequencer transaction
+        #[prost(bytes = "bytes", tag = "1")]
+        pub sequencer_transaction_hash: ::prost::bytes::Bytes,
+        /// the height of the sequencer block
+        #[prost(uint64, tag = "2")]
+        pub height: u64,
+    }
+    impl ::prost::Name for Included {
+        const NAME: &'static str = "Included";
+        const PACKAGE: &'static str = "astria.composer.v1";
+        fn full_name() -> ::prost::alloc::string::String {
+            "astria.composer.v1.SubmissionReceipt.Included".into()
+        }
+        fn type_url() -> ::prost::alloc::string::String {
+            "/astria.composer.v1.SubmissionReceipt.Included".into()
+        }
+    }
+    /// the transaction was dropped and will not reach the sequencer
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct Dropped {
+        /// the reason the transaction was dropped
+        #[prost(string, tag = "1")]
+        pub reason: ::prost::alloc::string::String,
+    }
+    impl ::prost::Name for Dropped {
+        const NAME: &'static str = "Dropped";
+        const PACKAGE: &'static str = "astria.composer.v1";
+        fn full_name() -> ::prost::alloc::string::String {
+            "astria.composer.v1.SubmissionReceipt.Dropped".into()
+        }
+        fn type_url() -> ::prost::alloc::string::String {
+            "/astria.composer.v1.SubmissionReceipt.Dropped".into()
+        }
+    }
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Status {
+        #[prost(message, tag = "2")]
+        Queued(Queued),
+        #[prost(message, tag = "3")]
+        Bundled(Bundled),
+        #[prost(message, tag = "4")]
+        Included(Included),
+        #[prost(message, tag = "5")]
+        Dropped(Dropped),
+    }
+}
+impl ::prost::Name for SubmissionReceipt {
+    const NAME: &'static str = "SubmissionReceipt";
+    const PACKAGE: &'static str = "astria.composer.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "astria.composer.v1.SubmissionReceipt".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/astria.composer.v1.SubmissionReceipt".into()
+    }
+}
 /// Generated client implementations.
 #[cfg(feature = "client")]
 pub mod grpc_collector_service_client {
@@ -158,6 +284,37 @@ pub mod grpc_collector_service_client {
                 );
             self.inner.unary(req, path, codec).await
         }
+        /// GetSubmissionReceipt returns the status of a transaction submitted via `SubmitRollupTransaction`.
+        /// Receipts are only retained for the composer's configured receipt retention period.
+        pub async fn get_submission_receipt(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetSubmissionReceiptRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::SubmissionReceipt>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/astria.composer.v1.GrpcCollectorService/GetSubmissionReceipt",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "astria.composer.v1.GrpcCollectorService",
+                        "GetSubmissionReceipt",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
     }
 }
 /// Generated server implementations.
@@ -183,6 +340,15 @@ pub mod grpc_collector_service_server {
             tonic::Response<super::SubmitRollupTransactionResponse>,
             tonic::Status,
         >;
+        /// GetSubmissionReceipt returns the status of a transaction submitted via `SubmitRollupTransaction`.
+        /// Receipts are only retained for the composer's configured receipt retention period.
+        async fn get_submission_receipt(
+            self: std::sync::Arc<Self>,
+            request: tonic::Request<super::GetSubmissionReceiptRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::SubmissionReceipt>,
+            tonic::Status,
+        >;
     }
     /// GrpcCollectorService is a service that defines the gRPC collector of the Composer
     #[derive(Debug)]
@@ -315,6 +481,55 @@ pub mod grpc_collector_service_server {
                     };
                     Box::pin(fut)
                 }
+                "/astria.composer.v1.GrpcCollectorService/GetSubmissionReceipt" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetSubmissionReceiptSvc<T: GrpcCollectorService>(pub Arc<T>);
+                    impl<
+                        T: GrpcCollectorService,
+                    > tonic::server::UnaryService<super::GetSubmissionReceiptRequest>
+                    for GetSubmissionReceiptSvc<T> {
+                        type Response = super::SubmissionReceipt;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetSubmissionReceiptRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as GrpcCollectorService>::get_submission_receipt(
+                                        inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GetSubmissionReceiptSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 _ => {
                     Box::pin(async move {
                         let mut response = http::Response::new(empty_body());
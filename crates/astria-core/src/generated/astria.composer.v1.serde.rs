@@ -1,3 +1,631 @@
+impl serde::Serialize for GetSubmissionReceiptRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.receipt_id.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("astria.composer.v1.GetSubmissionReceiptRequest", len)?;
+        if !self.receipt_id.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("receiptId", pbjson::private::base64::encode(&self.receipt_id).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for GetSubmissionReceiptRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "receipt_id",
+            "receiptId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ReceiptId,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "receiptId" | "receipt_id" => Ok(GeneratedField::ReceiptId),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = GetSubmissionReceiptRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct astria.composer.v1.GetSubmissionReceiptRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<GetSubmissionReceiptRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut receipt_id__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::ReceiptId => {
+                            if receipt_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("receiptId"));
+                            }
+                            receipt_id__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(GetSubmissionReceiptRequest {
+                    receipt_id: receipt_id__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("astria.composer.v1.GetSubmissionReceiptRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for SubmissionReceipt {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.receipt_id.is_empty() {
+            len += 1;
+        }
+        if self.status.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("astria.composer.v1.SubmissionReceipt", len)?;
+        if !self.receipt_id.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("receiptId", pbjson::private::base64::encode(&self.receipt_id).as_str())?;
+        }
+        if let Some(v) = self.status.as_ref() {
+            match v {
+                submission_receipt::Status::Queued(v) => {
+                    struct_ser.serialize_field("queued", v)?;
+                }
+                submission_receipt::Status::Bundled(v) => {
+                    struct_ser.serialize_field("bundled", v)?;
+                }
+                submission_receipt::Status::Included(v) => {
+                    struct_ser.serialize_field("included", v)?;
+                }
+                submission_receipt::Status::Dropped(v) => {
+                    struct_ser.serialize_field("dropped", v)?;
+                }
+            }
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for SubmissionReceipt {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "receipt_id",
+            "receiptId",
+            "queued",
+            "bundled",
+            "included",
+            "dropped",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ReceiptId,
+            Queued,
+            Bundled,
+            Included,
+            Dropped,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "receiptId" | "receipt_id" => Ok(GeneratedField::ReceiptId),
+                            "queued" => Ok(GeneratedField::Queued),
+                            "bundled" => Ok(GeneratedField::Bundled),
+                            "included" => Ok(GeneratedField::Included),
+                            "dropped" => Ok(GeneratedField::Dropped),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = SubmissionReceipt;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct astria.composer.v1.SubmissionReceipt")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SubmissionReceipt, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut receipt_id__ = None;
+                let mut status__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::ReceiptId => {
+                            if receipt_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("receiptId"));
+                            }
+                            receipt_id__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Queued => {
+                            if status__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("queued"));
+                            }
+                            status__ = map_.next_value::<::std::option::Option<_>>()?.map(submission_receipt::Status::Queued)
+;
+                        }
+                        GeneratedField::Bundled => {
+                            if status__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("bundled"));
+                            }
+                            status__ = map_.next_value::<::std::option::Option<_>>()?.map(submission_receipt::Status::Bundled)
+;
+                        }
+                        GeneratedField::Included => {
+                            if status__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("included"));
+                            }
+                            status__ = map_.next_value::<::std::option::Option<_>>()?.map(submission_receipt::Status::Included)
+;
+                        }
+                        GeneratedField::Dropped => {
+                            if status__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("dropped"));
+                            }
+                            status__ = map_.next_value::<::std::option::Option<_>>()?.map(submission_receipt::Status::Dropped)
+;
+                        }
+                    }
+                }
+                Ok(SubmissionReceipt {
+                    receipt_id: receipt_id__.unwrap_or_default(),
+                    status: status__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("astria.composer.v1.SubmissionReceipt", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for submission_receipt::Bundled {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.sequencer_transaction_hash.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("astria.composer.v1.SubmissionReceipt.Bundled", len)?;
+        if !self.sequencer_transaction_hash.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("sequencerTransactionHash", pbjson::private::base64::encode(&self.sequencer_transaction_hash).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for submission_receipt::Bundled {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "sequencer_transaction_hash",
+            "sequencerTransactionHash",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            SequencerTransactionHash,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "sequencerTransactionHash" | "sequencer_transaction_hash" => Ok(GeneratedField::SequencerTransactionHash),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = submission_receipt::Bundled;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct astria.composer.v1.SubmissionReceipt.Bundled")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<submission_receipt::Bundled, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut sequencer_transaction_hash__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::SequencerTransactionHash => {
+                            if sequencer_transaction_hash__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("sequencerTransactionHash"));
+                            }
+                            sequencer_transaction_hash__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(submission_receipt::Bundled {
+                    sequencer_transaction_hash: sequencer_transaction_hash__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("astria.composer.v1.SubmissionReceipt.Bundled", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for submission_receipt::Dropped {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.reason.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("astria.composer.v1.SubmissionReceipt.Dropped", len)?;
+        if !self.reason.is_empty() {
+            struct_ser.serialize_field("reason", &self.reason)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for submission_receipt::Dropped {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "reason",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Reason,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "reason" => Ok(GeneratedField::Reason),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = submission_receipt::Dropped;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct astria.composer.v1.SubmissionReceipt.Dropped")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<submission_receipt::Dropped, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut reason__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Reason => {
+                            if reason__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("reason"));
+                            }
+                            reason__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(submission_receipt::Dropped {
+                    reason: reason__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("astria.composer.v1.SubmissionReceipt.Dropped", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for submission_receipt::Included {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.sequencer_transaction_hash.is_empty() {
+            len += 1;
+        }
+        if self.height != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("astria.composer.v1.SubmissionReceipt.Included", len)?;
+        if !self.sequencer_transaction_hash.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("sequencerTransactionHash", pbjson::private::base64::encode(&self.sequencer_transaction_hash).as_str())?;
+        }
+        if self.height != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("height", ToString::to_string(&self.height).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for submission_receipt::Included {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "sequencer_transaction_hash",
+            "sequencerTransactionHash",
+            "height",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            SequencerTransactionHash,
+            Height,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "sequencerTransactionHash" | "sequencer_transaction_hash" => Ok(GeneratedField::SequencerTransactionHash),
+                            "height" => Ok(GeneratedField::Height),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = submission_receipt::Included;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct astria.composer.v1.SubmissionReceipt.Included")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<submission_receipt::Included, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut sequencer_transaction_hash__ = None;
+                let mut height__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::SequencerTransactionHash => {
+                            if sequencer_transaction_hash__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("sequencerTransactionHash"));
+                            }
+                            sequencer_transaction_hash__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Height => {
+                            if height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("height"));
+                            }
+                            height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(submission_receipt::Included {
+                    sequencer_transaction_hash: sequencer_transaction_hash__.unwrap_or_default(),
+                    height: height__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("astria.composer.v1.SubmissionReceipt.Included", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for submission_receipt::Queued {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let len = 0;
+        let struct_ser = serializer.serialize_struct("astria.composer.v1.SubmissionReceipt.Queued", len)?;
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for submission_receipt::Queued {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                            Err(serde::de::Error::unknown_field(value, FIELDS))
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = submission_receipt::Queued;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct astria.composer.v1.SubmissionReceipt.Queued")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<submission_receipt::Queued, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                while map_.next_key::<GeneratedField>()?.is_some() {
+                    let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                }
+                Ok(submission_receipt::Queued {
+                })
+            }
+        }
+        deserializer.deserialize_struct("astria.composer.v1.SubmissionReceipt.Queued", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for SubmitRollupTransactionRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -118,8 +746,22 @@ impl serde::Serialize for SubmitRollupTransactionResponse {
         S: serde::Serializer,
     {
         use serde::ser::SerializeStruct;
-        let len = 0;
-        let struct_ser = serializer.serialize_struct("astria.composer.v1.SubmitRollupTransactionResponse", len)?;
+        let mut len = 0;
+        if !self.receipt_id.is_empty() {
+            len += 1;
+        }
+        if self.duplicate {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("astria.composer.v1.SubmitRollupTransactionResponse", len)?;
+        if !self.receipt_id.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("receiptId", pbjson::private::base64::encode(&self.receipt_id).as_str())?;
+        }
+        if self.duplicate {
+            struct_ser.serialize_field("duplicate", &self.duplicate)?;
+        }
         struct_ser.end()
     }
 }
@@ -130,10 +772,15 @@ impl<'de> serde::Deserialize<'de> for SubmitRollupTransactionResponse {
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
+            "receipt_id",
+            "receiptId",
+            "duplicate",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
+            ReceiptId,
+            Duplicate,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -154,7 +801,11 @@ impl<'de> serde::Deserialize<'de> for SubmitRollupTransactionResponse {
                     where
                         E: serde::de::Error,
                     {
-                            Err(serde::de::Error::unknown_field(value, FIELDS))
+                        match value {
+                            "receiptId" | "receipt_id" => Ok(GeneratedField::ReceiptId),
+                            "duplicate" => Ok(GeneratedField::Duplicate),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
                     }
                 }
                 deserializer.deserialize_identifier(GeneratedVisitor)
@@ -172,10 +823,29 @@ impl<'de> serde::Deserialize<'de> for SubmitRollupTransactionResponse {
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                while map_.next_key::<GeneratedField>()?.is_some() {
-                    let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                let mut receipt_id__ = None;
+                let mut duplicate__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::ReceiptId => {
+                            if receipt_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("receiptId"));
+                            }
+                            receipt_id__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Duplicate => {
+                            if duplicate__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("duplicate"));
+                            }
+                            duplicate__ = Some(map_.next_value()?);
+                        }
+                    }
                 }
                 Ok(SubmitRollupTransactionResponse {
+                    receipt_id: receipt_id__.unwrap_or_default(),
+                    duplicate: duplicate__.unwrap_or_default(),
                 })
             }
         }
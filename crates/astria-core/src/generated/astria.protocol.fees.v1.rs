@@ -322,6 +322,159 @@ impl ::prost::Name for MarketsChangeFeeComponents {
         "/astria.protocol.fees.v1.MarketsChangeFeeComponents".into()
     }
 }
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct RegisterMultisigFeeComponents {
+    #[prost(message, optional, tag = "1")]
+    pub base: ::core::option::Option<super::super::super::primitive::v1::Uint128>,
+    #[prost(message, optional, tag = "2")]
+    pub multiplier: ::core::option::Option<super::super::super::primitive::v1::Uint128>,
+}
+impl ::prost::Name for RegisterMultisigFeeComponents {
+    const NAME: &'static str = "RegisterMultisigFeeComponents";
+    const PACKAGE: &'static str = "astria.protocol.fees.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "astria.protocol.fees.v1.RegisterMultisigFeeComponents".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/astria.protocol.fees.v1.RegisterMultisigFeeComponents".into()
+    }
+}
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct TimelockedTransferFeeComponents {
+    #[prost(message, optional, tag = "1")]
+    pub base: ::core::option::Option<super::super::super::primitive::v1::Uint128>,
+    #[prost(message, optional, tag = "2")]
+    pub multiplier: ::core::option::Option<super::super::super::primitive::v1::Uint128>,
+}
+impl ::prost::Name for TimelockedTransferFeeComponents {
+    const NAME: &'static str = "TimelockedTransferFeeComponents";
+    const PACKAGE: &'static str = "astria.protocol.fees.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "astria.protocol.fees.v1.TimelockedTransferFeeComponents".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/astria.protocol.fees.v1.TimelockedTransferFeeComponents".into()
+    }
+}
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct ClaimTimelockedTransferFeeComponents {
+    #[prost(message, optional, tag = "1")]
+    pub base: ::core::option::Option<super::super::super::primitive::v1::Uint128>,
+    #[prost(message, optional, tag = "2")]
+    pub multiplier: ::core::option::Option<super::super::super::primitive::v1::Uint128>,
+}
+impl ::prost::Name for ClaimTimelockedTransferFeeComponents {
+    const NAME: &'static str = "ClaimTimelockedTransferFeeComponents";
+    const PACKAGE: &'static str = "astria.protocol.fees.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "astria.protocol.fees.v1.ClaimTimelockedTransferFeeComponents".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/astria.protocol.fees.v1.ClaimTimelockedTransferFeeComponents".into()
+    }
+}
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct CancelTimelockedTransferFeeComponents {
+    #[prost(message, optional, tag = "1")]
+    pub base: ::core::option::Option<super::super::super::primitive::v1::Uint128>,
+    #[prost(message, optional, tag = "2")]
+    pub multiplier: ::core::option::Option<super::super::super::primitive::v1::Uint128>,
+}
+impl ::prost::Name for CancelTimelockedTransferFeeComponents {
+    const NAME: &'static str = "CancelTimelockedTransferFeeComponents";
+    const PACKAGE: &'static str = "astria.protocol.fees.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "astria.protocol.fees.v1.CancelTimelockedTransferFeeComponents".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/astria.protocol.fees.v1.CancelTimelockedTransferFeeComponents".into()
+    }
+}
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct SubmitProposalFeeComponents {
+    #[prost(message, optional, tag = "1")]
+    pub base: ::core::option::Option<super::super::super::primitive::v1::Uint128>,
+    #[prost(message, optional, tag = "2")]
+    pub multiplier: ::core::option::Option<super::super::super::primitive::v1::Uint128>,
+}
+impl ::prost::Name for SubmitProposalFeeComponents {
+    const NAME: &'static str = "SubmitProposalFeeComponents";
+    const PACKAGE: &'static str = "astria.protocol.fees.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "astria.protocol.fees.v1.SubmitProposalFeeComponents".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/astria.protocol.fees.v1.SubmitProposalFeeComponents".into()
+    }
+}
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct VoteOnProposalFeeComponents {
+    #[prost(message, optional, tag = "1")]
+    pub base: ::core::option::Option<super::super::super::primitive::v1::Uint128>,
+    #[prost(message, optional, tag = "2")]
+    pub multiplier: ::core::option::Option<super::super::super::primitive::v1::Uint128>,
+}
+impl ::prost::Name for VoteOnProposalFeeComponents {
+    const NAME: &'static str = "VoteOnProposalFeeComponents";
+    const PACKAGE: &'static str = "astria.protocol.fees.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "astria.protocol.fees.v1.VoteOnProposalFeeComponents".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/astria.protocol.fees.v1.VoteOnProposalFeeComponents".into()
+    }
+}
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct FeeDistributionChangeFeeComponents {
+    #[prost(message, optional, tag = "1")]
+    pub base: ::core::option::Option<super::super::super::primitive::v1::Uint128>,
+    #[prost(message, optional, tag = "2")]
+    pub multiplier: ::core::option::Option<super::super::super::primitive::v1::Uint128>,
+}
+impl ::prost::Name for FeeDistributionChangeFeeComponents {
+    const NAME: &'static str = "FeeDistributionChangeFeeComponents";
+    const PACKAGE: &'static str = "astria.protocol.fees.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "astria.protocol.fees.v1.FeeDistributionChangeFeeComponents".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/astria.protocol.fees.v1.FeeDistributionChangeFeeComponents".into()
+    }
+}
+/// How the fees collected in a block are split at the end of the block. The
+/// shares are given in basis points and must add up to 10000. Any remainder
+/// left by rounding goes to the block proposer.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FeeDistribution {
+    /// the share paid to the proposer of the block
+    #[prost(uint32, tag = "1")]
+    pub proposer_basis_points: u32,
+    /// the share paid to the validators, pro-rata to their voting power
+    #[prost(uint32, tag = "2")]
+    pub validators_basis_points: u32,
+    /// the share paid to `community_pool_address`
+    #[prost(uint32, tag = "3")]
+    pub community_pool_basis_points: u32,
+    /// the share that is burned
+    #[prost(uint32, tag = "4")]
+    pub burn_basis_points: u32,
+    /// the account receiving the community pool share. Must be set if
+    /// `community_pool_basis_points` is not zero.
+    #[prost(message, optional, tag = "5")]
+    pub community_pool_address: ::core::option::Option<
+        super::super::super::primitive::v1::Address,
+    >,
+}
+impl ::prost::Name for FeeDistribution {
+    const NAME: &'static str = "FeeDistribution";
+    const PACKAGE: &'static str = "astria.protocol.fees.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "astria.protocol.fees.v1.FeeDistribution".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/astria.protocol.fees.v1.FeeDistribution".into()
+    }
+}
 /// Response to a transaction fee ABCI query.
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct TransactionFeeResponse {
@@ -430,6 +430,222 @@ impl<'de> serde::Deserialize<'de> for BridgeUnlockFeeComponents {
         deserializer.deserialize_struct("astria.protocol.fees.v1.BridgeUnlockFeeComponents", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for CancelTimelockedTransferFeeComponents {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.base.is_some() {
+            len += 1;
+        }
+        if self.multiplier.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("astria.protocol.fees.v1.CancelTimelockedTransferFeeComponents", len)?;
+        if let Some(v) = self.base.as_ref() {
+            struct_ser.serialize_field("base", v)?;
+        }
+        if let Some(v) = self.multiplier.as_ref() {
+            struct_ser.serialize_field("multiplier", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for CancelTimelockedTransferFeeComponents {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "base",
+            "multiplier",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Base,
+            Multiplier,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "base" => Ok(GeneratedField::Base),
+                            "multiplier" => Ok(GeneratedField::Multiplier),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = CancelTimelockedTransferFeeComponents;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct astria.protocol.fees.v1.CancelTimelockedTransferFeeComponents")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<CancelTimelockedTransferFeeComponents, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut base__ = None;
+                let mut multiplier__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Base => {
+                            if base__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("base"));
+                            }
+                            base__ = map_.next_value()?;
+                        }
+                        GeneratedField::Multiplier => {
+                            if multiplier__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("multiplier"));
+                            }
+                            multiplier__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(CancelTimelockedTransferFeeComponents {
+                    base: base__,
+                    multiplier: multiplier__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("astria.protocol.fees.v1.CancelTimelockedTransferFeeComponents", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ClaimTimelockedTransferFeeComponents {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.base.is_some() {
+            len += 1;
+        }
+        if self.multiplier.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("astria.protocol.fees.v1.ClaimTimelockedTransferFeeComponents", len)?;
+        if let Some(v) = self.base.as_ref() {
+            struct_ser.serialize_field("base", v)?;
+        }
+        if let Some(v) = self.multiplier.as_ref() {
+            struct_ser.serialize_field("multiplier", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ClaimTimelockedTransferFeeComponents {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "base",
+            "multiplier",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Base,
+            Multiplier,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "base" => Ok(GeneratedField::Base),
+                            "multiplier" => Ok(GeneratedField::Multiplier),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ClaimTimelockedTransferFeeComponents;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct astria.protocol.fees.v1.ClaimTimelockedTransferFeeComponents")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ClaimTimelockedTransferFeeComponents, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut base__ = None;
+                let mut multiplier__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Base => {
+                            if base__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("base"));
+                            }
+                            base__ = map_.next_value()?;
+                        }
+                        GeneratedField::Multiplier => {
+                            if multiplier__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("multiplier"));
+                            }
+                            multiplier__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(ClaimTimelockedTransferFeeComponents {
+                    base: base__,
+                    multiplier: multiplier__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("astria.protocol.fees.v1.ClaimTimelockedTransferFeeComponents", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for CurrencyPairsChangeFeeComponents {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -529,16 +745,620 @@ impl<'de> serde::Deserialize<'de> for CurrencyPairsChangeFeeComponents {
                         }
                     }
                 }
-                Ok(CurrencyPairsChangeFeeComponents {
+                Ok(CurrencyPairsChangeFeeComponents {
+                    base: base__,
+                    multiplier: multiplier__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("astria.protocol.fees.v1.CurrencyPairsChangeFeeComponents", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for FeeAssetChangeFeeComponents {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.base.is_some() {
+            len += 1;
+        }
+        if self.multiplier.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("astria.protocol.fees.v1.FeeAssetChangeFeeComponents", len)?;
+        if let Some(v) = self.base.as_ref() {
+            struct_ser.serialize_field("base", v)?;
+        }
+        if let Some(v) = self.multiplier.as_ref() {
+            struct_ser.serialize_field("multiplier", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for FeeAssetChangeFeeComponents {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "base",
+            "multiplier",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Base,
+            Multiplier,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "base" => Ok(GeneratedField::Base),
+                            "multiplier" => Ok(GeneratedField::Multiplier),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = FeeAssetChangeFeeComponents;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct astria.protocol.fees.v1.FeeAssetChangeFeeComponents")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<FeeAssetChangeFeeComponents, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut base__ = None;
+                let mut multiplier__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Base => {
+                            if base__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("base"));
+                            }
+                            base__ = map_.next_value()?;
+                        }
+                        GeneratedField::Multiplier => {
+                            if multiplier__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("multiplier"));
+                            }
+                            multiplier__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(FeeAssetChangeFeeComponents {
+                    base: base__,
+                    multiplier: multiplier__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("astria.protocol.fees.v1.FeeAssetChangeFeeComponents", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for FeeChangeFeeComponents {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.base.is_some() {
+            len += 1;
+        }
+        if self.multiplier.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("astria.protocol.fees.v1.FeeChangeFeeComponents", len)?;
+        if let Some(v) = self.base.as_ref() {
+            struct_ser.serialize_field("base", v)?;
+        }
+        if let Some(v) = self.multiplier.as_ref() {
+            struct_ser.serialize_field("multiplier", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for FeeChangeFeeComponents {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "base",
+            "multiplier",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Base,
+            Multiplier,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "base" => Ok(GeneratedField::Base),
+                            "multiplier" => Ok(GeneratedField::Multiplier),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = FeeChangeFeeComponents;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct astria.protocol.fees.v1.FeeChangeFeeComponents")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<FeeChangeFeeComponents, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut base__ = None;
+                let mut multiplier__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Base => {
+                            if base__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("base"));
+                            }
+                            base__ = map_.next_value()?;
+                        }
+                        GeneratedField::Multiplier => {
+                            if multiplier__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("multiplier"));
+                            }
+                            multiplier__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(FeeChangeFeeComponents {
+                    base: base__,
+                    multiplier: multiplier__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("astria.protocol.fees.v1.FeeChangeFeeComponents", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for FeeDistribution {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.proposer_basis_points != 0 {
+            len += 1;
+        }
+        if self.validators_basis_points != 0 {
+            len += 1;
+        }
+        if self.community_pool_basis_points != 0 {
+            len += 1;
+        }
+        if self.burn_basis_points != 0 {
+            len += 1;
+        }
+        if self.community_pool_address.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("astria.protocol.fees.v1.FeeDistribution", len)?;
+        if self.proposer_basis_points != 0 {
+            struct_ser.serialize_field("proposerBasisPoints", &self.proposer_basis_points)?;
+        }
+        if self.validators_basis_points != 0 {
+            struct_ser.serialize_field("validatorsBasisPoints", &self.validators_basis_points)?;
+        }
+        if self.community_pool_basis_points != 0 {
+            struct_ser.serialize_field("communityPoolBasisPoints", &self.community_pool_basis_points)?;
+        }
+        if self.burn_basis_points != 0 {
+            struct_ser.serialize_field("burnBasisPoints", &self.burn_basis_points)?;
+        }
+        if let Some(v) = self.community_pool_address.as_ref() {
+            struct_ser.serialize_field("communityPoolAddress", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for FeeDistribution {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "proposer_basis_points",
+            "proposerBasisPoints",
+            "validators_basis_points",
+            "validatorsBasisPoints",
+            "community_pool_basis_points",
+            "communityPoolBasisPoints",
+            "burn_basis_points",
+            "burnBasisPoints",
+            "community_pool_address",
+            "communityPoolAddress",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ProposerBasisPoints,
+            ValidatorsBasisPoints,
+            CommunityPoolBasisPoints,
+            BurnBasisPoints,
+            CommunityPoolAddress,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "proposerBasisPoints" | "proposer_basis_points" => Ok(GeneratedField::ProposerBasisPoints),
+                            "validatorsBasisPoints" | "validators_basis_points" => Ok(GeneratedField::ValidatorsBasisPoints),
+                            "communityPoolBasisPoints" | "community_pool_basis_points" => Ok(GeneratedField::CommunityPoolBasisPoints),
+                            "burnBasisPoints" | "burn_basis_points" => Ok(GeneratedField::BurnBasisPoints),
+                            "communityPoolAddress" | "community_pool_address" => Ok(GeneratedField::CommunityPoolAddress),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = FeeDistribution;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct astria.protocol.fees.v1.FeeDistribution")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<FeeDistribution, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut proposer_basis_points__ = None;
+                let mut validators_basis_points__ = None;
+                let mut community_pool_basis_points__ = None;
+                let mut burn_basis_points__ = None;
+                let mut community_pool_address__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::ProposerBasisPoints => {
+                            if proposer_basis_points__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("proposerBasisPoints"));
+                            }
+                            proposer_basis_points__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::ValidatorsBasisPoints => {
+                            if validators_basis_points__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("validatorsBasisPoints"));
+                            }
+                            validators_basis_points__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::CommunityPoolBasisPoints => {
+                            if community_pool_basis_points__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("communityPoolBasisPoints"));
+                            }
+                            community_pool_basis_points__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::BurnBasisPoints => {
+                            if burn_basis_points__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("burnBasisPoints"));
+                            }
+                            burn_basis_points__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::CommunityPoolAddress => {
+                            if community_pool_address__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("communityPoolAddress"));
+                            }
+                            community_pool_address__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(FeeDistribution {
+                    proposer_basis_points: proposer_basis_points__.unwrap_or_default(),
+                    validators_basis_points: validators_basis_points__.unwrap_or_default(),
+                    community_pool_basis_points: community_pool_basis_points__.unwrap_or_default(),
+                    burn_basis_points: burn_basis_points__.unwrap_or_default(),
+                    community_pool_address: community_pool_address__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("astria.protocol.fees.v1.FeeDistribution", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for FeeDistributionChangeFeeComponents {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.base.is_some() {
+            len += 1;
+        }
+        if self.multiplier.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("astria.protocol.fees.v1.FeeDistributionChangeFeeComponents", len)?;
+        if let Some(v) = self.base.as_ref() {
+            struct_ser.serialize_field("base", v)?;
+        }
+        if let Some(v) = self.multiplier.as_ref() {
+            struct_ser.serialize_field("multiplier", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for FeeDistributionChangeFeeComponents {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "base",
+            "multiplier",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Base,
+            Multiplier,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "base" => Ok(GeneratedField::Base),
+                            "multiplier" => Ok(GeneratedField::Multiplier),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = FeeDistributionChangeFeeComponents;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct astria.protocol.fees.v1.FeeDistributionChangeFeeComponents")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<FeeDistributionChangeFeeComponents, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut base__ = None;
+                let mut multiplier__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Base => {
+                            if base__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("base"));
+                            }
+                            base__ = map_.next_value()?;
+                        }
+                        GeneratedField::Multiplier => {
+                            if multiplier__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("multiplier"));
+                            }
+                            multiplier__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(FeeDistributionChangeFeeComponents {
+                    base: base__,
+                    multiplier: multiplier__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("astria.protocol.fees.v1.FeeDistributionChangeFeeComponents", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for IbcRelayFeeComponents {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.base.is_some() {
+            len += 1;
+        }
+        if self.multiplier.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("astria.protocol.fees.v1.IbcRelayFeeComponents", len)?;
+        if let Some(v) = self.base.as_ref() {
+            struct_ser.serialize_field("base", v)?;
+        }
+        if let Some(v) = self.multiplier.as_ref() {
+            struct_ser.serialize_field("multiplier", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for IbcRelayFeeComponents {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "base",
+            "multiplier",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Base,
+            Multiplier,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "base" => Ok(GeneratedField::Base),
+                            "multiplier" => Ok(GeneratedField::Multiplier),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = IbcRelayFeeComponents;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct astria.protocol.fees.v1.IbcRelayFeeComponents")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<IbcRelayFeeComponents, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut base__ = None;
+                let mut multiplier__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Base => {
+                            if base__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("base"));
+                            }
+                            base__ = map_.next_value()?;
+                        }
+                        GeneratedField::Multiplier => {
+                            if multiplier__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("multiplier"));
+                            }
+                            multiplier__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(IbcRelayFeeComponents {
                     base: base__,
                     multiplier: multiplier__,
                 })
             }
         }
-        deserializer.deserialize_struct("astria.protocol.fees.v1.CurrencyPairsChangeFeeComponents", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("astria.protocol.fees.v1.IbcRelayFeeComponents", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for FeeAssetChangeFeeComponents {
+impl serde::Serialize for IbcRelayerChangeFeeComponents {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -552,7 +1372,7 @@ impl serde::Serialize for FeeAssetChangeFeeComponents {
         if self.multiplier.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("astria.protocol.fees.v1.FeeAssetChangeFeeComponents", len)?;
+        let mut struct_ser = serializer.serialize_struct("astria.protocol.fees.v1.IbcRelayerChangeFeeComponents", len)?;
         if let Some(v) = self.base.as_ref() {
             struct_ser.serialize_field("base", v)?;
         }
@@ -562,7 +1382,7 @@ impl serde::Serialize for FeeAssetChangeFeeComponents {
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for FeeAssetChangeFeeComponents {
+impl<'de> serde::Deserialize<'de> for IbcRelayerChangeFeeComponents {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
@@ -609,13 +1429,13 @@ impl<'de> serde::Deserialize<'de> for FeeAssetChangeFeeComponents {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = FeeAssetChangeFeeComponents;
+            type Value = IbcRelayerChangeFeeComponents;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct astria.protocol.fees.v1.FeeAssetChangeFeeComponents")
+                formatter.write_str("struct astria.protocol.fees.v1.IbcRelayerChangeFeeComponents")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<FeeAssetChangeFeeComponents, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<IbcRelayerChangeFeeComponents, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
@@ -637,16 +1457,16 @@ impl<'de> serde::Deserialize<'de> for FeeAssetChangeFeeComponents {
                         }
                     }
                 }
-                Ok(FeeAssetChangeFeeComponents {
+                Ok(IbcRelayerChangeFeeComponents {
                     base: base__,
                     multiplier: multiplier__,
                 })
             }
         }
-        deserializer.deserialize_struct("astria.protocol.fees.v1.FeeAssetChangeFeeComponents", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("astria.protocol.fees.v1.IbcRelayerChangeFeeComponents", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for FeeChangeFeeComponents {
+impl serde::Serialize for IbcSudoChangeFeeComponents {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -660,7 +1480,7 @@ impl serde::Serialize for FeeChangeFeeComponents {
         if self.multiplier.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("astria.protocol.fees.v1.FeeChangeFeeComponents", len)?;
+        let mut struct_ser = serializer.serialize_struct("astria.protocol.fees.v1.IbcSudoChangeFeeComponents", len)?;
         if let Some(v) = self.base.as_ref() {
             struct_ser.serialize_field("base", v)?;
         }
@@ -670,7 +1490,7 @@ impl serde::Serialize for FeeChangeFeeComponents {
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for FeeChangeFeeComponents {
+impl<'de> serde::Deserialize<'de> for IbcSudoChangeFeeComponents {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
@@ -717,13 +1537,13 @@ impl<'de> serde::Deserialize<'de> for FeeChangeFeeComponents {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = FeeChangeFeeComponents;
+            type Value = IbcSudoChangeFeeComponents;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct astria.protocol.fees.v1.FeeChangeFeeComponents")
+                formatter.write_str("struct astria.protocol.fees.v1.IbcSudoChangeFeeComponents")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<FeeChangeFeeComponents, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<IbcSudoChangeFeeComponents, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
@@ -745,16 +1565,16 @@ impl<'de> serde::Deserialize<'de> for FeeChangeFeeComponents {
                         }
                     }
                 }
-                Ok(FeeChangeFeeComponents {
+                Ok(IbcSudoChangeFeeComponents {
                     base: base__,
                     multiplier: multiplier__,
                 })
             }
         }
-        deserializer.deserialize_struct("astria.protocol.fees.v1.FeeChangeFeeComponents", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("astria.protocol.fees.v1.IbcSudoChangeFeeComponents", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for IbcRelayFeeComponents {
+impl serde::Serialize for Ics20WithdrawalFeeComponents {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -768,7 +1588,7 @@ impl serde::Serialize for IbcRelayFeeComponents {
         if self.multiplier.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("astria.protocol.fees.v1.IbcRelayFeeComponents", len)?;
+        let mut struct_ser = serializer.serialize_struct("astria.protocol.fees.v1.Ics20WithdrawalFeeComponents", len)?;
         if let Some(v) = self.base.as_ref() {
             struct_ser.serialize_field("base", v)?;
         }
@@ -778,7 +1598,7 @@ impl serde::Serialize for IbcRelayFeeComponents {
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for IbcRelayFeeComponents {
+impl<'de> serde::Deserialize<'de> for Ics20WithdrawalFeeComponents {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
@@ -825,13 +1645,13 @@ impl<'de> serde::Deserialize<'de> for IbcRelayFeeComponents {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = IbcRelayFeeComponents;
+            type Value = Ics20WithdrawalFeeComponents;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct astria.protocol.fees.v1.IbcRelayFeeComponents")
+                formatter.write_str("struct astria.protocol.fees.v1.Ics20WithdrawalFeeComponents")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<IbcRelayFeeComponents, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Ics20WithdrawalFeeComponents, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
@@ -853,16 +1673,16 @@ impl<'de> serde::Deserialize<'de> for IbcRelayFeeComponents {
                         }
                     }
                 }
-                Ok(IbcRelayFeeComponents {
+                Ok(Ics20WithdrawalFeeComponents {
                     base: base__,
                     multiplier: multiplier__,
                 })
             }
         }
-        deserializer.deserialize_struct("astria.protocol.fees.v1.IbcRelayFeeComponents", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("astria.protocol.fees.v1.Ics20WithdrawalFeeComponents", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for IbcRelayerChangeFeeComponents {
+impl serde::Serialize for InitBridgeAccountFeeComponents {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -876,7 +1696,7 @@ impl serde::Serialize for IbcRelayerChangeFeeComponents {
         if self.multiplier.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("astria.protocol.fees.v1.IbcRelayerChangeFeeComponents", len)?;
+        let mut struct_ser = serializer.serialize_struct("astria.protocol.fees.v1.InitBridgeAccountFeeComponents", len)?;
         if let Some(v) = self.base.as_ref() {
             struct_ser.serialize_field("base", v)?;
         }
@@ -886,7 +1706,7 @@ impl serde::Serialize for IbcRelayerChangeFeeComponents {
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for IbcRelayerChangeFeeComponents {
+impl<'de> serde::Deserialize<'de> for InitBridgeAccountFeeComponents {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
@@ -933,13 +1753,13 @@ impl<'de> serde::Deserialize<'de> for IbcRelayerChangeFeeComponents {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = IbcRelayerChangeFeeComponents;
+            type Value = InitBridgeAccountFeeComponents;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct astria.protocol.fees.v1.IbcRelayerChangeFeeComponents")
+                formatter.write_str("struct astria.protocol.fees.v1.InitBridgeAccountFeeComponents")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<IbcRelayerChangeFeeComponents, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<InitBridgeAccountFeeComponents, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
@@ -961,16 +1781,16 @@ impl<'de> serde::Deserialize<'de> for IbcRelayerChangeFeeComponents {
                         }
                     }
                 }
-                Ok(IbcRelayerChangeFeeComponents {
+                Ok(InitBridgeAccountFeeComponents {
                     base: base__,
                     multiplier: multiplier__,
                 })
             }
         }
-        deserializer.deserialize_struct("astria.protocol.fees.v1.IbcRelayerChangeFeeComponents", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("astria.protocol.fees.v1.InitBridgeAccountFeeComponents", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for IbcSudoChangeFeeComponents {
+impl serde::Serialize for MarketsChangeFeeComponents {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -984,7 +1804,7 @@ impl serde::Serialize for IbcSudoChangeFeeComponents {
         if self.multiplier.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("astria.protocol.fees.v1.IbcSudoChangeFeeComponents", len)?;
+        let mut struct_ser = serializer.serialize_struct("astria.protocol.fees.v1.MarketsChangeFeeComponents", len)?;
         if let Some(v) = self.base.as_ref() {
             struct_ser.serialize_field("base", v)?;
         }
@@ -994,7 +1814,7 @@ impl serde::Serialize for IbcSudoChangeFeeComponents {
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for IbcSudoChangeFeeComponents {
+impl<'de> serde::Deserialize<'de> for MarketsChangeFeeComponents {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
@@ -1041,13 +1861,13 @@ impl<'de> serde::Deserialize<'de> for IbcSudoChangeFeeComponents {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = IbcSudoChangeFeeComponents;
+            type Value = MarketsChangeFeeComponents;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct astria.protocol.fees.v1.IbcSudoChangeFeeComponents")
+                formatter.write_str("struct astria.protocol.fees.v1.MarketsChangeFeeComponents")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<IbcSudoChangeFeeComponents, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<MarketsChangeFeeComponents, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
@@ -1069,16 +1889,16 @@ impl<'de> serde::Deserialize<'de> for IbcSudoChangeFeeComponents {
                         }
                     }
                 }
-                Ok(IbcSudoChangeFeeComponents {
+                Ok(MarketsChangeFeeComponents {
                     base: base__,
                     multiplier: multiplier__,
                 })
             }
         }
-        deserializer.deserialize_struct("astria.protocol.fees.v1.IbcSudoChangeFeeComponents", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("astria.protocol.fees.v1.MarketsChangeFeeComponents", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for Ics20WithdrawalFeeComponents {
+impl serde::Serialize for RecoverIbcClientFeeComponents {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -1092,7 +1912,7 @@ impl serde::Serialize for Ics20WithdrawalFeeComponents {
         if self.multiplier.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("astria.protocol.fees.v1.Ics20WithdrawalFeeComponents", len)?;
+        let mut struct_ser = serializer.serialize_struct("astria.protocol.fees.v1.RecoverIbcClientFeeComponents", len)?;
         if let Some(v) = self.base.as_ref() {
             struct_ser.serialize_field("base", v)?;
         }
@@ -1102,7 +1922,7 @@ impl serde::Serialize for Ics20WithdrawalFeeComponents {
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for Ics20WithdrawalFeeComponents {
+impl<'de> serde::Deserialize<'de> for RecoverIbcClientFeeComponents {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
@@ -1149,13 +1969,13 @@ impl<'de> serde::Deserialize<'de> for Ics20WithdrawalFeeComponents {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = Ics20WithdrawalFeeComponents;
+            type Value = RecoverIbcClientFeeComponents;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct astria.protocol.fees.v1.Ics20WithdrawalFeeComponents")
+                formatter.write_str("struct astria.protocol.fees.v1.RecoverIbcClientFeeComponents")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Ics20WithdrawalFeeComponents, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<RecoverIbcClientFeeComponents, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
@@ -1177,16 +1997,16 @@ impl<'de> serde::Deserialize<'de> for Ics20WithdrawalFeeComponents {
                         }
                     }
                 }
-                Ok(Ics20WithdrawalFeeComponents {
+                Ok(RecoverIbcClientFeeComponents {
                     base: base__,
                     multiplier: multiplier__,
                 })
             }
         }
-        deserializer.deserialize_struct("astria.protocol.fees.v1.Ics20WithdrawalFeeComponents", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("astria.protocol.fees.v1.RecoverIbcClientFeeComponents", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for InitBridgeAccountFeeComponents {
+impl serde::Serialize for RegisterMultisigFeeComponents {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -1200,7 +2020,7 @@ impl serde::Serialize for InitBridgeAccountFeeComponents {
         if self.multiplier.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("astria.protocol.fees.v1.InitBridgeAccountFeeComponents", len)?;
+        let mut struct_ser = serializer.serialize_struct("astria.protocol.fees.v1.RegisterMultisigFeeComponents", len)?;
         if let Some(v) = self.base.as_ref() {
             struct_ser.serialize_field("base", v)?;
         }
@@ -1210,7 +2030,7 @@ impl serde::Serialize for InitBridgeAccountFeeComponents {
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for InitBridgeAccountFeeComponents {
+impl<'de> serde::Deserialize<'de> for RegisterMultisigFeeComponents {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
@@ -1257,13 +2077,13 @@ impl<'de> serde::Deserialize<'de> for InitBridgeAccountFeeComponents {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = InitBridgeAccountFeeComponents;
+            type Value = RegisterMultisigFeeComponents;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct astria.protocol.fees.v1.InitBridgeAccountFeeComponents")
+                formatter.write_str("struct astria.protocol.fees.v1.RegisterMultisigFeeComponents")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<InitBridgeAccountFeeComponents, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<RegisterMultisigFeeComponents, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
@@ -1285,16 +2105,16 @@ impl<'de> serde::Deserialize<'de> for InitBridgeAccountFeeComponents {
                         }
                     }
                 }
-                Ok(InitBridgeAccountFeeComponents {
+                Ok(RegisterMultisigFeeComponents {
                     base: base__,
                     multiplier: multiplier__,
                 })
             }
         }
-        deserializer.deserialize_struct("astria.protocol.fees.v1.InitBridgeAccountFeeComponents", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("astria.protocol.fees.v1.RegisterMultisigFeeComponents", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for MarketsChangeFeeComponents {
+impl serde::Serialize for RollupDataSubmissionFeeComponents {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -1308,7 +2128,7 @@ impl serde::Serialize for MarketsChangeFeeComponents {
         if self.multiplier.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("astria.protocol.fees.v1.MarketsChangeFeeComponents", len)?;
+        let mut struct_ser = serializer.serialize_struct("astria.protocol.fees.v1.RollupDataSubmissionFeeComponents", len)?;
         if let Some(v) = self.base.as_ref() {
             struct_ser.serialize_field("base", v)?;
         }
@@ -1318,7 +2138,7 @@ impl serde::Serialize for MarketsChangeFeeComponents {
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for MarketsChangeFeeComponents {
+impl<'de> serde::Deserialize<'de> for RollupDataSubmissionFeeComponents {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
@@ -1365,13 +2185,13 @@ impl<'de> serde::Deserialize<'de> for MarketsChangeFeeComponents {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = MarketsChangeFeeComponents;
+            type Value = RollupDataSubmissionFeeComponents;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct astria.protocol.fees.v1.MarketsChangeFeeComponents")
+                formatter.write_str("struct astria.protocol.fees.v1.RollupDataSubmissionFeeComponents")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<MarketsChangeFeeComponents, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<RollupDataSubmissionFeeComponents, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
@@ -1393,16 +2213,16 @@ impl<'de> serde::Deserialize<'de> for MarketsChangeFeeComponents {
                         }
                     }
                 }
-                Ok(MarketsChangeFeeComponents {
+                Ok(RollupDataSubmissionFeeComponents {
                     base: base__,
                     multiplier: multiplier__,
                 })
             }
         }
-        deserializer.deserialize_struct("astria.protocol.fees.v1.MarketsChangeFeeComponents", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("astria.protocol.fees.v1.RollupDataSubmissionFeeComponents", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for RecoverIbcClientFeeComponents {
+impl serde::Serialize for SubmitProposalFeeComponents {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -1416,7 +2236,7 @@ impl serde::Serialize for RecoverIbcClientFeeComponents {
         if self.multiplier.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("astria.protocol.fees.v1.RecoverIbcClientFeeComponents", len)?;
+        let mut struct_ser = serializer.serialize_struct("astria.protocol.fees.v1.SubmitProposalFeeComponents", len)?;
         if let Some(v) = self.base.as_ref() {
             struct_ser.serialize_field("base", v)?;
         }
@@ -1426,7 +2246,7 @@ impl serde::Serialize for RecoverIbcClientFeeComponents {
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for RecoverIbcClientFeeComponents {
+impl<'de> serde::Deserialize<'de> for SubmitProposalFeeComponents {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
@@ -1473,13 +2293,13 @@ impl<'de> serde::Deserialize<'de> for RecoverIbcClientFeeComponents {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = RecoverIbcClientFeeComponents;
+            type Value = SubmitProposalFeeComponents;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct astria.protocol.fees.v1.RecoverIbcClientFeeComponents")
+                formatter.write_str("struct astria.protocol.fees.v1.SubmitProposalFeeComponents")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<RecoverIbcClientFeeComponents, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SubmitProposalFeeComponents, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
@@ -1501,16 +2321,16 @@ impl<'de> serde::Deserialize<'de> for RecoverIbcClientFeeComponents {
                         }
                     }
                 }
-                Ok(RecoverIbcClientFeeComponents {
+                Ok(SubmitProposalFeeComponents {
                     base: base__,
                     multiplier: multiplier__,
                 })
             }
         }
-        deserializer.deserialize_struct("astria.protocol.fees.v1.RecoverIbcClientFeeComponents", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("astria.protocol.fees.v1.SubmitProposalFeeComponents", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for RollupDataSubmissionFeeComponents {
+impl serde::Serialize for SudoAddressChangeFeeComponents {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -1524,7 +2344,7 @@ impl serde::Serialize for RollupDataSubmissionFeeComponents {
         if self.multiplier.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("astria.protocol.fees.v1.RollupDataSubmissionFeeComponents", len)?;
+        let mut struct_ser = serializer.serialize_struct("astria.protocol.fees.v1.SudoAddressChangeFeeComponents", len)?;
         if let Some(v) = self.base.as_ref() {
             struct_ser.serialize_field("base", v)?;
         }
@@ -1534,7 +2354,7 @@ impl serde::Serialize for RollupDataSubmissionFeeComponents {
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for RollupDataSubmissionFeeComponents {
+impl<'de> serde::Deserialize<'de> for SudoAddressChangeFeeComponents {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
@@ -1581,13 +2401,13 @@ impl<'de> serde::Deserialize<'de> for RollupDataSubmissionFeeComponents {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = RollupDataSubmissionFeeComponents;
+            type Value = SudoAddressChangeFeeComponents;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct astria.protocol.fees.v1.RollupDataSubmissionFeeComponents")
+                formatter.write_str("struct astria.protocol.fees.v1.SudoAddressChangeFeeComponents")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<RollupDataSubmissionFeeComponents, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SudoAddressChangeFeeComponents, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
@@ -1609,16 +2429,16 @@ impl<'de> serde::Deserialize<'de> for RollupDataSubmissionFeeComponents {
                         }
                     }
                 }
-                Ok(RollupDataSubmissionFeeComponents {
+                Ok(SudoAddressChangeFeeComponents {
                     base: base__,
                     multiplier: multiplier__,
                 })
             }
         }
-        deserializer.deserialize_struct("astria.protocol.fees.v1.RollupDataSubmissionFeeComponents", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("astria.protocol.fees.v1.SudoAddressChangeFeeComponents", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for SudoAddressChangeFeeComponents {
+impl serde::Serialize for TimelockedTransferFeeComponents {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -1632,7 +2452,7 @@ impl serde::Serialize for SudoAddressChangeFeeComponents {
         if self.multiplier.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("astria.protocol.fees.v1.SudoAddressChangeFeeComponents", len)?;
+        let mut struct_ser = serializer.serialize_struct("astria.protocol.fees.v1.TimelockedTransferFeeComponents", len)?;
         if let Some(v) = self.base.as_ref() {
             struct_ser.serialize_field("base", v)?;
         }
@@ -1642,7 +2462,7 @@ impl serde::Serialize for SudoAddressChangeFeeComponents {
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for SudoAddressChangeFeeComponents {
+impl<'de> serde::Deserialize<'de> for TimelockedTransferFeeComponents {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
@@ -1689,13 +2509,13 @@ impl<'de> serde::Deserialize<'de> for SudoAddressChangeFeeComponents {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = SudoAddressChangeFeeComponents;
+            type Value = TimelockedTransferFeeComponents;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct astria.protocol.fees.v1.SudoAddressChangeFeeComponents")
+                formatter.write_str("struct astria.protocol.fees.v1.TimelockedTransferFeeComponents")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SudoAddressChangeFeeComponents, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<TimelockedTransferFeeComponents, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
@@ -1717,13 +2537,13 @@ impl<'de> serde::Deserialize<'de> for SudoAddressChangeFeeComponents {
                         }
                     }
                 }
-                Ok(SudoAddressChangeFeeComponents {
+                Ok(TimelockedTransferFeeComponents {
                     base: base__,
                     multiplier: multiplier__,
                 })
             }
         }
-        deserializer.deserialize_struct("astria.protocol.fees.v1.SudoAddressChangeFeeComponents", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("astria.protocol.fees.v1.TimelockedTransferFeeComponents", FIELDS, GeneratedVisitor)
     }
 }
 impl serde::Serialize for TransactionFee {
@@ -2162,3 +2982,111 @@ impl<'de> serde::Deserialize<'de> for ValidatorUpdateFeeComponents {
         deserializer.deserialize_struct("astria.protocol.fees.v1.ValidatorUpdateFeeComponents", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for VoteOnProposalFeeComponents {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.base.is_some() {
+            len += 1;
+        }
+        if self.multiplier.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("astria.protocol.fees.v1.VoteOnProposalFeeComponents", len)?;
+        if let Some(v) = self.base.as_ref() {
+            struct_ser.serialize_field("base", v)?;
+        }
+        if let Some(v) = self.multiplier.as_ref() {
+            struct_ser.serialize_field("multiplier", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for VoteOnProposalFeeComponents {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "base",
+            "multiplier",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Base,
+            Multiplier,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "base" => Ok(GeneratedField::Base),
+                            "multiplier" => Ok(GeneratedField::Multiplier),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = VoteOnProposalFeeComponents;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct astria.protocol.fees.v1.VoteOnProposalFeeComponents")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<VoteOnProposalFeeComponents, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut base__ = None;
+                let mut multiplier__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Base => {
+                            if base__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("base"));
+                            }
+                            base__ = map_.next_value()?;
+                        }
+                        GeneratedField::Multiplier => {
+                            if multiplier__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("multiplier"));
+                            }
+                            multiplier__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(VoteOnProposalFeeComponents {
+                    base: base__,
+                    multiplier: multiplier__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("astria.protocol.fees.v1.VoteOnProposalFeeComponents", FIELDS, GeneratedVisitor)
+    }
+}
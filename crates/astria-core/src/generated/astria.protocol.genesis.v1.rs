@@ -27,6 +27,18 @@ pub struct GenesisAppState {
     pub allowed_fee_assets: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
     #[prost(message, optional, tag = "10")]
     pub fees: ::core::option::Option<GenesisFees>,
+    /// If unset, governance proposals cannot be submitted.
+    #[prost(message, optional, tag = "11")]
+    pub governance_parameters: ::core::option::Option<GovernanceParameters>,
+    /// If unset, all fees collected in a block are paid to the sudo address.
+    #[prost(message, optional, tag = "12")]
+    pub fee_distribution: ::core::option::Option<
+        super::super::fees::v1::FeeDistribution,
+    >,
+    /// If unset, validators' participation in the price feed oracle is not
+    /// tracked.
+    #[prost(message, optional, tag = "13")]
+    pub oracle_liveness_parameters: ::core::option::Option<OracleLivenessParameters>,
 }
 impl ::prost::Name for GenesisAppState {
     const NAME: &'static str = "GenesisAppState";
@@ -98,6 +110,68 @@ impl ::prost::Name for IbcParameters {
         "/astria.protocol.genesis.v1.IbcParameters".into()
     }
 }
+/// Governance configuration data.
+///
+/// Passed proposals execute their sudo action on behalf of the governance
+/// account, so they only take effect once the sudo address (and, for IBC
+/// actions, the IBC sudo address) is set to the governance account.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GovernanceParameters {
+    /// The addresses allowed to submit proposals.
+    #[prost(message, repeated, tag = "1")]
+    pub proposers: ::prost::alloc::vec::Vec<super::super::super::primitive::v1::Address>,
+    /// The addresses allowed to vote on proposals, each with equal weight. If
+    /// empty, validators vote on proposals weighted by their voting power.
+    #[prost(message, repeated, tag = "2")]
+    pub council: ::prost::alloc::vec::Vec<super::super::super::primitive::v1::Address>,
+    /// The number of blocks after its submission during which a proposal can
+    /// be voted on.
+    #[prost(uint64, tag = "3")]
+    pub voting_period_blocks: u64,
+    /// The share of the total voting weight, in basis points, that approving
+    /// votes must exceed for a proposal to pass.
+    #[prost(uint32, tag = "4")]
+    pub approval_threshold_basis_points: u32,
+}
+impl ::prost::Name for GovernanceParameters {
+    const NAME: &'static str = "GovernanceParameters";
+    const PACKAGE: &'static str = "astria.protocol.genesis.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "astria.protocol.genesis.v1.GovernanceParameters".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/astria.protocol.genesis.v1.GovernanceParameters".into()
+    }
+}
+/// Price feed oracle liveness configuration data.
+///
+/// A validator's participation is the share of the most recent blocks carrying
+/// vote extensions in which it submitted a vote extension.
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct OracleLivenessParameters {
+    /// The number of most recent blocks carrying vote extensions over which
+    /// participation is measured.
+    #[prost(uint64, tag = "1")]
+    pub window_blocks: u64,
+    /// The participation, in basis points of the window, below which a validator
+    /// is considered inactive.
+    #[prost(uint32, tag = "2")]
+    pub min_participation_basis_points: u32,
+    /// Whether validators found inactive over a full window are removed from the
+    /// validator set. If false, participation is only tracked.
+    #[prost(bool, tag = "3")]
+    pub remove_inactive_validators: bool,
+}
+impl ::prost::Name for OracleLivenessParameters {
+    const NAME: &'static str = "OracleLivenessParameters";
+    const PACKAGE: &'static str = "astria.protocol.genesis.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "astria.protocol.genesis.v1.OracleLivenessParameters".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/astria.protocol.genesis.v1.OracleLivenessParameters".into()
+    }
+}
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct GenesisFees {
     #[prost(message, optional, tag = "1")]
@@ -168,6 +242,34 @@ pub struct GenesisFees {
     pub markets_change: ::core::option::Option<
         super::super::fees::v1::MarketsChangeFeeComponents,
     >,
+    #[prost(message, optional, tag = "19")]
+    pub register_multisig: ::core::option::Option<
+        super::super::fees::v1::RegisterMultisigFeeComponents,
+    >,
+    #[prost(message, optional, tag = "20")]
+    pub timelocked_transfer: ::core::option::Option<
+        super::super::fees::v1::TimelockedTransferFeeComponents,
+    >,
+    #[prost(message, optional, tag = "21")]
+    pub claim_timelocked_transfer: ::core::option::Option<
+        super::super::fees::v1::ClaimTimelockedTransferFeeComponents,
+    >,
+    #[prost(message, optional, tag = "22")]
+    pub cancel_timelocked_transfer: ::core::option::Option<
+        super::super::fees::v1::CancelTimelockedTransferFeeComponents,
+    >,
+    #[prost(message, optional, tag = "23")]
+    pub submit_proposal: ::core::option::Option<
+        super::super::fees::v1::SubmitProposalFeeComponents,
+    >,
+    #[prost(message, optional, tag = "24")]
+    pub vote_on_proposal: ::core::option::Option<
+        super::super::fees::v1::VoteOnProposalFeeComponents,
+    >,
+    #[prost(message, optional, tag = "25")]
+    pub fee_distribution_change: ::core::option::Option<
+        super::super::fees::v1::FeeDistributionChangeFeeComponents,
+    >,
 }
 impl ::prost::Name for GenesisFees {
     const NAME: &'static str = "GenesisFees";
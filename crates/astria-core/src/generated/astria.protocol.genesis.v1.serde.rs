@@ -253,6 +253,15 @@ impl serde::Serialize for GenesisAppState {
         if self.fees.is_some() {
             len += 1;
         }
+        if self.governance_parameters.is_some() {
+            len += 1;
+        }
+        if self.fee_distribution.is_some() {
+            len += 1;
+        }
+        if self.oracle_liveness_parameters.is_some() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("astria.protocol.genesis.v1.GenesisAppState", len)?;
         if !self.chain_id.is_empty() {
             struct_ser.serialize_field("chainId", &self.chain_id)?;
@@ -284,6 +293,15 @@ impl serde::Serialize for GenesisAppState {
         if let Some(v) = self.fees.as_ref() {
             struct_ser.serialize_field("fees", v)?;
         }
+        if let Some(v) = self.governance_parameters.as_ref() {
+            struct_ser.serialize_field("governanceParameters", v)?;
+        }
+        if let Some(v) = self.fee_distribution.as_ref() {
+            struct_ser.serialize_field("feeDistribution", v)?;
+        }
+        if let Some(v) = self.oracle_liveness_parameters.as_ref() {
+            struct_ser.serialize_field("oracleLivenessParameters", v)?;
+        }
         struct_ser.end()
     }
 }
@@ -312,6 +330,12 @@ impl<'de> serde::Deserialize<'de> for GenesisAppState {
             "allowed_fee_assets",
             "allowedFeeAssets",
             "fees",
+            "governance_parameters",
+            "governanceParameters",
+            "fee_distribution",
+            "feeDistribution",
+            "oracle_liveness_parameters",
+            "oracleLivenessParameters",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -326,6 +350,9 @@ impl<'de> serde::Deserialize<'de> for GenesisAppState {
             IbcParameters,
             AllowedFeeAssets,
             Fees,
+            GovernanceParameters,
+            FeeDistribution,
+            OracleLivenessParameters,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -357,6 +384,9 @@ impl<'de> serde::Deserialize<'de> for GenesisAppState {
                             "ibcParameters" | "ibc_parameters" => Ok(GeneratedField::IbcParameters),
                             "allowedFeeAssets" | "allowed_fee_assets" => Ok(GeneratedField::AllowedFeeAssets),
                             "fees" => Ok(GeneratedField::Fees),
+                            "governanceParameters" | "governance_parameters" => Ok(GeneratedField::GovernanceParameters),
+                            "feeDistribution" | "fee_distribution" => Ok(GeneratedField::FeeDistribution),
+                            "oracleLivenessParameters" | "oracle_liveness_parameters" => Ok(GeneratedField::OracleLivenessParameters),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -386,6 +416,9 @@ impl<'de> serde::Deserialize<'de> for GenesisAppState {
                 let mut ibc_parameters__ = None;
                 let mut allowed_fee_assets__ = None;
                 let mut fees__ = None;
+                let mut governance_parameters__ = None;
+                let mut fee_distribution__ = None;
+                let mut oracle_liveness_parameters__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::ChainId => {
@@ -448,6 +481,24 @@ impl<'de> serde::Deserialize<'de> for GenesisAppState {
                             }
                             fees__ = map_.next_value()?;
                         }
+                        GeneratedField::GovernanceParameters => {
+                            if governance_parameters__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("governanceParameters"));
+                            }
+                            governance_parameters__ = map_.next_value()?;
+                        }
+                        GeneratedField::FeeDistribution => {
+                            if fee_distribution__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("feeDistribution"));
+                            }
+                            fee_distribution__ = map_.next_value()?;
+                        }
+                        GeneratedField::OracleLivenessParameters => {
+                            if oracle_liveness_parameters__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("oracleLivenessParameters"));
+                            }
+                            oracle_liveness_parameters__ = map_.next_value()?;
+                        }
                     }
                 }
                 Ok(GenesisAppState {
@@ -461,6 +512,9 @@ impl<'de> serde::Deserialize<'de> for GenesisAppState {
                     ibc_parameters: ibc_parameters__,
                     allowed_fee_assets: allowed_fee_assets__.unwrap_or_default(),
                     fees: fees__,
+                    governance_parameters: governance_parameters__,
+                    fee_distribution: fee_distribution__,
+                    oracle_liveness_parameters: oracle_liveness_parameters__,
                 })
             }
         }
@@ -529,6 +583,27 @@ impl serde::Serialize for GenesisFees {
         if self.markets_change.is_some() {
             len += 1;
         }
+        if self.register_multisig.is_some() {
+            len += 1;
+        }
+        if self.timelocked_transfer.is_some() {
+            len += 1;
+        }
+        if self.claim_timelocked_transfer.is_some() {
+            len += 1;
+        }
+        if self.cancel_timelocked_transfer.is_some() {
+            len += 1;
+        }
+        if self.submit_proposal.is_some() {
+            len += 1;
+        }
+        if self.vote_on_proposal.is_some() {
+            len += 1;
+        }
+        if self.fee_distribution_change.is_some() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("astria.protocol.genesis.v1.GenesisFees", len)?;
         if let Some(v) = self.bridge_lock.as_ref() {
             struct_ser.serialize_field("bridgeLock", v)?;
@@ -584,6 +659,27 @@ impl serde::Serialize for GenesisFees {
         if let Some(v) = self.markets_change.as_ref() {
             struct_ser.serialize_field("marketsChange", v)?;
         }
+        if let Some(v) = self.register_multisig.as_ref() {
+            struct_ser.serialize_field("registerMultisig", v)?;
+        }
+        if let Some(v) = self.timelocked_transfer.as_ref() {
+            struct_ser.serialize_field("timelockedTransfer", v)?;
+        }
+        if let Some(v) = self.claim_timelocked_transfer.as_ref() {
+            struct_ser.serialize_field("claimTimelockedTransfer", v)?;
+        }
+        if let Some(v) = self.cancel_timelocked_transfer.as_ref() {
+            struct_ser.serialize_field("cancelTimelockedTransfer", v)?;
+        }
+        if let Some(v) = self.submit_proposal.as_ref() {
+            struct_ser.serialize_field("submitProposal", v)?;
+        }
+        if let Some(v) = self.vote_on_proposal.as_ref() {
+            struct_ser.serialize_field("voteOnProposal", v)?;
+        }
+        if let Some(v) = self.fee_distribution_change.as_ref() {
+            struct_ser.serialize_field("feeDistributionChange", v)?;
+        }
         struct_ser.end()
     }
 }
@@ -629,6 +725,20 @@ impl<'de> serde::Deserialize<'de> for GenesisFees {
             "currencyPairsChange",
             "markets_change",
             "marketsChange",
+            "register_multisig",
+            "registerMultisig",
+            "timelocked_transfer",
+            "timelockedTransfer",
+            "claim_timelocked_transfer",
+            "claimTimelockedTransfer",
+            "cancel_timelocked_transfer",
+            "cancelTimelockedTransfer",
+            "submit_proposal",
+            "submitProposal",
+            "vote_on_proposal",
+            "voteOnProposal",
+            "fee_distribution_change",
+            "feeDistributionChange",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -651,6 +761,13 @@ impl<'de> serde::Deserialize<'de> for GenesisFees {
             RecoverIbcClient,
             CurrencyPairsChange,
             MarketsChange,
+            RegisterMultisig,
+            TimelockedTransfer,
+            ClaimTimelockedTransfer,
+            CancelTimelockedTransfer,
+            SubmitProposal,
+            VoteOnProposal,
+            FeeDistributionChange,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -690,6 +807,13 @@ impl<'de> serde::Deserialize<'de> for GenesisFees {
                             "recoverIbcClient" | "recover_ibc_client" => Ok(GeneratedField::RecoverIbcClient),
                             "currencyPairsChange" | "currency_pairs_change" => Ok(GeneratedField::CurrencyPairsChange),
                             "marketsChange" | "markets_change" => Ok(GeneratedField::MarketsChange),
+                            "registerMultisig" | "register_multisig" => Ok(GeneratedField::RegisterMultisig),
+                            "timelockedTransfer" | "timelocked_transfer" => Ok(GeneratedField::TimelockedTransfer),
+                            "claimTimelockedTransfer" | "claim_timelocked_transfer" => Ok(GeneratedField::ClaimTimelockedTransfer),
+                            "cancelTimelockedTransfer" | "cancel_timelocked_transfer" => Ok(GeneratedField::CancelTimelockedTransfer),
+                            "submitProposal" | "submit_proposal" => Ok(GeneratedField::SubmitProposal),
+                            "voteOnProposal" | "vote_on_proposal" => Ok(GeneratedField::VoteOnProposal),
+                            "feeDistributionChange" | "fee_distribution_change" => Ok(GeneratedField::FeeDistributionChange),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -727,6 +851,13 @@ impl<'de> serde::Deserialize<'de> for GenesisFees {
                 let mut recover_ibc_client__ = None;
                 let mut currency_pairs_change__ = None;
                 let mut markets_change__ = None;
+                let mut register_multisig__ = None;
+                let mut timelocked_transfer__ = None;
+                let mut claim_timelocked_transfer__ = None;
+                let mut cancel_timelocked_transfer__ = None;
+                let mut submit_proposal__ = None;
+                let mut vote_on_proposal__ = None;
+                let mut fee_distribution_change__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::BridgeLock => {
@@ -837,6 +968,48 @@ impl<'de> serde::Deserialize<'de> for GenesisFees {
                             }
                             markets_change__ = map_.next_value()?;
                         }
+                        GeneratedField::RegisterMultisig => {
+                            if register_multisig__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("registerMultisig"));
+                            }
+                            register_multisig__ = map_.next_value()?;
+                        }
+                        GeneratedField::TimelockedTransfer => {
+                            if timelocked_transfer__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("timelockedTransfer"));
+                            }
+                            timelocked_transfer__ = map_.next_value()?;
+                        }
+                        GeneratedField::ClaimTimelockedTransfer => {
+                            if claim_timelocked_transfer__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("claimTimelockedTransfer"));
+                            }
+                            claim_timelocked_transfer__ = map_.next_value()?;
+                        }
+                        GeneratedField::CancelTimelockedTransfer => {
+                            if cancel_timelocked_transfer__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("cancelTimelockedTransfer"));
+                            }
+                            cancel_timelocked_transfer__ = map_.next_value()?;
+                        }
+                        GeneratedField::SubmitProposal => {
+                            if submit_proposal__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("submitProposal"));
+                            }
+                            submit_proposal__ = map_.next_value()?;
+                        }
+                        GeneratedField::VoteOnProposal => {
+                            if vote_on_proposal__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("voteOnProposal"));
+                            }
+                            vote_on_proposal__ = map_.next_value()?;
+                        }
+                        GeneratedField::FeeDistributionChange => {
+                            if fee_distribution_change__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("feeDistributionChange"));
+                            }
+                            fee_distribution_change__ = map_.next_value()?;
+                        }
                     }
                 }
                 Ok(GenesisFees {
@@ -858,12 +1031,169 @@ impl<'de> serde::Deserialize<'de> for GenesisFees {
                     recover_ibc_client: recover_ibc_client__,
                     currency_pairs_change: currency_pairs_change__,
                     markets_change: markets_change__,
+                    register_multisig: register_multisig__,
+                    timelocked_transfer: timelocked_transfer__,
+                    claim_timelocked_transfer: claim_timelocked_transfer__,
+                    cancel_timelocked_transfer: cancel_timelocked_transfer__,
+                    submit_proposal: submit_proposal__,
+                    vote_on_proposal: vote_on_proposal__,
+                    fee_distribution_change: fee_distribution_change__,
                 })
             }
         }
         deserializer.deserialize_struct("astria.protocol.genesis.v1.GenesisFees", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for GovernanceParameters {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.proposers.is_empty() {
+            len += 1;
+        }
+        if !self.council.is_empty() {
+            len += 1;
+        }
+        if self.voting_period_blocks != 0 {
+            len += 1;
+        }
+        if self.approval_threshold_basis_points != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("astria.protocol.genesis.v1.GovernanceParameters", len)?;
+        if !self.proposers.is_empty() {
+            struct_ser.serialize_field("proposers", &self.proposers)?;
+        }
+        if !self.council.is_empty() {
+            struct_ser.serialize_field("council", &self.council)?;
+        }
+        if self.voting_period_blocks != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("votingPeriodBlocks", ToString::to_string(&self.voting_period_blocks).as_str())?;
+        }
+        if self.approval_threshold_basis_points != 0 {
+            struct_ser.serialize_field("approvalThresholdBasisPoints", &self.approval_threshold_basis_points)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for GovernanceParameters {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "proposers",
+            "council",
+            "voting_period_blocks",
+            "votingPeriodBlocks",
+            "approval_threshold_basis_points",
+            "approvalThresholdBasisPoints",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Proposers,
+            Council,
+            VotingPeriodBlocks,
+            ApprovalThresholdBasisPoints,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "proposers" => Ok(GeneratedField::Proposers),
+                            "council" => Ok(GeneratedField::Council),
+                            "votingPeriodBlocks" | "voting_period_blocks" => Ok(GeneratedField::VotingPeriodBlocks),
+                            "approvalThresholdBasisPoints" | "approval_threshold_basis_points" => Ok(GeneratedField::ApprovalThresholdBasisPoints),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = GovernanceParameters;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct astria.protocol.genesis.v1.GovernanceParameters")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<GovernanceParameters, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut proposers__ = None;
+                let mut council__ = None;
+                let mut voting_period_blocks__ = None;
+                let mut approval_threshold_basis_points__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Proposers => {
+                            if proposers__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("proposers"));
+                            }
+                            proposers__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Council => {
+                            if council__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("council"));
+                            }
+                            council__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::VotingPeriodBlocks => {
+                            if voting_period_blocks__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("votingPeriodBlocks"));
+                            }
+                            voting_period_blocks__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::ApprovalThresholdBasisPoints => {
+                            if approval_threshold_basis_points__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("approvalThresholdBasisPoints"));
+                            }
+                            approval_threshold_basis_points__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(GovernanceParameters {
+                    proposers: proposers__.unwrap_or_default(),
+                    council: council__.unwrap_or_default(),
+                    voting_period_blocks: voting_period_blocks__.unwrap_or_default(),
+                    approval_threshold_basis_points: approval_threshold_basis_points__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("astria.protocol.genesis.v1.GovernanceParameters", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for IbcParameters {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -992,3 +1322,137 @@ impl<'de> serde::Deserialize<'de> for IbcParameters {
         deserializer.deserialize_struct("astria.protocol.genesis.v1.IbcParameters", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for OracleLivenessParameters {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.window_blocks != 0 {
+            len += 1;
+        }
+        if self.min_participation_basis_points != 0 {
+            len += 1;
+        }
+        if self.remove_inactive_validators {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("astria.protocol.genesis.v1.OracleLivenessParameters", len)?;
+        if self.window_blocks != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("windowBlocks", ToString::to_string(&self.window_blocks).as_str())?;
+        }
+        if self.min_participation_basis_points != 0 {
+            struct_ser.serialize_field("minParticipationBasisPoints", &self.min_participation_basis_points)?;
+        }
+        if self.remove_inactive_validators {
+            struct_ser.serialize_field("removeInactiveValidators", &self.remove_inactive_validators)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for OracleLivenessParameters {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "window_blocks",
+            "windowBlocks",
+            "min_participation_basis_points",
+            "minParticipationBasisPoints",
+            "remove_inactive_validators",
+            "removeInactiveValidators",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            WindowBlocks,
+            MinParticipationBasisPoints,
+            RemoveInactiveValidators,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "windowBlocks" | "window_blocks" => Ok(GeneratedField::WindowBlocks),
+                            "minParticipationBasisPoints" | "min_participation_basis_points" => Ok(GeneratedField::MinParticipationBasisPoints),
+                            "removeInactiveValidators" | "remove_inactive_validators" => Ok(GeneratedField::RemoveInactiveValidators),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = OracleLivenessParameters;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct astria.protocol.genesis.v1.OracleLivenessParameters")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<OracleLivenessParameters, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut window_blocks__ = None;
+                let mut min_participation_basis_points__ = None;
+                let mut remove_inactive_validators__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::WindowBlocks => {
+                            if window_blocks__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("windowBlocks"));
+                            }
+                            window_blocks__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::MinParticipationBasisPoints => {
+                            if min_participation_basis_points__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("minParticipationBasisPoints"));
+                            }
+                            min_participation_basis_points__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::RemoveInactiveValidators => {
+                            if remove_inactive_validators__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("removeInactiveValidators"));
+                            }
+                            remove_inactive_validators__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(OracleLivenessParameters {
+                    window_blocks: window_blocks__.unwrap_or_default(),
+                    min_participation_basis_points: min_participation_basis_points__.unwrap_or_default(),
+                    remove_inactive_validators: remove_inactive_validators__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("astria.protocol.genesis.v1.OracleLivenessParameters", FIELDS, GeneratedVisitor)
+    }
+}
@@ -39,3 +39,360 @@ impl ::prost::Name for IdWithCurrencyPair {
         "/astria.protocol.price_feed.v1.IdWithCurrencyPair".into()
     }
 }
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetTwapRequest {
+    /// The currency pair to retrieve the time-weighted average price for, in the
+    /// form `<base>/<quote>`.
+    #[prost(string, tag = "1")]
+    pub currency_pair: ::prost::alloc::string::String,
+}
+impl ::prost::Name for GetTwapRequest {
+    const NAME: &'static str = "GetTwapRequest";
+    const PACKAGE: &'static str = "astria.protocol.price_feed.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "astria.protocol.price_feed.v1.GetTwapRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/astria.protocol.price_feed.v1.GetTwapRequest".into()
+    }
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetTwapResponse {
+    /// The latest accepted price of the currency pair.
+    #[prost(message, optional, tag = "1")]
+    pub price: ::core::option::Option<
+        super::super::super::super::connect::oracle::v2::QuotePrice,
+    >,
+    /// The time-weighted average price as of the latest accepted price.
+    #[prost(string, tag = "2")]
+    pub twap: ::prost::alloc::string::String,
+    /// Whether the latest accepted price is older than `max_age_seconds` at the
+    /// latest block.
+    #[prost(bool, tag = "3")]
+    pub stale: bool,
+    /// The number of decimals of the prices.
+    #[prost(uint64, tag = "4")]
+    pub decimals: u64,
+    /// The parameters bounding price updates of the currency pair.
+    #[prost(uint64, tag = "5")]
+    pub max_age_seconds: u64,
+    #[prost(uint32, tag = "6")]
+    pub max_deviation_basis_points: u32,
+    #[prost(uint64, tag = "7")]
+    pub twap_window_seconds: u64,
+}
+impl ::prost::Name for GetTwapResponse {
+    const NAME: &'static str = "GetTwapResponse";
+    const PACKAGE: &'static str = "astria.protocol.price_feed.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "astria.protocol.price_feed.v1.GetTwapResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/astria.protocol.price_feed.v1.GetTwapResponse".into()
+    }
+}
+/// Generated client implementations.
+#[cfg(feature = "client")]
+pub mod price_feed_service_client {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::wildcard_imports,
+        clippy::let_unit_value,
+    )]
+    use tonic::codegen::*;
+    use tonic::codegen::http::Uri;
+    #[derive(Debug, Clone)]
+    pub struct PriceFeedServiceClient<T> {
+        inner: tonic::client::Grpc<T>,
+    }
+    impl PriceFeedServiceClient<tonic::transport::Channel> {
+        /// Attempt to create a new client by connecting to a given endpoint.
+        pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
+        where
+            D: TryInto<tonic::transport::Endpoint>,
+            D::Error: Into<StdError>,
+        {
+            let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
+            Ok(Self::new(conn))
+        }
+    }
+    impl<T> PriceFeedServiceClient<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::BoxBody>,
+        T::Error: Into<StdError>,
+        T::ResponseBody: Body<Data = Bytes> + std::marker::Send + 'static,
+        <T::ResponseBody as Body>::Error: Into<StdError> + std::marker::Send,
+    {
+        pub fn new(inner: T) -> Self {
+            let inner = tonic::client::Grpc::new(inner);
+            Self { inner }
+        }
+        pub fn with_origin(inner: T, origin: Uri) -> Self {
+            let inner = tonic::client::Grpc::with_origin(inner, origin);
+            Self { inner }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> PriceFeedServiceClient<InterceptedService<T, F>>
+        where
+            F: tonic::service::Interceptor,
+            T::ResponseBody: Default,
+            T: tonic::codegen::Service<
+                http::Request<tonic::body::BoxBody>,
+                Response = http::Response<
+                    <T as tonic::client::GrpcService<tonic::body::BoxBody>>::ResponseBody,
+                >,
+            >,
+            <T as tonic::codegen::Service<
+                http::Request<tonic::body::BoxBody>,
+            >>::Error: Into<StdError> + std::marker::Send + std::marker::Sync,
+        {
+            PriceFeedServiceClient::new(InterceptedService::new(inner, interceptor))
+        }
+        /// Compress requests with the given encoding.
+        ///
+        /// This requires the server to support it otherwise it might respond with an
+        /// error.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.send_compressed(encoding);
+            self
+        }
+        /// Enable decompressing responses.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.accept_compressed(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_decoding_message_size(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_encoding_message_size(limit);
+            self
+        }
+        /// Returns the time-weighted average price of a currency pair alongside its
+        /// latest accepted price.
+        pub async fn get_twap(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetTwapRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetTwapResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/astria.protocol.price_feed.v1.PriceFeedService/GetTwap",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "astria.protocol.price_feed.v1.PriceFeedService",
+                        "GetTwap",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+    }
+}
+/// Generated server implementations.
+#[cfg(feature = "server")]
+pub mod price_feed_service_server {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::wildcard_imports,
+        clippy::let_unit_value,
+    )]
+    use tonic::codegen::*;
+    /// Generated trait containing gRPC methods that should be implemented for use with PriceFeedServiceServer.
+    #[async_trait]
+    pub trait PriceFeedService: std::marker::Send + std::marker::Sync + 'static {
+        /// Returns the time-weighted average price of a currency pair alongside its
+        /// latest accepted price.
+        async fn get_twap(
+            self: std::sync::Arc<Self>,
+            request: tonic::Request<super::GetTwapRequest>,
+        ) -> std::result::Result<tonic::Response<super::GetTwapResponse>, tonic::Status>;
+    }
+    #[derive(Debug)]
+    pub struct PriceFeedServiceServer<T> {
+        inner: Arc<T>,
+        accept_compression_encodings: EnabledCompressionEncodings,
+        send_compression_encodings: EnabledCompressionEncodings,
+        max_decoding_message_size: Option<usize>,
+        max_encoding_message_size: Option<usize>,
+    }
+    impl<T> PriceFeedServiceServer<T> {
+        pub fn new(inner: T) -> Self {
+            Self::from_arc(Arc::new(inner))
+        }
+        pub fn from_arc(inner: Arc<T>) -> Self {
+            Self {
+                inner,
+                accept_compression_encodings: Default::default(),
+                send_compression_encodings: Default::default(),
+                max_decoding_message_size: None,
+                max_encoding_message_size: None,
+            }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> InterceptedService<Self, F>
+        where
+            F: tonic::service::Interceptor,
+        {
+            InterceptedService::new(Self::new(inner), interceptor)
+        }
+        /// Enable decompressing requests with the given encoding.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.accept_compression_encodings.enable(encoding);
+            self
+        }
+        /// Compress responses with the given encoding, if the client supports it.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.send_compression_encodings.enable(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.max_decoding_message_size = Some(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.max_encoding_message_size = Some(limit);
+            self
+        }
+    }
+    impl<T, B> tonic::codegen::Service<http::Request<B>> for PriceFeedServiceServer<T>
+    where
+        T: PriceFeedService,
+        B: Body + std::marker::Send + 'static,
+        B::Error: Into<StdError> + std::marker::Send + 'static,
+    {
+        type Response = http::Response<tonic::body::BoxBody>;
+        type Error = std::convert::Infallible;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+        fn poll_ready(
+            &mut self,
+            _cx: &mut Context<'_>,
+        ) -> Poll<std::result::Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
+            match req.uri().path() {
+                "/astria.protocol.price_feed.v1.PriceFeedService/GetTwap" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetTwapSvc<T: PriceFeedService>(pub Arc<T>);
+                    impl<
+                        T: PriceFeedService,
+                    > tonic::server::UnaryService<super::GetTwapRequest>
+                    for GetTwapSvc<T> {
+                        type Response = super::GetTwapResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetTwapRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as PriceFeedService>::get_twap(inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GetTwapSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                _ => {
+                    Box::pin(async move {
+                        let mut response = http::Response::new(empty_body());
+                        let headers = response.headers_mut();
+                        headers
+                            .insert(
+                                tonic::Status::GRPC_STATUS,
+                                (tonic::Code::Unimplemented as i32).into(),
+                            );
+                        headers
+                            .insert(
+                                http::header::CONTENT_TYPE,
+                                tonic::metadata::GRPC_CONTENT_TYPE,
+                            );
+                        Ok(response)
+                    })
+                }
+            }
+        }
+    }
+    impl<T> Clone for PriceFeedServiceServer<T> {
+        fn clone(&self) -> Self {
+            let inner = self.inner.clone();
+            Self {
+                inner,
+                accept_compression_encodings: self.accept_compression_encodings,
+                send_compression_encodings: self.send_compression_encodings,
+                max_decoding_message_size: self.max_decoding_message_size,
+                max_encoding_message_size: self.max_encoding_message_size,
+            }
+        }
+    }
+    /// Generated gRPC service name
+    pub const SERVICE_NAME: &str = "astria.protocol.price_feed.v1.PriceFeedService";
+    impl<T> tonic::server::NamedService for PriceFeedServiceServer<T> {
+        const NAME: &'static str = SERVICE_NAME;
+    }
+}
@@ -108,6 +108,308 @@ impl<'de> serde::Deserialize<'de> for ExtendedCommitInfoWithCurrencyPairMapping
         deserializer.deserialize_struct("astria.protocol.price_feed.v1.ExtendedCommitInfoWithCurrencyPairMapping", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for GetTwapRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.currency_pair.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("astria.protocol.price_feed.v1.GetTwapRequest", len)?;
+        if !self.currency_pair.is_empty() {
+            struct_ser.serialize_field("currencyPair", &self.currency_pair)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for GetTwapRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "currency_pair",
+            "currencyPair",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            CurrencyPair,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "currencyPair" | "currency_pair" => Ok(GeneratedField::CurrencyPair),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = GetTwapRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct astria.protocol.price_feed.v1.GetTwapRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<GetTwapRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut currency_pair__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::CurrencyPair => {
+                            if currency_pair__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("currencyPair"));
+                            }
+                            currency_pair__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(GetTwapRequest {
+                    currency_pair: currency_pair__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("astria.protocol.price_feed.v1.GetTwapRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for GetTwapResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.price.is_some() {
+            len += 1;
+        }
+        if !self.twap.is_empty() {
+            len += 1;
+        }
+        if self.stale {
+            len += 1;
+        }
+        if self.decimals != 0 {
+            len += 1;
+        }
+        if self.max_age_seconds != 0 {
+            len += 1;
+        }
+        if self.max_deviation_basis_points != 0 {
+            len += 1;
+        }
+        if self.twap_window_seconds != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("astria.protocol.price_feed.v1.GetTwapResponse", len)?;
+        if let Some(v) = self.price.as_ref() {
+            struct_ser.serialize_field("price", v)?;
+        }
+        if !self.twap.is_empty() {
+            struct_ser.serialize_field("twap", &self.twap)?;
+        }
+        if self.stale {
+            struct_ser.serialize_field("stale", &self.stale)?;
+        }
+        if self.decimals != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("decimals", ToString::to_string(&self.decimals).as_str())?;
+        }
+        if self.max_age_seconds != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("maxAgeSeconds", ToString::to_string(&self.max_age_seconds).as_str())?;
+        }
+        if self.max_deviation_basis_points != 0 {
+            struct_ser.serialize_field("maxDeviationBasisPoints", &self.max_deviation_basis_points)?;
+        }
+        if self.twap_window_seconds != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("twapWindowSeconds", ToString::to_string(&self.twap_window_seconds).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for GetTwapResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "price",
+            "twap",
+            "stale",
+            "decimals",
+            "max_age_seconds",
+            "maxAgeSeconds",
+            "max_deviation_basis_points",
+            "maxDeviationBasisPoints",
+            "twap_window_seconds",
+            "twapWindowSeconds",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Price,
+            Twap,
+            Stale,
+            Decimals,
+            MaxAgeSeconds,
+            MaxDeviationBasisPoints,
+            TwapWindowSeconds,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "price" => Ok(GeneratedField::Price),
+                            "twap" => Ok(GeneratedField::Twap),
+                            "stale" => Ok(GeneratedField::Stale),
+                            "decimals" => Ok(GeneratedField::Decimals),
+                            "maxAgeSeconds" | "max_age_seconds" => Ok(GeneratedField::MaxAgeSeconds),
+                            "maxDeviationBasisPoints" | "max_deviation_basis_points" => Ok(GeneratedField::MaxDeviationBasisPoints),
+                            "twapWindowSeconds" | "twap_window_seconds" => Ok(GeneratedField::TwapWindowSeconds),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = GetTwapResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct astria.protocol.price_feed.v1.GetTwapResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<GetTwapResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut price__ = None;
+                let mut twap__ = None;
+                let mut stale__ = None;
+                let mut decimals__ = None;
+                let mut max_age_seconds__ = None;
+                let mut max_deviation_basis_points__ = None;
+                let mut twap_window_seconds__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Price => {
+                            if price__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("price"));
+                            }
+                            price__ = map_.next_value()?;
+                        }
+                        GeneratedField::Twap => {
+                            if twap__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("twap"));
+                            }
+                            twap__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Stale => {
+                            if stale__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("stale"));
+                            }
+                            stale__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Decimals => {
+                            if decimals__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("decimals"));
+                            }
+                            decimals__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::MaxAgeSeconds => {
+                            if max_age_seconds__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("maxAgeSeconds"));
+                            }
+                            max_age_seconds__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::MaxDeviationBasisPoints => {
+                            if max_deviation_basis_points__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("maxDeviationBasisPoints"));
+                            }
+                            max_deviation_basis_points__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::TwapWindowSeconds => {
+                            if twap_window_seconds__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("twapWindowSeconds"));
+                            }
+                            twap_window_seconds__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(GetTwapResponse {
+                    price: price__,
+                    twap: twap__.unwrap_or_default(),
+                    stale: stale__.unwrap_or_default(),
+                    decimals: decimals__.unwrap_or_default(),
+                    max_age_seconds: max_age_seconds__.unwrap_or_default(),
+                    max_deviation_basis_points: max_deviation_basis_points__.unwrap_or_default(),
+                    twap_window_seconds: twap_window_seconds__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("astria.protocol.price_feed.v1.GetTwapResponse", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for IdWithCurrencyPair {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
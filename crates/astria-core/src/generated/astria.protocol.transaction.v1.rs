@@ -3,7 +3,7 @@
 pub struct Action {
     #[prost(
         oneof = "action::Value",
-        tags = "1, 2, 11, 12, 13, 14, 15, 21, 22, 50, 51, 52, 53, 55, 56, 57, 71, 72"
+        tags = "1, 2, 3, 4, 5, 6, 11, 12, 13, 14, 15, 21, 22, 50, 51, 52, 53, 55, 56, 57, 58, 71, 72, 81, 82"
     )]
     pub value: ::core::option::Option<action::Value>,
 }
@@ -16,6 +16,14 @@ pub mod action {
         Transfer(super::Transfer),
         #[prost(message, tag = "2")]
         RollupDataSubmission(super::RollupDataSubmission),
+        #[prost(message, tag = "3")]
+        RegisterMultisig(super::RegisterMultisig),
+        #[prost(message, tag = "4")]
+        TimelockedTransfer(super::TimelockedTransfer),
+        #[prost(message, tag = "5")]
+        ClaimTimelockedTransfer(super::ClaimTimelockedTransfer),
+        #[prost(message, tag = "6")]
+        CancelTimelockedTransfer(super::CancelTimelockedTransfer),
         /// Bridge actions are defined on 11-20
         #[prost(message, tag = "11")]
         InitBridgeAccount(super::InitBridgeAccount),
@@ -47,11 +55,18 @@ pub mod action {
         IbcSudoChange(super::IbcSudoChange),
         #[prost(message, tag = "57")]
         RecoverIbcClient(super::RecoverIbcClient),
+        #[prost(message, tag = "58")]
+        FeeDistributionChange(super::FeeDistributionChange),
         /// Price feed actions are defined on 71-80
         #[prost(message, tag = "71")]
         CurrencyPairsChange(super::CurrencyPairsChange),
         #[prost(message, tag = "72")]
         MarketsChange(super::MarketsChange),
+        /// Governance actions are defined on 81-90
+        #[prost(message, tag = "81")]
+        SubmitProposal(::prost::alloc::boxed::Box<super::SubmitProposal>),
+        #[prost(message, tag = "82")]
+        VoteOnProposal(super::VoteOnProposal),
     }
 }
 impl ::prost::Name for Action {
@@ -497,7 +512,7 @@ pub struct FeeChange {
     /// the new fee components values
     #[prost(
         oneof = "fee_change::FeeComponents",
-        tags = "1, 2, 3, 4, 5, 7, 6, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18"
+        tags = "1, 2, 3, 4, 5, 7, 6, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25"
     )]
     pub fee_components: ::core::option::Option<fee_change::FeeComponents>,
 }
@@ -546,6 +561,28 @@ pub mod fee_change {
         ),
         #[prost(message, tag = "18")]
         MarketsChange(super::super::super::fees::v1::MarketsChangeFeeComponents),
+        #[prost(message, tag = "19")]
+        RegisterMultisig(super::super::super::fees::v1::RegisterMultisigFeeComponents),
+        #[prost(message, tag = "20")]
+        TimelockedTransfer(
+            super::super::super::fees::v1::TimelockedTransferFeeComponents,
+        ),
+        #[prost(message, tag = "21")]
+        ClaimTimelockedTransfer(
+            super::super::super::fees::v1::ClaimTimelockedTransferFeeComponents,
+        ),
+        #[prost(message, tag = "22")]
+        CancelTimelockedTransfer(
+            super::super::super::fees::v1::CancelTimelockedTransferFeeComponents,
+        ),
+        #[prost(message, tag = "23")]
+        SubmitProposal(super::super::super::fees::v1::SubmitProposalFeeComponents),
+        #[prost(message, tag = "24")]
+        VoteOnProposal(super::super::super::fees::v1::VoteOnProposalFeeComponents),
+        #[prost(message, tag = "25")]
+        FeeDistributionChange(
+            super::super::super::fees::v1::FeeDistributionChangeFeeComponents,
+        ),
     }
 }
 impl ::prost::Name for FeeChange {
@@ -603,10 +640,11 @@ impl ::prost::Name for RecoverIbcClient {
         "/astria.protocol.transaction.v1.RecoverIbcClient".into()
     }
 }
-/// Add or remove currency pairs to/from the price feed oracle.
+/// Add or remove currency pairs to/from the price feed oracle, or update the
+/// parameters bounding price updates of existing currency pairs.
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CurrencyPairsChange {
-    #[prost(oneof = "currency_pairs_change::Value", tags = "1, 2")]
+    #[prost(oneof = "currency_pairs_change::Value", tags = "1, 2, 3")]
     pub value: ::core::option::Option<currency_pairs_change::Value>,
 }
 /// Nested message and enum types in `CurrencyPairsChange`.
@@ -617,6 +655,8 @@ pub mod currency_pairs_change {
         Addition(super::CurrencyPairs),
         #[prost(message, tag = "2")]
         Removal(super::CurrencyPairs),
+        #[prost(message, tag = "3")]
+        ParametersUpdate(super::CurrencyPairsParameters),
     }
 }
 impl ::prost::Name for CurrencyPairsChange {
@@ -646,6 +686,54 @@ impl ::prost::Name for CurrencyPairs {
         "/astria.protocol.transaction.v1.CurrencyPairs".into()
     }
 }
+/// A list of currency pair parameters to set.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CurrencyPairsParameters {
+    #[prost(message, repeated, tag = "1")]
+    pub parameters: ::prost::alloc::vec::Vec<CurrencyPairParameters>,
+}
+impl ::prost::Name for CurrencyPairsParameters {
+    const NAME: &'static str = "CurrencyPairsParameters";
+    const PACKAGE: &'static str = "astria.protocol.transaction.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "astria.protocol.transaction.v1.CurrencyPairsParameters".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/astria.protocol.transaction.v1.CurrencyPairsParameters".into()
+    }
+}
+/// The parameters bounding price updates of a single currency pair.
+///
+/// Setting a field to zero disables the corresponding check.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CurrencyPairParameters {
+    /// the currency pair these parameters apply to
+    #[prost(message, optional, tag = "1")]
+    pub currency_pair: ::core::option::Option<
+        super::super::super::super::connect::types::v2::CurrencyPair,
+    >,
+    /// the age in seconds after which the latest price of the pair is considered stale
+    #[prost(uint64, tag = "2")]
+    pub max_age_seconds: u64,
+    /// the maximum change in basis points between the latest non-stale price and a
+    /// new price; larger changes are rejected as outliers. Requires `max_age_seconds`
+    /// to be set so that a pair cannot be locked at a price indefinitely.
+    #[prost(uint32, tag = "3")]
+    pub max_deviation_basis_points: u32,
+    /// the window in seconds over which the time-weighted average price is computed
+    #[prost(uint64, tag = "4")]
+    pub twap_window_seconds: u64,
+}
+impl ::prost::Name for CurrencyPairParameters {
+    const NAME: &'static str = "CurrencyPairParameters";
+    const PACKAGE: &'static str = "astria.protocol.transaction.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "astria.protocol.transaction.v1.CurrencyPairParameters".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/astria.protocol.transaction.v1.CurrencyPairParameters".into()
+    }
+}
 /// Either creates, removes or updates existing price feed markets.
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct MarketsChange {
@@ -692,8 +780,200 @@ impl ::prost::Name for Markets {
         "/astria.protocol.transaction.v1.Markets".into()
     }
 }
+/// `RegisterMultisig` turns the account of the transaction signer into a
+/// multisig account, or replaces the policy of an existing multisig account.
+///
+/// Once registered, transactions on behalf of the account must be authorized by
+/// at least `threshold` signatures of the account's members instead of the
+/// signature of a single key.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RegisterMultisig {
+    /// the ed25519 verification keys of the members of the multisig account
+    #[prost(bytes = "bytes", repeated, tag = "1")]
+    pub members: ::prost::alloc::vec::Vec<::prost::bytes::Bytes>,
+    /// the number of member signatures required to authorize a transaction
+    #[prost(uint32, tag = "2")]
+    pub threshold: u32,
+    /// the asset used to pay the transaction fee
+    #[prost(string, tag = "3")]
+    pub fee_asset: ::prost::alloc::string::String,
+}
+impl ::prost::Name for RegisterMultisig {
+    const NAME: &'static str = "RegisterMultisig";
+    const PACKAGE: &'static str = "astria.protocol.transaction.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "astria.protocol.transaction.v1.RegisterMultisig".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/astria.protocol.transaction.v1.RegisterMultisig".into()
+    }
+}
+/// `TimelockedTransfer` moves funds from the signer's account into an escrow
+/// held by the sequencer until the unlock point is reached.
+///
+/// Once the unlock point is reached, the recipient can release the funds to
+/// themselves with a `ClaimTimelockedTransfer` action. Before that, the signer
+/// can return the funds to their own account with a `CancelTimelockedTransfer`
+/// action.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TimelockedTransfer {
+    #[prost(message, optional, tag = "1")]
+    pub to: ::core::option::Option<super::super::super::primitive::v1::Address>,
+    #[prost(message, optional, tag = "2")]
+    pub amount: ::core::option::Option<super::super::super::primitive::v1::Uint128>,
+    /// the asset to be transferred
+    #[prost(string, tag = "3")]
+    pub asset: ::prost::alloc::string::String,
+    /// the asset used to pay the transaction fee
+    #[prost(string, tag = "4")]
+    pub fee_asset: ::prost::alloc::string::String,
+    /// the point at which the funds can be claimed by the recipient
+    #[prost(oneof = "timelocked_transfer::Unlock", tags = "5, 6")]
+    pub unlock: ::core::option::Option<timelocked_transfer::Unlock>,
+}
+/// Nested message and enum types in `TimelockedTransfer`.
+pub mod timelocked_transfer {
+    /// the point at which the funds can be claimed by the recipient
+    #[derive(Clone, Copy, PartialEq, ::prost::Oneof)]
+    pub enum Unlock {
+        /// the sequencer block height at or after which the funds unlock
+        #[prost(uint64, tag = "5")]
+        Height(u64),
+        /// the sequencer block timestamp at or after which the funds unlock
+        #[prost(message, tag = "6")]
+        Time(::pbjson_types::Timestamp),
+    }
+}
+impl ::prost::Name for TimelockedTransfer {
+    const NAME: &'static str = "TimelockedTransfer";
+    const PACKAGE: &'static str = "astria.protocol.transaction.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "astria.protocol.transaction.v1.TimelockedTransfer".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/astria.protocol.transaction.v1.TimelockedTransfer".into()
+    }
+}
+/// `ClaimTimelockedTransfer` releases the funds of an unlocked timelocked
+/// transfer to its recipient. Must be signed by the recipient.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ClaimTimelockedTransfer {
+    /// the ID of the timelocked transfer, as reported by the event emitted when
+    /// it was created
+    #[prost(uint64, tag = "1")]
+    pub id: u64,
+    /// the asset used to pay the transaction fee
+    #[prost(string, tag = "2")]
+    pub fee_asset: ::prost::alloc::string::String,
+}
+impl ::prost::Name for ClaimTimelockedTransfer {
+    const NAME: &'static str = "ClaimTimelockedTransfer";
+    const PACKAGE: &'static str = "astria.protocol.transaction.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "astria.protocol.transaction.v1.ClaimTimelockedTransfer".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/astria.protocol.transaction.v1.ClaimTimelockedTransfer".into()
+    }
+}
+/// `CancelTimelockedTransfer` returns the funds of a still locked timelocked
+/// transfer to its sender. Must be signed by the sender.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CancelTimelockedTransfer {
+    /// the ID of the timelocked transfer, as reported by the event emitted when
+    /// it was created
+    #[prost(uint64, tag = "1")]
+    pub id: u64,
+    /// the asset used to pay the transaction fee
+    #[prost(string, tag = "2")]
+    pub fee_asset: ::prost::alloc::string::String,
+}
+impl ::prost::Name for CancelTimelockedTransfer {
+    const NAME: &'static str = "CancelTimelockedTransfer";
+    const PACKAGE: &'static str = "astria.protocol.transaction.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "astria.protocol.transaction.v1.CancelTimelockedTransfer".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/astria.protocol.transaction.v1.CancelTimelockedTransfer".into()
+    }
+}
+/// `SubmitProposal` opens a governance proposal to execute a sudo action.
+///
+/// Must be signed by one of the proposers configured at genesis. The wrapped
+/// action is executed at the end of the block in which the approving votes
+/// exceed the configured threshold, as long as this happens before the voting
+/// period of the proposal ends.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SubmitProposal {
+    /// the sudo action to execute once the proposal passes
+    #[prost(message, optional, boxed, tag = "1")]
+    pub action: ::core::option::Option<::prost::alloc::boxed::Box<Action>>,
+    /// the asset used to pay the transaction fee
+    #[prost(string, tag = "2")]
+    pub fee_asset: ::prost::alloc::string::String,
+}
+impl ::prost::Name for SubmitProposal {
+    const NAME: &'static str = "SubmitProposal";
+    const PACKAGE: &'static str = "astria.protocol.transaction.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "astria.protocol.transaction.v1.SubmitProposal".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/astria.protocol.transaction.v1.SubmitProposal".into()
+    }
+}
+/// `VoteOnProposal` casts or replaces the signer's vote on an open governance
+/// proposal. Must be signed by a member of the governance council or, if no
+/// council is configured, by a validator.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct VoteOnProposal {
+    /// the ID of the proposal, as reported by the event emitted when it was
+    /// submitted
+    #[prost(uint64, tag = "1")]
+    pub proposal_id: u64,
+    /// whether the signer approves of the proposal
+    #[prost(bool, tag = "2")]
+    pub approve: bool,
+    /// the asset used to pay the transaction fee
+    #[prost(string, tag = "3")]
+    pub fee_asset: ::prost::alloc::string::String,
+}
+impl ::prost::Name for VoteOnProposal {
+    const NAME: &'static str = "VoteOnProposal";
+    const PACKAGE: &'static str = "astria.protocol.transaction.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "astria.protocol.transaction.v1.VoteOnProposal".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/astria.protocol.transaction.v1.VoteOnProposal".into()
+    }
+}
+/// `FeeDistributionChange` replaces how the fees collected in a block are split
+/// between the block proposer, the validators, the community pool and burning.
+/// Must be signed by the sudo address.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FeeDistributionChange {
+    #[prost(message, optional, tag = "1")]
+    pub new_distribution: ::core::option::Option<
+        super::super::fees::v1::FeeDistribution,
+    >,
+}
+impl ::prost::Name for FeeDistributionChange {
+    const NAME: &'static str = "FeeDistributionChange";
+    const PACKAGE: &'static str = "astria.protocol.transaction.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "astria.protocol.transaction.v1.FeeDistributionChange".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/astria.protocol.transaction.v1.FeeDistributionChange".into()
+    }
+}
 /// `Transaction` is a transaction `TransactionBody` together with a public
 /// ket and a signature.
+///
+/// Transactions on behalf of a multisig account leave `signature` and
+/// `public_key` empty and are authorized by `multisig` instead.
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Transaction {
     #[prost(bytes = "bytes", tag = "1")]
@@ -702,6 +982,8 @@ pub struct Transaction {
     pub public_key: ::prost::bytes::Bytes,
     #[prost(message, optional, tag = "3")]
     pub body: ::core::option::Option<::pbjson_types::Any>,
+    #[prost(message, optional, tag = "4")]
+    pub multisig: ::core::option::Option<MultisigSignatures>,
 }
 impl ::prost::Name for Transaction {
     const NAME: &'static str = "Transaction";
@@ -713,6 +995,43 @@ impl ::prost::Name for Transaction {
         "/astria.protocol.transaction.v1.Transaction".into()
     }
 }
+/// The signatures of the members of a multisig account over a `TransactionBody`.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MultisigSignatures {
+    /// the address bytes of the multisig account
+    #[prost(bytes = "bytes", tag = "1")]
+    pub address: ::prost::bytes::Bytes,
+    #[prost(message, repeated, tag = "2")]
+    pub signatures: ::prost::alloc::vec::Vec<MemberSignature>,
+}
+impl ::prost::Name for MultisigSignatures {
+    const NAME: &'static str = "MultisigSignatures";
+    const PACKAGE: &'static str = "astria.protocol.transaction.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "astria.protocol.transaction.v1.MultisigSignatures".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/astria.protocol.transaction.v1.MultisigSignatures".into()
+    }
+}
+/// The signature of a single member of a multisig account.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MemberSignature {
+    #[prost(bytes = "bytes", tag = "1")]
+    pub public_key: ::prost::bytes::Bytes,
+    #[prost(bytes = "bytes", tag = "2")]
+    pub signature: ::prost::bytes::Bytes,
+}
+impl ::prost::Name for MemberSignature {
+    const NAME: &'static str = "MemberSignature";
+    const PACKAGE: &'static str = "astria.protocol.transaction.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "astria.protocol.transaction.v1.MemberSignature".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/astria.protocol.transaction.v1.MemberSignature".into()
+    }
+}
 /// The `TransactionBody` of the `Transaction` that is being signed over.
 /// It contains transaction `TransactionParams` and `Actions`.
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -18,6 +18,18 @@ impl serde::Serialize for Action {
                 action::Value::RollupDataSubmission(v) => {
                     struct_ser.serialize_field("rollupDataSubmission", v)?;
                 }
+                action::Value::RegisterMultisig(v) => {
+                    struct_ser.serialize_field("registerMultisig", v)?;
+                }
+                action::Value::TimelockedTransfer(v) => {
+                    struct_ser.serialize_field("timelockedTransfer", v)?;
+                }
+                action::Value::ClaimTimelockedTransfer(v) => {
+                    struct_ser.serialize_field("claimTimelockedTransfer", v)?;
+                }
+                action::Value::CancelTimelockedTransfer(v) => {
+                    struct_ser.serialize_field("cancelTimelockedTransfer", v)?;
+                }
                 action::Value::InitBridgeAccount(v) => {
                     struct_ser.serialize_field("initBridgeAccount", v)?;
                 }
@@ -60,12 +72,21 @@ impl serde::Serialize for Action {
                 action::Value::RecoverIbcClient(v) => {
                     struct_ser.serialize_field("recoverIbcClient", v)?;
                 }
+                action::Value::FeeDistributionChange(v) => {
+                    struct_ser.serialize_field("feeDistributionChange", v)?;
+                }
                 action::Value::CurrencyPairsChange(v) => {
                     struct_ser.serialize_field("currencyPairsChange", v)?;
                 }
                 action::Value::MarketsChange(v) => {
                     struct_ser.serialize_field("marketsChange", v)?;
                 }
+                action::Value::SubmitProposal(v) => {
+                    struct_ser.serialize_field("submitProposal", v)?;
+                }
+                action::Value::VoteOnProposal(v) => {
+                    struct_ser.serialize_field("voteOnProposal", v)?;
+                }
             }
         }
         struct_ser.end()
@@ -81,6 +102,14 @@ impl<'de> serde::Deserialize<'de> for Action {
             "transfer",
             "rollup_data_submission",
             "rollupDataSubmission",
+            "register_multisig",
+            "registerMultisig",
+            "timelocked_transfer",
+            "timelockedTransfer",
+            "claim_timelocked_transfer",
+            "claimTimelockedTransfer",
+            "cancel_timelocked_transfer",
+            "cancelTimelockedTransfer",
             "init_bridge_account",
             "initBridgeAccount",
             "bridge_lock",
@@ -108,16 +137,26 @@ impl<'de> serde::Deserialize<'de> for Action {
             "ibcSudoChange",
             "recover_ibc_client",
             "recoverIbcClient",
+            "fee_distribution_change",
+            "feeDistributionChange",
             "currency_pairs_change",
             "currencyPairsChange",
             "markets_change",
             "marketsChange",
+            "submit_proposal",
+            "submitProposal",
+            "vote_on_proposal",
+            "voteOnProposal",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             Transfer,
             RollupDataSubmission,
+            RegisterMultisig,
+            TimelockedTransfer,
+            ClaimTimelockedTransfer,
+            CancelTimelockedTransfer,
             InitBridgeAccount,
             BridgeLock,
             BridgeUnlock,
@@ -132,8 +171,11 @@ impl<'de> serde::Deserialize<'de> for Action {
             FeeChange,
             IbcSudoChange,
             RecoverIbcClient,
+            FeeDistributionChange,
             CurrencyPairsChange,
             MarketsChange,
+            SubmitProposal,
+            VoteOnProposal,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -157,6 +199,10 @@ impl<'de> serde::Deserialize<'de> for Action {
                         match value {
                             "transfer" => Ok(GeneratedField::Transfer),
                             "rollupDataSubmission" | "rollup_data_submission" => Ok(GeneratedField::RollupDataSubmission),
+                            "registerMultisig" | "register_multisig" => Ok(GeneratedField::RegisterMultisig),
+                            "timelockedTransfer" | "timelocked_transfer" => Ok(GeneratedField::TimelockedTransfer),
+                            "claimTimelockedTransfer" | "claim_timelocked_transfer" => Ok(GeneratedField::ClaimTimelockedTransfer),
+                            "cancelTimelockedTransfer" | "cancel_timelocked_transfer" => Ok(GeneratedField::CancelTimelockedTransfer),
                             "initBridgeAccount" | "init_bridge_account" => Ok(GeneratedField::InitBridgeAccount),
                             "bridgeLock" | "bridge_lock" => Ok(GeneratedField::BridgeLock),
                             "bridgeUnlock" | "bridge_unlock" => Ok(GeneratedField::BridgeUnlock),
@@ -171,8 +217,11 @@ impl<'de> serde::Deserialize<'de> for Action {
                             "feeChange" | "fee_change" => Ok(GeneratedField::FeeChange),
                             "ibcSudoChange" | "ibc_sudo_change" => Ok(GeneratedField::IbcSudoChange),
                             "recoverIbcClient" | "recover_ibc_client" => Ok(GeneratedField::RecoverIbcClient),
+                            "feeDistributionChange" | "fee_distribution_change" => Ok(GeneratedField::FeeDistributionChange),
                             "currencyPairsChange" | "currency_pairs_change" => Ok(GeneratedField::CurrencyPairsChange),
                             "marketsChange" | "markets_change" => Ok(GeneratedField::MarketsChange),
+                            "submitProposal" | "submit_proposal" => Ok(GeneratedField::SubmitProposal),
+                            "voteOnProposal" | "vote_on_proposal" => Ok(GeneratedField::VoteOnProposal),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -207,6 +256,34 @@ impl<'de> serde::Deserialize<'de> for Action {
                                 return Err(serde::de::Error::duplicate_field("rollupDataSubmission"));
                             }
                             value__ = map_.next_value::<::std::option::Option<_>>()?.map(action::Value::RollupDataSubmission)
+;
+                        }
+                        GeneratedField::RegisterMultisig => {
+                            if value__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("registerMultisig"));
+                            }
+                            value__ = map_.next_value::<::std::option::Option<_>>()?.map(action::Value::RegisterMultisig)
+;
+                        }
+                        GeneratedField::TimelockedTransfer => {
+                            if value__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("timelockedTransfer"));
+                            }
+                            value__ = map_.next_value::<::std::option::Option<_>>()?.map(action::Value::TimelockedTransfer)
+;
+                        }
+                        GeneratedField::ClaimTimelockedTransfer => {
+                            if value__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("claimTimelockedTransfer"));
+                            }
+                            value__ = map_.next_value::<::std::option::Option<_>>()?.map(action::Value::ClaimTimelockedTransfer)
+;
+                        }
+                        GeneratedField::CancelTimelockedTransfer => {
+                            if value__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("cancelTimelockedTransfer"));
+                            }
+                            value__ = map_.next_value::<::std::option::Option<_>>()?.map(action::Value::CancelTimelockedTransfer)
 ;
                         }
                         GeneratedField::InitBridgeAccount => {
@@ -305,6 +382,13 @@ impl<'de> serde::Deserialize<'de> for Action {
                                 return Err(serde::de::Error::duplicate_field("recoverIbcClient"));
                             }
                             value__ = map_.next_value::<::std::option::Option<_>>()?.map(action::Value::RecoverIbcClient)
+;
+                        }
+                        GeneratedField::FeeDistributionChange => {
+                            if value__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("feeDistributionChange"));
+                            }
+                            value__ = map_.next_value::<::std::option::Option<_>>()?.map(action::Value::FeeDistributionChange)
 ;
                         }
                         GeneratedField::CurrencyPairsChange => {
@@ -319,6 +403,20 @@ impl<'de> serde::Deserialize<'de> for Action {
                                 return Err(serde::de::Error::duplicate_field("marketsChange"));
                             }
                             value__ = map_.next_value::<::std::option::Option<_>>()?.map(action::Value::MarketsChange)
+;
+                        }
+                        GeneratedField::SubmitProposal => {
+                            if value__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("submitProposal"));
+                            }
+                            value__ = map_.next_value::<::std::option::Option<_>>()?.map(action::Value::SubmitProposal)
+;
+                        }
+                        GeneratedField::VoteOnProposal => {
+                            if value__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("voteOnProposal"));
+                            }
+                            value__ = map_.next_value::<::std::option::Option<_>>()?.map(action::Value::VoteOnProposal)
 ;
                         }
                     }
@@ -1041,7 +1139,7 @@ impl<'de> serde::Deserialize<'de> for BridgeUnlock {
         deserializer.deserialize_struct("astria.protocol.transaction.v1.BridgeUnlock", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for CurrencyPairs {
+impl serde::Serialize for CancelTimelockedTransfer {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -1049,29 +1147,40 @@ impl serde::Serialize for CurrencyPairs {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if !self.pairs.is_empty() {
+        if self.id != 0 {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("astria.protocol.transaction.v1.CurrencyPairs", len)?;
-        if !self.pairs.is_empty() {
-            struct_ser.serialize_field("pairs", &self.pairs)?;
+        if !self.fee_asset.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("astria.protocol.transaction.v1.CancelTimelockedTransfer", len)?;
+        if self.id != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("id", ToString::to_string(&self.id).as_str())?;
+        }
+        if !self.fee_asset.is_empty() {
+            struct_ser.serialize_field("feeAsset", &self.fee_asset)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for CurrencyPairs {
+impl<'de> serde::Deserialize<'de> for CancelTimelockedTransfer {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "pairs",
+            "id",
+            "fee_asset",
+            "feeAsset",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Pairs,
+            Id,
+            FeeAsset,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -1093,7 +1202,8 @@ impl<'de> serde::Deserialize<'de> for CurrencyPairs {
                         E: serde::de::Error,
                     {
                         match value {
-                            "pairs" => Ok(GeneratedField::Pairs),
+                            "id" => Ok(GeneratedField::Id),
+                            "feeAsset" | "fee_asset" => Ok(GeneratedField::FeeAsset),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -1103,36 +1213,46 @@ impl<'de> serde::Deserialize<'de> for CurrencyPairs {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = CurrencyPairs;
+            type Value = CancelTimelockedTransfer;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct astria.protocol.transaction.v1.CurrencyPairs")
+                formatter.write_str("struct astria.protocol.transaction.v1.CancelTimelockedTransfer")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<CurrencyPairs, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<CancelTimelockedTransfer, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut pairs__ = None;
+                let mut id__ = None;
+                let mut fee_asset__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Pairs => {
-                            if pairs__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("pairs"));
+                        GeneratedField::Id => {
+                            if id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("id"));
                             }
-                            pairs__ = Some(map_.next_value()?);
+                            id__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::FeeAsset => {
+                            if fee_asset__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("feeAsset"));
+                            }
+                            fee_asset__ = Some(map_.next_value()?);
                         }
                     }
                 }
-                Ok(CurrencyPairs {
-                    pairs: pairs__.unwrap_or_default(),
+                Ok(CancelTimelockedTransfer {
+                    id: id__.unwrap_or_default(),
+                    fee_asset: fee_asset__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("astria.protocol.transaction.v1.CurrencyPairs", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("astria.protocol.transaction.v1.CancelTimelockedTransfer", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for CurrencyPairsChange {
+impl serde::Serialize for ClaimTimelockedTransfer {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -1140,38 +1260,40 @@ impl serde::Serialize for CurrencyPairsChange {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.value.is_some() {
+        if self.id != 0 {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("astria.protocol.transaction.v1.CurrencyPairsChange", len)?;
-        if let Some(v) = self.value.as_ref() {
-            match v {
-                currency_pairs_change::Value::Addition(v) => {
-                    struct_ser.serialize_field("addition", v)?;
-                }
-                currency_pairs_change::Value::Removal(v) => {
-                    struct_ser.serialize_field("removal", v)?;
-                }
-            }
+        if !self.fee_asset.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("astria.protocol.transaction.v1.ClaimTimelockedTransfer", len)?;
+        if self.id != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("id", ToString::to_string(&self.id).as_str())?;
+        }
+        if !self.fee_asset.is_empty() {
+            struct_ser.serialize_field("feeAsset", &self.fee_asset)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for CurrencyPairsChange {
+impl<'de> serde::Deserialize<'de> for ClaimTimelockedTransfer {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "addition",
-            "removal",
+            "id",
+            "fee_asset",
+            "feeAsset",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Addition,
-            Removal,
+            Id,
+            FeeAsset,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -1193,8 +1315,8 @@ impl<'de> serde::Deserialize<'de> for CurrencyPairsChange {
                         E: serde::de::Error,
                     {
                         match value {
-                            "addition" => Ok(GeneratedField::Addition),
-                            "removal" => Ok(GeneratedField::Removal),
+                            "id" => Ok(GeneratedField::Id),
+                            "feeAsset" | "fee_asset" => Ok(GeneratedField::FeeAsset),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -1204,44 +1326,46 @@ impl<'de> serde::Deserialize<'de> for CurrencyPairsChange {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = CurrencyPairsChange;
+            type Value = ClaimTimelockedTransfer;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct astria.protocol.transaction.v1.CurrencyPairsChange")
+                formatter.write_str("struct astria.protocol.transaction.v1.ClaimTimelockedTransfer")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<CurrencyPairsChange, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ClaimTimelockedTransfer, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut value__ = None;
+                let mut id__ = None;
+                let mut fee_asset__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Addition => {
-                            if value__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("addition"));
+                        GeneratedField::Id => {
+                            if id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("id"));
                             }
-                            value__ = map_.next_value::<::std::option::Option<_>>()?.map(currency_pairs_change::Value::Addition)
-;
+                            id__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
                         }
-                        GeneratedField::Removal => {
-                            if value__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("removal"));
+                        GeneratedField::FeeAsset => {
+                            if fee_asset__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("feeAsset"));
                             }
-                            value__ = map_.next_value::<::std::option::Option<_>>()?.map(currency_pairs_change::Value::Removal)
-;
+                            fee_asset__ = Some(map_.next_value()?);
                         }
                     }
                 }
-                Ok(CurrencyPairsChange {
-                    value: value__,
+                Ok(ClaimTimelockedTransfer {
+                    id: id__.unwrap_or_default(),
+                    fee_asset: fee_asset__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("astria.protocol.transaction.v1.CurrencyPairsChange", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("astria.protocol.transaction.v1.ClaimTimelockedTransfer", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for FeeAssetChange {
+impl serde::Serialize for CurrencyPairParameters {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -1249,38 +1373,61 @@ impl serde::Serialize for FeeAssetChange {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.value.is_some() {
+        if self.currency_pair.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("astria.protocol.transaction.v1.FeeAssetChange", len)?;
-        if let Some(v) = self.value.as_ref() {
-            match v {
-                fee_asset_change::Value::Addition(v) => {
-                    struct_ser.serialize_field("addition", v)?;
-                }
-                fee_asset_change::Value::Removal(v) => {
-                    struct_ser.serialize_field("removal", v)?;
-                }
-            }
+        if self.max_age_seconds != 0 {
+            len += 1;
+        }
+        if self.max_deviation_basis_points != 0 {
+            len += 1;
+        }
+        if self.twap_window_seconds != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("astria.protocol.transaction.v1.CurrencyPairParameters", len)?;
+        if let Some(v) = self.currency_pair.as_ref() {
+            struct_ser.serialize_field("currencyPair", v)?;
+        }
+        if self.max_age_seconds != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("maxAgeSeconds", ToString::to_string(&self.max_age_seconds).as_str())?;
+        }
+        if self.max_deviation_basis_points != 0 {
+            struct_ser.serialize_field("maxDeviationBasisPoints", &self.max_deviation_basis_points)?;
+        }
+        if self.twap_window_seconds != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("twapWindowSeconds", ToString::to_string(&self.twap_window_seconds).as_str())?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for FeeAssetChange {
+impl<'de> serde::Deserialize<'de> for CurrencyPairParameters {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "addition",
-            "removal",
+            "currency_pair",
+            "currencyPair",
+            "max_age_seconds",
+            "maxAgeSeconds",
+            "max_deviation_basis_points",
+            "maxDeviationBasisPoints",
+            "twap_window_seconds",
+            "twapWindowSeconds",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Addition,
-            Removal,
+            CurrencyPair,
+            MaxAgeSeconds,
+            MaxDeviationBasisPoints,
+            TwapWindowSeconds,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -1302,8 +1449,10 @@ impl<'de> serde::Deserialize<'de> for FeeAssetChange {
                         E: serde::de::Error,
                     {
                         match value {
-                            "addition" => Ok(GeneratedField::Addition),
-                            "removal" => Ok(GeneratedField::Removal),
+                            "currencyPair" | "currency_pair" => Ok(GeneratedField::CurrencyPair),
+                            "maxAgeSeconds" | "max_age_seconds" => Ok(GeneratedField::MaxAgeSeconds),
+                            "maxDeviationBasisPoints" | "max_deviation_basis_points" => Ok(GeneratedField::MaxDeviationBasisPoints),
+                            "twapWindowSeconds" | "twap_window_seconds" => Ok(GeneratedField::TwapWindowSeconds),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -1313,42 +1462,66 @@ impl<'de> serde::Deserialize<'de> for FeeAssetChange {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = FeeAssetChange;
+            type Value = CurrencyPairParameters;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct astria.protocol.transaction.v1.FeeAssetChange")
+                formatter.write_str("struct astria.protocol.transaction.v1.CurrencyPairParameters")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<FeeAssetChange, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<CurrencyPairParameters, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut value__ = None;
+                let mut currency_pair__ = None;
+                let mut max_age_seconds__ = None;
+                let mut max_deviation_basis_points__ = None;
+                let mut twap_window_seconds__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Addition => {
-                            if value__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("addition"));
+                        GeneratedField::CurrencyPair => {
+                            if currency_pair__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("currencyPair"));
                             }
-                            value__ = map_.next_value::<::std::option::Option<_>>()?.map(fee_asset_change::Value::Addition);
+                            currency_pair__ = map_.next_value()?;
                         }
-                        GeneratedField::Removal => {
-                            if value__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("removal"));
+                        GeneratedField::MaxAgeSeconds => {
+                            if max_age_seconds__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("maxAgeSeconds"));
                             }
-                            value__ = map_.next_value::<::std::option::Option<_>>()?.map(fee_asset_change::Value::Removal);
+                            max_age_seconds__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::MaxDeviationBasisPoints => {
+                            if max_deviation_basis_points__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("maxDeviationBasisPoints"));
+                            }
+                            max_deviation_basis_points__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::TwapWindowSeconds => {
+                            if twap_window_seconds__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("twapWindowSeconds"));
+                            }
+                            twap_window_seconds__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
                         }
                     }
                 }
-                Ok(FeeAssetChange {
-                    value: value__,
+                Ok(CurrencyPairParameters {
+                    currency_pair: currency_pair__,
+                    max_age_seconds: max_age_seconds__.unwrap_or_default(),
+                    max_deviation_basis_points: max_deviation_basis_points__.unwrap_or_default(),
+                    twap_window_seconds: twap_window_seconds__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("astria.protocol.transaction.v1.FeeAssetChange", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("astria.protocol.transaction.v1.CurrencyPairParameters", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for FeeChange {
+impl serde::Serialize for CurrencyPairs {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -1356,135 +1529,29 @@ impl serde::Serialize for FeeChange {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.fee_components.is_some() {
+        if !self.pairs.is_empty() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("astria.protocol.transaction.v1.FeeChange", len)?;
-        if let Some(v) = self.fee_components.as_ref() {
-            match v {
-                fee_change::FeeComponents::BridgeLock(v) => {
-                    struct_ser.serialize_field("bridgeLock", v)?;
-                }
-                fee_change::FeeComponents::BridgeSudoChange(v) => {
-                    struct_ser.serialize_field("bridgeSudoChange", v)?;
-                }
-                fee_change::FeeComponents::BridgeUnlock(v) => {
-                    struct_ser.serialize_field("bridgeUnlock", v)?;
-                }
-                fee_change::FeeComponents::FeeAssetChange(v) => {
-                    struct_ser.serialize_field("feeAssetChange", v)?;
-                }
-                fee_change::FeeComponents::FeeChange(v) => {
-                    struct_ser.serialize_field("feeChange", v)?;
-                }
-                fee_change::FeeComponents::IbcRelay(v) => {
-                    struct_ser.serialize_field("ibcRelay", v)?;
-                }
-                fee_change::FeeComponents::IbcRelayerChange(v) => {
-                    struct_ser.serialize_field("ibcRelayerChange", v)?;
-                }
-                fee_change::FeeComponents::IbcSudoChange(v) => {
-                    struct_ser.serialize_field("ibcSudoChange", v)?;
-                }
-                fee_change::FeeComponents::Ics20Withdrawal(v) => {
-                    struct_ser.serialize_field("ics20Withdrawal", v)?;
-                }
-                fee_change::FeeComponents::InitBridgeAccount(v) => {
-                    struct_ser.serialize_field("initBridgeAccount", v)?;
-                }
-                fee_change::FeeComponents::RollupDataSubmission(v) => {
-                    struct_ser.serialize_field("rollupDataSubmission", v)?;
-                }
-                fee_change::FeeComponents::SudoAddressChange(v) => {
-                    struct_ser.serialize_field("sudoAddressChange", v)?;
-                }
-                fee_change::FeeComponents::Transfer(v) => {
-                    struct_ser.serialize_field("transfer", v)?;
-                }
-                fee_change::FeeComponents::ValidatorUpdate(v) => {
-                    struct_ser.serialize_field("validatorUpdate", v)?;
-                }
-                fee_change::FeeComponents::BridgeTransfer(v) => {
-                    struct_ser.serialize_field("bridgeTransfer", v)?;
-                }
-                fee_change::FeeComponents::RecoverIbcClient(v) => {
-                    struct_ser.serialize_field("recoverIbcClient", v)?;
-                }
-                fee_change::FeeComponents::CurrencyPairsChange(v) => {
-                    struct_ser.serialize_field("currencyPairsChange", v)?;
-                }
-                fee_change::FeeComponents::MarketsChange(v) => {
-                    struct_ser.serialize_field("marketsChange", v)?;
-                }
-            }
+        let mut struct_ser = serializer.serialize_struct("astria.protocol.transaction.v1.CurrencyPairs", len)?;
+        if !self.pairs.is_empty() {
+            struct_ser.serialize_field("pairs", &self.pairs)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for FeeChange {
+impl<'de> serde::Deserialize<'de> for CurrencyPairs {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "bridge_lock",
-            "bridgeLock",
-            "bridge_sudo_change",
-            "bridgeSudoChange",
-            "bridge_unlock",
-            "bridgeUnlock",
-            "fee_asset_change",
-            "feeAssetChange",
-            "fee_change",
-            "feeChange",
-            "ibc_relay",
-            "ibcRelay",
-            "ibc_relayer_change",
-            "ibcRelayerChange",
-            "ibc_sudo_change",
-            "ibcSudoChange",
-            "ics20_withdrawal",
-            "ics20Withdrawal",
-            "init_bridge_account",
-            "initBridgeAccount",
-            "rollup_data_submission",
-            "rollupDataSubmission",
-            "sudo_address_change",
-            "sudoAddressChange",
-            "transfer",
-            "validator_update",
-            "validatorUpdate",
-            "bridge_transfer",
-            "bridgeTransfer",
-            "recover_ibc_client",
-            "recoverIbcClient",
-            "currency_pairs_change",
-            "currencyPairsChange",
-            "markets_change",
-            "marketsChange",
+            "pairs",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            BridgeLock,
-            BridgeSudoChange,
-            BridgeUnlock,
-            FeeAssetChange,
-            FeeChange,
-            IbcRelay,
-            IbcRelayerChange,
-            IbcSudoChange,
-            Ics20Withdrawal,
-            InitBridgeAccount,
-            RollupDataSubmission,
-            SudoAddressChange,
-            Transfer,
-            ValidatorUpdate,
-            BridgeTransfer,
-            RecoverIbcClient,
-            CurrencyPairsChange,
-            MarketsChange,
+            Pairs,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -1506,24 +1573,7 @@ impl<'de> serde::Deserialize<'de> for FeeChange {
                         E: serde::de::Error,
                     {
                         match value {
-                            "bridgeLock" | "bridge_lock" => Ok(GeneratedField::BridgeLock),
-                            "bridgeSudoChange" | "bridge_sudo_change" => Ok(GeneratedField::BridgeSudoChange),
-                            "bridgeUnlock" | "bridge_unlock" => Ok(GeneratedField::BridgeUnlock),
-                            "feeAssetChange" | "fee_asset_change" => Ok(GeneratedField::FeeAssetChange),
-                            "feeChange" | "fee_change" => Ok(GeneratedField::FeeChange),
-                            "ibcRelay" | "ibc_relay" => Ok(GeneratedField::IbcRelay),
-                            "ibcRelayerChange" | "ibc_relayer_change" => Ok(GeneratedField::IbcRelayerChange),
-                            "ibcSudoChange" | "ibc_sudo_change" => Ok(GeneratedField::IbcSudoChange),
-                            "ics20Withdrawal" | "ics20_withdrawal" => Ok(GeneratedField::Ics20Withdrawal),
-                            "initBridgeAccount" | "init_bridge_account" => Ok(GeneratedField::InitBridgeAccount),
-                            "rollupDataSubmission" | "rollup_data_submission" => Ok(GeneratedField::RollupDataSubmission),
-                            "sudoAddressChange" | "sudo_address_change" => Ok(GeneratedField::SudoAddressChange),
-                            "transfer" => Ok(GeneratedField::Transfer),
-                            "validatorUpdate" | "validator_update" => Ok(GeneratedField::ValidatorUpdate),
-                            "bridgeTransfer" | "bridge_transfer" => Ok(GeneratedField::BridgeTransfer),
-                            "recoverIbcClient" | "recover_ibc_client" => Ok(GeneratedField::RecoverIbcClient),
-                            "currencyPairsChange" | "currency_pairs_change" => Ok(GeneratedField::CurrencyPairsChange),
-                            "marketsChange" | "markets_change" => Ok(GeneratedField::MarketsChange),
+                            "pairs" => Ok(GeneratedField::Pairs),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -1533,156 +1583,1636 @@ impl<'de> serde::Deserialize<'de> for FeeChange {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = FeeChange;
+            type Value = CurrencyPairs;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct astria.protocol.transaction.v1.FeeChange")
+                formatter.write_str("struct astria.protocol.transaction.v1.CurrencyPairs")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<FeeChange, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<CurrencyPairs, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut fee_components__ = None;
+                let mut pairs__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::BridgeLock => {
-                            if fee_components__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("bridgeLock"));
-                            }
-                            fee_components__ = map_.next_value::<::std::option::Option<_>>()?.map(fee_change::FeeComponents::BridgeLock)
-;
-                        }
-                        GeneratedField::BridgeSudoChange => {
-                            if fee_components__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("bridgeSudoChange"));
-                            }
-                            fee_components__ = map_.next_value::<::std::option::Option<_>>()?.map(fee_change::FeeComponents::BridgeSudoChange)
-;
-                        }
-                        GeneratedField::BridgeUnlock => {
-                            if fee_components__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("bridgeUnlock"));
-                            }
-                            fee_components__ = map_.next_value::<::std::option::Option<_>>()?.map(fee_change::FeeComponents::BridgeUnlock)
-;
-                        }
-                        GeneratedField::FeeAssetChange => {
-                            if fee_components__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("feeAssetChange"));
-                            }
-                            fee_components__ = map_.next_value::<::std::option::Option<_>>()?.map(fee_change::FeeComponents::FeeAssetChange)
-;
-                        }
-                        GeneratedField::FeeChange => {
-                            if fee_components__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("feeChange"));
-                            }
-                            fee_components__ = map_.next_value::<::std::option::Option<_>>()?.map(fee_change::FeeComponents::FeeChange)
-;
-                        }
-                        GeneratedField::IbcRelay => {
-                            if fee_components__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("ibcRelay"));
-                            }
-                            fee_components__ = map_.next_value::<::std::option::Option<_>>()?.map(fee_change::FeeComponents::IbcRelay)
-;
-                        }
-                        GeneratedField::IbcRelayerChange => {
-                            if fee_components__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("ibcRelayerChange"));
+                        GeneratedField::Pairs => {
+                            if pairs__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("pairs"));
                             }
-                            fee_components__ = map_.next_value::<::std::option::Option<_>>()?.map(fee_change::FeeComponents::IbcRelayerChange)
-;
+                            pairs__ = Some(map_.next_value()?);
                         }
-                        GeneratedField::IbcSudoChange => {
-                            if fee_components__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("ibcSudoChange"));
-                            }
-                            fee_components__ = map_.next_value::<::std::option::Option<_>>()?.map(fee_change::FeeComponents::IbcSudoChange)
-;
+                    }
+                }
+                Ok(CurrencyPairs {
+                    pairs: pairs__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("astria.protocol.transaction.v1.CurrencyPairs", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for CurrencyPairsChange {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.value.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("astria.protocol.transaction.v1.CurrencyPairsChange", len)?;
+        if let Some(v) = self.value.as_ref() {
+            match v {
+                currency_pairs_change::Value::Addition(v) => {
+                    struct_ser.serialize_field("addition", v)?;
+                }
+                currency_pairs_change::Value::Removal(v) => {
+                    struct_ser.serialize_field("removal", v)?;
+                }
+                currency_pairs_change::Value::ParametersUpdate(v) => {
+                    struct_ser.serialize_field("parametersUpdate", v)?;
+                }
+            }
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for CurrencyPairsChange {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "addition",
+            "removal",
+            "parameters_update",
+            "parametersUpdate",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Addition,
+            Removal,
+            ParametersUpdate,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "addition" => Ok(GeneratedField::Addition),
+                            "removal" => Ok(GeneratedField::Removal),
+                            "parametersUpdate" | "parameters_update" => Ok(GeneratedField::ParametersUpdate),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
-                        GeneratedField::Ics20Withdrawal => {
-                            if fee_components__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("ics20Withdrawal"));
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = CurrencyPairsChange;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct astria.protocol.transaction.v1.CurrencyPairsChange")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<CurrencyPairsChange, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut value__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Addition => {
+                            if value__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("addition"));
                             }
-                            fee_components__ = map_.next_value::<::std::option::Option<_>>()?.map(fee_change::FeeComponents::Ics20Withdrawal)
+                            value__ = map_.next_value::<::std::option::Option<_>>()?.map(currency_pairs_change::Value::Addition)
 ;
                         }
-                        GeneratedField::InitBridgeAccount => {
-                            if fee_components__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("initBridgeAccount"));
+                        GeneratedField::Removal => {
+                            if value__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("removal"));
                             }
-                            fee_components__ = map_.next_value::<::std::option::Option<_>>()?.map(fee_change::FeeComponents::InitBridgeAccount)
+                            value__ = map_.next_value::<::std::option::Option<_>>()?.map(currency_pairs_change::Value::Removal)
 ;
                         }
-                        GeneratedField::RollupDataSubmission => {
-                            if fee_components__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("rollupDataSubmission"));
+                        GeneratedField::ParametersUpdate => {
+                            if value__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("parametersUpdate"));
                             }
-                            fee_components__ = map_.next_value::<::std::option::Option<_>>()?.map(fee_change::FeeComponents::RollupDataSubmission)
+                            value__ = map_.next_value::<::std::option::Option<_>>()?.map(currency_pairs_change::Value::ParametersUpdate)
+;
+                        }
+                    }
+                }
+                Ok(CurrencyPairsChange {
+                    value: value__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("astria.protocol.transaction.v1.CurrencyPairsChange", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for CurrencyPairsParameters {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.parameters.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("astria.protocol.transaction.v1.CurrencyPairsParameters", len)?;
+        if !self.parameters.is_empty() {
+            struct_ser.serialize_field("parameters", &self.parameters)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for CurrencyPairsParameters {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "parameters",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Parameters,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "parameters" => Ok(GeneratedField::Parameters),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = CurrencyPairsParameters;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct astria.protocol.transaction.v1.CurrencyPairsParameters")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<CurrencyPairsParameters, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut parameters__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Parameters => {
+                            if parameters__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("parameters"));
+                            }
+                            parameters__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(CurrencyPairsParameters {
+                    parameters: parameters__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("astria.protocol.transaction.v1.CurrencyPairsParameters", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for FeeAssetChange {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.value.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("astria.protocol.transaction.v1.FeeAssetChange", len)?;
+        if let Some(v) = self.value.as_ref() {
+            match v {
+                fee_asset_change::Value::Addition(v) => {
+                    struct_ser.serialize_field("addition", v)?;
+                }
+                fee_asset_change::Value::Removal(v) => {
+                    struct_ser.serialize_field("removal", v)?;
+                }
+            }
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for FeeAssetChange {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "addition",
+            "removal",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Addition,
+            Removal,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "addition" => Ok(GeneratedField::Addition),
+                            "removal" => Ok(GeneratedField::Removal),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = FeeAssetChange;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct astria.protocol.transaction.v1.FeeAssetChange")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<FeeAssetChange, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut value__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Addition => {
+                            if value__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("addition"));
+                            }
+                            value__ = map_.next_value::<::std::option::Option<_>>()?.map(fee_asset_change::Value::Addition);
+                        }
+                        GeneratedField::Removal => {
+                            if value__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("removal"));
+                            }
+                            value__ = map_.next_value::<::std::option::Option<_>>()?.map(fee_asset_change::Value::Removal);
+                        }
+                    }
+                }
+                Ok(FeeAssetChange {
+                    value: value__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("astria.protocol.transaction.v1.FeeAssetChange", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for FeeChange {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.fee_components.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("astria.protocol.transaction.v1.FeeChange", len)?;
+        if let Some(v) = self.fee_components.as_ref() {
+            match v {
+                fee_change::FeeComponents::BridgeLock(v) => {
+                    struct_ser.serialize_field("bridgeLock", v)?;
+                }
+                fee_change::FeeComponents::BridgeSudoChange(v) => {
+                    struct_ser.serialize_field("bridgeSudoChange", v)?;
+                }
+                fee_change::FeeComponents::BridgeUnlock(v) => {
+                    struct_ser.serialize_field("bridgeUnlock", v)?;
+                }
+                fee_change::FeeComponents::FeeAssetChange(v) => {
+                    struct_ser.serialize_field("feeAssetChange", v)?;
+                }
+                fee_change::FeeComponents::FeeChange(v) => {
+                    struct_ser.serialize_field("feeChange", v)?;
+                }
+                fee_change::FeeComponents::IbcRelay(v) => {
+                    struct_ser.serialize_field("ibcRelay", v)?;
+                }
+                fee_change::FeeComponents::IbcRelayerChange(v) => {
+                    struct_ser.serialize_field("ibcRelayerChange", v)?;
+                }
+                fee_change::FeeComponents::IbcSudoChange(v) => {
+                    struct_ser.serialize_field("ibcSudoChange", v)?;
+                }
+                fee_change::FeeComponents::Ics20Withdrawal(v) => {
+                    struct_ser.serialize_field("ics20Withdrawal", v)?;
+                }
+                fee_change::FeeComponents::InitBridgeAccount(v) => {
+                    struct_ser.serialize_field("initBridgeAccount", v)?;
+                }
+                fee_change::FeeComponents::RollupDataSubmission(v) => {
+                    struct_ser.serialize_field("rollupDataSubmission", v)?;
+                }
+                fee_change::FeeComponents::SudoAddressChange(v) => {
+                    struct_ser.serialize_field("sudoAddressChange", v)?;
+                }
+                fee_change::FeeComponents::Transfer(v) => {
+                    struct_ser.serialize_field("transfer", v)?;
+                }
+                fee_change::FeeComponents::ValidatorUpdate(v) => {
+                    struct_ser.serialize_field("validatorUpdate", v)?;
+                }
+                fee_change::FeeComponents::BridgeTransfer(v) => {
+                    struct_ser.serialize_field("bridgeTransfer", v)?;
+                }
+                fee_change::FeeComponents::RecoverIbcClient(v) => {
+                    struct_ser.serialize_field("recoverIbcClient", v)?;
+                }
+                fee_change::FeeComponents::CurrencyPairsChange(v) => {
+                    struct_ser.serialize_field("currencyPairsChange", v)?;
+                }
+                fee_change::FeeComponents::MarketsChange(v) => {
+                    struct_ser.serialize_field("marketsChange", v)?;
+                }
+                fee_change::FeeComponents::RegisterMultisig(v) => {
+                    struct_ser.serialize_field("registerMultisig", v)?;
+                }
+                fee_change::FeeComponents::TimelockedTransfer(v) => {
+                    struct_ser.serialize_field("timelockedTransfer", v)?;
+                }
+                fee_change::FeeComponents::ClaimTimelockedTransfer(v) => {
+                    struct_ser.serialize_field("claimTimelockedTransfer", v)?;
+                }
+                fee_change::FeeComponents::CancelTimelockedTransfer(v) => {
+                    struct_ser.serialize_field("cancelTimelockedTransfer", v)?;
+                }
+                fee_change::FeeComponents::SubmitProposal(v) => {
+                    struct_ser.serialize_field("submitProposal", v)?;
+                }
+                fee_change::FeeComponents::VoteOnProposal(v) => {
+                    struct_ser.serialize_field("voteOnProposal", v)?;
+                }
+                fee_change::FeeComponents::FeeDistributionChange(v) => {
+                    struct_ser.serialize_field("feeDistributionChange", v)?;
+                }
+            }
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for FeeChange {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "bridge_lock",
+            "bridgeLock",
+            "bridge_sudo_change",
+            "bridgeSudoChange",
+            "bridge_unlock",
+            "bridgeUnlock",
+            "fee_asset_change",
+            "feeAssetChange",
+            "fee_change",
+            "feeChange",
+            "ibc_relay",
+            "ibcRelay",
+            "ibc_relayer_change",
+            "ibcRelayerChange",
+            "ibc_sudo_change",
+            "ibcSudoChange",
+            "ics20_withdrawal",
+            "ics20Withdrawal",
+            "init_bridge_account",
+            "initBridgeAccount",
+            "rollup_data_submission",
+            "rollupDataSubmission",
+            "sudo_address_change",
+            "sudoAddressChange",
+            "transfer",
+            "validator_update",
+            "validatorUpdate",
+            "bridge_transfer",
+            "bridgeTransfer",
+            "recover_ibc_client",
+            "recoverIbcClient",
+            "currency_pairs_change",
+            "currencyPairsChange",
+            "markets_change",
+            "marketsChange",
+            "register_multisig",
+            "registerMultisig",
+            "timelocked_transfer",
+            "timelockedTransfer",
+            "claim_timelocked_transfer",
+            "claimTimelockedTransfer",
+            "cancel_timelocked_transfer",
+            "cancelTimelockedTransfer",
+            "submit_proposal",
+            "submitProposal",
+            "vote_on_proposal",
+            "voteOnProposal",
+            "fee_distribution_change",
+            "feeDistributionChange",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            BridgeLock,
+            BridgeSudoChange,
+            BridgeUnlock,
+            FeeAssetChange,
+            FeeChange,
+            IbcRelay,
+            IbcRelayerChange,
+            IbcSudoChange,
+            Ics20Withdrawal,
+            InitBridgeAccount,
+            RollupDataSubmission,
+            SudoAddressChange,
+            Transfer,
+            ValidatorUpdate,
+            BridgeTransfer,
+            RecoverIbcClient,
+            CurrencyPairsChange,
+            MarketsChange,
+            RegisterMultisig,
+            TimelockedTransfer,
+            ClaimTimelockedTransfer,
+            CancelTimelockedTransfer,
+            SubmitProposal,
+            VoteOnProposal,
+            FeeDistributionChange,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "bridgeLock" | "bridge_lock" => Ok(GeneratedField::BridgeLock),
+                            "bridgeSudoChange" | "bridge_sudo_change" => Ok(GeneratedField::BridgeSudoChange),
+                            "bridgeUnlock" | "bridge_unlock" => Ok(GeneratedField::BridgeUnlock),
+                            "feeAssetChange" | "fee_asset_change" => Ok(GeneratedField::FeeAssetChange),
+                            "feeChange" | "fee_change" => Ok(GeneratedField::FeeChange),
+                            "ibcRelay" | "ibc_relay" => Ok(GeneratedField::IbcRelay),
+                            "ibcRelayerChange" | "ibc_relayer_change" => Ok(GeneratedField::IbcRelayerChange),
+                            "ibcSudoChange" | "ibc_sudo_change" => Ok(GeneratedField::IbcSudoChange),
+                            "ics20Withdrawal" | "ics20_withdrawal" => Ok(GeneratedField::Ics20Withdrawal),
+                            "initBridgeAccount" | "init_bridge_account" => Ok(GeneratedField::InitBridgeAccount),
+                            "rollupDataSubmission" | "rollup_data_submission" => Ok(GeneratedField::RollupDataSubmission),
+                            "sudoAddressChange" | "sudo_address_change" => Ok(GeneratedField::SudoAddressChange),
+                            "transfer" => Ok(GeneratedField::Transfer),
+                            "validatorUpdate" | "validator_update" => Ok(GeneratedField::ValidatorUpdate),
+                            "bridgeTransfer" | "bridge_transfer" => Ok(GeneratedField::BridgeTransfer),
+                            "recoverIbcClient" | "recover_ibc_client" => Ok(GeneratedField::RecoverIbcClient),
+                            "currencyPairsChange" | "currency_pairs_change" => Ok(GeneratedField::CurrencyPairsChange),
+                            "marketsChange" | "markets_change" => Ok(GeneratedField::MarketsChange),
+                            "registerMultisig" | "register_multisig" => Ok(GeneratedField::RegisterMultisig),
+                            "timelockedTransfer" | "timelocked_transfer" => Ok(GeneratedField::TimelockedTransfer),
+                            "claimTimelockedTransfer" | "claim_timelocked_transfer" => Ok(GeneratedField::ClaimTimelockedTransfer),
+                            "cancelTimelockedTransfer" | "cancel_timelocked_transfer" => Ok(GeneratedField::CancelTimelockedTransfer),
+                            "submitProposal" | "submit_proposal" => Ok(GeneratedField::SubmitProposal),
+                            "voteOnProposal" | "vote_on_proposal" => Ok(GeneratedField::VoteOnProposal),
+                            "feeDistributionChange" | "fee_distribution_change" => Ok(GeneratedField::FeeDistributionChange),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = FeeChange;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct astria.protocol.transaction.v1.FeeChange")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<FeeChange, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut fee_components__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::BridgeLock => {
+                            if fee_components__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("bridgeLock"));
+                            }
+                            fee_components__ = map_.next_value::<::std::option::Option<_>>()?.map(fee_change::FeeComponents::BridgeLock)
+;
+                        }
+                        GeneratedField::BridgeSudoChange => {
+                            if fee_components__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("bridgeSudoChange"));
+                            }
+                            fee_components__ = map_.next_value::<::std::option::Option<_>>()?.map(fee_change::FeeComponents::BridgeSudoChange)
+;
+                        }
+                        GeneratedField::BridgeUnlock => {
+                            if fee_components__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("bridgeUnlock"));
+                            }
+                            fee_components__ = map_.next_value::<::std::option::Option<_>>()?.map(fee_change::FeeComponents::BridgeUnlock)
+;
+                        }
+                        GeneratedField::FeeAssetChange => {
+                            if fee_components__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("feeAssetChange"));
+                            }
+                            fee_components__ = map_.next_value::<::std::option::Option<_>>()?.map(fee_change::FeeComponents::FeeAssetChange)
+;
+                        }
+                        GeneratedField::FeeChange => {
+                            if fee_components__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("feeChange"));
+                            }
+                            fee_components__ = map_.next_value::<::std::option::Option<_>>()?.map(fee_change::FeeComponents::FeeChange)
+;
+                        }
+                        GeneratedField::IbcRelay => {
+                            if fee_components__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("ibcRelay"));
+                            }
+                            fee_components__ = map_.next_value::<::std::option::Option<_>>()?.map(fee_change::FeeComponents::IbcRelay)
+;
+                        }
+                        GeneratedField::IbcRelayerChange => {
+                            if fee_components__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("ibcRelayerChange"));
+                            }
+                            fee_components__ = map_.next_value::<::std::option::Option<_>>()?.map(fee_change::FeeComponents::IbcRelayerChange)
+;
+                        }
+                        GeneratedField::IbcSudoChange => {
+                            if fee_components__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("ibcSudoChange"));
+                            }
+                            fee_components__ = map_.next_value::<::std::option::Option<_>>()?.map(fee_change::FeeComponents::IbcSudoChange)
+;
+                        }
+                        GeneratedField::Ics20Withdrawal => {
+                            if fee_components__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("ics20Withdrawal"));
+                            }
+                            fee_components__ = map_.next_value::<::std::option::Option<_>>()?.map(fee_change::FeeComponents::Ics20Withdrawal)
+;
+                        }
+                        GeneratedField::InitBridgeAccount => {
+                            if fee_components__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("initBridgeAccount"));
+                            }
+                            fee_components__ = map_.next_value::<::std::option::Option<_>>()?.map(fee_change::FeeComponents::InitBridgeAccount)
+;
+                        }
+                        GeneratedField::RollupDataSubmission => {
+                            if fee_components__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("rollupDataSubmission"));
+                            }
+                            fee_components__ = map_.next_value::<::std::option::Option<_>>()?.map(fee_change::FeeComponents::RollupDataSubmission)
 ;
                         }
                         GeneratedField::SudoAddressChange => {
                             if fee_components__.is_some() {
                                 return Err(serde::de::Error::duplicate_field("sudoAddressChange"));
                             }
-                            fee_components__ = map_.next_value::<::std::option::Option<_>>()?.map(fee_change::FeeComponents::SudoAddressChange)
-;
+                            fee_components__ = map_.next_value::<::std::option::Option<_>>()?.map(fee_change::FeeComponents::SudoAddressChange)
+;
+                        }
+                        GeneratedField::Transfer => {
+                            if fee_components__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("transfer"));
+                            }
+                            fee_components__ = map_.next_value::<::std::option::Option<_>>()?.map(fee_change::FeeComponents::Transfer)
+;
+                        }
+                        GeneratedField::ValidatorUpdate => {
+                            if fee_components__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("validatorUpdate"));
+                            }
+                            fee_components__ = map_.next_value::<::std::option::Option<_>>()?.map(fee_change::FeeComponents::ValidatorUpdate)
+;
+                        }
+                        GeneratedField::BridgeTransfer => {
+                            if fee_components__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("bridgeTransfer"));
+                            }
+                            fee_components__ = map_.next_value::<::std::option::Option<_>>()?.map(fee_change::FeeComponents::BridgeTransfer)
+;
+                        }
+                        GeneratedField::RecoverIbcClient => {
+                            if fee_components__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("recoverIbcClient"));
+                            }
+                            fee_components__ = map_.next_value::<::std::option::Option<_>>()?.map(fee_change::FeeComponents::RecoverIbcClient)
+;
+                        }
+                        GeneratedField::CurrencyPairsChange => {
+                            if fee_components__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("currencyPairsChange"));
+                            }
+                            fee_components__ = map_.next_value::<::std::option::Option<_>>()?.map(fee_change::FeeComponents::CurrencyPairsChange)
+;
+                        }
+                        GeneratedField::MarketsChange => {
+                            if fee_components__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("marketsChange"));
+                            }
+                            fee_components__ = map_.next_value::<::std::option::Option<_>>()?.map(fee_change::FeeComponents::MarketsChange)
+;
+                        }
+                        GeneratedField::RegisterMultisig => {
+                            if fee_components__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("registerMultisig"));
+                            }
+                            fee_components__ = map_.next_value::<::std::option::Option<_>>()?.map(fee_change::FeeComponents::RegisterMultisig)
+;
+                        }
+                        GeneratedField::TimelockedTransfer => {
+                            if fee_components__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("timelockedTransfer"));
+                            }
+                            fee_components__ = map_.next_value::<::std::option::Option<_>>()?.map(fee_change::FeeComponents::TimelockedTransfer)
+;
+                        }
+                        GeneratedField::ClaimTimelockedTransfer => {
+                            if fee_components__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("claimTimelockedTransfer"));
+                            }
+                            fee_components__ = map_.next_value::<::std::option::Option<_>>()?.map(fee_change::FeeComponents::ClaimTimelockedTransfer)
+;
+                        }
+                        GeneratedField::CancelTimelockedTransfer => {
+                            if fee_components__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("cancelTimelockedTransfer"));
+                            }
+                            fee_components__ = map_.next_value::<::std::option::Option<_>>()?.map(fee_change::FeeComponents::CancelTimelockedTransfer)
+;
+                        }
+                        GeneratedField::SubmitProposal => {
+                            if fee_components__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("submitProposal"));
+                            }
+                            fee_components__ = map_.next_value::<::std::option::Option<_>>()?.map(fee_change::FeeComponents::SubmitProposal)
+;
+                        }
+                        GeneratedField::VoteOnProposal => {
+                            if fee_components__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("voteOnProposal"));
+                            }
+                            fee_components__ = map_.next_value::<::std::option::Option<_>>()?.map(fee_change::FeeComponents::VoteOnProposal)
+;
+                        }
+                        GeneratedField::FeeDistributionChange => {
+                            if fee_components__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("feeDistributionChange"));
+                            }
+                            fee_components__ = map_.next_value::<::std::option::Option<_>>()?.map(fee_change::FeeComponents::FeeDistributionChange)
+;
+                        }
+                    }
+                }
+                Ok(FeeChange {
+                    fee_components: fee_components__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("astria.protocol.transaction.v1.FeeChange", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for FeeDistributionChange {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.new_distribution.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("astria.protocol.transaction.v1.FeeDistributionChange", len)?;
+        if let Some(v) = self.new_distribution.as_ref() {
+            struct_ser.serialize_field("newDistribution", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for FeeDistributionChange {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "new_distribution",
+            "newDistribution",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            NewDistribution,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "newDistribution" | "new_distribution" => Ok(GeneratedField::NewDistribution),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = FeeDistributionChange;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct astria.protocol.transaction.v1.FeeDistributionChange")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<FeeDistributionChange, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut new_distribution__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::NewDistribution => {
+                            if new_distribution__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("newDistribution"));
+                            }
+                            new_distribution__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(FeeDistributionChange {
+                    new_distribution: new_distribution__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("astria.protocol.transaction.v1.FeeDistributionChange", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for IbcHeight {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.revision_number != 0 {
+            len += 1;
+        }
+        if self.revision_height != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("astria.protocol.transaction.v1.IbcHeight", len)?;
+        if self.revision_number != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("revisionNumber", ToString::to_string(&self.revision_number).as_str())?;
+        }
+        if self.revision_height != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("revisionHeight", ToString::to_string(&self.revision_height).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for IbcHeight {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "revision_number",
+            "revisionNumber",
+            "revision_height",
+            "revisionHeight",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            RevisionNumber,
+            RevisionHeight,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "revisionNumber" | "revision_number" => Ok(GeneratedField::RevisionNumber),
+                            "revisionHeight" | "revision_height" => Ok(GeneratedField::RevisionHeight),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = IbcHeight;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct astria.protocol.transaction.v1.IbcHeight")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<IbcHeight, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut revision_number__ = None;
+                let mut revision_height__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::RevisionNumber => {
+                            if revision_number__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("revisionNumber"));
+                            }
+                            revision_number__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::RevisionHeight => {
+                            if revision_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("revisionHeight"));
+                            }
+                            revision_height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(IbcHeight {
+                    revision_number: revision_number__.unwrap_or_default(),
+                    revision_height: revision_height__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("astria.protocol.transaction.v1.IbcHeight", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for IbcRelayerChange {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.value.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("astria.protocol.transaction.v1.IbcRelayerChange", len)?;
+        if let Some(v) = self.value.as_ref() {
+            match v {
+                ibc_relayer_change::Value::Addition(v) => {
+                    struct_ser.serialize_field("addition", v)?;
+                }
+                ibc_relayer_change::Value::Removal(v) => {
+                    struct_ser.serialize_field("removal", v)?;
+                }
+            }
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for IbcRelayerChange {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "addition",
+            "removal",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Addition,
+            Removal,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "addition" => Ok(GeneratedField::Addition),
+                            "removal" => Ok(GeneratedField::Removal),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = IbcRelayerChange;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct astria.protocol.transaction.v1.IbcRelayerChange")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<IbcRelayerChange, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut value__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Addition => {
+                            if value__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("addition"));
+                            }
+                            value__ = map_.next_value::<::std::option::Option<_>>()?.map(ibc_relayer_change::Value::Addition)
+;
+                        }
+                        GeneratedField::Removal => {
+                            if value__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("removal"));
+                            }
+                            value__ = map_.next_value::<::std::option::Option<_>>()?.map(ibc_relayer_change::Value::Removal)
+;
+                        }
+                    }
+                }
+                Ok(IbcRelayerChange {
+                    value: value__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("astria.protocol.transaction.v1.IbcRelayerChange", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for IbcSudoChange {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.new_address.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("astria.protocol.transaction.v1.IbcSudoChange", len)?;
+        if let Some(v) = self.new_address.as_ref() {
+            struct_ser.serialize_field("newAddress", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for IbcSudoChange {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "new_address",
+            "newAddress",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            NewAddress,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "newAddress" | "new_address" => Ok(GeneratedField::NewAddress),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = IbcSudoChange;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct astria.protocol.transaction.v1.IbcSudoChange")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<IbcSudoChange, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut new_address__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::NewAddress => {
+                            if new_address__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("newAddress"));
+                            }
+                            new_address__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(IbcSudoChange {
+                    new_address: new_address__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("astria.protocol.transaction.v1.IbcSudoChange", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for Ics20Withdrawal {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.amount.is_some() {
+            len += 1;
+        }
+        if !self.denom.is_empty() {
+            len += 1;
+        }
+        if !self.destination_chain_address.is_empty() {
+            len += 1;
+        }
+        if self.return_address.is_some() {
+            len += 1;
+        }
+        if self.timeout_height.is_some() {
+            len += 1;
+        }
+        if self.timeout_time != 0 {
+            len += 1;
+        }
+        if !self.source_channel.is_empty() {
+            len += 1;
+        }
+        if !self.fee_asset.is_empty() {
+            len += 1;
+        }
+        if !self.memo.is_empty() {
+            len += 1;
+        }
+        if self.bridge_address.is_some() {
+            len += 1;
+        }
+        if self.use_compat_address {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("astria.protocol.transaction.v1.Ics20Withdrawal", len)?;
+        if let Some(v) = self.amount.as_ref() {
+            struct_ser.serialize_field("amount", v)?;
+        }
+        if !self.denom.is_empty() {
+            struct_ser.serialize_field("denom", &self.denom)?;
+        }
+        if !self.destination_chain_address.is_empty() {
+            struct_ser.serialize_field("destinationChainAddress", &self.destination_chain_address)?;
+        }
+        if let Some(v) = self.return_address.as_ref() {
+            struct_ser.serialize_field("returnAddress", v)?;
+        }
+        if let Some(v) = self.timeout_height.as_ref() {
+            struct_ser.serialize_field("timeoutHeight", v)?;
+        }
+        if self.timeout_time != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("timeoutTime", ToString::to_string(&self.timeout_time).as_str())?;
+        }
+        if !self.source_channel.is_empty() {
+            struct_ser.serialize_field("sourceChannel", &self.source_channel)?;
+        }
+        if !self.fee_asset.is_empty() {
+            struct_ser.serialize_field("feeAsset", &self.fee_asset)?;
+        }
+        if !self.memo.is_empty() {
+            struct_ser.serialize_field("memo", &self.memo)?;
+        }
+        if let Some(v) = self.bridge_address.as_ref() {
+            struct_ser.serialize_field("bridgeAddress", v)?;
+        }
+        if self.use_compat_address {
+            struct_ser.serialize_field("useCompatAddress", &self.use_compat_address)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for Ics20Withdrawal {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "amount",
+            "denom",
+            "destination_chain_address",
+            "destinationChainAddress",
+            "return_address",
+            "returnAddress",
+            "timeout_height",
+            "timeoutHeight",
+            "timeout_time",
+            "timeoutTime",
+            "source_channel",
+            "sourceChannel",
+            "fee_asset",
+            "feeAsset",
+            "memo",
+            "bridge_address",
+            "bridgeAddress",
+            "use_compat_address",
+            "useCompatAddress",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Amount,
+            Denom,
+            DestinationChainAddress,
+            ReturnAddress,
+            TimeoutHeight,
+            TimeoutTime,
+            SourceChannel,
+            FeeAsset,
+            Memo,
+            BridgeAddress,
+            UseCompatAddress,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "amount" => Ok(GeneratedField::Amount),
+                            "denom" => Ok(GeneratedField::Denom),
+                            "destinationChainAddress" | "destination_chain_address" => Ok(GeneratedField::DestinationChainAddress),
+                            "returnAddress" | "return_address" => Ok(GeneratedField::ReturnAddress),
+                            "timeoutHeight" | "timeout_height" => Ok(GeneratedField::TimeoutHeight),
+                            "timeoutTime" | "timeout_time" => Ok(GeneratedField::TimeoutTime),
+                            "sourceChannel" | "source_channel" => Ok(GeneratedField::SourceChannel),
+                            "feeAsset" | "fee_asset" => Ok(GeneratedField::FeeAsset),
+                            "memo" => Ok(GeneratedField::Memo),
+                            "bridgeAddress" | "bridge_address" => Ok(GeneratedField::BridgeAddress),
+                            "useCompatAddress" | "use_compat_address" => Ok(GeneratedField::UseCompatAddress),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = Ics20Withdrawal;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct astria.protocol.transaction.v1.Ics20Withdrawal")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Ics20Withdrawal, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut amount__ = None;
+                let mut denom__ = None;
+                let mut destination_chain_address__ = None;
+                let mut return_address__ = None;
+                let mut timeout_height__ = None;
+                let mut timeout_time__ = None;
+                let mut source_channel__ = None;
+                let mut fee_asset__ = None;
+                let mut memo__ = None;
+                let mut bridge_address__ = None;
+                let mut use_compat_address__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Amount => {
+                            if amount__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("amount"));
+                            }
+                            amount__ = map_.next_value()?;
                         }
-                        GeneratedField::Transfer => {
-                            if fee_components__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("transfer"));
+                        GeneratedField::Denom => {
+                            if denom__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("denom"));
                             }
-                            fee_components__ = map_.next_value::<::std::option::Option<_>>()?.map(fee_change::FeeComponents::Transfer)
-;
+                            denom__ = Some(map_.next_value()?);
                         }
-                        GeneratedField::ValidatorUpdate => {
-                            if fee_components__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("validatorUpdate"));
+                        GeneratedField::DestinationChainAddress => {
+                            if destination_chain_address__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("destinationChainAddress"));
                             }
-                            fee_components__ = map_.next_value::<::std::option::Option<_>>()?.map(fee_change::FeeComponents::ValidatorUpdate)
-;
+                            destination_chain_address__ = Some(map_.next_value()?);
                         }
-                        GeneratedField::BridgeTransfer => {
-                            if fee_components__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("bridgeTransfer"));
+                        GeneratedField::ReturnAddress => {
+                            if return_address__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("returnAddress"));
                             }
-                            fee_components__ = map_.next_value::<::std::option::Option<_>>()?.map(fee_change::FeeComponents::BridgeTransfer)
-;
+                            return_address__ = map_.next_value()?;
                         }
-                        GeneratedField::RecoverIbcClient => {
-                            if fee_components__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("recoverIbcClient"));
+                        GeneratedField::TimeoutHeight => {
+                            if timeout_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("timeoutHeight"));
                             }
-                            fee_components__ = map_.next_value::<::std::option::Option<_>>()?.map(fee_change::FeeComponents::RecoverIbcClient)
-;
+                            timeout_height__ = map_.next_value()?;
                         }
-                        GeneratedField::CurrencyPairsChange => {
-                            if fee_components__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("currencyPairsChange"));
+                        GeneratedField::TimeoutTime => {
+                            if timeout_time__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("timeoutTime"));
                             }
-                            fee_components__ = map_.next_value::<::std::option::Option<_>>()?.map(fee_change::FeeComponents::CurrencyPairsChange)
-;
+                            timeout_time__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::SourceChannel => {
+                            if source_channel__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("sourceChannel"));
+                            }
+                            source_channel__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::FeeAsset => {
+                            if fee_asset__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("feeAsset"));
+                            }
+                            fee_asset__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Memo => {
+                            if memo__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("memo"));
+                            }
+                            memo__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::BridgeAddress => {
+                            if bridge_address__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("bridgeAddress"));
+                            }
+                            bridge_address__ = map_.next_value()?;
+                        }
+                        GeneratedField::UseCompatAddress => {
+                            if use_compat_address__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("useCompatAddress"));
+                            }
+                            use_compat_address__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(Ics20Withdrawal {
+                    amount: amount__,
+                    denom: denom__.unwrap_or_default(),
+                    destination_chain_address: destination_chain_address__.unwrap_or_default(),
+                    return_address: return_address__,
+                    timeout_height: timeout_height__,
+                    timeout_time: timeout_time__.unwrap_or_default(),
+                    source_channel: source_channel__.unwrap_or_default(),
+                    fee_asset: fee_asset__.unwrap_or_default(),
+                    memo: memo__.unwrap_or_default(),
+                    bridge_address: bridge_address__,
+                    use_compat_address: use_compat_address__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("astria.protocol.transaction.v1.Ics20Withdrawal", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for InitBridgeAccount {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.rollup_id.is_some() {
+            len += 1;
+        }
+        if !self.asset.is_empty() {
+            len += 1;
+        }
+        if !self.fee_asset.is_empty() {
+            len += 1;
+        }
+        if self.sudo_address.is_some() {
+            len += 1;
+        }
+        if self.withdrawer_address.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("astria.protocol.transaction.v1.InitBridgeAccount", len)?;
+        if let Some(v) = self.rollup_id.as_ref() {
+            struct_ser.serialize_field("rollupId", v)?;
+        }
+        if !self.asset.is_empty() {
+            struct_ser.serialize_field("asset", &self.asset)?;
+        }
+        if !self.fee_asset.is_empty() {
+            struct_ser.serialize_field("feeAsset", &self.fee_asset)?;
+        }
+        if let Some(v) = self.sudo_address.as_ref() {
+            struct_ser.serialize_field("sudoAddress", v)?;
+        }
+        if let Some(v) = self.withdrawer_address.as_ref() {
+            struct_ser.serialize_field("withdrawerAddress", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for InitBridgeAccount {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "rollup_id",
+            "rollupId",
+            "asset",
+            "fee_asset",
+            "feeAsset",
+            "sudo_address",
+            "sudoAddress",
+            "withdrawer_address",
+            "withdrawerAddress",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            RollupId,
+            Asset,
+            FeeAsset,
+            SudoAddress,
+            WithdrawerAddress,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "rollupId" | "rollup_id" => Ok(GeneratedField::RollupId),
+                            "asset" => Ok(GeneratedField::Asset),
+                            "feeAsset" | "fee_asset" => Ok(GeneratedField::FeeAsset),
+                            "sudoAddress" | "sudo_address" => Ok(GeneratedField::SudoAddress),
+                            "withdrawerAddress" | "withdrawer_address" => Ok(GeneratedField::WithdrawerAddress),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = InitBridgeAccount;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct astria.protocol.transaction.v1.InitBridgeAccount")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<InitBridgeAccount, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut rollup_id__ = None;
+                let mut asset__ = None;
+                let mut fee_asset__ = None;
+                let mut sudo_address__ = None;
+                let mut withdrawer_address__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::RollupId => {
+                            if rollup_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("rollupId"));
+                            }
+                            rollup_id__ = map_.next_value()?;
+                        }
+                        GeneratedField::Asset => {
+                            if asset__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("asset"));
+                            }
+                            asset__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::FeeAsset => {
+                            if fee_asset__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("feeAsset"));
+                            }
+                            fee_asset__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::SudoAddress => {
+                            if sudo_address__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("sudoAddress"));
+                            }
+                            sudo_address__ = map_.next_value()?;
                         }
-                        GeneratedField::MarketsChange => {
-                            if fee_components__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("marketsChange"));
+                        GeneratedField::WithdrawerAddress => {
+                            if withdrawer_address__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("withdrawerAddress"));
                             }
-                            fee_components__ = map_.next_value::<::std::option::Option<_>>()?.map(fee_change::FeeComponents::MarketsChange)
-;
+                            withdrawer_address__ = map_.next_value()?;
                         }
                     }
                 }
-                Ok(FeeChange {
-                    fee_components: fee_components__,
+                Ok(InitBridgeAccount {
+                    rollup_id: rollup_id__,
+                    asset: asset__.unwrap_or_default(),
+                    fee_asset: fee_asset__.unwrap_or_default(),
+                    sudo_address: sudo_address__,
+                    withdrawer_address: withdrawer_address__,
                 })
             }
         }
-        deserializer.deserialize_struct("astria.protocol.transaction.v1.FeeChange", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("astria.protocol.transaction.v1.InitBridgeAccount", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for IbcHeight {
+impl serde::Serialize for Markets {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -1690,43 +3220,29 @@ impl serde::Serialize for IbcHeight {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.revision_number != 0 {
-            len += 1;
-        }
-        if self.revision_height != 0 {
+        if !self.markets.is_empty() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("astria.protocol.transaction.v1.IbcHeight", len)?;
-        if self.revision_number != 0 {
-            #[allow(clippy::needless_borrow)]
-            #[allow(clippy::needless_borrows_for_generic_args)]
-            struct_ser.serialize_field("revisionNumber", ToString::to_string(&self.revision_number).as_str())?;
-        }
-        if self.revision_height != 0 {
-            #[allow(clippy::needless_borrow)]
-            #[allow(clippy::needless_borrows_for_generic_args)]
-            struct_ser.serialize_field("revisionHeight", ToString::to_string(&self.revision_height).as_str())?;
+        let mut struct_ser = serializer.serialize_struct("astria.protocol.transaction.v1.Markets", len)?;
+        if !self.markets.is_empty() {
+            struct_ser.serialize_field("markets", &self.markets)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for IbcHeight {
+impl<'de> serde::Deserialize<'de> for Markets {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "revision_number",
-            "revisionNumber",
-            "revision_height",
-            "revisionHeight",
+            "markets",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            RevisionNumber,
-            RevisionHeight,
+            Markets,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -1748,8 +3264,7 @@ impl<'de> serde::Deserialize<'de> for IbcHeight {
                         E: serde::de::Error,
                     {
                         match value {
-                            "revisionNumber" | "revision_number" => Ok(GeneratedField::RevisionNumber),
-                            "revisionHeight" | "revision_height" => Ok(GeneratedField::RevisionHeight),
+                            "markets" => Ok(GeneratedField::Markets),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -1759,48 +3274,36 @@ impl<'de> serde::Deserialize<'de> for IbcHeight {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = IbcHeight;
+            type Value = Markets;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct astria.protocol.transaction.v1.IbcHeight")
+                formatter.write_str("struct astria.protocol.transaction.v1.Markets")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<IbcHeight, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Markets, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut revision_number__ = None;
-                let mut revision_height__ = None;
+                let mut markets__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::RevisionNumber => {
-                            if revision_number__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("revisionNumber"));
-                            }
-                            revision_number__ = 
-                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
-                        }
-                        GeneratedField::RevisionHeight => {
-                            if revision_height__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("revisionHeight"));
+                        GeneratedField::Markets => {
+                            if markets__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("markets"));
                             }
-                            revision_height__ = 
-                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
+                            markets__ = Some(map_.next_value()?);
                         }
                     }
                 }
-                Ok(IbcHeight {
-                    revision_number: revision_number__.unwrap_or_default(),
-                    revision_height: revision_height__.unwrap_or_default(),
+                Ok(Markets {
+                    markets: markets__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("astria.protocol.transaction.v1.IbcHeight", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("astria.protocol.transaction.v1.Markets", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for IbcRelayerChange {
+impl serde::Serialize for MarketsChange {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -1808,38 +3311,43 @@ impl serde::Serialize for IbcRelayerChange {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.value.is_some() {
+        if self.action.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("astria.protocol.transaction.v1.IbcRelayerChange", len)?;
-        if let Some(v) = self.value.as_ref() {
+        let mut struct_ser = serializer.serialize_struct("astria.protocol.transaction.v1.MarketsChange", len)?;
+        if let Some(v) = self.action.as_ref() {
             match v {
-                ibc_relayer_change::Value::Addition(v) => {
-                    struct_ser.serialize_field("addition", v)?;
+                markets_change::Action::Creation(v) => {
+                    struct_ser.serialize_field("creation", v)?;
                 }
-                ibc_relayer_change::Value::Removal(v) => {
+                markets_change::Action::Removal(v) => {
                     struct_ser.serialize_field("removal", v)?;
                 }
+                markets_change::Action::Update(v) => {
+                    struct_ser.serialize_field("update", v)?;
+                }
             }
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for IbcRelayerChange {
+impl<'de> serde::Deserialize<'de> for MarketsChange {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "addition",
+            "creation",
             "removal",
+            "update",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Addition,
+            Creation,
             Removal,
+            Update,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -1861,8 +3369,9 @@ impl<'de> serde::Deserialize<'de> for IbcRelayerChange {
                         E: serde::de::Error,
                     {
                         match value {
-                            "addition" => Ok(GeneratedField::Addition),
+                            "creation" => Ok(GeneratedField::Creation),
                             "removal" => Ok(GeneratedField::Removal),
+                            "update" => Ok(GeneratedField::Update),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -1872,44 +3381,51 @@ impl<'de> serde::Deserialize<'de> for IbcRelayerChange {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = IbcRelayerChange;
+            type Value = MarketsChange;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct astria.protocol.transaction.v1.IbcRelayerChange")
+                formatter.write_str("struct astria.protocol.transaction.v1.MarketsChange")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<IbcRelayerChange, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<MarketsChange, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut value__ = None;
+                let mut action__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Addition => {
-                            if value__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("addition"));
+                        GeneratedField::Creation => {
+                            if action__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("creation"));
                             }
-                            value__ = map_.next_value::<::std::option::Option<_>>()?.map(ibc_relayer_change::Value::Addition)
+                            action__ = map_.next_value::<::std::option::Option<_>>()?.map(markets_change::Action::Creation)
 ;
                         }
                         GeneratedField::Removal => {
-                            if value__.is_some() {
+                            if action__.is_some() {
                                 return Err(serde::de::Error::duplicate_field("removal"));
                             }
-                            value__ = map_.next_value::<::std::option::Option<_>>()?.map(ibc_relayer_change::Value::Removal)
+                            action__ = map_.next_value::<::std::option::Option<_>>()?.map(markets_change::Action::Removal)
+;
+                        }
+                        GeneratedField::Update => {
+                            if action__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("update"));
+                            }
+                            action__ = map_.next_value::<::std::option::Option<_>>()?.map(markets_change::Action::Update)
 ;
                         }
                     }
                 }
-                Ok(IbcRelayerChange {
-                    value: value__,
+                Ok(MarketsChange {
+                    action: action__,
                 })
             }
         }
-        deserializer.deserialize_struct("astria.protocol.transaction.v1.IbcRelayerChange", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("astria.protocol.transaction.v1.MarketsChange", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for IbcSudoChange {
+impl serde::Serialize for MemberSignature {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -1917,30 +3433,42 @@ impl serde::Serialize for IbcSudoChange {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.new_address.is_some() {
+        if !self.public_key.is_empty() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("astria.protocol.transaction.v1.IbcSudoChange", len)?;
-        if let Some(v) = self.new_address.as_ref() {
-            struct_ser.serialize_field("newAddress", v)?;
+        if !self.signature.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("astria.protocol.transaction.v1.MemberSignature", len)?;
+        if !self.public_key.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("publicKey", pbjson::private::base64::encode(&self.public_key).as_str())?;
+        }
+        if !self.signature.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("signature", pbjson::private::base64::encode(&self.signature).as_str())?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for IbcSudoChange {
+impl<'de> serde::Deserialize<'de> for MemberSignature {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "new_address",
-            "newAddress",
+            "public_key",
+            "publicKey",
+            "signature",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            NewAddress,
+            PublicKey,
+            Signature,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -1962,7 +3490,8 @@ impl<'de> serde::Deserialize<'de> for IbcSudoChange {
                         E: serde::de::Error,
                     {
                         match value {
-                            "newAddress" | "new_address" => Ok(GeneratedField::NewAddress),
+                            "publicKey" | "public_key" => Ok(GeneratedField::PublicKey),
+                            "signature" => Ok(GeneratedField::Signature),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -1972,156 +3501,88 @@ impl<'de> serde::Deserialize<'de> for IbcSudoChange {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = IbcSudoChange;
+            type Value = MemberSignature;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct astria.protocol.transaction.v1.IbcSudoChange")
+                formatter.write_str("struct astria.protocol.transaction.v1.MemberSignature")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<IbcSudoChange, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<MemberSignature, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut new_address__ = None;
+                let mut public_key__ = None;
+                let mut signature__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::NewAddress => {
-                            if new_address__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("newAddress"));
+                        GeneratedField::PublicKey => {
+                            if public_key__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("publicKey"));
                             }
-                            new_address__ = map_.next_value()?;
+                            public_key__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Signature => {
+                            if signature__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("signature"));
+                            }
+                            signature__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
                         }
                     }
                 }
-                Ok(IbcSudoChange {
-                    new_address: new_address__,
+                Ok(MemberSignature {
+                    public_key: public_key__.unwrap_or_default(),
+                    signature: signature__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("astria.protocol.transaction.v1.IbcSudoChange", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("astria.protocol.transaction.v1.MemberSignature", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for Ics20Withdrawal {
+impl serde::Serialize for MultisigSignatures {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
-        S: serde::Serializer,
-    {
-        use serde::ser::SerializeStruct;
-        let mut len = 0;
-        if self.amount.is_some() {
-            len += 1;
-        }
-        if !self.denom.is_empty() {
-            len += 1;
-        }
-        if !self.destination_chain_address.is_empty() {
-            len += 1;
-        }
-        if self.return_address.is_some() {
-            len += 1;
-        }
-        if self.timeout_height.is_some() {
-            len += 1;
-        }
-        if self.timeout_time != 0 {
-            len += 1;
-        }
-        if !self.source_channel.is_empty() {
-            len += 1;
-        }
-        if !self.fee_asset.is_empty() {
-            len += 1;
-        }
-        if !self.memo.is_empty() {
-            len += 1;
-        }
-        if self.bridge_address.is_some() {
-            len += 1;
-        }
-        if self.use_compat_address {
-            len += 1;
-        }
-        let mut struct_ser = serializer.serialize_struct("astria.protocol.transaction.v1.Ics20Withdrawal", len)?;
-        if let Some(v) = self.amount.as_ref() {
-            struct_ser.serialize_field("amount", v)?;
-        }
-        if !self.denom.is_empty() {
-            struct_ser.serialize_field("denom", &self.denom)?;
-        }
-        if !self.destination_chain_address.is_empty() {
-            struct_ser.serialize_field("destinationChainAddress", &self.destination_chain_address)?;
-        }
-        if let Some(v) = self.return_address.as_ref() {
-            struct_ser.serialize_field("returnAddress", v)?;
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.address.is_empty() {
+            len += 1;
         }
-        if let Some(v) = self.timeout_height.as_ref() {
-            struct_ser.serialize_field("timeoutHeight", v)?;
+        if !self.signatures.is_empty() {
+            len += 1;
         }
-        if self.timeout_time != 0 {
+        let mut struct_ser = serializer.serialize_struct("astria.protocol.transaction.v1.MultisigSignatures", len)?;
+        if !self.address.is_empty() {
             #[allow(clippy::needless_borrow)]
             #[allow(clippy::needless_borrows_for_generic_args)]
-            struct_ser.serialize_field("timeoutTime", ToString::to_string(&self.timeout_time).as_str())?;
-        }
-        if !self.source_channel.is_empty() {
-            struct_ser.serialize_field("sourceChannel", &self.source_channel)?;
-        }
-        if !self.fee_asset.is_empty() {
-            struct_ser.serialize_field("feeAsset", &self.fee_asset)?;
+            struct_ser.serialize_field("address", pbjson::private::base64::encode(&self.address).as_str())?;
         }
-        if !self.memo.is_empty() {
-            struct_ser.serialize_field("memo", &self.memo)?;
-        }
-        if let Some(v) = self.bridge_address.as_ref() {
-            struct_ser.serialize_field("bridgeAddress", v)?;
-        }
-        if self.use_compat_address {
-            struct_ser.serialize_field("useCompatAddress", &self.use_compat_address)?;
+        if !self.signatures.is_empty() {
+            struct_ser.serialize_field("signatures", &self.signatures)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for Ics20Withdrawal {
+impl<'de> serde::Deserialize<'de> for MultisigSignatures {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "amount",
-            "denom",
-            "destination_chain_address",
-            "destinationChainAddress",
-            "return_address",
-            "returnAddress",
-            "timeout_height",
-            "timeoutHeight",
-            "timeout_time",
-            "timeoutTime",
-            "source_channel",
-            "sourceChannel",
-            "fee_asset",
-            "feeAsset",
-            "memo",
-            "bridge_address",
-            "bridgeAddress",
-            "use_compat_address",
-            "useCompatAddress",
+            "address",
+            "signatures",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Amount,
-            Denom,
-            DestinationChainAddress,
-            ReturnAddress,
-            TimeoutHeight,
-            TimeoutTime,
-            SourceChannel,
-            FeeAsset,
-            Memo,
-            BridgeAddress,
-            UseCompatAddress,
+            Address,
+            Signatures,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -2143,17 +3604,8 @@ impl<'de> serde::Deserialize<'de> for Ics20Withdrawal {
                         E: serde::de::Error,
                     {
                         match value {
-                            "amount" => Ok(GeneratedField::Amount),
-                            "denom" => Ok(GeneratedField::Denom),
-                            "destinationChainAddress" | "destination_chain_address" => Ok(GeneratedField::DestinationChainAddress),
-                            "returnAddress" | "return_address" => Ok(GeneratedField::ReturnAddress),
-                            "timeoutHeight" | "timeout_height" => Ok(GeneratedField::TimeoutHeight),
-                            "timeoutTime" | "timeout_time" => Ok(GeneratedField::TimeoutTime),
-                            "sourceChannel" | "source_channel" => Ok(GeneratedField::SourceChannel),
-                            "feeAsset" | "fee_asset" => Ok(GeneratedField::FeeAsset),
-                            "memo" => Ok(GeneratedField::Memo),
-                            "bridgeAddress" | "bridge_address" => Ok(GeneratedField::BridgeAddress),
-                            "useCompatAddress" | "use_compat_address" => Ok(GeneratedField::UseCompatAddress),
+                            "address" => Ok(GeneratedField::Address),
+                            "signatures" => Ok(GeneratedField::Signatures),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -2163,118 +3615,46 @@ impl<'de> serde::Deserialize<'de> for Ics20Withdrawal {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = Ics20Withdrawal;
+            type Value = MultisigSignatures;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct astria.protocol.transaction.v1.Ics20Withdrawal")
+                formatter.write_str("struct astria.protocol.transaction.v1.MultisigSignatures")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Ics20Withdrawal, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<MultisigSignatures, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut amount__ = None;
-                let mut denom__ = None;
-                let mut destination_chain_address__ = None;
-                let mut return_address__ = None;
-                let mut timeout_height__ = None;
-                let mut timeout_time__ = None;
-                let mut source_channel__ = None;
-                let mut fee_asset__ = None;
-                let mut memo__ = None;
-                let mut bridge_address__ = None;
-                let mut use_compat_address__ = None;
+                let mut address__ = None;
+                let mut signatures__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Amount => {
-                            if amount__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("amount"));
-                            }
-                            amount__ = map_.next_value()?;
-                        }
-                        GeneratedField::Denom => {
-                            if denom__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("denom"));
-                            }
-                            denom__ = Some(map_.next_value()?);
-                        }
-                        GeneratedField::DestinationChainAddress => {
-                            if destination_chain_address__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("destinationChainAddress"));
-                            }
-                            destination_chain_address__ = Some(map_.next_value()?);
-                        }
-                        GeneratedField::ReturnAddress => {
-                            if return_address__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("returnAddress"));
-                            }
-                            return_address__ = map_.next_value()?;
-                        }
-                        GeneratedField::TimeoutHeight => {
-                            if timeout_height__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("timeoutHeight"));
-                            }
-                            timeout_height__ = map_.next_value()?;
-                        }
-                        GeneratedField::TimeoutTime => {
-                            if timeout_time__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("timeoutTime"));
+                        GeneratedField::Address => {
+                            if address__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("address"));
                             }
-                            timeout_time__ = 
-                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            address__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
                             ;
                         }
-                        GeneratedField::SourceChannel => {
-                            if source_channel__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("sourceChannel"));
-                            }
-                            source_channel__ = Some(map_.next_value()?);
-                        }
-                        GeneratedField::FeeAsset => {
-                            if fee_asset__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("feeAsset"));
-                            }
-                            fee_asset__ = Some(map_.next_value()?);
-                        }
-                        GeneratedField::Memo => {
-                            if memo__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("memo"));
-                            }
-                            memo__ = Some(map_.next_value()?);
-                        }
-                        GeneratedField::BridgeAddress => {
-                            if bridge_address__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("bridgeAddress"));
-                            }
-                            bridge_address__ = map_.next_value()?;
-                        }
-                        GeneratedField::UseCompatAddress => {
-                            if use_compat_address__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("useCompatAddress"));
+                        GeneratedField::Signatures => {
+                            if signatures__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("signatures"));
                             }
-                            use_compat_address__ = Some(map_.next_value()?);
+                            signatures__ = Some(map_.next_value()?);
                         }
                     }
                 }
-                Ok(Ics20Withdrawal {
-                    amount: amount__,
-                    denom: denom__.unwrap_or_default(),
-                    destination_chain_address: destination_chain_address__.unwrap_or_default(),
-                    return_address: return_address__,
-                    timeout_height: timeout_height__,
-                    timeout_time: timeout_time__.unwrap_or_default(),
-                    source_channel: source_channel__.unwrap_or_default(),
-                    fee_asset: fee_asset__.unwrap_or_default(),
-                    memo: memo__.unwrap_or_default(),
-                    bridge_address: bridge_address__,
-                    use_compat_address: use_compat_address__.unwrap_or_default(),
+                Ok(MultisigSignatures {
+                    address: address__.unwrap_or_default(),
+                    signatures: signatures__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("astria.protocol.transaction.v1.Ics20Withdrawal", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("astria.protocol.transaction.v1.MultisigSignatures", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for InitBridgeAccount {
+impl serde::Serialize for RecoverIbcClient {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -2282,65 +3662,39 @@ impl serde::Serialize for InitBridgeAccount {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.rollup_id.is_some() {
-            len += 1;
-        }
-        if !self.asset.is_empty() {
-            len += 1;
-        }
-        if !self.fee_asset.is_empty() {
-            len += 1;
-        }
-        if self.sudo_address.is_some() {
+        if !self.client_id.is_empty() {
             len += 1;
         }
-        if self.withdrawer_address.is_some() {
+        if !self.replacement_client_id.is_empty() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("astria.protocol.transaction.v1.InitBridgeAccount", len)?;
-        if let Some(v) = self.rollup_id.as_ref() {
-            struct_ser.serialize_field("rollupId", v)?;
-        }
-        if !self.asset.is_empty() {
-            struct_ser.serialize_field("asset", &self.asset)?;
-        }
-        if !self.fee_asset.is_empty() {
-            struct_ser.serialize_field("feeAsset", &self.fee_asset)?;
-        }
-        if let Some(v) = self.sudo_address.as_ref() {
-            struct_ser.serialize_field("sudoAddress", v)?;
+        let mut struct_ser = serializer.serialize_struct("astria.protocol.transaction.v1.RecoverIbcClient", len)?;
+        if !self.client_id.is_empty() {
+            struct_ser.serialize_field("clientId", &self.client_id)?;
         }
-        if let Some(v) = self.withdrawer_address.as_ref() {
-            struct_ser.serialize_field("withdrawerAddress", v)?;
+        if !self.replacement_client_id.is_empty() {
+            struct_ser.serialize_field("replacementClientId", &self.replacement_client_id)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for InitBridgeAccount {
+impl<'de> serde::Deserialize<'de> for RecoverIbcClient {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "rollup_id",
-            "rollupId",
-            "asset",
-            "fee_asset",
-            "feeAsset",
-            "sudo_address",
-            "sudoAddress",
-            "withdrawer_address",
-            "withdrawerAddress",
+            "client_id",
+            "clientId",
+            "replacement_client_id",
+            "replacementClientId",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            RollupId,
-            Asset,
-            FeeAsset,
-            SudoAddress,
-            WithdrawerAddress,
+            ClientId,
+            ReplacementClientId,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -2362,11 +3716,8 @@ impl<'de> serde::Deserialize<'de> for InitBridgeAccount {
                         E: serde::de::Error,
                     {
                         match value {
-                            "rollupId" | "rollup_id" => Ok(GeneratedField::RollupId),
-                            "asset" => Ok(GeneratedField::Asset),
-                            "feeAsset" | "fee_asset" => Ok(GeneratedField::FeeAsset),
-                            "sudoAddress" | "sudo_address" => Ok(GeneratedField::SudoAddress),
-                            "withdrawerAddress" | "withdrawer_address" => Ok(GeneratedField::WithdrawerAddress),
+                            "clientId" | "client_id" => Ok(GeneratedField::ClientId),
+                            "replacementClientId" | "replacement_client_id" => Ok(GeneratedField::ReplacementClientId),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -2376,68 +3727,44 @@ impl<'de> serde::Deserialize<'de> for InitBridgeAccount {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = InitBridgeAccount;
+            type Value = RecoverIbcClient;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct astria.protocol.transaction.v1.InitBridgeAccount")
+                formatter.write_str("struct astria.protocol.transaction.v1.RecoverIbcClient")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<InitBridgeAccount, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<RecoverIbcClient, V::Error>
                 where
-                    V: serde::de::MapAccess<'de>,
-            {
-                let mut rollup_id__ = None;
-                let mut asset__ = None;
-                let mut fee_asset__ = None;
-                let mut sudo_address__ = None;
-                let mut withdrawer_address__ = None;
-                while let Some(k) = map_.next_key()? {
-                    match k {
-                        GeneratedField::RollupId => {
-                            if rollup_id__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("rollupId"));
-                            }
-                            rollup_id__ = map_.next_value()?;
-                        }
-                        GeneratedField::Asset => {
-                            if asset__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("asset"));
-                            }
-                            asset__ = Some(map_.next_value()?);
-                        }
-                        GeneratedField::FeeAsset => {
-                            if fee_asset__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("feeAsset"));
-                            }
-                            fee_asset__ = Some(map_.next_value()?);
-                        }
-                        GeneratedField::SudoAddress => {
-                            if sudo_address__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("sudoAddress"));
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut client_id__ = None;
+                let mut replacement_client_id__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::ClientId => {
+                            if client_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("clientId"));
                             }
-                            sudo_address__ = map_.next_value()?;
+                            client_id__ = Some(map_.next_value()?);
                         }
-                        GeneratedField::WithdrawerAddress => {
-                            if withdrawer_address__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("withdrawerAddress"));
+                        GeneratedField::ReplacementClientId => {
+                            if replacement_client_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("replacementClientId"));
                             }
-                            withdrawer_address__ = map_.next_value()?;
+                            replacement_client_id__ = Some(map_.next_value()?);
                         }
                     }
                 }
-                Ok(InitBridgeAccount {
-                    rollup_id: rollup_id__,
-                    asset: asset__.unwrap_or_default(),
-                    fee_asset: fee_asset__.unwrap_or_default(),
-                    sudo_address: sudo_address__,
-                    withdrawer_address: withdrawer_address__,
+                Ok(RecoverIbcClient {
+                    client_id: client_id__.unwrap_or_default(),
+                    replacement_client_id: replacement_client_id__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("astria.protocol.transaction.v1.InitBridgeAccount", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("astria.protocol.transaction.v1.RecoverIbcClient", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for Markets {
+impl serde::Serialize for RegisterMultisig {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -2445,29 +3772,46 @@ impl serde::Serialize for Markets {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if !self.markets.is_empty() {
+        if !self.members.is_empty() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("astria.protocol.transaction.v1.Markets", len)?;
-        if !self.markets.is_empty() {
-            struct_ser.serialize_field("markets", &self.markets)?;
+        if self.threshold != 0 {
+            len += 1;
+        }
+        if !self.fee_asset.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("astria.protocol.transaction.v1.RegisterMultisig", len)?;
+        if !self.members.is_empty() {
+            struct_ser.serialize_field("members", &self.members.iter().map(pbjson::private::base64::encode).collect::<Vec<_>>())?;
+        }
+        if self.threshold != 0 {
+            struct_ser.serialize_field("threshold", &self.threshold)?;
+        }
+        if !self.fee_asset.is_empty() {
+            struct_ser.serialize_field("feeAsset", &self.fee_asset)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for Markets {
+impl<'de> serde::Deserialize<'de> for RegisterMultisig {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "markets",
+            "members",
+            "threshold",
+            "fee_asset",
+            "feeAsset",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Markets,
+            Members,
+            Threshold,
+            FeeAsset,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -2489,7 +3833,9 @@ impl<'de> serde::Deserialize<'de> for Markets {
                         E: serde::de::Error,
                     {
                         match value {
-                            "markets" => Ok(GeneratedField::Markets),
+                            "members" => Ok(GeneratedField::Members),
+                            "threshold" => Ok(GeneratedField::Threshold),
+                            "feeAsset" | "fee_asset" => Ok(GeneratedField::FeeAsset),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -2499,36 +3845,57 @@ impl<'de> serde::Deserialize<'de> for Markets {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = Markets;
+            type Value = RegisterMultisig;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct astria.protocol.transaction.v1.Markets")
+                formatter.write_str("struct astria.protocol.transaction.v1.RegisterMultisig")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Markets, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<RegisterMultisig, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut markets__ = None;
+                let mut members__ = None;
+                let mut threshold__ = None;
+                let mut fee_asset__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Markets => {
-                            if markets__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("markets"));
+                        GeneratedField::Members => {
+                            if members__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("members"));
                             }
-                            markets__ = Some(map_.next_value()?);
+                            members__ = 
+                                Some(map_.next_value::<Vec<::pbjson::private::BytesDeserialize<_>>>()?
+                                    .into_iter().map(|x| x.0).collect())
+                            ;
+                        }
+                        GeneratedField::Threshold => {
+                            if threshold__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("threshold"));
+                            }
+                            threshold__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::FeeAsset => {
+                            if fee_asset__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("feeAsset"));
+                            }
+                            fee_asset__ = Some(map_.next_value()?);
                         }
                     }
                 }
-                Ok(Markets {
-                    markets: markets__.unwrap_or_default(),
+                Ok(RegisterMultisig {
+                    members: members__.unwrap_or_default(),
+                    threshold: threshold__.unwrap_or_default(),
+                    fee_asset: fee_asset__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("astria.protocol.transaction.v1.Markets", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("astria.protocol.transaction.v1.RegisterMultisig", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for MarketsChange {
+impl serde::Serialize for RollupDataSubmission {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -2536,43 +3903,49 @@ impl serde::Serialize for MarketsChange {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.action.is_some() {
+        if self.rollup_id.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("astria.protocol.transaction.v1.MarketsChange", len)?;
-        if let Some(v) = self.action.as_ref() {
-            match v {
-                markets_change::Action::Creation(v) => {
-                    struct_ser.serialize_field("creation", v)?;
-                }
-                markets_change::Action::Removal(v) => {
-                    struct_ser.serialize_field("removal", v)?;
-                }
-                markets_change::Action::Update(v) => {
-                    struct_ser.serialize_field("update", v)?;
-                }
-            }
+        if !self.data.is_empty() {
+            len += 1;
+        }
+        if !self.fee_asset.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("astria.protocol.transaction.v1.RollupDataSubmission", len)?;
+        if let Some(v) = self.rollup_id.as_ref() {
+            struct_ser.serialize_field("rollupId", v)?;
+        }
+        if !self.data.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("data", pbjson::private::base64::encode(&self.data).as_str())?;
+        }
+        if !self.fee_asset.is_empty() {
+            struct_ser.serialize_field("feeAsset", &self.fee_asset)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for MarketsChange {
+impl<'de> serde::Deserialize<'de> for RollupDataSubmission {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "creation",
-            "removal",
-            "update",
+            "rollup_id",
+            "rollupId",
+            "data",
+            "fee_asset",
+            "feeAsset",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Creation,
-            Removal,
-            Update,
+            RollupId,
+            Data,
+            FeeAsset,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -2594,9 +3967,9 @@ impl<'de> serde::Deserialize<'de> for MarketsChange {
                         E: serde::de::Error,
                     {
                         match value {
-                            "creation" => Ok(GeneratedField::Creation),
-                            "removal" => Ok(GeneratedField::Removal),
-                            "update" => Ok(GeneratedField::Update),
+                            "rollupId" | "rollup_id" => Ok(GeneratedField::RollupId),
+                            "data" => Ok(GeneratedField::Data),
+                            "feeAsset" | "fee_asset" => Ok(GeneratedField::FeeAsset),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -2606,51 +3979,54 @@ impl<'de> serde::Deserialize<'de> for MarketsChange {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = MarketsChange;
+            type Value = RollupDataSubmission;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct astria.protocol.transaction.v1.MarketsChange")
+                formatter.write_str("struct astria.protocol.transaction.v1.RollupDataSubmission")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<MarketsChange, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<RollupDataSubmission, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut action__ = None;
+                let mut rollup_id__ = None;
+                let mut data__ = None;
+                let mut fee_asset__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Creation => {
-                            if action__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("creation"));
+                        GeneratedField::RollupId => {
+                            if rollup_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("rollupId"));
                             }
-                            action__ = map_.next_value::<::std::option::Option<_>>()?.map(markets_change::Action::Creation)
-;
+                            rollup_id__ = map_.next_value()?;
                         }
-                        GeneratedField::Removal => {
-                            if action__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("removal"));
+                        GeneratedField::Data => {
+                            if data__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("data"));
                             }
-                            action__ = map_.next_value::<::std::option::Option<_>>()?.map(markets_change::Action::Removal)
-;
+                            data__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
                         }
-                        GeneratedField::Update => {
-                            if action__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("update"));
+                        GeneratedField::FeeAsset => {
+                            if fee_asset__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("feeAsset"));
                             }
-                            action__ = map_.next_value::<::std::option::Option<_>>()?.map(markets_change::Action::Update)
-;
+                            fee_asset__ = Some(map_.next_value()?);
                         }
                     }
                 }
-                Ok(MarketsChange {
-                    action: action__,
+                Ok(RollupDataSubmission {
+                    rollup_id: rollup_id__,
+                    data: data__.unwrap_or_default(),
+                    fee_asset: fee_asset__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("astria.protocol.transaction.v1.MarketsChange", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("astria.protocol.transaction.v1.RollupDataSubmission", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for RecoverIbcClient {
+impl serde::Serialize for SubmitProposal {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -2658,39 +4034,38 @@ impl serde::Serialize for RecoverIbcClient {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if !self.client_id.is_empty() {
+        if self.action.is_some() {
             len += 1;
         }
-        if !self.replacement_client_id.is_empty() {
+        if !self.fee_asset.is_empty() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("astria.protocol.transaction.v1.RecoverIbcClient", len)?;
-        if !self.client_id.is_empty() {
-            struct_ser.serialize_field("clientId", &self.client_id)?;
+        let mut struct_ser = serializer.serialize_struct("astria.protocol.transaction.v1.SubmitProposal", len)?;
+        if let Some(v) = self.action.as_ref() {
+            struct_ser.serialize_field("action", v)?;
         }
-        if !self.replacement_client_id.is_empty() {
-            struct_ser.serialize_field("replacementClientId", &self.replacement_client_id)?;
+        if !self.fee_asset.is_empty() {
+            struct_ser.serialize_field("feeAsset", &self.fee_asset)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for RecoverIbcClient {
+impl<'de> serde::Deserialize<'de> for SubmitProposal {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "client_id",
-            "clientId",
-            "replacement_client_id",
-            "replacementClientId",
+            "action",
+            "fee_asset",
+            "feeAsset",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            ClientId,
-            ReplacementClientId,
+            Action,
+            FeeAsset,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -2712,8 +4087,8 @@ impl<'de> serde::Deserialize<'de> for RecoverIbcClient {
                         E: serde::de::Error,
                     {
                         match value {
-                            "clientId" | "client_id" => Ok(GeneratedField::ClientId),
-                            "replacementClientId" | "replacement_client_id" => Ok(GeneratedField::ReplacementClientId),
+                            "action" => Ok(GeneratedField::Action),
+                            "feeAsset" | "fee_asset" => Ok(GeneratedField::FeeAsset),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -2723,44 +4098,44 @@ impl<'de> serde::Deserialize<'de> for RecoverIbcClient {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = RecoverIbcClient;
+            type Value = SubmitProposal;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct astria.protocol.transaction.v1.RecoverIbcClient")
+                formatter.write_str("struct astria.protocol.transaction.v1.SubmitProposal")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<RecoverIbcClient, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SubmitProposal, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut client_id__ = None;
-                let mut replacement_client_id__ = None;
+                let mut action__ = None;
+                let mut fee_asset__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::ClientId => {
-                            if client_id__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("clientId"));
+                        GeneratedField::Action => {
+                            if action__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("action"));
                             }
-                            client_id__ = Some(map_.next_value()?);
+                            action__ = map_.next_value()?;
                         }
-                        GeneratedField::ReplacementClientId => {
-                            if replacement_client_id__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("replacementClientId"));
+                        GeneratedField::FeeAsset => {
+                            if fee_asset__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("feeAsset"));
                             }
-                            replacement_client_id__ = Some(map_.next_value()?);
+                            fee_asset__ = Some(map_.next_value()?);
                         }
                     }
                 }
-                Ok(RecoverIbcClient {
-                    client_id: client_id__.unwrap_or_default(),
-                    replacement_client_id: replacement_client_id__.unwrap_or_default(),
+                Ok(SubmitProposal {
+                    action: action__,
+                    fee_asset: fee_asset__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("astria.protocol.transaction.v1.RecoverIbcClient", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("astria.protocol.transaction.v1.SubmitProposal", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for RollupDataSubmission {
+impl serde::Serialize for SudoAddressChange {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -2768,49 +4143,30 @@ impl serde::Serialize for RollupDataSubmission {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.rollup_id.is_some() {
-            len += 1;
-        }
-        if !self.data.is_empty() {
-            len += 1;
-        }
-        if !self.fee_asset.is_empty() {
+        if self.new_address.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("astria.protocol.transaction.v1.RollupDataSubmission", len)?;
-        if let Some(v) = self.rollup_id.as_ref() {
-            struct_ser.serialize_field("rollupId", v)?;
-        }
-        if !self.data.is_empty() {
-            #[allow(clippy::needless_borrow)]
-            #[allow(clippy::needless_borrows_for_generic_args)]
-            struct_ser.serialize_field("data", pbjson::private::base64::encode(&self.data).as_str())?;
-        }
-        if !self.fee_asset.is_empty() {
-            struct_ser.serialize_field("feeAsset", &self.fee_asset)?;
+        let mut struct_ser = serializer.serialize_struct("astria.protocol.transaction.v1.SudoAddressChange", len)?;
+        if let Some(v) = self.new_address.as_ref() {
+            struct_ser.serialize_field("newAddress", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for RollupDataSubmission {
+impl<'de> serde::Deserialize<'de> for SudoAddressChange {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "rollup_id",
-            "rollupId",
-            "data",
-            "fee_asset",
-            "feeAsset",
+            "new_address",
+            "newAddress",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            RollupId,
-            Data,
-            FeeAsset,
+            NewAddress,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -2832,9 +4188,7 @@ impl<'de> serde::Deserialize<'de> for RollupDataSubmission {
                         E: serde::de::Error,
                     {
                         match value {
-                            "rollupId" | "rollup_id" => Ok(GeneratedField::RollupId),
-                            "data" => Ok(GeneratedField::Data),
-                            "feeAsset" | "fee_asset" => Ok(GeneratedField::FeeAsset),
+                            "newAddress" | "new_address" => Ok(GeneratedField::NewAddress),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -2844,54 +4198,36 @@ impl<'de> serde::Deserialize<'de> for RollupDataSubmission {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = RollupDataSubmission;
+            type Value = SudoAddressChange;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct astria.protocol.transaction.v1.RollupDataSubmission")
+                formatter.write_str("struct astria.protocol.transaction.v1.SudoAddressChange")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<RollupDataSubmission, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SudoAddressChange, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut rollup_id__ = None;
-                let mut data__ = None;
-                let mut fee_asset__ = None;
+                let mut new_address__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::RollupId => {
-                            if rollup_id__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("rollupId"));
-                            }
-                            rollup_id__ = map_.next_value()?;
-                        }
-                        GeneratedField::Data => {
-                            if data__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("data"));
-                            }
-                            data__ = 
-                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
-                            ;
-                        }
-                        GeneratedField::FeeAsset => {
-                            if fee_asset__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("feeAsset"));
+                        GeneratedField::NewAddress => {
+                            if new_address__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("newAddress"));
                             }
-                            fee_asset__ = Some(map_.next_value()?);
+                            new_address__ = map_.next_value()?;
                         }
                     }
                 }
-                Ok(RollupDataSubmission {
-                    rollup_id: rollup_id__,
-                    data: data__.unwrap_or_default(),
-                    fee_asset: fee_asset__.unwrap_or_default(),
+                Ok(SudoAddressChange {
+                    new_address: new_address__,
                 })
             }
         }
-        deserializer.deserialize_struct("astria.protocol.transaction.v1.RollupDataSubmission", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("astria.protocol.transaction.v1.SudoAddressChange", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for SudoAddressChange {
+impl serde::Serialize for TimelockedTransfer {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -2899,30 +4235,73 @@ impl serde::Serialize for SudoAddressChange {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.new_address.is_some() {
+        if self.to.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("astria.protocol.transaction.v1.SudoAddressChange", len)?;
-        if let Some(v) = self.new_address.as_ref() {
-            struct_ser.serialize_field("newAddress", v)?;
+        if self.amount.is_some() {
+            len += 1;
+        }
+        if !self.asset.is_empty() {
+            len += 1;
+        }
+        if !self.fee_asset.is_empty() {
+            len += 1;
+        }
+        if self.unlock.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("astria.protocol.transaction.v1.TimelockedTransfer", len)?;
+        if let Some(v) = self.to.as_ref() {
+            struct_ser.serialize_field("to", v)?;
+        }
+        if let Some(v) = self.amount.as_ref() {
+            struct_ser.serialize_field("amount", v)?;
+        }
+        if !self.asset.is_empty() {
+            struct_ser.serialize_field("asset", &self.asset)?;
+        }
+        if !self.fee_asset.is_empty() {
+            struct_ser.serialize_field("feeAsset", &self.fee_asset)?;
+        }
+        if let Some(v) = self.unlock.as_ref() {
+            match v {
+                timelocked_transfer::Unlock::Height(v) => {
+                    #[allow(clippy::needless_borrow)]
+                    #[allow(clippy::needless_borrows_for_generic_args)]
+                    struct_ser.serialize_field("height", ToString::to_string(&v).as_str())?;
+                }
+                timelocked_transfer::Unlock::Time(v) => {
+                    struct_ser.serialize_field("time", v)?;
+                }
+            }
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for SudoAddressChange {
+impl<'de> serde::Deserialize<'de> for TimelockedTransfer {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "new_address",
-            "newAddress",
+            "to",
+            "amount",
+            "asset",
+            "fee_asset",
+            "feeAsset",
+            "height",
+            "time",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            NewAddress,
+            To,
+            Amount,
+            Asset,
+            FeeAsset,
+            Height,
+            Time,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -2944,7 +4323,12 @@ impl<'de> serde::Deserialize<'de> for SudoAddressChange {
                         E: serde::de::Error,
                     {
                         match value {
-                            "newAddress" | "new_address" => Ok(GeneratedField::NewAddress),
+                            "to" => Ok(GeneratedField::To),
+                            "amount" => Ok(GeneratedField::Amount),
+                            "asset" => Ok(GeneratedField::Asset),
+                            "feeAsset" | "fee_asset" => Ok(GeneratedField::FeeAsset),
+                            "height" => Ok(GeneratedField::Height),
+                            "time" => Ok(GeneratedField::Time),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -2954,33 +4338,72 @@ impl<'de> serde::Deserialize<'de> for SudoAddressChange {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = SudoAddressChange;
+            type Value = TimelockedTransfer;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct astria.protocol.transaction.v1.SudoAddressChange")
+                formatter.write_str("struct astria.protocol.transaction.v1.TimelockedTransfer")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SudoAddressChange, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<TimelockedTransfer, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut new_address__ = None;
+                let mut to__ = None;
+                let mut amount__ = None;
+                let mut asset__ = None;
+                let mut fee_asset__ = None;
+                let mut unlock__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::NewAddress => {
-                            if new_address__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("newAddress"));
+                        GeneratedField::To => {
+                            if to__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("to"));
                             }
-                            new_address__ = map_.next_value()?;
+                            to__ = map_.next_value()?;
+                        }
+                        GeneratedField::Amount => {
+                            if amount__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("amount"));
+                            }
+                            amount__ = map_.next_value()?;
+                        }
+                        GeneratedField::Asset => {
+                            if asset__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("asset"));
+                            }
+                            asset__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::FeeAsset => {
+                            if fee_asset__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("feeAsset"));
+                            }
+                            fee_asset__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Height => {
+                            if unlock__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("height"));
+                            }
+                            unlock__ = map_.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>()?.map(|x| timelocked_transfer::Unlock::Height(x.0));
+                        }
+                        GeneratedField::Time => {
+                            if unlock__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("time"));
+                            }
+                            unlock__ = map_.next_value::<::std::option::Option<_>>()?.map(timelocked_transfer::Unlock::Time)
+;
                         }
                     }
                 }
-                Ok(SudoAddressChange {
-                    new_address: new_address__,
+                Ok(TimelockedTransfer {
+                    to: to__,
+                    amount: amount__,
+                    asset: asset__.unwrap_or_default(),
+                    fee_asset: fee_asset__.unwrap_or_default(),
+                    unlock: unlock__,
                 })
             }
         }
-        deserializer.deserialize_struct("astria.protocol.transaction.v1.SudoAddressChange", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("astria.protocol.transaction.v1.TimelockedTransfer", FIELDS, GeneratedVisitor)
     }
 }
 impl serde::Serialize for Transaction {
@@ -3000,6 +4423,9 @@ impl serde::Serialize for Transaction {
         if self.body.is_some() {
             len += 1;
         }
+        if self.multisig.is_some() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("astria.protocol.transaction.v1.Transaction", len)?;
         if !self.signature.is_empty() {
             #[allow(clippy::needless_borrow)]
@@ -3014,6 +4440,9 @@ impl serde::Serialize for Transaction {
         if let Some(v) = self.body.as_ref() {
             struct_ser.serialize_field("body", v)?;
         }
+        if let Some(v) = self.multisig.as_ref() {
+            struct_ser.serialize_field("multisig", v)?;
+        }
         struct_ser.end()
     }
 }
@@ -3028,6 +4457,7 @@ impl<'de> serde::Deserialize<'de> for Transaction {
             "public_key",
             "publicKey",
             "body",
+            "multisig",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -3035,6 +4465,7 @@ impl<'de> serde::Deserialize<'de> for Transaction {
             Signature,
             PublicKey,
             Body,
+            Multisig,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -3059,6 +4490,7 @@ impl<'de> serde::Deserialize<'de> for Transaction {
                             "signature" => Ok(GeneratedField::Signature),
                             "publicKey" | "public_key" => Ok(GeneratedField::PublicKey),
                             "body" => Ok(GeneratedField::Body),
+                            "multisig" => Ok(GeneratedField::Multisig),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -3081,6 +4513,7 @@ impl<'de> serde::Deserialize<'de> for Transaction {
                 let mut signature__ = None;
                 let mut public_key__ = None;
                 let mut body__ = None;
+                let mut multisig__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::Signature => {
@@ -3105,12 +4538,19 @@ impl<'de> serde::Deserialize<'de> for Transaction {
                             }
                             body__ = map_.next_value()?;
                         }
+                        GeneratedField::Multisig => {
+                            if multisig__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("multisig"));
+                            }
+                            multisig__ = map_.next_value()?;
+                        }
                     }
                 }
                 Ok(Transaction {
                     signature: signature__.unwrap_or_default(),
                     public_key: public_key__.unwrap_or_default(),
                     body: body__,
+                    multisig: multisig__,
                 })
             }
         }
@@ -3609,3 +5049,134 @@ impl<'de> serde::Deserialize<'de> for ValidatorUpdate {
         deserializer.deserialize_struct("astria.protocol.transaction.v1.ValidatorUpdate", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for VoteOnProposal {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.proposal_id != 0 {
+            len += 1;
+        }
+        if self.approve {
+            len += 1;
+        }
+        if !self.fee_asset.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("astria.protocol.transaction.v1.VoteOnProposal", len)?;
+        if self.proposal_id != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("proposalId", ToString::to_string(&self.proposal_id).as_str())?;
+        }
+        if self.approve {
+            struct_ser.serialize_field("approve", &self.approve)?;
+        }
+        if !self.fee_asset.is_empty() {
+            struct_ser.serialize_field("feeAsset", &self.fee_asset)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for VoteOnProposal {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "proposal_id",
+            "proposalId",
+            "approve",
+            "fee_asset",
+            "feeAsset",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ProposalId,
+            Approve,
+            FeeAsset,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "proposalId" | "proposal_id" => Ok(GeneratedField::ProposalId),
+                            "approve" => Ok(GeneratedField::Approve),
+                            "feeAsset" | "fee_asset" => Ok(GeneratedField::FeeAsset),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = VoteOnProposal;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct astria.protocol.transaction.v1.VoteOnProposal")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<VoteOnProposal, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut proposal_id__ = None;
+                let mut approve__ = None;
+                let mut fee_asset__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::ProposalId => {
+                            if proposal_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("proposalId"));
+                            }
+                            proposal_id__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Approve => {
+                            if approve__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("approve"));
+                            }
+                            approve__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::FeeAsset => {
+                            if fee_asset__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("feeAsset"));
+                            }
+                            fee_asset__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(VoteOnProposal {
+                    proposal_id: proposal_id__.unwrap_or_default(),
+                    approve: approve__.unwrap_or_default(),
+                    fee_asset: fee_asset__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("astria.protocol.transaction.v1.VoteOnProposal", FIELDS, GeneratedVisitor)
+    }
+}
@@ -527,6 +527,26 @@ impl ::prost::Name for GetFilteredSequencerBlockRequest {
     }
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
+pub struct StreamFilteredSequencerBlocksRequest {
+    /// The height of the first block to stream. If set to 0 only blocks committed after
+    /// the request was received are streamed.
+    #[prost(uint64, tag = "1")]
+    pub start_height: u64,
+    /// The 32 bytes identifying a rollup. Usually the sha256 hash of a plain rollup name.
+    #[prost(message, repeated, tag = "2")]
+    pub rollup_ids: ::prost::alloc::vec::Vec<super::super::primitive::v1::RollupId>,
+}
+impl ::prost::Name for StreamFilteredSequencerBlocksRequest {
+    const NAME: &'static str = "StreamFilteredSequencerBlocksRequest";
+    const PACKAGE: &'static str = "astria.sequencerblock.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "astria.sequencerblock.v1.StreamFilteredSequencerBlocksRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/astria.sequencerblock.v1.StreamFilteredSequencerBlocksRequest".into()
+    }
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GetPendingNonceRequest {
     /// The account to retrieve the pending nonce for.
     #[prost(message, optional, tag = "1")]
@@ -798,6 +818,37 @@ pub mod sequencer_service_client {
                 );
             self.inner.unary(req, path, codec).await
         }
+        /// Given a set of rollup ids, streams a FilteredSequencerBlock for every block as it is
+        /// committed. Blocks starting at `start_height` are sent first to allow catching up.
+        pub async fn stream_filtered_sequencer_blocks(
+            &mut self,
+            request: impl tonic::IntoRequest<super::StreamFilteredSequencerBlocksRequest>,
+        ) -> std::result::Result<
+            tonic::Response<tonic::codec::Streaming<super::FilteredSequencerBlock>>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/astria.sequencerblock.v1.SequencerService/StreamFilteredSequencerBlocks",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "astria.sequencerblock.v1.SequencerService",
+                        "StreamFilteredSequencerBlocks",
+                    ),
+                );
+            self.inner.server_streaming(req, path, codec).await
+        }
         /// Returns the pending nonce for the given account.
         pub async fn get_pending_nonce(
             &mut self,
@@ -918,6 +969,21 @@ pub mod sequencer_service_server {
             tonic::Response<super::FilteredSequencerBlock>,
             tonic::Status,
         >;
+        /// Server streaming response type for the StreamFilteredSequencerBlocks method.
+        type StreamFilteredSequencerBlocksStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<super::FilteredSequencerBlock, tonic::Status>,
+            >
+            + std::marker::Send
+            + 'static;
+        /// Given a set of rollup ids, streams a FilteredSequencerBlock for every block as it is
+        /// committed. Blocks starting at `start_height` are sent first to allow catching up.
+        async fn stream_filtered_sequencer_blocks(
+            self: std::sync::Arc<Self>,
+            request: tonic::Request<super::StreamFilteredSequencerBlocksRequest>,
+        ) -> std::result::Result<
+            tonic::Response<Self::StreamFilteredSequencerBlocksStream>,
+            tonic::Status,
+        >;
         /// Returns the pending nonce for the given account.
         async fn get_pending_nonce(
             self: std::sync::Arc<Self>,
@@ -1117,6 +1183,61 @@ pub mod sequencer_service_server {
                     };
                     Box::pin(fut)
                 }
+                "/astria.sequencerblock.v1.SequencerService/StreamFilteredSequencerBlocks" => {
+                    #[allow(non_camel_case_types)]
+                    struct StreamFilteredSequencerBlocksSvc<T: SequencerService>(
+                        pub Arc<T>,
+                    );
+                    impl<
+                        T: SequencerService,
+                    > tonic::server::ServerStreamingService<
+                        super::StreamFilteredSequencerBlocksRequest,
+                    > for StreamFilteredSequencerBlocksSvc<T> {
+                        type Response = super::FilteredSequencerBlock;
+                        type ResponseStream = T::StreamFilteredSequencerBlocksStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::StreamFilteredSequencerBlocksRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as SequencerService>::stream_filtered_sequencer_blocks(
+                                        inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = StreamFilteredSequencerBlocksSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 "/astria.sequencerblock.v1.SequencerService/GetPendingNonce" => {
                     #[allow(non_camel_case_types)]
                     struct GetPendingNonceSvc<T: SequencerService>(pub Arc<T>);
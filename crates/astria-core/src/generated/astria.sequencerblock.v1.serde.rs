@@ -2557,6 +2557,120 @@ impl<'de> serde::Deserialize<'de> for SequencerBlockHeader {
         deserializer.deserialize_struct("astria.sequencerblock.v1.SequencerBlockHeader", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for StreamFilteredSequencerBlocksRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.start_height != 0 {
+            len += 1;
+        }
+        if !self.rollup_ids.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("astria.sequencerblock.v1.StreamFilteredSequencerBlocksRequest", len)?;
+        if self.start_height != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("startHeight", ToString::to_string(&self.start_height).as_str())?;
+        }
+        if !self.rollup_ids.is_empty() {
+            struct_ser.serialize_field("rollupIds", &self.rollup_ids)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for StreamFilteredSequencerBlocksRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "start_height",
+            "startHeight",
+            "rollup_ids",
+            "rollupIds",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            StartHeight,
+            RollupIds,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "startHeight" | "start_height" => Ok(GeneratedField::StartHeight),
+                            "rollupIds" | "rollup_ids" => Ok(GeneratedField::RollupIds),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = StreamFilteredSequencerBlocksRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct astria.sequencerblock.v1.StreamFilteredSequencerBlocksRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<StreamFilteredSequencerBlocksRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut start_height__ = None;
+                let mut rollup_ids__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::StartHeight => {
+                            if start_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("startHeight"));
+                            }
+                            start_height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::RollupIds => {
+                            if rollup_ids__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("rollupIds"));
+                            }
+                            rollup_ids__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(StreamFilteredSequencerBlocksRequest {
+                    start_height: start_height__.unwrap_or_default(),
+                    rollup_ids: rollup_ids__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("astria.sequencerblock.v1.StreamFilteredSequencerBlocksRequest", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for SubmittedMetadata {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -12,25 +12,36 @@ use prost::Name as _;
 
 use crate::{
     generated::astria::protocol::fees::v1 as raw,
-    primitive::v1::asset,
+    primitive::v1::{
+        asset,
+        Address,
+        AddressError,
+    },
     protocol::transaction::v1::action::{
         BridgeLock,
         BridgeSudoChange,
         BridgeTransfer,
         BridgeUnlock,
+        CancelTimelockedTransfer,
+        ClaimTimelockedTransfer,
         CurrencyPairsChange,
         FeeAssetChange,
         FeeChange,
+        FeeDistributionChange,
         IbcRelayerChange,
         IbcSudoChange,
         Ics20Withdrawal,
         InitBridgeAccount,
         MarketsChange,
         RecoverIbcClient,
+        RegisterMultisig,
         RollupDataSubmission,
+        SubmitProposal,
         SudoAddressChange,
+        TimelockedTransfer,
         Transfer,
         ValidatorUpdate,
+        VoteOnProposal,
     },
     Protobuf,
 };
@@ -116,6 +127,13 @@ impl_protobuf_for_fee_components!(
     FeeComponents<RecoverIbcClient> => raw::RecoverIbcClientFeeComponents,
     FeeComponents<CurrencyPairsChange> => raw::CurrencyPairsChangeFeeComponents,
     FeeComponents<MarketsChange> => raw::MarketsChangeFeeComponents,
+    FeeComponents<RegisterMultisig> => raw::RegisterMultisigFeeComponents,
+    FeeComponents<TimelockedTransfer> => raw::TimelockedTransferFeeComponents,
+    FeeComponents<ClaimTimelockedTransfer> => raw::ClaimTimelockedTransferFeeComponents,
+    FeeComponents<CancelTimelockedTransfer> => raw::CancelTimelockedTransferFeeComponents,
+    FeeComponents<SubmitProposal> => raw::SubmitProposalFeeComponents,
+    FeeComponents<VoteOnProposal> => raw::VoteOnProposalFeeComponents,
+    FeeComponents<FeeDistributionChange> => raw::FeeDistributionChangeFeeComponents,
 );
 
 pub struct FeeComponents<T: ?Sized> {
@@ -259,3 +277,134 @@ enum TransactionFeeResponseErrorKind {
     #[error("failed to parse asset denom in the `assets` field")]
     Asset(#[source] asset::ParseDenomError),
 }
+
+/// The sum of all shares of a [`FeeDistribution`].
+pub const FEE_DISTRIBUTION_TOTAL_BASIS_POINTS: u32 = 10_000;
+
+/// How the fees collected in a block are split at the end of the block.
+///
+/// All shares are given in basis points and add up to [`FEE_DISTRIBUTION_TOTAL_BASIS_POINTS`].
+/// Any remainder left by rounding goes to the block proposer.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FeeDistribution {
+    /// The share paid to the proposer of the block.
+    pub proposer_basis_points: u32,
+    /// The share paid to the validators, pro-rata to their voting power.
+    pub validators_basis_points: u32,
+    /// The share paid to `community_pool_address`.
+    pub community_pool_basis_points: u32,
+    /// The share that is burned.
+    pub burn_basis_points: u32,
+    /// The recipient of the community pool share. Always set if `community_pool_basis_points`
+    /// is not zero.
+    pub community_pool_address: Option<Address>,
+}
+
+impl FeeDistribution {
+    /// Returns a distribution paying all fees to the block proposer.
+    #[must_use]
+    pub fn proposer_only() -> Self {
+        Self {
+            proposer_basis_points: FEE_DISTRIBUTION_TOTAL_BASIS_POINTS,
+            validators_basis_points: 0,
+            community_pool_basis_points: 0,
+            burn_basis_points: 0,
+            community_pool_address: None,
+        }
+    }
+}
+
+impl Protobuf for FeeDistribution {
+    type Error = FeeDistributionError;
+    type Raw = raw::FeeDistribution;
+
+    fn try_from_raw_ref(raw: &Self::Raw) -> Result<Self, Self::Error> {
+        let Self::Raw {
+            proposer_basis_points,
+            validators_basis_points,
+            community_pool_basis_points,
+            burn_basis_points,
+            community_pool_address,
+        } = raw;
+        let sum = [
+            *proposer_basis_points,
+            *validators_basis_points,
+            *community_pool_basis_points,
+            *burn_basis_points,
+        ]
+        .into_iter()
+        .map(u64::from)
+        .sum::<u64>();
+        if sum != u64::from(FEE_DISTRIBUTION_TOTAL_BASIS_POINTS) {
+            return Err(Self::Error::invalid_sum(sum));
+        }
+        let community_pool_address = community_pool_address
+            .as_ref()
+            .map(Address::try_from_raw_ref)
+            .transpose()
+            .map_err(Self::Error::community_pool_address)?;
+        if *community_pool_basis_points > 0 && community_pool_address.is_none() {
+            return Err(Self::Error::community_pool_address_not_set());
+        }
+        Ok(Self {
+            proposer_basis_points: *proposer_basis_points,
+            validators_basis_points: *validators_basis_points,
+            community_pool_basis_points: *community_pool_basis_points,
+            burn_basis_points: *burn_basis_points,
+            community_pool_address,
+        })
+    }
+
+    fn to_raw(&self) -> Self::Raw {
+        let Self {
+            proposer_basis_points,
+            validators_basis_points,
+            community_pool_basis_points,
+            burn_basis_points,
+            community_pool_address,
+        } = self;
+        Self::Raw {
+            proposer_basis_points: *proposer_basis_points,
+            validators_basis_points: *validators_basis_points,
+            community_pool_basis_points: *community_pool_basis_points,
+            burn_basis_points: *burn_basis_points,
+            community_pool_address: community_pool_address.as_ref().map(Address::to_raw),
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error(transparent)]
+pub struct FeeDistributionError(FeeDistributionErrorKind);
+
+impl FeeDistributionError {
+    fn invalid_sum(sum: u64) -> Self {
+        Self(FeeDistributionErrorKind::InvalidSum {
+            sum,
+        })
+    }
+
+    fn community_pool_address(source: AddressError) -> Self {
+        Self(FeeDistributionErrorKind::CommunityPoolAddress {
+            source,
+        })
+    }
+
+    fn community_pool_address_not_set() -> Self {
+        Self(FeeDistributionErrorKind::CommunityPoolAddressNotSet)
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("failed ensuring invariants of {}", FeeDistribution::full_name())]
+enum FeeDistributionErrorKind {
+    #[error(
+        "the shares added up to `{sum}` basis points, but must add up to \
+         {FEE_DISTRIBUTION_TOTAL_BASIS_POINTS}"
+    )]
+    InvalidSum { sum: u64 },
+    #[error("`community_pool_address` field was invalid")]
+    CommunityPoolAddress { source: AddressError },
+    #[error("`community_pool_address` field must be set if the community pool share is not zero")]
+    CommunityPoolAddressNotSet,
+}
@@ -20,25 +20,34 @@ use crate::{
         fees::v1::{
             FeeComponentError,
             FeeComponents,
+            FeeDistribution,
+            FeeDistributionError,
         },
         transaction::v1::action::{
             BridgeLock,
             BridgeSudoChange,
             BridgeTransfer,
             BridgeUnlock,
+            CancelTimelockedTransfer,
+            ClaimTimelockedTransfer,
             CurrencyPairsChange,
             FeeAssetChange,
             FeeChange,
+            FeeDistributionChange,
             IbcRelayerChange,
             IbcSudoChange,
             Ics20Withdrawal,
             InitBridgeAccount,
             MarketsChange,
             RecoverIbcClient,
+            RegisterMultisig,
             RollupDataSubmission,
+            SubmitProposal,
             SudoAddressChange,
+            TimelockedTransfer,
             Transfer,
             ValidatorUpdate,
+            VoteOnProposal,
         },
     },
     Protobuf,
@@ -65,6 +74,9 @@ pub struct GenesisAppState {
     ibc_parameters: IBCParameters,
     allowed_fee_assets: Vec<asset::Denom>,
     fees: GenesisFees,
+    governance_parameters: Option<GovernanceParameters>,
+    fee_distribution: Option<FeeDistribution>,
+    oracle_liveness_parameters: Option<OracleLivenessParameters>,
 }
 
 impl GenesisAppState {
@@ -118,6 +130,21 @@ impl GenesisAppState {
         &self.fees
     }
 
+    #[must_use]
+    pub fn governance_parameters(&self) -> Option<&GovernanceParameters> {
+        self.governance_parameters.as_ref()
+    }
+
+    #[must_use]
+    pub fn fee_distribution(&self) -> Option<&FeeDistribution> {
+        self.fee_distribution.as_ref()
+    }
+
+    #[must_use]
+    pub fn oracle_liveness_parameters(&self) -> Option<&OracleLivenessParameters> {
+        self.oracle_liveness_parameters.as_ref()
+    }
+
     fn ensure_address_has_base_prefix(
         &self,
         address: &Address,
@@ -148,6 +175,30 @@ impl GenesisAppState {
         for (i, address) in self.ibc_relayer_addresses.iter().enumerate() {
             self.ensure_address_has_base_prefix(address, &format!(".ibc_relayer_addresses[{i}]"))?;
         }
+        if let Some(governance_parameters) = &self.governance_parameters {
+            for (i, address) in governance_parameters.proposers.iter().enumerate() {
+                self.ensure_address_has_base_prefix(
+                    address,
+                    &format!(".governance_parameters.proposers[{i}]"),
+                )?;
+            }
+            for (i, address) in governance_parameters.council.iter().enumerate() {
+                self.ensure_address_has_base_prefix(
+                    address,
+                    &format!(".governance_parameters.council[{i}]"),
+                )?;
+            }
+        }
+        if let Some(address) = self
+            .fee_distribution
+            .as_ref()
+            .and_then(|distribution| distribution.community_pool_address.as_ref())
+        {
+            self.ensure_address_has_base_prefix(
+                address,
+                ".fee_distribution.community_pool_address",
+            )?;
+        }
 
         Ok(())
     }
@@ -157,6 +208,7 @@ impl Protobuf for GenesisAppState {
     type Error = GenesisAppStateError;
     type Raw = raw::GenesisAppState;
 
+    #[expect(clippy::too_many_lines, reason = "there are a lot of genesis fields")]
     fn try_from_raw_ref(raw: &Self::Raw) -> Result<Self, Self::Error> {
         let Self::Raw {
             address_prefixes,
@@ -169,6 +221,9 @@ impl Protobuf for GenesisAppState {
             ibc_parameters,
             allowed_fee_assets,
             fees,
+            governance_parameters,
+            fee_distribution,
+            oracle_liveness_parameters,
         } = raw;
         let address_prefixes = address_prefixes
             .as_ref()
@@ -230,6 +285,24 @@ impl Protobuf for GenesisAppState {
             .ok_or_else(|| Self::Error::field_not_set("fees"))
             .and_then(|fees| GenesisFees::try_from_raw_ref(fees).map_err(Self::Error::fees))?;
 
+        let governance_parameters = governance_parameters
+            .as_ref()
+            .map(GovernanceParameters::try_from_raw_ref)
+            .transpose()
+            .map_err(Self::Error::governance_parameters)?;
+
+        let fee_distribution = fee_distribution
+            .as_ref()
+            .map(FeeDistribution::try_from_raw_ref)
+            .transpose()
+            .map_err(Self::Error::fee_distribution)?;
+
+        let oracle_liveness_parameters = oracle_liveness_parameters
+            .as_ref()
+            .map(OracleLivenessParameters::try_from_raw_ref)
+            .transpose()
+            .map_err(Self::Error::oracle_liveness_parameters)?;
+
         let this = Self {
             address_prefixes,
             accounts,
@@ -241,6 +314,9 @@ impl Protobuf for GenesisAppState {
             ibc_parameters,
             allowed_fee_assets,
             fees,
+            governance_parameters,
+            fee_distribution,
+            oracle_liveness_parameters,
         };
         this.ensure_all_addresses_have_base_prefix()
             .map_err(Self::Error::address_does_not_match_base)?;
@@ -259,6 +335,9 @@ impl Protobuf for GenesisAppState {
             ibc_parameters,
             allowed_fee_assets,
             fees,
+            governance_parameters,
+            fee_distribution,
+            oracle_liveness_parameters,
         } = self;
         Self::Raw {
             address_prefixes: Some(address_prefixes.to_raw()),
@@ -273,6 +352,13 @@ impl Protobuf for GenesisAppState {
             ibc_parameters: Some(ibc_parameters.to_raw()),
             allowed_fee_assets: allowed_fee_assets.iter().map(ToString::to_string).collect(),
             fees: Some(fees.to_raw()),
+            governance_parameters: governance_parameters
+                .as_ref()
+                .map(GovernanceParameters::to_raw),
+            fee_distribution: fee_distribution.as_ref().map(FeeDistribution::to_raw),
+            oracle_liveness_parameters: oracle_liveness_parameters
+                .as_ref()
+                .map(OracleLivenessParameters::to_raw),
         }
     }
 }
@@ -332,12 +418,24 @@ impl GenesisAppStateError {
         })
     }
 
+    fn fee_distribution(source: FeeDistributionError) -> Self {
+        Self(GenesisAppStateErrorKind::FeeDistribution {
+            source,
+        })
+    }
+
     fn field_not_set(name: &'static str) -> Self {
         Self(GenesisAppStateErrorKind::FieldNotSet {
             name,
         })
     }
 
+    fn governance_parameters(source: GovernanceParametersError) -> Self {
+        Self(GenesisAppStateErrorKind::GovernanceParameters {
+            source,
+        })
+    }
+
     fn ibc_relayer_addresses(source: AddressError) -> Self {
         Self(GenesisAppStateErrorKind::IbcRelayerAddresses {
             source,
@@ -355,6 +453,12 @@ impl GenesisAppStateError {
             source,
         })
     }
+
+    fn oracle_liveness_parameters(source: OracleLivenessParametersError) -> Self {
+        Self(GenesisAppStateErrorKind::OracleLivenessParameters {
+            source,
+        })
+    }
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -374,14 +478,22 @@ enum GenesisAppStateErrorKind {
     AuthoritySudoAddress { source: AddressError },
     #[error("`fees` field was invalid")]
     Fees { source: FeesError },
+    #[error("`fee_distribution` field was invalid")]
+    FeeDistribution { source: FeeDistributionError },
     #[error("`ibc_sudo_address` field was invalid")]
     IbcSudoAddress { source: AddressError },
     #[error("`ibc_relayer_addresses` field was invalid")]
     IbcRelayerAddresses { source: AddressError },
     #[error("field was not set: `{name}`")]
     FieldNotSet { name: &'static str },
+    #[error("`governance_parameters` field was invalid")]
+    GovernanceParameters { source: GovernanceParametersError },
     #[error("`native_asset_base_denomination` field was invalid")]
     NativeAssetBaseDenomination { source: ParseTracePrefixedError },
+    #[error("`oracle_liveness_parameters` field was invalid")]
+    OracleLivenessParameters {
+        source: OracleLivenessParametersError,
+    },
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -582,6 +694,240 @@ impl From<raw::IbcParameters> for IBCParameters {
     }
 }
 
+/// The maximum value of [`GovernanceParameters::approval_threshold_basis_points`] (exclusive).
+pub const MAX_APPROVAL_THRESHOLD_BASIS_POINTS: u32 = 10_000;
+
+/// The configuration of on-chain governance.
+///
+/// Passed proposals execute their sudo action on behalf of the governance account, so they only
+/// take effect once the sudo address (and, for IBC actions, the IBC sudo address) is set to the
+/// governance account.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GovernanceParameters {
+    /// The addresses allowed to submit proposals.
+    pub proposers: Vec<Address>,
+    /// The addresses allowed to vote on proposals, each with equal weight. If empty, validators
+    /// vote on proposals weighted by their voting power.
+    pub council: Vec<Address>,
+    /// The number of blocks after its submission during which a proposal can be voted on.
+    pub voting_period_blocks: u64,
+    /// The share of the total voting weight, in basis points, that approving votes must exceed
+    /// for a proposal to pass.
+    pub approval_threshold_basis_points: u32,
+}
+
+impl Protobuf for GovernanceParameters {
+    type Error = GovernanceParametersError;
+    type Raw = raw::GovernanceParameters;
+
+    fn try_from_raw_ref(raw: &Self::Raw) -> Result<Self, Self::Error> {
+        let Self::Raw {
+            proposers,
+            council,
+            voting_period_blocks,
+            approval_threshold_basis_points,
+        } = raw;
+        let proposers: Vec<_> = proposers
+            .iter()
+            .map(Address::try_from_raw_ref)
+            .collect::<Result<_, _>>()
+            .map_err(Self::Error::proposers)?;
+        if proposers.is_empty() {
+            return Err(Self::Error::no_proposers());
+        }
+        let council = council
+            .iter()
+            .map(Address::try_from_raw_ref)
+            .collect::<Result<_, _>>()
+            .map_err(Self::Error::council)?;
+        if *voting_period_blocks == 0 {
+            return Err(Self::Error::zero_voting_period());
+        }
+        if *approval_threshold_basis_points >= MAX_APPROVAL_THRESHOLD_BASIS_POINTS {
+            return Err(Self::Error::approval_threshold_too_high(
+                *approval_threshold_basis_points,
+            ));
+        }
+        Ok(Self {
+            proposers,
+            council,
+            voting_period_blocks: *voting_period_blocks,
+            approval_threshold_basis_points: *approval_threshold_basis_points,
+        })
+    }
+
+    fn to_raw(&self) -> Self::Raw {
+        let Self {
+            proposers,
+            council,
+            voting_period_blocks,
+            approval_threshold_basis_points,
+        } = self;
+        Self::Raw {
+            proposers: proposers.iter().map(Address::to_raw).collect(),
+            council: council.iter().map(Address::to_raw).collect(),
+            voting_period_blocks: *voting_period_blocks,
+            approval_threshold_basis_points: *approval_threshold_basis_points,
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error(transparent)]
+pub struct GovernanceParametersError(GovernanceParametersErrorKind);
+
+impl GovernanceParametersError {
+    fn proposers(source: AddressError) -> Self {
+        Self(GovernanceParametersErrorKind::Proposers {
+            source,
+        })
+    }
+
+    fn no_proposers() -> Self {
+        Self(GovernanceParametersErrorKind::NoProposers)
+    }
+
+    fn council(source: AddressError) -> Self {
+        Self(GovernanceParametersErrorKind::Council {
+            source,
+        })
+    }
+
+    fn zero_voting_period() -> Self {
+        Self(GovernanceParametersErrorKind::ZeroVotingPeriod)
+    }
+
+    fn approval_threshold_too_high(basis_points: u32) -> Self {
+        Self(GovernanceParametersErrorKind::ApprovalThresholdTooHigh {
+            basis_points,
+        })
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("failed ensuring invariants of {}", GovernanceParameters::full_name())]
+enum GovernanceParametersErrorKind {
+    #[error("`proposers` field was invalid")]
+    Proposers { source: AddressError },
+    #[error("`proposers` field must contain at least one address")]
+    NoProposers,
+    #[error("`council` field was invalid")]
+    Council { source: AddressError },
+    #[error("`voting_period_blocks` field must not be zero")]
+    ZeroVotingPeriod,
+    #[error(
+        "`approval_threshold_basis_points` field was `{basis_points}`, but must be less than \
+         {MAX_APPROVAL_THRESHOLD_BASIS_POINTS}, or no proposal could pass"
+    )]
+    ApprovalThresholdTooHigh { basis_points: u32 },
+}
+
+/// The maximum value of [`OracleLivenessParameters::window_blocks`].
+pub const MAX_ORACLE_LIVENESS_WINDOW_BLOCKS: u64 = 100_000;
+
+/// The maximum value of [`OracleLivenessParameters::min_participation_basis_points`].
+pub const MAX_MIN_PARTICIPATION_BASIS_POINTS: u32 = 10_000;
+
+/// The configuration of price feed oracle liveness tracking.
+///
+/// A validator's participation is the share of the most recent blocks carrying vote extensions in
+/// which it submitted a vote extension.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct OracleLivenessParameters {
+    /// The number of most recent blocks carrying vote extensions over which participation is
+    /// measured.
+    pub window_blocks: u64,
+    /// The participation, in basis points of the window, below which a validator is considered
+    /// inactive.
+    pub min_participation_basis_points: u32,
+    /// Whether validators found inactive over a full window are removed from the validator set.
+    pub remove_inactive_validators: bool,
+}
+
+impl Protobuf for OracleLivenessParameters {
+    type Error = OracleLivenessParametersError;
+    type Raw = raw::OracleLivenessParameters;
+
+    fn try_from_raw_ref(raw: &Self::Raw) -> Result<Self, Self::Error> {
+        let Self::Raw {
+            window_blocks,
+            min_participation_basis_points,
+            remove_inactive_validators,
+        } = *raw;
+        if window_blocks == 0 {
+            return Err(Self::Error::zero_window());
+        }
+        if window_blocks > MAX_ORACLE_LIVENESS_WINDOW_BLOCKS {
+            return Err(Self::Error::window_too_large(window_blocks));
+        }
+        if min_participation_basis_points > MAX_MIN_PARTICIPATION_BASIS_POINTS {
+            return Err(Self::Error::min_participation_too_high(
+                min_participation_basis_points,
+            ));
+        }
+        Ok(Self {
+            window_blocks,
+            min_participation_basis_points,
+            remove_inactive_validators,
+        })
+    }
+
+    fn to_raw(&self) -> Self::Raw {
+        let Self {
+            window_blocks,
+            min_participation_basis_points,
+            remove_inactive_validators,
+        } = *self;
+        Self::Raw {
+            window_blocks,
+            min_participation_basis_points,
+            remove_inactive_validators,
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error(transparent)]
+pub struct OracleLivenessParametersError(OracleLivenessParametersErrorKind);
+
+impl OracleLivenessParametersError {
+    fn zero_window() -> Self {
+        Self(OracleLivenessParametersErrorKind::ZeroWindow)
+    }
+
+    fn window_too_large(window_blocks: u64) -> Self {
+        Self(OracleLivenessParametersErrorKind::WindowTooLarge {
+            window_blocks,
+        })
+    }
+
+    fn min_participation_too_high(basis_points: u32) -> Self {
+        Self(OracleLivenessParametersErrorKind::MinParticipationTooHigh {
+            basis_points,
+        })
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error(
+    "failed ensuring invariants of {}",
+    OracleLivenessParameters::full_name()
+)]
+enum OracleLivenessParametersErrorKind {
+    #[error("`window_blocks` field must not be zero")]
+    ZeroWindow,
+    #[error(
+        "`window_blocks` field was `{window_blocks}`, but must not exceed \
+         {MAX_ORACLE_LIVENESS_WINDOW_BLOCKS}"
+    )]
+    WindowTooLarge { window_blocks: u64 },
+    #[error(
+        "`min_participation_basis_points` field was `{basis_points}`, but must not exceed \
+         {MAX_MIN_PARTICIPATION_BASIS_POINTS}"
+    )]
+    MinParticipationTooHigh { basis_points: u32 },
+}
+
 #[derive(Clone, Debug)]
 pub struct GenesisFees {
     pub rollup_data_submission: Option<FeeComponents<RollupDataSubmission>>,
@@ -602,6 +948,13 @@ pub struct GenesisFees {
     pub recover_ibc_client: Option<FeeComponents<RecoverIbcClient>>,
     pub currency_pairs_change: Option<FeeComponents<CurrencyPairsChange>>,
     pub markets_change: Option<FeeComponents<MarketsChange>>,
+    pub register_multisig: Option<FeeComponents<RegisterMultisig>>,
+    pub timelocked_transfer: Option<FeeComponents<TimelockedTransfer>>,
+    pub claim_timelocked_transfer: Option<FeeComponents<ClaimTimelockedTransfer>>,
+    pub cancel_timelocked_transfer: Option<FeeComponents<CancelTimelockedTransfer>>,
+    pub submit_proposal: Option<FeeComponents<SubmitProposal>>,
+    pub vote_on_proposal: Option<FeeComponents<VoteOnProposal>>,
+    pub fee_distribution_change: Option<FeeComponents<FeeDistributionChange>>,
 }
 
 impl Protobuf for GenesisFees {
@@ -629,6 +982,13 @@ impl Protobuf for GenesisFees {
             recover_ibc_client,
             currency_pairs_change,
             markets_change,
+            register_multisig,
+            timelocked_transfer,
+            claim_timelocked_transfer,
+            cancel_timelocked_transfer,
+            submit_proposal,
+            vote_on_proposal,
+            fee_distribution_change,
         } = raw;
         let rollup_data_submission = rollup_data_submission
             .map(FeeComponents::<RollupDataSubmission>::try_from_raw)
@@ -720,6 +1080,41 @@ impl Protobuf for GenesisFees {
             .transpose()
             .map_err(|e| FeesError::fee_components("markets_change", e))?;
 
+        let register_multisig = register_multisig
+            .map(FeeComponents::<RegisterMultisig>::try_from_raw)
+            .transpose()
+            .map_err(|e| FeesError::fee_components("register_multisig", e))?;
+
+        let timelocked_transfer = timelocked_transfer
+            .map(FeeComponents::<TimelockedTransfer>::try_from_raw)
+            .transpose()
+            .map_err(|e| FeesError::fee_components("timelocked_transfer", e))?;
+
+        let claim_timelocked_transfer = claim_timelocked_transfer
+            .map(FeeComponents::<ClaimTimelockedTransfer>::try_from_raw)
+            .transpose()
+            .map_err(|e| FeesError::fee_components("claim_timelocked_transfer", e))?;
+
+        let cancel_timelocked_transfer = cancel_timelocked_transfer
+            .map(FeeComponents::<CancelTimelockedTransfer>::try_from_raw)
+            .transpose()
+            .map_err(|e| FeesError::fee_components("cancel_timelocked_transfer", e))?;
+
+        let submit_proposal = submit_proposal
+            .map(FeeComponents::<SubmitProposal>::try_from_raw)
+            .transpose()
+            .map_err(|e| FeesError::fee_components("submit_proposal", e))?;
+
+        let vote_on_proposal = vote_on_proposal
+            .map(FeeComponents::<VoteOnProposal>::try_from_raw)
+            .transpose()
+            .map_err(|e| FeesError::fee_components("vote_on_proposal", e))?;
+
+        let fee_distribution_change = fee_distribution_change
+            .map(FeeComponents::<FeeDistributionChange>::try_from_raw)
+            .transpose()
+            .map_err(|e| FeesError::fee_components("fee_distribution_change", e))?;
+
         Ok(Self {
             rollup_data_submission,
             transfer,
@@ -739,6 +1134,13 @@ impl Protobuf for GenesisFees {
             recover_ibc_client,
             currency_pairs_change,
             markets_change,
+            register_multisig,
+            timelocked_transfer,
+            claim_timelocked_transfer,
+            cancel_timelocked_transfer,
+            submit_proposal,
+            vote_on_proposal,
+            fee_distribution_change,
         })
     }
 
@@ -762,6 +1164,13 @@ impl Protobuf for GenesisFees {
             recover_ibc_client,
             currency_pairs_change,
             markets_change,
+            register_multisig,
+            timelocked_transfer,
+            claim_timelocked_transfer,
+            cancel_timelocked_transfer,
+            submit_proposal,
+            vote_on_proposal,
+            fee_distribution_change,
         } = self;
         Self::Raw {
             transfer: transfer.map(|act| FeeComponents::<Transfer>::to_raw(&act)),
@@ -794,6 +1203,20 @@ impl Protobuf for GenesisFees {
             currency_pairs_change: currency_pairs_change
                 .map(|act| FeeComponents::<CurrencyPairsChange>::to_raw(&act)),
             markets_change: markets_change.map(|act| FeeComponents::<MarketsChange>::to_raw(&act)),
+            register_multisig: register_multisig
+                .map(|act| FeeComponents::<RegisterMultisig>::to_raw(&act)),
+            timelocked_transfer: timelocked_transfer
+                .map(|act| FeeComponents::<TimelockedTransfer>::to_raw(&act)),
+            claim_timelocked_transfer: claim_timelocked_transfer
+                .map(|act| FeeComponents::<ClaimTimelockedTransfer>::to_raw(&act)),
+            cancel_timelocked_transfer: cancel_timelocked_transfer
+                .map(|act| FeeComponents::<CancelTimelockedTransfer>::to_raw(&act)),
+            submit_proposal: submit_proposal
+                .map(|act| FeeComponents::<SubmitProposal>::to_raw(&act)),
+            vote_on_proposal: vote_on_proposal
+                .map(|act| FeeComponents::<VoteOnProposal>::to_raw(&act)),
+            fee_distribution_change: fee_distribution_change
+                .map(|act| FeeComponents::<FeeDistributionChange>::to_raw(&act)),
         }
     }
 }
@@ -832,7 +1255,10 @@ enum FeesErrorKind {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::primitive::v1::Address;
+    use crate::{
+        generated::astria::protocol::fees::v1 as raw_fees,
+        primitive::v1::Address,
+    };
 
     const ASTRIA_ADDRESS_PREFIX: &str = "astria";
 
@@ -890,6 +1316,19 @@ mod tests {
             recover_ibc_client: Some(FeeComponents::<RecoverIbcClient>::new(0, 0).to_raw()),
             currency_pairs_change: Some(FeeComponents::<CurrencyPairsChange>::new(0, 0).to_raw()),
             markets_change: Some(FeeComponents::<MarketsChange>::new(0, 0).to_raw()),
+            register_multisig: Some(FeeComponents::<RegisterMultisig>::new(0, 0).to_raw()),
+            timelocked_transfer: Some(FeeComponents::<TimelockedTransfer>::new(12, 0).to_raw()),
+            claim_timelocked_transfer: Some(
+                FeeComponents::<ClaimTimelockedTransfer>::new(12, 0).to_raw(),
+            ),
+            cancel_timelocked_transfer: Some(
+                FeeComponents::<CancelTimelockedTransfer>::new(12, 0).to_raw(),
+            ),
+            submit_proposal: Some(FeeComponents::<SubmitProposal>::new(12, 0).to_raw()),
+            vote_on_proposal: Some(FeeComponents::<VoteOnProposal>::new(12, 0).to_raw()),
+            fee_distribution_change: Some(
+                FeeComponents::<FeeDistributionChange>::new(0, 0).to_raw(),
+            ),
         }
     }
 
@@ -925,6 +1364,24 @@ mod tests {
             }),
             allowed_fee_assets: vec!["nria".into()],
             fees: Some(genesis_fees()),
+            governance_parameters: Some(raw::GovernanceParameters {
+                proposers: vec![alice().to_raw()],
+                council: vec![bob().to_raw(), charlie().to_raw()],
+                voting_period_blocks: 100,
+                approval_threshold_basis_points: 6_666,
+            }),
+            fee_distribution: Some(raw_fees::FeeDistribution {
+                proposer_basis_points: 5_000,
+                validators_basis_points: 3_000,
+                community_pool_basis_points: 1_500,
+                burn_basis_points: 500,
+                community_pool_address: Some(charlie().to_raw()),
+            }),
+            oracle_liveness_parameters: Some(raw::OracleLivenessParameters {
+                window_blocks: 1_000,
+                min_participation_basis_points: 5_000,
+                remove_inactive_validators: true,
+            }),
         }
     }
 
@@ -997,6 +1454,115 @@ mod tests {
             },
             ".accounts[1].address",
         );
+        assert_bad_prefix(
+            raw::GenesisAppState {
+                governance_parameters: Some(raw::GovernanceParameters {
+                    council: vec![alice().to_raw(), mallory().to_raw()],
+                    ..proto_genesis_state().governance_parameters.unwrap()
+                }),
+                ..proto_genesis_state()
+            },
+            ".governance_parameters.council[1]",
+        );
+        assert_bad_prefix(
+            raw::GenesisAppState {
+                fee_distribution: Some(raw_fees::FeeDistribution {
+                    community_pool_address: Some(mallory().to_raw()),
+                    ..proto_genesis_state().fee_distribution.unwrap()
+                }),
+                ..proto_genesis_state()
+            },
+            ".fee_distribution.community_pool_address",
+        );
+    }
+
+    #[test]
+    fn invalid_fee_distribution_is_rejected() {
+        #[track_caller]
+        fn assert_invalid(fee_distribution: raw_fees::FeeDistribution) {
+            let error = GenesisAppState::try_from(raw::GenesisAppState {
+                fee_distribution: Some(fee_distribution),
+                ..proto_genesis_state()
+            })
+            .expect_err("fee distribution should have been rejected");
+            assert!(
+                matches!(error.0, GenesisAppStateErrorKind::FeeDistribution { .. }),
+                "expected fee distribution error, got: {error:?}"
+            );
+        }
+        let valid = proto_genesis_state().fee_distribution.unwrap();
+        assert_invalid(raw_fees::FeeDistribution {
+            burn_basis_points: 501,
+            ..valid.clone()
+        });
+        assert_invalid(raw_fees::FeeDistribution {
+            community_pool_address: None,
+            ..valid
+        });
+    }
+
+    #[test]
+    fn invalid_governance_parameters_are_rejected() {
+        #[track_caller]
+        fn assert_invalid(governance_parameters: raw::GovernanceParameters) {
+            let error = GenesisAppState::try_from(raw::GenesisAppState {
+                governance_parameters: Some(governance_parameters),
+                ..proto_genesis_state()
+            })
+            .expect_err("governance parameters should have been rejected");
+            assert!(
+                matches!(
+                    error.0,
+                    GenesisAppStateErrorKind::GovernanceParameters { .. }
+                ),
+                "expected governance parameters error, got: {error:?}"
+            );
+        }
+        let valid = proto_genesis_state().governance_parameters.unwrap();
+        assert_invalid(raw::GovernanceParameters {
+            proposers: vec![],
+            ..valid.clone()
+        });
+        assert_invalid(raw::GovernanceParameters {
+            voting_period_blocks: 0,
+            ..valid.clone()
+        });
+        assert_invalid(raw::GovernanceParameters {
+            approval_threshold_basis_points: MAX_APPROVAL_THRESHOLD_BASIS_POINTS,
+            ..valid
+        });
+    }
+
+    #[test]
+    fn invalid_oracle_liveness_parameters_are_rejected() {
+        #[track_caller]
+        fn assert_invalid(oracle_liveness_parameters: raw::OracleLivenessParameters) {
+            let error = GenesisAppState::try_from(raw::GenesisAppState {
+                oracle_liveness_parameters: Some(oracle_liveness_parameters),
+                ..proto_genesis_state()
+            })
+            .expect_err("oracle liveness parameters should have been rejected");
+            assert!(
+                matches!(
+                    error.0,
+                    GenesisAppStateErrorKind::OracleLivenessParameters { .. }
+                ),
+                "expected oracle liveness parameters error, got: {error:?}"
+            );
+        }
+        let valid = proto_genesis_state().oracle_liveness_parameters.unwrap();
+        assert_invalid(raw::OracleLivenessParameters {
+            window_blocks: 0,
+            ..valid
+        });
+        assert_invalid(raw::OracleLivenessParameters {
+            window_blocks: MAX_ORACLE_LIVENESS_WINDOW_BLOCKS + 1,
+            ..valid
+        });
+        assert_invalid(raw::OracleLivenessParameters {
+            min_participation_basis_points: MAX_MIN_PARTICIPATION_BASIS_POINTS + 1,
+            ..valid
+        });
     }
 
     #[cfg(feature = "serde")]
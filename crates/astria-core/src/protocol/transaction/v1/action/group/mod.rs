@@ -21,11 +21,12 @@ impl Action {
             | Action::FeeAssetChange(_)
             | Action::RecoverIbcClient(_)
             | Action::CurrencyPairsChange(_)
-            | Action::MarketsChange(_) => Group::BundleableSudo,
+            | Action::MarketsChange(_)
+            | Action::FeeDistributionChange(_) => Group::BundleableSudo,
 
-            Action::InitBridgeAccount(_) | Action::BridgeSudoChange(_) => {
-                Group::UnbundleableGeneral
-            }
+            Action::InitBridgeAccount(_)
+            | Action::BridgeSudoChange(_)
+            | Action::RegisterMultisig(_) => Group::UnbundleableGeneral,
 
             Action::RollupDataSubmission(_)
             | Action::Transfer(_)
@@ -34,9 +35,24 @@ impl Action {
             | Action::BridgeLock(_)
             | Action::BridgeUnlock(_)
             | Action::BridgeTransfer(_)
-            | Action::Ibc(_) => Group::BundleableGeneral,
+            | Action::Ibc(_)
+            | Action::TimelockedTransfer(_)
+            | Action::ClaimTimelockedTransfer(_)
+            | Action::CancelTimelockedTransfer(_)
+            | Action::SubmitProposal(_)
+            | Action::VoteOnProposal(_) => Group::BundleableGeneral,
         }
     }
+
+    /// Returns whether the action can be wrapped in a governance proposal.
+    ///
+    /// These are the actions gated by the sudo address or the IBC sudo address.
+    pub const fn is_governable(&self) -> bool {
+        matches!(
+            self.group(),
+            Group::UnbundleableSudo | Group::BundleableSudo
+        ) || matches!(self, Action::ValidatorUpdate(_))
+    }
 }
 
 /// `action::Group`
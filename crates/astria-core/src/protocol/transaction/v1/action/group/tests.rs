@@ -1,39 +1,54 @@
 use ibc_types::core::client::Height;
 
 use crate::{
-    crypto::VerificationKey,
+    crypto::{
+        SigningKey,
+        VerificationKey,
+    },
     primitive::v1::{
         asset::Denom,
         Address,
         RollupId,
     },
-    protocol::transaction::v1::action::{
-        group::{
-            Actions,
-            ErrorKind,
-            Group,
+    protocol::{
+        fees::v1::FeeDistribution,
+        transaction::v1::action::{
+            group::{
+                Actions,
+                ErrorKind,
+                Group,
+            },
+            Action,
+            BridgeLock,
+            BridgeSudoChange,
+            BridgeTransfer,
+            BridgeUnlock,
+            CancelTimelockedTransfer,
+            ClaimTimelockedTransfer,
+            CurrencyPairsChange,
+            FeeAssetChange,
+            FeeChange,
+            FeeComponents,
+            FeeDistributionChange,
+            IbcRelay,
+            IbcRelayerChange,
+            IbcSudoChange,
+            Ics20Withdrawal,
+            InitBridgeAccount,
+            MarketsChange,
+            RecoverIbcClient,
+            RegisterMultisig,
+            RollupDataSubmission,
+            SubmitProposal,
+            SudoAddressChange,
+            TimelockUnlock,
+            TimelockedTransfer,
+            Transfer,
+            ValidatorUpdate,
+            VoteOnProposal,
         },
-        Action,
-        BridgeLock,
-        BridgeSudoChange,
-        BridgeTransfer,
-        BridgeUnlock,
-        CurrencyPairsChange,
-        FeeAssetChange,
-        FeeChange,
-        FeeComponents,
-        IbcRelay,
-        IbcRelayerChange,
-        IbcSudoChange,
-        Ics20Withdrawal,
-        InitBridgeAccount,
-        MarketsChange,
-        RecoverIbcClient,
-        RollupDataSubmission,
-        SudoAddressChange,
-        Transfer,
-        ValidatorUpdate,
     },
+    Protobuf as _,
 };
 const ASTRIA_ADDRESS_PREFIX: &str = "astria";
 
@@ -102,6 +117,32 @@ fn try_from_list_of_actions_bundleable_general() {
             rollup_withdrawal_event_id: String::new(),
         }),
         Action::Ibc(IbcRelay::Unknown(pbjson_types::Any::default())),
+        Action::TimelockedTransfer(TimelockedTransfer {
+            to: address,
+            amount: 100,
+            asset: asset.clone(),
+            fee_asset: asset.clone(),
+            unlock: TimelockUnlock::Height(10),
+        }),
+        Action::ClaimTimelockedTransfer(ClaimTimelockedTransfer {
+            id: 0,
+            fee_asset: asset.clone(),
+        }),
+        Action::CancelTimelockedTransfer(CancelTimelockedTransfer {
+            id: 1,
+            fee_asset: asset.clone(),
+        }),
+        Action::SubmitProposal(SubmitProposal {
+            action: Box::new(Action::SudoAddressChange(SudoAddressChange {
+                new_address: address,
+            })),
+            fee_asset: asset.clone(),
+        }),
+        Action::VoteOnProposal(VoteOnProposal {
+            proposal_id: 0,
+            approve: true,
+            fee_asset: asset.clone(),
+        }),
     ];
 
     assert!(matches!(
@@ -129,6 +170,9 @@ fn from_list_of_actions_bundleable_sudo() {
         }),
         Action::CurrencyPairsChange(CurrencyPairsChange::Addition(vec![])),
         Action::MarketsChange(MarketsChange::Creation(vec![])),
+        Action::FeeDistributionChange(FeeDistributionChange {
+            new_distribution: FeeDistribution::proposer_only(),
+        }),
     ];
 
     assert!(matches!(
@@ -218,6 +262,23 @@ fn from_list_of_actions_unbundleable_general() {
         Group::UnbundleableGeneral
     ));
 
+    let register_multisig_action = RegisterMultisig::try_new(
+        vec![
+            SigningKey::from([1; 32]).verification_key(),
+            SigningKey::from([2; 32]).verification_key(),
+        ],
+        2,
+        asset.clone(),
+    )
+    .unwrap();
+
+    let actions = vec![register_multisig_action.into()];
+
+    assert!(matches!(
+        Actions::try_from_list_of_actions(actions).unwrap().group(),
+        Group::UnbundleableGeneral
+    ));
+
     let actions = vec![
         init_bridge_account_action.into(),
         sudo_bridge_address_change_action.into(),
@@ -272,3 +333,46 @@ fn should_be_in_expected_order() {
     assert!(Group::BundleableSudo < Group::UnbundleableGeneral);
     assert!(Group::UnbundleableGeneral < Group::BundleableGeneral);
 }
+
+#[test]
+fn only_sudo_actions_are_governable() {
+    let address: Address<_> = Address::builder()
+        .array([0; 20])
+        .prefix(ASTRIA_ADDRESS_PREFIX)
+        .try_build()
+        .unwrap();
+    let asset: Denom = "nria".parse().unwrap();
+
+    let sudo_address_change = Action::SudoAddressChange(SudoAddressChange {
+        new_address: address,
+    });
+    let validator_update = Action::ValidatorUpdate(ValidatorUpdate {
+        power: 100,
+        verification_key: VerificationKey::try_from([0; 32]).unwrap(),
+        name: "test_validator".parse().unwrap(),
+    });
+    let fee_change = Action::FeeChange(FeeChange::Transfer(FeeComponents::<Transfer>::new(1, 0)));
+    let transfer = Action::Transfer(Transfer {
+        to: address,
+        amount: 100,
+        asset: asset.clone(),
+        fee_asset: asset.clone(),
+    });
+    assert!(sudo_address_change.is_governable());
+    assert!(validator_update.is_governable());
+    assert!(fee_change.is_governable());
+    assert!(!transfer.is_governable());
+
+    let raw = SubmitProposal {
+        action: Box::new(transfer),
+        fee_asset: asset,
+    }
+    .into_raw();
+    let error = SubmitProposal::try_from_raw(raw).unwrap_err();
+    assert!(
+        error
+            .to_string()
+            .contains("contained a `Transfer` action, which cannot be proposed"),
+        "unexpected error: {error}"
+    );
+}
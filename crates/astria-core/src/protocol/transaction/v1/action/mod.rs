@@ -16,6 +16,7 @@ use prost::Name as _;
 
 use super::raw;
 use crate::{
+    crypto::VerificationKey,
     oracles::price_feed::{
         market_map::v2::{
             Market,
@@ -39,6 +40,8 @@ use crate::{
     protocol::fees::v1::{
         FeeComponentError,
         FeeComponents,
+        FeeDistribution,
+        FeeDistributionError,
     },
     Protobuf,
 };
@@ -47,6 +50,9 @@ pub mod group;
 
 const MAX_VALIDATOR_NAME_LENGTH: usize = 32;
 
+/// The maximum number of members of a multisig account.
+pub const MAX_MULTISIG_MEMBERS: usize = 32;
+
 #[derive(Clone, Debug)]
 #[cfg_attr(
     feature = "serde",
@@ -72,6 +78,13 @@ pub enum Action {
     RecoverIbcClient(RecoverIbcClient),
     CurrencyPairsChange(CurrencyPairsChange),
     MarketsChange(MarketsChange),
+    RegisterMultisig(RegisterMultisig),
+    TimelockedTransfer(TimelockedTransfer),
+    ClaimTimelockedTransfer(ClaimTimelockedTransfer),
+    CancelTimelockedTransfer(CancelTimelockedTransfer),
+    SubmitProposal(SubmitProposal),
+    VoteOnProposal(VoteOnProposal),
+    FeeDistributionChange(FeeDistributionChange),
 }
 
 impl Protobuf for Action {
@@ -100,6 +113,13 @@ impl Protobuf for Action {
             Action::RecoverIbcClient(act) => Value::RecoverIbcClient(act.to_raw()),
             Action::CurrencyPairsChange(act) => Value::CurrencyPairsChange(act.to_raw()),
             Action::MarketsChange(act) => Value::MarketsChange(act.to_raw()),
+            Action::RegisterMultisig(act) => Value::RegisterMultisig(act.to_raw()),
+            Action::TimelockedTransfer(act) => Value::TimelockedTransfer(act.to_raw()),
+            Action::ClaimTimelockedTransfer(act) => Value::ClaimTimelockedTransfer(act.to_raw()),
+            Action::CancelTimelockedTransfer(act) => Value::CancelTimelockedTransfer(act.to_raw()),
+            Action::SubmitProposal(act) => Value::SubmitProposal(Box::new(act.to_raw())),
+            Action::VoteOnProposal(act) => Value::VoteOnProposal(act.to_raw()),
+            Action::FeeDistributionChange(act) => Value::FeeDistributionChange(act.to_raw()),
         };
         raw::Action {
             value: Some(kind),
@@ -185,6 +205,29 @@ impl Protobuf for Action {
             Value::MarketsChange(act) => Self::MarketsChange(
                 MarketsChange::try_from_raw(act).map_err(Error::markets_change)?,
             ),
+            Value::RegisterMultisig(act) => Self::RegisterMultisig(
+                RegisterMultisig::try_from_raw(act).map_err(Error::register_multisig)?,
+            ),
+            Value::TimelockedTransfer(act) => Self::TimelockedTransfer(
+                TimelockedTransfer::try_from_raw(act).map_err(Error::timelocked_transfer)?,
+            ),
+            Value::ClaimTimelockedTransfer(act) => Self::ClaimTimelockedTransfer(
+                ClaimTimelockedTransfer::try_from_raw(act)
+                    .map_err(Error::claim_timelocked_transfer)?,
+            ),
+            Value::CancelTimelockedTransfer(act) => Self::CancelTimelockedTransfer(
+                CancelTimelockedTransfer::try_from_raw(act)
+                    .map_err(Error::cancel_timelocked_transfer)?,
+            ),
+            Value::SubmitProposal(act) => Self::SubmitProposal(
+                SubmitProposal::try_from_raw(*act).map_err(Error::submit_proposal)?,
+            ),
+            Value::VoteOnProposal(act) => Self::VoteOnProposal(
+                VoteOnProposal::try_from_raw(act).map_err(Error::vote_on_proposal)?,
+            ),
+            Value::FeeDistributionChange(act) => Self::FeeDistributionChange(
+                FeeDistributionChange::try_from_raw(act).map_err(Error::fee_distribution_change)?,
+            ),
         };
         Ok(action)
     }
@@ -319,12 +362,54 @@ impl From<MarketsChange> for Action {
     }
 }
 
+impl From<RegisterMultisig> for Action {
+    fn from(value: RegisterMultisig) -> Self {
+        Self::RegisterMultisig(value)
+    }
+}
+
+impl From<TimelockedTransfer> for Action {
+    fn from(value: TimelockedTransfer) -> Self {
+        Self::TimelockedTransfer(value)
+    }
+}
+
+impl From<ClaimTimelockedTransfer> for Action {
+    fn from(value: ClaimTimelockedTransfer) -> Self {
+        Self::ClaimTimelockedTransfer(value)
+    }
+}
+
+impl From<CancelTimelockedTransfer> for Action {
+    fn from(value: CancelTimelockedTransfer) -> Self {
+        Self::CancelTimelockedTransfer(value)
+    }
+}
+
+impl From<SubmitProposal> for Action {
+    fn from(value: SubmitProposal) -> Self {
+        Self::SubmitProposal(value)
+    }
+}
+
+impl From<VoteOnProposal> for Action {
+    fn from(value: VoteOnProposal) -> Self {
+        Self::VoteOnProposal(value)
+    }
+}
+
 impl From<ValidatorUpdate> for Action {
     fn from(value: ValidatorUpdate) -> Self {
         Self::ValidatorUpdate(value)
     }
 }
 
+impl From<FeeDistributionChange> for Action {
+    fn from(value: FeeDistributionChange) -> Self {
+        Self::FeeDistributionChange(value)
+    }
+}
+
 impl From<Action> for raw::Action {
     fn from(value: Action) -> Self {
         value.into_raw()
@@ -341,7 +426,11 @@ impl TryFrom<raw::Action> for Action {
 
 // TODO: replace this trait with a Protobuf:FullName implementation.
 // Issue tracked in #1567
-pub(super) trait ActionName {
+#[expect(
+    clippy::module_name_repetitions,
+    reason = "following our naming conventions"
+)]
+pub trait ActionName {
     fn name(&self) -> &'static str;
 }
 
@@ -366,6 +455,13 @@ impl ActionName for Action {
             Action::RecoverIbcClient(_) => "RecoverIbcClient",
             Action::CurrencyPairsChange(_) => "CurrencyPairsChange",
             Action::MarketsChange(_) => "MarketsChange",
+            Action::RegisterMultisig(_) => "RegisterMultisig",
+            Action::TimelockedTransfer(_) => "TimelockedTransfer",
+            Action::ClaimTimelockedTransfer(_) => "ClaimTimelockedTransfer",
+            Action::CancelTimelockedTransfer(_) => "CancelTimelockedTransfer",
+            Action::SubmitProposal(_) => "SubmitProposal",
+            Action::VoteOnProposal(_) => "VoteOnProposal",
+            Action::FeeDistributionChange(_) => "FeeDistributionChange",
         }
     }
 }
@@ -450,6 +546,34 @@ impl Error {
     fn markets_change(inner: MarketsChangeError) -> Self {
         Self(ActionErrorKind::MarketsChange(inner))
     }
+
+    fn register_multisig(inner: RegisterMultisigError) -> Self {
+        Self(ActionErrorKind::RegisterMultisig(inner))
+    }
+
+    fn timelocked_transfer(inner: TimelockedTransferError) -> Self {
+        Self(ActionErrorKind::TimelockedTransfer(inner))
+    }
+
+    fn claim_timelocked_transfer(inner: TimelockedTransferIdError) -> Self {
+        Self(ActionErrorKind::ClaimTimelockedTransfer(inner))
+    }
+
+    fn cancel_timelocked_transfer(inner: TimelockedTransferIdError) -> Self {
+        Self(ActionErrorKind::CancelTimelockedTransfer(inner))
+    }
+
+    fn submit_proposal(inner: SubmitProposalError) -> Self {
+        Self(ActionErrorKind::SubmitProposal(inner))
+    }
+
+    fn vote_on_proposal(inner: VoteOnProposalError) -> Self {
+        Self(ActionErrorKind::VoteOnProposal(inner))
+    }
+
+    fn fee_distribution_change(inner: FeeDistributionChangeError) -> Self {
+        Self(ActionErrorKind::FeeDistributionChange(inner))
+    }
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -492,6 +616,20 @@ enum ActionErrorKind {
     CurrencyPairsChange(#[source] CurrencyPairsChangeError),
     #[error("markets change action was not valid")]
     MarketsChange(#[source] MarketsChangeError),
+    #[error("register multisig action was not valid")]
+    RegisterMultisig(#[source] RegisterMultisigError),
+    #[error("timelocked transfer action was not valid")]
+    TimelockedTransfer(#[source] TimelockedTransferError),
+    #[error("claim timelocked transfer action was not valid")]
+    ClaimTimelockedTransfer(#[source] TimelockedTransferIdError),
+    #[error("cancel timelocked transfer action was not valid")]
+    CancelTimelockedTransfer(#[source] TimelockedTransferIdError),
+    #[error("submit proposal action was not valid")]
+    SubmitProposal(#[source] SubmitProposalError),
+    #[error("vote on proposal action was not valid")]
+    VoteOnProposal(#[source] VoteOnProposalError),
+    #[error("fee distribution change action was not valid")]
+    FeeDistributionChange(#[source] FeeDistributionChangeError),
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -2244,6 +2382,13 @@ pub enum FeeChange {
     RecoverIbcClient(FeeComponents<RecoverIbcClient>),
     CurrencyPairsChange(FeeComponents<CurrencyPairsChange>),
     MarketsChange(FeeComponents<MarketsChange>),
+    RegisterMultisig(FeeComponents<RegisterMultisig>),
+    TimelockedTransfer(FeeComponents<TimelockedTransfer>),
+    ClaimTimelockedTransfer(FeeComponents<ClaimTimelockedTransfer>),
+    CancelTimelockedTransfer(FeeComponents<CancelTimelockedTransfer>),
+    SubmitProposal(FeeComponents<SubmitProposal>),
+    VoteOnProposal(FeeComponents<VoteOnProposal>),
+    FeeDistributionChange(FeeComponents<FeeDistributionChange>),
 }
 
 impl Protobuf for FeeChange {
@@ -2308,6 +2453,27 @@ impl Protobuf for FeeChange {
                 Self::MarketsChange(fee_change) => {
                     raw::fee_change::FeeComponents::MarketsChange(fee_change.to_raw())
                 }
+                Self::RegisterMultisig(fee_change) => {
+                    raw::fee_change::FeeComponents::RegisterMultisig(fee_change.to_raw())
+                }
+                Self::TimelockedTransfer(fee_change) => {
+                    raw::fee_change::FeeComponents::TimelockedTransfer(fee_change.to_raw())
+                }
+                Self::ClaimTimelockedTransfer(fee_change) => {
+                    raw::fee_change::FeeComponents::ClaimTimelockedTransfer(fee_change.to_raw())
+                }
+                Self::CancelTimelockedTransfer(fee_change) => {
+                    raw::fee_change::FeeComponents::CancelTimelockedTransfer(fee_change.to_raw())
+                }
+                Self::SubmitProposal(fee_change) => {
+                    raw::fee_change::FeeComponents::SubmitProposal(fee_change.to_raw())
+                }
+                Self::VoteOnProposal(fee_change) => {
+                    raw::fee_change::FeeComponents::VoteOnProposal(fee_change.to_raw())
+                }
+                Self::FeeDistributionChange(fee_change) => {
+                    raw::fee_change::FeeComponents::FeeDistributionChange(fee_change.to_raw())
+                }
             }),
         }
     }
@@ -2318,6 +2484,10 @@ impl Protobuf for FeeChange {
     ///
     /// - if the fee change `value` field is missing
     /// - if the `new_value` field is missing
+    #[expect(
+        clippy::too_many_lines,
+        reason = "one match arm per action with fees, so length grows with the action set"
+    )]
     fn try_from_raw_ref(proto: &raw::FeeChange) -> Result<Self, Self::Error> {
         Ok(match &proto.fee_components {
             Some(raw::fee_change::FeeComponents::Transfer(fee_change)) => {
@@ -2396,6 +2566,41 @@ impl Protobuf for FeeChange {
             Some(raw::fee_change::FeeComponents::MarketsChange(fee_change)) => Self::MarketsChange(
                 FeeComponents::<MarketsChange>::try_from_raw_ref(fee_change)?,
             ),
+            Some(raw::fee_change::FeeComponents::RegisterMultisig(fee_change)) => {
+                Self::RegisterMultisig(FeeComponents::<RegisterMultisig>::try_from_raw_ref(
+                    fee_change,
+                )?)
+            }
+            Some(raw::fee_change::FeeComponents::TimelockedTransfer(fee_change)) => {
+                Self::TimelockedTransfer(FeeComponents::<TimelockedTransfer>::try_from_raw_ref(
+                    fee_change,
+                )?)
+            }
+            Some(raw::fee_change::FeeComponents::ClaimTimelockedTransfer(fee_change)) => {
+                Self::ClaimTimelockedTransfer(
+                    FeeComponents::<ClaimTimelockedTransfer>::try_from_raw_ref(fee_change)?,
+                )
+            }
+            Some(raw::fee_change::FeeComponents::CancelTimelockedTransfer(fee_change)) => {
+                Self::CancelTimelockedTransfer(
+                    FeeComponents::<CancelTimelockedTransfer>::try_from_raw_ref(fee_change)?,
+                )
+            }
+            Some(raw::fee_change::FeeComponents::SubmitProposal(fee_change)) => {
+                Self::SubmitProposal(FeeComponents::<SubmitProposal>::try_from_raw_ref(
+                    fee_change,
+                )?)
+            }
+            Some(raw::fee_change::FeeComponents::VoteOnProposal(fee_change)) => {
+                Self::VoteOnProposal(FeeComponents::<VoteOnProposal>::try_from_raw_ref(
+                    fee_change,
+                )?)
+            }
+            Some(raw::fee_change::FeeComponents::FeeDistributionChange(fee_change)) => {
+                Self::FeeDistributionChange(
+                    FeeComponents::<FeeDistributionChange>::try_from_raw_ref(fee_change)?,
+                )
+            }
             None => return Err(FeeChangeError::field_unset("fee_components")),
         })
     }
@@ -2509,6 +2714,48 @@ impl From<FeeComponents<MarketsChange>> for FeeChange {
     }
 }
 
+impl From<FeeComponents<RegisterMultisig>> for FeeChange {
+    fn from(fee: FeeComponents<RegisterMultisig>) -> Self {
+        FeeChange::RegisterMultisig(fee)
+    }
+}
+
+impl From<FeeComponents<TimelockedTransfer>> for FeeChange {
+    fn from(fee: FeeComponents<TimelockedTransfer>) -> Self {
+        FeeChange::TimelockedTransfer(fee)
+    }
+}
+
+impl From<FeeComponents<ClaimTimelockedTransfer>> for FeeChange {
+    fn from(fee: FeeComponents<ClaimTimelockedTransfer>) -> Self {
+        FeeChange::ClaimTimelockedTransfer(fee)
+    }
+}
+
+impl From<FeeComponents<CancelTimelockedTransfer>> for FeeChange {
+    fn from(fee: FeeComponents<CancelTimelockedTransfer>) -> Self {
+        FeeChange::CancelTimelockedTransfer(fee)
+    }
+}
+
+impl From<FeeComponents<SubmitProposal>> for FeeChange {
+    fn from(fee: FeeComponents<SubmitProposal>) -> Self {
+        FeeChange::SubmitProposal(fee)
+    }
+}
+
+impl From<FeeComponents<VoteOnProposal>> for FeeChange {
+    fn from(fee: FeeComponents<VoteOnProposal>) -> Self {
+        FeeChange::VoteOnProposal(fee)
+    }
+}
+
+impl From<FeeComponents<FeeDistributionChange>> for FeeChange {
+    fn from(fee: FeeComponents<FeeDistributionChange>) -> Self {
+        FeeChange::FeeDistributionChange(fee)
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct RecoverIbcClient {
     pub client_id: ibc_types::core::client::ClientId,
@@ -2581,6 +2828,7 @@ enum RecoverIbcClientErrorKind {
 pub enum CurrencyPairsChange {
     Addition(Vec<CurrencyPair>),
     Removal(Vec<CurrencyPair>),
+    ParametersUpdate(Vec<CurrencyPairParameters>),
 }
 
 impl Protobuf for CurrencyPairsChange {
@@ -2600,6 +2848,14 @@ impl Protobuf for CurrencyPairsChange {
                     pairs: pairs.into_iter().map(CurrencyPair::into_raw).collect(),
                 })
             }
+            CurrencyPairsChange::ParametersUpdate(parameters) => {
+                raw::currency_pairs_change::Value::ParametersUpdate(raw::CurrencyPairsParameters {
+                    parameters: parameters
+                        .into_iter()
+                        .map(CurrencyPairParameters::into_raw)
+                        .collect(),
+                })
+            }
         };
         Self::Raw {
             value: Some(raw),
@@ -2617,6 +2873,7 @@ impl Protobuf for CurrencyPairsChange {
     ///
     /// - if the raw value is `None`
     /// - if any of the `pairs` field is invalid
+    /// - if any of the `parameters` field is invalid
     fn try_from_raw(raw: raw::CurrencyPairsChange) -> Result<Self, Self::Error> {
         match raw.value {
             Some(raw::currency_pairs_change::Value::Addition(raw::CurrencyPairs {
@@ -2639,6 +2896,18 @@ impl Protobuf for CurrencyPairsChange {
                     .map_err(Self::Error::invalid_currency_pair)?;
                 Ok(Self::Removal(pairs))
             }
+            Some(raw::currency_pairs_change::Value::ParametersUpdate(
+                raw::CurrencyPairsParameters {
+                    parameters,
+                },
+            )) => {
+                let parameters = parameters
+                    .into_iter()
+                    .map(CurrencyPairParameters::try_from_raw)
+                    .collect::<Result<_, _>>()
+                    .map_err(Self::Error::invalid_parameters)?;
+                Ok(Self::ParametersUpdate(parameters))
+            }
             None => Err(Self::Error::unset()),
         }
     }
@@ -2668,6 +2937,11 @@ impl CurrencyPairsChangeError {
     fn invalid_currency_pair(err: CurrencyPairError) -> Self {
         Self(CurrencyPairsChangeErrorKind::InvalidCurrencyPair(err))
     }
+
+    #[must_use]
+    fn invalid_parameters(err: CurrencyPairParametersError) -> Self {
+        Self(CurrencyPairsChangeErrorKind::InvalidParameters(err))
+    }
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -2676,6 +2950,123 @@ enum CurrencyPairsChangeErrorKind {
     Unset,
     #[error("a currency pair was invalid")]
     InvalidCurrencyPair(#[from] CurrencyPairError),
+    #[error("the parameters of a currency pair were invalid")]
+    InvalidParameters(#[from] CurrencyPairParametersError),
+}
+
+/// The maximum window over which the time-weighted average price of a currency pair can be
+/// computed: one week.
+pub const MAX_TWAP_WINDOW_SECONDS: u64 = 7 * 24 * 60 * 60;
+
+/// The parameters bounding price updates of a currency pair in the price feed oracle.
+///
+/// A field set to zero disables the corresponding check.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CurrencyPairParameters {
+    pub currency_pair: CurrencyPair,
+    /// The age in seconds after which the latest price of the pair is considered stale.
+    pub max_age_seconds: u64,
+    /// The maximum change in basis points between the latest non-stale price and a new price.
+    /// New prices deviating further are rejected as outliers.
+    pub max_deviation_basis_points: u32,
+    /// The window in seconds over which the time-weighted average price is computed.
+    pub twap_window_seconds: u64,
+}
+
+impl Protobuf for CurrencyPairParameters {
+    type Error = CurrencyPairParametersError;
+    type Raw = raw::CurrencyPairParameters;
+
+    /// Convert from a reference to a raw, unchecked protobuf [`raw::CurrencyPairParameters`].
+    ///
+    /// # Errors
+    ///
+    /// - if the `currency_pair` field is not set or invalid
+    /// - if `max_deviation_basis_points` is set but `max_age_seconds` is not
+    /// - if `twap_window_seconds` exceeds [`MAX_TWAP_WINDOW_SECONDS`]
+    fn try_from_raw_ref(raw: &Self::Raw) -> Result<Self, Self::Error> {
+        let Self::Raw {
+            currency_pair,
+            max_age_seconds,
+            max_deviation_basis_points,
+            twap_window_seconds,
+        } = raw;
+        let currency_pair = currency_pair
+            .clone()
+            .ok_or_else(|| Self::Error::field_not_set("currency_pair"))
+            .and_then(|pair| {
+                CurrencyPair::try_from_raw(pair).map_err(Self::Error::invalid_currency_pair)
+            })?;
+        if *max_deviation_basis_points != 0 && *max_age_seconds == 0 {
+            return Err(Self::Error::deviation_without_max_age());
+        }
+        if *twap_window_seconds > MAX_TWAP_WINDOW_SECONDS {
+            return Err(Self::Error::twap_window_too_large(*twap_window_seconds));
+        }
+        Ok(Self {
+            currency_pair,
+            max_age_seconds: *max_age_seconds,
+            max_deviation_basis_points: *max_deviation_basis_points,
+            twap_window_seconds: *twap_window_seconds,
+        })
+    }
+
+    fn to_raw(&self) -> Self::Raw {
+        self.clone().into_raw()
+    }
+
+    fn into_raw(self) -> Self::Raw {
+        Self::Raw {
+            currency_pair: Some(self.currency_pair.into_raw()),
+            max_age_seconds: self.max_age_seconds,
+            max_deviation_basis_points: self.max_deviation_basis_points,
+            twap_window_seconds: self.twap_window_seconds,
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error(transparent)]
+pub struct CurrencyPairParametersError(CurrencyPairParametersErrorKind);
+
+impl CurrencyPairParametersError {
+    fn field_not_set(field: &'static str) -> Self {
+        Self(CurrencyPairParametersErrorKind::FieldNotSet {
+            field,
+        })
+    }
+
+    fn invalid_currency_pair(err: CurrencyPairError) -> Self {
+        Self(CurrencyPairParametersErrorKind::InvalidCurrencyPair(err))
+    }
+
+    fn deviation_without_max_age() -> Self {
+        Self(CurrencyPairParametersErrorKind::DeviationWithoutMaxAge)
+    }
+
+    fn twap_window_too_large(twap_window_seconds: u64) -> Self {
+        Self(CurrencyPairParametersErrorKind::TwapWindowTooLarge {
+            twap_window_seconds,
+        })
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+enum CurrencyPairParametersErrorKind {
+    #[error("the expected field in the raw source type was not set: `{field}`")]
+    FieldNotSet { field: &'static str },
+    #[error("the `currency_pair` field was invalid")]
+    InvalidCurrencyPair(#[source] CurrencyPairError),
+    #[error(
+        "`max_deviation_basis_points` requires `max_age_seconds` to be set so that outliers \
+         cannot lock a price indefinitely"
+    )]
+    DeviationWithoutMaxAge,
+    #[error(
+        "`twap_window_seconds` was `{twap_window_seconds}` but must not exceed \
+         {MAX_TWAP_WINDOW_SECONDS}"
+    )]
+    TwapWindowTooLarge { twap_window_seconds: u64 },
 }
 
 /// Takes a list of markets and either creates, removes or updates them depending on its variant.
@@ -2777,3 +3168,609 @@ pub enum MarketsChangeErrorKind {
     #[error("change market action contained no markets to change")]
     MissingMarkets,
 }
+
+/// Turns the account of the transaction signer into a multisig account, or replaces the policy
+/// of an existing multisig account.
+///
+/// The members are kept sorted so that equal policies have equal representations.
+#[derive(Debug, Clone)]
+pub struct RegisterMultisig {
+    members: Vec<VerificationKey>,
+    threshold: u32,
+    pub fee_asset: asset::Denom,
+}
+
+impl RegisterMultisig {
+    /// Constructs a new `RegisterMultisig` action.
+    ///
+    /// # Errors
+    ///
+    /// - if `members` is empty or contains more than [`MAX_MULTISIG_MEMBERS`] keys
+    /// - if `members` contains duplicate keys
+    /// - if `threshold` is zero or greater than the number of members
+    pub fn try_new(
+        mut members: Vec<VerificationKey>,
+        threshold: u32,
+        fee_asset: asset::Denom,
+    ) -> Result<Self, RegisterMultisigError> {
+        if members.is_empty() || members.len() > MAX_MULTISIG_MEMBERS {
+            return Err(RegisterMultisigError::invalid_member_count(members.len()));
+        }
+        members.sort_unstable();
+        if let Some([duplicate, ..]) = members.windows(2).find(|pair| pair[0] == pair[1]) {
+            return Err(RegisterMultisigError::duplicate_member(duplicate));
+        }
+        if threshold == 0 || usize::try_from(threshold).map_or(true, |t| t > members.len()) {
+            return Err(RegisterMultisigError::invalid_threshold(
+                threshold,
+                members.len(),
+            ));
+        }
+        Ok(Self {
+            members,
+            threshold,
+            fee_asset,
+        })
+    }
+
+    /// Returns the verification keys of the members, sorted in ascending order.
+    #[must_use]
+    pub fn members(&self) -> &[VerificationKey] {
+        &self.members
+    }
+
+    /// Returns the number of member signatures required to authorize a transaction.
+    #[must_use]
+    pub fn threshold(&self) -> u32 {
+        self.threshold
+    }
+}
+
+impl Protobuf for RegisterMultisig {
+    type Error = RegisterMultisigError;
+    type Raw = raw::RegisterMultisig;
+
+    fn to_raw(&self) -> Self::Raw {
+        raw::RegisterMultisig {
+            members: self
+                .members
+                .iter()
+                .map(|member| Bytes::copy_from_slice(member.as_bytes()))
+                .collect(),
+            threshold: self.threshold,
+            fee_asset: self.fee_asset.to_string(),
+        }
+    }
+
+    /// Convert from a reference to a raw, unchecked protobuf [`raw::RegisterMultisig`].
+    ///
+    /// # Errors
+    ///
+    /// - if any of the `members` is not a valid verification key
+    /// - if the `fee_asset` field is invalid
+    /// - if the members and threshold do not form a valid policy; see [`RegisterMultisig::try_new`]
+    fn try_from_raw_ref(proto: &Self::Raw) -> Result<Self, Self::Error> {
+        let raw::RegisterMultisig {
+            members,
+            threshold,
+            fee_asset,
+        } = proto;
+        let members = members
+            .iter()
+            .map(|member| VerificationKey::try_from(&**member))
+            .collect::<Result<_, _>>()
+            .map_err(RegisterMultisigError::invalid_member)?;
+        let fee_asset = fee_asset
+            .parse()
+            .map_err(RegisterMultisigError::invalid_fee_asset)?;
+        Self::try_new(members, *threshold, fee_asset)
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error(transparent)]
+pub struct RegisterMultisigError(RegisterMultisigErrorKind);
+
+impl RegisterMultisigError {
+    fn invalid_member(source: crate::crypto::Error) -> Self {
+        Self(RegisterMultisigErrorKind::InvalidMember {
+            source,
+        })
+    }
+
+    fn invalid_member_count(count: usize) -> Self {
+        Self(RegisterMultisigErrorKind::InvalidMemberCount {
+            count,
+        })
+    }
+
+    fn duplicate_member(member: &VerificationKey) -> Self {
+        Self(RegisterMultisigErrorKind::DuplicateMember {
+            member: member.to_string(),
+        })
+    }
+
+    fn invalid_threshold(threshold: u32, members: usize) -> Self {
+        Self(RegisterMultisigErrorKind::InvalidThreshold {
+            threshold,
+            members,
+        })
+    }
+
+    fn invalid_fee_asset(source: asset::ParseDenomError) -> Self {
+        Self(RegisterMultisigErrorKind::InvalidFeeAsset {
+            source,
+        })
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+enum RegisterMultisigErrorKind {
+    #[error("a member in the `members` field was not a valid verification key")]
+    InvalidMember { source: crate::crypto::Error },
+    #[error(
+        "a multisig account must have between 1 and {MAX_MULTISIG_MEMBERS} members, but got \
+         {count}"
+    )]
+    InvalidMemberCount { count: usize },
+    #[error("member `{member}` was listed more than once")]
+    DuplicateMember { member: String },
+    #[error("the threshold `{threshold}` must be between 1 and the number of members `{members}`")]
+    InvalidThreshold { threshold: u32, members: usize },
+    #[error("the `fee_asset` field was invalid")]
+    InvalidFeeAsset { source: asset::ParseDenomError },
+}
+
+/// The point at which the funds of a [`TimelockedTransfer`] unlock.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TimelockUnlock {
+    /// The funds unlock once the sequencer reaches this block height.
+    Height(u64),
+    /// The funds unlock once a sequencer block has a timestamp at or after this time.
+    Time(tendermint::Time),
+}
+
+/// Moves funds from the signer's account into an escrow which the recipient can claim once
+/// `unlock` is reached, and which the signer can cancel before then.
+#[derive(Clone, Debug)]
+pub struct TimelockedTransfer {
+    pub to: Address,
+    pub amount: u128,
+    /// asset to be transferred.
+    pub asset: asset::Denom,
+    /// asset to use for fee payment.
+    pub fee_asset: asset::Denom,
+    pub unlock: TimelockUnlock,
+}
+
+impl Protobuf for TimelockedTransfer {
+    type Error = TimelockedTransferError;
+    type Raw = raw::TimelockedTransfer;
+
+    #[must_use]
+    fn to_raw(&self) -> raw::TimelockedTransfer {
+        let Self {
+            to,
+            amount,
+            asset,
+            fee_asset,
+            unlock,
+        } = self;
+        let unlock = match *unlock {
+            TimelockUnlock::Height(height) => raw::timelocked_transfer::Unlock::Height(height),
+            TimelockUnlock::Time(time) => {
+                let time: tendermint_proto::google::protobuf::Timestamp = time.into();
+                raw::timelocked_transfer::Unlock::Time(pbjson_types::Timestamp {
+                    seconds: time.seconds,
+                    nanos: time.nanos,
+                })
+            }
+        };
+        raw::TimelockedTransfer {
+            to: Some(to.to_raw()),
+            amount: Some((*amount).into()),
+            asset: asset.to_string(),
+            fee_asset: fee_asset.to_string(),
+            unlock: Some(unlock),
+        }
+    }
+
+    /// Convert from a reference to the raw protobuf type.
+    ///
+    /// # Errors
+    ///
+    /// - if the `to` or `unlock` fields are not set
+    /// - if the `to` field is not a valid address
+    /// - if the `asset` or `fee_asset` fields are invalid
+    /// - if the `unlock` field holds a timestamp that is out of range
+    fn try_from_raw_ref(raw: &Self::Raw) -> Result<Self, Self::Error> {
+        let raw::TimelockedTransfer {
+            to,
+            amount,
+            asset,
+            fee_asset,
+            unlock,
+        } = raw;
+        let Some(to) = to else {
+            return Err(TimelockedTransferError::field_not_set("to"));
+        };
+        let to = Address::try_from_raw_ref(to).map_err(TimelockedTransferError::address)?;
+        let amount = amount.map_or(0, Into::into);
+        let asset = asset.parse().map_err(TimelockedTransferError::asset)?;
+        let fee_asset = fee_asset
+            .parse()
+            .map_err(TimelockedTransferError::fee_asset)?;
+        let unlock = match unlock {
+            Some(raw::timelocked_transfer::Unlock::Height(height)) => {
+                TimelockUnlock::Height(*height)
+            }
+            Some(raw::timelocked_transfer::Unlock::Time(time)) => {
+                let time =
+                    tendermint::Time::try_from(tendermint_proto::google::protobuf::Timestamp {
+                        seconds: time.seconds,
+                        nanos: time.nanos,
+                    })
+                    .map_err(TimelockedTransferError::time)?;
+                TimelockUnlock::Time(time)
+            }
+            None => return Err(TimelockedTransferError::field_not_set("unlock")),
+        };
+
+        Ok(Self {
+            to,
+            amount,
+            asset,
+            fee_asset,
+            unlock,
+        })
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error(transparent)]
+pub struct TimelockedTransferError(TimelockedTransferErrorKind);
+
+impl TimelockedTransferError {
+    fn field_not_set(field: &'static str) -> Self {
+        Self(TimelockedTransferErrorKind::FieldNotSet(field))
+    }
+
+    fn address(inner: AddressError) -> Self {
+        Self(TimelockedTransferErrorKind::Address(inner))
+    }
+
+    fn asset(inner: asset::ParseDenomError) -> Self {
+        Self(TimelockedTransferErrorKind::Asset(inner))
+    }
+
+    fn fee_asset(inner: asset::ParseDenomError) -> Self {
+        Self(TimelockedTransferErrorKind::FeeAsset(inner))
+    }
+
+    fn time(inner: tendermint::Error) -> Self {
+        Self(TimelockedTransferErrorKind::Time(inner))
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+enum TimelockedTransferErrorKind {
+    #[error("the expected field in the raw source type was not set: `{0}`")]
+    FieldNotSet(&'static str),
+    #[error("`to` field did not contain a valid address")]
+    Address(#[source] AddressError),
+    #[error("`asset` field did not contain a valid asset ID")]
+    Asset(#[source] asset::ParseDenomError),
+    #[error("`fee_asset` field did not contain a valid asset ID")]
+    FeeAsset(#[source] asset::ParseDenomError),
+    #[error("`time` field of `unlock` did not contain a valid timestamp")]
+    Time(#[source] tendermint::Error),
+}
+
+/// Releases the funds of an unlocked [`TimelockedTransfer`] to its recipient.
+///
+/// Must be signed by the recipient of the timelocked transfer.
+#[derive(Clone, Debug)]
+pub struct ClaimTimelockedTransfer {
+    /// the ID of the timelocked transfer, as reported by the event emitted on its creation.
+    pub id: u64,
+    pub fee_asset: asset::Denom,
+}
+
+impl Protobuf for ClaimTimelockedTransfer {
+    type Error = TimelockedTransferIdError;
+    type Raw = raw::ClaimTimelockedTransfer;
+
+    #[must_use]
+    fn to_raw(&self) -> raw::ClaimTimelockedTransfer {
+        raw::ClaimTimelockedTransfer {
+            id: self.id,
+            fee_asset: self.fee_asset.to_string(),
+        }
+    }
+
+    /// Convert from a reference to the raw protobuf type.
+    ///
+    /// # Errors
+    ///
+    /// - if the `fee_asset` field is invalid
+    fn try_from_raw_ref(raw: &Self::Raw) -> Result<Self, Self::Error> {
+        let raw::ClaimTimelockedTransfer {
+            id,
+            fee_asset,
+        } = raw;
+        let fee_asset = fee_asset
+            .parse()
+            .map_err(TimelockedTransferIdError::fee_asset)?;
+        Ok(Self {
+            id: *id,
+            fee_asset,
+        })
+    }
+}
+
+/// Returns the funds of a still locked [`TimelockedTransfer`] to its sender.
+///
+/// Must be signed by the sender of the timelocked transfer.
+#[derive(Clone, Debug)]
+pub struct CancelTimelockedTransfer {
+    /// the ID of the timelocked transfer, as reported by the event emitted on its creation.
+    pub id: u64,
+    pub fee_asset: asset::Denom,
+}
+
+impl Protobuf for CancelTimelockedTransfer {
+    type Error = TimelockedTransferIdError;
+    type Raw = raw::CancelTimelockedTransfer;
+
+    #[must_use]
+    fn to_raw(&self) -> raw::CancelTimelockedTransfer {
+        raw::CancelTimelockedTransfer {
+            id: self.id,
+            fee_asset: self.fee_asset.to_string(),
+        }
+    }
+
+    /// Convert from a reference to the raw protobuf type.
+    ///
+    /// # Errors
+    ///
+    /// - if the `fee_asset` field is invalid
+    fn try_from_raw_ref(raw: &Self::Raw) -> Result<Self, Self::Error> {
+        let raw::CancelTimelockedTransfer {
+            id,
+            fee_asset,
+        } = raw;
+        let fee_asset = fee_asset
+            .parse()
+            .map_err(TimelockedTransferIdError::fee_asset)?;
+        Ok(Self {
+            id: *id,
+            fee_asset,
+        })
+    }
+}
+
+/// The error returned when converting a [`ClaimTimelockedTransfer`] or a
+/// [`CancelTimelockedTransfer`] from its raw protobuf representation.
+#[derive(Debug, thiserror::Error)]
+#[error(transparent)]
+pub struct TimelockedTransferIdError(TimelockedTransferIdErrorKind);
+
+impl TimelockedTransferIdError {
+    fn fee_asset(inner: asset::ParseDenomError) -> Self {
+        Self(TimelockedTransferIdErrorKind::FeeAsset(inner))
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+enum TimelockedTransferIdErrorKind {
+    #[error("`fee_asset` field did not contain a valid asset ID")]
+    FeeAsset(#[source] asset::ParseDenomError),
+}
+
+/// Opens a governance proposal to execute `action` once enough voters approve of it.
+///
+/// Must be signed by one of the proposers configured at genesis. Only sudo actions (including
+/// [`ValidatorUpdate`]) can be proposed, see [`Action::is_governable`].
+#[derive(Clone, Debug)]
+pub struct SubmitProposal {
+    pub action: Box<Action>,
+    pub fee_asset: asset::Denom,
+}
+
+impl Protobuf for SubmitProposal {
+    type Error = SubmitProposalError;
+    type Raw = raw::SubmitProposal;
+
+    #[must_use]
+    fn to_raw(&self) -> raw::SubmitProposal {
+        raw::SubmitProposal {
+            action: Some(Box::new(self.action.to_raw())),
+            fee_asset: self.fee_asset.to_string(),
+        }
+    }
+
+    /// Convert from a reference to the raw protobuf type.
+    ///
+    /// # Errors
+    ///
+    /// - if the `action` field is not set or is not a valid action
+    /// - if the `action` field holds an action that cannot be proposed
+    /// - if the `fee_asset` field is invalid
+    fn try_from_raw_ref(raw: &Self::Raw) -> Result<Self, Self::Error> {
+        let raw::SubmitProposal {
+            action,
+            fee_asset,
+        } = raw;
+        let Some(action) = action else {
+            return Err(SubmitProposalError::field_not_set("action"));
+        };
+        let action = Action::try_from_raw_ref(action).map_err(SubmitProposalError::action)?;
+        if !action.is_governable() {
+            return Err(SubmitProposalError::not_governable(action.name()));
+        }
+        let fee_asset = fee_asset.parse().map_err(SubmitProposalError::fee_asset)?;
+        Ok(Self {
+            action: Box::new(action),
+            fee_asset,
+        })
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error(transparent)]
+pub struct SubmitProposalError(SubmitProposalErrorKind);
+
+impl SubmitProposalError {
+    fn field_not_set(field: &'static str) -> Self {
+        Self(SubmitProposalErrorKind::FieldNotSet(field))
+    }
+
+    fn action(inner: Error) -> Self {
+        Self(SubmitProposalErrorKind::Action(Box::new(inner)))
+    }
+
+    fn not_governable(name: &'static str) -> Self {
+        Self(SubmitProposalErrorKind::NotGovernable(name))
+    }
+
+    fn fee_asset(inner: asset::ParseDenomError) -> Self {
+        Self(SubmitProposalErrorKind::FeeAsset(inner))
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+enum SubmitProposalErrorKind {
+    #[error("the expected field in the raw source type was not set: `{0}`")]
+    FieldNotSet(&'static str),
+    #[error("`action` field did not contain a valid action")]
+    Action(#[source] Box<Error>),
+    #[error("`action` field contained a `{0}` action, which cannot be proposed")]
+    NotGovernable(&'static str),
+    #[error("`fee_asset` field did not contain a valid asset ID")]
+    FeeAsset(#[source] asset::ParseDenomError),
+}
+
+/// Casts or replaces the signer's vote on an open governance proposal.
+///
+/// Must be signed by a member of the governance council or, if no council is configured, by a
+/// validator.
+#[derive(Clone, Debug)]
+pub struct VoteOnProposal {
+    /// the ID of the proposal, as reported by the event emitted on its submission.
+    pub proposal_id: u64,
+    pub approve: bool,
+    pub fee_asset: asset::Denom,
+}
+
+impl Protobuf for VoteOnProposal {
+    type Error = VoteOnProposalError;
+    type Raw = raw::VoteOnProposal;
+
+    #[must_use]
+    fn to_raw(&self) -> raw::VoteOnProposal {
+        raw::VoteOnProposal {
+            proposal_id: self.proposal_id,
+            approve: self.approve,
+            fee_asset: self.fee_asset.to_string(),
+        }
+    }
+
+    /// Convert from a reference to the raw protobuf type.
+    ///
+    /// # Errors
+    ///
+    /// - if the `fee_asset` field is invalid
+    fn try_from_raw_ref(raw: &Self::Raw) -> Result<Self, Self::Error> {
+        let raw::VoteOnProposal {
+            proposal_id,
+            approve,
+            fee_asset,
+        } = raw;
+        let fee_asset = fee_asset.parse().map_err(VoteOnProposalError::fee_asset)?;
+        Ok(Self {
+            proposal_id: *proposal_id,
+            approve: *approve,
+            fee_asset,
+        })
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error(transparent)]
+pub struct VoteOnProposalError(VoteOnProposalErrorKind);
+
+impl VoteOnProposalError {
+    fn fee_asset(inner: asset::ParseDenomError) -> Self {
+        Self(VoteOnProposalErrorKind::FeeAsset(inner))
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+enum VoteOnProposalErrorKind {
+    #[error("`fee_asset` field did not contain a valid asset ID")]
+    FeeAsset(#[source] asset::ParseDenomError),
+}
+
+/// Replaces how the fees collected in a block are split at the end of the block.
+///
+/// Must be signed by the sudo address.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FeeDistributionChange {
+    pub new_distribution: FeeDistribution,
+}
+
+impl Protobuf for FeeDistributionChange {
+    type Error = FeeDistributionChangeError;
+    type Raw = raw::FeeDistributionChange;
+
+    #[must_use]
+    fn to_raw(&self) -> raw::FeeDistributionChange {
+        raw::FeeDistributionChange {
+            new_distribution: Some(self.new_distribution.to_raw()),
+        }
+    }
+
+    /// Convert from a reference to the raw protobuf type.
+    ///
+    /// # Errors
+    ///
+    /// - if the `new_distribution` field is not set or is invalid
+    fn try_from_raw_ref(raw: &Self::Raw) -> Result<Self, Self::Error> {
+        let raw::FeeDistributionChange {
+            new_distribution,
+        } = raw;
+        let Some(new_distribution) = new_distribution else {
+            return Err(FeeDistributionChangeError::field_not_set(
+                "new_distribution",
+            ));
+        };
+        let new_distribution = FeeDistribution::try_from_raw_ref(new_distribution)
+            .map_err(FeeDistributionChangeError::new_distribution)?;
+        Ok(Self {
+            new_distribution,
+        })
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error(transparent)]
+pub struct FeeDistributionChangeError(FeeDistributionChangeErrorKind);
+
+impl FeeDistributionChangeError {
+    fn field_not_set(field: &'static str) -> Self {
+        Self(FeeDistributionChangeErrorKind::FieldNotSet(field))
+    }
+
+    fn new_distribution(inner: FeeDistributionError) -> Self {
+        Self(FeeDistributionChangeErrorKind::NewDistribution(inner))
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+enum FeeDistributionChangeErrorKind {
+    #[error("the expected field in the raw source type was not set: `{0}`")]
+    FieldNotSet(&'static str),
+    #[error("`new_distribution` field was invalid")]
+    NewDistribution(#[source] FeeDistributionError),
+}
@@ -53,6 +53,48 @@ impl TransactionError {
     fn unset_body() -> Self {
         Self(TransactionErrorKind::UnsetBody)
     }
+
+    fn multisig_and_single_signature() -> Self {
+        Self(TransactionErrorKind::MultisigAndSingleSignature)
+    }
+
+    fn multisig_address(actual: usize) -> Self {
+        Self(TransactionErrorKind::MultisigAddress {
+            expected: ADDRESS_LEN,
+            actual,
+        })
+    }
+
+    fn no_multisig_signatures() -> Self {
+        Self(TransactionErrorKind::NoMultisigSignatures)
+    }
+
+    fn member_signature(index: usize, inner: crypto::Error) -> Self {
+        Self(TransactionErrorKind::MemberSignature {
+            index,
+            source: inner,
+        })
+    }
+
+    fn member_verification_key(index: usize, inner: crypto::Error) -> Self {
+        Self(TransactionErrorKind::MemberVerificationKey {
+            index,
+            source: inner,
+        })
+    }
+
+    fn member_verification(index: usize, inner: crypto::Error) -> Self {
+        Self(TransactionErrorKind::MemberVerification {
+            index,
+            source: inner,
+        })
+    }
+
+    fn duplicate_member_signature(index: usize) -> Self {
+        Self(TransactionErrorKind::DuplicateMemberSignature {
+            index,
+        })
+    }
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -67,20 +109,199 @@ enum TransactionErrorKind {
     VerificationKey(#[source] crypto::Error),
     #[error("transaction could not be verified given the signature and verification key")]
     Verification(crypto::Error),
+    #[error("`multisig` field must not be set together with `signature` or `public_key`")]
+    MultisigAndSingleSignature,
+    #[error("`multisig.address` must be {expected} bytes, but {actual} bytes provided")]
+    MultisigAddress { expected: usize, actual: usize },
+    #[error("`multisig.signatures` field contained no signatures")]
+    NoMultisigSignatures,
+    #[error("`multisig.signatures[{index}].signature` field invalid")]
+    MemberSignature { index: usize, source: crypto::Error },
+    #[error("`multisig.signatures[{index}].public_key` field invalid")]
+    MemberVerificationKey { index: usize, source: crypto::Error },
+    #[error(
+        "transaction could not be verified given the signature and verification key at \
+         `multisig.signatures[{index}]`"
+    )]
+    MemberVerification { index: usize, source: crypto::Error },
+    #[error("`multisig.signatures[{index}]` repeats the verification key of an earlier signature")]
+    DuplicateMemberSignature { index: usize },
 }
 
 /// An Astria transaction.
 ///
-/// [`Transaction`] contains an [`Body`] together
-/// with its signature and public key.
+/// [`Transaction`] contains an [`Body`] together with the signature(s) authorizing it: either
+/// a single signature and public key, or the signatures of the members of a multisig account.
 #[derive(Clone, Debug)]
 pub struct Transaction {
-    signature: Signature,
-    verification_key: VerificationKey,
+    authorization: Authorization,
     body: TransactionBody,
     body_bytes: bytes::Bytes,
 }
 
+#[derive(Clone, Debug)]
+#[expect(
+    clippy::large_enum_variant,
+    reason = "single-signature transactions are by far the most common, so boxing them would add \
+              an allocation to nearly every transaction"
+)]
+enum Authorization {
+    Single {
+        signature: Signature,
+        verification_key: VerificationKey,
+    },
+    Multisig(MultisigSignatures),
+}
+
+impl Authorization {
+    /// Verifies the raw authorization fields of a transaction over `body_bytes`.
+    fn try_from_raw_parts(
+        signature: &[u8],
+        public_key: &[u8],
+        multisig: Option<&raw::MultisigSignatures>,
+        body_bytes: &[u8],
+    ) -> Result<Self, TransactionError> {
+        if let Some(multisig) = multisig {
+            if !signature.is_empty() || !public_key.is_empty() {
+                return Err(TransactionError::multisig_and_single_signature());
+            }
+            return MultisigSignatures::try_from_raw_ref(multisig, body_bytes).map(Self::Multisig);
+        }
+        let signature = Signature::try_from(signature).map_err(TransactionError::signature)?;
+        let verification_key =
+            VerificationKey::try_from(public_key).map_err(TransactionError::verification_key)?;
+        verification_key
+            .verify(&signature, body_bytes)
+            .map_err(TransactionError::verification)?;
+        Ok(Self::Single {
+            signature,
+            verification_key,
+        })
+    }
+
+    /// Returns the raw `signature`, `public_key`, and `multisig` fields of a transaction.
+    fn to_raw_parts(&self) -> (Bytes, Bytes, Option<raw::MultisigSignatures>) {
+        match self {
+            Self::Single {
+                signature,
+                verification_key,
+            } => (
+                Bytes::copy_from_slice(&signature.to_bytes()),
+                Bytes::copy_from_slice(&verification_key.to_bytes()),
+                None,
+            ),
+            Self::Multisig(multisig) => (Bytes::new(), Bytes::new(), Some(multisig.to_raw())),
+        }
+    }
+}
+
+/// The signatures of the members of a multisig account over a transaction body.
+///
+/// Every signature is verified on construction, and no verification key appears more than once.
+/// Whether the signers are members of the account's policy and meet its threshold can only be
+/// checked against chain state.
+#[derive(Clone, Debug)]
+pub struct MultisigSignatures {
+    address_bytes: [u8; ADDRESS_LEN],
+    signatures: Vec<MemberSignature>,
+}
+
+impl MultisigSignatures {
+    #[must_use]
+    pub fn address_bytes(&self) -> &[u8; ADDRESS_LEN] {
+        &self.address_bytes
+    }
+
+    #[must_use]
+    pub fn signatures(&self) -> &[MemberSignature] {
+        &self.signatures
+    }
+
+    /// Returns an iterator over the verification keys of all signers.
+    pub fn signers(&self) -> impl Iterator<Item = &VerificationKey> {
+        self.signatures.iter().map(|sig| &sig.verification_key)
+    }
+
+    fn try_from_raw_ref(
+        raw: &raw::MultisigSignatures,
+        body_bytes: &[u8],
+    ) -> Result<Self, TransactionError> {
+        let raw::MultisigSignatures {
+            address,
+            signatures,
+        } = raw;
+        let address_bytes = <[u8; ADDRESS_LEN]>::try_from(&**address)
+            .map_err(|_| TransactionError::multisig_address(address.len()))?;
+        if signatures.is_empty() {
+            return Err(TransactionError::no_multisig_signatures());
+        }
+        let mut verified: Vec<MemberSignature> = Vec::with_capacity(signatures.len());
+        for (
+            index,
+            raw::MemberSignature {
+                public_key,
+                signature,
+            },
+        ) in signatures.iter().enumerate()
+        {
+            let verification_key = VerificationKey::try_from(&**public_key)
+                .map_err(|e| TransactionError::member_verification_key(index, e))?;
+            let signature = Signature::try_from(&**signature)
+                .map_err(|e| TransactionError::member_signature(index, e))?;
+            if verified
+                .iter()
+                .any(|sig| sig.verification_key == verification_key)
+            {
+                return Err(TransactionError::duplicate_member_signature(index));
+            }
+            verification_key
+                .verify(&signature, body_bytes)
+                .map_err(|e| TransactionError::member_verification(index, e))?;
+            verified.push(MemberSignature {
+                verification_key,
+                signature,
+            });
+        }
+        Ok(Self {
+            address_bytes,
+            signatures: verified,
+        })
+    }
+
+    fn to_raw(&self) -> raw::MultisigSignatures {
+        raw::MultisigSignatures {
+            address: Bytes::copy_from_slice(&self.address_bytes),
+            signatures: self
+                .signatures
+                .iter()
+                .map(|sig| raw::MemberSignature {
+                    public_key: Bytes::copy_from_slice(&sig.verification_key.to_bytes()),
+                    signature: Bytes::copy_from_slice(&sig.signature.to_bytes()),
+                })
+                .collect(),
+        }
+    }
+}
+
+/// The signature of a single member of a multisig account.
+#[derive(Clone, Debug)]
+pub struct MemberSignature {
+    verification_key: VerificationKey,
+    signature: Signature,
+}
+
+impl MemberSignature {
+    #[must_use]
+    pub fn verification_key(&self) -> &VerificationKey {
+        &self.verification_key
+    }
+
+    #[must_use]
+    pub fn signature(&self) -> Signature {
+        self.signature
+    }
+}
+
 impl Protobuf for Transaction {
     type Error = TransactionError;
     type Raw = raw::Transaction;
@@ -90,22 +311,18 @@ impl Protobuf for Transaction {
             signature,
             public_key,
             body,
+            multisig,
         } = raw;
-        let signature = Signature::try_from(&**signature).map_err(TransactionError::signature)?;
-        let verification_key =
-            VerificationKey::try_from(&**public_key).map_err(TransactionError::verification_key)?;
         let Some(body) = body else {
             return Err(TransactionError::unset_body());
         };
         let bytes = body.value.clone();
-        verification_key
-            .verify(&signature, &bytes)
-            .map_err(TransactionError::verification)?;
+        let authorization =
+            Authorization::try_from_raw_parts(signature, public_key, multisig.as_ref(), &bytes)?;
         let transaction =
             TransactionBody::try_from_any(body.clone()).map_err(TransactionError::body)?;
         Ok(Self {
-            signature,
-            verification_key,
+            authorization,
             body: transaction,
             body_bytes: bytes,
         })
@@ -116,21 +333,17 @@ impl Protobuf for Transaction {
             signature,
             public_key,
             body,
+            multisig,
         } = raw;
-        let signature = Signature::try_from(&*signature).map_err(TransactionError::signature)?;
-        let verification_key =
-            VerificationKey::try_from(&*public_key).map_err(TransactionError::verification_key)?;
         let Some(body) = body else {
             return Err(TransactionError::unset_body());
         };
         let bytes = body.value.clone();
-        verification_key
-            .verify(&signature, &bytes)
-            .map_err(TransactionError::verification)?;
+        let authorization =
+            Authorization::try_from_raw_parts(&signature, &public_key, multisig.as_ref(), &bytes)?;
         let transaction = TransactionBody::try_from_any(body).map_err(TransactionError::body)?;
         Ok(Self {
-            signature,
-            verification_key,
+            authorization,
             body: transaction,
             body_bytes: bytes,
         })
@@ -138,42 +351,53 @@ impl Protobuf for Transaction {
 
     fn into_raw(self) -> raw::Transaction {
         let Self {
-            signature,
-            verification_key,
+            authorization,
             body_bytes: transaction_bytes,
             ..
         } = self;
+        let (signature, public_key, multisig) = authorization.to_raw_parts();
         Self::Raw {
-            signature: Bytes::copy_from_slice(&signature.to_bytes()),
-            public_key: Bytes::copy_from_slice(&verification_key.to_bytes()),
+            signature,
+            public_key,
             body: Some(pbjson_types::Any {
                 type_url: raw::TransactionBody::type_url(),
                 value: transaction_bytes,
             }),
+            multisig,
         }
     }
 
     fn to_raw(&self) -> raw::Transaction {
         let Self {
-            signature,
-            verification_key,
+            authorization,
             body_bytes: transaction_bytes,
             ..
         } = self;
+        let (signature, public_key, multisig) = authorization.to_raw_parts();
         Self::Raw {
-            signature: Bytes::copy_from_slice(&signature.to_bytes()),
-            public_key: Bytes::copy_from_slice(&verification_key.to_bytes()),
+            signature,
+            public_key,
             body: Some(pbjson_types::Any {
                 type_url: raw::TransactionBody::type_url(),
                 value: transaction_bytes.clone(),
             }),
+            multisig,
         }
     }
 }
 
 impl Transaction {
+    /// Returns the address bytes of the account on whose behalf the transaction is executed.
+    ///
+    /// This is the address derived from the verification key for single-signature transactions,
+    /// and the multisig account's address for multisig transactions.
     pub fn address_bytes(&self) -> &[u8; ADDRESS_LEN] {
-        self.verification_key.address_bytes()
+        match &self.authorization {
+            Authorization::Single {
+                verification_key, ..
+            } => verification_key.address_bytes(),
+            Authorization::Multisig(multisig) => multisig.address_bytes(),
+        }
     }
 
     /// Returns the transaction ID, containing the transaction hash.
@@ -210,14 +434,40 @@ impl Transaction {
         self.body.actions.group().is_bundleable_sudo()
     }
 
+    /// Returns the signature of a single-signature transaction, or `None` if this is a
+    /// multisig transaction.
     #[must_use]
-    pub fn signature(&self) -> Signature {
-        self.signature
+    pub fn signature(&self) -> Option<Signature> {
+        match &self.authorization {
+            Authorization::Single {
+                signature, ..
+            } => Some(*signature),
+            Authorization::Multisig(_) => None,
+        }
     }
 
+    /// Returns the verification key of a single-signature transaction, or `None` if this is a
+    /// multisig transaction.
     #[must_use]
-    pub fn verification_key(&self) -> &VerificationKey {
-        &self.verification_key
+    pub fn verification_key(&self) -> Option<&VerificationKey> {
+        match &self.authorization {
+            Authorization::Single {
+                verification_key, ..
+            } => Some(verification_key),
+            Authorization::Multisig(_) => None,
+        }
+    }
+
+    /// Returns the member signatures of a multisig transaction, or `None` if this is a
+    /// single-signature transaction.
+    #[must_use]
+    pub fn multisig_signatures(&self) -> Option<&MultisigSignatures> {
+        match &self.authorization {
+            Authorization::Single {
+                ..
+            } => None,
+            Authorization::Multisig(multisig) => Some(multisig),
+        }
     }
 
     #[must_use]
@@ -368,8 +618,44 @@ impl TransactionBody {
         let signature = signing_key.sign(&bytes);
         let verification_key = signing_key.verification_key();
         Transaction {
-            signature,
-            verification_key,
+            authorization: Authorization::Single {
+                signature,
+                verification_key,
+            },
+            body: self,
+            body_bytes: bytes.into(),
+        }
+    }
+
+    /// Signs the transaction body on behalf of the multisig account at `address_bytes`.
+    ///
+    /// Every key in `signing_keys` signs the body. Duplicate keys are only used once.
+    #[must_use]
+    pub fn sign_multisig(
+        self,
+        address_bytes: [u8; ADDRESS_LEN],
+        signing_keys: &[SigningKey],
+    ) -> Transaction {
+        let bytes = self.to_raw().encode_to_vec();
+        let mut signatures: Vec<MemberSignature> = Vec::with_capacity(signing_keys.len());
+        for signing_key in signing_keys {
+            let verification_key = signing_key.verification_key();
+            if signatures
+                .iter()
+                .any(|sig| sig.verification_key == verification_key)
+            {
+                continue;
+            }
+            signatures.push(MemberSignature {
+                verification_key,
+                signature: signing_key.sign(&bytes),
+            });
+        }
+        Transaction {
+            authorization: Authorization::Multisig(MultisigSignatures {
+                address_bytes,
+                signatures,
+            }),
             body: self,
             body_bytes: bytes.into(),
         }
@@ -615,8 +901,10 @@ mod tests {
             .unwrap();
 
         let tx = Transaction {
-            signature,
-            verification_key,
+            authorization: Authorization::Single {
+                signature,
+                verification_key,
+            },
             body: body.clone(),
             body_bytes: body.to_raw().encode_to_vec().into(),
         };
@@ -655,4 +943,105 @@ mod tests {
         // `try_from_raw` verifies the signature
         Transaction::try_from_raw(raw).unwrap();
     }
+
+    fn multisig_test_body() -> TransactionBody {
+        let transfer = Transfer {
+            to: Address::builder()
+                .array([0; 20])
+                .prefix(ASTRIA_ADDRESS_PREFIX)
+                .try_build()
+                .unwrap(),
+            amount: 0,
+            asset: asset(),
+            fee_asset: asset(),
+        };
+        TransactionBody::builder()
+            .actions(vec![transfer.into()])
+            .chain_id("test-1".to_string())
+            .nonce(1)
+            .try_build()
+            .unwrap()
+    }
+
+    #[test]
+    fn multisig_transaction_verification_roundtrip() {
+        let signing_keys = [SigningKey::from([1; 32]), SigningKey::from([2; 32])];
+        let signed_tx = multisig_test_body().sign_multisig([9; 20], &signing_keys);
+        assert_eq!(&[9; 20], signed_tx.address_bytes());
+        assert!(signed_tx.verification_key().is_none());
+
+        let raw = signed_tx.to_raw();
+        assert!(raw.signature.is_empty());
+        assert!(raw.public_key.is_empty());
+
+        let tx = Transaction::try_from_raw(raw).unwrap();
+        let multisig = tx.multisig_signatures().unwrap();
+        assert_eq!(&[9; 20], multisig.address_bytes());
+        assert_eq!(
+            signing_keys
+                .iter()
+                .map(SigningKey::verification_key)
+                .collect::<Vec<_>>(),
+            multisig.signers().cloned().collect::<Vec<_>>(),
+        );
+    }
+
+    #[test]
+    fn multisig_transaction_with_invalid_member_signature_is_rejected() {
+        let signing_keys = [SigningKey::from([1; 32]), SigningKey::from([2; 32])];
+        let mut raw = multisig_test_body()
+            .sign_multisig([9; 20], &signing_keys)
+            .into_raw();
+        let multisig = raw.multisig.as_mut().unwrap();
+        multisig.signatures[1].signature = multisig.signatures[0].signature.clone();
+
+        let error = Transaction::try_from_raw(raw).unwrap_err();
+        assert!(
+            matches!(
+                error.0,
+                TransactionErrorKind::MemberVerification {
+                    index: 1,
+                    ..
+                }
+            ),
+            "{error:?}"
+        );
+    }
+
+    #[test]
+    fn multisig_transaction_with_duplicate_member_is_rejected() {
+        let signing_keys = [SigningKey::from([1; 32])];
+        let mut raw = multisig_test_body()
+            .sign_multisig([9; 20], &signing_keys)
+            .into_raw();
+        let multisig = raw.multisig.as_mut().unwrap();
+        multisig.signatures.push(multisig.signatures[0].clone());
+
+        let error = Transaction::try_from_raw(raw).unwrap_err();
+        assert!(
+            matches!(
+                error.0,
+                TransactionErrorKind::DuplicateMemberSignature {
+                    index: 1
+                }
+            ),
+            "{error:?}"
+        );
+    }
+
+    #[test]
+    fn multisig_transaction_with_single_signature_is_rejected() {
+        let signing_key = SigningKey::from([1; 32]);
+        let single = multisig_test_body().sign(&signing_key).into_raw();
+        let mut raw = multisig_test_body()
+            .sign_multisig([9; 20], &[signing_key])
+            .into_raw();
+        raw.signature = single.signature;
+
+        let error = Transaction::try_from_raw(raw).unwrap_err();
+        assert!(
+            matches!(error.0, TransactionErrorKind::MultisigAndSingleSignature),
+            "{error:?}"
+        );
+    }
 }
@@ -218,6 +218,7 @@ mod expanded_block_data {
             signature: vec![1].into(),
             public_key: vec![2].into(),
             body: None,
+            multisig: None,
         };
         let data = [
             rollup_txs_root_legacy_bytes(),
@@ -347,6 +348,7 @@ mod expanded_block_data {
             signature: vec![1].into(),
             public_key: vec![2].into(),
             body: None,
+            multisig: None,
         };
         let data = [
             rollup_txs_root_bytes(),
@@ -0,0 +1,271 @@
+use std::{
+    future::{
+        Future,
+        IntoFuture as _,
+    },
+    net::SocketAddr,
+};
+
+use astria_core::primitive::v1::{
+    Address,
+    RollupId,
+    ADDRESS_LEN,
+};
+use astria_eyre::eyre::{
+    self,
+    WrapErr as _,
+};
+use axum::{
+    extract::{
+        Path,
+        Query,
+        State,
+    },
+    response::{
+        IntoResponse,
+        Response,
+    },
+    routing::get,
+    Json,
+    Router,
+};
+use base64::{
+    engine::general_purpose::URL_SAFE,
+    Engine as _,
+};
+use futures::FutureExt as _;
+use http::status::StatusCode;
+use serde::Serialize;
+use tokio_util::sync::CancellationToken;
+use tracing::{
+    instrument,
+    warn,
+};
+
+use crate::{
+    model::{
+        Block,
+        Deposit,
+        SequencedData,
+        Transaction,
+    },
+    store::{
+        Page,
+        Store,
+    },
+};
+
+pub(super) async fn serve(
+    socket_addr: &str,
+    store: Store,
+    shutdown_token: CancellationToken,
+) -> eyre::Result<Serve> {
+    let listener = tokio::net::TcpListener::bind(socket_addr)
+        .await
+        .wrap_err_with(|| format!("failed to bind TCP socket at `{socket_addr}`"))?;
+    let serve = axum::serve(listener, router(store))
+        .with_graceful_shutdown(shutdown_token.cancelled_owned());
+    let local_addr = serve
+        .local_addr()
+        .wrap_err("bound TCP listener failed to yield local address")?;
+    Ok(Serve {
+        local_addr,
+        fut: serve.into_future().boxed(),
+    })
+}
+
+fn router(store: Store) -> Router {
+    Router::new()
+        .route("/healthz", get(get_healthz))
+        .route("/blocks/latest", get(get_latest_block))
+        .route("/blocks/:height", get(get_block))
+        .route("/blocks/:height/transactions", get(get_block_transactions))
+        .route("/transactions/:hash", get(get_transaction))
+        .route(
+            "/accounts/:address/transactions",
+            get(get_account_transactions),
+        )
+        .route("/accounts/:address/deposits", get(get_account_deposits))
+        .route("/rollups/:rollup_id/deposits", get(get_rollup_deposits))
+        .route("/rollups/:rollup_id/data", get(get_rollup_data))
+        .with_state(store)
+}
+
+/// A wrapper around a type-erased [`axum::Serve::serve`] future.
+pub(super) struct Serve {
+    local_addr: SocketAddr,
+    fut: futures::future::BoxFuture<'static, std::io::Result<()>>,
+}
+
+impl Serve {
+    pub(super) fn local_addr(&self) -> SocketAddr {
+        self.local_addr
+    }
+}
+
+impl Future for Serve {
+    type Output = std::io::Result<()>;
+
+    fn poll(
+        mut self: std::pin::Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<Self::Output> {
+        self.fut.as_mut().poll(cx)
+    }
+}
+
+#[instrument(skip_all)]
+async fn get_healthz() -> Json<serde_json::Value> {
+    Json(serde_json::json!({ "status": "ok" }))
+}
+
+#[instrument(skip_all)]
+async fn get_latest_block(State(store): State<Store>) -> Result<Json<Block>, Error> {
+    store.block(None).await?.map(Json).ok_or(Error::NotFound)
+}
+
+#[instrument(skip_all, fields(%height))]
+async fn get_block(
+    State(store): State<Store>,
+    Path(height): Path<u64>,
+) -> Result<Json<Block>, Error> {
+    store
+        .block(Some(height))
+        .await?
+        .map(Json)
+        .ok_or(Error::NotFound)
+}
+
+#[instrument(skip_all, fields(%height))]
+async fn get_block_transactions(
+    State(store): State<Store>,
+    Path(height): Path<u64>,
+) -> Result<Json<Vec<Transaction>>, Error> {
+    if store.block(Some(height)).await?.is_none() {
+        return Err(Error::NotFound);
+    }
+    Ok(Json(store.block_transactions(height).await?))
+}
+
+#[instrument(skip_all)]
+async fn get_transaction(
+    State(store): State<Store>,
+    Path(hash): Path<String>,
+) -> Result<Json<Transaction>, Error> {
+    store
+        .transaction(hash)
+        .await?
+        .map(Json)
+        .ok_or(Error::NotFound)
+}
+
+#[instrument(skip_all)]
+async fn get_account_transactions(
+    State(store): State<Store>,
+    Path(address): Path<String>,
+    Query(page): Query<Page>,
+) -> Result<Json<Vec<Transaction>>, Error> {
+    let address = parse_address(&address)?;
+    Ok(Json(store.account_transactions(address, page).await?))
+}
+
+#[instrument(skip_all)]
+async fn get_account_deposits(
+    State(store): State<Store>,
+    Path(address): Path<String>,
+    Query(page): Query<Page>,
+) -> Result<Json<Vec<Deposit>>, Error> {
+    let address = parse_address(&address)?;
+    Ok(Json(store.account_deposits(address, page).await?))
+}
+
+#[instrument(skip_all)]
+async fn get_rollup_deposits(
+    State(store): State<Store>,
+    Path(rollup_id): Path<String>,
+    Query(page): Query<Page>,
+) -> Result<Json<Vec<Deposit>>, Error> {
+    let rollup_id = parse_rollup_id(&rollup_id)?;
+    Ok(Json(
+        store.rollup_deposits(rollup_id.to_string(), page).await?,
+    ))
+}
+
+#[instrument(skip_all)]
+async fn get_rollup_data(
+    State(store): State<Store>,
+    Path(rollup_id): Path<String>,
+    Query(page): Query<Page>,
+) -> Result<Json<Vec<SequencedData>>, Error> {
+    let rollup_id = parse_rollup_id(&rollup_id)?;
+    Ok(Json(
+        store
+            .rollup_sequenced_data(rollup_id.to_string(), page)
+            .await?,
+    ))
+}
+
+/// Parses a bech32m encoded sequencer address of any prefix.
+fn parse_address(input: &str) -> Result<[u8; ADDRESS_LEN], Error> {
+    input
+        .parse::<Address>()
+        .map(Address::bytes)
+        .map_err(|error| Error::BadRequest(format!("invalid address `{input}`: {error}")))
+}
+
+/// Parses a rollup ID given either as 64 hex characters or as URL-safe base64.
+fn parse_rollup_id(input: &str) -> Result<RollupId, Error> {
+    let bytes = if input.len() == 64 {
+        hex::decode(input).ok()
+    } else {
+        URL_SAFE.decode(input).ok()
+    };
+    bytes
+        .and_then(|bytes| RollupId::try_from_slice(&bytes).ok())
+        .ok_or_else(|| {
+            Error::BadRequest(format!(
+                "invalid rollup ID `{input}`: expected 32 bytes encoded as hex or URL-safe base64"
+            ))
+        })
+}
+
+enum Error {
+    BadRequest(String),
+    NotFound,
+    Internal(eyre::Report),
+}
+
+impl From<eyre::Report> for Error {
+    fn from(error: eyre::Report) -> Self {
+        Self::Internal(error)
+    }
+}
+
+impl IntoResponse for Error {
+    fn into_response(self) -> Response {
+        #[derive(Debug, Serialize)]
+        struct ErrorBody {
+            error: String,
+        }
+        let (status, msg) = match self {
+            Self::BadRequest(msg) => (StatusCode::BAD_REQUEST, msg),
+            Self::NotFound => (StatusCode::NOT_FOUND, "not found".to_string()),
+            Self::Internal(error) => {
+                warn!(%error, "failed serving request");
+                (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    "internal server error".to_string(),
+                )
+            }
+        };
+        let mut response = Json(ErrorBody {
+            error: msg,
+        })
+        .into_response();
+        *response.status_mut() = status;
+        response
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,129 @@
+use axum::{
+    body::Body,
+    http::Request,
+};
+use http::StatusCode;
+use serde_json::Value;
+use tower::ServiceExt as _;
+
+use super::router;
+use crate::{
+    store::Store,
+    test_utils::{
+        make_indexed_block,
+        recipient,
+        DEPOSIT_ROLLUP,
+        SEQUENCED_ROLLUP,
+    },
+};
+
+async fn get(store: &Store, uri: &str) -> (StatusCode, Value) {
+    let response = router(store.clone())
+        .oneshot(Request::get(uri).body(Body::empty()).unwrap())
+        .await
+        .unwrap();
+    let status = response.status();
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    (status, serde_json::from_slice(&body).unwrap())
+}
+
+async fn store_with_blocks(count: u32) -> Store {
+    let store = Store::open_in_memory().unwrap();
+    for height in 1..=count {
+        store
+            .insert_block(make_indexed_block(height))
+            .await
+            .unwrap();
+    }
+    store
+}
+
+#[tokio::test]
+async fn blocks_are_served() {
+    let store = store_with_blocks(2).await;
+
+    let (status, latest) = get(&store, "/blocks/latest").await;
+    assert_eq!(StatusCode::OK, status);
+    assert_eq!(2, latest["height"]);
+
+    let (status, block) = get(&store, "/blocks/1").await;
+    assert_eq!(StatusCode::OK, status);
+    assert_eq!(1, block["height"]);
+    assert_eq!(1, block["transaction_count"]);
+
+    let (status, txs) = get(&store, "/blocks/1/transactions").await;
+    assert_eq!(StatusCode::OK, status);
+    let hash = txs[0]["hash"].as_str().unwrap();
+
+    let (status, tx) = get(&store, &format!("/transactions/{hash}")).await;
+    assert_eq!(StatusCode::OK, status);
+    assert_eq!(txs[0], tx);
+}
+
+#[tokio::test]
+async fn missing_records_are_not_found() {
+    let store = store_with_blocks(1).await;
+    for uri in ["/blocks/2", "/blocks/2/transactions", "/transactions/abcd"] {
+        let (status, body) = get(&store, uri).await;
+        assert_eq!(StatusCode::NOT_FOUND, status, "{uri}");
+        assert_eq!("not found", body["error"], "{uri}");
+    }
+    let (status, _) = get(&Store::open_in_memory().unwrap(), "/blocks/latest").await;
+    assert_eq!(StatusCode::NOT_FOUND, status);
+}
+
+#[tokio::test]
+async fn account_and_rollup_records_are_served() {
+    let store = store_with_blocks(3).await;
+
+    let (status, txs) = get(
+        &store,
+        &format!("/accounts/{}/transactions?limit=2", recipient()),
+    )
+    .await;
+    assert_eq!(StatusCode::OK, status);
+    assert_eq!(2, txs.as_array().unwrap().len());
+    assert_eq!(3, txs[0]["height"]);
+
+    let (status, by_base64) = get(&store, &format!("/rollups/{DEPOSIT_ROLLUP}/deposits")).await;
+    assert_eq!(StatusCode::OK, status);
+    assert_eq!(3, by_base64.as_array().unwrap().len());
+    assert_eq!("3", by_base64[0]["amount"]);
+
+    let (status, by_hex) = get(
+        &store,
+        &format!("/rollups/{}/deposits", hex::encode(DEPOSIT_ROLLUP)),
+    )
+    .await;
+    assert_eq!(StatusCode::OK, status);
+    assert_eq!(by_base64, by_hex);
+
+    let (status, data) = get(
+        &store,
+        &format!("/rollups/{SEQUENCED_ROLLUP}/data?offset=2"),
+    )
+    .await;
+    assert_eq!(StatusCode::OK, status);
+    assert_eq!(1, data.as_array().unwrap().len());
+    assert_eq!(1, data[0]["height"]);
+}
+
+#[tokio::test]
+async fn malformed_identifiers_are_rejected() {
+    let store = store_with_blocks(1).await;
+    for uri in [
+        "/accounts/notanaddress/transactions",
+        "/accounts/notanaddress/deposits",
+        "/rollups/abcd/deposits",
+        "/rollups/abcd/data",
+    ] {
+        let (status, body) = get(&store, uri).await;
+        assert_eq!(StatusCode::BAD_REQUEST, status, "{uri}");
+        assert!(
+            body["error"].as_str().unwrap().starts_with("invalid"),
+            "{uri}"
+        );
+    }
+}
@@ -0,0 +1,3 @@
+use astria_build_info::BuildInfo;
+
+pub const BUILD_INFO: BuildInfo = astria_build_info::get!();
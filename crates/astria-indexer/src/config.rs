@@ -0,0 +1,54 @@
+use std::path::PathBuf;
+
+use serde::{
+    Deserialize,
+    Serialize,
+};
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+/// The single config for creating an astria-indexer service.
+pub struct Config {
+    /// The chain ID of the sequencer network the indexer follows.
+    pub sequencer_chain_id: String,
+    /// The URL of the sequencer's cometbft RPC, used to poll the latest height and to read the
+    /// transactions of each block.
+    pub cometbft_endpoint: String,
+    /// The URL of the sequencer's gRPC service, used to fetch sequencer blocks.
+    pub sequencer_grpc_endpoint: String,
+    /// The duration in milliseconds between polls of the latest sequencer height.
+    pub block_time: u64,
+    /// The path of the `SQLite` database the indexer writes to. It is created if it does not
+    /// exist.
+    pub database_path: PathBuf,
+    /// The sequencer height at which indexing starts if the database is empty.
+    pub start_height: u64,
+    /// The bech32m prefix of sequencer addresses, used to render transaction signers.
+    pub address_prefix: String,
+    /// The socket address at which the indexer serves its HTTP/JSON API.
+    pub api_addr: String,
+    pub log: String,
+    /// Forces writing trace data to stdout no matter if connected to a tty or not.
+    pub force_stdout: bool,
+    /// Disables writing trace data to an opentelemetry endpoint.
+    pub no_otel: bool,
+    /// Set to true to disable the metrics server
+    pub no_metrics: bool,
+    /// The endpoint which will be listened on for serving prometheus metrics
+    pub metrics_http_listener_addr: String,
+}
+
+impl config::Config for Config {
+    const PREFIX: &'static str = "ASTRIA_INDEXER_";
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Config;
+
+    const EXAMPLE_ENV: &str = include_str!("../local.env.example");
+
+    #[test]
+    fn example_env_config_is_up_to_date() {
+        config::tests::example_env_config_is_up_to_date::<Config>(EXAMPLE_ENV);
+    }
+}
@@ -0,0 +1,369 @@
+use std::time::{
+    Duration,
+    Instant,
+};
+
+use astria_core::{
+    generated::astria::sequencerblock::v1::{
+        sequencer_service_client::SequencerServiceClient,
+        GetSequencerBlockRequest,
+    },
+    sequencerblock::v1::{
+        block::ExpandedBlockData,
+        SequencerBlock,
+    },
+};
+use astria_eyre::eyre::{
+    self,
+    ensure,
+    Report,
+    WrapErr as _,
+};
+use bytes::Bytes;
+use futures::{
+    future::{
+        BoxFuture,
+        Fuse,
+        FusedFuture as _,
+    },
+    FutureExt as _,
+};
+use sequencer_client::{
+    tendermint::block::Height,
+    tendermint_rpc,
+    HttpClient,
+};
+use tokio::select;
+use tokio_stream::StreamExt as _;
+use tokio_util::sync::CancellationToken;
+use tonic::transport::Channel;
+use tracing::{
+    debug,
+    info,
+    instrument,
+    trace,
+    warn,
+    Instrument as _,
+    Level,
+    Span,
+};
+
+use crate::{
+    metrics::Metrics,
+    model::IndexedBlock,
+    store::Store,
+};
+
+/// Follows the sequencer and writes every block it produces to the [`Store`].
+pub(crate) struct Follower {
+    pub(crate) shutdown_token: CancellationToken,
+    pub(crate) sequencer_chain_id: String,
+    pub(crate) cometbft_client: HttpClient,
+    pub(crate) sequencer_grpc_client: SequencerServiceClient<Channel>,
+    pub(crate) sequencer_poll_period: Duration,
+    pub(crate) start_height: u64,
+    pub(crate) address_prefix: String,
+    pub(crate) store: Store,
+    pub(crate) metrics: &'static Metrics,
+}
+
+impl Follower {
+    /// Runs the follower until it is shut down.
+    ///
+    /// Indexing resumes after the highest block in the store, or starts at the configured start
+    /// height if the store is empty.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the sequencer chain ID does not match the configured one, if fetching
+    /// a block failed after `u32::MAX` retries, or if a block could not be decoded or written.
+    pub(crate) async fn run(self) -> eyre::Result<()> {
+        select!(
+            () = self.shutdown_token.cancelled() => return Ok(()),
+            init_result = confirm_sequencer_chain_id(
+                self.sequencer_chain_id.clone(),
+                self.cometbft_client.clone(),
+            ) => init_result,
+        )?;
+
+        let mut next_height = self
+            .store
+            .latest_height()
+            .await
+            .wrap_err("failed to read the highest indexed height from the database")?
+            .map_or(self.start_height, |height| height.saturating_add(1))
+            .max(1);
+        info!(next_height, "starting to index sequencer blocks");
+
+        let mut latest_height_stream = {
+            use sequencer_client::StreamLatestHeight as _;
+            self.cometbft_client
+                .stream_latest_height(self.sequencer_poll_period)
+        };
+        let mut latest_sequencer_height = 0;
+        let mut indexing: Fuse<BoxFuture<'static, eyre::Result<()>>> = Fuse::terminated();
+
+        let reason = loop {
+            if indexing.is_terminated() && next_height <= latest_sequencer_height {
+                indexing = index_block(
+                    self.sequencer_grpc_client.clone(),
+                    self.cometbft_client.clone(),
+                    next_height,
+                    self.sequencer_poll_period,
+                    self.address_prefix.clone(),
+                    self.store.clone(),
+                    self.metrics,
+                )
+                .boxed()
+                .fuse();
+            }
+
+            select!(
+                biased;
+
+                () = self.shutdown_token.cancelled() => break Ok("shutdown signal received"),
+
+                res = &mut indexing, if !indexing.is_terminated() => {
+                    if let Err(error) = res.wrap_err_with(|| {
+                        format!("failed indexing sequencer block at height {next_height}")
+                    }) {
+                        break Err(error);
+                    }
+                    self.metrics.absolute_set_indexed_sequencer_height(next_height);
+                    next_height = next_height.saturating_add(1);
+                }
+
+                Some(res) = latest_height_stream.next() => {
+                    match res {
+                        Ok(height) => {
+                            debug!(%height, "received latest height from sequencer");
+                            latest_sequencer_height = height.value();
+                        }
+                        Err(error) => {
+                            self.metrics.increment_sequencer_height_fetch_failure_count();
+                            warn!(
+                                %error,
+                                "failed fetching latest height from sequencer; waiting until next \
+                                 tick",
+                            );
+                        }
+                    }
+                }
+            );
+        };
+
+        match &reason {
+            Ok(reason) => info!(reason, "shutting down"),
+            Err(reason) => warn!(%reason, "shutting down"),
+        }
+        reason.map(|_| ())
+    }
+}
+
+/// Fetches the sequencer block at `height` along with the transactions it contains, decodes them
+/// and writes them to `store`.
+#[instrument(skip_all, fields(%height), err)]
+async fn index_block(
+    grpc_client: SequencerServiceClient<Channel>,
+    cometbft_client: HttpClient,
+    height: u64,
+    block_time: Duration,
+    address_prefix: String,
+    store: Store,
+    metrics: &'static Metrics,
+) -> eyre::Result<()> {
+    let start = Instant::now();
+    let height = Height::try_from(height).wrap_err("height is not a valid cometbft height")?;
+
+    let sequencer_block = fetch_sequencer_block(grpc_client, height, block_time, metrics).await?;
+    let data = fetch_block_data(cometbft_client, height, block_time, metrics).await?;
+
+    let expanded_block_data = ExpandedBlockData::new_from_typed_data(
+        &data,
+        sequencer_block.extended_commit_info().is_some(),
+    )
+    .or_else(|_| ExpandedBlockData::new_from_untyped_data(&data))
+    .wrap_err("failed to parse the transactions of the cometbft block")?;
+    ensure!(
+        &expanded_block_data.data_root_hash == sequencer_block.header().data_hash(),
+        "the transactions of the cometbft block do not match the data hash of the sequencer block"
+    );
+
+    let indexed = IndexedBlock::new(
+        &sequencer_block,
+        &expanded_block_data.user_submitted_transactions,
+        &address_prefix,
+    )
+    .wrap_err("failed to decode sequencer block")?;
+    let transaction_count = indexed.transactions.len();
+    store
+        .insert_block(indexed)
+        .await
+        .wrap_err("failed to write sequencer block to the database")?;
+
+    metrics.increment_indexed_transaction_count(transaction_count);
+    metrics.record_block_indexing_latency(start.elapsed());
+    debug!(transaction_count, "indexed sequencer block");
+    Ok(())
+}
+
+/// Fetches the sequencer block at `height`.
+///
+/// If fetching the block fails, then a new fetch is scheduled with exponential backoff,
+/// up to a maximum of `block_time` duration between subsequent requests.
+async fn fetch_sequencer_block(
+    client: SequencerServiceClient<Channel>,
+    height: Height,
+    block_time: Duration,
+    metrics: &'static Metrics,
+) -> eyre::Result<SequencerBlock> {
+    // Moving the span into `on_retry`, because tryhard spawns these in a tokio
+    // task, losing the span.
+    let span = Span::current();
+    let retry_config = tryhard::RetryFutureConfig::new(u32::MAX)
+        .exponential_backoff(Duration::from_millis(100))
+        .max_delay(block_time)
+        .on_retry(
+            |attempt: u32, next_delay: Option<Duration>, error: &eyre::Report| {
+                metrics.increment_sequencer_block_fetch_failure_count();
+                let wait_duration = next_delay
+                    .map(telemetry::display::format_duration)
+                    .map(tracing::field::display);
+                warn!(
+                    parent: &span,
+                    attempt,
+                    wait_duration,
+                    %error,
+                    "failed fetching block from sequencer; retrying after backoff",
+                );
+                futures::future::ready(())
+            },
+        );
+
+    let block = tryhard::retry_fn(move || {
+        let mut client = client.clone();
+        let request = GetSequencerBlockRequest {
+            height: height.value(),
+        };
+        async move {
+            client
+                .get_sequencer_block(request)
+                .await
+                .map_err(Report::new)
+        }
+    })
+    .with_config(retry_config)
+    .in_current_span()
+    .await
+    .wrap_err("retry attempts exhausted; bailing")?;
+
+    let block = SequencerBlock::try_from_raw(block.into_inner())
+        .wrap_err("failed to parse raw proto block from grpc response")?;
+    ensure!(
+        height == block.height(),
+        "requested block at height `{height}` but received a block at height `{}`",
+        block.height()
+    );
+    Ok(block)
+}
+
+/// Fetches the raw transactions of the cometbft block at `height`.
+///
+/// Retries the same way as [`fetch_sequencer_block`].
+async fn fetch_block_data(
+    client: HttpClient,
+    height: Height,
+    block_time: Duration,
+    metrics: &'static Metrics,
+) -> eyre::Result<Vec<Bytes>> {
+    use sequencer_client::Client as _;
+
+    let span = Span::current();
+    let retry_config = tryhard::RetryFutureConfig::new(u32::MAX)
+        .exponential_backoff(Duration::from_millis(100))
+        .max_delay(block_time)
+        .on_retry(
+            |attempt: u32, next_delay: Option<Duration>, error: &tendermint_rpc::Error| {
+                metrics.increment_sequencer_block_fetch_failure_count();
+                let wait_duration = next_delay
+                    .map(telemetry::display::format_duration)
+                    .map(tracing::field::display);
+                warn!(
+                    parent: &span,
+                    attempt,
+                    wait_duration,
+                    error = %eyre::Report::new(error.clone()),
+                    "failed fetching block from cometbft; retrying after backoff",
+                );
+                futures::future::ready(())
+            },
+        );
+
+    let response = tryhard::retry_fn(move || {
+        let client = client.clone();
+        async move { client.block(height).await }
+    })
+    .with_config(retry_config)
+    .in_current_span()
+    .await
+    .wrap_err("retry attempts exhausted; bailing")?;
+
+    Ok(response.block.data.into_iter().map(Bytes::from).collect())
+}
+
+#[instrument(skip_all, err)]
+async fn confirm_sequencer_chain_id(
+    configured_sequencer_chain_id: String,
+    cometbft_client: HttpClient,
+) -> eyre::Result<()> {
+    let span = Span::current();
+
+    let retry_config = tryhard::RetryFutureConfig::new(u32::MAX)
+        .max_delay(Duration::from_secs(30))
+        .exponential_backoff(Duration::from_secs(1))
+        .on_retry(
+            |attempt: u32, next_delay: Option<Duration>, error: &tendermint_rpc::Error| {
+                let wait_duration = next_delay
+                    .map(telemetry::display::format_duration)
+                    .map(tracing::field::display);
+                warn!(
+                    parent: &span,
+                    attempt,
+                    wait_duration,
+                    error = %eyre::Report::new(error.clone()),
+                    "failed to fetch sequencer chain id; retrying after backoff",
+                );
+                futures::future::ready(())
+            },
+        );
+
+    let received_sequencer_chain_id =
+        tryhard::retry_fn(move || fetch_sequencer_chain_id(cometbft_client.clone()))
+            .with_config(retry_config)
+            .in_current_span()
+            .await
+            .wrap_err("retry attempts exhausted; bailing")?;
+
+    ensure!(
+        received_sequencer_chain_id == configured_sequencer_chain_id,
+        "configured sequencer chain ID does not match received; configured: \
+         `{configured_sequencer_chain_id}`, received: `{received_sequencer_chain_id}`"
+    );
+    info!(sequencer_chain_id = %configured_sequencer_chain_id, "confirmed sequencer chain id");
+    Ok(())
+}
+
+#[instrument(skip_all, err(level = Level::WARN))]
+async fn fetch_sequencer_chain_id(
+    cometbft_client: HttpClient,
+) -> Result<String, tendermint_rpc::Error> {
+    use sequencer_client::Client as _;
+
+    let response = cometbft_client.status().await;
+    // trace-level logging, so using Debug format is ok.
+    #[cfg_attr(dylint_lib = "tracing_debug_field", allow(tracing_debug_field))]
+    {
+        trace!(?response);
+    }
+    response.map(|status_response| status_response.node_info.network.to_string())
+}
@@ -0,0 +1,272 @@
+use std::{
+    net::SocketAddr,
+    time::Duration,
+};
+
+use astria_core::generated::astria::sequencerblock::v1::sequencer_service_client::SequencerServiceClient;
+use astria_eyre::eyre::{
+    self,
+    WrapErr as _,
+};
+use sequencer_client::HttpClient;
+use tokio::{
+    select,
+    task::{
+        JoinError,
+        JoinHandle,
+    },
+    time::timeout,
+};
+use tokio_util::sync::{
+    CancellationToken,
+    WaitForCancellationFuture,
+};
+use tonic::transport::{
+    Endpoint,
+    Uri,
+};
+use tracing::{
+    error,
+    info,
+    instrument,
+};
+
+use crate::{
+    api,
+    config::Config,
+    follower::Follower,
+    metrics::Metrics,
+    store::Store,
+};
+
+pub struct Indexer {
+    api: api::Serve,
+    api_shutdown_token: CancellationToken,
+    follower: Follower,
+    shutdown_handle: ShutdownHandle,
+}
+
+impl Indexer {
+    /// Instantiates a new `Indexer`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database could not be opened, if the sequencer endpoints are
+    /// malformed, or if the API server could not be started.
+    pub async fn new(
+        cfg: Config,
+        metrics: &'static Metrics,
+    ) -> eyre::Result<(Self, ShutdownHandle)> {
+        let shutdown_handle = ShutdownHandle::new();
+        let Config {
+            sequencer_chain_id,
+            cometbft_endpoint,
+            sequencer_grpc_endpoint,
+            block_time,
+            database_path,
+            start_height,
+            address_prefix,
+            api_addr,
+            ..
+        } = cfg;
+
+        let store = Store::open(&database_path)?;
+
+        let cometbft_client = HttpClient::new(&*cometbft_endpoint)
+            .wrap_err("failed constructing cometbft http client")?;
+        let sequencer_grpc_client = {
+            let uri: Uri = sequencer_grpc_endpoint
+                .parse()
+                .wrap_err("failed parsing provided sequencer grpc endpoint as Uri")?;
+            let endpoint = Endpoint::from(uri);
+            SequencerServiceClient::new(endpoint.connect_lazy())
+        };
+
+        let follower = Follower {
+            shutdown_token: shutdown_handle.token.child_token(),
+            sequencer_chain_id,
+            cometbft_client,
+            sequencer_grpc_client,
+            sequencer_poll_period: Duration::from_millis(block_time),
+            start_height,
+            address_prefix,
+            store: store.clone(),
+            metrics,
+        };
+
+        let api_shutdown_token = CancellationToken::new();
+        let api = api::serve(&api_addr, store, api_shutdown_token.child_token())
+            .await
+            .wrap_err("failed to start API server")?;
+        let indexer = Self {
+            api,
+            api_shutdown_token,
+            follower,
+            shutdown_handle: shutdown_handle.clone(),
+        };
+        Ok((indexer, shutdown_handle))
+    }
+
+    pub fn local_addr(&self) -> SocketAddr {
+        self.api.local_addr()
+    }
+
+    /// Runs the indexer.
+    pub async fn run(self) {
+        let Self {
+            api,
+            api_shutdown_token,
+            follower,
+            shutdown_handle,
+        } = self;
+        let (mut api_task, mut follower_task) = spawn_tasks(api, follower);
+
+        let shutdown = select!(
+            o = &mut api_task => {
+                report_exit("api server", o);
+                Shutdown { api_task: None, follower_task: Some(follower_task), api_shutdown_token, handle: shutdown_handle }
+            }
+            o = &mut follower_task => {
+                report_exit("follower", o);
+                Shutdown { api_task: Some(api_task), follower_task: None, api_shutdown_token, handle: shutdown_handle }
+            }
+
+        );
+        shutdown.run().await;
+    }
+}
+
+#[instrument(skip_all)]
+fn spawn_tasks(
+    api: api::Serve,
+    follower: Follower,
+) -> (JoinHandle<eyre::Result<()>>, JoinHandle<eyre::Result<()>>) {
+    let api_task = tokio::spawn(async move { api.await.wrap_err("API server exited with error") });
+    let follower_task = tokio::spawn(follower.run());
+    info!("spawned follower task");
+
+    (api_task, follower_task)
+}
+
+/// A handle for instructing the [`Indexer`] to shut down.
+///
+/// It is returned along with its related `Indexer` from [`Indexer::new`].  The
+/// `Indexer` will begin to shut down as soon as [`ShutdownHandle::shutdown`] is called or
+/// when the `ShutdownHandle` is dropped.
+#[derive(Clone)]
+pub struct ShutdownHandle {
+    token: CancellationToken,
+}
+
+impl ShutdownHandle {
+    #[must_use]
+    fn new() -> Self {
+        Self {
+            token: CancellationToken::new(),
+        }
+    }
+
+    /// Returns a `Future` that gets fulfilled when cancellation is requested.
+    ///
+    /// See [`CancellationToken::cancelled`] for further details.
+    pub fn cancelled(&self) -> WaitForCancellationFuture<'_> {
+        self.token.cancelled()
+    }
+
+    /// Consumes `self` and cancels the wrapped cancellation token.
+    ///
+    /// See [`CancellationToken::cancel`] for further details.
+    pub fn shutdown(self) {
+        self.token.cancel();
+    }
+}
+
+impl Drop for ShutdownHandle {
+    fn drop(&mut self) {
+        if !self.token.is_cancelled() {
+            info!("shutdown handle dropped, issuing shutdown to all services");
+        }
+        self.token.cancel();
+    }
+}
+
+fn report_exit(task_name: &str, outcome: Result<eyre::Result<()>, JoinError>) {
+    match outcome {
+        Ok(Ok(())) => info!(task = task_name, "task has exited"),
+        Ok(Err(error)) => {
+            error!(task = task_name, %error, "task returned with error");
+        }
+        Err(e) => {
+            error!(
+                task = task_name,
+                error = &e as &dyn std::error::Error,
+                "task failed to complete"
+            );
+        }
+    }
+}
+
+struct Shutdown {
+    api_task: Option<JoinHandle<eyre::Result<()>>>,
+    api_shutdown_token: CancellationToken,
+    follower_task: Option<JoinHandle<eyre::Result<()>>>,
+    handle: ShutdownHandle,
+}
+
+impl Shutdown {
+    #[instrument(skip_all)]
+    async fn run(self) {
+        let Self {
+            api_task,
+            api_shutdown_token,
+            follower_task,
+            handle,
+        } = self;
+        handle.shutdown();
+        // Giving the follower 25 seconds to shutdown because Kubernetes issues a SIGKILL after 30.
+        if let Some(mut follower_task) = follower_task {
+            info!("waiting for follower task to shut down");
+            let limit = Duration::from_secs(25);
+            match timeout(limit, &mut follower_task)
+                .await
+                .map(crate::utils::flatten)
+            {
+                Ok(Ok(())) => info!("follower exited gracefully"),
+                Ok(Err(error)) => error!(%error, "follower exited with an error"),
+                Err(_) => {
+                    error!(
+                        timeout_secs = limit.as_secs(),
+                        "follower did not shut down within timeout; killing it"
+                    );
+                    follower_task.abort();
+                }
+            }
+        } else {
+            info!("follower task was already dead");
+        }
+
+        // Giving the API task another 4 seconds. 25 for the follower + 4s = 29s (out of 30s for
+        // k8s).
+        if let Some(mut api_task) = api_task {
+            info!("sending shutdown signal to API server");
+            api_shutdown_token.cancel();
+            let limit = Duration::from_secs(4);
+            match timeout(limit, &mut api_task)
+                .await
+                .map(crate::utils::flatten)
+            {
+                Ok(Ok(())) => info!("API server exited gracefully"),
+                Ok(Err(error)) => error!(%error, "API server exited with an error"),
+                Err(_) => {
+                    error!(
+                        timeout_secs = limit.as_secs(),
+                        "API server did not shut down within timeout; killing it"
+                    );
+                    api_task.abort();
+                }
+            }
+        } else {
+            info!("API server was already dead");
+        }
+    }
+}
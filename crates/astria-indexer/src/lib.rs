@@ -0,0 +1,19 @@
+pub(crate) mod api;
+mod build_info;
+pub mod config;
+pub(crate) mod follower;
+pub mod indexer;
+pub(crate) mod metrics;
+pub(crate) mod model;
+pub(crate) mod store;
+#[cfg(test)]
+pub(crate) mod test_utils;
+pub(crate) mod utils;
+
+pub use build_info::BUILD_INFO;
+pub use config::Config;
+pub use indexer::{
+    Indexer,
+    ShutdownHandle,
+};
+pub use metrics::Metrics;
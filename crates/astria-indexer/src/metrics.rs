@@ -0,0 +1,130 @@
+use std::time::Duration;
+
+use telemetry::{
+    metric_names,
+    metrics::{
+        Counter,
+        Histogram,
+        RegisteringBuilder,
+    },
+};
+
+pub struct Metrics {
+    indexed_sequencer_height: Counter,
+    indexed_transaction_count: Counter,
+    block_indexing_latency: Histogram,
+    sequencer_block_fetch_failure_count: Counter,
+    sequencer_height_fetch_failure_count: Counter,
+}
+
+impl Metrics {
+    pub(crate) fn absolute_set_indexed_sequencer_height(&self, height: u64) {
+        self.indexed_sequencer_height.absolute(height);
+    }
+
+    pub(crate) fn increment_indexed_transaction_count(&self, count: usize) {
+        self.indexed_transaction_count
+            .increment(u64::try_from(count).unwrap_or(u64::MAX));
+    }
+
+    pub(crate) fn record_block_indexing_latency(&self, latency: Duration) {
+        self.block_indexing_latency.record(latency);
+    }
+
+    pub(crate) fn increment_sequencer_block_fetch_failure_count(&self) {
+        self.sequencer_block_fetch_failure_count.increment(1);
+    }
+
+    pub(crate) fn increment_sequencer_height_fetch_failure_count(&self) {
+        self.sequencer_height_fetch_failure_count.increment(1);
+    }
+}
+
+impl telemetry::Metrics for Metrics {
+    type Config = ();
+
+    fn register(
+        builder: &mut RegisteringBuilder,
+        _config: &Self::Config,
+    ) -> Result<Self, telemetry::metrics::Error> {
+        let indexed_sequencer_height = builder
+            .new_counter_factory(
+                INDEXED_SEQUENCER_HEIGHT,
+                "The height of the last sequencer block written to the database",
+            )?
+            .register()?;
+
+        let indexed_transaction_count = builder
+            .new_counter_factory(
+                INDEXED_TRANSACTION_COUNT,
+                "The number of sequencer transactions written to the database",
+            )?
+            .register()?;
+
+        let block_indexing_latency = builder
+            .new_histogram_factory(
+                BLOCK_INDEXING_LATENCY,
+                "The latency of fetching, decoding and writing a single sequencer block",
+            )?
+            .register()?;
+
+        let sequencer_block_fetch_failure_count = builder
+            .new_counter_factory(
+                SEQUENCER_BLOCK_FETCH_FAILURE_COUNT,
+                "The number of calls made to fetch a block from sequencer which have failed",
+            )?
+            .register()?;
+
+        let sequencer_height_fetch_failure_count = builder
+            .new_counter_factory(
+                SEQUENCER_HEIGHT_FETCH_FAILURE_COUNT,
+                "The number of calls made to fetch the current height from sequencer which have \
+                 failed",
+            )?
+            .register()?;
+
+        Ok(Self {
+            indexed_sequencer_height,
+            indexed_transaction_count,
+            block_indexing_latency,
+            sequencer_block_fetch_failure_count,
+            sequencer_height_fetch_failure_count,
+        })
+    }
+}
+
+metric_names!(const METRICS_NAMES:
+    INDEXED_SEQUENCER_HEIGHT,
+    INDEXED_TRANSACTION_COUNT,
+    BLOCK_INDEXING_LATENCY,
+    SEQUENCER_BLOCK_FETCH_FAILURE_COUNT,
+    SEQUENCER_HEIGHT_FETCH_FAILURE_COUNT
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[track_caller]
+    fn assert_const(actual: &'static str, suffix: &str) {
+        // XXX: hard-code this so the crate name isn't accidentally changed.
+        const CRATE_NAME: &str = "astria_indexer";
+        let expected = format!("{CRATE_NAME}_{suffix}");
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn metrics_are_as_expected() {
+        assert_const(INDEXED_SEQUENCER_HEIGHT, "indexed_sequencer_height");
+        assert_const(INDEXED_TRANSACTION_COUNT, "indexed_transaction_count");
+        assert_const(BLOCK_INDEXING_LATENCY, "block_indexing_latency");
+        assert_const(
+            SEQUENCER_BLOCK_FETCH_FAILURE_COUNT,
+            "sequencer_block_fetch_failure_count",
+        );
+        assert_const(
+            SEQUENCER_HEIGHT_FETCH_FAILURE_COUNT,
+            "sequencer_height_fetch_failure_count",
+        );
+    }
+}
@@ -0,0 +1,297 @@
+//! The records written to and served from the indexer's database.
+
+use std::{
+    collections::BTreeSet,
+    sync::Arc,
+};
+
+use astria_core::{
+    primitive::v1::{
+        Address,
+        ADDRESS_LEN,
+    },
+    protocol::transaction::v1::{
+        Action,
+        Transaction as SignedTransaction,
+    },
+    sequencerblock::v1::{
+        block::RollupData,
+        SequencerBlock,
+    },
+    Protobuf as _,
+};
+use astria_eyre::eyre::{
+    self,
+    WrapErr as _,
+};
+use base64::{
+    display::Base64Display,
+    engine::general_purpose::STANDARD,
+};
+use prost::Message as _;
+use serde::Serialize;
+
+/// A sequencer block as returned by the API.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub(crate) struct Block {
+    pub(crate) height: u64,
+    pub(crate) hash: String,
+    pub(crate) chain_id: String,
+    pub(crate) time: String,
+    pub(crate) proposer_address: String,
+    pub(crate) transaction_count: u64,
+    pub(crate) rollup_ids: Vec<String>,
+}
+
+/// A user submitted sequencer transaction as returned by the API.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub(crate) struct Transaction {
+    pub(crate) hash: String,
+    pub(crate) height: u64,
+    pub(crate) index: u64,
+    pub(crate) signer: String,
+    pub(crate) nonce: u32,
+    pub(crate) chain_id: String,
+    /// The transaction's actions in their protobuf JSON representation.
+    pub(crate) actions: serde_json::Value,
+}
+
+/// A bridge deposit to a rollup as returned by the API.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub(crate) struct Deposit {
+    pub(crate) height: u64,
+    pub(crate) rollup_id: String,
+    pub(crate) bridge_address: String,
+    /// The amount as a decimal string, as it does not fit into a JSON number.
+    pub(crate) amount: String,
+    pub(crate) asset: String,
+    pub(crate) destination_chain_address: String,
+    pub(crate) source_transaction_id: String,
+    pub(crate) source_action_index: u64,
+}
+
+/// Data sequenced for a rollup as returned by the API.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub(crate) struct SequencedData {
+    pub(crate) height: u64,
+    pub(crate) rollup_id: String,
+    /// The position of the data among all items sent to the rollup in this block.
+    pub(crate) index: u64,
+    /// The base64 encoded data.
+    pub(crate) data: String,
+}
+
+/// Everything indexed from a single sequencer block, written to the database in one go.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct IndexedBlock {
+    pub(crate) block: Block,
+    pub(crate) transactions: Vec<Transaction>,
+    /// The addresses involved in each transaction, as pairs of the address and the transaction
+    /// hash.
+    pub(crate) account_transactions: Vec<([u8; ADDRESS_LEN], String)>,
+    /// The bridge addresses of the deposits, in the same order as `deposits`.
+    pub(crate) deposit_accounts: Vec<[u8; ADDRESS_LEN]>,
+    pub(crate) deposits: Vec<Deposit>,
+    pub(crate) sequenced_data: Vec<SequencedData>,
+}
+
+impl IndexedBlock {
+    /// Decodes a sequencer block and the user submitted transactions contained in it.
+    ///
+    /// Transaction signers are rendered as bech32m addresses with `address_prefix`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a signer address cannot be constructed with `address_prefix`, or if
+    /// an item sent to a rollup cannot be decoded.
+    pub(crate) fn new(
+        sequencer_block: &SequencerBlock,
+        user_submitted_transactions: &[Arc<SignedTransaction>],
+        address_prefix: &str,
+    ) -> eyre::Result<Self> {
+        let height = sequencer_block.height().value();
+        let header = sequencer_block.header();
+
+        let mut transactions = Vec::with_capacity(user_submitted_transactions.len());
+        let mut account_transactions = Vec::new();
+        for (index, tx) in (0u64..).zip(user_submitted_transactions) {
+            let hash = tx.id().to_string();
+            let signer: Address = Address::builder()
+                .array(*tx.address_bytes())
+                .prefix(address_prefix)
+                .try_build()
+                .wrap_err_with(|| {
+                    format!(
+                        "failed constructing the signer address of transaction `{hash}` with \
+                         prefix `{address_prefix}`"
+                    )
+                })?;
+            let actions = tx
+                .actions()
+                .iter()
+                .map(|action| serde_json::to_value(action.to_raw()))
+                .collect::<Result<_, _>>()
+                .wrap_err_with(|| {
+                    format!("failed encoding the actions of transaction `{hash}` as JSON")
+                })?;
+            account_transactions.extend(
+                involved_accounts(*tx.address_bytes(), tx.actions())
+                    .into_iter()
+                    .map(|address| (address, hash.clone())),
+            );
+            transactions.push(Transaction {
+                hash,
+                height,
+                index,
+                signer: signer.to_string(),
+                nonce: tx.nonce(),
+                chain_id: tx.chain_id().to_string(),
+                actions: serde_json::Value::Array(actions),
+            });
+        }
+
+        let mut deposits = Vec::new();
+        let mut deposit_accounts = Vec::new();
+        let mut sequenced_data = Vec::new();
+        for (rollup_id, rollup_transactions) in sequencer_block.rollup_transactions() {
+            for (index, item) in (0u64..).zip(rollup_transactions.transactions()) {
+                let raw = astria_core::generated::astria::sequencerblock::v1::RollupData::decode(
+                    item.as_ref(),
+                )
+                .wrap_err_with(|| {
+                    format!("failed decoding item `{index}` sent to rollup `{rollup_id}`")
+                })?;
+                match RollupData::try_from_raw(raw).wrap_err_with(|| {
+                    format!("failed validating item `{index}` sent to rollup `{rollup_id}`")
+                })? {
+                    RollupData::SequencedData(data) => sequenced_data.push(SequencedData {
+                        height,
+                        rollup_id: rollup_id.to_string(),
+                        index,
+                        data: Base64Display::new(&data, &STANDARD).to_string(),
+                    }),
+                    RollupData::Deposit(deposit) => {
+                        deposit_accounts.push(deposit.bridge_address.bytes());
+                        deposits.push(Deposit {
+                            height,
+                            rollup_id: deposit.rollup_id.to_string(),
+                            bridge_address: deposit.bridge_address.to_string(),
+                            amount: deposit.amount.to_string(),
+                            asset: deposit.asset.to_string(),
+                            destination_chain_address: deposit.destination_chain_address,
+                            source_transaction_id: deposit.source_transaction_id.to_string(),
+                            source_action_index: deposit.source_action_index,
+                        });
+                    }
+                    RollupData::PriceFeedData(_) => {}
+                }
+            }
+        }
+
+        Ok(Self {
+            block: Block {
+                height,
+                hash: sequencer_block.block_hash().to_string(),
+                chain_id: header.chain_id().to_string(),
+                time: header.time().to_rfc3339(),
+                proposer_address: header.proposer_address().to_string(),
+                transaction_count: transactions.len() as u64,
+                rollup_ids: sequencer_block
+                    .rollup_transactions()
+                    .keys()
+                    .map(ToString::to_string)
+                    .collect(),
+            },
+            transactions,
+            account_transactions,
+            deposit_accounts,
+            deposits,
+            sequenced_data,
+        })
+    }
+}
+
+/// Returns the signer and all accounts receiving funds through the transaction's actions.
+fn involved_accounts(signer: [u8; ADDRESS_LEN], actions: &[Action]) -> BTreeSet<[u8; ADDRESS_LEN]> {
+    let mut accounts = BTreeSet::from([signer]);
+    for action in actions {
+        match action {
+            Action::Transfer(transfer) => {
+                accounts.insert(transfer.to.bytes());
+            }
+            Action::BridgeLock(lock) => {
+                accounts.insert(lock.to.bytes());
+            }
+            Action::BridgeUnlock(unlock) => {
+                accounts.extend([unlock.to.bytes(), unlock.bridge_address.bytes()]);
+            }
+            Action::BridgeTransfer(transfer) => {
+                accounts.extend([transfer.to.bytes(), transfer.bridge_address.bytes()]);
+            }
+            _ => {}
+        }
+    }
+    accounts
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_utils::{
+        bridge_address,
+        make_indexed_block,
+        recipient,
+        signing_key,
+        ADDRESS_PREFIX,
+        DEPOSIT_ROLLUP,
+        SEQUENCED_ROLLUP,
+    };
+
+    #[test]
+    fn sequencer_block_is_decoded() {
+        let indexed = make_indexed_block(7);
+
+        assert_eq!(7, indexed.block.height);
+        assert_eq!(1, indexed.block.transaction_count);
+        assert_eq!(
+            vec![SEQUENCED_ROLLUP.to_string(), DEPOSIT_ROLLUP.to_string()],
+            indexed.block.rollup_ids,
+        );
+
+        let [tx] = indexed.transactions.as_slice() else {
+            panic!("expected exactly one transaction");
+        };
+        assert_eq!(7, tx.height);
+        assert_eq!(7, tx.nonce);
+        assert!(tx.signer.starts_with(ADDRESS_PREFIX));
+        assert_eq!(
+            recipient().to_string(),
+            tx.actions[0]["transfer"]["to"]["bech32m"],
+        );
+
+        let mut accounts: Vec<_> = indexed
+            .account_transactions
+            .iter()
+            .map(|(address, hash)| {
+                assert_eq!(&tx.hash, hash);
+                *address
+            })
+            .collect();
+        accounts.sort_unstable();
+        let mut expected = vec![signing_key().address_bytes(), recipient().bytes()];
+        expected.sort_unstable();
+        assert_eq!(expected, accounts);
+
+        let [deposit] = indexed.deposits.as_slice() else {
+            panic!("expected exactly one deposit");
+        };
+        assert_eq!(DEPOSIT_ROLLUP.to_string(), deposit.rollup_id);
+        assert_eq!("7", deposit.amount);
+        assert_eq!(vec![bridge_address().bytes()], indexed.deposit_accounts);
+
+        let [data] = indexed.sequenced_data.as_slice() else {
+            panic!("expected exactly one item of sequenced data");
+        };
+        assert_eq!(SEQUENCED_ROLLUP.to_string(), data.rollup_id);
+        assert_eq!("aGVsbG8=", data.data);
+    }
+}
@@ -0,0 +1,436 @@
+//! The `SQLite` database into which sequencer blocks are indexed.
+
+use std::{
+    path::Path,
+    sync::{
+        Arc,
+        Mutex,
+    },
+};
+
+use astria_core::primitive::v1::ADDRESS_LEN;
+use astria_eyre::eyre::{
+    self,
+    eyre,
+    WrapErr as _,
+};
+use rusqlite::{
+    params,
+    types::Type,
+    Connection,
+    OptionalExtension as _,
+    Row,
+};
+use serde::Deserialize;
+
+use crate::model::{
+    Block,
+    Deposit,
+    IndexedBlock,
+    SequencedData,
+    Transaction,
+};
+
+const SCHEMA: &str = "
+CREATE TABLE IF NOT EXISTS blocks (
+    height INTEGER PRIMARY KEY,
+    hash TEXT NOT NULL,
+    chain_id TEXT NOT NULL,
+    time TEXT NOT NULL,
+    proposer_address TEXT NOT NULL,
+    transaction_count INTEGER NOT NULL,
+    rollup_ids TEXT NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS transactions (
+    hash TEXT PRIMARY KEY,
+    height INTEGER NOT NULL REFERENCES blocks (height),
+    position INTEGER NOT NULL,
+    signer TEXT NOT NULL,
+    nonce INTEGER NOT NULL,
+    chain_id TEXT NOT NULL,
+    actions TEXT NOT NULL
+);
+CREATE INDEX IF NOT EXISTS transactions_by_height ON transactions (height, position);
+
+CREATE TABLE IF NOT EXISTS account_transactions (
+    address BLOB NOT NULL,
+    hash TEXT NOT NULL REFERENCES transactions (hash),
+    PRIMARY KEY (address, hash)
+);
+
+CREATE TABLE IF NOT EXISTS deposits (
+    height INTEGER NOT NULL REFERENCES blocks (height),
+    position INTEGER NOT NULL,
+    rollup_id TEXT NOT NULL,
+    bridge_account BLOB NOT NULL,
+    bridge_address TEXT NOT NULL,
+    amount TEXT NOT NULL,
+    asset TEXT NOT NULL,
+    destination_chain_address TEXT NOT NULL,
+    source_transaction_id TEXT NOT NULL,
+    source_action_index INTEGER NOT NULL,
+    PRIMARY KEY (height, position)
+);
+CREATE INDEX IF NOT EXISTS deposits_by_rollup ON deposits (rollup_id, height);
+CREATE INDEX IF NOT EXISTS deposits_by_bridge_account ON deposits (bridge_account, height);
+
+CREATE TABLE IF NOT EXISTS sequenced_data (
+    rollup_id TEXT NOT NULL,
+    height INTEGER NOT NULL REFERENCES blocks (height),
+    position INTEGER NOT NULL,
+    data TEXT NOT NULL,
+    PRIMARY KEY (rollup_id, height, position)
+);
+";
+
+const DEPOSIT_COLUMNS: &str = "height, rollup_id, bridge_address, amount, asset, \
+                               destination_chain_address, source_transaction_id, \
+                               source_action_index";
+
+const TRANSACTION_COLUMNS: &str = "hash, height, position, signer, nonce, chain_id, actions";
+
+/// The default number of records returned by queries over lists.
+const DEFAULT_PAGE_LIMIT: u32 = 100;
+
+/// The maximum number of records returned by queries over lists.
+const MAX_PAGE_LIMIT: u32 = 1000;
+
+/// Selects a window of records in queries over lists, which are ordered newest first.
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Eq)]
+pub(crate) struct Page {
+    limit: Option<u32>,
+    offset: Option<u64>,
+}
+
+impl Page {
+    fn limit(self) -> u32 {
+        self.limit.unwrap_or(DEFAULT_PAGE_LIMIT).min(MAX_PAGE_LIMIT)
+    }
+
+    fn offset(self) -> u64 {
+        self.offset.unwrap_or(0)
+    }
+}
+
+/// A handle to the indexer's database, shared between the follower writing to it and the API
+/// reading from it.
+#[derive(Clone)]
+pub(crate) struct Store {
+    connection: Arc<Mutex<Connection>>,
+}
+
+impl Store {
+    /// Opens the database at `path`, creating it and its tables if they do not exist.
+    pub(crate) fn open(path: &Path) -> eyre::Result<Self> {
+        let connection = Connection::open(path)
+            .wrap_err_with(|| format!("failed to open database at `{}`", path.display()))?;
+        connection
+            .pragma_update(None, "journal_mode", "WAL")
+            .wrap_err("failed to enable write-ahead logging")?;
+        Self::with_schema(connection)
+    }
+
+    #[cfg(test)]
+    pub(crate) fn open_in_memory() -> eyre::Result<Self> {
+        let connection =
+            Connection::open_in_memory().wrap_err("failed to open in-memory database")?;
+        Self::with_schema(connection)
+    }
+
+    fn with_schema(connection: Connection) -> eyre::Result<Self> {
+        connection
+            .execute_batch(SCHEMA)
+            .wrap_err("failed to create database schema")?;
+        Ok(Self {
+            connection: Arc::new(Mutex::new(connection)),
+        })
+    }
+
+    /// Runs `f` with exclusive access to the database on tokio's blocking thread pool.
+    async fn with_connection<T, F>(&self, f: F) -> eyre::Result<T>
+    where
+        T: Send + 'static,
+        F: FnOnce(&mut Connection) -> rusqlite::Result<T> + Send + 'static,
+    {
+        let connection = self.connection.clone();
+        tokio::task::spawn_blocking(move || {
+            let mut connection = connection
+                .lock()
+                .map_err(|_| eyre!("database connection mutex is poisoned"))?;
+            f(&mut connection).wrap_err("database query failed")
+        })
+        .await
+        .wrap_err("database task panicked")?
+    }
+
+    /// Returns the height of the highest indexed block, if any.
+    pub(crate) async fn latest_height(&self) -> eyre::Result<Option<u64>> {
+        self.with_connection(|connection| {
+            connection.query_row("SELECT MAX(height) FROM blocks", [], |row| row.get(0))
+        })
+        .await
+    }
+
+    /// Writes `indexed` to the database in a single database transaction.
+    pub(crate) async fn insert_block(&self, indexed: IndexedBlock) -> eyre::Result<()> {
+        self.with_connection(move |connection| {
+            let db_tx = connection.transaction()?;
+            let IndexedBlock {
+                block,
+                transactions,
+                account_transactions,
+                deposit_accounts,
+                deposits,
+                sequenced_data,
+            } = indexed;
+            db_tx.execute(
+                "INSERT INTO blocks (height, hash, chain_id, time, proposer_address, \
+                 transaction_count, rollup_ids) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+                params![
+                    block.height,
+                    block.hash,
+                    block.chain_id,
+                    block.time,
+                    block.proposer_address,
+                    block.transaction_count,
+                    serde_json::Value::from(block.rollup_ids).to_string(),
+                ],
+            )?;
+            for tx in transactions {
+                db_tx.execute(
+                    &format!(
+                        "INSERT INTO transactions ({TRANSACTION_COLUMNS}) VALUES (?1, ?2, ?3, ?4, \
+                         ?5, ?6, ?7)"
+                    ),
+                    params![
+                        tx.hash,
+                        tx.height,
+                        tx.index,
+                        tx.signer,
+                        tx.nonce,
+                        tx.chain_id,
+                        tx.actions.to_string(),
+                    ],
+                )?;
+            }
+            for (address, hash) in account_transactions {
+                db_tx.execute(
+                    "INSERT OR IGNORE INTO account_transactions (address, hash) VALUES (?1, ?2)",
+                    params![address.as_slice(), hash],
+                )?;
+            }
+            for ((position, deposit), bridge_account) in
+                (0u64..).zip(deposits).zip(deposit_accounts)
+            {
+                db_tx.execute(
+                    &format!(
+                        "INSERT INTO deposits (position, bridge_account, {DEPOSIT_COLUMNS}) \
+                         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)"
+                    ),
+                    params![
+                        position,
+                        bridge_account.as_slice(),
+                        deposit.height,
+                        deposit.rollup_id,
+                        deposit.bridge_address,
+                        deposit.amount,
+                        deposit.asset,
+                        deposit.destination_chain_address,
+                        deposit.source_transaction_id,
+                        deposit.source_action_index,
+                    ],
+                )?;
+            }
+            for data in sequenced_data {
+                db_tx.execute(
+                    "INSERT INTO sequenced_data (rollup_id, height, position, data) VALUES (?1, \
+                     ?2, ?3, ?4)",
+                    params![data.rollup_id, data.height, data.index, data.data],
+                )?;
+            }
+            db_tx.commit()
+        })
+        .await
+    }
+
+    /// Returns the block at `height`, or the highest indexed block if `height` is `None`.
+    pub(crate) async fn block(&self, height: Option<u64>) -> eyre::Result<Option<Block>> {
+        self.with_connection(move |connection| {
+            connection
+                .query_row(
+                    "SELECT height, hash, chain_id, time, proposer_address, transaction_count, \
+                     rollup_ids FROM blocks WHERE height = COALESCE(?1, (SELECT MAX(height) FROM \
+                     blocks))",
+                    [height],
+                    |row| {
+                        Ok(Block {
+                            height: row.get(0)?,
+                            hash: row.get(1)?,
+                            chain_id: row.get(2)?,
+                            time: row.get(3)?,
+                            proposer_address: row.get(4)?,
+                            transaction_count: row.get(5)?,
+                            rollup_ids: json_column(row, 6)?,
+                        })
+                    },
+                )
+                .optional()
+        })
+        .await
+    }
+
+    /// Returns the transactions of the block at `height` in the order they were included.
+    pub(crate) async fn block_transactions(&self, height: u64) -> eyre::Result<Vec<Transaction>> {
+        self.with_connection(move |connection| {
+            connection
+                .prepare(&format!(
+                    "SELECT {TRANSACTION_COLUMNS} FROM transactions WHERE height = ?1 ORDER BY \
+                     position"
+                ))?
+                .query_map([height], transaction_from_row)?
+                .collect()
+        })
+        .await
+    }
+
+    /// Returns the transaction with the hex encoded `hash`.
+    pub(crate) async fn transaction(&self, hash: String) -> eyre::Result<Option<Transaction>> {
+        self.with_connection(move |connection| {
+            connection
+                .query_row(
+                    &format!("SELECT {TRANSACTION_COLUMNS} FROM transactions WHERE hash = ?1"),
+                    [hash.to_lowercase()],
+                    transaction_from_row,
+                )
+                .optional()
+        })
+        .await
+    }
+
+    /// Returns the transactions signed by or sending funds to `address`, newest first.
+    pub(crate) async fn account_transactions(
+        &self,
+        address: [u8; ADDRESS_LEN],
+        page: Page,
+    ) -> eyre::Result<Vec<Transaction>> {
+        self.with_connection(move |connection| {
+            connection
+                .prepare(
+                    "SELECT t.hash, t.height, t.position, t.signer, t.nonce, t.chain_id, \
+                     t.actions FROM account_transactions a JOIN transactions t ON a.hash = t.hash \
+                     WHERE a.address = ?1 ORDER BY t.height DESC, t.position DESC LIMIT ?2 OFFSET \
+                     ?3",
+                )?
+                .query_map(
+                    params![address.as_slice(), page.limit(), page.offset()],
+                    transaction_from_row,
+                )?
+                .collect()
+        })
+        .await
+    }
+
+    /// Returns the deposits made to the bridge account `address`, newest first.
+    pub(crate) async fn account_deposits(
+        &self,
+        address: [u8; ADDRESS_LEN],
+        page: Page,
+    ) -> eyre::Result<Vec<Deposit>> {
+        self.with_connection(move |connection| {
+            connection
+                .prepare(&format!(
+                    "SELECT {DEPOSIT_COLUMNS} FROM deposits WHERE bridge_account = ?1 ORDER BY \
+                     height DESC, position DESC LIMIT ?2 OFFSET ?3"
+                ))?
+                .query_map(
+                    params![address.as_slice(), page.limit(), page.offset()],
+                    deposit_from_row,
+                )?
+                .collect()
+        })
+        .await
+    }
+
+    /// Returns the deposits made to the rollup with the base64 encoded `rollup_id`, newest first.
+    pub(crate) async fn rollup_deposits(
+        &self,
+        rollup_id: String,
+        page: Page,
+    ) -> eyre::Result<Vec<Deposit>> {
+        self.with_connection(move |connection| {
+            connection
+                .prepare(&format!(
+                    "SELECT {DEPOSIT_COLUMNS} FROM deposits WHERE rollup_id = ?1 ORDER BY height \
+                     DESC, position DESC LIMIT ?2 OFFSET ?3"
+                ))?
+                .query_map(
+                    params![rollup_id, page.limit(), page.offset()],
+                    deposit_from_row,
+                )?
+                .collect()
+        })
+        .await
+    }
+
+    /// Returns the data sequenced for the rollup with the base64 encoded `rollup_id`, newest
+    /// first.
+    pub(crate) async fn rollup_sequenced_data(
+        &self,
+        rollup_id: String,
+        page: Page,
+    ) -> eyre::Result<Vec<SequencedData>> {
+        self.with_connection(move |connection| {
+            connection
+                .prepare(
+                    "SELECT height, rollup_id, position, data FROM sequenced_data WHERE rollup_id \
+                     = ?1 ORDER BY height DESC, position DESC LIMIT ?2 OFFSET ?3",
+                )?
+                .query_map(params![rollup_id, page.limit(), page.offset()], |row| {
+                    Ok(SequencedData {
+                        height: row.get(0)?,
+                        rollup_id: row.get(1)?,
+                        index: row.get(2)?,
+                        data: row.get(3)?,
+                    })
+                })?
+                .collect()
+        })
+        .await
+    }
+}
+
+fn json_column<T: serde::de::DeserializeOwned>(row: &Row<'_>, index: usize) -> rusqlite::Result<T> {
+    let text: String = row.get(index)?;
+    serde_json::from_str(&text).map_err(|source| {
+        rusqlite::Error::FromSqlConversionFailure(index, Type::Text, source.into())
+    })
+}
+
+fn transaction_from_row(row: &Row<'_>) -> rusqlite::Result<Transaction> {
+    Ok(Transaction {
+        hash: row.get(0)?,
+        height: row.get(1)?,
+        index: row.get(2)?,
+        signer: row.get(3)?,
+        nonce: row.get(4)?,
+        chain_id: row.get(5)?,
+        actions: json_column(row, 6)?,
+    })
+}
+
+fn deposit_from_row(row: &Row<'_>) -> rusqlite::Result<Deposit> {
+    Ok(Deposit {
+        height: row.get(0)?,
+        rollup_id: row.get(1)?,
+        bridge_address: row.get(2)?,
+        amount: row.get(3)?,
+        asset: row.get(4)?,
+        destination_chain_address: row.get(5)?,
+        source_transaction_id: row.get(6)?,
+        source_action_index: row.get(7)?,
+    })
+}
+
+#[cfg(test)]
+mod tests;
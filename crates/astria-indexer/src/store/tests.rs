@@ -0,0 +1,122 @@
+use super::{
+    Page,
+    Store,
+};
+use crate::test_utils::{
+    bridge_address,
+    make_indexed_block,
+    recipient,
+    DEPOSIT_ROLLUP,
+    SEQUENCED_ROLLUP,
+};
+
+async fn store_with_blocks(heights: std::ops::RangeInclusive<u32>) -> Store {
+    let store = Store::open_in_memory().unwrap();
+    for height in heights {
+        store
+            .insert_block(make_indexed_block(height))
+            .await
+            .unwrap();
+    }
+    store
+}
+
+#[tokio::test]
+async fn empty_store_has_no_latest_height() {
+    let store = Store::open_in_memory().unwrap();
+    assert_eq!(None, store.latest_height().await.unwrap());
+    assert_eq!(None, store.block(None).await.unwrap());
+}
+
+#[tokio::test]
+async fn inserted_block_is_read_back() {
+    let store = store_with_blocks(1..=3).await;
+    let expected = make_indexed_block(2);
+
+    assert_eq!(Some(3), store.latest_height().await.unwrap());
+    assert_eq!(Some(expected.block), store.block(Some(2)).await.unwrap());
+    assert_eq!(
+        expected.transactions,
+        store.block_transactions(2).await.unwrap()
+    );
+    assert_eq!(
+        Some(expected.transactions[0].clone()),
+        store
+            .transaction(expected.transactions[0].hash.to_uppercase())
+            .await
+            .unwrap()
+    );
+    assert_eq!(None, store.block(Some(4)).await.unwrap());
+}
+
+#[tokio::test]
+async fn inserting_a_block_twice_fails() {
+    let store = store_with_blocks(1..=1).await;
+    let _ = store.insert_block(make_indexed_block(1)).await.unwrap_err();
+    assert_eq!(Some(1), store.latest_height().await.unwrap());
+}
+
+#[tokio::test]
+async fn lists_are_newest_first_and_paginated() {
+    let store = store_with_blocks(1..=5).await;
+    let page = Page {
+        limit: Some(2),
+        offset: Some(1),
+    };
+
+    let heights = |txs: Vec<crate::model::Transaction>| -> Vec<u64> {
+        txs.into_iter().map(|tx| tx.height).collect()
+    };
+    assert_eq!(
+        vec![4, 3],
+        heights(
+            store
+                .account_transactions(recipient().bytes(), page)
+                .await
+                .unwrap()
+        )
+    );
+
+    let deposits = store
+        .rollup_deposits(DEPOSIT_ROLLUP.to_string(), page)
+        .await
+        .unwrap();
+    assert_eq!(
+        vec![4, 3],
+        deposits
+            .iter()
+            .map(|deposit| deposit.height)
+            .collect::<Vec<_>>()
+    );
+    assert_eq!(
+        deposits,
+        store
+            .account_deposits(bridge_address().bytes(), page)
+            .await
+            .unwrap()
+    );
+
+    let data = store
+        .rollup_sequenced_data(SEQUENCED_ROLLUP.to_string(), Page::default())
+        .await
+        .unwrap();
+    assert_eq!(
+        vec![5, 4, 3, 2, 1],
+        data.iter().map(|data| data.height).collect::<Vec<_>>()
+    );
+    assert!(store
+        .rollup_deposits(SEQUENCED_ROLLUP.to_string(), Page::default())
+        .await
+        .unwrap()
+        .is_empty());
+}
+
+#[test]
+fn page_limit_is_capped() {
+    let page = Page {
+        limit: Some(u32::MAX),
+        offset: None,
+    };
+    assert_eq!(super::MAX_PAGE_LIMIT, page.limit());
+    assert_eq!(super::DEFAULT_PAGE_LIMIT, Page::default().limit());
+}
@@ -0,0 +1,95 @@
+use std::sync::Arc;
+
+use astria_core::{
+    crypto::SigningKey,
+    primitive::v1::{
+        Address,
+        RollupId,
+        TransactionId,
+    },
+    protocol::{
+        test_utils::ConfigureSequencerBlock,
+        transaction::v1::{
+            action::Transfer,
+            Transaction,
+            TransactionBody,
+        },
+    },
+    sequencerblock::v1::block::Deposit,
+};
+
+use crate::model::IndexedBlock;
+
+pub(crate) const ADDRESS_PREFIX: &str = "astria";
+
+pub(crate) const SEQUENCED_ROLLUP: RollupId = RollupId::new([1; 32]);
+
+pub(crate) const DEPOSIT_ROLLUP: RollupId = RollupId::new([2; 32]);
+
+pub(crate) fn signing_key() -> SigningKey {
+    SigningKey::from([1; 32])
+}
+
+pub(crate) fn address(bytes: [u8; 20]) -> Address {
+    Address::builder()
+        .array(bytes)
+        .prefix(ADDRESS_PREFIX)
+        .try_build()
+        .unwrap()
+}
+
+pub(crate) fn recipient() -> Address {
+    address([3; 20])
+}
+
+pub(crate) fn bridge_address() -> Address {
+    address([4; 20])
+}
+
+/// Returns a transfer of `amount` to [`recipient`] signed by [`signing_key`].
+pub(crate) fn make_transfer(nonce: u32, amount: u128) -> Arc<Transaction> {
+    let body = TransactionBody::builder()
+        .actions(vec![Transfer {
+            to: recipient(),
+            amount,
+            asset: "nria".parse().unwrap(),
+            fee_asset: "nria".parse().unwrap(),
+        }
+        .into()])
+        .chain_id("test")
+        .nonce(nonce)
+        .try_build()
+        .unwrap();
+    Arc::new(body.sign(&signing_key()))
+}
+
+pub(crate) fn make_deposit(amount: u128) -> Deposit {
+    Deposit {
+        bridge_address: bridge_address(),
+        rollup_id: DEPOSIT_ROLLUP,
+        amount,
+        asset: "nria".parse().unwrap(),
+        destination_chain_address: "0xdeadbeef".to_string(),
+        source_transaction_id: TransactionId::new([5; 32]),
+        source_action_index: 0,
+    }
+}
+
+/// Returns a block at `height` containing a transfer with nonce `height`, a deposit to
+/// [`DEPOSIT_ROLLUP`] and data sequenced for [`SEQUENCED_ROLLUP`].
+pub(crate) fn make_indexed_block(height: u32) -> IndexedBlock {
+    let block = ConfigureSequencerBlock {
+        height,
+        signing_key: Some(signing_key()),
+        sequence_data: vec![(SEQUENCED_ROLLUP, b"hello".to_vec())],
+        deposits: vec![make_deposit(u128::from(height))],
+        ..ConfigureSequencerBlock::default()
+    }
+    .make();
+    IndexedBlock::new(
+        &block,
+        &[make_transfer(height, u128::from(height))],
+        ADDRESS_PREFIX,
+    )
+    .unwrap()
+}
@@ -18,6 +18,15 @@ use serde::{
     Serialize,
 };
 
+/// The data availability layer to which sequencer blocks are submitted.
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub enum DaBackend {
+    /// Submit blobs to Celestia.
+    Celestia,
+    /// Store blobs in a local directory which can be read by conductor, for devnets and tests.
+    Local,
+}
+
 #[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
 /// The single config for creating an astria-sequencer-relayer service.
 pub struct Config {
@@ -45,6 +54,21 @@ pub struct Config {
     pub metrics_http_listener_addr: String,
     /// The path to which relayer will write its state while submitting to Celestia.
     pub submission_state_path: PathBuf,
+    /// The number of Celestia blocks after which a blob transaction that has not been included is
+    /// resubmitted with an escalated fee.
+    ///
+    /// This must not be lower than the mempool TTL of the Celestia app (`ttl-num-blocks`): the
+    /// Celestia app rejects a resubmission with an account sequence mismatch for as long as the
+    /// blob transaction it supersedes is still in its mempool.
+    pub celestia_resubmit_after_blocks: u32,
+    /// The percentage by which the fee is raised on each resubmission of a blob transaction.
+    pub celestia_fee_escalation_percent: u32,
+    /// The maximum gas price in utia per unit of gas that an escalated fee is capped at.
+    pub celestia_max_gas_price: f64,
+    /// The data availability layer to submit to.
+    pub da_backend: DaBackend,
+    /// The directory in which blobs are stored if `da_backend` is `Local`.
+    pub local_da_path: PathBuf,
 }
 
 impl Config {
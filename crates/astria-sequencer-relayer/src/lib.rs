@@ -9,6 +9,7 @@ pub(crate) mod utils;
 pub use build_info::BUILD_INFO;
 pub use config::{
     Config,
+    DaBackend,
     IncludeRollup,
 };
 pub use metrics::Metrics;
@@ -14,6 +14,7 @@ pub struct Metrics {
     celestia_submission_height: Counter,
     celestia_submission_count: Counter,
     celestia_submission_failure_count: Counter,
+    celestia_submission_resubmission_count: Counter,
     blocks_per_celestia_tx: Histogram,
     blobs_per_celestia_tx: Histogram,
     bytes_per_celestia_tx: Histogram,
@@ -41,6 +42,10 @@ impl Metrics {
         self.celestia_submission_failure_count.increment(1);
     }
 
+    pub(crate) fn increment_celestia_submission_resubmission_count(&self) {
+        self.celestia_submission_resubmission_count.increment(1);
+    }
+
     pub(crate) fn record_blocks_per_celestia_tx(&self, block_count: usize) {
         self.blocks_per_celestia_tx.record(block_count);
     }
@@ -123,6 +128,14 @@ impl telemetry::Metrics for Metrics {
             )?
             .register()?;
 
+        let celestia_submission_resubmission_count = builder
+            .new_counter_factory(
+                CELESTIA_SUBMISSION_RESUBMISSION_COUNT,
+                "The number of times a blob transaction which was not included by Celestia in \
+                 time has been resubmitted with an escalated fee",
+            )?
+            .register()?;
+
         let blocks_per_celestia_tx = builder
             .new_histogram_factory(
                 BLOCKS_PER_CELESTIA_TX,
@@ -218,6 +231,7 @@ impl telemetry::Metrics for Metrics {
             celestia_submission_height,
             celestia_submission_count,
             celestia_submission_failure_count,
+            celestia_submission_resubmission_count,
             blocks_per_celestia_tx,
             blobs_per_celestia_tx,
             bytes_per_celestia_tx,
@@ -238,6 +252,7 @@ metric_names!(const METRICS_NAMES:
     CELESTIA_SUBMISSION_HEIGHT,
     CELESTIA_SUBMISSION_COUNT,
     CELESTIA_SUBMISSION_FAILURE_COUNT,
+    CELESTIA_SUBMISSION_RESUBMISSION_COUNT,
     BLOCKS_PER_CELESTIA_TX,
     BLOBS_PER_CELESTIA_TX,
     BYTES_PER_CELESTIA_TX,
@@ -272,6 +287,10 @@ mod tests {
             CELESTIA_SUBMISSION_FAILURE_COUNT,
             "celestia_submission_failure_count",
         );
+        assert_const(
+            CELESTIA_SUBMISSION_RESUBMISSION_COUNT,
+            "celestia_submission_resubmission_count",
+        );
         assert_const(BLOCKS_PER_CELESTIA_TX, "blocks_per_celestia_tx");
         assert_const(BLOBS_PER_CELESTIA_TX, "blobs_per_celestia_tx");
         assert_const(BYTES_PER_CELESTIA_TX, "bytes_per_celestia_tx");
@@ -7,6 +7,7 @@ use std::{
 use astria_core::generated::astria::sequencerblock::v1::sequencer_service_client::SequencerServiceClient;
 use astria_eyre::eyre::{
     self,
+    ensure,
     WrapErr as _,
 };
 use sequencer_client::HttpClient as SequencerClient;
@@ -17,11 +18,16 @@ use tonic::transport::{
 
 use super::{
     state::State,
+    AnyDaClientBuilder,
     CelestiaClientBuilder,
     CelestiaKeys,
+    FeeEscalation,
+    LocalDaClientBuilder,
+    CELESTIA_BLOCK_TIME,
 };
 use crate::{
     metrics::Metrics,
+    DaBackend,
     IncludeRollup,
 };
 
@@ -36,6 +42,11 @@ pub(crate) struct Builder {
     pub(crate) sequencer_grpc_endpoint: String,
     pub(crate) rollup_filter: IncludeRollup,
     pub(crate) submission_state_path: PathBuf,
+    pub(crate) celestia_resubmit_after_blocks: u32,
+    pub(crate) celestia_fee_escalation_percent: u32,
+    pub(crate) celestia_max_gas_price: f64,
+    pub(crate) da_backend: DaBackend,
+    pub(crate) local_da_path: PathBuf,
     pub(crate) metrics: &'static Metrics,
 }
 
@@ -53,9 +64,23 @@ impl Builder {
             sequencer_grpc_endpoint,
             rollup_filter,
             submission_state_path,
+            celestia_resubmit_after_blocks,
+            celestia_fee_escalation_percent,
+            celestia_max_gas_price,
+            da_backend,
+            local_da_path,
             metrics,
         } = self;
 
+        ensure!(
+            celestia_resubmit_after_blocks > 0,
+            "the number of celestia blocks after which to resubmit must be greater than zero"
+        );
+        ensure!(
+            celestia_max_gas_price.is_finite() && celestia_max_gas_price >= 0.0,
+            "the maximum celestia gas price must be a non-negative number"
+        );
+
         let submitter_shutdown_token = relayer_shutdown_token.child_token();
 
         let sequencer_cometbft_client = SequencerClient::new(&*cometbft_endpoint)
@@ -71,14 +96,31 @@ impl Builder {
 
         let state = Arc::new(State::new());
 
-        let celestia_client_builder = {
-            let uri: Uri = celestia_app_grpc_endpoint
-                .parse()
-                .wrap_err("failed parsing provided celestia app grpc endpoint as Uri")?;
-            let celestia_keys = CelestiaKeys::from_path(celestia_app_key_file)
-                .wrap_err("failed to get celestia keys from file")?;
-            CelestiaClientBuilder::new(celestia_chain_id, uri, celestia_keys, state.clone())
-                .wrap_err("failed to create celestia client builder")?
+        let da_client_builder = match da_backend {
+            DaBackend::Celestia => {
+                let uri: Uri = celestia_app_grpc_endpoint
+                    .parse()
+                    .wrap_err("failed parsing provided celestia app grpc endpoint as Uri")?;
+                let celestia_keys = CelestiaKeys::from_path(celestia_app_key_file)
+                    .wrap_err("failed to get celestia keys from file")?;
+                let fee_escalation =
+                    FeeEscalation::new(celestia_fee_escalation_percent, celestia_max_gas_price);
+                AnyDaClientBuilder::Celestia(
+                    CelestiaClientBuilder::new(
+                        celestia_chain_id,
+                        uri,
+                        celestia_keys,
+                        fee_escalation,
+                        state.clone(),
+                    )
+                    .wrap_err("failed to create celestia client builder")?,
+                )
+            }
+            DaBackend::Local => AnyDaClientBuilder::Local(LocalDaClientBuilder::new(
+                celestia_chain_id,
+                local_da_path,
+                state.clone(),
+            )),
         };
 
         Ok(super::Relayer {
@@ -88,7 +130,9 @@ impl Builder {
             sequencer_cometbft_client,
             sequencer_grpc_client,
             sequencer_poll_period,
-            celestia_client_builder,
+            da_client_builder,
+            celestia_resubmit_after: CELESTIA_BLOCK_TIME
+                .saturating_mul(celestia_resubmit_after_blocks),
             rollup_filter,
             state,
             submission_state_path,
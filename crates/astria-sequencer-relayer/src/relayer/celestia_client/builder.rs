@@ -1,4 +1,9 @@
 use std::{
+    io,
+    path::{
+        Path,
+        PathBuf,
+    },
     sync::Arc,
     time::Duration,
 };
@@ -28,6 +33,7 @@ use super::{
     Bech32Address,
     CelestiaClient,
     CelestiaKeys,
+    FeeEscalation,
     GrpcResponseError,
 };
 
@@ -58,6 +64,21 @@ pub(in crate::relayer) enum BuilderError {
         configured: String,
         received: String,
     },
+    /// Failed to access the local data availability store.
+    #[error("failed to access `{}` in the local data availability store", path.display())]
+    LocalDa {
+        path: PathBuf,
+        source: Arc<io::Error>,
+    },
+}
+
+impl BuilderError {
+    pub(in crate::relayer) fn local_da(path: &Path, source: io::Error) -> Self {
+        Self::LocalDa {
+            path: path.to_path_buf(),
+            source: Arc::new(source),
+        }
+    }
 }
 
 /// An error while encoding a Bech32 string.
@@ -75,6 +96,8 @@ pub(in crate::relayer) struct Builder {
     signing_keys: CelestiaKeys,
     /// The Bech32-encoded address of our Celestia account.
     address: Bech32Address,
+    /// How the fee is raised when resubmitting a blob transaction which was not included in time.
+    fee_escalation: FeeEscalation,
     /// A handle to the mutable state of the relayer.
     state: Arc<State>,
 }
@@ -85,6 +108,7 @@ impl Builder {
         configured_celestia_chain_id: String,
         uri: Uri,
         signing_keys: CelestiaKeys,
+        fee_escalation: FeeEscalation,
         state: Arc<State>,
     ) -> Result<Self, BuilderError> {
         let grpc_channel = Endpoint::from(uri).timeout(REQUEST_TIMEOUT).connect_lazy();
@@ -94,6 +118,7 @@ impl Builder {
             grpc_channel,
             signing_keys,
             address,
+            fee_escalation,
             state,
         })
     }
@@ -108,6 +133,7 @@ impl Builder {
             grpc_channel,
             signing_keys,
             address,
+            fee_escalation,
             state,
         } = self;
 
@@ -128,6 +154,7 @@ impl Builder {
             signing_keys,
             address,
             chain_id: received_celestia_chain_id,
+            fee_escalation,
         })
     }
 
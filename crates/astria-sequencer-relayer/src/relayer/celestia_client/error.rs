@@ -4,7 +4,13 @@ use std::{
         Display,
         Formatter,
     },
+    io,
     num::ParseFloatError,
+    path::{
+        Path,
+        PathBuf,
+    },
+    sync::Arc,
 };
 
 use prost::DecodeError;
@@ -97,6 +103,41 @@ pub(in crate::relayer) enum TrySubmitError {
     /// The get transaction response specified a negative block height.
     #[error("get transaction response specifies a negative block height ({0})")]
     GetTxResponseNegativeBlockHeight(i64),
+    /// Failed to access the local data availability store.
+    #[error("failed to access `{}` in the local data availability store", path.display())]
+    LocalDa {
+        path: PathBuf,
+        source: Arc<io::Error>,
+    },
+    /// A height read from the local data availability store could not be parsed.
+    #[error("failed to parse `{height}` read from `{}` as a height", path.display())]
+    LocalDaInvalidHeight { path: PathBuf, height: String },
+}
+
+// From https://github.com/celestiaorg/cosmos-sdk/blob/v1.18.3-sdk-v0.46.14/types/errors/errors.go#L110
+const WRONG_SEQUENCE_CODE: u32 = 32;
+const SDK_CODESPACE: &str = "sdk";
+
+impl TrySubmitError {
+    /// Returns whether the Celestia app rejected a broadcast transaction because its account
+    /// sequence did not match the expected one.
+    ///
+    /// This is the case while an earlier transaction using the same sequence is still in the
+    /// Celestia app's mempool.
+    pub(in crate::relayer) fn is_sequence_mismatch(&self) -> bool {
+        matches!(
+            self,
+            Self::BroadcastTxResponseErrorCode { code, namespace, .. }
+                if *code == WRONG_SEQUENCE_CODE && namespace == SDK_CODESPACE
+        )
+    }
+
+    pub(in crate::relayer) fn local_da(path: &Path, source: io::Error) -> Self {
+        Self::LocalDa {
+            path: path.to_path_buf(),
+            source: Arc::new(source),
+        }
+    }
 }
 
 /// A gRPC status representing an error response from an RPC call.
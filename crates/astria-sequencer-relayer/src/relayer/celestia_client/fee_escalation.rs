@@ -0,0 +1,42 @@
+/// How the fee of a blob transaction is raised when it is resubmitted after not being included by
+/// Celestia in time.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub(in crate::relayer) struct FeeEscalation {
+    /// The percentage by which the fee is raised on each escalation.
+    percent: u32,
+    /// The maximum gas price in utia per unit of gas an escalated fee is capped at.
+    max_gas_price: f64,
+}
+
+impl FeeEscalation {
+    pub(in crate::relayer) fn new(percent: u32, max_gas_price: f64) -> Self {
+        Self {
+            percent,
+            max_gas_price,
+        }
+    }
+
+    /// Returns `fee` raised by the configured percentage `escalations` times, compounding.
+    ///
+    /// The result is capped such that the gas price does not exceed the configured maximum, but is
+    /// never lower than `fee` itself.
+    pub(super) fn escalate(&self, fee: u64, gas_limit: u64, escalations: u32) -> u64 {
+        if escalations == 0 {
+            return fee;
+        }
+        let factor = 1.0 + f64::from(self.percent) / 100.0;
+        #[expect(
+            clippy::cast_sign_loss,
+            clippy::cast_possible_truncation,
+            clippy::cast_precision_loss,
+            reason = "all values are non-negative, and the conversions saturate rather than wrap; \
+                      precision loss is acceptable as the result is a best-effort fee"
+        )]
+        let (escalated_fee, max_fee) = (
+            (fee as f64 * factor.powi(i32::try_from(escalations).unwrap_or(i32::MAX))).ceil()
+                as u64,
+            (self.max_gas_price * gas_limit as f64).floor() as u64,
+        );
+        escalated_fee.min(max_fee).max(fee)
+    }
+}
@@ -2,6 +2,7 @@ mod builder;
 mod celestia_cost_params;
 pub(crate) mod celestia_keys;
 mod error;
+mod fee_escalation;
 #[cfg(test)]
 mod tests;
 
@@ -84,6 +85,7 @@ pub(super) use error::{
     ProtobufDecodeError,
     TrySubmitError,
 };
+pub(super) use fee_escalation::FeeEscalation;
 use hex::{
     FromHex,
     FromHexError,
@@ -139,6 +141,8 @@ pub(super) struct CelestiaClient {
     address: Bech32Address,
     /// The Celestia network ID.
     chain_id: String,
+    /// How the fee is raised when resubmitting a blob transaction which was not included in time.
+    fee_escalation: FeeEscalation,
 }
 
 impl CelestiaClient {
@@ -148,12 +152,16 @@ impl CelestiaClient {
     /// the first attempt for these blobs, or `None` if it is the first attempt.  The error can be
     /// used to obtain the appropriate fee in the case that the previous attempt failed due to a
     /// low fee.
+    ///
+    /// `fee_escalations` is the number of times the fee should be escalated, i.e. the number of
+    /// previous attempts with the same blobs which were not included by Celestia in time.
     // Copied from https://github.com/celestiaorg/celestia-app/blob/v1.4.0/x/blob/payforblob.go
     #[instrument(skip_all, err(level = Level::WARN))]
     pub(super) async fn try_prepare(
         &mut self,
         blobs: Arc<Vec<Blob>>,
         maybe_last_error: Option<TrySubmitError>,
+        fee_escalations: u32,
     ) -> Result<BlobTxAndFee, TrySubmitError> {
         info!("fetching cost params and account info from celestia app");
         let (blob_params, auth_params, min_gas_price, base_account) = tokio::try_join!(
@@ -179,7 +187,11 @@ impl CelestiaClient {
         let cost_params =
             CelestiaCostParams::new(gas_per_blob_byte, tx_size_cost_per_byte, min_gas_price);
         let gas_limit = estimate_gas(&msg_pay_for_blobs.blob_sizes, cost_params);
-        let fee = calculate_fee(cost_params, gas_limit, maybe_last_error);
+        let fee = self.fee_escalation.escalate(
+            calculate_fee(cost_params, gas_limit, maybe_last_error),
+            gas_limit.0,
+            fee_escalations,
+        );
 
         let signed_tx = new_signed_tx(
             &msg_pay_for_blobs,
@@ -193,18 +205,20 @@ impl CelestiaClient {
         info!(
             gas_limit = gas_limit.0,
             fee_utia = fee,
+            fee_escalations,
             "prepared blob transaction for celestia app"
         );
 
         Ok(BlobTxAndFee::new(&signed_tx, blobs.iter(), fee))
     }
 
+    /// Broadcasts `blob_tx` to the Celestia app without waiting for it to be included.
     #[instrument(skip_all, err(level = Level::WARN))]
-    pub(super) async fn try_submit(
+    pub(super) async fn try_broadcast(
         &mut self,
         blob_tx_hash: BlobTxHash,
         blob_tx: BlobTx,
-    ) -> Result<u64, TrySubmitError> {
+    ) -> Result<(), TrySubmitError> {
         info!("broadcasting blob transaction to celestia app");
         let hex_encoded_tx_hash = self.broadcast_tx(blob_tx).await?;
         if hex_encoded_tx_hash != blob_tx_hash.to_hex() {
@@ -217,23 +231,26 @@ impl CelestiaClient {
             );
         }
         info!(tx_hash = %hex_encoded_tx_hash, "broadcast blob transaction succeeded");
-
-        let height = self.confirm_submission(hex_encoded_tx_hash).await;
-        Ok(height)
+        Ok(())
     }
 
-    /// Repeatedly sends `GetTx` until a successful response is received or `timeout` duration has
-    /// elapsed.
+    /// Repeatedly sends `GetTx` for each of `blob_tx_hashes` until a successful response is
+    /// received for one of them or `timeout` duration has elapsed.
     ///
     /// Returns the height of the Celestia block in which the blobs were submitted, or `None` if
     /// timed out.
     #[instrument(skip_all)]
     pub(super) async fn confirm_submission_with_timeout(
         &mut self,
-        blob_tx_hash: &BlobTxHash,
+        blob_tx_hashes: &[BlobTxHash],
         timeout: Duration,
     ) -> Option<u64> {
-        tokio::time::timeout(timeout, self.confirm_submission(blob_tx_hash.to_hex()))
+        let hex_encoded_tx_hashes = blob_tx_hashes
+            .iter()
+            .copied()
+            .map(BlobTxHash::to_hex)
+            .collect();
+        tokio::time::timeout(timeout, self.confirm_submission(hex_encoded_tx_hashes))
             .await
             .ok()
     }
@@ -338,10 +355,14 @@ impl CelestiaClient {
         block_height_from_response(response)
     }
 
-    /// Repeatedly sends `GetTx` until a successful response is received.  Returns the height of the
-    /// Celestia block in which the blobs were submitted.
-    #[instrument(skip_all, fields(hex_encoded_tx_hash))]
-    async fn confirm_submission(&mut self, hex_encoded_tx_hash: String) -> u64 {
+    /// Repeatedly sends `GetTx` for each of the given hashes until a successful response is
+    /// received for one of them.  Returns the height of the Celestia block in which the blobs were
+    /// submitted.
+    ///
+    /// More than one hash is given if the same blobs were submitted several times with escalating
+    /// fees; only one of these transactions can be included.
+    #[instrument(skip_all, fields(hex_encoded_tx_hashes))]
+    async fn confirm_submission(&mut self, hex_encoded_tx_hashes: Vec<String>) -> u64 {
         // The min seconds to sleep after receiving a GetTx response and sending the next request.
         const MIN_POLL_INTERVAL_SECS: u64 = 1;
         // The max seconds to sleep after receiving a GetTx response and sending the next request.
@@ -361,7 +382,7 @@ impl CelestiaClient {
             let reason = maybe_error.map_or(Report::msg("transaction still pending"), Report::new);
             warn!(
                 %reason,
-                tx_hash = %hex_encoded_tx_hash,
+                tx_hashes = %hex_encoded_tx_hashes.join(","),
                 elapsed_seconds = start.elapsed().as_secs_f32(),
                 "waiting to confirm blob submission"
             );
@@ -371,18 +392,20 @@ impl CelestiaClient {
         let mut sleep_secs = MIN_POLL_INTERVAL_SECS;
         loop {
             tokio::time::sleep(Duration::from_secs(sleep_secs)).await;
-            match self.get_tx(hex_encoded_tx_hash.clone()).await {
-                Ok(Some(height)) => return height,
-                Ok(None) => {
-                    sleep_secs = MIN_POLL_INTERVAL_SECS;
-                    log_if_due(None);
-                }
-                Err(error) => {
-                    sleep_secs =
-                        std::cmp::min(sleep_secs.saturating_mul(2), MAX_POLL_INTERVAL_SECS);
-                    log_if_due(Some(error));
+            let mut maybe_error = None;
+            for hex_encoded_tx_hash in &hex_encoded_tx_hashes {
+                match self.get_tx(hex_encoded_tx_hash.clone()).await {
+                    Ok(Some(height)) => return height,
+                    Ok(None) => {}
+                    Err(error) => maybe_error = Some(error),
                 }
             }
+            if maybe_error.is_some() {
+                sleep_secs = std::cmp::min(sleep_secs.saturating_mul(2), MAX_POLL_INTERVAL_SECS);
+            } else {
+                sleep_secs = MIN_POLL_INTERVAL_SECS;
+            }
+            log_if_due(maybe_error);
         }
     }
 }
@@ -775,8 +798,8 @@ fn new_signed_tx(
     }
 }
 
-pub(in crate::relayer) struct BlobTxAndFee {
-    pub(in crate::relayer) tx: BlobTx,
+pub(in crate::relayer) struct BlobTxAndFee<T = BlobTx> {
+    pub(in crate::relayer) tx: T,
     pub(in crate::relayer) fee: u64,
 }
 
@@ -829,10 +852,13 @@ impl BlobTxHash {
         hex::encode(self.0)
     }
 
-    #[cfg(test)]
     pub(super) const fn from_raw(hash: [u8; 32]) -> Self {
         Self(hash)
     }
+
+    pub(super) fn as_bytes(&self) -> &[u8; 32] {
+        &self.0
+    }
 }
 
 impl Display for BlobTxHash {
@@ -511,6 +511,25 @@ fn should_use_fee_from_error_log() {
     assert_eq!(fee, required_fee);
 }
 
+#[test]
+fn fee_escalation_should_compound_and_respect_limits() {
+    let fee_escalation = FeeEscalation::new(50, 1.0);
+
+    // No escalation should leave the fee unchanged.
+    assert_eq!(fee_escalation.escalate(100, 1_000, 0), 100);
+
+    // Each escalation should raise the fee by 50% of the previous one.
+    assert_eq!(fee_escalation.escalate(100, 1_000, 1), 150);
+    assert_eq!(fee_escalation.escalate(100, 1_000, 2), 225);
+
+    // The fee should be capped at max gas price * gas limit.
+    assert_eq!(fee_escalation.escalate(100, 1_000, 10), 1_000);
+    assert_eq!(fee_escalation.escalate(100, 1_000, u32::MAX), 1_000);
+
+    // The fee should never be lowered, even if it exceeds the cap already.
+    assert_eq!(fee_escalation.escalate(2_000, 1_000, 1), 2_000);
+}
+
 #[test]
 fn extract_required_fee_from_log_should_succeed() {
     fn check(fee: u64) {
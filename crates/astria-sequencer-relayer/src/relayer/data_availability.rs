@@ -0,0 +1,125 @@
+//! The abstraction over the data availability layer to which the relayer submits blobs.
+//!
+//! [`BlobSubmitter`](super::write::BlobSubmitter) is generic over [`DaClientBuilder`] so that it
+//! can submit either to Celestia via [`CelestiaClient`] or to a local directory via
+//! [`LocalDaClient`](super::local_da_client::LocalDaClient).
+use std::{
+    sync::Arc,
+    time::Duration,
+};
+
+use astria_core::generated::tendermint::types::BlobTx;
+use async_trait::async_trait;
+use celestia_types::Blob;
+
+use super::{
+    celestia_client::{
+        BlobTxAndFee,
+        CelestiaClient,
+    },
+    local_da_client::LocalDaClientBuilder,
+    BlobTxHash,
+    BuilderError,
+    CelestiaClientBuilder,
+    TrySubmitError,
+};
+
+/// A builder for a [`DaClient`], retried by the blob submitter until it succeeds or returns a
+/// [`BuilderError::MismatchedCelestiaChainId`].
+#[async_trait]
+pub(super) trait DaClientBuilder: Clone + Send + Sync + 'static {
+    type Client: DaClient;
+
+    async fn try_build(self) -> Result<Self::Client, BuilderError>;
+}
+
+/// A client submitting blobs to a data availability layer.
+///
+/// Submissions are made in two steps so that the hash of a prepared transaction can be persisted
+/// before it is submitted, allowing its fate to be confirmed after a restart.
+#[async_trait]
+pub(super) trait DaClient: Clone + Send + Sync + 'static {
+    /// The transaction carrying the blobs of a single submission.
+    type BlobTx: Send + 'static;
+
+    /// Returns the hash identifying `blob_tx`.
+    fn blob_tx_hash(blob_tx: &Self::BlobTx) -> BlobTxHash;
+
+    /// Prepares a transaction carrying `blobs`.
+    ///
+    /// `maybe_last_error` is the error of the previous attempt for these blobs, if any, and
+    /// `fee_escalations` the number of previous attempts which were not included in time.
+    async fn try_prepare(
+        &mut self,
+        blobs: Arc<Vec<Blob>>,
+        maybe_last_error: Option<TrySubmitError>,
+        fee_escalations: u32,
+    ) -> Result<BlobTxAndFee<Self::BlobTx>, TrySubmitError>;
+
+    /// Broadcasts `blob_tx` without waiting for it to be included.
+    ///
+    /// Inclusion is confirmed using [`DaClient::confirm_submission_with_timeout`].
+    async fn try_broadcast(
+        &mut self,
+        blob_tx_hash: BlobTxHash,
+        blob_tx: Self::BlobTx,
+    ) -> Result<(), TrySubmitError>;
+
+    /// Returns the height at which any of the transactions identified by `blob_tx_hashes` was
+    /// included, or `None` if none was included within `timeout`.
+    async fn confirm_submission_with_timeout(
+        &mut self,
+        blob_tx_hashes: &[BlobTxHash],
+        timeout: Duration,
+    ) -> Option<u64>;
+}
+
+#[async_trait]
+impl DaClientBuilder for CelestiaClientBuilder {
+    type Client = CelestiaClient;
+
+    async fn try_build(self) -> Result<CelestiaClient, BuilderError> {
+        CelestiaClientBuilder::try_build(self).await
+    }
+}
+
+#[async_trait]
+impl DaClient for CelestiaClient {
+    type BlobTx = BlobTx;
+
+    fn blob_tx_hash(blob_tx: &BlobTx) -> BlobTxHash {
+        BlobTxHash::compute(blob_tx)
+    }
+
+    async fn try_prepare(
+        &mut self,
+        blobs: Arc<Vec<Blob>>,
+        maybe_last_error: Option<TrySubmitError>,
+        fee_escalations: u32,
+    ) -> Result<BlobTxAndFee, TrySubmitError> {
+        CelestiaClient::try_prepare(self, blobs, maybe_last_error, fee_escalations).await
+    }
+
+    async fn try_broadcast(
+        &mut self,
+        blob_tx_hash: BlobTxHash,
+        blob_tx: BlobTx,
+    ) -> Result<(), TrySubmitError> {
+        CelestiaClient::try_broadcast(self, blob_tx_hash, blob_tx).await
+    }
+
+    async fn confirm_submission_with_timeout(
+        &mut self,
+        blob_tx_hashes: &[BlobTxHash],
+        timeout: Duration,
+    ) -> Option<u64> {
+        CelestiaClient::confirm_submission_with_timeout(self, blob_tx_hashes, timeout).await
+    }
+}
+
+/// The builder of the client for the configured data availability backend.
+#[derive(Clone)]
+pub(super) enum AnyDaClientBuilder {
+    Celestia(CelestiaClientBuilder),
+    Local(LocalDaClientBuilder),
+}
@@ -0,0 +1,435 @@
+//! A data availability client storing blobs in a local directory instead of submitting them to
+//! Celestia.
+//!
+//! The directory follows the layout described in [`astria_core::celestia::local`], which is read
+//! by conductor. This allows devnets and integration tests to exercise the firm commitment path
+//! without running a Celestia node.
+//!
+//! Only a single relayer must write to a given directory.
+use std::{
+    collections::HashMap,
+    io,
+    path::{
+        Path,
+        PathBuf,
+    },
+    sync::Arc,
+    time::Duration,
+};
+
+use astria_core::celestia::local;
+use async_trait::async_trait;
+use celestia_types::Blob;
+use sha2::{
+    Digest as _,
+    Sha256,
+};
+use tracing::{
+    info,
+    instrument,
+    warn,
+};
+
+use super::{
+    celestia_client::BlobTxAndFee,
+    data_availability::{
+        DaClient,
+        DaClientBuilder,
+    },
+    BlobTxHash,
+    BuilderError,
+    State,
+    TrySubmitError,
+};
+
+/// A builder for a [`LocalDaClient`].
+#[derive(Clone)]
+pub(super) struct LocalDaClientBuilder {
+    configured_chain_id: String,
+    /// The root directory of the local data availability store.
+    root: PathBuf,
+    /// A handle to the mutable state of the relayer.
+    state: Arc<State>,
+}
+
+impl LocalDaClientBuilder {
+    pub(super) fn new(configured_chain_id: String, root: PathBuf, state: Arc<State>) -> Self {
+        Self {
+            configured_chain_id,
+            root,
+            state,
+        }
+    }
+}
+
+#[async_trait]
+impl DaClientBuilder for LocalDaClientBuilder {
+    type Client = LocalDaClient;
+
+    /// Returns a new `LocalDaClient`, initializing the store at the configured root if it does
+    /// not exist yet.
+    #[instrument(skip_all, err)]
+    async fn try_build(self) -> Result<LocalDaClient, BuilderError> {
+        let Self {
+            configured_chain_id,
+            root,
+            state,
+        } = self;
+
+        tokio::fs::create_dir_all(&root)
+            .await
+            .map_err(|source| BuilderError::local_da(&root, source))?;
+
+        let chain_id_path = local::chain_id_path(&root);
+        let chain_id = match tokio::fs::read_to_string(&chain_id_path).await {
+            Ok(chain_id) => chain_id,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => {
+                write_atomically(&chain_id_path, configured_chain_id.as_bytes())
+                    .await
+                    .map_err(|source| BuilderError::local_da(&chain_id_path, source))?;
+                info!(root = %root.display(), "initialized local data availability store");
+                configured_chain_id.clone()
+            }
+            Err(source) => return Err(BuilderError::local_da(&chain_id_path, source)),
+        };
+
+        if chain_id != configured_chain_id {
+            return Err(BuilderError::MismatchedCelestiaChainId {
+                configured: configured_chain_id,
+                received: chain_id,
+            });
+        }
+
+        info!(%chain_id, "confirmed local data availability chain id");
+        state.set_celestia_connected(true);
+
+        Ok(LocalDaClient {
+            root,
+        })
+    }
+}
+
+/// The blobs of a single submission to the local data availability store.
+pub(super) struct LocalBlobTx {
+    blobs: Arc<Vec<Blob>>,
+    hash: BlobTxHash,
+}
+
+/// A client storing blobs in a local directory.
+///
+/// Every submission is stored at the height following the latest one, and is immediately
+/// included. Submissions are identified by the hash of their blobs, so that storing the same
+/// blobs twice is a no-op.
+#[derive(Clone, Debug)]
+pub(super) struct LocalDaClient {
+    /// The root directory of the local data availability store.
+    root: PathBuf,
+}
+
+impl LocalDaClient {
+    /// Returns the latest height at which blobs were stored, or 0 if none were.
+    async fn latest_height(&self) -> Result<u64, TrySubmitError> {
+        let path = local::latest_height_path(&self.root);
+        match tokio::fs::read_to_string(&path).await {
+            Ok(height) => parse_height(&path, &height),
+            Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(0),
+            Err(source) => Err(TrySubmitError::local_da(&path, source)),
+        }
+    }
+
+    /// Returns the height at which the first of the submissions identified by `blob_tx_hashes`
+    /// was stored, if any.
+    async fn find_submission(
+        &self,
+        blob_tx_hashes: &[BlobTxHash],
+    ) -> Result<Option<u64>, TrySubmitError> {
+        for blob_tx_hash in blob_tx_hashes {
+            let path = local::submission_path(&self.root, blob_tx_hash.as_bytes());
+            match tokio::fs::read_to_string(&path).await {
+                Ok(height) => return parse_height(&path, &height).map(Some),
+                Err(error) if error.kind() == io::ErrorKind::NotFound => {}
+                Err(source) => return Err(TrySubmitError::local_da(&path, source)),
+            }
+        }
+        Ok(None)
+    }
+
+    /// Removes the blobs left at `height` in any namespace by earlier, unrecorded attempts to
+    /// store a submission.
+    async fn remove_stale_blobs(&self, height: u64) -> Result<(), TrySubmitError> {
+        let blobs_root = local::blobs_root(&self.root);
+        let mut namespace_dirs = match tokio::fs::read_dir(&blobs_root).await {
+            Ok(namespace_dirs) => namespace_dirs,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(()),
+            Err(source) => return Err(TrySubmitError::local_da(&blobs_root, source)),
+        };
+        while let Some(namespace_dir) = namespace_dirs
+            .next_entry()
+            .await
+            .map_err(|source| TrySubmitError::local_da(&blobs_root, source))?
+        {
+            let dir = namespace_dir.path().join(height.to_string());
+            match tokio::fs::remove_dir_all(&dir).await {
+                Ok(()) => warn!(dir = %dir.display(), "removed blobs of an unrecorded submission"),
+                Err(error) if error.kind() == io::ErrorKind::NotFound => {}
+                Err(source) => return Err(TrySubmitError::local_da(&dir, source)),
+            }
+        }
+        Ok(())
+    }
+
+    /// Stores `blobs` at `height`, indexing them per namespace in the order given.
+    ///
+    /// Any blobs left at `height` by an earlier attempt are removed first, so that readers only
+    /// find the blobs of this submission.
+    async fn write_blobs(&self, blobs: &[Blob], height: u64) -> Result<(), TrySubmitError> {
+        self.remove_stale_blobs(height).await?;
+        let mut next_indices = HashMap::new();
+        for blob in blobs {
+            let dir = local::blobs_dir(&self.root, &blob.namespace, height);
+            let index: &mut u64 = next_indices.entry(blob.namespace).or_default();
+            if *index == 0 {
+                tokio::fs::create_dir_all(&dir)
+                    .await
+                    .map_err(|source| TrySubmitError::local_da(&dir, source))?;
+            }
+            let path = dir.join(index.to_string());
+            write_atomically(&path, &blob.data)
+                .await
+                .map_err(|source| TrySubmitError::local_da(&path, source))?;
+            *index = index.saturating_add(1);
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl DaClient for LocalDaClient {
+    type BlobTx = LocalBlobTx;
+
+    fn blob_tx_hash(blob_tx: &LocalBlobTx) -> BlobTxHash {
+        blob_tx.hash
+    }
+
+    async fn try_prepare(
+        &mut self,
+        blobs: Arc<Vec<Blob>>,
+        _maybe_last_error: Option<TrySubmitError>,
+        _fee_escalations: u32,
+    ) -> Result<BlobTxAndFee<LocalBlobTx>, TrySubmitError> {
+        let hash = hash_blobs(&blobs);
+        Ok(BlobTxAndFee {
+            tx: LocalBlobTx {
+                blobs,
+                hash,
+            },
+            fee: 0,
+        })
+    }
+
+    #[instrument(skip_all, fields(%blob_tx_hash), err)]
+    async fn try_broadcast(
+        &mut self,
+        blob_tx_hash: BlobTxHash,
+        blob_tx: LocalBlobTx,
+    ) -> Result<(), TrySubmitError> {
+        if let Some(height) = self.find_submission(&[blob_tx_hash]).await? {
+            info!(
+                height,
+                "blobs were already stored in local data availability store"
+            );
+            return Ok(());
+        }
+
+        let height = self.latest_height().await?.saturating_add(1);
+        self.write_blobs(&blob_tx.blobs, height).await?;
+
+        // The submission is only recorded once all of its blobs are written, and the latest
+        // height is only advanced once the submission is recorded. A partially written height is
+        // cleared by the next attempt.
+        let submission_path = local::submission_path(&self.root, blob_tx_hash.as_bytes());
+        if let Some(parent) = submission_path.parent() {
+            tokio::fs::create_dir_all(parent)
+                .await
+                .map_err(|source| TrySubmitError::local_da(parent, source))?;
+        }
+        write_atomically(&submission_path, height.to_string().as_bytes())
+            .await
+            .map_err(|source| TrySubmitError::local_da(&submission_path, source))?;
+        let latest_height_path = local::latest_height_path(&self.root);
+        write_atomically(&latest_height_path, height.to_string().as_bytes())
+            .await
+            .map_err(|source| TrySubmitError::local_da(&latest_height_path, source))?;
+
+        info!(height, "stored blobs in local data availability store");
+        Ok(())
+    }
+
+    async fn confirm_submission_with_timeout(
+        &mut self,
+        blob_tx_hashes: &[BlobTxHash],
+        _timeout: Duration,
+    ) -> Option<u64> {
+        // Submissions are stored synchronously, so there is nothing to wait for.
+        self.find_submission(blob_tx_hashes)
+            .await
+            .inspect_err(|error| {
+                warn!(%error, "failed to look up submission in local data availability store");
+            })
+            .ok()
+            .flatten()
+    }
+}
+
+/// Returns the SHA256 digest over the namespaces and data of `blobs`.
+fn hash_blobs(blobs: &[Blob]) -> BlobTxHash {
+    let mut hasher = Sha256::new();
+    for blob in blobs {
+        hasher.update(blob.namespace.as_bytes());
+        hasher.update((blob.data.len() as u64).to_be_bytes());
+        hasher.update(&blob.data);
+    }
+    BlobTxHash::from_raw(hasher.finalize().into())
+}
+
+fn parse_height(path: &Path, height: &str) -> Result<u64, TrySubmitError> {
+    height
+        .trim()
+        .parse()
+        .map_err(|_| TrySubmitError::LocalDaInvalidHeight {
+            path: path.to_path_buf(),
+            height: height.to_string(),
+        })
+}
+
+/// Writes `contents` to a temporary file next to `path` and renames it to `path`, so that readers
+/// never observe a partially written file.
+async fn write_atomically(path: &Path, contents: &[u8]) -> io::Result<()> {
+    let temp_path = path.with_extension("tmp");
+    tokio::fs::write(&temp_path, contents).await?;
+    tokio::fs::rename(&temp_path, path).await
+}
+
+#[cfg(test)]
+mod tests {
+    use astria_core::celestia::Namespace;
+
+    use super::*;
+
+    const CHAIN_ID: &str = "local-da";
+
+    fn blobs(data: &[&[u8]]) -> Arc<Vec<Blob>> {
+        Arc::new(
+            data.iter()
+                .map(|data| {
+                    Blob::new(
+                        Namespace::const_v0([1; 10]),
+                        data.to_vec(),
+                        celestia_types::AppVersion::V3,
+                    )
+                    .unwrap()
+                })
+                .collect(),
+        )
+    }
+
+    async fn client(root: &Path) -> LocalDaClient {
+        LocalDaClientBuilder::new(
+            CHAIN_ID.to_string(),
+            root.to_path_buf(),
+            Arc::new(State::new()),
+        )
+        .try_build()
+        .await
+        .unwrap()
+    }
+
+    async fn submit(client: &mut LocalDaClient, blobs: Arc<Vec<Blob>>) -> (BlobTxHash, u64) {
+        let blob_tx = client.try_prepare(blobs, None, 0).await.unwrap().tx;
+        let hash = LocalDaClient::blob_tx_hash(&blob_tx);
+        client.try_broadcast(hash, blob_tx).await.unwrap();
+        let height = client
+            .confirm_submission_with_timeout(&[hash], Duration::ZERO)
+            .await
+            .unwrap();
+        (hash, height)
+    }
+
+    #[tokio::test]
+    async fn should_reject_mismatched_chain_id() {
+        let root = tempfile::tempdir().unwrap();
+        let _ = client(root.path()).await;
+
+        let error = LocalDaClientBuilder::new(
+            "other-chain".to_string(),
+            root.path().to_path_buf(),
+            Arc::new(State::new()),
+        )
+        .try_build()
+        .await
+        .unwrap_err();
+        assert!(
+            matches!(error, BuilderError::MismatchedCelestiaChainId { .. }),
+            "{error:?}"
+        );
+    }
+
+    #[tokio::test]
+    async fn should_store_submissions_at_increasing_heights() {
+        let root = tempfile::tempdir().unwrap();
+        let mut client = client(root.path()).await;
+
+        let (first_hash, first_height) = submit(&mut client, blobs(&[b"a", b"b"])).await;
+        let (second_hash, second_height) = submit(&mut client, blobs(&[b"c"])).await;
+        assert_eq!(first_height, 1);
+        assert_eq!(second_height, 2);
+
+        let dir = local::blobs_dir(root.path(), &Namespace::const_v0([1; 10]), 1);
+        assert_eq!(std::fs::read(dir.join("0")).unwrap(), b"a");
+        assert_eq!(std::fs::read(dir.join("1")).unwrap(), b"b");
+        assert_eq!(
+            client
+                .confirm_submission_with_timeout(&[second_hash], Duration::ZERO)
+                .await,
+            Some(2)
+        );
+        assert_eq!(
+            client
+                .confirm_submission_with_timeout(&[first_hash], Duration::ZERO)
+                .await,
+            Some(1)
+        );
+    }
+
+    #[tokio::test]
+    async fn should_remove_blobs_of_unrecorded_attempts() {
+        let root = tempfile::tempdir().unwrap();
+        let mut client = client(root.path()).await;
+
+        let dir = local::blobs_dir(root.path(), &Namespace::const_v0([1; 10]), 1);
+        let other_dir = local::blobs_dir(root.path(), &Namespace::const_v0([2; 10]), 1);
+        for dir in [&dir, &other_dir] {
+            std::fs::create_dir_all(dir).unwrap();
+            std::fs::write(dir.join("0"), b"stale").unwrap();
+            std::fs::write(dir.join("1"), b"stale").unwrap();
+        }
+
+        let (_, height) = submit(&mut client, blobs(&[b"a"])).await;
+        assert_eq!(height, 1);
+        assert_eq!(std::fs::read(dir.join("0")).unwrap(), b"a");
+        assert!(!dir.join("1").exists());
+        assert!(!other_dir.exists());
+    }
+
+    #[tokio::test]
+    async fn should_not_store_same_submission_twice() {
+        let root = tempfile::tempdir().unwrap();
+        let mut client = client(root.path()).await;
+
+        let (first_hash, first_height) = submit(&mut client, blobs(&[b"a"])).await;
+        let (second_hash, second_height) = submit(&mut client, blobs(&[b"a"])).await;
+        assert_eq!(first_hash, second_hash);
+        assert_eq!(first_height, second_height);
+        assert_eq!(client.latest_height().await.unwrap(), 1);
+    }
+}
@@ -57,6 +57,8 @@ use tracing::{
 
 mod builder;
 mod celestia_client;
+mod data_availability;
+mod local_da_client;
 mod read;
 mod state;
 mod submission;
@@ -68,8 +70,11 @@ use celestia_client::{
     BuilderError,
     CelestiaClientBuilder,
     CelestiaKeys,
+    FeeEscalation,
     TrySubmitError,
 };
+use data_availability::AnyDaClientBuilder;
+use local_da_client::LocalDaClientBuilder;
 use state::State;
 pub(crate) use state::StateSnapshot;
 use submission::{
@@ -83,6 +88,9 @@ use crate::{
     IncludeRollup,
 };
 
+/// The expected time between two Celestia blocks.
+const CELESTIA_BLOCK_TIME: Duration = Duration::from_secs(12);
+
 type ForwardFut<'a> =
     Fuse<BoxFuture<'a, Result<(), tokio::sync::mpsc::error::SendError<Box<SequencerBlock>>>>>;
 
@@ -110,8 +118,12 @@ pub(crate) struct Relayer {
     /// The poll period defines the fixed interval at which the sequencer is polled.
     sequencer_poll_period: Duration,
 
-    /// The gRPC client for submitting sequencer blocks to celestia.
-    celestia_client_builder: CelestiaClientBuilder,
+    /// The builder of the client for submitting sequencer blocks to the data availability layer.
+    da_client_builder: AnyDaClientBuilder,
+
+    /// The duration after which a blob transaction that has not been included by Celestia is
+    /// resubmitted with an escalated fee.
+    celestia_resubmit_after: Duration,
 
     /// The rollups whose data should be included in submissions.
     rollup_filter: IncludeRollup,
@@ -157,7 +169,8 @@ impl Relayer {
         };
 
         let (mut submitter_task, submitter) = spawn_submitter(
-            self.celestia_client_builder.clone(),
+            self.da_client_builder.clone(),
+            self.celestia_resubmit_after,
             self.rollup_filter.clone(),
             self.state.clone(),
             submission_state_at_startup,
@@ -372,7 +385,8 @@ async fn fetch_sequencer_chain_id(
 }
 
 fn spawn_submitter(
-    client_builder: CelestiaClientBuilder,
+    client_builder: AnyDaClientBuilder,
+    resubmit_after: Duration,
     rollup_filter: IncludeRollup,
     state: Arc<State>,
     submission_state_at_startup: SubmissionStateAtStartup,
@@ -382,15 +396,24 @@ fn spawn_submitter(
     Fuse<JoinHandle<eyre::Result<()>>>,
     write::BlobSubmitterHandle,
 ) {
-    let (submitter, handle) = write::BlobSubmitter::new(
-        client_builder,
-        rollup_filter,
-        state,
-        submission_state_at_startup,
-        submitter_shutdown_token,
-        metrics,
-    );
-    (tokio::spawn(submitter.run()).fuse(), handle)
+    macro_rules! spawn {
+        ($client_builder:expr) => {{
+            let (submitter, handle) = write::BlobSubmitter::new(
+                $client_builder,
+                resubmit_after,
+                rollup_filter,
+                state,
+                submission_state_at_startup,
+                submitter_shutdown_token,
+                metrics,
+            );
+            (tokio::spawn(submitter.run()).fuse(), handle)
+        }};
+    }
+    match client_builder {
+        AnyDaClientBuilder::Celestia(client_builder) => spawn!(client_builder),
+        AnyDaClientBuilder::Local(client_builder) => spawn!(client_builder),
+    }
 }
 
 #[instrument(skip_all)]
@@ -71,6 +71,10 @@ enum State {
     /// blob transaction.
     Started {
         last_submission: CompletedSubmission,
+        /// The number of times the gas price was escalated for a previous submission which was
+        /// not included by Celestia and was given up on when the relayer restarted.
+        #[serde(default, skip_serializing_if = "is_zero")]
+        fee_escalations: u32,
     },
     /// Indicates that preparation of a signed blob transaction has happened, and we are now in the
     /// process of submitting the transaction (sending a `broadcast_tx` gRPC) and confirming its
@@ -82,13 +86,29 @@ enum State {
         blob_tx_hash: BlobTxHash,
         #[serde(with = "as_rfc_3339")]
         at: SystemTime,
+        /// The hashes of earlier attempts of this submission which were broadcast but not
+        /// included by Celestia in time. Any of them might still be included.
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        superseded_blob_tx_hashes: Vec<BlobTxHash>,
+        /// The number of times the gas price was escalated for this submission.
+        #[serde(default, skip_serializing_if = "is_zero")]
+        fee_escalations: u32,
     },
 }
 
+#[expect(
+    clippy::trivially_copy_pass_by_ref,
+    reason = "the function signature is dictated by the serde(skip_serializing_if) attribute"
+)]
+fn is_zero(value: &u32) -> bool {
+    *value == 0
+}
+
 impl State {
-    fn new_started(last_submission: CompletedSubmission) -> Self {
+    fn new_started(last_submission: CompletedSubmission, fee_escalations: u32) -> Self {
         Self::Started {
             last_submission,
+            fee_escalations,
         }
     }
 
@@ -97,12 +117,16 @@ impl State {
         last_submission: CompletedSubmission,
         blob_tx_hash: BlobTxHash,
         at: SystemTime,
+        superseded_blob_tx_hashes: Vec<BlobTxHash>,
+        fee_escalations: u32,
     ) -> Self {
         Self::Prepared {
             sequencer_height,
             last_submission,
             blob_tx_hash,
             at,
+            superseded_blob_tx_hashes,
+            fee_escalations,
         }
     }
 
@@ -199,6 +223,7 @@ impl FreshSubmission {
         let last_submission = CompletedSubmission::new(0, SequencerHeight::from(0_u8));
         StartedSubmission {
             last_submission,
+            fee_escalations: 0,
             state_file_path: self.state_file_path,
             temp_file_path: self.temp_file_path,
         }
@@ -209,6 +234,7 @@ impl FreshSubmission {
 #[derive(Clone, Debug)]
 pub(super) struct StartedSubmission {
     last_submission: CompletedSubmission,
+    fee_escalations: u32,
     state_file_path: StateFilePath,
     temp_file_path: TempFilePath,
 }
@@ -218,10 +244,11 @@ impl StartedSubmission {
     #[instrument(skip_all, err)]
     async fn construct_and_write(
         last_submission: CompletedSubmission,
+        fee_escalations: u32,
         state_file_path: StateFilePath,
         temp_file_path: TempFilePath,
     ) -> eyre::Result<Self> {
-        let state = State::new_started(last_submission);
+        let state = State::new_started(last_submission, fee_escalations);
         debug!(%state, "writing submission started state to file");
         state
             .write(&state_file_path, &temp_file_path)
@@ -229,11 +256,20 @@ impl StartedSubmission {
             .wrap_err("failed commiting submission started state to disk")?;
         Ok(Self {
             last_submission,
+            fee_escalations,
             state_file_path,
             temp_file_path,
         })
     }
 
+    /// Returns the number of gas price escalations the next submission should start at.
+    ///
+    /// This is non-zero only if the previous session gave up on a submission which was not
+    /// included by Celestia in time.
+    pub(super) fn fee_escalations(&self) -> u32 {
+        self.fee_escalations
+    }
+
     /// Returns the celestia block height from the last completed submission.
     pub(super) fn last_submission_celestia_height(&self) -> u64 {
         self.last_submission.celestia_height
@@ -251,13 +287,22 @@ impl StartedSubmission {
         new_sequencer_height: SequencerHeight,
         blob_tx_hash: BlobTxHash,
     ) -> eyre::Result<PreparedSubmission> {
-        PreparedSubmission::construct_and_write(
-            new_sequencer_height,
-            self.last_submission,
+        ensure!(
+            new_sequencer_height > self.last_submission.sequencer_height,
+            "cannot submit a sequencer block at height below or equal to what was already \
+             successfully submitted"
+        );
+        PreparedSubmission {
+            sequencer_height: new_sequencer_height,
+            last_submission: self.last_submission,
             blob_tx_hash,
-            self.state_file_path,
-            self.temp_file_path,
-        )
+            superseded_blob_tx_hashes: Vec::new(),
+            fee_escalations: self.fee_escalations,
+            created_at: SystemTime::now(),
+            state_file_path: self.state_file_path,
+            temp_file_path: self.temp_file_path,
+        }
+        .write()
         .await
     }
 }
@@ -268,7 +313,7 @@ impl Display for StartedSubmission {
             formatter,
             "file: {}, {}",
             self.state_file_path.0.display(),
-            State::new_started(self.last_submission)
+            State::new_started(self.last_submission, self.fee_escalations)
         )
     }
 }
@@ -280,41 +325,33 @@ pub(super) struct PreparedSubmission {
     sequencer_height: SequencerHeight,
     last_submission: CompletedSubmission,
     blob_tx_hash: BlobTxHash,
+    superseded_blob_tx_hashes: Vec<BlobTxHash>,
+    fee_escalations: u32,
     created_at: SystemTime,
     state_file_path: StateFilePath,
     temp_file_path: TempFilePath,
 }
 
 impl PreparedSubmission {
-    /// Constructs a new `PreparedSubmission` and writes the state to disk.
+    /// Writes the state represented by `self` to disk.
     #[instrument(skip_all, err)]
-    async fn construct_and_write(
-        sequencer_height: SequencerHeight,
-        last_submission: CompletedSubmission,
-        blob_tx_hash: BlobTxHash,
-        state_file_path: StateFilePath,
-        temp_file_path: TempFilePath,
-    ) -> eyre::Result<Self> {
-        ensure!(
-            sequencer_height > last_submission.sequencer_height,
-            "cannot submit a sequencer block at height below or equal to what was already \
-             successfully submitted"
-        );
-        let created_at = SystemTime::now();
-        let state =
-            State::new_prepared(sequencer_height, last_submission, blob_tx_hash, created_at);
-        state
-            .write(&state_file_path, &temp_file_path)
+    async fn write(self) -> eyre::Result<Self> {
+        self.to_state()
+            .write(&self.state_file_path, &self.temp_file_path)
             .await
             .wrap_err("failed commiting submission prepared state to disk")?;
-        Ok(Self {
-            sequencer_height,
-            last_submission,
-            blob_tx_hash,
-            created_at,
-            state_file_path,
-            temp_file_path,
-        })
+        Ok(self)
+    }
+
+    fn to_state(&self) -> State {
+        State::new_prepared(
+            self.sequencer_height,
+            self.last_submission,
+            self.blob_tx_hash,
+            self.created_at,
+            self.superseded_blob_tx_hashes.clone(),
+            self.fee_escalations,
+        )
     }
 
     /// Returns the transaction hash of the prepared `BlobTx`.
@@ -322,6 +359,41 @@ impl PreparedSubmission {
         &self.blob_tx_hash
     }
 
+    /// Returns the transaction hashes of all attempts of this submission, the latest first.
+    ///
+    /// All of them must be checked when confirming the submission, as an attempt which was
+    /// superseded due to not being included in time might still have been included later.
+    pub(super) fn blob_tx_hashes(&self) -> Vec<BlobTxHash> {
+        std::iter::once(self.blob_tx_hash)
+            .chain(self.superseded_blob_tx_hashes.iter().rev().copied())
+            .collect()
+    }
+
+    /// Returns the transaction hashes of earlier attempts of this submission.
+    pub(super) fn superseded_blob_tx_hashes(&self) -> &[BlobTxHash] {
+        &self.superseded_blob_tx_hashes
+    }
+
+    /// Returns the number of times the gas price was escalated for this submission.
+    pub(super) fn fee_escalations(&self) -> u32 {
+        self.fee_escalations
+    }
+
+    /// Records a resubmission of the same data with the gas price escalated `fee_escalations`
+    /// times, superseding the current attempt. Writes the new state to disk.
+    #[instrument(skip_all, err)]
+    pub(super) async fn into_resubmitted(
+        mut self,
+        blob_tx_hash: BlobTxHash,
+        fee_escalations: u32,
+    ) -> eyre::Result<PreparedSubmission> {
+        let superseded = std::mem::replace(&mut self.blob_tx_hash, blob_tx_hash);
+        self.superseded_blob_tx_hashes.push(superseded);
+        self.fee_escalations = fee_escalations;
+        self.created_at = SystemTime::now();
+        self.write().await
+    }
+
     /// Returns the maximum duration for which the Celestia app should be polled with `GetTx`
     /// requests to confirm successful storage of the associated `BlobTx`.
     ///
@@ -344,6 +416,7 @@ impl PreparedSubmission {
         let last_submission = CompletedSubmission::new(celestia_height, self.sequencer_height);
         StartedSubmission::construct_and_write(
             last_submission,
+            0,
             self.state_file_path,
             self.temp_file_path,
         )
@@ -351,11 +424,13 @@ impl PreparedSubmission {
     }
 
     /// Reverts `self` into a `StartedSubmission` retaining the last submission from `self` as the
-    /// last submission. Writes the new state to disk.
+    /// last submission. The gas price escalation of `self` is retained so that the next
+    /// submission continues from it. Writes the new state to disk.
     #[instrument(skip_all, err)]
     pub(super) async fn revert(self) -> eyre::Result<StartedSubmission> {
         StartedSubmission::construct_and_write(
             self.last_submission,
+            self.fee_escalations,
             self.state_file_path,
             self.temp_file_path,
         )
@@ -369,12 +444,7 @@ impl Display for PreparedSubmission {
             formatter,
             "file: {}, {}",
             self.state_file_path.0.display(),
-            State::new_prepared(
-                self.sequencer_height,
-                self.last_submission,
-                self.blob_tx_hash,
-                self.created_at
-            )
+            self.to_state()
         )
     }
 }
@@ -418,8 +488,10 @@ impl SubmissionStateAtStartup {
             })),
             State::Started {
                 last_submission,
+                fee_escalations,
             } => Ok(Self::Started(StartedSubmission {
                 last_submission,
+                fee_escalations,
                 state_file_path,
                 temp_file_path,
             })),
@@ -428,10 +500,14 @@ impl SubmissionStateAtStartup {
                 last_submission,
                 blob_tx_hash,
                 at,
+                superseded_blob_tx_hashes,
+                fee_escalations,
             } => Ok(Self::Prepared(PreparedSubmission {
                 sequencer_height,
                 last_submission,
                 blob_tx_hash,
+                superseded_blob_tx_hashes,
+                fee_escalations,
                 created_at: at,
                 state_file_path,
                 temp_file_path,
@@ -534,6 +610,9 @@ mod tests {
     const BLOB_TX_HASH_STR: &str =
         "0909090909090909090909090909090909090909090909090909090909090909";
     const BLOB_TX_HASH: BlobTxHash = BlobTxHash::from_raw([9; 32]);
+    const OTHER_BLOB_TX_HASH_STR: &str =
+        "0808080808080808080808080808080808080808080808080808080808080808";
+    const OTHER_BLOB_TX_HASH: BlobTxHash = BlobTxHash::from_raw([8; 32]);
     const AT_STR: &str = "2024-06-24T22:22:22.222222222Z";
     const AT_DURATION_SINCE_EPOCH: Duration = Duration::from_nanos(1_719_267_742_222_222_222);
 
@@ -592,7 +671,9 @@ mod tests {
         match parsed {
             State::Started {
                 last_submission,
+                fee_escalations,
             } => {
+                assert_eq!(fee_escalations, 0);
                 let expected_submission = CompletedSubmission::new(
                     CELESTIA_HEIGHT,
                     SequencerHeight::from(SEQUENCER_HEIGHT_LOW),
@@ -615,6 +696,8 @@ mod tests {
                 last_submission,
                 blob_tx_hash,
                 at,
+                superseded_blob_tx_hashes,
+                fee_escalations,
             } => {
                 assert_eq!(
                     sequencer_height,
@@ -627,6 +710,40 @@ mod tests {
                 assert_eq!(last_submission, expected_submission);
                 assert_eq!(blob_tx_hash, BLOB_TX_HASH);
                 assert_eq!(at, SystemTime::UNIX_EPOCH + AT_DURATION_SINCE_EPOCH);
+                assert!(superseded_blob_tx_hashes.is_empty());
+                assert_eq!(fee_escalations, 0);
+            }
+            _ => panic!("expected prepared state, got:\n{parsed:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn should_read_resubmitted_prepared_state() {
+        let file = write(&json!({
+            "state": "prepared",
+            "sequencer_height": SEQUENCER_HEIGHT_HIGH,
+            "last_submission": {
+                "celestia_height": CELESTIA_HEIGHT,
+                "sequencer_height": SEQUENCER_HEIGHT_LOW
+            },
+            "blob_tx_hash": BLOB_TX_HASH_STR,
+            "at": AT_STR,
+            "superseded_blob_tx_hashes": [OTHER_BLOB_TX_HASH_STR],
+            "fee_escalations": 1
+        }));
+        let parsed = State::read(&StateFilePath(file.path().to_path_buf()))
+            .await
+            .unwrap();
+        match parsed {
+            State::Prepared {
+                blob_tx_hash,
+                superseded_blob_tx_hashes,
+                fee_escalations,
+                ..
+            } => {
+                assert_eq!(blob_tx_hash, BLOB_TX_HASH);
+                assert_eq!(superseded_blob_tx_hashes, vec![OTHER_BLOB_TX_HASH]);
+                assert_eq!(fee_escalations, 1);
             }
             _ => panic!("expected prepared state, got:\n{parsed:?}"),
         }
@@ -695,7 +812,8 @@ mod tests {
     async fn should_write_started_state() {
         let last_submission =
             CompletedSubmission::new(CELESTIA_HEIGHT, SequencerHeight::from(SEQUENCER_HEIGHT_LOW));
-        should_write_state(State::new_started(last_submission)).await;
+        should_write_state(State::new_started(last_submission, 0)).await;
+        should_write_state(State::new_started(last_submission, 2)).await;
     }
 
     #[tokio::test]
@@ -704,7 +822,23 @@ mod tests {
         let last_submission =
             CompletedSubmission::new(CELESTIA_HEIGHT, SequencerHeight::from(SEQUENCER_HEIGHT_LOW));
         let at = SystemTime::UNIX_EPOCH + AT_DURATION_SINCE_EPOCH;
-        let state = State::new_prepared(sequencer_height, last_submission, BLOB_TX_HASH, at);
+        let state = State::new_prepared(
+            sequencer_height,
+            last_submission,
+            BLOB_TX_HASH,
+            at,
+            vec![],
+            0,
+        );
+        should_write_state(state).await;
+        let state = State::new_prepared(
+            sequencer_height,
+            last_submission,
+            BLOB_TX_HASH,
+            at,
+            vec![OTHER_BLOB_TX_HASH],
+            1,
+        );
         should_write_state(state).await;
     }
 
@@ -717,6 +851,7 @@ mod tests {
         let temp_file = TempFilePath(tempdir.path().join("state.json.tmp"));
         let started_submission = StartedSubmission {
             last_submission,
+            fee_escalations: 0,
             state_file_path: destination.clone(),
             temp_file_path: temp_file.clone(),
         };
@@ -752,6 +887,7 @@ mod tests {
         let temp_file = TempFilePath(tempdir.path().join("state.json.tmp"));
         let started_submission = StartedSubmission {
             last_submission,
+            fee_escalations: 0,
             state_file_path: destination.clone(),
             temp_file_path: temp_file.clone(),
         };
@@ -785,6 +921,8 @@ mod tests {
             sequencer_height,
             last_submission,
             blob_tx_hash: BLOB_TX_HASH,
+            superseded_blob_tx_hashes: vec![],
+            fee_escalations: 0,
             created_at,
             state_file_path: destination.clone(),
             temp_file_path: temp_file.clone(),
@@ -825,6 +963,8 @@ mod tests {
             sequencer_height,
             last_submission,
             blob_tx_hash: BLOB_TX_HASH,
+            superseded_blob_tx_hashes: vec![],
+            fee_escalations: 0,
             created_at,
             state_file_path: destination.clone(),
             temp_file_path: temp_file.clone(),
@@ -846,6 +986,59 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn prepared_submission_should_record_resubmission() {
+        let sequencer_height = SequencerHeight::from(SEQUENCER_HEIGHT_HIGH);
+        let last_submission =
+            CompletedSubmission::new(CELESTIA_HEIGHT, SequencerHeight::from(SEQUENCER_HEIGHT_LOW));
+        let tempdir = tempfile::tempdir().unwrap();
+        let destination = StateFilePath(tempdir.path().join("state.json"));
+        let temp_file = TempFilePath(tempdir.path().join("state.json.tmp"));
+        let prepared_submission = PreparedSubmission {
+            sequencer_height,
+            last_submission,
+            blob_tx_hash: OTHER_BLOB_TX_HASH,
+            superseded_blob_tx_hashes: vec![],
+            fee_escalations: 0,
+            created_at: SystemTime::UNIX_EPOCH,
+            state_file_path: destination.clone(),
+            temp_file_path: temp_file.clone(),
+        };
+
+        // Resubmit - should supersede the first attempt and escalate.
+        let resubmitted = prepared_submission
+            .into_resubmitted(BLOB_TX_HASH, 1)
+            .await
+            .unwrap();
+        assert_eq!(resubmitted.sequencer_height, sequencer_height);
+        assert_eq!(resubmitted.last_submission, last_submission);
+        assert_eq!(
+            resubmitted.blob_tx_hashes(),
+            vec![BLOB_TX_HASH, OTHER_BLOB_TX_HASH]
+        );
+        assert_eq!(
+            resubmitted.superseded_blob_tx_hashes(),
+            &[OTHER_BLOB_TX_HASH]
+        );
+        assert_eq!(resubmitted.fee_escalations(), 1);
+        assert!(resubmitted.created_at > SystemTime::UNIX_EPOCH);
+
+        // Ensure the new state was written to disk.
+        let parsed_state = State::read(&destination).await.unwrap();
+        assert_eq!(parsed_state, resubmitted.to_state());
+
+        // Reverting should retain the escalation, completing should reset it.
+        let reverted = resubmitted.revert().await.unwrap();
+        assert_eq!(reverted.fee_escalations(), 1);
+        let prepared = reverted
+            .into_prepared(sequencer_height, BLOB_TX_HASH)
+            .await
+            .unwrap();
+        assert_eq!(prepared.fee_escalations(), 1);
+        let started = prepared.into_started(CELESTIA_HEIGHT + 1).await.unwrap();
+        assert_eq!(started.fee_escalations(), 0);
+    }
+
     #[test]
     fn confirmation_timeout_should_respect_limits() {
         let last_submission =
@@ -854,6 +1047,8 @@ mod tests {
             sequencer_height: SequencerHeight::from(SEQUENCER_HEIGHT_HIGH),
             last_submission,
             blob_tx_hash: BLOB_TX_HASH,
+            superseded_blob_tx_hashes: vec![],
+            fee_escalations: 0,
             created_at: SystemTime::UNIX_EPOCH,
             state_file_path: StateFilePath(PathBuf::new()),
             temp_file_path: TempFilePath(PathBuf::new()),
@@ -60,10 +60,11 @@ use tracing::{
 };
 
 use super::{
-    celestia_client::CelestiaClient,
-    BlobTxHash,
+    data_availability::{
+        DaClient,
+        DaClientBuilder,
+    },
     BuilderError,
-    CelestiaClientBuilder,
     PreparedSubmission,
     StartedSubmission,
     SubmissionStateAtStartup,
@@ -111,9 +112,9 @@ impl BlobSubmitterHandle {
     }
 }
 
-pub(super) struct BlobSubmitter {
-    /// The builder for a client to submit blobs to Celestia.
-    client_builder: CelestiaClientBuilder,
+pub(super) struct BlobSubmitter<B> {
+    /// The builder for a client to submit blobs to the data availability layer.
+    client_builder: B,
 
     /// The channel over which sequencer blocks are received.
     blocks: mpsc::Receiver<Box<SequencerBlock>>,
@@ -135,12 +136,17 @@ pub(super) struct BlobSubmitter {
     /// hardcoded limit.
     pending_block: Option<SequencerBlock>,
 
+    /// The duration after which a blob transaction that has not been included by Celestia is
+    /// resubmitted with an escalated fee.
+    resubmit_after: Duration,
+
     metrics: &'static Metrics,
 }
 
-impl BlobSubmitter {
+impl<B: DaClientBuilder> BlobSubmitter<B> {
     pub(super) fn new(
-        client_builder: CelestiaClientBuilder,
+        client_builder: B,
+        resubmit_after: Duration,
         rollup_filter: IncludeRollup,
         state: Arc<super::State>,
         submission_state_at_startup: SubmissionStateAtStartup,
@@ -158,6 +164,7 @@ impl BlobSubmitter {
             submission_state_at_startup: Some(submission_state_at_startup),
             submitter_shutdown_token,
             pending_block: None,
+            resubmit_after,
             metrics,
         };
         let handle = BlobSubmitterHandle {
@@ -235,6 +242,7 @@ impl BlobSubmitter {
                         submission,
                         self.state.clone(),
                         started_submission.clone(),
+                        self.resubmit_after,
                         self.metrics,
                     ).boxed().fuse();
                     if let Some(block) = self.pending_block.take() {
@@ -303,23 +311,28 @@ impl BlobSubmitter {
 /// This should only be called where submission state on startup is `Prepared`, meaning we don't yet
 /// know whether that final submission attempt succeeded or not.
 ///
-/// Internally, this polls `GetTx` for up to one minute.  The returned `SubmissionState` is
-/// guaranteed to be in `Started` state, either holding the heights of the previously prepared
-/// submission if confirmed by Celestia, or holding the heights of the last known confirmed
-/// submission in the case of timing out.
+/// Internally, this polls `GetTx` for all attempts of the submission for up to one minute.  The
+/// returned `SubmissionState` is guaranteed to be in `Started` state, either holding the heights of
+/// the previously prepared submission if confirmed by Celestia, or holding the heights of the last
+/// known confirmed submission in the case of timing out.  In the latter case, the number of fee
+/// escalations of the unconfirmed submission is retained so the next submission continues from it.
 #[instrument(skip_all, err)]
-async fn try_confirm_submission_from_last_session(
-    mut client: CelestiaClient,
+async fn try_confirm_submission_from_last_session<C: DaClient>(
+    mut client: C,
     prepared_submission: PreparedSubmission,
     state: Arc<super::State>,
     metrics: &'static Metrics,
 ) -> eyre::Result<StartedSubmission> {
     let blob_tx_hash = prepared_submission.blob_tx_hash();
-    info!(%blob_tx_hash, "confirming submission of last `BlobTx` from previous session");
+    info!(
+        %blob_tx_hash,
+        superseded_attempts = prepared_submission.superseded_blob_tx_hashes().len(),
+        "confirming submission of last `BlobTx` from previous session"
+    );
 
     let timeout = prepared_submission.confirmation_timeout();
     let new_state = if let Some(celestia_height) = client
-        .confirm_submission_with_timeout(blob_tx_hash, timeout)
+        .confirm_submission_with_timeout(&prepared_submission.blob_tx_hashes(), timeout)
         .await
     {
         info!(%celestia_height, "confirmed previous session submitted blobs to Celestia");
@@ -349,11 +362,12 @@ async fn try_confirm_submission_from_last_session(
 
 /// Submits new blobs Celestia.
 #[instrument(skip_all, err)]
-async fn submit_blobs(
-    client: CelestiaClient,
+async fn submit_blobs<C: DaClient>(
+    client: C,
     data: conversion::Submission,
     state: Arc<super::State>,
     started_submission: StartedSubmission,
+    resubmit_after: Duration,
     metrics: &'static Metrics,
 ) -> eyre::Result<StartedSubmission> {
     let total_data_uncompressed_size = data.uncompressed_size();
@@ -386,6 +400,7 @@ async fn submit_blobs(
         state.clone(),
         started_submission,
         largest_sequencer_height,
+        resubmit_after,
         metrics,
     )
     .await
@@ -416,7 +431,7 @@ async fn submit_blobs(
 }
 
 #[instrument(skip_all, err)]
-async fn init_with_retry(client_builder: CelestiaClientBuilder) -> eyre::Result<CelestiaClient> {
+async fn init_with_retry<B: DaClientBuilder>(client_builder: B) -> eyre::Result<B::Client> {
     let span = Span::current();
 
     let initial_retry_delay = Duration::from_secs(1);
@@ -466,15 +481,43 @@ enum SubmissionError {
     Unrecoverable(#[source] Arc<Report>),
     #[error("broadcast tx timed out")]
     BroadcastTxTimedOut(PreparedSubmission),
+    #[error("blob tx was not included by celestia in time")]
+    NotIncluded(PreparedSubmission),
+    #[error("failed to resubmit blob tx which was not included by celestia in time")]
+    Resubmission {
+        prepared_submission: PreparedSubmission,
+        source: TrySubmitError,
+    },
+    #[error(
+        "resubmitted blob tx was rejected as the blob tx it supersedes is still pending in the \
+         celestia app's mempool"
+    )]
+    SupersededPending(PreparedSubmission),
+}
+
+impl SubmissionError {
+    /// Returns whether the error means that an earlier blob tx is still pending in the Celestia
+    /// app's mempool, rather than that submitting failed.
+    ///
+    /// The Celestia app rejects a new blob tx with a sequence mismatch until the earlier one is
+    /// either included or evicted from its mempool.
+    fn is_waiting_for_pending_blob_tx(&self) -> bool {
+        match self {
+            Self::SupersededPending(_) => true,
+            Self::TrySubmit(error) => error.is_sequence_mismatch(),
+            _ => false,
+        }
+    }
 }
 
 #[instrument(skip_all)]
-async fn submit_with_retry(
-    client: CelestiaClient,
+async fn submit_with_retry<C: DaClient>(
+    client: C,
     blobs: Vec<Blob>,
     state: Arc<super::State>,
     started_submission: StartedSubmission,
     largest_sequencer_height: SequencerHeight,
+    resubmit_after: Duration,
     metrics: &'static Metrics,
 ) -> eyre::Result<StartedSubmissionAndFee> {
     // Moving the span into `on_retry`, because tryhard spawns these in a tokio
@@ -501,16 +544,31 @@ async fn submit_with_retry(
         })
         .on_retry(
             |attempt: u32, next_delay: Option<Duration>, error: &SubmissionError| {
-                metrics.increment_celestia_submission_failure_count();
-
-                let state = Arc::clone(&state);
-                state.set_celestia_connected(false);
                 let _ = last_error_sender.send(Some(error.clone()));
 
                 let wait_duration = next_delay
                     .map(telemetry::display::format_duration)
                     .map(tracing::field::display);
 
+                // Neither a blob tx that was broadcast but not included in time, nor a blob tx
+                // that is still pending in the mempool indicate that Celestia is unreachable.
+                if error.is_waiting_for_pending_blob_tx() {
+                    info!(
+                        parent: &span,
+                        attempt,
+                        wait_duration,
+                        error = %eyre::Report::new(error.clone()),
+                        "blob tx still pending in celestia app's mempool; retrying after backoff",
+                    );
+                    return futures::future::ready(());
+                }
+                if matches!(error, SubmissionError::NotIncluded(_)) {
+                    metrics.increment_celestia_submission_resubmission_count();
+                } else {
+                    metrics.increment_celestia_submission_failure_count();
+                    state.set_celestia_connected(false);
+                }
+
                 warn!(
                     parent: &span,
                     attempt,
@@ -530,6 +588,7 @@ async fn submit_with_retry(
             blobs.clone(),
             started_submission.clone(),
             largest_sequencer_height,
+            resubmit_after,
             last_error_receiver.clone(),
         )
     })
@@ -541,47 +600,153 @@ async fn submit_with_retry(
 }
 
 #[instrument(skip_all, err(level = Level::WARN))]
-async fn try_submit(
-    mut client: CelestiaClient,
+async fn try_submit<C: DaClient>(
+    mut client: C,
     blobs: Arc<Vec<Blob>>,
     started_submission: StartedSubmission,
     largest_sequencer_height: SequencerHeight,
+    resubmit_after: Duration,
     last_error_receiver: watch::Receiver<Option<SubmissionError>>,
 ) -> Result<StartedSubmissionAndFee, SubmissionError> {
     // Get the error from the last attempt to `try_submit`.
     let maybe_last_error = last_error_receiver.borrow().clone();
-    let maybe_try_submit_error = match maybe_last_error {
-        // If error is broadcast timeout, try to confirm submission from last attempt.
+    // If the last attempt left a prepared submission whose fate is unknown, it is superseded by
+    // this attempt, which is made with the given number of fee escalations. A failed resubmission
+    // was never recorded, so it is retried with the same escalation.
+    //
+    // Before superseding it, the prepared submission is confirmed for the given duration, if any.
+    // A submission which was not included was just polled for `resubmit_after`, so it is
+    // resubmitted right away. A submission which is still pending in the Celestia app's mempool
+    // is polled for another `resubmit_after`.
+    let (maybe_try_submit_error, maybe_superseded) = match maybe_last_error {
         Some(SubmissionError::BroadcastTxTimedOut(prepared_submission)) => {
-            if let Some(new_state) =
-                try_confirm_submission_from_failed_attempt(client.clone(), prepared_submission)
-                    .await?
-            {
-                return Ok(StartedSubmissionAndFee {
-                    new_state,
-                    fee: None,
-                });
-            }
-            None
+            let fee_escalations = prepared_submission.fee_escalations();
+            let timeout = prepared_submission.confirmation_timeout();
+            (
+                None,
+                Some((prepared_submission, fee_escalations, Some(timeout))),
+            )
+        }
+        Some(SubmissionError::NotIncluded(prepared_submission)) => {
+            let fee_escalations = prepared_submission.fee_escalations().saturating_add(1);
+            (None, Some((prepared_submission, fee_escalations, None)))
         }
-        Some(SubmissionError::TrySubmit(error)) => Some(error),
+        Some(SubmissionError::SupersededPending(prepared_submission)) => {
+            let fee_escalations = prepared_submission.fee_escalations().saturating_add(1);
+            (
+                None,
+                Some((prepared_submission, fee_escalations, Some(resubmit_after))),
+            )
+        }
+        Some(SubmissionError::Resubmission {
+            prepared_submission,
+            source,
+        }) => {
+            let fee_escalations = prepared_submission.fee_escalations().saturating_add(1);
+            let timeout = prepared_submission.confirmation_timeout();
+            (
+                Some(source),
+                Some((prepared_submission, fee_escalations, Some(timeout))),
+            )
+        }
+        Some(SubmissionError::TrySubmit(error)) => (Some(error), None),
         Some(SubmissionError::Unrecoverable(error)) => {
             unreachable!("this error should not make it past `custom_backoff`: {error:#}");
         }
-        None => None,
+        None => (None, None),
     };
 
-    let blob_tx_and_fee = client.try_prepare(blobs, maybe_try_submit_error).await?;
-    let blob_tx_hash = BlobTxHash::compute(&blob_tx_and_fee.tx);
+    let maybe_superseded = match maybe_superseded {
+        Some((prepared_submission, fee_escalations, Some(timeout))) => {
+            match try_confirm_submission_from_failed_attempt(
+                client.clone(),
+                prepared_submission,
+                timeout,
+            )
+            .await?
+            {
+                ConfirmedOrNot::Confirmed(new_state) => {
+                    return Ok(StartedSubmissionAndFee {
+                        new_state,
+                        fee: None,
+                    });
+                }
+                ConfirmedOrNot::NotConfirmed(prepared_submission) => {
+                    Some((prepared_submission, fee_escalations))
+                }
+            }
+        }
+        Some((prepared_submission, fee_escalations, None)) => {
+            Some((prepared_submission, fee_escalations))
+        }
+        None => None,
+    };
 
-    let prepared_submission = started_submission
-        .into_prepared(largest_sequencer_height, blob_tx_hash)
+    let fee_escalations = maybe_superseded.as_ref().map_or_else(
+        || started_submission.fee_escalations(),
+        |(_, fee_escalations)| *fee_escalations,
+    );
+    let blob_tx_and_fee = match client
+        .try_prepare(blobs, maybe_try_submit_error, fee_escalations)
         .await
-        .map_err(|error| SubmissionError::Unrecoverable(Arc::new(error)))?;
-
+    {
+        Ok(blob_tx_and_fee) => blob_tx_and_fee,
+        Err(source) => {
+            return Err(match maybe_superseded {
+                Some((prepared_submission, _)) => SubmissionError::Resubmission {
+                    prepared_submission,
+                    source,
+                },
+                None => SubmissionError::TrySubmit(source),
+            });
+        }
+    };
+    let blob_tx_hash = C::blob_tx_hash(&blob_tx_and_fee.tx);
     let fee = blob_tx_and_fee.fee;
-    match client.try_submit(blob_tx_hash, blob_tx_and_fee.tx).await {
-        Ok(celestia_height) => prepared_submission
+
+    let prepared_submission = if let Some((prepared_submission, fee_escalations)) = maybe_superseded
+    {
+        info!(
+            %blob_tx_hash,
+            superseded_blob_tx_hash = %prepared_submission.blob_tx_hash(),
+            fee_escalations,
+            "resubmitting blobs with new blob transaction"
+        );
+        // The new blob tx is only recorded once it was broadcast, so that the recorded attempts
+        // are only ever ones that might be included.
+        if let Err(source) = client.try_broadcast(blob_tx_hash, blob_tx_and_fee.tx).await {
+            return Err(if source.is_sequence_mismatch() {
+                SubmissionError::SupersededPending(prepared_submission)
+            } else {
+                SubmissionError::Resubmission {
+                    prepared_submission,
+                    source,
+                }
+            });
+        }
+        prepared_submission
+            .into_resubmitted(blob_tx_hash, fee_escalations)
+            .await
+            .map_err(|error| SubmissionError::Unrecoverable(Arc::new(error)))?
+    } else {
+        let prepared_submission = started_submission
+            .into_prepared(largest_sequencer_height, blob_tx_hash)
+            .await
+            .map_err(|error| SubmissionError::Unrecoverable(Arc::new(error)))?;
+        match client.try_broadcast(blob_tx_hash, blob_tx_and_fee.tx).await {
+            Ok(()) => prepared_submission,
+            Err(TrySubmitError::FailedToBroadcastTx(error)) if error.is_timeout() => {
+                return Err(SubmissionError::BroadcastTxTimedOut(prepared_submission));
+            }
+            Err(error) => return Err(SubmissionError::TrySubmit(error)),
+        }
+    };
+
+    match client
+        .confirm_submission_with_timeout(&prepared_submission.blob_tx_hashes(), resubmit_after)
+        .await
+    {
+        Some(celestia_height) => prepared_submission
             .into_started(celestia_height)
             .await
             .map(|new_state| StartedSubmissionAndFee {
@@ -589,28 +754,33 @@ async fn try_submit(
                 fee: Some(fee),
             })
             .map_err(|error| SubmissionError::Unrecoverable(Arc::new(error))),
-        Err(TrySubmitError::FailedToBroadcastTx(error)) if error.is_timeout() => {
-            Err(SubmissionError::BroadcastTxTimedOut(prepared_submission))
-        }
-        Err(error) => Err(SubmissionError::TrySubmit(error)),
+        None => Err(SubmissionError::NotIncluded(prepared_submission)),
     }
 }
 
-/// Tries to confirm the submission from a failed previous attempt.  Returns `Some` if the
-/// submission is confirmed, or `None` if not.
+/// The outcome of trying to confirm the submission of a failed previous attempt.
+enum ConfirmedOrNot {
+    Confirmed(StartedSubmission),
+    NotConfirmed(PreparedSubmission),
+}
+
+/// Tries to confirm the submission from a failed previous attempt, including all attempts it
+/// superseded, for up to `timeout`.  Returns the new `Started` state if the submission is
+/// confirmed, or gives back the `Prepared` state if not.
 ///
 /// This should only be called where submission state is `Prepared`, meaning we don't yet
 /// know whether that previous submission attempt succeeded or not.
 #[instrument(skip_all, err)]
-async fn try_confirm_submission_from_failed_attempt(
-    mut client: CelestiaClient,
+async fn try_confirm_submission_from_failed_attempt<C: DaClient>(
+    mut client: C,
     prepared_submission: PreparedSubmission,
-) -> Result<Option<StartedSubmission>, SubmissionError> {
+    timeout: Duration,
+) -> Result<ConfirmedOrNot, SubmissionError> {
     let blob_tx_hash = prepared_submission.blob_tx_hash();
     info!(%blob_tx_hash, "confirming submission of last `BlobTx` from previous attempt");
 
     if let Some(celestia_height) = client
-        .confirm_submission_with_timeout(blob_tx_hash, prepared_submission.confirmation_timeout())
+        .confirm_submission_with_timeout(&prepared_submission.blob_tx_hashes(), timeout)
         .await
     {
         info!(%celestia_height, "confirmed previous attempt submitted blobs to Celestia");
@@ -618,11 +788,11 @@ async fn try_confirm_submission_from_failed_attempt(
             .into_started(celestia_height)
             .await
             .map_err(|error| SubmissionError::Unrecoverable(Arc::new(error)))?;
-        return Ok(Some(new_state));
+        return Ok(ConfirmedOrNot::Confirmed(new_state));
     }
 
     info!("previous attempt's last submission was not completed; starting resubmission");
-    Ok(None)
+    Ok(ConfirmedOrNot::NotConfirmed(prepared_submission))
 }
 
 type OngoingSubmission =
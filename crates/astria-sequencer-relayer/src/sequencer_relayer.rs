@@ -64,6 +64,11 @@ impl SequencerRelayer {
             block_time,
             api_addr,
             submission_state_path,
+            celestia_resubmit_after_blocks,
+            celestia_fee_escalation_percent,
+            celestia_max_gas_price,
+            da_backend,
+            local_da_path,
             ..
         } = cfg;
 
@@ -78,6 +83,11 @@ impl SequencerRelayer {
             sequencer_grpc_endpoint,
             rollup_filter,
             submission_state_path,
+            celestia_resubmit_after_blocks,
+            celestia_fee_escalation_percent,
+            celestia_max_gas_price,
+            da_backend,
+            local_da_path,
             metrics,
         }
         .build()
@@ -157,6 +157,15 @@ impl MockCelestiaAppServer {
             .await
     }
 
+    pub async fn mount_broadcast_tx_sequence_mismatch_response_as_scoped(
+        &self,
+        debug_name: impl Into<String>,
+    ) -> MockGuard {
+        Self::prepare_broadcast_tx_sequence_mismatch_response(debug_name)
+            .mount_as_scoped(&self.mock_server)
+            .await
+    }
+
     pub async fn mount_get_tx_response(&self, height: i64, debug_name: impl Into<String>) {
         Self::prepare_get_tx_response(height, debug_name)
             .mount(&self.mock_server)
@@ -202,6 +211,28 @@ impl MockCelestiaAppServer {
             .with_name(debug_name)
     }
 
+    fn prepare_broadcast_tx_sequence_mismatch_response(debug_name: impl Into<String>) -> Mock {
+        let debug_name = debug_name.into();
+        // This is the response of the Celestia app while an earlier tx with the same account
+        // sequence is still in its mempool.
+        let tx_response = TxResponse {
+            txhash: debug_name.clone(),
+            code: 32,
+            codespace: "sdk".to_string(),
+            raw_log: "account sequence mismatch, expected 54, got 53: incorrect account sequence"
+                .to_string(),
+            ..TxResponse::default()
+        };
+        let response = BroadcastTxResponse {
+            tx_response: Some(tx_response),
+        };
+        Mock::for_rpc_given(BROADCAST_TX_GRPC_NAME, message_type::<BroadcastTxRequest>())
+            .respond_with(constant_response(response))
+            .expect(1)
+            .up_to_n_times(1)
+            .with_name(debug_name)
+    }
+
     fn prepare_get_tx_response(height: i64, debug_name: impl Into<String>) -> Mock {
         let debug_name = debug_name.into();
         // We only use the `tx_response.code` and `tx_response.height` fields in the success case.
@@ -10,6 +10,7 @@ use std::{
     io::Write,
     mem,
     net::SocketAddr,
+    path::PathBuf,
     sync::LazyLock,
     time::Duration,
 };
@@ -22,6 +23,7 @@ use astria_core::{
 use astria_grpc_mock::MockGuard as GrpcMockGuard;
 use astria_sequencer_relayer::{
     config::Config,
+    DaBackend,
     Metrics,
     SequencerRelayer,
     ShutdownHandle,
@@ -272,6 +274,20 @@ impl TestSequencerRelayer {
             .await
     }
 
+    /// Mounts a Celestia `BroadcastTx` response rejecting the transaction with an account
+    /// sequence mismatch, and returns a `GrpcMockGuard` to allow for waiting for the mock to be
+    /// satisfied.
+    ///
+    /// The `debug_name` is assigned to the mock and is output on error to assist with debugging.
+    pub async fn mount_celestia_app_broadcast_tx_sequence_mismatch_response_as_scoped(
+        &self,
+        debug_name: impl Into<String>,
+    ) -> GrpcMockGuard {
+        self.celestia_app
+            .mount_broadcast_tx_sequence_mismatch_response_as_scoped(debug_name)
+            .await
+    }
+
     /// Mounts a Celestia `GetTx` response.
     ///
     /// The `debug_name` is assigned to the mock and is output on error to assist with debugging.
@@ -614,6 +630,36 @@ impl TestSequencerRelayer {
         }
     }
 
+    /// Returns the current contents of the submission state file.
+    pub fn submission_state(&self) -> serde_json::Value {
+        serde_json::from_str(&fs::read_to_string(&self.config.submission_state_path).unwrap())
+            .unwrap()
+    }
+
+    /// Polls the submission state file until `predicate` holds for its contents, and returns
+    /// them.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `predicate` doesn't hold within the number of milliseconds specified.
+    pub async fn wait_for_submission_state<P: Fn(&serde_json::Value) -> bool>(
+        &self,
+        predicate: P,
+        within_ms: u64,
+        context: &str,
+    ) -> serde_json::Value {
+        let check = async {
+            loop {
+                let submission_state = self.submission_state();
+                if predicate(&submission_state) {
+                    return submission_state;
+                }
+                tokio::time::sleep(Duration::from_millis(50)).await;
+            }
+        };
+        self.timeout_ms(within_ms, context, check).await
+    }
+
     #[track_caller]
     pub fn check_state_file(&self, last_sequencer_height: u32, current_sequencer_height: u32) {
         let submission_state: serde_json::Value =
@@ -664,6 +710,11 @@ pub struct TestSequencerRelayerConfig {
     pub sequencer_chain_id: String,
     /// The Celestia chain ID.
     pub celestia_chain_id: String,
+    /// The number of Celestia blocks after which a blob tx which was not included is resubmitted.
+    pub celestia_resubmit_after_blocks: u32,
+    /// The submission state to start from, e.g. as left behind by a previous relayer. Takes
+    /// precedence over `last_written_sequencer_height`.
+    pub submission_state: Option<serde_json::Value>,
 }
 
 impl TestSequencerRelayerConfig {
@@ -699,12 +750,13 @@ impl TestSequencerRelayerConfig {
         let sequencer = MockSequencerServer::spawn().await;
         let sequencer_grpc_endpoint = format!("http://{}", sequencer.local_addr);
 
-        let submission_state_file =
-            if let Some(last_written_sequencer_height) = self.last_written_sequencer_height {
-                create_file_for_start_at_height(last_written_sequencer_height)
-            } else {
-                create_file_for_fresh_start()
-            };
+        let submission_state_file = if let Some(submission_state) = &self.submission_state {
+            create_file_with_state(submission_state)
+        } else if let Some(last_written_sequencer_height) = self.last_written_sequencer_height {
+            create_file_for_start_at_height(last_written_sequencer_height)
+        } else {
+            create_file_for_fresh_start()
+        };
 
         let only_include_rollups = self.only_include_rollups.iter().join(",").to_string();
 
@@ -724,6 +776,11 @@ impl TestSequencerRelayerConfig {
             no_metrics: false,
             metrics_http_listener_addr: "127.0.0.1:9000".to_string(),
             submission_state_path: submission_state_file.path().to_owned(),
+            celestia_resubmit_after_blocks: self.celestia_resubmit_after_blocks,
+            celestia_fee_escalation_percent: 20,
+            celestia_max_gas_price: 0.2,
+            da_backend: DaBackend::Celestia,
+            local_da_path: PathBuf::new(),
         };
 
         let (metrics, metrics_handle) = metrics::ConfigBuilder::new()
@@ -770,6 +827,8 @@ impl Default for TestSequencerRelayerConfig {
             only_include_rollups: HashSet::new(),
             sequencer_chain_id: SEQUENCER_CHAIN_ID.to_string(),
             celestia_chain_id: CELESTIA_CHAIN_ID.to_string(),
+            celestia_resubmit_after_blocks: 5,
+            submission_state: None,
         }
     }
 }
@@ -814,6 +873,14 @@ async fn write_file(data: &'static [u8]) -> NamedTempFile {
     .unwrap()
 }
 
+fn create_file_with_state(submission_state: &serde_json::Value) -> NamedTempFile {
+    let temp_file = NamedTempFile::new()
+        .expect("must be able to create an empty submission state file to run tests");
+    serde_json::to_writer(&temp_file, submission_state)
+        .expect("must be able to write submission state to run tests");
+    temp_file
+}
+
 fn create_file_for_fresh_start() -> NamedTempFile {
     let temp_file = NamedTempFile::new()
         .expect("must be able to create an empty submission state file to run tests");
@@ -363,3 +363,147 @@ async fn should_exit_if_celestia_chain_id_mismatch() {
 
     sequencer_relayer.wait_for_relayer_shutdown(100).await;
 }
+
+#[expect(
+    clippy::too_many_lines,
+    reason = "the test walks through the whole resubmission flow across a restart"
+)]
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn blob_tx_not_included_is_resubmitted_and_confirmed_after_restart() {
+    let sequencer_relayer = TestSequencerRelayerConfig {
+        celestia_resubmit_after_blocks: 1,
+        ..TestSequencerRelayerConfig::default()
+    }
+    .spawn_relayer()
+    .await;
+
+    sequencer_relayer.mount_abci_response(1).await;
+    let block_to_mount = SequencerBlockToMount::GoodAtHeight(1);
+    sequencer_relayer
+        .mount_sequencer_block_response(block_to_mount, "good block 1")
+        .await;
+    // No `GetTx` response is mounted, so none of the broadcast blob txs is ever included.
+    let first_broadcast_guard = sequencer_relayer
+        .mount_celestia_app_broadcast_tx_response_as_scoped("broadcast tx 1")
+        .await;
+    let rejected_broadcast_guard = sequencer_relayer
+        .mount_celestia_app_broadcast_tx_sequence_mismatch_response_as_scoped(
+            "rejected broadcast tx 2",
+        )
+        .await;
+    let second_broadcast_guard = sequencer_relayer
+        .mount_celestia_app_broadcast_tx_response_as_scoped("broadcast tx 3")
+        .await;
+
+    sequencer_relayer
+        .timeout_ms(
+            2_000,
+            "waiting for first broadcast tx guard",
+            first_broadcast_guard.wait_until_satisfied(),
+        )
+        .await;
+    let submission_state = sequencer_relayer.submission_state();
+    assert_eq!(submission_state["state"], "prepared");
+    assert_eq!(submission_state.get("superseded_blob_tx_hashes"), None);
+    let first_blob_tx_hash = submission_state["blob_tx_hash"].clone();
+
+    // After not being included within one Celestia block, the blobs are resubmitted. The
+    // Celestia app rejects the resubmission while the first blob tx is still in its mempool.
+    sequencer_relayer
+        .timeout_ms(
+            20_000,
+            "waiting for rejected broadcast tx guard",
+            rejected_broadcast_guard.wait_until_satisfied(),
+        )
+        .await;
+    let submission_state = sequencer_relayer.submission_state();
+    assert_eq!(
+        submission_state["blob_tx_hash"], first_blob_tx_hash,
+        "a rejected resubmission must not be recorded"
+    );
+    assert_eq!(submission_state.get("superseded_blob_tx_hashes"), None);
+    assert!(
+        sequencer_relayer
+            .state("checking celestia connection")
+            .await
+            .celestia_connected,
+        "a blob tx pending in the mempool must not be reported as a lost connection"
+    );
+
+    // The relayer keeps waiting for the first blob tx for another Celestia block, then
+    // resubmits again.
+    sequencer_relayer
+        .timeout_ms(
+            20_000,
+            "waiting for second broadcast tx guard",
+            second_broadcast_guard.wait_until_satisfied(),
+        )
+        .await;
+    let submission_state = sequencer_relayer
+        .wait_for_submission_state(
+            |state| state["blob_tx_hash"] != first_blob_tx_hash,
+            1_000,
+            "waiting for resubmission to be recorded",
+        )
+        .await;
+    assert_eq!(submission_state["state"], "prepared");
+    assert_eq!(
+        submission_state["superseded_blob_tx_hashes"],
+        serde_json::json!([first_blob_tx_hash])
+    );
+    assert_eq!(submission_state["fee_escalations"], 1);
+
+    // A relayer restarted from the submission state confirms the resubmitted blob tx and
+    // continues relaying from there.
+    let restarted_relayer = TestSequencerRelayerConfig {
+        submission_state: Some(submission_state),
+        ..TestSequencerRelayerConfig::default()
+    }
+    .spawn_relayer()
+    .await;
+    let get_tx_guard = restarted_relayer
+        .mount_celestia_app_get_tx_response_as_scoped(53, "get tx 3")
+        .await;
+    restarted_relayer
+        .timeout_ms(
+            5_000,
+            "waiting for get tx guard after restart",
+            get_tx_guard.wait_until_satisfied(),
+        )
+        .await;
+    restarted_relayer
+        .wait_for_latest_confirmed_celestia_height(53, 1_000)
+        .await;
+    let submission_state = restarted_relayer.submission_state();
+    assert_eq!(submission_state["state"], "started");
+    assert_eq!(submission_state["last_submission"]["sequencer_height"], 1);
+
+    // Block 1 is fetched again as the restarted relayer starts reading from the last completed
+    // submission, but it is skipped as it was already submitted.
+    restarted_relayer.mount_abci_response(2).await;
+    restarted_relayer
+        .mount_sequencer_block_response(SequencerBlockToMount::GoodAtHeight(1), "good block 1")
+        .await;
+    restarted_relayer
+        .mount_sequencer_block_response(SequencerBlockToMount::GoodAtHeight(2), "good block 2")
+        .await;
+    restarted_relayer
+        .mount_celestia_app_broadcast_tx_response("broadcast tx 4")
+        .await;
+    let get_tx_guard = restarted_relayer
+        .mount_celestia_app_get_tx_response_as_scoped(54, "get tx 4")
+        .await;
+    restarted_relayer
+        .timeout_ms(
+            5_000,
+            "waiting for get tx guard of next submission",
+            get_tx_guard.wait_until_satisfied(),
+        )
+        .await;
+    restarted_relayer
+        .wait_for_latest_confirmed_celestia_height(54, 1_000)
+        .await;
+    restarted_relayer
+        .wait_for_latest_fetched_sequencer_height(2, 1_000)
+        .await;
+}
@@ -0,0 +1,818 @@
+use std::{
+    collections::{
+        BTreeMap,
+        HashMap,
+        HashSet,
+    },
+    fmt::{
+        self,
+        Display,
+        Formatter,
+    },
+    time::Duration,
+};
+
+use astria_core::{
+    brotli::decompress_bytes,
+    celestia::{
+        namespace_v0_from_rollup_id,
+        namespace_v0_from_sha256_of_bytes,
+        Namespace,
+    },
+    generated::astria::sequencerblock::v1::{
+        SubmittedMetadata as RawSubmittedMetadata,
+        SubmittedMetadataList as RawSubmittedMetadataList,
+        SubmittedRollupData as RawSubmittedRollupData,
+        SubmittedRollupDataList as RawSubmittedRollupDataList,
+    },
+    primitive::v1::RollupId,
+    sequencerblock::v1::{
+        block,
+        SubmittedMetadata,
+        SubmittedRollupData,
+    },
+};
+use astria_eyre::eyre::{
+    bail,
+    ensure,
+    eyre,
+    Result,
+    WrapErr as _,
+};
+use base64::{
+    prelude::BASE64_STANDARD,
+    Engine as _,
+};
+use serde::Serialize;
+use serde_json::{
+    json,
+    Value,
+};
+
+use crate::blob_parser::Format;
+
+const TIMEOUT_DURATION: Duration = Duration::from_secs(10);
+
+#[derive(clap::Args, Debug)]
+pub struct Args {
+    /// The URL of the Celestia node's JSON-RPC server
+    #[arg(
+        long,
+        short = 'u',
+        value_name = "URL",
+        default_value = "http://127.0.0.1:26658"
+    )]
+    celestia_url: String,
+
+    /// The auth token of the Celestia node, if it requires one
+    #[arg(long, value_name = "TOKEN")]
+    celestia_bearer_token: Option<String>,
+
+    /// The first Celestia height to inspect
+    #[arg(long, value_name = "HEIGHT")]
+    from_height: u64,
+
+    /// The last Celestia height to inspect [default: the value of `--from-height`]
+    #[arg(long, value_name = "HEIGHT")]
+    to_height: Option<u64>,
+
+    /// The chain ID of the sequencer network, from which the sequencer namespace is derived
+    #[arg(long, value_name = "CHAIN ID")]
+    sequencer_chain_id: String,
+
+    /// The ID of a rollup, as hex or URL-safe base64, whose namespace to inspect as well. Its
+    /// data is verified against the sequencer metadata
+    #[arg(long, value_name = "ROLLUP ID", value_parser = parse_rollup_id)]
+    rollup_id: Option<RollupId>,
+
+    /// Configure formatting of output
+    #[arg(short, long, default_value_t = Format::Display, value_enum)]
+    format: Format,
+}
+
+/// Fetches all blobs in the sequencer namespace and optionally a rollup namespace over a range of
+/// Celestia heights, verifies them against each other and prints what was found.
+///
+/// # Errors
+///
+/// Returns an error if fetching the blobs failed, or if the inspection found any problems.
+pub async fn run(args: Args) -> Result<()> {
+    let to_height = args.to_height.unwrap_or(args.from_height);
+    ensure!(
+        args.from_height <= to_height,
+        "`--from-height` must not be greater than `--to-height`"
+    );
+    let sequencer_namespace = namespace_v0_from_sha256_of_bytes(&args.sequencer_chain_id);
+    let rollup_namespace = args.rollup_id.map(namespace_v0_from_rollup_id);
+
+    let client = reqwest::Client::new();
+    let mut blobs = Vec::new();
+    for celestia_height in args.from_height..=to_height {
+        for (kind, namespace) in std::iter::once((Kind::Sequencer, sequencer_namespace))
+            .chain(rollup_namespace.map(|namespace| (Kind::Rollup, namespace)))
+        {
+            let data = fetch_blobs(&client, &args, celestia_height, namespace).await?;
+            blobs.extend(data.into_iter().map(|data| Blob {
+                celestia_height,
+                kind,
+                data,
+            }));
+        }
+    }
+
+    let inspection = Inspection {
+        from_height: args.from_height,
+        to_height,
+        sequencer_namespace: BASE64_STANDARD.encode(sequencer_namespace.as_bytes()),
+        rollup_namespace: rollup_namespace
+            .map(|namespace| BASE64_STANDARD.encode(namespace.as_bytes())),
+        ..inspect(blobs, args.rollup_id)
+    };
+    match args.format {
+        Format::Display => print!("{inspection}"),
+        Format::Json => println!(
+            "{}",
+            serde_json::to_string(&inspection).wrap_err("failed to json-encode")?
+        ),
+    }
+    if !inspection.problems.is_empty() {
+        bail!(
+            "found {} problem(s) in the inspected blobs",
+            inspection.problems.len()
+        );
+    }
+    Ok(())
+}
+
+fn parse_rollup_id(input: &str) -> Result<RollupId> {
+    let bytes = if input.len() == 64 {
+        hex::decode(input).wrap_err("failed to decode rollup ID as hex")?
+    } else {
+        base64::prelude::BASE64_URL_SAFE
+            .decode(input)
+            .wrap_err("failed to decode rollup ID as URL-safe base64")?
+    };
+    RollupId::try_from_slice(&bytes).wrap_err("rollup ID must be 32 bytes")
+}
+
+/// Returns the data of all blobs in `namespace` at `celestia_height` using the node's
+/// `blob.GetAll` method.
+async fn fetch_blobs(
+    client: &reqwest::Client,
+    args: &Args,
+    celestia_height: u64,
+    namespace: Namespace,
+) -> Result<Vec<Vec<u8>>> {
+    let request = json!({
+        "jsonrpc": "2.0",
+        "id": 1,
+        "method": "blob.GetAll",
+        "params": [celestia_height, [BASE64_STANDARD.encode(namespace.as_bytes())]],
+    });
+    let mut builder = client
+        .post(&args.celestia_url)
+        .header("content-type", "application/json")
+        .body(request.to_string());
+    if let Some(token) = &args.celestia_bearer_token {
+        builder = builder.bearer_auth(token);
+    }
+    let blocking_getter = async {
+        builder
+            .send()
+            .await
+            .wrap_err_with(|| format!("failed to get blobs at Celestia height {celestia_height}"))?
+            .text()
+            .await
+            .wrap_err("failed to parse blob response as UTF-8 string")
+    };
+    let response = tokio::time::timeout(TIMEOUT_DURATION, blocking_getter)
+        .await
+        .wrap_err_with(|| {
+            format!(
+                "failed to fetch blobs at Celestia height {celestia_height} within {}s",
+                TIMEOUT_DURATION.as_secs_f32()
+            )
+        })??;
+    let json_rpc_response: Value = serde_json::from_str(&response)
+        .wrap_err_with(|| format!("failed to parse blob response `{response}` as json"))?;
+
+    if let Some(error) = json_rpc_response.get("error") {
+        // Older Celestia nodes report an empty namespace as an error instead of a null result.
+        if error
+            .get("message")
+            .and_then(Value::as_str)
+            .is_some_and(|message| message.contains("blob: not found"))
+        {
+            return Ok(vec![]);
+        }
+        bail!("Celestia node returned an error for height {celestia_height}: {error}");
+    }
+    let Some(blobs) = json_rpc_response.get("result") else {
+        bail!("expected blob response `{response}` to have field `result`");
+    };
+    if blobs.is_null() {
+        return Ok(vec![]);
+    }
+    blobs
+        .as_array()
+        .ok_or_else(|| eyre!("expected `result` of blob response `{response}` to be an array"))?
+        .iter()
+        .map(|blob| {
+            let data = blob
+                .get("data")
+                .and_then(Value::as_str)
+                .ok_or_else(|| eyre!("expected blob `{blob}` to have string field `data`"))?;
+            BASE64_STANDARD
+                .decode(data)
+                .wrap_err("failed to decode blob data as base64")
+        })
+        .collect()
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+enum Kind {
+    Sequencer,
+    Rollup,
+}
+
+impl Display for Kind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Sequencer => f.write_str("sequencer"),
+            Self::Rollup => f.write_str("rollup"),
+        }
+    }
+}
+
+/// The data of a blob fetched from Celestia.
+struct Blob {
+    celestia_height: u64,
+    kind: Kind,
+    data: Vec<u8>,
+}
+
+#[derive(Debug, Serialize)]
+struct MetadataEntry {
+    celestia_height: u64,
+    sequencer_height: u64,
+    block_hash: String,
+    rollup_count: usize,
+}
+
+#[derive(Debug, Serialize)]
+struct RollupDataEntry {
+    celestia_height: u64,
+    /// The height of the sequencer block the data was verified against, if any.
+    sequencer_height: Option<u64>,
+    block_hash: String,
+    transaction_count: usize,
+}
+
+#[derive(Debug, PartialEq, Eq, Serialize)]
+#[serde(tag = "problem", rename_all = "snake_case")]
+enum Problem {
+    /// A blob could not be decompressed or decoded.
+    UndecodableBlob {
+        celestia_height: u64,
+        kind: Kind,
+        error: String,
+    },
+    /// An entry in a blob was decoded but failed validation.
+    InvalidEntry {
+        celestia_height: u64,
+        kind: Kind,
+        error: String,
+    },
+    /// No metadata was found for a range of sequencer heights between the lowest and highest ones
+    /// found.
+    MissingSequencerHeights { first: u64, last: u64 },
+    /// Metadata with different block hashes was found for the same sequencer height.
+    ConflictingMetadata {
+        sequencer_height: u64,
+        block_hashes: Vec<String>,
+    },
+    /// The metadata of a sequencer block lists the rollup, but no rollup data for the block was
+    /// found.
+    MissingRollupData {
+        sequencer_height: u64,
+        block_hash: String,
+    },
+    /// Rollup data was found for a sequencer block whose metadata was not found.
+    UnmatchedRollupData {
+        celestia_height: u64,
+        block_hash: String,
+    },
+    /// The Merkle proof of rollup data does not lead to the rollup transactions root of the
+    /// sequencer block's metadata.
+    ProofMismatch {
+        celestia_height: u64,
+        sequencer_height: u64,
+        block_hash: String,
+    },
+}
+
+impl Display for Problem {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UndecodableBlob {
+                celestia_height,
+                kind,
+                error,
+            } => write!(
+                f,
+                "undecodable {kind} blob at celestia height {celestia_height}: {error}"
+            ),
+            Self::InvalidEntry {
+                celestia_height,
+                kind,
+                error,
+            } => write!(
+                f,
+                "invalid {kind} entry at celestia height {celestia_height}: {error}"
+            ),
+            Self::MissingSequencerHeights {
+                first,
+                last,
+            } => write!(f, "no metadata for sequencer heights {first} to {last}"),
+            Self::ConflictingMetadata {
+                sequencer_height,
+                block_hashes,
+            } => write!(
+                f,
+                "conflicting metadata for sequencer height {sequencer_height} with block hashes {}",
+                block_hashes.join(", ")
+            ),
+            Self::MissingRollupData {
+                sequencer_height,
+                block_hash,
+            } => write!(
+                f,
+                "no rollup data for sequencer height {sequencer_height} (block hash {block_hash}) \
+                 although its metadata lists the rollup"
+            ),
+            Self::UnmatchedRollupData {
+                celestia_height,
+                block_hash,
+            } => write!(
+                f,
+                "rollup data at celestia height {celestia_height} for block hash {block_hash} has \
+                 no matching metadata"
+            ),
+            Self::ProofMismatch {
+                celestia_height,
+                sequencer_height,
+                block_hash,
+            } => write!(
+                f,
+                "rollup data at celestia height {celestia_height} for sequencer height \
+                 {sequencer_height} (block hash {block_hash}) does not match its metadata's \
+                 rollup transactions root"
+            ),
+        }
+    }
+}
+
+#[derive(Debug, Default, Serialize)]
+struct Inspection {
+    from_height: u64,
+    to_height: u64,
+    sequencer_namespace: String,
+    rollup_namespace: Option<String>,
+    metadata: Vec<MetadataEntry>,
+    rollup_data: Vec<RollupDataEntry>,
+    problems: Vec<Problem>,
+}
+
+impl Display for Inspection {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "celestia heights: {} to {}",
+            self.from_height, self.to_height
+        )?;
+        writeln!(f, "sequencer namespace: {}", self.sequencer_namespace)?;
+        if let Some(rollup_namespace) = &self.rollup_namespace {
+            writeln!(f, "rollup namespace: {rollup_namespace}")?;
+        }
+        writeln!(f, "sequencer metadata entries: {}", self.metadata.len())?;
+        for entry in &self.metadata {
+            writeln!(
+                f,
+                "    celestia height {}: sequencer height {}, block hash {}, {} rollup(s)",
+                entry.celestia_height, entry.sequencer_height, entry.block_hash, entry.rollup_count
+            )?;
+        }
+        if self.rollup_namespace.is_some() {
+            writeln!(f, "rollup data entries: {}", self.rollup_data.len())?;
+            for entry in &self.rollup_data {
+                let sequencer_height = entry
+                    .sequencer_height
+                    .map_or_else(|| "unknown".to_string(), |height| height.to_string());
+                writeln!(
+                    f,
+                    "    celestia height {}: sequencer height {sequencer_height}, block hash {}, \
+                     {} transaction(s)",
+                    entry.celestia_height, entry.block_hash, entry.transaction_count
+                )?;
+            }
+        }
+        writeln!(f, "problems: {}", self.problems.len())?;
+        for problem in &self.problems {
+            writeln!(f, "    {problem}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Decodes `blobs` and verifies the rollup data in them against the sequencer metadata.
+///
+/// Blobs at the edges of the inspected range may reference blobs outside of it, which are then
+/// reported as missing or unmatched.
+fn inspect(blobs: Vec<Blob>, rollup_id: Option<RollupId>) -> Inspection {
+    let mut problems = Vec::new();
+    let DecodedBlobs {
+        mut metadata,
+        rollup_data,
+    } = decode_blobs(blobs, rollup_id, &mut problems);
+
+    metadata.sort_by_key(|(celestia_height, entry)| (entry.height(), *celestia_height));
+    check_sequencer_heights(&metadata, &mut problems);
+
+    let metadata_by_hash: HashMap<block::Hash, &SubmittedMetadata> = metadata
+        .iter()
+        .map(|(_, entry)| (*entry.block_hash(), entry))
+        .collect();
+    let mut verified = HashSet::new();
+    let mut rollup_data_entries = Vec::with_capacity(rollup_data.len());
+    for (celestia_height, entry) in &rollup_data {
+        let block_hash = *entry.sequencer_block_hash();
+        let sequencer_height = match metadata_by_hash.get(&block_hash) {
+            None => {
+                problems.push(Problem::UnmatchedRollupData {
+                    celestia_height: *celestia_height,
+                    block_hash: block_hash.to_string(),
+                });
+                None
+            }
+            Some(metadata) if !verify_rollup_data(entry, metadata) => {
+                problems.push(Problem::ProofMismatch {
+                    celestia_height: *celestia_height,
+                    sequencer_height: metadata.height().value(),
+                    block_hash: block_hash.to_string(),
+                });
+                None
+            }
+            Some(metadata) => {
+                verified.insert(block_hash);
+                Some(metadata.height().value())
+            }
+        };
+        rollup_data_entries.push(RollupDataEntry {
+            celestia_height: *celestia_height,
+            sequencer_height,
+            block_hash: block_hash.to_string(),
+            transaction_count: entry.transactions().len(),
+        });
+    }
+    if let Some(rollup_id) = rollup_id {
+        for (_, entry) in &metadata {
+            if entry.contains_rollup_id(rollup_id) && !verified.contains(entry.block_hash()) {
+                problems.push(Problem::MissingRollupData {
+                    sequencer_height: entry.height().value(),
+                    block_hash: entry.block_hash().to_string(),
+                });
+            }
+        }
+    }
+
+    Inspection {
+        metadata: metadata
+            .iter()
+            .map(|(celestia_height, entry)| MetadataEntry {
+                celestia_height: *celestia_height,
+                sequencer_height: entry.height().value(),
+                block_hash: entry.block_hash().to_string(),
+                rollup_count: entry.rollup_ids().count(),
+            })
+            .collect(),
+        rollup_data: rollup_data_entries,
+        problems,
+        ..Inspection::default()
+    }
+}
+
+/// The entries decoded from blobs, paired with the Celestia height they were found at.
+struct DecodedBlobs {
+    metadata: Vec<(u64, SubmittedMetadata)>,
+    rollup_data: Vec<(u64, SubmittedRollupData)>,
+}
+
+/// Decodes `blobs` into the sequencer metadata and the data of rollup `rollup_id` contained in
+/// them.
+fn decode_blobs(
+    blobs: Vec<Blob>,
+    rollup_id: Option<RollupId>,
+    problems: &mut Vec<Problem>,
+) -> DecodedBlobs {
+    let mut metadata = Vec::new();
+    let mut rollup_data = Vec::new();
+    for Blob {
+        celestia_height,
+        kind,
+        data,
+    } in blobs
+    {
+        let decoded = match kind {
+            Kind::Sequencer => {
+                decode_entries::<RawSubmittedMetadataList, RawSubmittedMetadata, _>(&data, |list| {
+                    list.entries
+                })
+                .map(|entries| {
+                    for entry in entries {
+                        match SubmittedMetadata::try_from_raw(entry) {
+                            Ok(entry) => metadata.push((celestia_height, entry)),
+                            Err(error) => problems.push(Problem::InvalidEntry {
+                                celestia_height,
+                                kind,
+                                error: error.to_string(),
+                            }),
+                        }
+                    }
+                })
+            }
+            Kind::Rollup => {
+                decode_entries::<RawSubmittedRollupDataList, RawSubmittedRollupData, _>(
+                    &data,
+                    |list| list.entries,
+                )
+                .map(|entries| {
+                    for entry in entries {
+                        match SubmittedRollupData::try_from_raw(entry) {
+                            Ok(entry) if Some(entry.rollup_id()) != rollup_id => {
+                                problems.push(Problem::InvalidEntry {
+                                    celestia_height,
+                                    kind,
+                                    error: format!(
+                                        "rollup data belongs to rollup `{}`",
+                                        entry.rollup_id()
+                                    ),
+                                });
+                            }
+                            Ok(entry) => rollup_data.push((celestia_height, entry)),
+                            Err(error) => problems.push(Problem::InvalidEntry {
+                                celestia_height,
+                                kind,
+                                error: error.to_string(),
+                            }),
+                        }
+                    }
+                })
+            }
+        };
+        if let Err(error) = decoded {
+            problems.push(Problem::UndecodableBlob {
+                celestia_height,
+                kind,
+                error: format!("{error:#}"),
+            });
+        }
+    }
+    DecodedBlobs {
+        metadata,
+        rollup_data,
+    }
+}
+
+/// Decompresses `data` and decodes it as a list of entries, or as a single entry.
+fn decode_entries<List, Entry, F>(data: &[u8], into_entries: F) -> Result<Vec<Entry>>
+where
+    List: prost::Message + Default,
+    Entry: prost::Message + Default,
+    F: FnOnce(List) -> Vec<Entry>,
+{
+    let decompressed = decompress_bytes(data).wrap_err("failed to decompress blob data")?;
+    if let Ok(list) = List::decode(&*decompressed) {
+        return Ok(into_entries(list));
+    }
+    Entry::decode(&*decompressed)
+        .map(|entry| vec![entry])
+        .wrap_err("failed to decode blob data as a list of entries or a single entry")
+}
+
+/// Reports gaps between the sequencer heights of `metadata`, which must be sorted by height, and
+/// heights for which metadata with different block hashes was found.
+fn check_sequencer_heights(metadata: &[(u64, SubmittedMetadata)], problems: &mut Vec<Problem>) {
+    let mut by_height: BTreeMap<u64, Vec<String>> = BTreeMap::new();
+    for (_, entry) in metadata {
+        let hashes = by_height.entry(entry.height().value()).or_default();
+        let hash = entry.block_hash().to_string();
+        if !hashes.contains(&hash) {
+            hashes.push(hash);
+        }
+    }
+    let mut previous: Option<u64> = None;
+    for (height, block_hashes) in by_height {
+        if let Some(previous) = previous {
+            if height > previous.saturating_add(1) {
+                problems.push(Problem::MissingSequencerHeights {
+                    first: previous.saturating_add(1),
+                    last: height.saturating_sub(1),
+                });
+            }
+        }
+        if block_hashes.len() > 1 {
+            problems.push(Problem::ConflictingMetadata {
+                sequencer_height: height,
+                block_hashes,
+            });
+        }
+        previous = Some(height);
+    }
+}
+
+fn verify_rollup_data(rollup_data: &SubmittedRollupData, metadata: &SubmittedMetadata) -> bool {
+    rollup_data
+        .proof()
+        .audit()
+        .with_root(*metadata.rollup_transactions_root())
+        .with_leaf_builder()
+        .write(rollup_data.rollup_id().as_bytes())
+        .write(&astria_merkle::Tree::from_leaves(rollup_data.transactions()).root())
+        .finish_leaf()
+        .perform()
+}
+
+#[cfg(test)]
+mod tests {
+    use astria_core::{
+        brotli::compress_bytes,
+        protocol::test_utils::ConfigureSequencerBlock,
+        sequencerblock::v1::celestia::UncheckedSubmittedRollupData,
+    };
+    use prost::Message as _;
+
+    use super::*;
+
+    const ROLLUP_ID: RollupId = RollupId::new([1; 32]);
+
+    /// Returns the metadata and the rollup data of a block at `height` with a transaction for
+    /// [`ROLLUP_ID`].
+    fn make_block(height: u32) -> (SubmittedMetadata, SubmittedRollupData) {
+        let (metadata, mut rollup_data) = ConfigureSequencerBlock {
+            block_hash: Some(block::Hash::new([height.to_le_bytes()[0]; 32])),
+            height,
+            sequence_data: vec![(ROLLUP_ID, vec![1, 2, 3])],
+            ..ConfigureSequencerBlock::default()
+        }
+        .make()
+        .split_for_celestia();
+        (metadata, rollup_data.remove(0))
+    }
+
+    fn metadata_blob(celestia_height: u64, entries: Vec<SubmittedMetadata>) -> Blob {
+        let list = RawSubmittedMetadataList {
+            entries: entries
+                .into_iter()
+                .map(SubmittedMetadata::into_raw)
+                .collect(),
+        };
+        Blob {
+            celestia_height,
+            kind: Kind::Sequencer,
+            data: compress_bytes(&list.encode_to_vec()).unwrap(),
+        }
+    }
+
+    fn rollup_blob(celestia_height: u64, entries: Vec<SubmittedRollupData>) -> Blob {
+        let list = RawSubmittedRollupDataList {
+            entries: entries
+                .into_iter()
+                .map(SubmittedRollupData::into_raw)
+                .collect(),
+        };
+        Blob {
+            celestia_height,
+            kind: Kind::Rollup,
+            data: compress_bytes(&list.encode_to_vec()).unwrap(),
+        }
+    }
+
+    #[test]
+    fn consistent_blobs_have_no_problems() {
+        let (metadata_1, rollup_data_1) = make_block(1);
+        let (metadata_2, rollup_data_2) = make_block(2);
+        let inspection = inspect(
+            vec![
+                metadata_blob(10, vec![metadata_1, metadata_2]),
+                rollup_blob(10, vec![rollup_data_1]),
+                rollup_blob(11, vec![rollup_data_2]),
+            ],
+            Some(ROLLUP_ID),
+        );
+        assert_eq!(Vec::<Problem>::new(), inspection.problems);
+        assert_eq!(2, inspection.metadata.len());
+        assert_eq!(
+            vec![Some(1), Some(2)],
+            inspection
+                .rollup_data
+                .iter()
+                .map(|entry| entry.sequencer_height)
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn gaps_in_sequencer_heights_are_reported() {
+        let inspection = inspect(
+            vec![
+                metadata_blob(10, vec![make_block(5).0]),
+                metadata_blob(12, vec![make_block(2).0]),
+            ],
+            None,
+        );
+        assert_eq!(
+            vec![Problem::MissingSequencerHeights {
+                first: 3,
+                last: 4,
+            }],
+            inspection.problems
+        );
+    }
+
+    #[test]
+    fn missing_and_unmatched_rollup_data_is_reported() {
+        let (metadata_1, _) = make_block(1);
+        let (_, rollup_data_2) = make_block(2);
+        let inspection = inspect(
+            vec![
+                metadata_blob(10, vec![metadata_1.clone()]),
+                rollup_blob(10, vec![rollup_data_2.clone()]),
+            ],
+            Some(ROLLUP_ID),
+        );
+        assert_eq!(
+            vec![
+                Problem::UnmatchedRollupData {
+                    celestia_height: 10,
+                    block_hash: rollup_data_2.sequencer_block_hash().to_string(),
+                },
+                Problem::MissingRollupData {
+                    sequencer_height: 1,
+                    block_hash: metadata_1.block_hash().to_string(),
+                },
+            ],
+            inspection.problems
+        );
+    }
+
+    #[test]
+    fn tampered_rollup_data_is_reported() {
+        let (metadata, rollup_data) = make_block(1);
+        let mut unchecked = rollup_data.into_unchecked();
+        unchecked.transactions.push(vec![4, 5, 6].into());
+        let tampered = UncheckedSubmittedRollupData::into_celestia_rollup_blob(unchecked);
+        let inspection = inspect(
+            vec![
+                metadata_blob(10, vec![metadata.clone()]),
+                rollup_blob(11, vec![tampered]),
+            ],
+            Some(ROLLUP_ID),
+        );
+        let block_hash = metadata.block_hash().to_string();
+        assert_eq!(
+            vec![
+                Problem::ProofMismatch {
+                    celestia_height: 11,
+                    sequencer_height: 1,
+                    block_hash: block_hash.clone(),
+                },
+                Problem::MissingRollupData {
+                    sequencer_height: 1,
+                    block_hash,
+                },
+            ],
+            inspection.problems
+        );
+    }
+
+    #[test]
+    fn undecodable_blobs_are_reported() {
+        let inspection = inspect(
+            vec![Blob {
+                celestia_height: 10,
+                kind: Kind::Sequencer,
+                data: compress_bytes(&[0xff; 8]).unwrap(),
+            }],
+            None,
+        );
+        assert!(matches!(
+            inspection.problems.as_slice(),
+            [Problem::UndecodableBlob {
+                celestia_height: 10,
+                kind: Kind::Sequencer,
+                ..
+            }]
+        ));
+    }
+}
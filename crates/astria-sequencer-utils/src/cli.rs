@@ -6,6 +6,7 @@ use clap::{
 use super::{
     activation_point_estimator,
     blob_parser,
+    celestia_inspector,
     genesis_example,
     genesis_parser,
 };
@@ -19,10 +20,6 @@ struct Cli {
 }
 
 #[derive(Debug, Subcommand)]
-#[expect(
-    clippy::large_enum_variant,
-    reason = "this doesn't matter for this enum"
-)]
 pub enum Command {
     /// Copy genesis state to a JSON file
     #[command(arg_required_else_help = true)]
@@ -38,6 +35,10 @@ pub enum Command {
     /// Estimate the activation point of an upgrade to schedule or check an upcoming one
     #[command(arg_required_else_help = true)]
     EstimateActivationPoint(activation_point_estimator::Args),
+
+    /// Fetch sequencer blobs from Celestia over a range of heights and verify them
+    #[command(arg_required_else_help = true)]
+    InspectCelestia(celestia_inspector::Args),
 }
 
 #[must_use]
@@ -8,11 +8,16 @@ use astria_core::{
     generated::astria::protocol::genesis::v1::{
         AddressPrefixes,
         GenesisFees,
+        GovernanceParameters,
         IbcParameters,
+        OracleLivenessParameters,
     },
     primitive::v1::Address,
     protocol::{
-        fees::v1::FeeComponents,
+        fees::v1::{
+            FeeComponents,
+            FeeDistribution,
+        },
         genesis::v1::{
             Account,
             GenesisAppState,
@@ -22,19 +27,26 @@ use astria_core::{
             BridgeSudoChange,
             BridgeTransfer,
             BridgeUnlock,
+            CancelTimelockedTransfer,
+            ClaimTimelockedTransfer,
             CurrencyPairsChange,
             FeeAssetChange,
             FeeChange,
+            FeeDistributionChange,
             IbcRelayerChange,
             IbcSudoChange,
             Ics20Withdrawal,
             InitBridgeAccount,
             MarketsChange,
             RecoverIbcClient,
+            RegisterMultisig,
             RollupDataSubmission,
+            SubmitProposal,
             SudoAddressChange,
+            TimelockedTransfer,
             Transfer,
             ValidatorUpdate,
+            VoteOnProposal,
         },
     },
     Protobuf,
@@ -131,6 +143,31 @@ fn proto_genesis_state() -> astria_core::generated::astria::protocol::genesis::v
             recover_ibc_client: Some(FeeComponents::<RecoverIbcClient>::new(0, 0).to_raw()),
             currency_pairs_change: Some(FeeComponents::<CurrencyPairsChange>::new(0, 0).to_raw()),
             markets_change: Some(FeeComponents::<MarketsChange>::new(0, 0).to_raw()),
+            register_multisig: Some(FeeComponents::<RegisterMultisig>::new(0, 0).to_raw()),
+            timelocked_transfer: Some(FeeComponents::<TimelockedTransfer>::new(12, 0).to_raw()),
+            claim_timelocked_transfer: Some(
+                FeeComponents::<ClaimTimelockedTransfer>::new(0, 0).to_raw(),
+            ),
+            cancel_timelocked_transfer: Some(
+                FeeComponents::<CancelTimelockedTransfer>::new(0, 0).to_raw(),
+            ),
+            submit_proposal: Some(FeeComponents::<SubmitProposal>::new(0, 0).to_raw()),
+            vote_on_proposal: Some(FeeComponents::<VoteOnProposal>::new(0, 0).to_raw()),
+            fee_distribution_change: Some(
+                FeeComponents::<FeeDistributionChange>::new(0, 0).to_raw(),
+            ),
+        }),
+        governance_parameters: Some(GovernanceParameters {
+            proposers: vec![alice().to_raw()],
+            council: vec![],
+            voting_period_blocks: 100,
+            approval_threshold_basis_points: 6_666,
+        }),
+        fee_distribution: Some(FeeDistribution::proposer_only().to_raw()),
+        oracle_liveness_parameters: Some(OracleLivenessParameters {
+            window_blocks: 1_000,
+            min_participation_basis_points: 5_000,
+            remove_inactive_validators: false,
         }),
     }
 }
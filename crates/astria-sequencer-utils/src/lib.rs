@@ -1,5 +1,6 @@
 pub mod activation_point_estimator;
 pub mod blob_parser;
+pub mod celestia_inspector;
 pub mod cli;
 pub mod genesis_example;
 pub mod genesis_parser;
@@ -2,6 +2,7 @@ use astria_eyre::eyre::Result;
 use astria_sequencer_utils::{
     activation_point_estimator,
     blob_parser,
+    celestia_inspector,
     cli::{
         self,
         Command,
@@ -19,5 +20,6 @@ async fn main() -> Result<()> {
         Command::GenerateGenesisState(args) => genesis_example::run(&args),
         Command::ParseBlob(args) => blob_parser::run(args),
         Command::EstimateActivationPoint(args) => activation_point_estimator::run(args).await,
+        Command::InspectCelestia(args) => celestia_inspector::run(args).await,
     }
 }
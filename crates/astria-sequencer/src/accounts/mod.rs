@@ -12,6 +12,7 @@ use astria_core::{
     protocol::transaction::v1::Transaction,
 };
 pub(crate) use state_ext::{
+    AccountTransaction,
     AssetBalance,
     StateReadExt,
     StateWriteExt,
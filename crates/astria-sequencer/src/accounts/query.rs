@@ -34,7 +34,10 @@ use tracing::{
 };
 
 use crate::{
-    accounts::StateReadExt as _,
+    accounts::{
+        AccountTransaction,
+        StateReadExt as _,
+    },
     app::StateReadExt as _,
     assets::StateReadExt as _,
 };
@@ -72,6 +75,59 @@ async fn get_trace_prefixed_account_balances<S: StateRead>(
     stream.try_collect::<Vec<_>>().await
 }
 
+#[instrument(skip_all, fields(%address, height), err(level = Level::DEBUG))]
+async fn get_trace_prefixed_historical_balances<S: StateRead>(
+    state: &S,
+    address: &Address,
+    height: u64,
+) -> Result<Vec<AssetBalance>> {
+    let mut balances = Vec::new();
+    for asset_balance in state
+        .get_historical_account_balances(address, height)
+        .await
+        .context("failed to read historical balances")?
+    {
+        let denom = ibc_to_trace(state, &asset_balance.asset)
+            .await
+            .context("failed to map ibc prefixed asset to trace prefixed")?
+            .into();
+        balances.push(AssetBalance {
+            denom,
+            balance: asset_balance.balance,
+        });
+    }
+    Ok(balances)
+}
+
+/// The maximum number of transactions returned per page by [`transactions_request`].
+const TRANSACTIONS_PAGE_SIZE: usize = 100;
+
+#[derive(serde::Serialize)]
+struct AccountTransactionsResponse {
+    height: u64,
+    page: usize,
+    transactions: Vec<AccountTransactionResponse>,
+}
+
+#[derive(serde::Serialize)]
+struct AccountTransactionResponse {
+    height: u64,
+    index: u32,
+    hash: String,
+    actions: Vec<String>,
+}
+
+impl From<AccountTransaction> for AccountTransactionResponse {
+    fn from(transaction: AccountTransaction) -> Self {
+        Self {
+            height: transaction.block_height,
+            index: transaction.index,
+            hash: hex::encode(transaction.transaction_id.get()),
+            actions: transaction.action_names,
+        }
+    }
+}
+
 /// Returns a list of [`AssetBalance`]s for the provided address. `AssetBalance`s are sorted
 /// alphabetically by [`asset::Denom`].
 #[instrument(skip_all)]
@@ -156,6 +212,133 @@ pub(crate) async fn nonce_request(
     }
 }
 
+/// Returns the balances of the provided address as of the height of the request. `AssetBalance`s
+/// are sorted alphabetically by [`asset::Denom`].
+///
+/// The balances are read from the account history index, and so are available for heights at
+/// which the verifiable state was already pruned.
+#[instrument(skip_all)]
+pub(crate) async fn balance_history_request(
+    storage: Storage,
+    request: request::Query,
+    params: Vec<(String, String)>,
+) -> response::Query {
+    use astria_core::protocol::account::v1::BalanceResponse;
+    let address = match parse_address(&params) {
+        Ok(address) => address,
+        Err(err_rsp) => return err_rsp,
+    };
+    let (snapshot, height) = match history_snapshot_and_height(&storage, &request).await {
+        Ok(tup) => tup,
+        Err(err_rsp) => return err_rsp,
+    };
+
+    let mut balances =
+        match get_trace_prefixed_historical_balances(&snapshot, &address, height.value()).await {
+            Ok(balances) => balances,
+            Err(err) => {
+                return response::Query {
+                    code: Code::Err(AbciErrorCode::INTERNAL_ERROR.value()),
+                    info: AbciErrorCode::INTERNAL_ERROR.info(),
+                    log: format!("failed getting balance history for provided address: {err:#}"),
+                    height,
+                    ..response::Query::default()
+                };
+            }
+        };
+
+    balances.sort_unstable_by(|a, b| a.denom.cmp(&b.denom));
+
+    let payload = BalanceResponse {
+        height: height.value(),
+        balances,
+    }
+    .into_raw()
+    .encode_to_vec()
+    .into();
+    response::Query {
+        code: 0.into(),
+        key: request.path.clone().into_bytes().into(),
+        value: payload,
+        height,
+        ..response::Query::default()
+    }
+}
+
+/// Returns a page of the transactions that touched the provided address at or before the height
+/// of the request, ordered from most recent.
+///
+/// The optional `page` path parameter selects the page, starting at 0. Each page contains at most
+/// [`TRANSACTIONS_PAGE_SIZE`] transactions.
+#[instrument(skip_all)]
+pub(crate) async fn transactions_request(
+    storage: Storage,
+    request: request::Query,
+    params: Vec<(String, String)>,
+) -> response::Query {
+    let address = match parse_address(&params) {
+        Ok(address) => address,
+        Err(err_rsp) => return err_rsp,
+    };
+    let page = match params.iter().find_map(|(k, v)| (k == "page").then_some(v)) {
+        None => 0,
+        Some(page) => match page.parse::<usize>() {
+            Ok(page) => page,
+            Err(err) => {
+                return response::Query {
+                    code: Code::Err(AbciErrorCode::INVALID_PARAMETER.value()),
+                    info: AbciErrorCode::INVALID_PARAMETER.info(),
+                    log: format!("page could not be parsed from provided parameter: {err:#}"),
+                    ..response::Query::default()
+                };
+            }
+        },
+    };
+    let (snapshot, height) = match history_snapshot_and_height(&storage, &request).await {
+        Ok(tup) => tup,
+        Err(err_rsp) => return err_rsp,
+    };
+
+    let transactions = match snapshot
+        .get_account_transactions(
+            &address,
+            height.value(),
+            page.saturating_mul(TRANSACTIONS_PAGE_SIZE),
+            TRANSACTIONS_PAGE_SIZE,
+        )
+        .await
+    {
+        Ok(transactions) => transactions,
+        Err(err) => {
+            return response::Query {
+                code: Code::Err(AbciErrorCode::INTERNAL_ERROR.value()),
+                info: AbciErrorCode::INTERNAL_ERROR.info(),
+                log: format!("failed getting transactions for provided address: {err:#}"),
+                height,
+                ..response::Query::default()
+            };
+        }
+    };
+
+    let payload = AccountTransactionsResponse {
+        height: height.value(),
+        page,
+        transactions: transactions
+            .into_iter()
+            .map(AccountTransactionResponse::from)
+            .collect(),
+    };
+    response::Query {
+        code: 0.into(),
+        key: request.path.clone().into_bytes().into(),
+        value: serde_json::to_vec(&payload)
+            .expect("object does not contain keys that don't map to json keys")
+            .into(),
+        height,
+        ..response::Query::default()
+    }
+}
+
 #[instrument(skip_all, fields(%height), err(level = Level::DEBUG))]
 async fn get_snapshot_and_height(storage: &Storage, height: Height) -> Result<(Snapshot, Height)> {
     let snapshot = match height.value() {
@@ -186,6 +369,23 @@ async fn preprocess_request(
     request: &request::Query,
     params: &[(String, String)],
 ) -> Result<(Address, Snapshot, Height), response::Query> {
+    let address = parse_address(params)?;
+    let (snapshot, height) = match get_snapshot_and_height(storage, request.height).await {
+        Ok(tup) => tup,
+        Err(err) => {
+            return Err(response::Query {
+                code: Code::Err(AbciErrorCode::INTERNAL_ERROR.value()),
+                info: AbciErrorCode::INTERNAL_ERROR.info(),
+                log: format!("failed to query internal storage for snapshot and height: {err:#}"),
+                ..response::Query::default()
+            });
+        }
+    };
+    Ok((address, snapshot, height))
+}
+
+/// Reads the `account` path parameter as an [`Address`].
+fn parse_address(params: &[(String, String)]) -> Result<Address, response::Query> {
     let Some(address) = params
         .iter()
         .find_map(|(k, v)| (k == "account").then_some(v))
@@ -197,7 +397,7 @@ async fn preprocess_request(
             ..response::Query::default()
         });
     };
-    let address = address
+    address
         .parse()
         .wrap_err("failed to parse argument as address")
         .map_err(|err| response::Query {
@@ -205,17 +405,53 @@ async fn preprocess_request(
             info: AbciErrorCode::INVALID_PARAMETER.info(),
             log: format!("address could not be constructed from provided parameter: {err:#}"),
             ..response::Query::default()
-        })?;
-    let (snapshot, height) = match get_snapshot_and_height(storage, request.height).await {
-        Ok(tup) => tup,
+        })
+}
+
+/// Returns the latest snapshot and the height up to which history should be read.
+///
+/// The account history index is only ever appended to, so unlike the other queries the latest
+/// snapshot can serve requests for any height, including heights for which the verifiable state
+/// was already pruned. A requested height of 0 means the latest height.
+#[instrument(skip_all)]
+async fn history_snapshot_and_height(
+    storage: &Storage,
+    request: &request::Query,
+) -> Result<(Snapshot, Height), response::Query> {
+    let snapshot = storage.latest_snapshot();
+    let latest_height = match snapshot.get_block_height().await {
+        Ok(height) => height,
         Err(err) => {
             return Err(response::Query {
                 code: Code::Err(AbciErrorCode::INTERNAL_ERROR.value()),
                 info: AbciErrorCode::INTERNAL_ERROR.info(),
-                log: format!("failed to query internal storage for snapshot and height: {err:#}"),
+                log: format!("failed to get block height from snapshot: {err:#}"),
                 ..response::Query::default()
             });
         }
     };
-    Ok((address, snapshot, height))
+    let height = match request.height.value() {
+        0 => latest_height,
+        requested if requested > latest_height => {
+            return Err(response::Query {
+                code: Code::Err(AbciErrorCode::INVALID_PARAMETER.value()),
+                info: AbciErrorCode::INVALID_PARAMETER.info(),
+                log: format!(
+                    "requested height `{requested}` is greater than the latest height \
+                     `{latest_height}`"
+                ),
+                ..response::Query::default()
+            });
+        }
+        requested => requested,
+    };
+    let height = Height::try_from(height).map_err(|err| response::Query {
+        code: Code::Err(AbciErrorCode::INTERNAL_ERROR.value()),
+        info: AbciErrorCode::INTERNAL_ERROR.info(),
+        log: format!(
+            "internal u64 block height does not fit into tendermint i64 `Height`: {err:#}"
+        ),
+        ..response::Query::default()
+    })?;
+    Ok((snapshot, height))
 }
@@ -1,7 +1,11 @@
 use std::{
     borrow::Cow,
+    collections::BTreeSet,
     fmt::Display,
-    pin::Pin,
+    pin::{
+        pin,
+        Pin,
+    },
     task::{
         ready,
         Context,
@@ -9,7 +13,17 @@ use std::{
     },
 };
 
-use astria_core::primitive::v1::asset;
+use astria_core::{
+    primitive::v1::{
+        asset,
+        TransactionId,
+        ADDRESS_LEN,
+    },
+    protocol::transaction::v1::{
+        action::ActionName as _,
+        Transaction,
+    },
+};
 use astria_eyre::{
     anyhow_to_eyre,
     eyre::{
@@ -23,7 +37,12 @@ use cnidarium::{
     StateRead,
     StateWrite,
 };
-use futures::Stream;
+use futures::{
+    future,
+    Stream,
+    StreamExt as _,
+    TryStreamExt as _,
+};
 use pin_project_lite::pin_project;
 use tracing::{
     instrument,
@@ -40,6 +59,7 @@ use super::storage::{
 use crate::{
     accounts::AddressBytes,
     storage::StoredValue,
+    transaction::StateReadExt as _,
 };
 
 pin_project! {
@@ -79,6 +99,25 @@ pub(crate) struct AssetBalance {
     pub(crate) balance: u128,
 }
 
+/// A transaction which touched an account, as recorded in the account's transaction history.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct AccountTransaction {
+    pub(crate) block_height: u64,
+    /// The index of the transaction among the transactions executed in its block.
+    pub(crate) index: u32,
+    pub(crate) transaction_id: TransactionId,
+    pub(crate) action_names: Vec<String>,
+}
+
+/// A transaction executed in the current block together with the accounts whose balances it
+/// changed. Kept in the ephemeral store until the block's account history is written.
+#[derive(Clone)]
+struct ExecutedTransaction {
+    transaction_id: TransactionId,
+    action_names: Vec<String>,
+    accounts: BTreeSet<[u8; ADDRESS_LEN]>,
+}
+
 pin_project! {
     /// A stream of IBC prefixed assets and their balances for a given account.
     pub(crate) struct AccountAssetBalancesStream<St> {
@@ -183,6 +222,69 @@ pub(crate) trait StateReadExt: StateRead + crate::assets::StateReadExt {
             .and_then(|value| storage::Nonce::try_from(value).map(u32::from))
             .wrap_err("invalid nonce bytes")
     }
+
+    /// Returns the balances of `address` as of block `height`.
+    ///
+    /// Returns no balances if no history was recorded for `address` at or before `height`.
+    #[instrument(skip_all, fields(address = %address.display_address(), height), err(level = Level::WARN))]
+    async fn get_historical_account_balances<T: AddressBytes>(
+        &self,
+        address: &T,
+        height: u64,
+    ) -> Result<Vec<AssetBalance>> {
+        let prefix = keys::balance_history_prefix(address);
+        let mut history = pin!(self
+            .nonverifiable_range_raw(
+                Some(prefix.as_bytes()),
+                keys::history_height_suffix(height).into_bytes()..,
+            )
+            .map_err(anyhow_to_eyre)
+            .wrap_err("failed to iterate over account balance history")?);
+        let Some((_, bytes)) = history
+            .try_next()
+            .await
+            .map_err(anyhow_to_eyre)
+            .wrap_err("failed reading raw account balance history from state")?
+        else {
+            return Ok(vec![]);
+        };
+        StoredValue::deserialize(&bytes)
+            .and_then(|value| storage::HistoricalBalances::try_from(value).map(Vec::from))
+            .wrap_err("invalid historical balances bytes")
+    }
+
+    /// Returns up to `limit` transactions which touched `address` at or before block `height`,
+    /// ordered from most recent and skipping the first `skip` transactions.
+    #[instrument(skip_all, fields(address = %address.display_address(), height, skip, limit), err(level = Level::WARN))]
+    async fn get_account_transactions<T: AddressBytes>(
+        &self,
+        address: &T,
+        height: u64,
+        skip: usize,
+        limit: usize,
+    ) -> Result<Vec<AccountTransaction>> {
+        let prefix = keys::transaction_history_prefix(address);
+        self.nonverifiable_range_raw(
+            Some(prefix.as_bytes()),
+            keys::history_height_suffix(height).into_bytes()..,
+        )
+        .map_err(anyhow_to_eyre)
+        .wrap_err("failed to iterate over account transaction history")?
+        .skip(skip)
+        .take(limit)
+        .map_err(|err| anyhow_to_eyre(err).wrap_err("failed reading from state"))
+        .and_then(|(_, bytes)| {
+            future::ready(
+                StoredValue::deserialize(&bytes)
+                    .and_then(|value| {
+                        storage::TransactionRecord::try_from(value).map(AccountTransaction::from)
+                    })
+                    .wrap_err("invalid transaction record bytes"),
+            )
+        })
+        .try_collect()
+        .await
+    }
 }
 
 impl<T: StateRead + ?Sized> StateReadExt for T {}
@@ -205,6 +307,109 @@ pub(crate) trait StateWriteExt: StateWrite {
             .serialize()
             .wrap_err("failed to serialize balance")?;
         self.put_raw(keys::balance(address, asset), bytes);
+        self.record_account_touched(address);
+        Ok(())
+    }
+
+    /// Records in the ephemeral store that the balance of `address` changed in the current block
+    /// and, if a transaction is being executed, in the current transaction.
+    fn record_account_touched<T: AddressBytes>(&mut self, address: &T) {
+        let address = *address.address_bytes();
+        let mut touched_in_block: BTreeSet<[u8; ADDRESS_LEN]> = self
+            .object_get(keys::ACCOUNTS_TOUCHED_IN_BLOCK)
+            .unwrap_or_default();
+        if touched_in_block.insert(address) {
+            self.object_put(keys::ACCOUNTS_TOUCHED_IN_BLOCK, touched_in_block);
+        }
+        if self.get_transaction_context().is_none() {
+            return;
+        }
+        let mut touched_in_transaction: BTreeSet<[u8; ADDRESS_LEN]> = self
+            .object_get(keys::ACCOUNTS_TOUCHED_IN_TRANSACTION)
+            .unwrap_or_default();
+        if touched_in_transaction.insert(address) {
+            self.object_put(
+                keys::ACCOUNTS_TOUCHED_IN_TRANSACTION,
+                touched_in_transaction,
+            );
+        }
+    }
+
+    /// Records `transaction` as executed in the current block.
+    ///
+    /// The transaction is attributed to its signer and to all accounts whose balances it changed.
+    /// Must be called after the transaction was successfully executed.
+    fn record_executed_transaction(&mut self, transaction: &Transaction) {
+        let mut accounts: BTreeSet<[u8; ADDRESS_LEN]> = self
+            .object_get(keys::ACCOUNTS_TOUCHED_IN_TRANSACTION)
+            .unwrap_or_default();
+        self.object_delete(keys::ACCOUNTS_TOUCHED_IN_TRANSACTION);
+        accounts.insert(*transaction.address_bytes());
+
+        let mut executed: Vec<ExecutedTransaction> = self
+            .object_get(keys::EXECUTED_TRANSACTIONS_IN_BLOCK)
+            .unwrap_or_default();
+        executed.push(ExecutedTransaction {
+            transaction_id: transaction.id(),
+            action_names: transaction
+                .actions()
+                .iter()
+                .map(|action| action.name().to_string())
+                .collect(),
+            accounts,
+        });
+        self.object_put(keys::EXECUTED_TRANSACTIONS_IN_BLOCK, executed);
+    }
+
+    /// Writes the balances of all accounts touched in the current block and the transactions
+    /// executed in it to the account history index at `height`.
+    ///
+    /// The index is kept in nonverifiable storage as it is not part of consensus.
+    #[instrument(skip_all, fields(height), err(level = Level::WARN))]
+    async fn put_account_history(&mut self, height: u64) -> Result<()> {
+        let touched_in_block: BTreeSet<[u8; ADDRESS_LEN]> = self
+            .object_get(keys::ACCOUNTS_TOUCHED_IN_BLOCK)
+            .unwrap_or_default();
+        for address in &touched_in_block {
+            let balances: Vec<AssetBalance> = self
+                .account_asset_balances(address)
+                .try_collect()
+                .await
+                .wrap_err("failed to read account balances")?;
+            let bytes = StoredValue::from(storage::HistoricalBalances::from(balances.as_slice()))
+                .serialize()
+                .wrap_err("failed to serialize historical balances")?;
+            self.nonverifiable_put_raw(keys::balance_history(address, height).into_bytes(), bytes);
+        }
+
+        let executed: Vec<ExecutedTransaction> = self
+            .object_get(keys::EXECUTED_TRANSACTIONS_IN_BLOCK)
+            .unwrap_or_default();
+        for (index, transaction) in (0..).zip(executed) {
+            let ExecutedTransaction {
+                transaction_id,
+                action_names,
+                accounts,
+            } = transaction;
+            let record = storage::TransactionRecord::from(&AccountTransaction {
+                block_height: height,
+                index,
+                transaction_id,
+                action_names,
+            });
+            let bytes = StoredValue::from(record)
+                .serialize()
+                .wrap_err("failed to serialize transaction record")?;
+            for address in &accounts {
+                self.nonverifiable_put_raw(
+                    keys::transaction_history(address, height, index).into_bytes(),
+                    bytes.clone(),
+                );
+            }
+        }
+
+        self.object_delete(keys::ACCOUNTS_TOUCHED_IN_BLOCK);
+        self.object_delete(keys::EXECUTED_TRANSACTIONS_IN_BLOCK);
         Ok(())
     }
 
@@ -763,4 +968,129 @@ mod tests {
             .await
             .expect_err("should not be able to subtract larger balance than what existed");
     }
+
+    #[tokio::test]
+    async fn historical_account_balances() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = StateDelta::new(snapshot);
+
+        let address = astria_address(&[42u8; 20]);
+        let asset = asset_0();
+
+        state.put_account_balance(&address, &asset, 10).unwrap();
+        state.put_account_history(1).await.unwrap();
+        state.put_account_balance(&address, &asset, 20).unwrap();
+        state.put_account_history(3).await.unwrap();
+
+        let balance_at = |height| {
+            let state = &state;
+            async move {
+                state
+                    .get_historical_account_balances(&address, height)
+                    .await
+                    .unwrap()
+                    .into_iter()
+                    .map(|balance| balance.balance)
+                    .collect::<Vec<_>>()
+            }
+        };
+        assert!(
+            balance_at(0).await.is_empty(),
+            "no balances should be recorded before the account was touched"
+        );
+        assert_eq!(vec![10], balance_at(1).await);
+        assert_eq!(vec![10], balance_at(2).await);
+        assert_eq!(vec![20], balance_at(3).await);
+        assert_eq!(vec![20], balance_at(10).await);
+    }
+
+    #[tokio::test]
+    async fn account_transactions_are_recorded_for_signer_and_touched_accounts() {
+        use astria_core::{
+            crypto::SigningKey,
+            protocol::transaction::v1::{
+                action::Transfer,
+                TransactionBody,
+            },
+        };
+
+        use crate::transaction::StateWriteExt as _;
+
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = StateDelta::new(snapshot);
+
+        let signer = SigningKey::from([1; 32]);
+        let signer_address = astria_address(&signer.address_bytes());
+        let recipient = astria_address(&[42u8; 20]);
+        let bystander = astria_address(&[43u8; 20]);
+
+        let mut transactions = vec![];
+        for height in [1, 2] {
+            let tx = TransactionBody::builder()
+                .actions(vec![Transfer {
+                    to: recipient,
+                    amount: 1,
+                    asset: nria().into(),
+                    fee_asset: nria().into(),
+                }
+                .into()])
+                .nonce(height)
+                .chain_id("test")
+                .try_build()
+                .unwrap()
+                .sign(&signer);
+            state.put_transaction_context(&tx);
+            state
+                .increase_balance(&recipient, &nria(), 1)
+                .await
+                .unwrap();
+            state.delete_current_transaction_context();
+            state.record_executed_transaction(&tx);
+            state.put_account_history(height.into()).await.unwrap();
+            transactions.push(tx);
+        }
+        // balance changes outside of a transaction are not attributed to one
+        state
+            .increase_balance(&bystander, &nria(), 1)
+            .await
+            .unwrap();
+        state.put_account_history(3).await.unwrap();
+
+        let expected = |height: u32| AccountTransaction {
+            block_height: height.into(),
+            index: 0,
+            transaction_id: transactions[usize::try_from(height).unwrap() - 1].id(),
+            action_names: vec!["Transfer".to_string()],
+        };
+        for address in [signer_address, recipient] {
+            assert_eq!(
+                vec![expected(2), expected(1)],
+                state
+                    .get_account_transactions(&address, 3, 0, 10)
+                    .await
+                    .unwrap()
+            );
+            assert_eq!(
+                vec![expected(1)],
+                state
+                    .get_account_transactions(&address, 1, 0, 10)
+                    .await
+                    .unwrap()
+            );
+            assert_eq!(
+                vec![expected(1)],
+                state
+                    .get_account_transactions(&address, 3, 1, 1)
+                    .await
+                    .unwrap()
+            );
+        }
+        assert!(state
+            .get_account_transactions(&bystander, 3, 0, 10)
+            .await
+            .unwrap()
+            .is_empty());
+    }
 }
@@ -18,6 +18,17 @@ use crate::{
 const COMPONENT_PREFIX: &str = "accounts/";
 const BALANCE_PREFIX: &str = "balance/";
 const NONCE: &str = "nonce";
+const BALANCE_HISTORY_PREFIX: &str = "balance_history/";
+const TRANSACTION_HISTORY_PREFIX: &str = "transactions/";
+
+/// Ephemeral key holding the accounts whose balances changed in the current block.
+pub(in crate::accounts) const ACCOUNTS_TOUCHED_IN_BLOCK: &str = "accounts/touched_in_block";
+/// Ephemeral key holding the accounts whose balances changed in the current transaction.
+pub(in crate::accounts) const ACCOUNTS_TOUCHED_IN_TRANSACTION: &str =
+    "accounts/touched_in_transaction";
+/// Ephemeral key holding the transactions executed in the current block.
+pub(in crate::accounts) const EXECUTED_TRANSACTIONS_IN_BLOCK: &str =
+    "accounts/executed_transactions_in_block";
 
 /// Example: `accounts/gGhH....zZ4=/balance/`.
 ///                   |base64 chars|
@@ -54,6 +65,71 @@ pub(in crate::accounts) fn nonce<TAddress: AddressBytes>(address: &TAddress) ->
     )
 }
 
+/// Example: `accounts/gGhH....zZ4=/balance_history/`.
+///                   |base64 chars|
+pub(in crate::accounts) fn balance_history_prefix<TAddress: AddressBytes>(
+    address: &TAddress,
+) -> String {
+    format!(
+        "{}/{BALANCE_HISTORY_PREFIX}",
+        AccountPrefixer::new(COMPONENT_PREFIX, address)
+    )
+}
+
+/// Example: `accounts/gGhH....zZ4=/balance_history/18446744073709551605`.
+///                   |base64 chars|
+///
+/// The suffix is the bitwise NOT of the block height (20 digits), so that iterating over the keys
+/// returns the most recent entries first.
+pub(in crate::accounts) fn balance_history<TAddress: AddressBytes>(
+    address: &TAddress,
+    height: u64,
+) -> String {
+    format!(
+        "{}{}",
+        balance_history_prefix(address),
+        history_height_suffix(height)
+    )
+}
+
+/// Example: `accounts/gGhH....zZ4=/transactions/`.
+///                   |base64 chars|
+pub(in crate::accounts) fn transaction_history_prefix<TAddress: AddressBytes>(
+    address: &TAddress,
+) -> String {
+    format!(
+        "{}/{TRANSACTION_HISTORY_PREFIX}",
+        AccountPrefixer::new(COMPONENT_PREFIX, address)
+    )
+}
+
+/// Example: `accounts/gGhH....zZ4=/transactions/18446744073709551605/4294967294`.
+///                   |base64 chars|
+///
+/// The suffix is the bitwise NOT of the block height (20 digits) followed by the bitwise NOT of
+/// the index of the transaction in its block (10 digits), so that iterating over the keys returns
+/// the most recent transactions first.
+pub(in crate::accounts) fn transaction_history<TAddress: AddressBytes>(
+    address: &TAddress,
+    height: u64,
+    index: u32,
+) -> String {
+    format!(
+        "{}{}/{:010}",
+        transaction_history_prefix(address),
+        history_height_suffix(height),
+        !index
+    )
+}
+
+/// Returns the part of a history key following its prefix that encodes the block `height`.
+///
+/// Iterating over a history prefix starting at this suffix yields all entries at `height` or
+/// lower, ordered from most recent.
+pub(in crate::accounts) fn history_height_suffix(height: u64) -> String {
+    format!("{:020}", !height)
+}
+
 pub(in crate::accounts) fn extract_asset_from_key(key: &str) -> Result<IbcPrefixed> {
     Ok(key
         .strip_prefix(COMPONENT_PREFIX)
@@ -87,12 +163,41 @@ mod tests {
     fn keys_should_not_change() {
         insta::assert_snapshot!("balance_key", balance(&address(), &asset()));
         insta::assert_snapshot!("nonce_key", nonce(&address()));
+        insta::assert_snapshot!("balance_history_key", balance_history(&address(), 10));
+        insta::assert_snapshot!(
+            "transaction_history_key",
+            transaction_history(&address(), 10, 1)
+        );
+        insta::assert_snapshot!("accounts_touched_in_block_key", ACCOUNTS_TOUCHED_IN_BLOCK);
+        insta::assert_snapshot!(
+            "accounts_touched_in_transaction_key",
+            ACCOUNTS_TOUCHED_IN_TRANSACTION
+        );
+        insta::assert_snapshot!(
+            "executed_transactions_in_block_key",
+            EXECUTED_TRANSACTIONS_IN_BLOCK
+        );
     }
 
     #[test]
     fn keys_should_have_component_prefix() {
         assert!(balance(&address(), &asset()).starts_with(COMPONENT_PREFIX));
         assert!(nonce(&address()).starts_with(COMPONENT_PREFIX));
+        assert!(balance_history(&address(), 10).starts_with(COMPONENT_PREFIX));
+        assert!(transaction_history(&address(), 10, 1).starts_with(COMPONENT_PREFIX));
+        assert!(ACCOUNTS_TOUCHED_IN_BLOCK.starts_with(COMPONENT_PREFIX));
+        assert!(ACCOUNTS_TOUCHED_IN_TRANSACTION.starts_with(COMPONENT_PREFIX));
+        assert!(EXECUTED_TRANSACTIONS_IN_BLOCK.starts_with(COMPONENT_PREFIX));
+    }
+
+    #[test]
+    fn history_keys_are_ordered_from_most_recent() {
+        assert!(balance_history(&address(), 11) < balance_history(&address(), 10));
+        assert!(transaction_history(&address(), 11, 0) < transaction_history(&address(), 10, 1));
+        assert!(transaction_history(&address(), 10, 1) < transaction_history(&address(), 10, 0));
+        assert!(balance_history(&address(), 10).starts_with(&balance_history_prefix(&address())));
+        assert!(transaction_history(&address(), 10, 1)
+            .starts_with(&transaction_history_prefix(&address())));
     }
 
     #[test]
@@ -4,5 +4,7 @@ mod values;
 pub(crate) use values::Value;
 pub(super) use values::{
     Balance,
+    HistoricalBalances,
     Nonce,
+    TransactionRecord,
 };
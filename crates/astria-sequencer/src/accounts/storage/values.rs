@@ -1,9 +1,18 @@
+use astria_core::primitive::v1::{
+    asset::IbcPrefixed,
+    TransactionId,
+};
 use astria_eyre::eyre::bail;
 use borsh::{
     BorshDeserialize,
     BorshSerialize,
 };
 
+use crate::accounts::{
+    AccountTransaction,
+    AssetBalance,
+};
+
 #[derive(Debug, BorshSerialize, BorshDeserialize)]
 pub(crate) struct Value(ValueImpl);
 
@@ -11,6 +20,8 @@ pub(crate) struct Value(ValueImpl);
 enum ValueImpl {
     Balance(Balance),
     Nonce(Nonce),
+    HistoricalBalances(HistoricalBalances),
+    TransactionRecord(TransactionRecord),
 }
 
 #[derive(Debug, BorshSerialize, BorshDeserialize)]
@@ -78,6 +89,108 @@ impl<'a> TryFrom<crate::storage::StoredValue<'a>> for Nonce {
     }
 }
 
+/// The balances of all assets held by an account at a given block height.
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub(in crate::accounts) struct HistoricalBalances(Vec<([u8; 32], u128)>);
+
+impl From<&[AssetBalance]> for HistoricalBalances {
+    fn from(balances: &[AssetBalance]) -> Self {
+        HistoricalBalances(
+            balances
+                .iter()
+                .map(|balance| (*balance.asset.as_bytes(), balance.balance))
+                .collect(),
+        )
+    }
+}
+
+impl From<HistoricalBalances> for Vec<AssetBalance> {
+    fn from(balances: HistoricalBalances) -> Self {
+        balances
+            .0
+            .into_iter()
+            .map(|(asset, balance)| AssetBalance {
+                asset: IbcPrefixed::new(asset),
+                balance,
+            })
+            .collect()
+    }
+}
+
+impl From<HistoricalBalances> for crate::storage::StoredValue<'_> {
+    fn from(balances: HistoricalBalances) -> Self {
+        crate::storage::StoredValue::Accounts(Value(ValueImpl::HistoricalBalances(balances)))
+    }
+}
+
+impl<'a> TryFrom<crate::storage::StoredValue<'a>> for HistoricalBalances {
+    type Error = astria_eyre::eyre::Error;
+
+    fn try_from(value: crate::storage::StoredValue<'a>) -> Result<Self, Self::Error> {
+        let crate::storage::StoredValue::Accounts(Value(ValueImpl::HistoricalBalances(balances))) =
+            value
+        else {
+            bail!(
+                "accounts stored value type mismatch: expected historical balances, found \
+                 {value:?}"
+            );
+        };
+        Ok(balances)
+    }
+}
+
+/// A transaction which touched an account.
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub(in crate::accounts) struct TransactionRecord {
+    block_height: u64,
+    index: u32,
+    transaction_id: [u8; 32],
+    action_names: Vec<String>,
+}
+
+impl From<&AccountTransaction> for TransactionRecord {
+    fn from(transaction: &AccountTransaction) -> Self {
+        TransactionRecord {
+            block_height: transaction.block_height,
+            index: transaction.index,
+            transaction_id: transaction.transaction_id.get(),
+            action_names: transaction.action_names.clone(),
+        }
+    }
+}
+
+impl From<TransactionRecord> for AccountTransaction {
+    fn from(record: TransactionRecord) -> Self {
+        AccountTransaction {
+            block_height: record.block_height,
+            index: record.index,
+            transaction_id: TransactionId::new(record.transaction_id),
+            action_names: record.action_names,
+        }
+    }
+}
+
+impl From<TransactionRecord> for crate::storage::StoredValue<'_> {
+    fn from(record: TransactionRecord) -> Self {
+        crate::storage::StoredValue::Accounts(Value(ValueImpl::TransactionRecord(record)))
+    }
+}
+
+impl<'a> TryFrom<crate::storage::StoredValue<'a>> for TransactionRecord {
+    type Error = astria_eyre::eyre::Error;
+
+    fn try_from(value: crate::storage::StoredValue<'a>) -> Result<Self, Self::Error> {
+        let crate::storage::StoredValue::Accounts(Value(ValueImpl::TransactionRecord(record))) =
+            value
+        else {
+            bail!(
+                "accounts stored value type mismatch: expected transaction record, found {value:?}"
+            );
+        };
+        Ok(record)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use insta::assert_snapshot;
@@ -95,6 +208,21 @@ mod tests {
             "value_impl_nonce",
             borsh_then_hex(&ValueImpl::Nonce(Nonce(0)))
         );
+        assert_snapshot!(
+            "value_impl_historical_balances",
+            borsh_then_hex(&ValueImpl::HistoricalBalances(HistoricalBalances(vec![(
+                [1; 32], 2
+            )])))
+        );
+        assert_snapshot!(
+            "value_impl_transaction_record",
+            borsh_then_hex(&ValueImpl::TransactionRecord(TransactionRecord {
+                block_height: 1,
+                index: 2,
+                transaction_id: [3; 32],
+                action_names: vec!["Transfer".to_string()],
+            }))
+        );
     }
 
     // Note: This test must be here instead of in `crate::storage` since `ValueImpl` is not
@@ -0,0 +1,175 @@
+use astria_core::protocol::transaction::v1::action::CancelTimelockedTransfer;
+use astria_eyre::eyre::{
+    ensure,
+    eyre,
+    Result,
+    WrapErr as _,
+};
+use async_trait::async_trait;
+use cnidarium::StateWrite;
+use tracing::{
+    instrument,
+    Level,
+};
+
+use crate::{
+    accounts::StateWriteExt as _,
+    action_handler::ActionHandler,
+    app::StateReadExt as _,
+    timelock::{
+        StateReadExt as _,
+        StateWriteExt as _,
+    },
+    transaction::StateReadExt as _,
+};
+
+#[async_trait]
+impl ActionHandler for CancelTimelockedTransfer {
+    async fn check_stateless(&self) -> Result<()> {
+        Ok(())
+    }
+
+    /// Returns the escrowed funds to the sender as long as the unlock point was not reached.
+    #[instrument(skip_all, err(level = Level::DEBUG))]
+    async fn check_and_execute<S: StateWrite>(&self, mut state: S) -> Result<()> {
+        let signer = state
+            .get_transaction_context()
+            .expect("transaction source must be present in state when executing an action")
+            .address_bytes();
+
+        let escrow = state
+            .get_timelocked_transfer(self.id)
+            .await
+            .wrap_err("failed to get timelocked transfer")?
+            .ok_or_else(|| eyre!("timelocked transfer with id `{}` does not exist", self.id))?;
+        ensure!(
+            signer == escrow.from,
+            "only the sender can cancel a timelocked transfer",
+        );
+
+        let height = state
+            .get_block_height()
+            .await
+            .wrap_err("failed to get block height")?;
+        let timestamp = state
+            .get_block_timestamp()
+            .await
+            .wrap_err("failed to get block timestamp")?;
+        ensure!(
+            !escrow.is_unlocked(height, timestamp),
+            "timelocked transfer is already unlocked",
+        );
+
+        state
+            .increase_balance(&escrow.from, &escrow.asset, escrow.amount)
+            .await
+            .wrap_err("failed to return escrowed funds to the sender")?;
+        state.delete_timelocked_transfer(self.id);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use astria_core::{
+        primitive::v1::TransactionId,
+        protocol::transaction::v1::action::TimelockUnlock,
+    };
+    use cnidarium::StateDelta;
+    use tendermint::Time;
+
+    use super::*;
+    use crate::{
+        accounts::StateReadExt as _,
+        app::StateWriteExt as _,
+        benchmark_and_test_utils::{
+            assert_eyre_error,
+            nria,
+        },
+        timelock::TimelockedTransferEscrow,
+        transaction::{
+            StateWriteExt as _,
+            TransactionContext,
+        },
+    };
+
+    const SENDER: [u8; 20] = [1; 20];
+    const RECIPIENT: [u8; 20] = [2; 20];
+
+    fn setup(state: &mut StateDelta<cnidarium::Snapshot>, signer: [u8; 20], height: u64) {
+        state.put_block_height(height).unwrap();
+        state
+            .put_block_timestamp(Time::from_unix_timestamp(1_000, 0).unwrap())
+            .unwrap();
+        state.put_transaction_context(TransactionContext {
+            address_bytes: signer,
+            transaction_id: TransactionId::new([0; 32]),
+            position_in_transaction: 0,
+        });
+        state
+            .put_timelocked_transfer(
+                0,
+                &TimelockedTransferEscrow {
+                    from: SENDER,
+                    to: RECIPIENT,
+                    asset: nria().to_ibc_prefixed(),
+                    amount: 100,
+                    unlock: TimelockUnlock::Height(10),
+                },
+            )
+            .unwrap();
+    }
+
+    fn action() -> CancelTimelockedTransfer {
+        CancelTimelockedTransfer {
+            id: 0,
+            fee_asset: nria().into(),
+        }
+    }
+
+    #[tokio::test]
+    async fn cancel_returns_funds_to_sender() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = StateDelta::new(snapshot);
+        setup(&mut state, SENDER, 9);
+
+        action().check_and_execute(&mut state).await.unwrap();
+
+        assert_eq!(
+            state.get_account_balance(&SENDER, &nria()).await.unwrap(),
+            100
+        );
+        assert_eq!(state.get_timelocked_transfer(0).await.unwrap(), None);
+        assert_eyre_error(
+            &action().check_and_execute(&mut state).await.unwrap_err(),
+            "timelocked transfer with id `0` does not exist",
+        );
+    }
+
+    #[tokio::test]
+    async fn cancel_fails_if_already_unlocked() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = StateDelta::new(snapshot);
+        setup(&mut state, SENDER, 10);
+
+        assert_eyre_error(
+            &action().check_and_execute(&mut state).await.unwrap_err(),
+            "timelocked transfer is already unlocked",
+        );
+    }
+
+    #[tokio::test]
+    async fn cancel_fails_if_signer_is_not_sender() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = StateDelta::new(snapshot);
+        setup(&mut state, RECIPIENT, 9);
+
+        assert_eyre_error(
+            &action().check_and_execute(&mut state).await.unwrap_err(),
+            "only the sender can cancel a timelocked transfer",
+        );
+    }
+}
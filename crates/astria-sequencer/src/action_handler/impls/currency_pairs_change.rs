@@ -6,7 +6,10 @@ use astria_core::{
             CurrencyPairNonce,
         },
     },
-    protocol::transaction::v1::action::CurrencyPairsChange,
+    protocol::transaction::v1::action::{
+        CurrencyPairParameters,
+        CurrencyPairsChange,
+    },
 };
 use astria_eyre::eyre::{
     ensure,
@@ -45,6 +48,9 @@ impl ActionHandler for CurrencyPairsChange {
             CurrencyPairsChange::Removal(currency_pairs) => {
                 check_and_execute_currency_pairs_removal(state, currency_pairs).await
             }
+            CurrencyPairsChange::ParametersUpdate(parameters) => {
+                check_and_execute_currency_pairs_parameters_update(state, parameters).await
+            }
         }
     }
 }
@@ -131,6 +137,38 @@ async fn check_and_execute_currency_pairs_removal<S: StateWrite>(
         .wrap_err("failed to put number of currency pairs")
 }
 
+async fn check_and_execute_currency_pairs_parameters_update<S: StateWrite>(
+    mut state: S,
+    parameters: &[CurrencyPairParameters],
+) -> Result<()> {
+    validate_signer_is_sudo(&state).await?;
+
+    for parameters in parameters {
+        let currency_pair = &parameters.currency_pair;
+        ensure!(
+            state
+                .get_currency_pair_state(currency_pair)
+                .await
+                .wrap_err("failed to get currency pair state")?
+                .is_some(),
+            "currency pair {currency_pair} does not exist",
+        );
+        let previous_twap_window_seconds = state
+            .get_currency_pair_parameters(currency_pair)
+            .await
+            .wrap_err("failed to get currency pair parameters")?
+            .map(|previous| previous.twap_window_seconds);
+        // the running average was computed over a different window, so restart it.
+        if previous_twap_window_seconds != Some(parameters.twap_window_seconds) {
+            state.delete_currency_pair_twap(currency_pair);
+        }
+        state
+            .put_currency_pair_parameters(parameters)
+            .wrap_err("failed to put currency pair parameters")?;
+    }
+    Ok(())
+}
+
 async fn validate_signer_is_sudo<S: StateRead>(state: S) -> Result<()> {
     let from = state
         .get_transaction_context()
@@ -149,7 +187,10 @@ mod test {
     use astria_core::{
         oracles::price_feed::{
             oracle::v2::CurrencyPairState,
-            types::v2::CurrencyPairId,
+            types::v2::{
+                CurrencyPairId,
+                Price,
+            },
         },
         primitive::v1::TransactionId,
     };
@@ -159,7 +200,10 @@ mod test {
     use crate::{
         app::test_utils::get_alice_signing_key,
         authority::StateWriteExt as _,
-        benchmark_and_test_utils::astria_address,
+        benchmark_and_test_utils::{
+            assert_eyre_error,
+            astria_address,
+        },
         transaction::{
             StateWriteExt,
             TransactionContext,
@@ -283,4 +327,64 @@ mod test {
 
         assert_eq!(state.get_num_currency_pairs().await.unwrap(), 1);
     }
+
+    #[tokio::test]
+    async fn update_currency_pairs_parameters() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = StateDelta::new(snapshot);
+
+        let alice = get_alice_signing_key();
+        let alice_address = astria_address(&alice.address_bytes());
+        state.put_transaction_context(TransactionContext {
+            address_bytes: alice.address_bytes(),
+            transaction_id: TransactionId::new([0; 32]),
+            position_in_transaction: 0,
+        });
+        state.put_sudo_address(alice_address).unwrap();
+
+        let btc_usd: CurrencyPair = "BTC/USD".parse().unwrap();
+        state
+            .put_currency_pair_state(
+                btc_usd.clone(),
+                CurrencyPairState {
+                    price: None,
+                    nonce: CurrencyPairNonce::new(0),
+                    id: CurrencyPairId::new(0),
+                },
+            )
+            .unwrap();
+        state
+            .put_currency_pair_twap(&btc_usd, Price::new(100))
+            .unwrap();
+        let parameters = CurrencyPairParameters {
+            currency_pair: btc_usd.clone(),
+            max_age_seconds: 60,
+            max_deviation_basis_points: 500,
+            twap_window_seconds: 600,
+        };
+
+        let action = CurrencyPairsChange::ParametersUpdate(vec![parameters.clone()]);
+        action.check_and_execute(&mut state).await.unwrap();
+        assert_eq!(
+            state.get_currency_pair_parameters(&btc_usd).await.unwrap(),
+            Some(parameters.clone()),
+        );
+        // the twap window changed, so the running average was restarted
+        assert!(state
+            .get_currency_pair_twap(&btc_usd)
+            .await
+            .unwrap()
+            .is_none());
+
+        // parameters of pairs not in the oracle cannot be set
+        let action = CurrencyPairsChange::ParametersUpdate(vec![CurrencyPairParameters {
+            currency_pair: "ETH/USD".parse().unwrap(),
+            ..parameters
+        }]);
+        assert_eyre_error(
+            &action.check_and_execute(&mut state).await.unwrap_err(),
+            "currency pair ETH/USD does not exist",
+        );
+    }
 }
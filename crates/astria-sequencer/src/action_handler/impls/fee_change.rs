@@ -94,6 +94,27 @@ impl ActionHandler for FeeChange {
             Self::MarketsChange(fees) => state
                 .put_fees(*fees)
                 .wrap_err("failed to put markets change fees"),
+            Self::RegisterMultisig(fees) => state
+                .put_fees(*fees)
+                .wrap_err("failed to put register multisig fees"),
+            Self::TimelockedTransfer(fees) => state
+                .put_fees(*fees)
+                .wrap_err("failed to put timelocked transfer fees"),
+            Self::ClaimTimelockedTransfer(fees) => state
+                .put_fees(*fees)
+                .wrap_err("failed to put claim timelocked transfer fees"),
+            Self::CancelTimelockedTransfer(fees) => state
+                .put_fees(*fees)
+                .wrap_err("failed to put cancel timelocked transfer fees"),
+            Self::SubmitProposal(fees) => state
+                .put_fees(*fees)
+                .wrap_err("failed to put submit proposal fees"),
+            Self::VoteOnProposal(fees) => state
+                .put_fees(*fees)
+                .wrap_err("failed to put vote on proposal fees"),
+            Self::FeeDistributionChange(fees) => state
+                .put_fees(*fees)
+                .wrap_err("failed to put fee distribution change fees"),
         }
     }
 }
@@ -243,6 +264,41 @@ mod tests {
         test_fee_change_action::<MarketsChange>().await;
     }
 
+    #[tokio::test]
+    async fn register_multisig_fee_change_action_executes_as_expected() {
+        test_fee_change_action::<RegisterMultisig>().await;
+    }
+
+    #[tokio::test]
+    async fn timelocked_transfer_fee_change_action_executes_as_expected() {
+        test_fee_change_action::<TimelockedTransfer>().await;
+    }
+
+    #[tokio::test]
+    async fn claim_timelocked_transfer_fee_change_action_executes_as_expected() {
+        test_fee_change_action::<ClaimTimelockedTransfer>().await;
+    }
+
+    #[tokio::test]
+    async fn cancel_timelocked_transfer_fee_change_action_executes_as_expected() {
+        test_fee_change_action::<CancelTimelockedTransfer>().await;
+    }
+
+    #[tokio::test]
+    async fn submit_proposal_fee_change_action_executes_as_expected() {
+        test_fee_change_action::<SubmitProposal>().await;
+    }
+
+    #[tokio::test]
+    async fn vote_on_proposal_fee_change_action_executes_as_expected() {
+        test_fee_change_action::<VoteOnProposal>().await;
+    }
+
+    #[tokio::test]
+    async fn fee_distribution_change_fee_change_action_executes_as_expected() {
+        test_fee_change_action::<FeeDistributionChange>().await;
+    }
+
     async fn test_fee_change_action<'a, F>()
     where
         F: FeeHandler,
@@ -0,0 +1,175 @@
+use astria_core::protocol::transaction::v1::action::FeeDistributionChange;
+use astria_eyre::eyre::{
+    ensure,
+    Result,
+    WrapErr as _,
+};
+use async_trait::async_trait;
+use cnidarium::StateWrite;
+use tracing::{
+    instrument,
+    Level,
+};
+
+use crate::{
+    action_handler::ActionHandler,
+    address::StateReadExt as _,
+    authority::StateReadExt as _,
+    fees::{
+        Distribution,
+        StateWriteExt as _,
+    },
+    transaction::StateReadExt as _,
+};
+
+#[async_trait]
+impl ActionHandler for FeeDistributionChange {
+    async fn check_stateless(&self) -> Result<()> {
+        Ok(())
+    }
+
+    /// check that the signer of the transaction is the current sudo address,
+    /// as only that address can change how fees are distributed
+    #[instrument(skip_all, err(level = Level::DEBUG))]
+    async fn check_and_execute<S: StateWrite>(&self, mut state: S) -> Result<()> {
+        let from = state
+            .get_transaction_context()
+            .expect("transaction source must be present in state when executing an action")
+            .address_bytes();
+        if let Some(community_pool_address) = &self.new_distribution.community_pool_address {
+            state
+                .ensure_base_prefix(community_pool_address)
+                .await
+                .wrap_err("community pool address has an unsupported prefix")?;
+        }
+        // ensure signer is the valid `sudo` key in state
+        let sudo_address = state
+            .get_sudo_address()
+            .await
+            .wrap_err("failed to get sudo address from state")?;
+        ensure!(sudo_address == from, "signer is not the sudo key");
+        state
+            .put_fee_distribution(&Distribution::from(&self.new_distribution))
+            .wrap_err("failed to put fee distribution in state")?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use astria_core::{
+        primitive::v1::{
+            Address,
+            TransactionId,
+        },
+        protocol::fees::v1::FeeDistribution,
+    };
+
+    use super::*;
+    use crate::{
+        accounts::AddressBytes as _,
+        address::StateWriteExt as _,
+        authority::StateWriteExt as _,
+        benchmark_and_test_utils::{
+            assert_eyre_error,
+            astria_address,
+            ASTRIA_PREFIX,
+        },
+        fees::StateReadExt as _,
+        transaction::{
+            StateWriteExt as _,
+            TransactionContext,
+        },
+    };
+
+    fn distribution(community_pool_address: Address) -> FeeDistribution {
+        FeeDistribution {
+            proposer_basis_points: 5_000,
+            validators_basis_points: 3_000,
+            community_pool_basis_points: 1_500,
+            burn_basis_points: 500,
+            community_pool_address: Some(community_pool_address),
+        }
+    }
+
+    #[tokio::test]
+    async fn fee_distribution_change_executes_as_expected() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = cnidarium::StateDelta::new(snapshot);
+
+        let sudo_address = astria_address(&[0; 20]);
+        state.put_base_prefix(ASTRIA_PREFIX.to_string()).unwrap();
+        state.put_sudo_address(sudo_address).unwrap();
+        state.put_transaction_context(TransactionContext {
+            address_bytes: *sudo_address.address_bytes(),
+            transaction_id: TransactionId::new([0; 32]),
+            position_in_transaction: 0,
+        });
+        assert!(state.get_fee_distribution().await.unwrap().is_none());
+
+        let action = FeeDistributionChange {
+            new_distribution: distribution(astria_address(&[1; 20])),
+        };
+        action.check_and_execute(&mut state).await.unwrap();
+
+        assert_eq!(
+            state.get_fee_distribution().await.unwrap(),
+            Some(Distribution::from(&action.new_distribution)),
+        );
+    }
+
+    #[tokio::test]
+    async fn fee_distribution_change_fails_if_community_pool_is_not_base_prefixed() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = cnidarium::StateDelta::new(snapshot);
+
+        let different_prefix = "different_prefix";
+        let community_pool_address = Address::builder()
+            .prefix(different_prefix)
+            .array([1; 20])
+            .try_build()
+            .unwrap();
+        state.put_base_prefix(ASTRIA_PREFIX.to_string()).unwrap();
+        state.put_transaction_context(TransactionContext {
+            address_bytes: [0; 20],
+            transaction_id: TransactionId::new([0; 32]),
+            position_in_transaction: 0,
+        });
+
+        let action = FeeDistributionChange {
+            new_distribution: distribution(community_pool_address),
+        };
+        assert_eyre_error(
+            &action.check_and_execute(&mut state).await.unwrap_err(),
+            &format!(
+                "address has prefix `{different_prefix}` but only `{ASTRIA_PREFIX}` is permitted"
+            ),
+        );
+    }
+
+    #[tokio::test]
+    async fn fee_distribution_change_fails_if_signer_is_not_sudo() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = cnidarium::StateDelta::new(snapshot);
+
+        state.put_base_prefix(ASTRIA_PREFIX.to_string()).unwrap();
+        state.put_sudo_address(astria_address(&[0; 20])).unwrap();
+        state.put_transaction_context(TransactionContext {
+            address_bytes: [2; 20],
+            transaction_id: TransactionId::new([0; 32]),
+            position_in_transaction: 0,
+        });
+
+        let action = FeeDistributionChange {
+            new_distribution: distribution(astria_address(&[1; 20])),
+        };
+        assert_eyre_error(
+            &action.check_and_execute(&mut state).await.unwrap_err(),
+            "signer is not the sudo key",
+        );
+        assert!(state.get_fee_distribution().await.unwrap().is_none());
+    }
+}
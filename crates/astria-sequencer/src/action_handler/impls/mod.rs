@@ -2,19 +2,26 @@ pub(crate) mod bridge_lock;
 pub(crate) mod bridge_sudo_change;
 pub(crate) mod bridge_transfer;
 pub(crate) mod bridge_unlock;
+pub(crate) mod cancel_timelocked_transfer;
+pub(crate) mod claim_timelocked_transfer;
 pub(crate) mod currency_pairs_change;
 pub(crate) mod fee_asset_change;
 pub(crate) mod fee_change;
+pub(crate) mod fee_distribution_change;
 pub(crate) mod ibc_relayer_change;
 pub(crate) mod ibc_sudo_change;
 pub(crate) mod ics20_withdrawal;
 pub(crate) mod init_bridge_account;
 pub(crate) mod markets_change;
 pub(crate) mod recover_ibc_client;
+pub(crate) mod register_multisig;
 pub(crate) mod rollup_data_submission;
+pub(crate) mod submit_proposal;
 pub(crate) mod sudo_address_change;
 #[cfg(test)]
 pub(crate) mod test_utils;
+pub(crate) mod timelocked_transfer;
 pub(crate) mod transaction;
 pub(crate) mod transfer;
 pub(crate) mod validator_update;
+pub(crate) mod vote_on_proposal;
@@ -0,0 +1,150 @@
+use astria_core::protocol::transaction::v1::action::RegisterMultisig;
+use astria_eyre::eyre::{
+    ensure,
+    Result,
+    WrapErr as _,
+};
+use async_trait::async_trait;
+use cnidarium::StateWrite;
+use tracing::{
+    instrument,
+    Level,
+};
+
+use crate::{
+    action_handler::ActionHandler,
+    bridge::StateReadExt as _,
+    multisig::{
+        MultisigPolicy,
+        StateWriteExt as _,
+    },
+    transaction::StateReadExt as _,
+};
+
+#[async_trait]
+impl ActionHandler for RegisterMultisig {
+    async fn check_stateless(&self) -> Result<()> {
+        Ok(())
+    }
+
+    /// Converts the signer's account into a multisig account governed by the policy in this
+    /// action.
+    ///
+    /// If the signer already is a multisig account its policy is replaced. Because transactions
+    /// from a multisig account must carry signatures meeting its current policy (see
+    /// [`crate::transaction::check_multisig_authorization`]), only the existing members can
+    /// change it.
+    #[instrument(skip_all, err(level = Level::DEBUG))]
+    async fn check_and_execute<S: StateWrite>(&self, mut state: S) -> Result<()> {
+        let from = state
+            .get_transaction_context()
+            .expect("transaction source must be present in state when executing an action")
+            .address_bytes();
+
+        // bridge accounts have their own sudo and withdrawer authorization; mixing the two
+        // would make it ambiguous who controls the account.
+        ensure!(
+            !state
+                .is_a_bridge_account(&from)
+                .await
+                .wrap_err("failed to check if signer is a bridge account")?,
+            "bridge accounts cannot be registered as multisig accounts"
+        );
+
+        state
+            .put_multisig_policy(&from, &MultisigPolicy::from(self))
+            .wrap_err("failed to put multisig policy")?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use astria_core::{
+        crypto::SigningKey,
+        primitive::v1::{
+            RollupId,
+            TransactionId,
+        },
+    };
+
+    use super::*;
+    use crate::{
+        address::StateWriteExt as _,
+        benchmark_and_test_utils::{
+            assert_eyre_error,
+            astria_address,
+            nria,
+            ASTRIA_PREFIX,
+        },
+        bridge::StateWriteExt as _,
+        multisig::StateReadExt as _,
+        transaction::{
+            StateWriteExt as _,
+            TransactionContext,
+        },
+    };
+
+    fn action() -> RegisterMultisig {
+        RegisterMultisig::try_new(
+            vec![
+                SigningKey::from([1; 32]).verification_key(),
+                SigningKey::from([2; 32]).verification_key(),
+                SigningKey::from([3; 32]).verification_key(),
+            ],
+            2,
+            nria().into(),
+        )
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn register_multisig_executes_as_expected() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = cnidarium::StateDelta::new(snapshot);
+
+        state.put_base_prefix(ASTRIA_PREFIX.to_string()).unwrap();
+        let multisig_address = [1; 20];
+        state.put_transaction_context(TransactionContext {
+            address_bytes: multisig_address,
+            transaction_id: TransactionId::new([0; 32]),
+            position_in_transaction: 0,
+        });
+
+        let action = action();
+        action.check_and_execute(&mut state).await.unwrap();
+
+        assert_eq!(
+            state
+                .get_multisig_policy(&multisig_address)
+                .await
+                .unwrap()
+                .unwrap(),
+            MultisigPolicy::from(&action),
+        );
+    }
+
+    #[tokio::test]
+    async fn register_multisig_fails_for_bridge_account() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = cnidarium::StateDelta::new(snapshot);
+
+        state.put_base_prefix(ASTRIA_PREFIX.to_string()).unwrap();
+        let bridge_address = astria_address(&[1; 20]);
+        state
+            .put_bridge_account_rollup_id(&bridge_address, RollupId::new([0; 32]))
+            .unwrap();
+        state.put_transaction_context(TransactionContext {
+            address_bytes: bridge_address.bytes(),
+            transaction_id: TransactionId::new([0; 32]),
+            position_in_transaction: 0,
+        });
+
+        assert_eyre_error(
+            &action().check_and_execute(&mut state).await.unwrap_err(),
+            "bridge accounts cannot be registered as multisig accounts",
+        );
+    }
+}
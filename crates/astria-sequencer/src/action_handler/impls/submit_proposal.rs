@@ -0,0 +1,238 @@
+use astria_core::protocol::transaction::v1::action::SubmitProposal;
+use astria_eyre::eyre::{
+    ensure,
+    OptionExt as _,
+    Result,
+    WrapErr as _,
+};
+use async_trait::async_trait;
+use cnidarium::StateWrite;
+use tendermint::abci::Event;
+use tracing::{
+    instrument,
+    Level,
+};
+
+use crate::{
+    action_handler::ActionHandler,
+    app::StateReadExt as _,
+    governance::{
+        check_stateless_governable_action,
+        Proposal,
+        ProposalStatus,
+        StateReadExt as _,
+        StateWriteExt as _,
+    },
+    transaction::StateReadExt as _,
+};
+
+#[async_trait]
+impl ActionHandler for SubmitProposal {
+    async fn check_stateless(&self) -> Result<()> {
+        check_stateless_governable_action(&self.action)
+            .await
+            .wrap_err("stateless check failed for proposed action")
+    }
+
+    /// Opens a proposal for the wrapped action and emits a `tx.proposalSubmitted` event carrying
+    /// the proposal's ID. The signer must be one of the configured proposers.
+    #[instrument(skip_all, err(level = Level::DEBUG))]
+    async fn check_and_execute<S: StateWrite>(&self, mut state: S) -> Result<()> {
+        let transaction_context = state
+            .get_transaction_context()
+            .expect("transaction source must be present in state when executing an action");
+        let from = transaction_context.address_bytes();
+
+        let params = state
+            .get_governance_parameters()
+            .await
+            .wrap_err("failed to get governance parameters")?
+            .ok_or_eyre("governance is not enabled")?;
+        ensure!(
+            params.is_proposer(&from),
+            "signer is not a governance proposer"
+        );
+
+        let height = state
+            .get_block_height()
+            .await
+            .wrap_err("failed to get block height")?;
+        let voting_end_height = height
+            .checked_add(params.voting_period_blocks)
+            .ok_or_eyre("end of voting period overflowed")?;
+
+        let id = state
+            .get_next_proposal_id()
+            .await
+            .wrap_err("failed to get next proposal id")?;
+        let next_id = id.checked_add(1).ok_or_eyre("proposal id overflowed")?;
+        state
+            .put_next_proposal_id(next_id)
+            .wrap_err("failed to put next proposal id")?;
+        state
+            .put_proposal(
+                id,
+                &Proposal {
+                    proposer: from,
+                    action: (*self.action).clone(),
+                    submitted_height: height,
+                    voting_end_height,
+                    status: ProposalStatus::Open,
+                    votes: std::collections::BTreeMap::new(),
+                },
+            )
+            .wrap_err("failed to put proposal")?;
+        let mut open_proposal_ids = state
+            .get_open_proposal_ids()
+            .await
+            .wrap_err("failed to get open proposal ids")?;
+        open_proposal_ids.push(id);
+        state
+            .put_open_proposal_ids(&open_proposal_ids)
+            .wrap_err("failed to put open proposal ids")?;
+
+        state.record(construct_proposal_submitted_event(
+            id,
+            transaction_context.position_in_transaction,
+        ));
+        Ok(())
+    }
+}
+
+fn construct_proposal_submitted_event(id: u64, position_in_transaction: u64) -> Event {
+    Event::new(
+        "tx.proposalSubmitted",
+        [
+            ("id", id.to_string()),
+            ("positionInTransaction", position_in_transaction.to_string()),
+        ],
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use astria_core::{
+        primitive::v1::TransactionId,
+        protocol::transaction::v1::{
+            action::{
+                FeeChange,
+                SudoAddressChange,
+            },
+            Action,
+        },
+    };
+    use cnidarium::StateDelta;
+
+    use super::*;
+    use crate::{
+        app::StateWriteExt as _,
+        benchmark_and_test_utils::{
+            assert_eyre_error,
+            astria_address,
+            nria,
+        },
+        governance::Parameters,
+        transaction::{
+            StateWriteExt as _,
+            TransactionContext,
+        },
+    };
+
+    fn action() -> SubmitProposal {
+        SubmitProposal {
+            action: Box::new(Action::SudoAddressChange(SudoAddressChange {
+                new_address: astria_address(&[9; 20]),
+            })),
+            fee_asset: nria().into(),
+        }
+    }
+
+    fn setup(state: &mut StateDelta<cnidarium::Snapshot>, signer: [u8; 20]) {
+        state.put_block_height(5).unwrap();
+        state
+            .put_governance_parameters(&Parameters {
+                proposers: vec![[1; 20]],
+                council: vec![],
+                voting_period_blocks: 10,
+                approval_threshold_basis_points: 5_000,
+            })
+            .unwrap();
+        state.put_transaction_context(TransactionContext {
+            address_bytes: signer,
+            transaction_id: TransactionId::new([0; 32]),
+            position_in_transaction: 0,
+        });
+    }
+
+    #[tokio::test]
+    async fn submit_proposal_opens_proposal() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let mut state = StateDelta::new(storage.latest_snapshot());
+        setup(&mut state, [1; 20]);
+
+        let mut state_tx = StateDelta::new(&mut state);
+        action().check_and_execute(&mut state_tx).await.unwrap();
+        action().check_and_execute(&mut state_tx).await.unwrap();
+        let (_, events) = state_tx.apply();
+        assert_eq!(
+            events,
+            vec![
+                construct_proposal_submitted_event(0, 0),
+                construct_proposal_submitted_event(1, 0),
+            ]
+        );
+
+        assert_eq!(state.get_next_proposal_id().await.unwrap(), 2);
+        assert_eq!(state.get_open_proposal_ids().await.unwrap(), vec![0, 1]);
+        assert_eq!(
+            state.get_proposal(0).await.unwrap(),
+            Some(Proposal {
+                proposer: [1; 20],
+                action: *action().action,
+                submitted_height: 5,
+                voting_end_height: 15,
+                status: ProposalStatus::Open,
+                votes: std::collections::BTreeMap::new(),
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn submit_proposal_fails_if_signer_is_not_proposer() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let mut state = StateDelta::new(storage.latest_snapshot());
+        setup(&mut state, [2; 20]);
+
+        assert_eyre_error(
+            &action().check_and_execute(&mut state).await.unwrap_err(),
+            "signer is not a governance proposer",
+        );
+    }
+
+    #[tokio::test]
+    async fn submit_proposal_fails_if_governance_is_not_enabled() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let mut state = StateDelta::new(storage.latest_snapshot());
+        state.put_transaction_context(TransactionContext {
+            address_bytes: [1; 20],
+            transaction_id: TransactionId::new([0; 32]),
+            position_in_transaction: 0,
+        });
+
+        assert_eyre_error(
+            &action().check_and_execute(&mut state).await.unwrap_err(),
+            "governance is not enabled",
+        );
+    }
+
+    #[tokio::test]
+    async fn submit_proposal_runs_stateless_checks_of_proposed_action() {
+        let action = SubmitProposal {
+            action: Box::new(Action::FeeChange(FeeChange::Transfer(
+                astria_core::protocol::fees::v1::FeeComponents::new(1, 1),
+            ))),
+            fee_asset: nria().into(),
+        };
+        action.check_stateless().await.unwrap();
+    }
+}
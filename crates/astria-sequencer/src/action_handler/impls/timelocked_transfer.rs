@@ -0,0 +1,222 @@
+use astria_core::protocol::transaction::v1::action::TimelockedTransfer;
+use astria_eyre::eyre::{
+    ensure,
+    OptionExt as _,
+    Result,
+    WrapErr as _,
+};
+use async_trait::async_trait;
+use cnidarium::StateWrite;
+use tendermint::abci::Event;
+use tracing::{
+    instrument,
+    Level,
+};
+
+use crate::{
+    accounts::StateWriteExt as _,
+    action_handler::ActionHandler,
+    address::StateReadExt as _,
+    app::StateReadExt as _,
+    bridge::StateReadExt as _,
+    timelock::{
+        StateReadExt as _,
+        StateWriteExt as _,
+        TimelockedTransferEscrow,
+    },
+    transaction::StateReadExt as _,
+};
+
+#[async_trait]
+impl ActionHandler for TimelockedTransfer {
+    async fn check_stateless(&self) -> Result<()> {
+        ensure!(self.amount > 0, "amount must be greater than zero");
+        Ok(())
+    }
+
+    /// Moves `amount` of `asset` from the signer's account into a new escrow and emits a
+    /// `tx.timelockedTransfer` event carrying the escrow's ID.
+    #[instrument(skip_all, err(level = Level::DEBUG))]
+    async fn check_and_execute<S: StateWrite>(&self, mut state: S) -> Result<()> {
+        let transaction_context = state
+            .get_transaction_context()
+            .expect("transaction source must be present in state when executing an action");
+        let from = transaction_context.address_bytes();
+
+        state
+            .ensure_base_prefix(&self.to)
+            .await
+            .wrap_err("failed check for base prefix of destination address")?;
+        ensure!(
+            state
+                .get_bridge_account_rollup_id(&from)
+                .await
+                .wrap_err("failed to get bridge account rollup id")?
+                .is_none(),
+            "cannot transfer out of bridge account; BridgeUnlock must be used",
+        );
+
+        let escrow = TimelockedTransferEscrow {
+            from,
+            to: self.to.bytes(),
+            asset: self.asset.to_ibc_prefixed(),
+            amount: self.amount,
+            unlock: self.unlock,
+        };
+        let height = state
+            .get_block_height()
+            .await
+            .wrap_err("failed to get block height")?;
+        let timestamp = state
+            .get_block_timestamp()
+            .await
+            .wrap_err("failed to get block timestamp")?;
+        ensure!(
+            !escrow.is_unlocked(height, timestamp),
+            "unlock point of timelocked transfer must be in the future",
+        );
+
+        state
+            .decrease_balance(&from, &self.asset, self.amount)
+            .await
+            .wrap_err("failed to move funds into escrow")?;
+
+        let id = state
+            .get_next_timelocked_transfer_id()
+            .await
+            .wrap_err("failed to get next timelocked transfer id")?;
+        let next_id = id
+            .checked_add(1)
+            .ok_or_eyre("timelocked transfer id overflowed")?;
+        state
+            .put_next_timelocked_transfer_id(next_id)
+            .wrap_err("failed to put next timelocked transfer id")?;
+        state
+            .put_timelocked_transfer(id, &escrow)
+            .wrap_err("failed to put timelocked transfer")?;
+
+        state.record(construct_timelocked_transfer_event(
+            id,
+            transaction_context.position_in_transaction,
+        ));
+        Ok(())
+    }
+}
+
+fn construct_timelocked_transfer_event(id: u64, position_in_transaction: u64) -> Event {
+    Event::new(
+        "tx.timelockedTransfer",
+        [
+            ("id", id.to_string()),
+            ("positionInTransaction", position_in_transaction.to_string()),
+        ],
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use astria_core::{
+        primitive::v1::TransactionId,
+        protocol::transaction::v1::action::TimelockUnlock,
+    };
+    use cnidarium::StateDelta;
+    use tendermint::Time;
+
+    use super::*;
+    use crate::{
+        accounts::StateReadExt as _,
+        address::StateWriteExt as _,
+        app::StateWriteExt as _,
+        benchmark_and_test_utils::{
+            assert_eyre_error,
+            astria_address,
+            nria,
+            ASTRIA_PREFIX,
+        },
+        transaction::{
+            StateWriteExt as _,
+            TransactionContext,
+        },
+    };
+
+    fn action(unlock: TimelockUnlock) -> TimelockedTransfer {
+        TimelockedTransfer {
+            to: astria_address(&[2; 20]),
+            amount: 100,
+            asset: nria().into(),
+            fee_asset: nria().into(),
+            unlock,
+        }
+    }
+
+    fn setup(state: &mut StateDelta<cnidarium::Snapshot>) {
+        state.put_base_prefix(ASTRIA_PREFIX.to_string()).unwrap();
+        state.put_block_height(5).unwrap();
+        state
+            .put_block_timestamp(Time::from_unix_timestamp(1_000, 0).unwrap())
+            .unwrap();
+        state.put_transaction_context(TransactionContext {
+            address_bytes: [1; 20],
+            transaction_id: TransactionId::new([0; 32]),
+            position_in_transaction: 0,
+        });
+        state.put_account_balance(&[1; 20], &nria(), 150).unwrap();
+    }
+
+    #[tokio::test]
+    async fn timelocked_transfer_escrows_funds() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = StateDelta::new(snapshot);
+        setup(&mut state);
+
+        let mut state_tx = StateDelta::new(&mut state);
+        action(TimelockUnlock::Height(10))
+            .check_and_execute(&mut state_tx)
+            .await
+            .unwrap();
+        let (_, events) = state_tx.apply();
+        assert_eq!(events, vec![construct_timelocked_transfer_event(0, 0)]);
+
+        assert_eq!(
+            state.get_account_balance(&[1; 20], &nria()).await.unwrap(),
+            50
+        );
+        assert_eq!(state.get_next_timelocked_transfer_id().await.unwrap(), 1);
+        assert_eq!(
+            state.get_timelocked_transfer(0).await.unwrap(),
+            Some(TimelockedTransferEscrow {
+                from: [1; 20],
+                to: [2; 20],
+                asset: nria().to_ibc_prefixed(),
+                amount: 100,
+                unlock: TimelockUnlock::Height(10),
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn timelocked_transfer_fails_if_already_unlocked() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = StateDelta::new(snapshot);
+        setup(&mut state);
+
+        assert_eyre_error(
+            &action(TimelockUnlock::Height(5))
+                .check_and_execute(&mut state)
+                .await
+                .unwrap_err(),
+            "unlock point of timelocked transfer must be in the future",
+        );
+        assert_eyre_error(
+            &action(TimelockUnlock::Time(
+                Time::from_unix_timestamp(1_000, 0).unwrap(),
+            ))
+            .check_and_execute(&mut state)
+            .await
+            .unwrap_err(),
+            "unlock point of timelocked transfer must be in the future",
+        );
+    }
+}
@@ -42,6 +42,7 @@ use crate::{
     storage::StoredValue,
     transaction::{
         check_balance_for_total_fees_and_transfers,
+        check_multisig_authorization,
         StateWriteExt as _,
     },
 };
@@ -162,6 +163,34 @@ impl ActionHandler for Transaction {
                     .check_stateless()
                     .await
                     .wrap_err("stateless check failed for MarketsChange action")?,
+                Action::RegisterMultisig(act) => act
+                    .check_stateless()
+                    .await
+                    .wrap_err("stateless check failed for RegisterMultisig action")?,
+                Action::TimelockedTransfer(act) => act
+                    .check_stateless()
+                    .await
+                    .wrap_err("stateless check failed for TimelockedTransfer action")?,
+                Action::ClaimTimelockedTransfer(act) => act
+                    .check_stateless()
+                    .await
+                    .wrap_err("stateless check failed for ClaimTimelockedTransfer action")?,
+                Action::CancelTimelockedTransfer(act) => act
+                    .check_stateless()
+                    .await
+                    .wrap_err("stateless check failed for CancelTimelockedTransfer action")?,
+                Action::SubmitProposal(act) => act
+                    .check_stateless()
+                    .await
+                    .wrap_err("stateless check failed for SubmitProposal action")?,
+                Action::VoteOnProposal(act) => act
+                    .check_stateless()
+                    .await
+                    .wrap_err("stateless check failed for VoteOnProposal action")?,
+                Action::FeeDistributionChange(act) => act
+                    .check_stateless()
+                    .await
+                    .wrap_err("stateless check failed for FeeDistributionChange action")?,
             }
         }
         Ok(())
@@ -192,6 +221,11 @@ impl ActionHandler for Transaction {
             .wrap_err("failed to get nonce for transaction signer")?;
         ensure!(curr_nonce == self.nonce(), InvalidNonce(self.nonce()));
 
+        // Multisig accounts can only be acted on with signatures meeting their policy.
+        check_multisig_authorization(self, &state)
+            .await
+            .wrap_err("transaction is not authorized by the multisig policy of its account")?;
+
         // Should have enough balance to cover all actions.
         check_balance_for_total_fees_and_transfers(self, &state)
             .await
@@ -300,6 +334,31 @@ impl ActionHandler for Transaction {
                 Action::MarketsChange(act) => check_execute_and_pay_fees(act, &mut state)
                     .await
                     .wrap_err("failed executing markets change")?,
+                Action::RegisterMultisig(act) => check_execute_and_pay_fees(act, &mut state)
+                    .await
+                    .wrap_err("failed executing register multisig")?,
+                Action::TimelockedTransfer(act) => check_execute_and_pay_fees(act, &mut state)
+                    .await
+                    .wrap_err("failed executing timelocked transfer")?,
+                Action::ClaimTimelockedTransfer(act) => {
+                    check_execute_and_pay_fees(act, &mut state)
+                        .await
+                        .wrap_err("failed executing claim timelocked transfer")?;
+                }
+                Action::CancelTimelockedTransfer(act) => {
+                    check_execute_and_pay_fees(act, &mut state)
+                        .await
+                        .wrap_err("failed executing cancel timelocked transfer")?;
+                }
+                Action::SubmitProposal(act) => check_execute_and_pay_fees(act, &mut state)
+                    .await
+                    .wrap_err("failed executing submit proposal")?,
+                Action::VoteOnProposal(act) => check_execute_and_pay_fees(act, &mut state)
+                    .await
+                    .wrap_err("failed executing vote on proposal")?,
+                Action::FeeDistributionChange(act) => check_execute_and_pay_fees(act, &mut state)
+                    .await
+                    .wrap_err("failed executing fee distribution change")?,
             }
         }
 
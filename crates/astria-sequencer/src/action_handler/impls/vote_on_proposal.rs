@@ -0,0 +1,246 @@
+use astria_core::protocol::transaction::v1::action::VoteOnProposal;
+use astria_eyre::eyre::{
+    ensure,
+    OptionExt as _,
+    Result,
+    WrapErr as _,
+};
+use async_trait::async_trait;
+use cnidarium::StateWrite;
+use tendermint::abci::Event;
+use tracing::{
+    instrument,
+    Level,
+};
+
+use crate::{
+    action_handler::ActionHandler,
+    app::StateReadExt as _,
+    governance::{
+        electorate,
+        ProposalStatus,
+        StateReadExt as _,
+        StateWriteExt as _,
+    },
+    transaction::StateReadExt as _,
+};
+
+#[async_trait]
+impl ActionHandler for VoteOnProposal {
+    async fn check_stateless(&self) -> Result<()> {
+        Ok(())
+    }
+
+    /// Records the signer's vote on an open proposal, replacing any earlier vote of theirs. The
+    /// signer must be a council member or, if no council is configured, a validator.
+    #[instrument(skip_all, err(level = Level::DEBUG))]
+    async fn check_and_execute<S: StateWrite>(&self, mut state: S) -> Result<()> {
+        let transaction_context = state
+            .get_transaction_context()
+            .expect("transaction source must be present in state when executing an action");
+        let from = transaction_context.address_bytes();
+
+        let params = state
+            .get_governance_parameters()
+            .await
+            .wrap_err("failed to get governance parameters")?
+            .ok_or_eyre("governance is not enabled")?;
+        let mut proposal = state
+            .get_proposal(self.proposal_id)
+            .await
+            .wrap_err("failed to get proposal")?
+            .ok_or_eyre("proposal not found")?;
+        ensure!(
+            proposal.status == ProposalStatus::Open,
+            "proposal is not open for voting"
+        );
+        let height = state
+            .get_block_height()
+            .await
+            .wrap_err("failed to get block height")?;
+        ensure!(
+            height < proposal.voting_end_height,
+            "voting period of proposal has ended"
+        );
+        ensure!(
+            electorate(&state, &params)
+                .await
+                .wrap_err("failed to determine governance electorate")?
+                .contains_key(&from),
+            "signer is not allowed to vote on proposals",
+        );
+
+        proposal.votes.insert(from, self.approve);
+        state
+            .put_proposal(self.proposal_id, &proposal)
+            .wrap_err("failed to put proposal")?;
+
+        state.record(construct_proposal_vote_event(
+            self.proposal_id,
+            self.approve,
+            transaction_context.position_in_transaction,
+        ));
+        Ok(())
+    }
+}
+
+fn construct_proposal_vote_event(id: u64, approve: bool, position_in_transaction: u64) -> Event {
+    Event::new(
+        "tx.proposalVote",
+        [
+            ("id", id.to_string()),
+            ("approve", approve.to_string()),
+            ("positionInTransaction", position_in_transaction.to_string()),
+        ],
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use astria_core::{
+        primitive::v1::TransactionId,
+        protocol::transaction::v1::{
+            action::SudoAddressChange,
+            Action,
+        },
+    };
+    use cnidarium::StateDelta;
+
+    use super::*;
+    use crate::{
+        app::StateWriteExt as _,
+        benchmark_and_test_utils::{
+            assert_eyre_error,
+            astria_address,
+            nria,
+        },
+        governance::{
+            Parameters,
+            Proposal,
+        },
+        transaction::{
+            StateWriteExt as _,
+            TransactionContext,
+        },
+    };
+
+    fn vote(approve: bool) -> VoteOnProposal {
+        VoteOnProposal {
+            proposal_id: 0,
+            approve,
+            fee_asset: nria().into(),
+        }
+    }
+
+    fn proposal(status: ProposalStatus) -> Proposal {
+        Proposal {
+            proposer: [1; 20],
+            action: Action::SudoAddressChange(SudoAddressChange {
+                new_address: astria_address(&[9; 20]),
+            }),
+            submitted_height: 1,
+            voting_end_height: 11,
+            status,
+            votes: BTreeMap::new(),
+        }
+    }
+
+    fn setup(state: &mut StateDelta<cnidarium::Snapshot>, signer: [u8; 20], height: u64) {
+        state.put_block_height(height).unwrap();
+        state
+            .put_governance_parameters(&Parameters {
+                proposers: vec![[1; 20]],
+                council: vec![[2; 20], [3; 20]],
+                voting_period_blocks: 10,
+                approval_threshold_basis_points: 5_000,
+            })
+            .unwrap();
+        state
+            .put_proposal(0, &proposal(ProposalStatus::Open))
+            .unwrap();
+        state.put_transaction_context(TransactionContext {
+            address_bytes: signer,
+            transaction_id: TransactionId::new([0; 32]),
+            position_in_transaction: 0,
+        });
+    }
+
+    #[tokio::test]
+    async fn vote_is_recorded_and_can_be_replaced() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let mut state = StateDelta::new(storage.latest_snapshot());
+        setup(&mut state, [2; 20], 5);
+
+        let mut state_tx = StateDelta::new(&mut state);
+        vote(true).check_and_execute(&mut state_tx).await.unwrap();
+        let (_, events) = state_tx.apply();
+        assert_eq!(events, vec![construct_proposal_vote_event(0, true, 0)]);
+        assert_eq!(
+            state.get_proposal(0).await.unwrap().unwrap().votes,
+            BTreeMap::from([([2; 20], true)])
+        );
+
+        vote(false).check_and_execute(&mut state).await.unwrap();
+        assert_eq!(
+            state.get_proposal(0).await.unwrap().unwrap().votes,
+            BTreeMap::from([([2; 20], false)])
+        );
+    }
+
+    #[tokio::test]
+    async fn vote_fails_if_signer_is_not_in_electorate() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let mut state = StateDelta::new(storage.latest_snapshot());
+        setup(&mut state, [1; 20], 5);
+
+        assert_eyre_error(
+            &vote(true).check_and_execute(&mut state).await.unwrap_err(),
+            "signer is not allowed to vote on proposals",
+        );
+    }
+
+    #[tokio::test]
+    async fn vote_fails_after_voting_period() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let mut state = StateDelta::new(storage.latest_snapshot());
+        setup(&mut state, [2; 20], 11);
+
+        assert_eyre_error(
+            &vote(true).check_and_execute(&mut state).await.unwrap_err(),
+            "voting period of proposal has ended",
+        );
+    }
+
+    #[tokio::test]
+    async fn vote_fails_if_proposal_is_closed() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let mut state = StateDelta::new(storage.latest_snapshot());
+        setup(&mut state, [2; 20], 5);
+        state
+            .put_proposal(0, &proposal(ProposalStatus::Executed))
+            .unwrap();
+
+        assert_eyre_error(
+            &vote(true).check_and_execute(&mut state).await.unwrap_err(),
+            "proposal is not open for voting",
+        );
+    }
+
+    #[tokio::test]
+    async fn vote_fails_if_proposal_does_not_exist() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let mut state = StateDelta::new(storage.latest_snapshot());
+        setup(&mut state, [2; 20], 5);
+
+        let vote = VoteOnProposal {
+            proposal_id: 1,
+            ..vote(true)
+        };
+        assert_eyre_error(
+            &vote.check_and_execute(&mut state).await.unwrap_err(),
+            "proposal not found",
+        );
+    }
+}
@@ -17,19 +17,26 @@ use astria_core::{
             BridgeSudoChange,
             BridgeTransfer,
             BridgeUnlock,
+            CancelTimelockedTransfer,
+            ClaimTimelockedTransfer,
             CurrencyPairsChange,
             FeeAssetChange,
             FeeChange,
+            FeeDistributionChange,
             IbcRelayerChange,
             IbcSudoChange,
             Ics20Withdrawal,
             InitBridgeAccount,
             MarketsChange,
             RecoverIbcClient,
+            RegisterMultisig,
             RollupDataSubmission,
+            SubmitProposal,
             SudoAddressChange,
+            TimelockedTransfer,
             Transfer,
             ValidatorUpdate,
+            VoteOnProposal,
         },
     },
     upgrades::{
@@ -98,6 +105,14 @@ pub(crate) fn default_fees() -> astria_core::protocol::genesis::v1::GenesisFees
         recover_ibc_client: Some(FeeComponents::<RecoverIbcClient>::new(0, 0)),
         currency_pairs_change: Some(FeeComponents::<CurrencyPairsChange>::new(0, 0)),
         markets_change: Some(FeeComponents::<MarketsChange>::new(0, 0)),
+        register_multisig: Some(FeeComponents::<RegisterMultisig>::new(0, 0)),
+        // should reflect transfer fee
+        timelocked_transfer: Some(FeeComponents::<TimelockedTransfer>::new(12, 0)),
+        claim_timelocked_transfer: Some(FeeComponents::<ClaimTimelockedTransfer>::new(0, 0)),
+        cancel_timelocked_transfer: Some(FeeComponents::<CancelTimelockedTransfer>::new(0, 0)),
+        submit_proposal: Some(FeeComponents::<SubmitProposal>::new(0, 0)),
+        vote_on_proposal: Some(FeeComponents::<VoteOnProposal>::new(0, 0)),
+        fee_distribution_change: Some(FeeComponents::<FeeDistributionChange>::new(0, 0)),
     }
 }
 
@@ -125,6 +140,9 @@ pub(crate) fn proto_genesis_state(
         }),
         allowed_fee_assets: vec![nria().to_string()],
         fees: Some(default_fees().to_raw()),
+        governance_parameters: None,
+        fee_distribution: None,
+        oracle_liveness_parameters: None,
     }
 }
 
@@ -170,7 +188,7 @@ impl AppInitializer {
             .expect("failed to create temp storage backing chain state");
         let snapshot = storage.latest_snapshot();
         let metrics = Box::leak(Box::new(Metrics::noop_metrics(&()).unwrap()));
-        let mempool = Mempool::new(metrics, 100);
+        let mempool = Mempool::new(metrics, 100, 10);
         let upgrades_handler = self
             .upgrades
             .unwrap_or_else(|| UpgradesBuilder::new().set_aspen(Some(1)).build())
@@ -429,6 +447,48 @@ pub(crate) async fn mock_state_getter() -> StateDelta<Snapshot> {
         .wrap_err("failed to initiate markets change fee components")
         .unwrap();
 
+    let register_multisig_fees = FeeComponents::<RegisterMultisig>::new(0, 0);
+    state
+        .put_fees(register_multisig_fees)
+        .wrap_err("failed to initiate register multisig fee components")
+        .unwrap();
+
+    let timelocked_transfer_fees = FeeComponents::<TimelockedTransfer>::new(12, 0);
+    state
+        .put_fees(timelocked_transfer_fees)
+        .wrap_err("failed to initiate timelocked transfer fee components")
+        .unwrap();
+
+    let claim_timelocked_transfer_fees = FeeComponents::<ClaimTimelockedTransfer>::new(0, 0);
+    state
+        .put_fees(claim_timelocked_transfer_fees)
+        .wrap_err("failed to initiate claim timelocked transfer fee components")
+        .unwrap();
+
+    let cancel_timelocked_transfer_fees = FeeComponents::<CancelTimelockedTransfer>::new(0, 0);
+    state
+        .put_fees(cancel_timelocked_transfer_fees)
+        .wrap_err("failed to initiate cancel timelocked transfer fee components")
+        .unwrap();
+
+    let submit_proposal_fees = FeeComponents::<SubmitProposal>::new(0, 0);
+    state
+        .put_fees(submit_proposal_fees)
+        .wrap_err("failed to initiate submit proposal fee components")
+        .unwrap();
+
+    let vote_on_proposal_fees = FeeComponents::<VoteOnProposal>::new(0, 0);
+    state
+        .put_fees(vote_on_proposal_fees)
+        .wrap_err("failed to initiate vote on proposal fee components")
+        .unwrap();
+
+    let fee_distribution_change_fees = FeeComponents::<FeeDistributionChange>::new(0, 0);
+    state
+        .put_fees(fee_distribution_change_fees)
+        .wrap_err("failed to initiate fee distribution change fee components")
+        .unwrap();
+
     // put denoms as allowed fee asset
     state.put_allowed_fee_asset(&denom_0()).unwrap();
 
@@ -79,11 +79,13 @@ impl<T> EventSender<T> {
 
 /// A subscription to the event bus.
 ///
-/// Allows subscribing to specific events like [`Self::process_proposal_blocks`]
-/// and [`Self::finalized_blocks`].
+/// Allows subscribing to specific events like [`Self::process_proposal_blocks`],
+/// [`Self::finalized_blocks`] and [`Self::committed_block_heights`].
+#[derive(Clone)]
 pub(crate) struct EventBusSubscription {
     process_proposal_blocks: EventReceiver<Arc<SequencerBlock>>,
     finalized_blocks: EventReceiver<Arc<FinalizeBlock>>,
+    committed_block_heights: EventReceiver<u64>,
 }
 
 impl EventBusSubscription {
@@ -106,6 +108,16 @@ impl EventBusSubscription {
         receiver.inner.mark_unchanged();
         receiver
     }
+
+    /// Receive the heights of blocks after they were committed to storage.
+    ///
+    /// The returned [`EventReceiver`] will always provide the next
+    /// event and ignore the latest one.
+    pub(crate) fn committed_block_heights(&self) -> EventReceiver<u64> {
+        let mut receiver = self.committed_block_heights.clone();
+        receiver.inner.mark_unchanged();
+        receiver
+    }
 }
 
 /// The Sequencer `EventBus` is used to send and receive events between different components of the
@@ -115,42 +127,53 @@ impl EventBusSubscription {
 ///
 /// The `EventBus` is implemented using [`tokio::sync::watch`] which allows for multiple receivers
 /// to receive the event at any given time.
-pub(super) struct EventBus {
+pub(crate) struct EventBus {
     // Sends a process proposal block event to the subscribers. The event is sent in the form of a
     // sequencer block which is created during the process proposal block phase.
-    process_proposal_block_sender: EventSender<Arc<SequencerBlock>>,
+    process_proposal_blocks: EventSender<Arc<SequencerBlock>>,
     // Sends a finalized block event to the subscribers. The event is sent in the form of the
     // finalize block abci request.
-    finalized_block_sender: EventSender<Arc<FinalizeBlock>>,
+    finalized_blocks: EventSender<Arc<FinalizeBlock>>,
+    // Sends a committed block event to the subscribers. The event is sent in the form of the
+    // height of the block whose state was just committed to storage.
+    committed_block_heights: EventSender<u64>,
 }
 
 impl EventBus {
     /// Instantiates a new event bus.
-    pub(super) fn new() -> Self {
-        let process_proposal_block_sender = EventSender::new();
-        let finalized_block_sender = EventSender::new();
+    pub(crate) fn new() -> Self {
+        let process_proposal_blocks = EventSender::new();
+        let finalized_blocks = EventSender::new();
+        let committed_block_heights = EventSender::new();
 
         Self {
-            process_proposal_block_sender,
-            finalized_block_sender,
+            process_proposal_blocks,
+            finalized_blocks,
+            committed_block_heights,
         }
     }
 
     /// Subscribe to the event bus.
-    pub(super) fn subscribe(&self) -> EventBusSubscription {
+    pub(crate) fn subscribe(&self) -> EventBusSubscription {
         EventBusSubscription {
-            process_proposal_blocks: self.process_proposal_block_sender.subscribe(),
-            finalized_blocks: self.finalized_block_sender.subscribe(),
+            process_proposal_blocks: self.process_proposal_blocks.subscribe(),
+            finalized_blocks: self.finalized_blocks.subscribe(),
+            committed_block_heights: self.committed_block_heights.subscribe(),
         }
     }
 
     /// Sends a process proposal block event over the event bus.
     pub(super) fn send_process_proposal_block(&self, sequencer_block: Arc<SequencerBlock>) {
-        self.process_proposal_block_sender.send(sequencer_block);
+        self.process_proposal_blocks.send(sequencer_block);
     }
 
     /// Sends a finalized block event over the event bus.
     pub(super) fn send_finalized_block(&self, sequencer_block_commit: Arc<FinalizeBlock>) {
-        self.finalized_block_sender.send(sequencer_block_commit);
+        self.finalized_blocks.send(sequencer_block_commit);
+    }
+
+    /// Sends a committed block event over the event bus.
+    pub(crate) fn send_committed_block_height(&self, height: u64) {
+        self.committed_block_heights.send(height);
     }
 }
@@ -25,7 +25,10 @@ use std::{
 };
 
 use astria_core::{
-    primitive::v1::TRANSACTION_ID_LEN,
+    primitive::v1::{
+        ADDRESS_LEN,
+        TRANSACTION_ID_LEN,
+    },
     protocol::{
         abci::AbciErrorCode,
         genesis::v1::GenesisAppState,
@@ -101,9 +104,12 @@ use tracing::{
     Level,
 };
 
-pub(crate) use self::state_ext::{
-    StateReadExt,
-    StateWriteExt,
+pub(crate) use self::{
+    state_ext::{
+        StateReadExt,
+        StateWriteExt,
+    },
+    storage::keys::STORAGE_VERSION_BY_HEIGHT_PREFIX,
 };
 use crate::{
     accounts::{
@@ -141,9 +147,14 @@ use crate::{
     },
     component::Component as _,
     fees::{
+        self,
         component::FeesComponent,
         StateReadExt as _,
     },
+    governance::{
+        component::GovernanceComponent,
+        StateReadExt as _,
+    },
     grpc::StateWriteExt as _,
     ibc::component::IbcComponent,
     mempool::{
@@ -151,6 +162,10 @@ use crate::{
         RemovalReason,
     },
     metrics::Metrics,
+    oracles::price_feed::liveness::{
+        self,
+        StateWriteExt as _,
+    },
     proposal::{
         block_size_constraints::BlockSizeConstraints,
         commitment::generate_rollup_datas_commitment,
@@ -217,6 +232,11 @@ pub(crate) struct App {
     )]
     app_hash: AppHash,
 
+    // the storage version at which `app_hash` was committed.
+    // used to detect if storage was written to outside of consensus, i.e. restored from a state
+    // sync snapshot, in which case `app_hash` is reloaded.
+    committed_version: u64,
+
     // the sequencer event bus, used to send and receive events between components within the app
     event_bus: EventBus,
 
@@ -249,6 +269,8 @@ impl App {
             .try_into()
             .expect("root hash conversion must succeed; should be 32 bytes");
 
+        let committed_version = snapshot.version();
+
         // We perform the `Arc` wrapping of `State` here to ensure
         // there should be no unexpected copies elsewhere.
         let state = Arc::new(StateDelta::new(snapshot));
@@ -262,6 +284,7 @@ impl App {
             recost_mempool: false,
             write_batch: None,
             app_hash,
+            committed_version,
             event_bus,
             upgrades_handler,
             vote_extension_handler,
@@ -328,6 +351,19 @@ impl App {
         IbcComponent::init_chain(&mut state_tx, &genesis_state)
             .await
             .wrap_err("init_chain failed on IbcComponent")?;
+        GovernanceComponent::init_chain(&mut state_tx, &genesis_state)
+            .await
+            .wrap_err("init_chain failed on GovernanceComponent")?;
+        if let Some(params) = genesis_state.oracle_liveness_parameters() {
+            state_tx
+                .put_oracle_liveness_parameters(params)
+                .wrap_err("failed to write oracle liveness parameters to state")?;
+        }
+
+        state_tx
+            .put_account_history(0)
+            .await
+            .wrap_err("failed to write genesis account history to state")?;
 
         state_tx.apply();
 
@@ -351,6 +387,31 @@ impl App {
         self.execution_state = ExecutionStateMachine::new();
     }
 
+    /// Reloads the app hash from storage if storage was committed to since the last call to
+    /// `commit`, which happens if the state was restored from a state sync snapshot.
+    async fn reload_app_hash_if_storage_restored(&mut self, storage: &Storage) -> Result<()> {
+        let snapshot = storage.latest_snapshot();
+        if snapshot.version() == self.committed_version {
+            return Ok(());
+        }
+        self.app_hash = snapshot
+            .root_hash()
+            .await
+            .map_err(anyhow_to_eyre)
+            .wrap_err("failed to get current root hash")?
+            .0
+            .to_vec()
+            .try_into()
+            .expect("root hash conversion must succeed; should be 32 bytes");
+        self.committed_version = snapshot.version();
+        info!(
+            app_hash = %telemetry::display::hex(&self.app_hash),
+            version = self.committed_version,
+            "reloaded app hash after storage was restored"
+        );
+        Ok(())
+    }
+
     /// Generates a commitment to the `sequence::Actions` in the block's transactions.
     ///
     /// This is required so that a rollup can easily verify that the transactions it
@@ -365,6 +426,7 @@ impl App {
         prepare_proposal: abci::request::PrepareProposal,
         storage: Storage,
     ) -> Result<abci::response::PrepareProposal> {
+        self.reload_app_hash_if_storage_restored(&storage).await?;
         // Always reset when preparing a proposal.
         self.update_state_for_new_round(&storage);
 
@@ -503,6 +565,8 @@ impl App {
         process_proposal: abci::request::ProcessProposal,
         storage: Storage,
     ) -> Result<()> {
+        self.reload_app_hash_if_storage_restored(&storage).await?;
+
         // Check the proposal against the prepared proposal fingerprint.
         let skip_execution = self
             .execution_state
@@ -1124,13 +1188,12 @@ impl App {
             .get_chain_id()
             .await
             .wrap_err("failed to get chain ID from state")?;
-        let sudo_address = self
-            .state
-            .get_sudo_address()
-            .await
-            .wrap_err("failed to get sudo address from state")?;
+        let proposer: [u8; ADDRESS_LEN] = proposer_address
+            .as_bytes()
+            .try_into()
+            .expect("a cometbft account id is always 20 bytes");
 
-        let end_block = self.end_block(height.value(), &sudo_address).await?;
+        let end_block = self.end_block(height.value(), &proposer).await?;
 
         // get deposits for this block from state's ephemeral cache and put them to storage.
         let mut state_tx = StateDelta::new(self.state.clone());
@@ -1182,6 +1245,11 @@ impl App {
             );
         }
 
+        state_tx
+            .put_account_history(height.value())
+            .await
+            .wrap_err("failed to write account history to state")?;
+
         let result = PostTransactionExecutionResult {
             events: end_block.events,
             validator_updates: end_block.validator_updates,
@@ -1213,6 +1281,7 @@ impl App {
         let Hash::Sha256(block_hash) = finalize_block.hash else {
             bail!("block hash is empty; this should not occur")
         };
+        self.reload_app_hash_if_storage_restored(&storage).await?;
         // If there is not a matching cached executed proposal, we need to execute the block.
         let skip_execution = self.execution_state.check_if_executed_block(block_hash);
         if !skip_execution {
@@ -1249,6 +1318,12 @@ impl App {
             )
             .await
             .wrap_err("failed to apply prices from vote extensions")?;
+            liveness::record_participation(
+                &mut state_tx,
+                &extended_commit_info.extended_commit_info,
+            )
+            .await
+            .wrap_err("failed to record oracle participation")?;
             self.apply(state_tx)
         } else {
             vec![]
@@ -1438,6 +1513,7 @@ impl App {
             .check_and_execute(&mut state_tx)
             .await
             .wrap_err("failed executing transaction")?;
+        state_tx.record_executed_transaction(&signed_tx);
 
         // flag mempool for cleaning if we ran a fee change action
         self.recost_mempool = self.recost_mempool
@@ -1463,9 +1539,16 @@ impl App {
     async fn end_block(
         &mut self,
         height: u64,
-        fee_recipient: &[u8; 20],
+        proposer: &[u8; ADDRESS_LEN],
     ) -> Result<abci::response::EndBlock> {
-        let state_tx = StateDelta::new(self.state.clone());
+        let mut state_tx = StateDelta::new(self.state.clone());
+
+        // distribute the fees collected in this block before any component runs, so that the
+        // split is computed against the sudo address and validator set that produced the block
+        fees::distribute_block_fees(&mut state_tx, self.state.get_block_fees(), proposer)
+            .await
+            .wrap_err("failed to distribute block fees")?;
+
         let mut arc_state_tx = Arc::new(state_tx);
 
         let end_block = abci::request::EndBlock {
@@ -1478,6 +1561,11 @@ impl App {
         AccountsComponent::end_block(&mut arc_state_tx, &end_block)
             .await
             .wrap_err("end_block failed on AccountsComponent")?;
+        // must run before the authority component so that validator updates of executed
+        // proposals are applied in this block
+        GovernanceComponent::end_block(&mut arc_state_tx, &end_block)
+            .await
+            .wrap_err("end_block failed on GovernanceComponent")?;
         AuthorityComponent::end_block(&mut arc_state_tx, &end_block)
             .await
             .wrap_err("end_block failed on AuthorityComponent")?;
@@ -1492,8 +1580,7 @@ impl App {
             .expect("components should not retain copies of shared state");
 
         // gather and return validator updates
-        let validator_updates = self
-            .state
+        let validator_updates = state_tx
             .get_block_validator_updates()
             .await
             .expect("failed getting validator updates");
@@ -1501,15 +1588,9 @@ impl App {
         // clear validator updates
         state_tx.clear_block_validator_updates();
 
-        // gather block fees and transfer them to the block proposer
-        let fees = self.state.get_block_fees();
-
-        for fee in fees {
-            state_tx
-                .increase_balance(fee_recipient, fee.asset(), fee.amount())
-                .await
-                .wrap_err("failed to increase fee recipient balance")?;
-        }
+        // flag mempool for cleaning if an executed proposal changed fees
+        self.recost_mempool =
+            self.recost_mempool || state_tx.get_governance_fees_changed_in_block();
 
         let events = self.apply(state_tx);
         Ok(abci::response::EndBlock {
@@ -1538,11 +1619,21 @@ impl App {
             .to_vec()
             .try_into()
             .expect("root hash to app hash conversion must succeed");
+        self.committed_version = storage.latest_version();
 
         // Get the latest version of the state, now that we've committed it.
         // and clear the previous fingerprint.
         self.update_state_for_new_round(&storage);
 
+        // notify subscribers that the block is now readable from the latest snapshot
+        match self.state.get_block_height().await {
+            Ok(height) => self.event_bus.send_committed_block_height(height),
+            Err(error) => warn!(
+                error = AsRef::<dyn std::error::Error>::as_ref(&error),
+                "failed to read block height of committed state; not sending committed block event",
+            ),
+        }
+
         // update the priority of any txs in the mempool based on the updated app state
         if self.recost_mempool {
             self.metrics.increment_mempool_recosted();
@@ -4,8 +4,10 @@ pub(in crate::app) const BLOCK_HEIGHT: &str = "app/block_height";
 pub(in crate::app) const BLOCK_TIMESTAMP: &str = "app/block_timestamp";
 pub(in crate::app) const CONSENSUS_PARAMS: &str = "app/consensus_params";
 
+pub(crate) const STORAGE_VERSION_BY_HEIGHT_PREFIX: &str = "app/storage_version/";
+
 pub(in crate::app) fn storage_version_by_height(height: u64) -> String {
-    format!("app/storage_version/{height}")
+    format!("{STORAGE_VERSION_BY_HEIGHT_PREFIX}{height}")
 }
 
 #[cfg(test)]
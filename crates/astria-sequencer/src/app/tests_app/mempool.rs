@@ -66,6 +66,7 @@ async fn trigger_cleaning() {
             0,
             mock_balances(0, 0),
             mock_tx_cost(0, 0, 0),
+            0,
         )
         .await
         .unwrap();
@@ -168,6 +169,7 @@ async fn do_not_trigger_cleaning() {
             0,
             mock_balances(0, 0),
             mock_tx_cost(0, 0, 0),
+            0,
         )
         .await
         .unwrap();
@@ -244,6 +246,7 @@ async fn maintenance_recosting_promotes() {
             0,
             bob_funds,
             tx_cost,
+            0,
         )
         .await
         .unwrap();
@@ -264,7 +267,7 @@ async fn maintenance_recosting_promotes() {
     let mut tx_cost = HashMap::new();
     tx_cost.insert(nria().into(), 0);
     app.mempool
-        .insert(Arc::new(tx_recost.clone()), 0, judy_funds, tx_cost)
+        .insert(Arc::new(tx_recost.clone()), 0, judy_funds, tx_cost, 0)
         .await
         .unwrap();
     assert_eq!(app.mempool.len().await, 2, "two txs in mempool");
@@ -425,6 +428,7 @@ async fn maintenance_funds_added_promotes() {
             0,
             carol_funds,
             tx_cost,
+            0,
         )
         .await
         .unwrap();
@@ -448,7 +452,7 @@ async fn maintenance_funds_added_promotes() {
     let mut tx_cost = HashMap::new();
     tx_cost.insert(nria().into(), 13);
     app.mempool
-        .insert(Arc::new(tx_fund.clone()), 0, alice_funds, tx_cost)
+        .insert(Arc::new(tx_fund.clone()), 0, alice_funds, tx_cost, 0)
         .await
         .unwrap();
 
@@ -541,7 +541,7 @@ async fn app_execution_results_match_proposal_vs_after_proposal() {
     // this will reset the app state.
     // this simulates executing the same block as a validator (specifically the proposer).
     app.mempool
-        .insert(signed_tx, 0, mock_balances(0, 0), mock_tx_cost(0, 0, 0))
+        .insert(signed_tx, 0, mock_balances(0, 0), mock_tx_cost(0, 0, 0), 0)
         .await
         .unwrap();
 
@@ -653,6 +653,7 @@ async fn app_prepare_proposal_cometbft_max_bytes_overflow_ok() {
             0,
             mock_balances(0, 0),
             mock_tx_cost(0, 0, 0),
+            0,
         )
         .await
         .unwrap();
@@ -662,6 +663,7 @@ async fn app_prepare_proposal_cometbft_max_bytes_overflow_ok() {
             0,
             mock_balances(0, 0),
             mock_tx_cost(0, 0, 0),
+            0,
         )
         .await
         .unwrap();
@@ -740,6 +742,7 @@ async fn app_prepare_proposal_sequencer_max_bytes_overflow_ok() {
             0,
             mock_balances(0, 0),
             mock_tx_cost(0, 0, 0),
+            0,
         )
         .await
         .unwrap();
@@ -749,6 +752,7 @@ async fn app_prepare_proposal_sequencer_max_bytes_overflow_ok() {
             0,
             mock_balances(0, 0),
             mock_tx_cost(0, 0, 0),
+            0,
         )
         .await
         .unwrap();
@@ -1100,6 +1104,7 @@ async fn app_proposal_fingerprint_triggers_update() {
             0,
             mock_balances(0, 0),
             mock_tx_cost(0, 0, 0),
+            0,
         )
         .await
         .unwrap();
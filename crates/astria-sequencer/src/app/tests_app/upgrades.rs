@@ -414,6 +414,7 @@ async fn execute_block_99(proposer: &mut Node, validator: &mut Node, non_validat
             0,
             mock_balances(0, 0),
             mock_tx_cost(0, 0, 0),
+            0,
         )
         .await
         .unwrap();
@@ -431,6 +432,7 @@ async fn execute_block_99(proposer: &mut Node, validator: &mut Node, non_validat
             0,
             mock_balances(0, 0),
             mock_tx_cost(0, 0, 0),
+            0,
         )
         .await
         .unwrap();
@@ -517,6 +519,7 @@ async fn execute_block_100(proposer: &mut Node, validator: &mut Node, non_valida
             1,
             mock_balances(0, 0),
             mock_tx_cost(0, 0, 0),
+            0,
         )
         .await
         .unwrap();
@@ -534,6 +537,7 @@ async fn execute_block_100(proposer: &mut Node, validator: &mut Node, non_valida
             1,
             mock_balances(0, 0),
             mock_tx_cost(0, 0, 0),
+            0,
         )
         .await
         .unwrap();
@@ -627,6 +631,7 @@ async fn execute_block_101(proposer: &mut Node, validator: &mut Node, non_valida
             2,
             mock_balances(0, 0),
             mock_tx_cost(0, 0, 0),
+            0,
         )
         .await
         .unwrap();
@@ -644,6 +649,7 @@ async fn execute_block_101(proposer: &mut Node, validator: &mut Node, non_valida
             2,
             mock_balances(0, 0),
             mock_tx_cost(0, 0, 0),
+            0,
         )
         .await
         .unwrap();
@@ -746,6 +752,7 @@ async fn execute_block_102(proposer: &mut Node, validator: &mut Node, non_valida
             3,
             mock_balances(0, 0),
             mock_tx_cost(0, 0, 0),
+            0,
         )
         .await
         .unwrap();
@@ -165,12 +165,24 @@ async fn app_prepare_proposal_account_block_misordering_ok() {
         .build();
 
     app.mempool
-        .insert(tx_0.clone(), 0, mock_balances(0, 0), mock_tx_cost(0, 0, 0))
+        .insert(
+            tx_0.clone(),
+            0,
+            mock_balances(0, 0),
+            mock_tx_cost(0, 0, 0),
+            0,
+        )
         .await
         .unwrap();
 
     app.mempool
-        .insert(tx_1.clone(), 0, mock_balances(0, 0), mock_tx_cost(0, 0, 0))
+        .insert(
+            tx_1.clone(),
+            0,
+            mock_balances(0, 0),
+            mock_tx_cost(0, 0, 0),
+            0,
+        )
         .await
         .unwrap();
 
@@ -16,6 +16,7 @@ use std::{
 };
 
 use astria_core::{
+    crypto::SigningKey,
     oracles::price_feed::{
         market_map::v2::Market,
         types::v2::CurrencyPair,
@@ -25,22 +26,30 @@ use astria_core::{
         RollupId,
     },
     protocol::{
+        fees::v1::FeeDistribution,
         genesis::v1::Account,
         transaction::v1::{
             action::{
                 BridgeLock,
                 BridgeSudoChange,
                 BridgeUnlock,
+                CancelTimelockedTransfer,
                 CurrencyPairsChange,
                 FeeAssetChange,
+                FeeDistributionChange,
                 IbcRelayerChange,
                 IbcSudoChange,
                 InitBridgeAccount,
                 MarketsChange,
+                RegisterMultisig,
                 RollupDataSubmission,
+                SubmitProposal,
                 SudoAddressChange,
+                TimelockUnlock,
+                TimelockedTransfer,
                 Transfer,
                 ValidatorUpdate,
+                VoteOnProposal,
             },
             Action,
             TransactionBody,
@@ -71,13 +80,14 @@ use crate::{
         },
         test_utils::{
             get_alice_signing_key,
+            get_bob_signing_key,
             get_bridge_signing_key,
+            get_carol_signing_key,
             get_judy_signing_key,
             run_until_aspen_applied,
             transactions_with_extended_commit_info_and_commitments,
         },
     },
-    authority::StateReadExt as _,
     benchmark_and_test_utils::{
         astria_address,
         astria_address_from_hex_string,
@@ -227,6 +237,14 @@ async fn app_execute_transaction_with_every_action_snapshot() {
                 .unwrap()
                 .to_raw(),
         ),
+        governance_parameters: Some(
+            astria_core::generated::astria::protocol::genesis::v1::GovernanceParameters {
+                proposers: vec![astria_address(&alice.address_bytes()).to_raw()],
+                council: vec![astria_address(&alice.address_bytes()).to_raw()],
+                voting_period_blocks: 10,
+                approval_threshold_basis_points: 5_000,
+            },
+        ),
         ..proto_genesis_state()
     }
     .try_into()
@@ -277,6 +295,16 @@ async fn app_execute_transaction_with_every_action_snapshot() {
             FeeAssetChange::Addition("test-0".parse().unwrap()).into(),
             FeeAssetChange::Addition("test-1".parse().unwrap()).into(),
             FeeAssetChange::Removal("test-0".parse().unwrap()).into(),
+            FeeDistributionChange {
+                new_distribution: FeeDistribution {
+                    proposer_basis_points: 5_000,
+                    validators_basis_points: 3_000,
+                    community_pool_basis_points: 1_500,
+                    burn_basis_points: 500,
+                    community_pool_address: Some(carol_address),
+                },
+            }
+            .into(),
             CurrencyPairsChange::Addition(vec![
                 currency_pair_tia.clone(),
                 currency_pair_eth.clone(),
@@ -411,8 +439,93 @@ async fn app_execute_transaction_with_every_action_snapshot() {
     let signed_tx = Arc::new(tx_bridge.sign(&bridge));
     app.execute_transaction(signed_tx).await.unwrap();
 
-    let sudo_address = app.state.get_sudo_address().await.unwrap();
-    app.end_block(height.value(), &sudo_address).await.unwrap();
+    let carol = get_carol_signing_key();
+    let multisig_members = [get_bob_signing_key(), get_judy_signing_key()];
+    let tx_register_multisig = TransactionBody::builder()
+        .actions(vec![RegisterMultisig::try_new(
+            multisig_members
+                .iter()
+                .map(SigningKey::verification_key)
+                .collect(),
+            2,
+            nria().into(),
+        )
+        .unwrap()
+        .into()])
+        .chain_id("test")
+        .try_build()
+        .unwrap();
+    let signed_tx = Arc::new(tx_register_multisig.sign(&carol));
+    app.execute_transaction(signed_tx).await.unwrap();
+
+    let tx_multisig = TransactionBody::builder()
+        .actions(vec![Transfer {
+            to: bob_address,
+            amount: 1,
+            asset: nria().into(),
+            fee_asset: nria().into(),
+        }
+        .into()])
+        .nonce(1)
+        .chain_id("test")
+        .try_build()
+        .unwrap();
+    let signed_tx = Arc::new(tx_multisig.sign_multisig(carol.address_bytes(), &multisig_members));
+    app.execute_transaction(signed_tx).await.unwrap();
+
+    let alice_address = astria_address(&alice.address_bytes());
+    let tx_timelock = TransactionBody::builder()
+        .actions(vec![
+            TimelockedTransfer {
+                to: alice_address,
+                amount: 100,
+                asset: nria().into(),
+                fee_asset: nria().into(),
+                unlock: TimelockUnlock::Height(height.value().checked_add(10).unwrap()),
+            }
+            .into(),
+            TimelockedTransfer {
+                to: alice_address,
+                amount: 200,
+                asset: nria().into(),
+                fee_asset: nria().into(),
+                unlock: TimelockUnlock::Height(height.value().checked_add(20).unwrap()),
+            }
+            .into(),
+            CancelTimelockedTransfer {
+                id: 0,
+                fee_asset: nria().into(),
+            }
+            .into(),
+        ])
+        .chain_id("test")
+        .try_build()
+        .unwrap();
+    let signed_tx = Arc::new(tx_timelock.sign(&get_bob_signing_key()));
+    app.execute_transaction(signed_tx).await.unwrap();
+
+    let tx_governance = TransactionBody::builder()
+        .actions(vec![
+            SubmitProposal {
+                action: Box::new(FeeAssetChange::Addition("test-2".parse().unwrap()).into()),
+                fee_asset: nria().into(),
+            }
+            .into(),
+            VoteOnProposal {
+                proposal_id: 0,
+                approve: true,
+                fee_asset: nria().into(),
+            }
+            .into(),
+        ])
+        .nonce(4)
+        .chain_id("test")
+        .try_build()
+        .unwrap();
+    let signed_tx = Arc::new(tx_governance.sign(&alice));
+    app.execute_transaction(signed_tx).await.unwrap();
+
+    app.end_block(height.value(), &[0; 20]).await.unwrap();
 
     app.prepare_commit(storage.clone()).await.unwrap();
     app.commit(storage.clone()).await.unwrap();
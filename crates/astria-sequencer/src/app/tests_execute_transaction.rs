@@ -29,6 +29,7 @@ use astria_core::{
                 IbcRelayerChange,
                 IbcSudoChange,
                 MarketsChange,
+                RegisterMultisig,
                 RollupDataSubmission,
                 SudoAddressChange,
                 Transfer,
@@ -1581,3 +1582,96 @@ async fn remove_markets_executes_as_expected() {
         Some(&market_2)
     );
 }
+
+#[tokio::test]
+async fn multisig_account_requires_member_signatures_meeting_threshold() {
+    let mut app = initialize_app(None).await;
+
+    let alice = get_alice_signing_key();
+    let alice_address = astria_address(&alice.address_bytes());
+    let members = [
+        SigningKey::from([1; 32]),
+        SigningKey::from([2; 32]),
+        SigningKey::from([3; 32]),
+    ];
+
+    let register = TransactionBody::builder()
+        .actions(vec![RegisterMultisig::try_new(
+            members.iter().map(SigningKey::verification_key).collect(),
+            2,
+            nria().into(),
+        )
+        .unwrap()
+        .into()])
+        .chain_id("test")
+        .try_build()
+        .unwrap();
+    app.execute_transaction(Arc::new(register.sign(&alice)))
+        .await
+        .unwrap();
+
+    let bob_address = astria_address_from_hex_string(BOB_ADDRESS);
+    let transfer = |nonce| {
+        TransactionBody::builder()
+            .actions(vec![Transfer {
+                to: bob_address,
+                amount: 1,
+                asset: nria().into(),
+                fee_asset: nria().into(),
+            }
+            .into()])
+            .nonce(nonce)
+            .chain_id("test")
+            .try_build()
+            .unwrap()
+    };
+
+    // the old single key no longer controls the account
+    let err = app
+        .execute_transaction(Arc::new(transfer(1).sign(&alice)))
+        .await
+        .unwrap_err();
+    assert!(
+        format!("{err:?}").contains("requires member signatures"),
+        "{err:?}"
+    );
+
+    // signatures below the threshold are rejected
+    let err = app
+        .execute_transaction(Arc::new(
+            transfer(1).sign_multisig(alice.address_bytes(), &members[..1]),
+        ))
+        .await
+        .unwrap_err();
+    assert!(
+        format!("{err:?}").contains("requires 2 member signatures, but 1 provided"),
+        "{err:?}"
+    );
+
+    // signatures by non-members are rejected
+    let err = app
+        .execute_transaction(Arc::new(transfer(1).sign_multisig(
+            alice.address_bytes(),
+            &[members[0].clone(), SigningKey::from([4; 32])],
+        )))
+        .await
+        .unwrap_err();
+    assert!(
+        format!("{err:?}").contains("not a member of the multisig account"),
+        "{err:?}"
+    );
+    assert_eq!(
+        app.state.get_account_nonce(&alice_address).await.unwrap(),
+        1
+    );
+
+    app.execute_transaction(Arc::new(
+        transfer(1).sign_multisig(alice.address_bytes(), &members[1..]),
+    ))
+    .await
+    .unwrap();
+    assert_eq!(
+        app.state.get_account_nonce(&alice_address).await.unwrap(),
+        2
+    );
+}
@@ -66,8 +66,10 @@ use crate::{
     app::state_ext::StateReadExt,
     authority::StateReadExt as _,
     oracles::price_feed::oracle::{
+        self,
         currency_pair_strategy::DefaultCurrencyPairStrategy,
         state_ext::StateWriteExt,
+        PriceUpdate,
     },
 };
 
@@ -608,22 +610,43 @@ pub(super) async fn apply_prices_from_vote_extensions<S: StateWriteExt>(
             },
             block_height: height,
         };
-        state
-            .put_price_for_currency_pair(price.currency_pair().clone(), quote_price)
+        let update = oracle::apply_price(state, price.currency_pair().clone(), quote_price)
             .await
-            .wrap_err("failed to put price")?;
-        debug!(
-            "applied price from vote extension currency_pair=\"{}\" price={}",
-            price.currency_pair(),
-            price.price()
-        );
-        let event = abci::Event::new(
-            "price_update",
-            [
-                ("currency_pair", price.currency_pair().to_string()),
-                ("price", price.price().to_string()),
-            ],
-        );
+            .wrap_err("failed to apply price")?;
+        let event = match update {
+            PriceUpdate::Accepted => {
+                debug!(
+                    "applied price from vote extension currency_pair=\"{}\" price={}",
+                    price.currency_pair(),
+                    price.price()
+                );
+                abci::Event::new(
+                    "price_update",
+                    [
+                        ("currency_pair", price.currency_pair().to_string()),
+                        ("price", price.price().to_string()),
+                    ],
+                )
+            }
+            PriceUpdate::Rejected {
+                previous,
+            } => {
+                debug!(
+                    "rejected outlier price from vote extension currency_pair=\"{}\" price={} \
+                     previous_price={previous}",
+                    price.currency_pair(),
+                    price.price()
+                );
+                abci::Event::new(
+                    "price_update_rejected",
+                    [
+                        ("currency_pair", price.currency_pair().to_string()),
+                        ("price", price.price().to_string()),
+                        ("previous_price", previous.to_string()),
+                    ],
+                )
+            }
+        };
         state.record(event);
     }
 
@@ -12,12 +12,17 @@ use astria_eyre::eyre::{
     Result,
     WrapErr as _,
 };
+use cnidarium::StateRead;
+use futures::TryStreamExt as _;
 pub(crate) use state_ext::{
     StateReadExt,
     StateWriteExt,
 };
 
-use crate::accounts::AddressBytes;
+use crate::{
+    accounts::AddressBytes,
+    action_handler::impls::validator_update::use_pre_aspen_validator_updates,
+};
 
 /// A map of public keys to validator updates.
 #[derive(Debug, PartialEq, Eq)]
@@ -80,3 +85,36 @@ impl ValidatorSet {
             .wrap_err("failed to map one or more astria validators to cometbft validators")
     }
 }
+
+/// Returns the voting power of each current validator, keyed by the address of its consensus key.
+pub(crate) async fn validator_powers<S: StateRead>(
+    state: &S,
+) -> Result<BTreeMap<[u8; ADDRESS_LEN], u64>> {
+    let validators: Vec<_> = if use_pre_aspen_validator_updates(state)
+        .await
+        .wrap_err("failed to determine upgrade status")?
+    {
+        state
+            .pre_aspen_get_validator_set()
+            .await
+            .wrap_err("failed to get validator set")?
+            .updates()
+            .cloned()
+            .collect()
+    } else {
+        state
+            .get_validators()
+            .try_collect()
+            .await
+            .wrap_err("failed to get validators")?
+    };
+    Ok(validators
+        .into_iter()
+        .map(|validator| {
+            (
+                *validator.verification_key.address_bytes(),
+                u64::from(validator.power),
+            )
+        })
+        .collect())
+}
@@ -46,8 +46,18 @@ pub struct Config {
     pub price_feed_client_timeout_milliseconds: u64,
     /// The maximum number of transactions that can be parked in the mempool.
     pub mempool_parked_max_tx_count: usize,
+    /// The percentage by which a transaction's fees must exceed those of a mempool transaction
+    /// with the same signer and nonce in order to replace it.
+    pub mempool_replacement_fee_bump_percent: u32,
     /// Disables streaming optimistic blocks over grpc.
     pub no_optimistic_blocks: bool,
+    /// The directory in which state sync snapshots are stored.
+    pub snapshot_dir: PathBuf,
+    /// The interval in blocks at which state sync snapshots are taken. Set to 0 to disable
+    /// taking snapshots.
+    pub snapshot_interval: u64,
+    /// The number of most recent state sync snapshots to keep on disk.
+    pub snapshot_keep_recent: usize,
 }
 
 impl config::Config for Config {
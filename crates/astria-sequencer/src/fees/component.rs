@@ -151,6 +151,61 @@ impl Component for FeesComponent {
                 .wrap_err("failed to store markets change fee components")?;
         }
 
+        let register_multisig_fees = app_state.fees().register_multisig;
+        if let Some(register_multisig_fees) = register_multisig_fees {
+            state
+                .put_fees(register_multisig_fees)
+                .wrap_err("failed to store register multisig fee components")?;
+        }
+
+        let timelocked_transfer_fees = app_state.fees().timelocked_transfer;
+        if let Some(timelocked_transfer_fees) = timelocked_transfer_fees {
+            state
+                .put_fees(timelocked_transfer_fees)
+                .wrap_err("failed to store timelocked transfer fee components")?;
+        }
+
+        let claim_timelocked_transfer_fees = app_state.fees().claim_timelocked_transfer;
+        if let Some(claim_timelocked_transfer_fees) = claim_timelocked_transfer_fees {
+            state
+                .put_fees(claim_timelocked_transfer_fees)
+                .wrap_err("failed to store claim timelocked transfer fee components")?;
+        }
+
+        let cancel_timelocked_transfer_fees = app_state.fees().cancel_timelocked_transfer;
+        if let Some(cancel_timelocked_transfer_fees) = cancel_timelocked_transfer_fees {
+            state
+                .put_fees(cancel_timelocked_transfer_fees)
+                .wrap_err("failed to store cancel timelocked transfer fee components")?;
+        }
+
+        let submit_proposal_fees = app_state.fees().submit_proposal;
+        if let Some(submit_proposal_fees) = submit_proposal_fees {
+            state
+                .put_fees(submit_proposal_fees)
+                .wrap_err("failed to store submit proposal fee components")?;
+        }
+
+        let vote_on_proposal_fees = app_state.fees().vote_on_proposal;
+        if let Some(vote_on_proposal_fees) = vote_on_proposal_fees {
+            state
+                .put_fees(vote_on_proposal_fees)
+                .wrap_err("failed to store vote on proposal fee components")?;
+        }
+
+        let fee_distribution_change_fees = app_state.fees().fee_distribution_change;
+        if let Some(fee_distribution_change_fees) = fee_distribution_change_fees {
+            state
+                .put_fees(fee_distribution_change_fees)
+                .wrap_err("failed to store fee distribution change fee components")?;
+        }
+
+        if let Some(distribution) = app_state.fee_distribution() {
+            state
+                .put_fee_distribution(&fees::Distribution::from(distribution))
+                .wrap_err("failed to store fee distribution")?;
+        }
+
         Ok(())
     }
 
@@ -0,0 +1,395 @@
+use std::collections::BTreeMap;
+
+use astria_core::{
+    primitive::v1::{
+        asset::IbcPrefixed,
+        ADDRESS_LEN,
+    },
+    protocol::fees::v1::{
+        FeeDistribution,
+        FEE_DISTRIBUTION_TOTAL_BASIS_POINTS,
+    },
+};
+use astria_eyre::eyre::{
+    Result,
+    WrapErr as _,
+};
+use cnidarium::StateWrite;
+use tendermint::abci::Event;
+use tracing::{
+    instrument,
+    Level,
+};
+
+use super::{
+    Fee,
+    StateReadExt as _,
+    StateWriteExt as _,
+};
+use crate::{
+    accounts::StateWriteExt as _,
+    authority::{
+        validator_powers,
+        StateReadExt as _,
+    },
+};
+
+/// How the fees collected in a block are split, with the community pool stored as raw bytes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct Distribution {
+    pub(crate) proposer_basis_points: u32,
+    pub(crate) validators_basis_points: u32,
+    pub(crate) community_pool_basis_points: u32,
+    pub(crate) burn_basis_points: u32,
+    pub(crate) community_pool_address: Option<[u8; ADDRESS_LEN]>,
+}
+
+impl From<&FeeDistribution> for Distribution {
+    fn from(distribution: &FeeDistribution) -> Self {
+        Self {
+            proposer_basis_points: distribution.proposer_basis_points,
+            validators_basis_points: distribution.validators_basis_points,
+            community_pool_basis_points: distribution.community_pool_basis_points,
+            burn_basis_points: distribution.burn_basis_points,
+            community_pool_address: distribution
+                .community_pool_address
+                .as_ref()
+                .map(|address| address.bytes()),
+        }
+    }
+}
+
+/// The amounts of a single asset that were paid out at the end of a block.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct AssetDistribution {
+    pub(crate) asset: IbcPrefixed,
+    pub(crate) total: u128,
+    pub(crate) proposer: u128,
+    pub(crate) validators: u128,
+    pub(crate) community_pool: u128,
+    pub(crate) burned: u128,
+}
+
+/// Pays out the fees collected in the block.
+///
+/// Without a configured distribution all fees are paid to the sudo address. Otherwise the fees of
+/// each asset are split according to the distribution: the validators' share is paid pro-rata to
+/// their voting power, the burned share is not credited to any account, and whatever is left
+/// after rounding down all other shares is paid to `proposer`. If there are no validators with
+/// voting power, their share is paid to `proposer` as well.
+#[instrument(skip_all, err(level = Level::WARN))]
+pub(crate) async fn distribute_block_fees<S: StateWrite>(
+    state: &mut S,
+    fees: Vec<Fee>,
+    proposer: &[u8; ADDRESS_LEN],
+) -> Result<()> {
+    let Some(distribution) = state
+        .get_fee_distribution()
+        .await
+        .wrap_err("failed to get fee distribution")?
+    else {
+        let sudo_address = state
+            .get_sudo_address()
+            .await
+            .wrap_err("failed to get sudo address")?;
+        for fee in fees {
+            state
+                .increase_balance(&sudo_address, fee.asset(), fee.amount())
+                .await
+                .wrap_err("failed to increase fee recipient balance")?;
+        }
+        return Ok(());
+    };
+
+    let validators = validator_powers(state)
+        .await
+        .wrap_err("failed to get validator voting powers")?;
+    let total_power: u128 = validators.values().copied().map(u128::from).sum();
+
+    let mut distributions = Vec::new();
+    for (asset, total) in sum_by_asset(fees) {
+        let mut payouts = BTreeMap::<[u8; ADDRESS_LEN], u128>::new();
+
+        let validators_share = share(total, distribution.validators_basis_points);
+        let mut validators_paid = 0u128;
+        if total_power > 0 {
+            for (validator, power) in &validators {
+                let amount = mul_div(validators_share, u128::from(*power), total_power);
+                validators_paid = validators_paid.saturating_add(amount);
+                credit(&mut payouts, *validator, amount);
+            }
+        }
+
+        let community_pool = share(total, distribution.community_pool_basis_points);
+        if let Some(address) = distribution.community_pool_address {
+            credit(&mut payouts, address, community_pool);
+        }
+        let burned = share(total, distribution.burn_basis_points);
+
+        // the shares add up to at most `total`, so this only collects the proposer's share and
+        // the remainders left by rounding
+        let proposer_amount = total
+            .saturating_sub(validators_paid)
+            .saturating_sub(community_pool)
+            .saturating_sub(burned);
+        credit(&mut payouts, *proposer, proposer_amount);
+
+        for (address, amount) in payouts {
+            state
+                .increase_balance(&address, &asset, amount)
+                .await
+                .wrap_err("failed to increase balance of fee recipient")?;
+        }
+
+        let asset_distribution = AssetDistribution {
+            asset,
+            total,
+            proposer: proposer_amount,
+            validators: validators_paid,
+            community_pool,
+            burned,
+        };
+        state.record(construct_fee_distribution_event(
+            &asset_distribution,
+            proposer,
+        ));
+        distributions.push(asset_distribution);
+    }
+    state
+        .put_last_block_fee_distribution(&distributions)
+        .wrap_err("failed to put fee distribution of the block")?;
+    Ok(())
+}
+
+/// Sums the fees of each asset, ordered by the first fee paid in that asset.
+fn sum_by_asset(fees: Vec<Fee>) -> Vec<(IbcPrefixed, u128)> {
+    let mut totals: Vec<(IbcPrefixed, u128)> = Vec::new();
+    for fee in fees {
+        let asset = fee.asset().to_ibc_prefixed();
+        match totals.iter_mut().find(|(known, _)| *known == asset) {
+            Some((_, total)) => *total = total.saturating_add(fee.amount()),
+            None => totals.push((asset, fee.amount())),
+        }
+    }
+    totals
+}
+
+fn credit(
+    payouts: &mut BTreeMap<[u8; ADDRESS_LEN], u128>,
+    address: [u8; ADDRESS_LEN],
+    amount: u128,
+) {
+    if amount > 0 {
+        let balance = payouts.entry(address).or_default();
+        *balance = balance.saturating_add(amount);
+    }
+}
+
+/// Returns `basis_points` of `amount`, rounded down.
+fn share(amount: u128, basis_points: u32) -> u128 {
+    mul_div(
+        amount,
+        u128::from(basis_points),
+        u128::from(FEE_DISTRIBUTION_TOTAL_BASIS_POINTS),
+    )
+}
+
+/// Returns `amount * numerator / denominator`, rounded down, without overflowing for any
+/// `numerator <= denominator`.
+fn mul_div(amount: u128, numerator: u128, denominator: u128) -> u128 {
+    let quotient = amount
+        .checked_div(denominator)
+        .expect("denominator must not be zero");
+    let remainder = amount
+        .checked_rem(denominator)
+        .expect("denominator must not be zero");
+    quotient.saturating_mul(numerator).saturating_add(
+        remainder
+            .saturating_mul(numerator)
+            .checked_div(denominator)
+            .expect("denominator must not be zero"),
+    )
+}
+
+/// Creates `abci::Event` of kind `fees.distribution` reporting how the fees of one asset were paid
+/// out at the end of the block.
+fn construct_fee_distribution_event(
+    distribution: &AssetDistribution,
+    proposer: &[u8; ADDRESS_LEN],
+) -> Event {
+    Event::new(
+        "fees.distribution",
+        [
+            ("asset", distribution.asset.to_string()),
+            ("total", distribution.total.to_string()),
+            ("proposer", hex::encode(proposer)),
+            ("proposerAmount", distribution.proposer.to_string()),
+            ("validatorsAmount", distribution.validators.to_string()),
+            (
+                "communityPoolAmount",
+                distribution.community_pool.to_string(),
+            ),
+            ("burnedAmount", distribution.burned.to_string()),
+        ],
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use astria_core::{
+        primitive::v1::asset,
+        protocol::transaction::v1::action::ValidatorUpdate,
+    };
+    use cnidarium::StateDelta;
+
+    use super::*;
+    use crate::{
+        accounts::StateReadExt as _,
+        authority::{
+            StateWriteExt as _,
+            ValidatorSet,
+        },
+        benchmark_and_test_utils::{
+            nria,
+            verification_key,
+        },
+    };
+
+    const PROPOSER: [u8; ADDRESS_LEN] = [9; ADDRESS_LEN];
+    const COMMUNITY_POOL: [u8; ADDRESS_LEN] = [8; ADDRESS_LEN];
+    const SUDO: [u8; ADDRESS_LEN] = [7; ADDRESS_LEN];
+
+    fn fee(asset: &asset::Denom, amount: u128) -> Fee {
+        Fee {
+            action_name: "astria.protocol.transaction.v1.Transfer".to_string(),
+            asset: asset.to_ibc_prefixed().into(),
+            amount,
+            position_in_transaction: 0,
+        }
+    }
+
+    fn validator(seed: u64, power: u32) -> ValidatorUpdate {
+        ValidatorUpdate {
+            name: "test".parse().unwrap(),
+            power,
+            verification_key: verification_key(seed),
+        }
+    }
+
+    fn setup(storage: &cnidarium::TempStorage) -> StateDelta<cnidarium::Snapshot> {
+        let mut state = StateDelta::new(storage.latest_snapshot());
+        state.put_sudo_address(SUDO).unwrap();
+        state
+            .pre_aspen_put_validator_set(ValidatorSet::new_from_updates(vec![
+                validator(1, 3),
+                validator(2, 1),
+            ]))
+            .unwrap();
+        state
+    }
+
+    async fn balance(state: &StateDelta<cnidarium::Snapshot>, address: &[u8; ADDRESS_LEN]) -> u128 {
+        state.get_account_balance(address, &nria()).await.unwrap()
+    }
+
+    #[tokio::test]
+    async fn fees_are_paid_to_sudo_address_without_distribution() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let mut state = setup(&storage);
+
+        distribute_block_fees(&mut state, vec![fee(&nria().into(), 100)], &PROPOSER)
+            .await
+            .unwrap();
+
+        assert_eq!(balance(&state, &SUDO).await, 100);
+        assert_eq!(balance(&state, &PROPOSER).await, 0);
+        assert!(state
+            .get_last_block_fee_distribution()
+            .await
+            .unwrap()
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn fees_are_split_according_to_distribution() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let mut state = setup(&storage);
+        state
+            .put_fee_distribution(&Distribution {
+                proposer_basis_points: 4_000,
+                validators_basis_points: 3_000,
+                community_pool_basis_points: 2_000,
+                burn_basis_points: 1_000,
+                community_pool_address: Some(COMMUNITY_POOL),
+            })
+            .unwrap();
+
+        let fees = vec![fee(&nria().into(), 600), fee(&nria().into(), 401)];
+        distribute_block_fees(&mut state, fees, &PROPOSER)
+            .await
+            .unwrap();
+
+        // 1001 in total: the validators share 300 by power 3:1, the community pool gets 200,
+        // 100 are burned, and the proposer gets its 400 plus the 1 left by rounding
+        let validator_1 = *verification_key(1).address_bytes();
+        let validator_2 = *verification_key(2).address_bytes();
+        assert_eq!(balance(&state, &validator_1).await, 225);
+        assert_eq!(balance(&state, &validator_2).await, 75);
+        assert_eq!(balance(&state, &COMMUNITY_POOL).await, 200);
+        assert_eq!(balance(&state, &PROPOSER).await, 401);
+        assert_eq!(balance(&state, &SUDO).await, 0);
+        assert_eq!(
+            state.get_last_block_fee_distribution().await.unwrap(),
+            vec![AssetDistribution {
+                asset: nria().to_ibc_prefixed(),
+                total: 1001,
+                proposer: 401,
+                validators: 300,
+                community_pool: 200,
+                burned: 100,
+            }]
+        );
+    }
+
+    #[tokio::test]
+    async fn validators_share_goes_to_proposer_without_validators() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let mut state = setup(&storage);
+        state
+            .pre_aspen_put_validator_set(ValidatorSet::new_from_updates(vec![]))
+            .unwrap();
+        state
+            .put_fee_distribution(&Distribution {
+                proposer_basis_points: 0,
+                validators_basis_points: 10_000,
+                community_pool_basis_points: 0,
+                burn_basis_points: 0,
+                community_pool_address: None,
+            })
+            .unwrap();
+
+        distribute_block_fees(&mut state, vec![fee(&nria().into(), 100)], &PROPOSER)
+            .await
+            .unwrap();
+
+        assert_eq!(balance(&state, &PROPOSER).await, 100);
+    }
+
+    #[test]
+    fn mul_div_does_not_overflow() {
+        assert_eq!(mul_div(u128::MAX, 1, 1), u128::MAX);
+        assert_eq!(mul_div(u128::MAX, 5_000, 10_000), u128::MAX / 2);
+        assert_eq!(share(9_999, 1), 0);
+        assert_eq!(share(10_001, 5_000), 5_000);
+    }
+
+    #[test]
+    fn fees_are_summed_by_asset_in_order_of_first_payment() {
+        let other: asset::Denom = "other".parse().unwrap();
+        let totals = sum_by_asset(vec![fee(&other, 1), fee(&nria().into(), 2), fee(&other, 3)]);
+        assert_eq!(
+            totals,
+            vec![(other.to_ibc_prefixed(), 4), (nria().to_ibc_prefixed(), 2)]
+        );
+    }
+}
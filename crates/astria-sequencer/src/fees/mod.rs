@@ -7,19 +7,26 @@ use astria_core::{
             BridgeSudoChange,
             BridgeTransfer,
             BridgeUnlock,
+            CancelTimelockedTransfer,
+            ClaimTimelockedTransfer,
             CurrencyPairsChange,
             FeeAssetChange,
             FeeChange,
+            FeeDistributionChange,
             IbcRelayerChange,
             IbcSudoChange,
             Ics20Withdrawal,
             InitBridgeAccount,
             MarketsChange,
             RecoverIbcClient,
+            RegisterMultisig,
             RollupDataSubmission,
+            SubmitProposal,
             SudoAddressChange,
+            TimelockedTransfer,
             Transfer,
             ValidatorUpdate,
+            VoteOnProposal,
         },
     },
     Protobuf,
@@ -45,6 +52,7 @@ use crate::{
 };
 
 pub(crate) mod component;
+mod distribution;
 pub(crate) mod query;
 mod state_ext;
 pub(crate) mod storage;
@@ -52,6 +60,11 @@ pub(crate) mod storage;
 #[cfg(test)]
 mod tests;
 
+pub(crate) use distribution::{
+    distribute_block_fees,
+    AssetDistribution,
+    Distribution,
+};
 pub(crate) use state_ext::{
     StateReadExt,
     StateWriteExt,
@@ -550,6 +563,160 @@ impl FeeHandler for MarketsChange {
     }
 }
 
+impl FeeHandler for RegisterMultisig {
+    fn name() -> &'static str {
+        <Self as Protobuf>::Raw::NAME
+    }
+
+    fn full_name() -> String {
+        <Self as Protobuf>::full_name()
+    }
+
+    fn snake_case_name() -> &'static str {
+        "register_multisig"
+    }
+
+    fn variable_component(&self) -> u128 {
+        0
+    }
+
+    fn fee_asset(&self) -> Option<&asset::Denom> {
+        Some(&self.fee_asset)
+    }
+}
+
+impl FeeHandler for TimelockedTransfer {
+    fn name() -> &'static str {
+        <Self as Protobuf>::Raw::NAME
+    }
+
+    fn full_name() -> String {
+        <Self as Protobuf>::full_name()
+    }
+
+    fn snake_case_name() -> &'static str {
+        "timelocked_transfer"
+    }
+
+    fn variable_component(&self) -> u128 {
+        0
+    }
+
+    fn fee_asset(&self) -> Option<&asset::Denom> {
+        Some(&self.fee_asset)
+    }
+}
+
+impl FeeHandler for ClaimTimelockedTransfer {
+    fn name() -> &'static str {
+        <Self as Protobuf>::Raw::NAME
+    }
+
+    fn full_name() -> String {
+        <Self as Protobuf>::full_name()
+    }
+
+    fn snake_case_name() -> &'static str {
+        "claim_timelocked_transfer"
+    }
+
+    fn variable_component(&self) -> u128 {
+        0
+    }
+
+    fn fee_asset(&self) -> Option<&asset::Denom> {
+        Some(&self.fee_asset)
+    }
+}
+
+impl FeeHandler for CancelTimelockedTransfer {
+    fn name() -> &'static str {
+        <Self as Protobuf>::Raw::NAME
+    }
+
+    fn full_name() -> String {
+        <Self as Protobuf>::full_name()
+    }
+
+    fn snake_case_name() -> &'static str {
+        "cancel_timelocked_transfer"
+    }
+
+    fn variable_component(&self) -> u128 {
+        0
+    }
+
+    fn fee_asset(&self) -> Option<&asset::Denom> {
+        Some(&self.fee_asset)
+    }
+}
+
+impl FeeHandler for SubmitProposal {
+    fn name() -> &'static str {
+        <Self as Protobuf>::Raw::NAME
+    }
+
+    fn full_name() -> String {
+        <Self as Protobuf>::full_name()
+    }
+
+    fn snake_case_name() -> &'static str {
+        "submit_proposal"
+    }
+
+    fn variable_component(&self) -> u128 {
+        0
+    }
+
+    fn fee_asset(&self) -> Option<&asset::Denom> {
+        Some(&self.fee_asset)
+    }
+}
+
+impl FeeHandler for VoteOnProposal {
+    fn name() -> &'static str {
+        <Self as Protobuf>::Raw::NAME
+    }
+
+    fn full_name() -> String {
+        <Self as Protobuf>::full_name()
+    }
+
+    fn snake_case_name() -> &'static str {
+        "vote_on_proposal"
+    }
+
+    fn variable_component(&self) -> u128 {
+        0
+    }
+
+    fn fee_asset(&self) -> Option<&asset::Denom> {
+        Some(&self.fee_asset)
+    }
+}
+
+impl FeeHandler for FeeDistributionChange {
+    fn name() -> &'static str {
+        <Self as Protobuf>::Raw::NAME
+    }
+
+    fn full_name() -> String {
+        <Self as Protobuf>::full_name()
+    }
+
+    fn snake_case_name() -> &'static str {
+        "fee_distribution_change"
+    }
+
+    fn variable_component(&self) -> u128 {
+        0
+    }
+
+    fn fee_asset(&self) -> Option<&asset::Denom> {
+        None
+    }
+}
+
 /// Returns a modified byte length of the deposit event. Length is calculated with reasonable values
 /// for all fields except `asset` and `destination_chain_address`, ergo it may not be representative
 /// of on-wire length.
@@ -19,19 +19,26 @@ use astria_core::{
                 BridgeSudoChange,
                 BridgeTransfer,
                 BridgeUnlock,
+                CancelTimelockedTransfer,
+                ClaimTimelockedTransfer,
                 CurrencyPairsChange,
                 FeeAssetChange,
                 FeeChange,
+                FeeDistributionChange,
                 IbcRelayerChange,
                 IbcSudoChange,
                 Ics20Withdrawal,
                 InitBridgeAccount,
                 MarketsChange,
                 RecoverIbcClient,
+                RegisterMultisig,
                 RollupDataSubmission,
+                SubmitProposal,
                 SudoAddressChange,
+                TimelockedTransfer,
                 Transfer,
                 ValidatorUpdate,
+                VoteOnProposal,
             },
             Action,
             TransactionBody,
@@ -76,9 +83,12 @@ use tracing::{
 };
 
 use crate::{
+    address::StateReadExt as _,
     app::StateReadExt as _,
     assets::StateReadExt as _,
     fees::{
+        AssetDistribution,
+        Distribution,
         FeeHandler,
         StateReadExt as _,
     },
@@ -200,6 +210,138 @@ pub(crate) async fn components(
     }
 }
 
+#[derive(serde::Serialize)]
+struct DistributionResponse {
+    height: u64,
+    distribution: Option<DistributionInner>,
+    last_block: Vec<AssetDistributionInner>,
+}
+
+#[derive(serde::Serialize)]
+struct DistributionInner {
+    proposer_basis_points: u32,
+    validators_basis_points: u32,
+    community_pool_basis_points: u32,
+    burn_basis_points: u32,
+    community_pool_address: Option<String>,
+}
+
+#[derive(serde::Serialize)]
+struct AssetDistributionInner {
+    asset: String,
+    total: u128,
+    proposer: u128,
+    validators: u128,
+    community_pool: u128,
+    burned: u128,
+}
+
+#[instrument(skip_all, err(level = Level::DEBUG))]
+async fn distribution_inner<S: StateRead>(
+    state: &S,
+    distribution: Distribution,
+) -> eyre::Result<DistributionInner> {
+    let community_pool_address = match distribution.community_pool_address {
+        Some(address) => Some(
+            state
+                .try_base_prefixed(&address)
+                .await
+                .wrap_err("failed to construct bech32m address of the community pool")?
+                .to_string(),
+        ),
+        None => None,
+    };
+    Ok(DistributionInner {
+        proposer_basis_points: distribution.proposer_basis_points,
+        validators_basis_points: distribution.validators_basis_points,
+        community_pool_basis_points: distribution.community_pool_basis_points,
+        burn_basis_points: distribution.burn_basis_points,
+        community_pool_address,
+    })
+}
+
+async fn asset_distribution_inner<S: StateRead>(
+    state: &S,
+    distribution: AssetDistribution,
+) -> AssetDistributionInner {
+    AssetDistributionInner {
+        asset: find_trace_prefixed_or_return_ibc(state, distribution.asset)
+            .await
+            .to_string(),
+        total: distribution.total,
+        proposer: distribution.proposer,
+        validators: distribution.validators,
+        community_pool: distribution.community_pool,
+        burned: distribution.burned,
+    }
+}
+
+/// Returns the configured fee distribution and how the fees of the last block were paid out as
+/// JSON.
+///
+/// The `distribution` field of the response is `null` if all fees are paid to the sudo address.
+#[instrument(skip_all)]
+pub(crate) async fn distribution_request(
+    storage: Storage,
+    request: request::Query,
+    _params: Vec<(String, String)>,
+) -> response::Query {
+    let snapshot = storage.latest_snapshot();
+    let internal_error = |log: String| response::Query {
+        code: Code::Err(AbciErrorCode::INTERNAL_ERROR.value()),
+        info: AbciErrorCode::INTERNAL_ERROR.info(),
+        log,
+        ..response::Query::default()
+    };
+
+    let height = match snapshot.get_block_height().await {
+        Ok(height) => height,
+        Err(err) => return internal_error(format!("failed getting block height: {err:#}")),
+    };
+    let distribution = match snapshot.get_fee_distribution().await {
+        Ok(Some(distribution)) => match distribution_inner(&snapshot, distribution).await {
+            Ok(distribution) => Some(distribution),
+            Err(err) => {
+                return internal_error(format!(
+                    "failed constructing fee distribution response: {err:#}"
+                ));
+            }
+        },
+        Ok(None) => None,
+        Err(err) => return internal_error(format!("failed getting fee distribution: {err:#}")),
+    };
+    let last_block = match snapshot.get_last_block_fee_distribution().await {
+        Ok(distributions) => {
+            let mut last_block = Vec::with_capacity(distributions.len());
+            for distribution in distributions {
+                last_block.push(asset_distribution_inner(&snapshot, distribution).await);
+            }
+            last_block
+        }
+        Err(err) => {
+            return internal_error(format!(
+                "failed getting fee distribution of the last block: {err:#}"
+            ));
+        }
+    };
+
+    let payload = DistributionResponse {
+        height,
+        distribution,
+        last_block,
+    };
+    let height = tendermint::block::Height::try_from(height).expect("height must fit into an i64");
+    response::Query {
+        code: tendermint::abci::Code::Ok,
+        key: request.path.into_bytes().into(),
+        value: serde_json::to_vec(&payload)
+            .expect("object does not contain keys that don't map to json keys")
+            .into(),
+        height,
+        ..response::Query::default()
+    }
+}
+
 pub(crate) async fn transaction_fee_request(
     storage: Storage,
     request: request::Query,
@@ -312,6 +454,17 @@ pub(crate) async fn get_fees_for_transaction<S: StateRead>(
     let currency_pairs_change_fees: OnceCell<Option<FeeComponents<CurrencyPairsChange>>> =
         OnceCell::new();
     let markets_change_fees: OnceCell<Option<FeeComponents<MarketsChange>>> = OnceCell::new();
+    let register_multisig_fees: OnceCell<Option<FeeComponents<RegisterMultisig>>> = OnceCell::new();
+    let timelocked_transfer_fees: OnceCell<Option<FeeComponents<TimelockedTransfer>>> =
+        OnceCell::new();
+    let claim_timelocked_transfer_fees: OnceCell<Option<FeeComponents<ClaimTimelockedTransfer>>> =
+        OnceCell::new();
+    let cancel_timelocked_transfer_fees: OnceCell<Option<FeeComponents<CancelTimelockedTransfer>>> =
+        OnceCell::new();
+    let submit_proposal_fees: OnceCell<Option<FeeComponents<SubmitProposal>>> = OnceCell::new();
+    let vote_on_proposal_fees: OnceCell<Option<FeeComponents<VoteOnProposal>>> = OnceCell::new();
+    let fee_distribution_change_fees: OnceCell<Option<FeeComponents<FeeDistributionChange>>> =
+        OnceCell::new();
 
     let mut fees_by_asset = HashMap::new();
     for action in tx.actions() {
@@ -388,6 +541,34 @@ pub(crate) async fn get_fees_for_transaction<S: StateRead>(
                 let fees = get_or_init_fees(state, &markets_change_fees).await?;
                 calculate_and_add_fees(act, &mut fees_by_asset, fees);
             }
+            Action::RegisterMultisig(act) => {
+                let fees = get_or_init_fees(state, &register_multisig_fees).await?;
+                calculate_and_add_fees(act, &mut fees_by_asset, fees);
+            }
+            Action::TimelockedTransfer(act) => {
+                let fees = get_or_init_fees(state, &timelocked_transfer_fees).await?;
+                calculate_and_add_fees(act, &mut fees_by_asset, fees);
+            }
+            Action::ClaimTimelockedTransfer(act) => {
+                let fees = get_or_init_fees(state, &claim_timelocked_transfer_fees).await?;
+                calculate_and_add_fees(act, &mut fees_by_asset, fees);
+            }
+            Action::CancelTimelockedTransfer(act) => {
+                let fees = get_or_init_fees(state, &cancel_timelocked_transfer_fees).await?;
+                calculate_and_add_fees(act, &mut fees_by_asset, fees);
+            }
+            Action::SubmitProposal(act) => {
+                let fees = get_or_init_fees(state, &submit_proposal_fees).await?;
+                calculate_and_add_fees(act, &mut fees_by_asset, fees);
+            }
+            Action::VoteOnProposal(act) => {
+                let fees = get_or_init_fees(state, &vote_on_proposal_fees).await?;
+                calculate_and_add_fees(act, &mut fees_by_asset, fees);
+            }
+            Action::FeeDistributionChange(act) => {
+                let fees = get_or_init_fees(state, &fee_distribution_change_fees).await?;
+                calculate_and_add_fees(act, &mut fees_by_asset, fees);
+            }
         }
     }
     Ok(fees_by_asset)
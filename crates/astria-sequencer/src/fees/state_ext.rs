@@ -34,10 +34,15 @@ use tracing::{
 };
 
 use super::{
-    storage::keys::{
+    storage::{
         self,
-        extract_asset_from_allowed_asset_key,
+        keys::{
+            self,
+            extract_asset_from_allowed_asset_key,
+        },
     },
+    AssetDistribution,
+    Distribution,
     Fee,
     FeeHandler,
 };
@@ -125,6 +130,44 @@ pub(crate) trait StateReadExt: StateRead {
             underlying: self.prefix_keys(keys::ALLOWED_ASSET_PREFIX),
         }
     }
+
+    /// Returns how the fees collected in a block are split, or `None` if all fees are paid to
+    /// the sudo address.
+    #[instrument(skip_all, err(level = Level::WARN))]
+    async fn get_fee_distribution(&self) -> Result<Option<Distribution>> {
+        let Some(bytes) = self
+            .get_raw(keys::DISTRIBUTION)
+            .await
+            .map_err(anyhow_to_eyre)
+            .wrap_err("failed reading raw fee distribution from state")?
+        else {
+            return Ok(None);
+        };
+        StoredValue::deserialize(&bytes)
+            .and_then(|value| {
+                storage::Distribution::try_from(value)
+                    .map(|distribution| Some(Distribution::from(distribution)))
+            })
+            .wrap_err("invalid fee distribution bytes")
+    }
+
+    /// Returns how the fees of the last block were paid out, one entry per fee asset.
+    ///
+    /// Empty if the last block collected no fees, or if no fee distribution was configured.
+    #[instrument(skip_all, err(level = Level::WARN))]
+    async fn get_last_block_fee_distribution(&self) -> Result<Vec<AssetDistribution>> {
+        let Some(bytes) = self
+            .nonverifiable_get_raw(keys::LAST_BLOCK_DISTRIBUTION.as_bytes())
+            .await
+            .map_err(anyhow_to_eyre)
+            .wrap_err("failed reading raw last block fee distribution from state")?
+        else {
+            return Ok(vec![]);
+        };
+        StoredValue::deserialize(&bytes)
+            .and_then(|value| storage::BlockDistribution::try_from(value).map(Vec::from))
+            .wrap_err("invalid last block fee distribution bytes")
+    }
 }
 
 impl<T: ?Sized + StateRead> StateReadExt for T {}
@@ -198,6 +241,30 @@ pub(crate) trait StateWriteExt: StateWrite {
         self.put_raw(keys::allowed_asset(asset), bytes);
         Ok(())
     }
+
+    #[instrument(skip_all, err(level = Level::WARN))]
+    fn put_fee_distribution(&mut self, distribution: &Distribution) -> Result<()> {
+        let bytes = StoredValue::from(storage::Distribution::from(distribution))
+            .serialize()
+            .wrap_err("failed to serialize fee distribution")?;
+        self.put_raw(keys::DISTRIBUTION.to_string(), bytes);
+        Ok(())
+    }
+
+    /// Records how the fees of the current block were paid out.
+    ///
+    /// This is kept in nonverifiable storage as it is only used for reporting.
+    #[instrument(skip_all, err(level = Level::WARN))]
+    fn put_last_block_fee_distribution(
+        &mut self,
+        distributions: &[AssetDistribution],
+    ) -> Result<()> {
+        let bytes = StoredValue::from(storage::BlockDistribution::from(distributions))
+            .serialize()
+            .wrap_err("failed to serialize last block fee distribution")?;
+        self.nonverifiable_put_raw(keys::LAST_BLOCK_DISTRIBUTION.as_bytes().to_vec(), bytes);
+        Ok(())
+    }
 }
 
 impl<T: StateWrite> StateWriteExt for T {}
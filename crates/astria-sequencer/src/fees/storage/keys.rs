@@ -14,6 +14,8 @@ use crate::{
 
 pub(in crate::fees) const BLOCK: &str = "fees/block"; // NOTE: `BLOCK` is only used in the ephemeral store.
 pub(in crate::fees) const ALLOWED_ASSET_PREFIX: &str = "fees/allowed_asset/";
+pub(in crate::fees) const DISTRIBUTION: &str = "fees/distribution";
+pub(in crate::fees) const LAST_BLOCK_DISTRIBUTION: &str = "fees/last_block_distribution"; // NOTE: `LAST_BLOCK_DISTRIBUTION` is only used in the non-verifiable store.
 pub(in crate::fees) fn name<F: FeeHandler + ?Sized>() -> String {
     format!("fees/{}", F::snake_case_name())
 }
@@ -50,19 +52,26 @@ mod tests {
             BridgeSudoChange,
             BridgeTransfer,
             BridgeUnlock,
+            CancelTimelockedTransfer,
+            ClaimTimelockedTransfer,
             CurrencyPairsChange,
             FeeAssetChange,
             FeeChange,
+            FeeDistributionChange,
             IbcRelayerChange,
             IbcSudoChange,
             Ics20Withdrawal,
             InitBridgeAccount,
             MarketsChange,
             RecoverIbcClient,
+            RegisterMultisig,
             RollupDataSubmission,
+            SubmitProposal,
             SudoAddressChange,
+            TimelockedTransfer,
             Transfer,
             ValidatorUpdate,
+            VoteOnProposal,
         },
     };
     use insta::assert_snapshot;
@@ -102,8 +111,17 @@ mod tests {
         check::<BridgeTransfer>();
         check::<CurrencyPairsChange>();
         check::<MarketsChange>();
+        check::<RegisterMultisig>();
+        check::<TimelockedTransfer>();
+        check::<ClaimTimelockedTransfer>();
+        check::<CancelTimelockedTransfer>();
+        check::<SubmitProposal>();
+        check::<VoteOnProposal>();
+        check::<FeeDistributionChange>();
         assert_snapshot!("allowed_asset_prefix", ALLOWED_ASSET_PREFIX);
         assert_snapshot!("allowed_asset_key", allowed_asset(&test_asset()));
+        assert_snapshot!("distribution_key", DISTRIBUTION);
+        assert_snapshot!("last_block_distribution_key", LAST_BLOCK_DISTRIBUTION);
     }
 
     #[test]
@@ -126,6 +144,15 @@ mod tests {
         assert!(name::<RecoverIbcClient>().starts_with(COMPONENT_PREFIX));
         assert!(name::<CurrencyPairsChange>().starts_with(COMPONENT_PREFIX));
         assert!(name::<MarketsChange>().starts_with(COMPONENT_PREFIX));
+        assert!(name::<RegisterMultisig>().starts_with(COMPONENT_PREFIX));
+        assert!(name::<TimelockedTransfer>().starts_with(COMPONENT_PREFIX));
+        assert!(name::<ClaimTimelockedTransfer>().starts_with(COMPONENT_PREFIX));
+        assert!(name::<CancelTimelockedTransfer>().starts_with(COMPONENT_PREFIX));
+        assert!(name::<SubmitProposal>().starts_with(COMPONENT_PREFIX));
+        assert!(name::<VoteOnProposal>().starts_with(COMPONENT_PREFIX));
+        assert!(name::<FeeDistributionChange>().starts_with(COMPONENT_PREFIX));
+        assert!(DISTRIBUTION.starts_with(COMPONENT_PREFIX));
+        assert!(LAST_BLOCK_DISTRIBUTION.starts_with(COMPONENT_PREFIX));
         assert!(ALLOWED_ASSET_PREFIX.starts_with(COMPONENT_PREFIX));
         assert!(allowed_asset(&test_asset()).starts_with(COMPONENT_PREFIX));
     }
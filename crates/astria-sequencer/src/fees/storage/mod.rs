@@ -2,3 +2,7 @@ pub(super) mod keys;
 mod values;
 
 pub(crate) use values::Value;
+pub(super) use values::{
+    BlockDistribution,
+    Distribution,
+};
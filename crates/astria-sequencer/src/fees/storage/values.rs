@@ -1,23 +1,42 @@
-use astria_core::protocol::{
-    fees::v1::FeeComponents as DomainFeeComponents,
-    transaction::v1::action::{
-        BridgeLock,
-        BridgeSudoChange,
-        BridgeTransfer,
-        BridgeUnlock,
-        CurrencyPairsChange,
-        FeeAssetChange,
-        FeeChange,
-        IbcRelayerChange,
-        IbcSudoChange,
-        Ics20Withdrawal,
-        InitBridgeAccount,
-        MarketsChange,
-        RecoverIbcClient,
-        RollupDataSubmission,
-        SudoAddressChange,
-        Transfer,
-        ValidatorUpdate,
+use std::fmt::{
+    self,
+    Debug,
+    Formatter,
+};
+
+use astria_core::{
+    primitive::v1::{
+        asset::IbcPrefixed,
+        ADDRESS_LEN,
+    },
+    protocol::{
+        fees::v1::FeeComponents as DomainFeeComponents,
+        transaction::v1::action::{
+            BridgeLock,
+            BridgeSudoChange,
+            BridgeTransfer,
+            BridgeUnlock,
+            CancelTimelockedTransfer,
+            ClaimTimelockedTransfer,
+            CurrencyPairsChange,
+            FeeAssetChange,
+            FeeChange,
+            FeeDistributionChange,
+            IbcRelayerChange,
+            IbcSudoChange,
+            Ics20Withdrawal,
+            InitBridgeAccount,
+            MarketsChange,
+            RecoverIbcClient,
+            RegisterMultisig,
+            RollupDataSubmission,
+            SubmitProposal,
+            SudoAddressChange,
+            TimelockedTransfer,
+            Transfer,
+            ValidatorUpdate,
+            VoteOnProposal,
+        },
     },
 };
 use astria_eyre::eyre::bail;
@@ -26,14 +45,16 @@ use borsh::{
     BorshSerialize,
 };
 use penumbra_ibc::IbcRelay;
+use telemetry::display::base64;
+
+use crate::fees::{
+    AssetDistribution as DomainAssetDistribution,
+    Distribution as DomainDistribution,
+};
 
 #[derive(Debug, BorshSerialize, BorshDeserialize)]
 pub(crate) struct Value(ValueImpl);
 
-#[expect(
-    clippy::enum_variant_names,
-    reason = "want to make it clear that these are fees and not actions"
-)]
 #[derive(Debug, BorshSerialize, BorshDeserialize)]
 enum ValueImpl {
     TransferFees(FeeComponents),
@@ -54,6 +75,15 @@ enum ValueImpl {
     RecoverIbcClientFees(FeeComponents),
     CurrencyPairsChangeFees(FeeComponents),
     MarketsChangeFees(FeeComponents),
+    RegisterMultisigFees(FeeComponents),
+    TimelockedTransferFees(FeeComponents),
+    ClaimTimelockedTransferFees(FeeComponents),
+    CancelTimelockedTransferFees(FeeComponents),
+    SubmitProposalFees(FeeComponents),
+    VoteOnProposalFees(FeeComponents),
+    FeeDistributionChangeFees(FeeComponents),
+    Distribution(Distribution),
+    BlockDistribution(Vec<AssetDistribution>),
 }
 
 macro_rules! impl_from_for_fee_storage {
@@ -121,8 +151,158 @@ impl_from_for_fee_storage!(
     DomainFeeComponents<RecoverIbcClient> => RecoverIbcClientFees,
     DomainFeeComponents<CurrencyPairsChange> => CurrencyPairsChangeFees,
     DomainFeeComponents<MarketsChange> => MarketsChangeFees,
+    DomainFeeComponents<RegisterMultisig> => RegisterMultisigFees,
+    DomainFeeComponents<TimelockedTransfer> => TimelockedTransferFees,
+    DomainFeeComponents<ClaimTimelockedTransfer> => ClaimTimelockedTransferFees,
+    DomainFeeComponents<CancelTimelockedTransfer> => CancelTimelockedTransferFees,
+    DomainFeeComponents<SubmitProposal> => SubmitProposalFees,
+    DomainFeeComponents<VoteOnProposal> => VoteOnProposalFees,
+    DomainFeeComponents<FeeDistributionChange> => FeeDistributionChangeFees,
 );
 
+#[derive(BorshSerialize, BorshDeserialize)]
+struct AddressBytes([u8; ADDRESS_LEN]);
+
+impl Debug for AddressBytes {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", base64(self.0.as_slice()))
+    }
+}
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub(in crate::fees) struct Distribution {
+    proposer_basis_points: u32,
+    validators_basis_points: u32,
+    community_pool_basis_points: u32,
+    burn_basis_points: u32,
+    community_pool_address: Option<AddressBytes>,
+}
+
+impl From<&DomainDistribution> for Distribution {
+    fn from(distribution: &DomainDistribution) -> Self {
+        Self {
+            proposer_basis_points: distribution.proposer_basis_points,
+            validators_basis_points: distribution.validators_basis_points,
+            community_pool_basis_points: distribution.community_pool_basis_points,
+            burn_basis_points: distribution.burn_basis_points,
+            community_pool_address: distribution.community_pool_address.map(AddressBytes),
+        }
+    }
+}
+
+impl From<Distribution> for DomainDistribution {
+    fn from(distribution: Distribution) -> Self {
+        Self {
+            proposer_basis_points: distribution.proposer_basis_points,
+            validators_basis_points: distribution.validators_basis_points,
+            community_pool_basis_points: distribution.community_pool_basis_points,
+            burn_basis_points: distribution.burn_basis_points,
+            community_pool_address: distribution.community_pool_address.map(|address| address.0),
+        }
+    }
+}
+
+impl From<Distribution> for crate::storage::StoredValue<'_> {
+    fn from(distribution: Distribution) -> Self {
+        crate::storage::StoredValue::Fees(Value(ValueImpl::Distribution(distribution)))
+    }
+}
+
+impl<'a> TryFrom<crate::storage::StoredValue<'a>> for Distribution {
+    type Error = astria_eyre::eyre::Error;
+
+    fn try_from(value: crate::storage::StoredValue<'a>) -> Result<Self, Self::Error> {
+        let crate::storage::StoredValue::Fees(Value(ValueImpl::Distribution(distribution))) = value
+        else {
+            bail!("fees stored value type mismatch: expected distribution, found {value:?}");
+        };
+        Ok(distribution)
+    }
+}
+
+#[derive(BorshSerialize, BorshDeserialize)]
+struct IbcPrefixedDenom([u8; 32]);
+
+impl Debug for IbcPrefixedDenom {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", IbcPrefixed::new(self.0))
+    }
+}
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub(in crate::fees) struct AssetDistribution {
+    asset: IbcPrefixedDenom,
+    total: u128,
+    proposer: u128,
+    validators: u128,
+    community_pool: u128,
+    burned: u128,
+}
+
+impl From<&DomainAssetDistribution> for AssetDistribution {
+    fn from(distribution: &DomainAssetDistribution) -> Self {
+        Self {
+            asset: IbcPrefixedDenom(*distribution.asset.as_bytes()),
+            total: distribution.total,
+            proposer: distribution.proposer,
+            validators: distribution.validators,
+            community_pool: distribution.community_pool,
+            burned: distribution.burned,
+        }
+    }
+}
+
+impl From<AssetDistribution> for DomainAssetDistribution {
+    fn from(distribution: AssetDistribution) -> Self {
+        Self {
+            asset: IbcPrefixed::new(distribution.asset.0),
+            total: distribution.total,
+            proposer: distribution.proposer,
+            validators: distribution.validators,
+            community_pool: distribution.community_pool,
+            burned: distribution.burned,
+        }
+    }
+}
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub(in crate::fees) struct BlockDistribution(Vec<AssetDistribution>);
+
+impl From<&[DomainAssetDistribution]> for BlockDistribution {
+    fn from(distributions: &[DomainAssetDistribution]) -> Self {
+        Self(distributions.iter().map(AssetDistribution::from).collect())
+    }
+}
+
+impl From<BlockDistribution> for Vec<DomainAssetDistribution> {
+    fn from(distributions: BlockDistribution) -> Self {
+        distributions
+            .0
+            .into_iter()
+            .map(DomainAssetDistribution::from)
+            .collect()
+    }
+}
+
+impl From<BlockDistribution> for crate::storage::StoredValue<'_> {
+    fn from(distributions: BlockDistribution) -> Self {
+        crate::storage::StoredValue::Fees(Value(ValueImpl::BlockDistribution(distributions.0)))
+    }
+}
+
+impl<'a> TryFrom<crate::storage::StoredValue<'a>> for BlockDistribution {
+    type Error = astria_eyre::eyre::Error;
+
+    fn try_from(value: crate::storage::StoredValue<'a>) -> Result<Self, Self::Error> {
+        let crate::storage::StoredValue::Fees(Value(ValueImpl::BlockDistribution(distributions))) =
+            value
+        else {
+            bail!("fees stored value type mismatch: expected block distribution, found {value:?}");
+        };
+        Ok(Self(distributions))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use insta::assert_snapshot;
@@ -213,6 +393,59 @@ mod tests {
             "value_impl_markets_change_fees",
             value_impl_borsh_as_hex!(MarketsChangeFees),
         );
+        assert_snapshot!(
+            "value_impl_register_multisig_fees",
+            value_impl_borsh_as_hex!(RegisterMultisigFees),
+        );
+        assert_snapshot!(
+            "value_impl_timelocked_transfer_fees",
+            value_impl_borsh_as_hex!(TimelockedTransferFees),
+        );
+        assert_snapshot!(
+            "value_impl_claim_timelocked_transfer_fees",
+            value_impl_borsh_as_hex!(ClaimTimelockedTransferFees),
+        );
+        assert_snapshot!(
+            "value_impl_cancel_timelocked_transfer_fees",
+            value_impl_borsh_as_hex!(CancelTimelockedTransferFees),
+        );
+        assert_snapshot!(
+            "value_impl_submit_proposal_fees",
+            value_impl_borsh_as_hex!(SubmitProposalFees),
+        );
+        assert_snapshot!(
+            "value_impl_vote_on_proposal_fees",
+            value_impl_borsh_as_hex!(VoteOnProposalFees),
+        );
+        assert_snapshot!(
+            "value_impl_fee_distribution_change_fees",
+            value_impl_borsh_as_hex!(FeeDistributionChangeFees),
+        );
+    }
+
+    #[test]
+    fn value_impl_distribution_variants_unchanged() {
+        assert_snapshot!(
+            "value_impl_distribution",
+            borsh_then_hex(&ValueImpl::Distribution(Distribution {
+                proposer_basis_points: 5_000,
+                validators_basis_points: 3_000,
+                community_pool_basis_points: 1_500,
+                burn_basis_points: 500,
+                community_pool_address: Some(AddressBytes([1; ADDRESS_LEN])),
+            }))
+        );
+        assert_snapshot!(
+            "value_impl_block_distribution",
+            borsh_then_hex(&ValueImpl::BlockDistribution(vec![AssetDistribution {
+                asset: IbcPrefixedDenom([2; 32]),
+                total: 100,
+                proposer: 50,
+                validators: 30,
+                community_pool: 15,
+                burned: 5,
+            }]))
+        );
     }
 
     // Note: This test must be here instead of in `crate::storage` since `ValueImpl` is not
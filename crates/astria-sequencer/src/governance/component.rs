@@ -0,0 +1,373 @@
+use std::sync::Arc;
+
+use astria_core::{
+    primitive::v1::TransactionId,
+    protocol::{
+        genesis::v1::GenesisAppState,
+        transaction::v1::Action,
+    },
+};
+use astria_eyre::eyre::{
+    OptionExt as _,
+    Result,
+    WrapErr as _,
+};
+use cnidarium::{
+    StateDelta,
+    StateWrite,
+};
+use sha2::{
+    Digest as _,
+    Sha256,
+};
+use tendermint::abci::{
+    request::{
+        BeginBlock,
+        EndBlock,
+    },
+    Event,
+};
+use tracing::{
+    info,
+    instrument,
+    warn,
+    Level,
+};
+
+use super::{
+    account_address_bytes,
+    check_and_execute_governable_action,
+    electorate,
+    Parameters,
+    ProposalStatus,
+    StateReadExt as _,
+    StateWriteExt as _,
+};
+use crate::{
+    app::StateReadExt as _,
+    component::Component,
+    transaction::{
+        StateWriteExt as _,
+        TransactionContext,
+    },
+};
+
+#[derive(Default)]
+pub(crate) struct GovernanceComponent;
+
+#[async_trait::async_trait]
+impl Component for GovernanceComponent {
+    type AppState = GenesisAppState;
+
+    #[instrument(name = "GovernanceComponent::init_chain", skip_all, err)]
+    async fn init_chain<S: StateWrite>(mut state: S, app_state: &Self::AppState) -> Result<()> {
+        if let Some(params) = app_state.governance_parameters() {
+            state
+                .put_governance_parameters(&Parameters::from(params))
+                .wrap_err("failed to write governance parameters to state")?;
+        }
+        Ok(())
+    }
+
+    #[instrument(name = "GovernanceComponent::begin_block", skip_all)]
+    async fn begin_block<S: StateWrite + 'static>(
+        _state: &mut Arc<S>,
+        _begin_block: &BeginBlock,
+    ) -> Result<()> {
+        Ok(())
+    }
+
+    /// Tallies the votes of all open proposals, executing those that were approved and rejecting
+    /// those whose voting period has ended.
+    #[instrument(name = "GovernanceComponent::end_block", skip_all, err(level = Level::WARN))]
+    async fn end_block<S: StateWrite + 'static>(
+        state: &mut Arc<S>,
+        _end_block: &EndBlock,
+    ) -> Result<()> {
+        let state = Arc::get_mut(state)
+            .ok_or_eyre("must only have one reference to the state; this is a bug")?;
+
+        let Some(params) = state
+            .get_governance_parameters()
+            .await
+            .wrap_err("failed to get governance parameters")?
+        else {
+            return Ok(());
+        };
+        let open_proposal_ids = state
+            .get_open_proposal_ids()
+            .await
+            .wrap_err("failed to get open proposal ids")?;
+        if open_proposal_ids.is_empty() {
+            return Ok(());
+        }
+
+        let height = state
+            .get_block_height()
+            .await
+            .wrap_err("failed to get block height")?;
+        let electorate = electorate(state, &params)
+            .await
+            .wrap_err("failed to determine governance electorate")?;
+
+        let mut still_open = Vec::with_capacity(open_proposal_ids.len());
+        for id in open_proposal_ids {
+            let mut proposal = state
+                .get_proposal(id)
+                .await
+                .wrap_err("failed to get proposal")?
+                .ok_or_eyre("open proposal not found in state")?;
+            if proposal.is_approved(&electorate, params.approval_threshold_basis_points) {
+                proposal.status = execute_proposal(state, id, &proposal.action).await;
+            } else if height >= proposal.voting_end_height {
+                proposal.status = ProposalStatus::Rejected;
+            } else {
+                still_open.push(id);
+                continue;
+            }
+            info!(
+                id,
+                status = proposal.status.as_str(),
+                "closed governance proposal"
+            );
+            state.record(construct_proposal_closed_event(id, proposal.status));
+            state
+                .put_proposal(id, &proposal)
+                .wrap_err("failed to put proposal")?;
+        }
+        state
+            .put_open_proposal_ids(&still_open)
+            .wrap_err("failed to put open proposal ids")?;
+        Ok(())
+    }
+}
+
+/// Executes the action of the approved proposal `id` as the governance account.
+///
+/// Execution happens on a fork of `state` which is only applied if it succeeds, so that a failing
+/// action neither leaves partial writes behind nor fails the block.
+async fn execute_proposal<S: StateWrite>(
+    state: &mut S,
+    id: u64,
+    action: &Action,
+) -> ProposalStatus {
+    let mut fork = StateDelta::new(&mut *state);
+    fork.put_transaction_context(TransactionContext {
+        address_bytes: account_address_bytes(),
+        transaction_id: proposal_transaction_id(id),
+        position_in_transaction: 0,
+    });
+    let result = check_and_execute_governable_action(action, &mut fork).await;
+    fork.delete_current_transaction_context();
+    match result {
+        Ok(()) => {
+            // events recorded on the fork are not carried over when applying it
+            let (state, events) = fork.apply();
+            for event in events {
+                state.record(event);
+            }
+            if action.is_fee_change() || action.is_fee_asset_change() {
+                state.put_governance_fees_changed_in_block();
+            }
+            ProposalStatus::Executed
+        }
+        Err(error) => {
+            warn!(
+                id,
+                error = AsRef::<dyn std::error::Error>::as_ref(&error),
+                "failed executing approved governance proposal",
+            );
+            ProposalStatus::ExecutionFailed
+        }
+    }
+}
+
+/// Returns the transaction ID under which the action of proposal `id` is executed.
+fn proposal_transaction_id(id: u64) -> TransactionId {
+    TransactionId::new(Sha256::digest(format!("astria/governance/proposal/{id}")).into())
+}
+
+fn construct_proposal_closed_event(id: u64, status: ProposalStatus) -> Event {
+    Event::new(
+        "governance.proposalClosed",
+        [
+            ("id", id.to_string()),
+            ("status", status.as_str().to_string()),
+        ],
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use astria_core::protocol::{
+        fees::v1::FeeComponents,
+        transaction::v1::action::{
+            FeeChange,
+            SudoAddressChange,
+        },
+    };
+    use cnidarium::Snapshot;
+
+    use super::*;
+    use crate::{
+        address::StateWriteExt as _,
+        app::StateWriteExt as _,
+        authority::{
+            StateReadExt as _,
+            StateWriteExt as _,
+        },
+        benchmark_and_test_utils::{
+            astria_address,
+            ASTRIA_PREFIX,
+        },
+        fees::StateReadExt as _,
+        governance::Proposal,
+    };
+
+    const COUNCIL: [[u8; 20]; 3] = [[1; 20], [2; 20], [3; 20]];
+
+    fn params() -> Parameters {
+        Parameters {
+            proposers: vec![COUNCIL[0]],
+            council: COUNCIL.to_vec(),
+            voting_period_blocks: 10,
+            approval_threshold_basis_points: 5_000,
+        }
+    }
+
+    fn proposal(action: Action, votes: &[([u8; 20], bool)]) -> Proposal {
+        Proposal {
+            proposer: COUNCIL[0],
+            action,
+            submitted_height: 1,
+            voting_end_height: 11,
+            status: ProposalStatus::Open,
+            votes: votes.iter().copied().collect(),
+        }
+    }
+
+    fn sudo_address_change() -> Action {
+        Action::SudoAddressChange(SudoAddressChange {
+            new_address: astria_address(&[9; 20]),
+        })
+    }
+
+    fn setup(storage: &cnidarium::TempStorage, height: u64) -> StateDelta<Snapshot> {
+        let mut state = StateDelta::new(storage.latest_snapshot());
+        state.put_base_prefix(ASTRIA_PREFIX.to_string()).unwrap();
+        state.put_governance_parameters(&params()).unwrap();
+        state.put_block_height(height).unwrap();
+        state.put_sudo_address(account_address_bytes()).unwrap();
+        state
+    }
+
+    async fn end_block(state: StateDelta<Snapshot>) -> (StateDelta<Snapshot>, Vec<Event>) {
+        let mut state = Arc::new(StateDelta::new(state));
+        GovernanceComponent::end_block(
+            &mut state,
+            &EndBlock {
+                height: 1u32.into(),
+            },
+        )
+        .await
+        .unwrap();
+        Arc::try_unwrap(state).unwrap().apply()
+    }
+
+    #[tokio::test]
+    async fn approved_proposal_is_executed_as_governance_account() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let mut state = setup(&storage, 5);
+        let votes = [(COUNCIL[0], true), (COUNCIL[1], true)];
+        state
+            .put_proposal(0, &proposal(sudo_address_change(), &votes))
+            .unwrap();
+        state.put_open_proposal_ids(&[0]).unwrap();
+
+        let (state, events) = end_block(state).await;
+
+        assert_eq!(state.get_sudo_address().await.unwrap(), [9; 20]);
+        assert_eq!(
+            state.get_proposal(0).await.unwrap().unwrap().status,
+            ProposalStatus::Executed
+        );
+        assert!(state.get_open_proposal_ids().await.unwrap().is_empty());
+        assert_eq!(
+            events,
+            vec![construct_proposal_closed_event(0, ProposalStatus::Executed)]
+        );
+    }
+
+    #[tokio::test]
+    async fn failing_proposal_is_closed_without_changes() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let mut state = setup(&storage, 5);
+        state.put_sudo_address([8; 20]).unwrap();
+        let votes = [(COUNCIL[0], true), (COUNCIL[1], true)];
+        state
+            .put_proposal(0, &proposal(sudo_address_change(), &votes))
+            .unwrap();
+        state.put_open_proposal_ids(&[0]).unwrap();
+
+        let (state, _) = end_block(state).await;
+
+        assert_eq!(state.get_sudo_address().await.unwrap(), [8; 20]);
+        assert_eq!(
+            state.get_proposal(0).await.unwrap().unwrap().status,
+            ProposalStatus::ExecutionFailed
+        );
+        assert!(state.get_open_proposal_ids().await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn unapproved_proposal_stays_open_until_voting_ends() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let mut state = setup(&storage, 10);
+        let votes = [(COUNCIL[0], true), (COUNCIL[1], false)];
+        state
+            .put_proposal(0, &proposal(sudo_address_change(), &votes))
+            .unwrap();
+        state.put_open_proposal_ids(&[0]).unwrap();
+
+        let (mut state, events) = end_block(state).await;
+        assert!(events.is_empty());
+        assert_eq!(state.get_open_proposal_ids().await.unwrap(), vec![0]);
+
+        state.put_block_height(11).unwrap();
+        let (state, events) = end_block(state).await;
+        assert_eq!(
+            events,
+            vec![construct_proposal_closed_event(0, ProposalStatus::Rejected)]
+        );
+        assert_eq!(
+            state.get_proposal(0).await.unwrap().unwrap().status,
+            ProposalStatus::Rejected
+        );
+        assert_eq!(
+            state.get_sudo_address().await.unwrap(),
+            account_address_bytes()
+        );
+    }
+
+    #[tokio::test]
+    async fn executed_fee_change_is_flagged() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let mut state = setup(&storage, 5);
+        let fee_change = Action::FeeChange(FeeChange::Transfer(FeeComponents::new(1, 2)));
+        let votes = [(COUNCIL[0], true), (COUNCIL[1], true)];
+        state
+            .put_proposal(0, &proposal(fee_change, &votes))
+            .unwrap();
+        state.put_open_proposal_ids(&[0]).unwrap();
+
+        let (state, _) = end_block(state).await;
+
+        assert!(state.get_governance_fees_changed_in_block());
+        assert_eq!(
+            state.get_fees().await.unwrap(),
+            Some(FeeComponents::<
+                astria_core::protocol::transaction::v1::action::Transfer,
+            >::new(1, 2))
+        );
+    }
+}
@@ -0,0 +1,273 @@
+pub(crate) mod component;
+pub(crate) mod query;
+mod state_ext;
+pub(crate) mod storage;
+
+use std::collections::BTreeMap;
+
+use astria_core::{
+    primitive::v1::ADDRESS_LEN,
+    protocol::{
+        genesis::v1::{
+            GovernanceParameters,
+            MAX_APPROVAL_THRESHOLD_BASIS_POINTS,
+        },
+        transaction::v1::{
+            action::ActionName as _,
+            Action,
+        },
+    },
+};
+use astria_eyre::eyre::{
+    bail,
+    Result,
+    WrapErr as _,
+};
+use cnidarium::{
+    StateRead,
+    StateWrite,
+};
+use sha2::{
+    Digest as _,
+    Sha256,
+};
+pub(crate) use state_ext::{
+    StateReadExt,
+    StateWriteExt,
+};
+
+use crate::{
+    action_handler::ActionHandler as _,
+    authority::validator_powers,
+};
+
+/// Returns the address of the governance account.
+///
+/// Approved proposals execute their action as if it had been signed by this account. It is the
+/// first 20 bytes of the SHA-256 digest of `astria/governance`, so no key exists for it. Sudo
+/// actions can only be governed once this account was made the sudo (or IBC sudo) address.
+pub(crate) fn account_address_bytes() -> [u8; ADDRESS_LEN] {
+    let digest = Sha256::digest(b"astria/governance");
+    let mut address = [0; ADDRESS_LEN];
+    address.copy_from_slice(&digest[..ADDRESS_LEN]);
+    address
+}
+
+/// The governance parameters set at genesis, with addresses stored as raw bytes.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct Parameters {
+    pub(crate) proposers: Vec<[u8; ADDRESS_LEN]>,
+    pub(crate) council: Vec<[u8; ADDRESS_LEN]>,
+    pub(crate) voting_period_blocks: u64,
+    pub(crate) approval_threshold_basis_points: u32,
+}
+
+impl Parameters {
+    pub(crate) fn is_proposer(&self, address: &[u8; ADDRESS_LEN]) -> bool {
+        self.proposers.contains(address)
+    }
+}
+
+impl From<&GovernanceParameters> for Parameters {
+    fn from(params: &GovernanceParameters) -> Self {
+        Self {
+            proposers: params
+                .proposers
+                .iter()
+                .map(|address| address.bytes())
+                .collect(),
+            council: params
+                .council
+                .iter()
+                .map(|address| address.bytes())
+                .collect(),
+            voting_period_blocks: params.voting_period_blocks,
+            approval_threshold_basis_points: params.approval_threshold_basis_points,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum ProposalStatus {
+    /// The proposal can be voted on.
+    Open,
+    /// The proposal was approved and its action executed successfully.
+    Executed,
+    /// The proposal was approved, but executing its action failed. None of its state changes
+    /// were applied.
+    ExecutionFailed,
+    /// The voting period ended without the proposal being approved.
+    Rejected,
+}
+
+impl ProposalStatus {
+    pub(crate) fn as_str(self) -> &'static str {
+        match self {
+            Self::Open => "open",
+            Self::Executed => "executed",
+            Self::ExecutionFailed => "execution_failed",
+            Self::Rejected => "rejected",
+        }
+    }
+}
+
+/// A proposal to execute a sudo action on behalf of the governance account.
+#[derive(Clone, Debug)]
+pub(crate) struct Proposal {
+    pub(crate) proposer: [u8; ADDRESS_LEN],
+    pub(crate) action: Action,
+    pub(crate) submitted_height: u64,
+    /// The first height at which the proposal can no longer be voted on.
+    pub(crate) voting_end_height: u64,
+    pub(crate) status: ProposalStatus,
+    /// The latest vote of each voter, `true` if approving.
+    pub(crate) votes: BTreeMap<[u8; ADDRESS_LEN], bool>,
+}
+
+// `Action` does not implement `PartialEq`, so compare its protobuf representation instead.
+#[cfg(test)]
+impl PartialEq for Proposal {
+    fn eq(&self, other: &Self) -> bool {
+        use astria_core::Protobuf as _;
+
+        self.proposer == other.proposer
+            && self.action.to_raw() == other.action.to_raw()
+            && self.submitted_height == other.submitted_height
+            && self.voting_end_height == other.voting_end_height
+            && self.status == other.status
+            && self.votes == other.votes
+    }
+}
+
+impl Proposal {
+    /// Returns whether the approving votes of the current `electorate` exceed
+    /// `approval_threshold_basis_points` of its total weight.
+    ///
+    /// Votes cast by addresses no longer in the electorate are ignored.
+    pub(crate) fn is_approved(
+        &self,
+        electorate: &BTreeMap<[u8; ADDRESS_LEN], u64>,
+        approval_threshold_basis_points: u32,
+    ) -> bool {
+        let total: u128 = electorate.values().copied().map(u128::from).sum();
+        let approving: u128 = self
+            .votes
+            .iter()
+            .filter(|(_, approve)| **approve)
+            .filter_map(|(voter, _)| electorate.get(voter).copied().map(u128::from))
+            .sum();
+        approving.saturating_mul(u128::from(MAX_APPROVAL_THRESHOLD_BASIS_POINTS))
+            > total.saturating_mul(u128::from(approval_threshold_basis_points))
+    }
+}
+
+/// Returns the addresses allowed to vote on proposals, mapped to the weight of their votes.
+///
+/// Each member of a configured council has a weight of 1. Without a council, validators vote
+/// (signing with their consensus key) weighted by their voting power.
+pub(crate) async fn electorate<S: StateRead>(
+    state: &S,
+    params: &Parameters,
+) -> Result<BTreeMap<[u8; ADDRESS_LEN], u64>> {
+    if !params.council.is_empty() {
+        return Ok(params.council.iter().map(|member| (*member, 1)).collect());
+    }
+    validator_powers(state)
+        .await
+        .wrap_err("failed to get validator voting powers")
+}
+
+/// Runs the stateless checks of an action wrapped in a proposal.
+pub(crate) async fn check_stateless_governable_action(action: &Action) -> Result<()> {
+    match action {
+        Action::SudoAddressChange(act) => act.check_stateless().await,
+        Action::IbcSudoChange(act) => act.check_stateless().await,
+        Action::IbcRelayerChange(act) => act.check_stateless().await,
+        Action::FeeChange(act) => act.check_stateless().await,
+        Action::FeeAssetChange(act) => act.check_stateless().await,
+        Action::RecoverIbcClient(act) => act.check_stateless().await,
+        Action::CurrencyPairsChange(act) => act.check_stateless().await,
+        Action::MarketsChange(act) => act.check_stateless().await,
+        Action::ValidatorUpdate(act) => act.check_stateless().await,
+        Action::FeeDistributionChange(act) => act.check_stateless().await,
+        other => bail!("`{}` actions cannot be governed", other.name()),
+    }
+}
+
+/// Checks and executes an action wrapped in an approved proposal.
+///
+/// The transaction context of `state` must name the governance account as the signer.
+pub(crate) async fn check_and_execute_governable_action<S: StateWrite>(
+    action: &Action,
+    state: S,
+) -> Result<()> {
+    match action {
+        Action::SudoAddressChange(act) => act.check_and_execute(state).await,
+        Action::IbcSudoChange(act) => act.check_and_execute(state).await,
+        Action::IbcRelayerChange(act) => act.check_and_execute(state).await,
+        Action::FeeChange(act) => act.check_and_execute(state).await,
+        Action::FeeAssetChange(act) => act.check_and_execute(state).await,
+        Action::RecoverIbcClient(act) => act.check_and_execute(state).await,
+        Action::CurrencyPairsChange(act) => act.check_and_execute(state).await,
+        Action::MarketsChange(act) => act.check_and_execute(state).await,
+        Action::ValidatorUpdate(act) => act.check_and_execute(state).await,
+        Action::FeeDistributionChange(act) => act.check_and_execute(state).await,
+        other => bail!("`{}` actions cannot be governed", other.name()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use astria_core::protocol::transaction::v1::action::SudoAddressChange;
+
+    use super::*;
+    use crate::benchmark_and_test_utils::astria_address;
+
+    fn proposal(votes: &[([u8; ADDRESS_LEN], bool)]) -> Proposal {
+        Proposal {
+            proposer: [1; ADDRESS_LEN],
+            action: Action::SudoAddressChange(SudoAddressChange {
+                new_address: astria_address(&[2; ADDRESS_LEN]),
+            }),
+            submitted_height: 1,
+            voting_end_height: 11,
+            status: ProposalStatus::Open,
+            votes: votes.iter().copied().collect(),
+        }
+    }
+
+    #[test]
+    fn account_address_should_not_change() {
+        insta::assert_snapshot!(
+            "governance_account_address",
+            hex::encode(account_address_bytes())
+        );
+    }
+
+    #[test]
+    fn approval_must_exceed_threshold() {
+        let electorate = [([1; ADDRESS_LEN], 2), ([2; ADDRESS_LEN], 1)]
+            .into_iter()
+            .collect();
+        // 2 of 3 is 6_666.67 basis points
+        let proposal = proposal(&[([1; ADDRESS_LEN], true), ([2; ADDRESS_LEN], false)]);
+        assert!(proposal.is_approved(&electorate, 6_666));
+        assert!(!proposal.is_approved(&electorate, 6_667));
+    }
+
+    #[test]
+    fn votes_outside_electorate_are_ignored() {
+        let electorate = [([1; ADDRESS_LEN], 1), ([2; ADDRESS_LEN], 1)]
+            .into_iter()
+            .collect();
+        let proposal = proposal(&[([1; ADDRESS_LEN], true), ([3; ADDRESS_LEN], true)]);
+        assert!(!proposal.is_approved(&electorate, 5_000));
+        assert!(proposal.is_approved(&electorate, 4_999));
+    }
+
+    #[test]
+    fn empty_electorate_approves_nothing() {
+        let proposal = proposal(&[([1; ADDRESS_LEN], true)]);
+        assert!(!proposal.is_approved(&BTreeMap::new(), 0));
+    }
+}
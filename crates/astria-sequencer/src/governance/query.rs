@@ -0,0 +1,445 @@
+use astria_core::{
+    protocol::abci::AbciErrorCode,
+    Protobuf as _,
+};
+use astria_eyre::eyre::{
+    Result,
+    WrapErr as _,
+};
+use cnidarium::{
+    StateRead,
+    Storage,
+};
+use tendermint::abci::{
+    request,
+    response,
+    Code,
+};
+use tracing::{
+    instrument,
+    Level,
+};
+
+use super::{
+    account_address_bytes,
+    Parameters,
+    Proposal,
+    StateReadExt as _,
+};
+use crate::{
+    address::StateReadExt as _,
+    app::StateReadExt as _,
+};
+
+#[derive(serde::Serialize)]
+struct ParametersResponse {
+    height: u64,
+    governance_address: String,
+    parameters: Option<ParametersInner>,
+}
+
+#[derive(serde::Serialize)]
+struct ParametersInner {
+    proposers: Vec<String>,
+    council: Vec<String>,
+    voting_period_blocks: u64,
+    approval_threshold_basis_points: u32,
+}
+
+#[derive(serde::Serialize)]
+struct ProposalResponse {
+    height: u64,
+    proposal: Option<ProposalInner>,
+}
+
+#[derive(serde::Serialize)]
+struct ProposalInner {
+    id: u64,
+    proposer: String,
+    action: serde_json::Value,
+    submitted_height: u64,
+    voting_end_height: u64,
+    status: &'static str,
+    votes: Vec<VoteResponse>,
+}
+
+#[derive(serde::Serialize)]
+struct VoteResponse {
+    voter: String,
+    approve: bool,
+}
+
+#[instrument(skip_all, err(level = Level::DEBUG))]
+async fn parameters_inner<S: StateRead>(state: &S, params: Parameters) -> Result<ParametersInner> {
+    let mut proposers = Vec::with_capacity(params.proposers.len());
+    for proposer in &params.proposers {
+        proposers.push(
+            state
+                .try_base_prefixed(proposer)
+                .await
+                .wrap_err("failed to construct bech32m address of a proposer")?
+                .to_string(),
+        );
+    }
+    let mut council = Vec::with_capacity(params.council.len());
+    for member in &params.council {
+        council.push(
+            state
+                .try_base_prefixed(member)
+                .await
+                .wrap_err("failed to construct bech32m address of a council member")?
+                .to_string(),
+        );
+    }
+    Ok(ParametersInner {
+        proposers,
+        council,
+        voting_period_blocks: params.voting_period_blocks,
+        approval_threshold_basis_points: params.approval_threshold_basis_points,
+    })
+}
+
+#[instrument(skip_all, fields(id), err(level = Level::DEBUG))]
+async fn proposal_inner<S: StateRead>(
+    state: &S,
+    id: u64,
+    proposal: Proposal,
+) -> Result<ProposalInner> {
+    let proposer = state
+        .try_base_prefixed(&proposal.proposer)
+        .await
+        .wrap_err("failed to construct bech32m address of the proposer")?;
+    let mut votes = Vec::with_capacity(proposal.votes.len());
+    for (voter, approve) in &proposal.votes {
+        let voter = state
+            .try_base_prefixed(voter)
+            .await
+            .wrap_err("failed to construct bech32m address of a voter")?;
+        votes.push(VoteResponse {
+            voter: voter.to_string(),
+            approve: *approve,
+        });
+    }
+    Ok(ProposalInner {
+        id,
+        proposer: proposer.to_string(),
+        action: serde_json::to_value(proposal.action.to_raw())
+            .wrap_err("failed to serialize proposal action")?,
+        submitted_height: proposal.submitted_height,
+        voting_end_height: proposal.voting_end_height,
+        status: proposal.status.as_str(),
+        votes,
+    })
+}
+
+fn internal_error(log: String) -> response::Query {
+    response::Query {
+        code: Code::Err(AbciErrorCode::INTERNAL_ERROR.value()),
+        info: AbciErrorCode::INTERNAL_ERROR.info(),
+        log,
+        ..response::Query::default()
+    }
+}
+
+fn json_response<T: serde::Serialize>(
+    request: &request::Query,
+    height: u64,
+    payload: &T,
+) -> response::Query {
+    let height = tendermint::block::Height::try_from(height).expect("height must fit into an i64");
+    response::Query {
+        code: 0.into(),
+        key: request.path.clone().into_bytes().into(),
+        value: serde_json::to_vec(payload)
+            .expect("object does not contain keys that don't map to json keys")
+            .into(),
+        height,
+        ..response::Query::default()
+    }
+}
+
+/// Returns the governance parameters and the address of the governance account as JSON.
+///
+/// The `parameters` field of the response is `null` if governance was not configured at genesis.
+#[instrument(skip_all)]
+pub(crate) async fn parameters_request(
+    storage: Storage,
+    request: request::Query,
+    _params: Vec<(String, String)>,
+) -> response::Query {
+    let snapshot = storage.latest_snapshot();
+    let height = match snapshot.get_block_height().await {
+        Ok(height) => height,
+        Err(err) => return internal_error(format!("failed to get block height: {err:#}")),
+    };
+    let governance_address = match snapshot.try_base_prefixed(&account_address_bytes()).await {
+        Ok(address) => address.to_string(),
+        Err(err) => {
+            return internal_error(format!(
+                "failed to construct bech32m address of the governance account: {err:#}"
+            ));
+        }
+    };
+    let parameters = match snapshot.get_governance_parameters().await {
+        Ok(Some(params)) => match parameters_inner(&snapshot, params).await {
+            Ok(params) => Some(params),
+            Err(err) => {
+                return internal_error(format!(
+                    "failed to construct governance parameters response: {err:#}"
+                ));
+            }
+        },
+        Ok(None) => None,
+        Err(err) => {
+            return internal_error(format!("failed to get governance parameters: {err:#}"));
+        }
+    };
+
+    json_response(
+        &request,
+        height,
+        &ParametersResponse {
+            height,
+            governance_address,
+            parameters,
+        },
+    )
+}
+
+/// Returns the proposal with the `id` path parameter, including its votes, as JSON.
+///
+/// The `proposal` field of the response is `null` if no proposal with that ID was submitted.
+#[instrument(skip_all)]
+pub(crate) async fn proposal_request(
+    storage: Storage,
+    request: request::Query,
+    params: Vec<(String, String)>,
+) -> response::Query {
+    let id = match parse_id(&params) {
+        Ok(id) => id,
+        Err(err_rsp) => return err_rsp,
+    };
+
+    let snapshot = storage.latest_snapshot();
+    let height = match snapshot.get_block_height().await {
+        Ok(height) => height,
+        Err(err) => return internal_error(format!("failed to get block height: {err:#}")),
+    };
+    let proposal = match snapshot.get_proposal(id).await {
+        Ok(Some(proposal)) => match proposal_inner(&snapshot, id, proposal).await {
+            Ok(proposal) => Some(proposal),
+            Err(err) => {
+                return internal_error(format!("failed to construct proposal response: {err:#}"));
+            }
+        },
+        Ok(None) => None,
+        Err(err) => return internal_error(format!("failed to get proposal: {err:#}")),
+    };
+
+    json_response(
+        &request,
+        height,
+        &ProposalResponse {
+            height,
+            proposal,
+        },
+    )
+}
+
+fn parse_id(params: &[(String, String)]) -> Result<u64, response::Query> {
+    let Some(id) = params.iter().find_map(|(k, v)| (k == "id").then_some(v)) else {
+        return Err(response::Query {
+            code: Code::Err(AbciErrorCode::INVALID_PARAMETER.value()),
+            info: AbciErrorCode::INVALID_PARAMETER.info(),
+            log: "path did not contain id parameter".into(),
+            ..response::Query::default()
+        });
+    };
+    id.parse().map_err(|err| response::Query {
+        code: Code::Err(AbciErrorCode::INVALID_PARAMETER.value()),
+        info: AbciErrorCode::INVALID_PARAMETER.info(),
+        log: format!("id could not be parsed from provided parameter: {err:#}"),
+        ..response::Query::default()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use astria_core::protocol::transaction::v1::{
+        action::SudoAddressChange,
+        Action,
+    };
+    use cnidarium::StateDelta;
+
+    use super::*;
+    use crate::{
+        address::StateWriteExt as _,
+        app::StateWriteExt as _,
+        benchmark_and_test_utils::{
+            astria_address,
+            ASTRIA_PREFIX,
+        },
+        governance::{
+            ProposalStatus,
+            StateWriteExt as _,
+        },
+    };
+
+    fn request(path: &str) -> request::Query {
+        request::Query {
+            data: vec![].into(),
+            path: path.to_string(),
+            height: 0u32.into(),
+            prove: false,
+        }
+    }
+
+    fn id_params(id: &str) -> Vec<(String, String)> {
+        vec![("id".to_string(), id.to_string())]
+    }
+
+    fn ok_json(response: &response::Query) -> serde_json::Value {
+        assert!(response.code.is_ok(), "{}", response.log);
+        serde_json::from_slice(&response.value).unwrap()
+    }
+
+    #[tokio::test]
+    async fn parameters_request_ok() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let mut state = StateDelta::new(storage.latest_snapshot());
+        state.put_base_prefix(ASTRIA_PREFIX.to_string()).unwrap();
+        state.put_block_height(1).unwrap();
+        storage.commit(state).await.unwrap();
+
+        let governance_address = astria_address(&account_address_bytes()).to_string();
+        assert_eq!(
+            ok_json(
+                &parameters_request(
+                    Storage::clone(&storage),
+                    request("governance/parameters"),
+                    vec![]
+                )
+                .await
+            ),
+            serde_json::json!({
+                "height": 1,
+                "governance_address": governance_address,
+                "parameters": null,
+            })
+        );
+
+        let mut state = StateDelta::new(storage.latest_snapshot());
+        state
+            .put_governance_parameters(&Parameters {
+                proposers: vec![[1; 20]],
+                council: vec![[2; 20]],
+                voting_period_blocks: 10,
+                approval_threshold_basis_points: 5_000,
+            })
+            .unwrap();
+        storage.commit(state).await.unwrap();
+
+        assert_eq!(
+            ok_json(
+                &parameters_request(
+                    Storage::clone(&storage),
+                    request("governance/parameters"),
+                    vec![]
+                )
+                .await
+            ),
+            serde_json::json!({
+                "height": 1,
+                "governance_address": governance_address,
+                "parameters": {
+                    "proposers": [astria_address(&[1; 20]).to_string()],
+                    "council": [astria_address(&[2; 20]).to_string()],
+                    "voting_period_blocks": 10,
+                    "approval_threshold_basis_points": 5_000,
+                },
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn proposal_request_ok() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let mut state = StateDelta::new(storage.latest_snapshot());
+        state.put_base_prefix(ASTRIA_PREFIX.to_string()).unwrap();
+        state.put_block_height(1).unwrap();
+        let action = Action::SudoAddressChange(SudoAddressChange {
+            new_address: astria_address(&[9; 20]),
+        });
+        state
+            .put_proposal(
+                3,
+                &Proposal {
+                    proposer: [1; 20],
+                    action: action.clone(),
+                    submitted_height: 1,
+                    voting_end_height: 11,
+                    status: ProposalStatus::Open,
+                    votes: BTreeMap::from([([2; 20], true)]),
+                },
+            )
+            .unwrap();
+        storage.commit(state).await.unwrap();
+
+        assert_eq!(
+            ok_json(
+                &proposal_request(
+                    Storage::clone(&storage),
+                    request("governance/proposal/3"),
+                    id_params("3"),
+                )
+                .await
+            ),
+            serde_json::json!({
+                "height": 1,
+                "proposal": {
+                    "id": 3,
+                    "proposer": astria_address(&[1; 20]).to_string(),
+                    "action": serde_json::to_value(action.to_raw()).unwrap(),
+                    "submitted_height": 1,
+                    "voting_end_height": 11,
+                    "status": "open",
+                    "votes": [{
+                        "voter": astria_address(&[2; 20]).to_string(),
+                        "approve": true,
+                    }],
+                },
+            })
+        );
+        assert_eq!(
+            ok_json(
+                &proposal_request(
+                    Storage::clone(&storage),
+                    request("governance/proposal/4"),
+                    id_params("4"),
+                )
+                .await
+            ),
+            serde_json::json!({
+                "height": 1,
+                "proposal": null,
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn proposal_request_rejects_invalid_id() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let response = proposal_request(
+            Storage::clone(&storage),
+            request("governance/proposal/abc"),
+            id_params("abc"),
+        )
+        .await;
+        assert_eq!(
+            response.code,
+            Code::Err(AbciErrorCode::INVALID_PARAMETER.value())
+        );
+    }
+}
@@ -0,0 +1,252 @@
+use astria_eyre::{
+    anyhow_to_eyre,
+    eyre::{
+        Result,
+        WrapErr as _,
+    },
+};
+use async_trait::async_trait;
+use cnidarium::{
+    StateRead,
+    StateWrite,
+};
+use tracing::{
+    instrument,
+    Level,
+};
+
+use super::{
+    storage::{
+        self,
+        keys,
+    },
+    Parameters,
+    Proposal,
+};
+use crate::storage::StoredValue;
+
+#[async_trait]
+pub(crate) trait StateReadExt: StateRead {
+    /// Returns the governance parameters, or `None` if governance was not configured at genesis.
+    #[instrument(skip_all, err(level = Level::WARN))]
+    async fn get_governance_parameters(&self) -> Result<Option<Parameters>> {
+        let Some(bytes) = self
+            .get_raw(keys::PARAMETERS)
+            .await
+            .map_err(anyhow_to_eyre)
+            .wrap_err("failed reading raw governance parameters from state")?
+        else {
+            return Ok(None);
+        };
+        StoredValue::deserialize(&bytes)
+            .and_then(|value| {
+                storage::Parameters::try_from(value).map(|params| Some(Parameters::from(params)))
+            })
+            .wrap_err("invalid governance parameters bytes")
+    }
+
+    /// Returns the ID that will be assigned to the next proposal.
+    #[instrument(skip_all, err(level = Level::WARN))]
+    async fn get_next_proposal_id(&self) -> Result<u64> {
+        let Some(bytes) = self
+            .get_raw(keys::NEXT_PROPOSAL_ID)
+            .await
+            .map_err(anyhow_to_eyre)
+            .wrap_err("failed reading raw next proposal id from state")?
+        else {
+            return Ok(0);
+        };
+        StoredValue::deserialize(&bytes)
+            .and_then(|value| storage::ProposalId::try_from(value).map(u64::from))
+            .wrap_err("invalid next proposal id bytes")
+    }
+
+    /// Returns the IDs of all proposals that are still open for voting, in ascending order.
+    #[instrument(skip_all, err(level = Level::WARN))]
+    async fn get_open_proposal_ids(&self) -> Result<Vec<u64>> {
+        let Some(bytes) = self
+            .get_raw(keys::OPEN_PROPOSAL_IDS)
+            .await
+            .map_err(anyhow_to_eyre)
+            .wrap_err("failed reading raw open proposal ids from state")?
+        else {
+            return Ok(vec![]);
+        };
+        StoredValue::deserialize(&bytes)
+            .and_then(|value| storage::ProposalIds::try_from(value).map(Vec::from))
+            .wrap_err("invalid open proposal ids bytes")
+    }
+
+    /// Returns the proposal with `id`, or `None` if no such proposal was ever submitted.
+    #[instrument(skip_all, fields(id), err(level = Level::WARN))]
+    async fn get_proposal(&self, id: u64) -> Result<Option<Proposal>> {
+        let Some(bytes) = self
+            .get_raw(&keys::proposal(id))
+            .await
+            .map_err(anyhow_to_eyre)
+            .wrap_err("failed reading raw proposal from state")?
+        else {
+            return Ok(None);
+        };
+        StoredValue::deserialize(&bytes)
+            .and_then(|value| {
+                storage::Proposal::try_from(value).map(|proposal| Some(Proposal::from(proposal)))
+            })
+            .wrap_err("invalid proposal bytes")
+    }
+
+    /// Returns whether a proposal executed in the current block changed fees or fee assets.
+    fn get_governance_fees_changed_in_block(&self) -> bool {
+        self.object_get(keys::FEES_CHANGED_IN_BLOCK)
+            .unwrap_or_default()
+    }
+}
+
+impl<T: StateRead + ?Sized> StateReadExt for T {}
+
+#[async_trait]
+pub(crate) trait StateWriteExt: StateWrite {
+    #[instrument(skip_all)]
+    fn put_governance_parameters(&mut self, params: &Parameters) -> Result<()> {
+        let bytes = StoredValue::from(storage::Parameters::from(params))
+            .serialize()
+            .wrap_err("failed to serialize governance parameters")?;
+        self.put_raw(keys::PARAMETERS.to_string(), bytes);
+        Ok(())
+    }
+
+    #[instrument(skip_all)]
+    fn put_next_proposal_id(&mut self, id: u64) -> Result<()> {
+        let bytes = StoredValue::from(storage::ProposalId::from(id))
+            .serialize()
+            .wrap_err("failed to serialize next proposal id")?;
+        self.put_raw(keys::NEXT_PROPOSAL_ID.to_string(), bytes);
+        Ok(())
+    }
+
+    #[instrument(skip_all)]
+    fn put_open_proposal_ids(&mut self, ids: &[u64]) -> Result<()> {
+        let bytes = StoredValue::from(storage::ProposalIds::from(ids))
+            .serialize()
+            .wrap_err("failed to serialize open proposal ids")?;
+        self.put_raw(keys::OPEN_PROPOSAL_IDS.to_string(), bytes);
+        Ok(())
+    }
+
+    #[instrument(skip_all, fields(id))]
+    fn put_proposal(&mut self, id: u64, proposal: &Proposal) -> Result<()> {
+        let bytes = StoredValue::from(storage::Proposal::from(proposal))
+            .serialize()
+            .wrap_err("failed to serialize proposal")?;
+        self.put_raw(keys::proposal(id), bytes);
+        Ok(())
+    }
+
+    fn put_governance_fees_changed_in_block(&mut self) {
+        self.object_put(keys::FEES_CHANGED_IN_BLOCK, true);
+    }
+}
+
+impl<T: StateWrite> StateWriteExt for T {}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use astria_core::protocol::transaction::v1::{
+        action::SudoAddressChange,
+        Action,
+    };
+    use cnidarium::StateDelta;
+
+    use super::*;
+    use crate::{
+        benchmark_and_test_utils::astria_address,
+        governance::ProposalStatus,
+    };
+
+    fn proposal() -> Proposal {
+        Proposal {
+            proposer: [1; 20],
+            action: Action::SudoAddressChange(SudoAddressChange {
+                new_address: astria_address(&[2; 20]),
+            }),
+            submitted_height: 10,
+            voting_end_height: 110,
+            status: ProposalStatus::Open,
+            votes: BTreeMap::from([([3; 20], true), ([4; 20], false)]),
+        }
+    }
+
+    #[tokio::test]
+    async fn governance_parameters_uninitialized_ok() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let state = StateDelta::new(snapshot);
+
+        assert_eq!(state.get_governance_parameters().await.unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn put_governance_parameters() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = StateDelta::new(snapshot);
+
+        let params = Parameters {
+            proposers: vec![[1; 20]],
+            council: vec![[2; 20], [3; 20]],
+            voting_period_blocks: 100,
+            approval_threshold_basis_points: 5_000,
+        };
+        state.put_governance_parameters(&params).unwrap();
+        assert_eq!(
+            state.get_governance_parameters().await.unwrap(),
+            Some(params)
+        );
+    }
+
+    #[tokio::test]
+    async fn next_proposal_id_defaults_to_zero() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = StateDelta::new(snapshot);
+
+        assert_eq!(state.get_next_proposal_id().await.unwrap(), 0);
+        state.put_next_proposal_id(3).unwrap();
+        assert_eq!(state.get_next_proposal_id().await.unwrap(), 3);
+    }
+
+    #[tokio::test]
+    async fn open_proposal_ids_default_to_empty() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = StateDelta::new(snapshot);
+
+        assert!(state.get_open_proposal_ids().await.unwrap().is_empty());
+        state.put_open_proposal_ids(&[1, 4]).unwrap();
+        assert_eq!(state.get_open_proposal_ids().await.unwrap(), vec![1, 4]);
+    }
+
+    #[tokio::test]
+    async fn put_and_get_proposal() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = StateDelta::new(snapshot);
+
+        assert_eq!(state.get_proposal(0).await.unwrap(), None);
+        state.put_proposal(0, &proposal()).unwrap();
+        assert_eq!(state.get_proposal(0).await.unwrap(), Some(proposal()));
+    }
+
+    #[tokio::test]
+    async fn fees_changed_in_block_flag() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = StateDelta::new(snapshot);
+
+        assert!(!state.get_governance_fees_changed_in_block());
+        state.put_governance_fees_changed_in_block();
+        assert!(state.get_governance_fees_changed_in_block());
+    }
+}
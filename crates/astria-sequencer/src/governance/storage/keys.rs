@@ -0,0 +1,39 @@
+pub(in crate::governance) const PARAMETERS: &str = "governance/parameters";
+pub(in crate::governance) const NEXT_PROPOSAL_ID: &str = "governance/next_proposal_id";
+pub(in crate::governance) const OPEN_PROPOSAL_IDS: &str = "governance/open_proposal_ids";
+pub(in crate::governance) const PROPOSAL_PREFIX: &str = "governance/proposal/";
+
+/// Ephemeral key set when an executed proposal changed fees or fee assets.
+pub(in crate::governance) const FEES_CHANGED_IN_BLOCK: &str = "governance/fees_changed_in_block";
+
+pub(in crate::governance) fn proposal(id: u64) -> String {
+    format!("{PROPOSAL_PREFIX}{id}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const COMPONENT_PREFIX: &str = "governance/";
+
+    #[test]
+    fn keys_should_not_change() {
+        insta::assert_snapshot!("parameters_key", PARAMETERS);
+        insta::assert_snapshot!("next_proposal_id_key", NEXT_PROPOSAL_ID);
+        insta::assert_snapshot!("open_proposal_ids_key", OPEN_PROPOSAL_IDS);
+        insta::assert_snapshot!("proposal_key", proposal(42));
+    }
+
+    #[test]
+    fn keys_should_have_component_prefix() {
+        assert!(PARAMETERS.starts_with(COMPONENT_PREFIX));
+        assert!(NEXT_PROPOSAL_ID.starts_with(COMPONENT_PREFIX));
+        assert!(OPEN_PROPOSAL_IDS.starts_with(COMPONENT_PREFIX));
+        assert!(proposal(42).starts_with(COMPONENT_PREFIX));
+    }
+
+    #[test]
+    fn prefixes_should_be_prefixes_of_relevant_keys() {
+        assert!(proposal(42).starts_with(PROPOSAL_PREFIX));
+    }
+}
@@ -0,0 +1,10 @@
+pub(super) mod keys;
+mod values;
+
+pub(crate) use values::Value;
+pub(super) use values::{
+    Parameters,
+    Proposal,
+    ProposalId,
+    ProposalIds,
+};
@@ -0,0 +1,340 @@
+use std::{
+    borrow::Cow,
+    fmt::{
+        self,
+        Debug,
+        Formatter,
+    },
+};
+
+use astria_core::{
+    generated::astria::protocol::transaction::v1 as raw,
+    primitive::v1::ADDRESS_LEN,
+    protocol::transaction::v1::Action,
+    Protobuf as _,
+};
+use astria_eyre::eyre::bail;
+use borsh::{
+    BorshDeserialize,
+    BorshSerialize,
+};
+use prost::Message as _;
+use telemetry::display::base64;
+
+use crate::governance::{
+    Parameters as DomainParameters,
+    Proposal as DomainProposal,
+    ProposalStatus as DomainProposalStatus,
+};
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub(crate) struct Value<'a>(ValueImpl<'a>);
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+enum ValueImpl<'a> {
+    Parameters(Parameters<'a>),
+    ProposalId(ProposalId),
+    ProposalIds(ProposalIds<'a>),
+    Proposal(Proposal<'a>),
+}
+
+#[derive(BorshSerialize, BorshDeserialize)]
+struct AddressBytes<'a>(Cow<'a, [u8; ADDRESS_LEN]>);
+
+impl Debug for AddressBytes<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", base64(self.0.as_slice()))
+    }
+}
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub(in crate::governance) struct Parameters<'a> {
+    proposers: Vec<AddressBytes<'a>>,
+    council: Vec<AddressBytes<'a>>,
+    voting_period_blocks: u64,
+    approval_threshold_basis_points: u32,
+}
+
+impl<'a> From<&'a DomainParameters> for Parameters<'a> {
+    fn from(params: &'a DomainParameters) -> Self {
+        Parameters {
+            proposers: params
+                .proposers
+                .iter()
+                .map(|address| AddressBytes(Cow::Borrowed(address)))
+                .collect(),
+            council: params
+                .council
+                .iter()
+                .map(|address| AddressBytes(Cow::Borrowed(address)))
+                .collect(),
+            voting_period_blocks: params.voting_period_blocks,
+            approval_threshold_basis_points: params.approval_threshold_basis_points,
+        }
+    }
+}
+
+impl<'a> From<Parameters<'a>> for DomainParameters {
+    fn from(params: Parameters<'a>) -> Self {
+        DomainParameters {
+            proposers: params
+                .proposers
+                .into_iter()
+                .map(|address| address.0.into_owned())
+                .collect(),
+            council: params
+                .council
+                .into_iter()
+                .map(|address| address.0.into_owned())
+                .collect(),
+            voting_period_blocks: params.voting_period_blocks,
+            approval_threshold_basis_points: params.approval_threshold_basis_points,
+        }
+    }
+}
+
+impl<'a> From<Parameters<'a>> for crate::storage::StoredValue<'a> {
+    fn from(params: Parameters<'a>) -> Self {
+        crate::storage::StoredValue::Governance(Value(ValueImpl::Parameters(params)))
+    }
+}
+
+impl<'a> TryFrom<crate::storage::StoredValue<'a>> for Parameters<'a> {
+    type Error = astria_eyre::eyre::Error;
+
+    fn try_from(value: crate::storage::StoredValue<'a>) -> Result<Self, Self::Error> {
+        let crate::storage::StoredValue::Governance(Value(ValueImpl::Parameters(params))) = value
+        else {
+            bail!("governance stored value type mismatch: expected parameters, found {value:?}");
+        };
+        Ok(params)
+    }
+}
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub(in crate::governance) struct ProposalId(u64);
+
+impl From<u64> for ProposalId {
+    fn from(id: u64) -> Self {
+        ProposalId(id)
+    }
+}
+
+impl From<ProposalId> for u64 {
+    fn from(id: ProposalId) -> Self {
+        id.0
+    }
+}
+
+impl From<ProposalId> for crate::storage::StoredValue<'_> {
+    fn from(id: ProposalId) -> Self {
+        crate::storage::StoredValue::Governance(Value(ValueImpl::ProposalId(id)))
+    }
+}
+
+impl<'a> TryFrom<crate::storage::StoredValue<'a>> for ProposalId {
+    type Error = astria_eyre::eyre::Error;
+
+    fn try_from(value: crate::storage::StoredValue<'a>) -> Result<Self, Self::Error> {
+        let crate::storage::StoredValue::Governance(Value(ValueImpl::ProposalId(id))) = value
+        else {
+            bail!("governance stored value type mismatch: expected proposal id, found {value:?}");
+        };
+        Ok(id)
+    }
+}
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub(in crate::governance) struct ProposalIds<'a>(Cow<'a, [u64]>);
+
+impl<'a> From<&'a [u64]> for ProposalIds<'a> {
+    fn from(ids: &'a [u64]) -> Self {
+        ProposalIds(Cow::Borrowed(ids))
+    }
+}
+
+impl<'a> From<ProposalIds<'a>> for Vec<u64> {
+    fn from(ids: ProposalIds<'a>) -> Self {
+        ids.0.into_owned()
+    }
+}
+
+impl<'a> From<ProposalIds<'a>> for crate::storage::StoredValue<'a> {
+    fn from(ids: ProposalIds<'a>) -> Self {
+        crate::storage::StoredValue::Governance(Value(ValueImpl::ProposalIds(ids)))
+    }
+}
+
+impl<'a> TryFrom<crate::storage::StoredValue<'a>> for ProposalIds<'a> {
+    type Error = astria_eyre::eyre::Error;
+
+    fn try_from(value: crate::storage::StoredValue<'a>) -> Result<Self, Self::Error> {
+        let crate::storage::StoredValue::Governance(Value(ValueImpl::ProposalIds(ids))) = value
+        else {
+            bail!("governance stored value type mismatch: expected proposal ids, found {value:?}");
+        };
+        Ok(ids)
+    }
+}
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+enum ProposalStatus {
+    Open,
+    Executed,
+    ExecutionFailed,
+    Rejected,
+}
+
+impl From<DomainProposalStatus> for ProposalStatus {
+    fn from(status: DomainProposalStatus) -> Self {
+        match status {
+            DomainProposalStatus::Open => ProposalStatus::Open,
+            DomainProposalStatus::Executed => ProposalStatus::Executed,
+            DomainProposalStatus::ExecutionFailed => ProposalStatus::ExecutionFailed,
+            DomainProposalStatus::Rejected => ProposalStatus::Rejected,
+        }
+    }
+}
+
+impl From<ProposalStatus> for DomainProposalStatus {
+    fn from(status: ProposalStatus) -> Self {
+        match status {
+            ProposalStatus::Open => DomainProposalStatus::Open,
+            ProposalStatus::Executed => DomainProposalStatus::Executed,
+            ProposalStatus::ExecutionFailed => DomainProposalStatus::ExecutionFailed,
+            ProposalStatus::Rejected => DomainProposalStatus::Rejected,
+        }
+    }
+}
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+struct Vote<'a> {
+    voter: AddressBytes<'a>,
+    approve: bool,
+}
+
+/// A stored governance proposal. The wrapped action is stored as its protobuf encoding so that the
+/// storage format does not have to mirror every action type.
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub(in crate::governance) struct Proposal<'a> {
+    proposer: AddressBytes<'a>,
+    action: Cow<'a, [u8]>,
+    submitted_height: u64,
+    voting_end_height: u64,
+    status: ProposalStatus,
+    votes: Vec<Vote<'a>>,
+}
+
+impl<'a> From<&'a DomainProposal> for Proposal<'a> {
+    fn from(proposal: &'a DomainProposal) -> Self {
+        Proposal {
+            proposer: AddressBytes(Cow::Borrowed(&proposal.proposer)),
+            action: Cow::Owned(proposal.action.to_raw().encode_to_vec()),
+            submitted_height: proposal.submitted_height,
+            voting_end_height: proposal.voting_end_height,
+            status: proposal.status.into(),
+            votes: proposal
+                .votes
+                .iter()
+                .map(|(voter, approve)| Vote {
+                    voter: AddressBytes(Cow::Borrowed(voter)),
+                    approve: *approve,
+                })
+                .collect(),
+        }
+    }
+}
+
+impl<'a> From<Proposal<'a>> for DomainProposal {
+    fn from(proposal: Proposal<'a>) -> Self {
+        let raw_action = raw::Action::decode(&*proposal.action)
+            .expect("proposal action in storage must be valid protobuf");
+        DomainProposal {
+            proposer: proposal.proposer.0.into_owned(),
+            action: Action::try_from_raw(raw_action)
+                .expect("proposal action in storage must be a valid action"),
+            submitted_height: proposal.submitted_height,
+            voting_end_height: proposal.voting_end_height,
+            status: proposal.status.into(),
+            votes: proposal
+                .votes
+                .into_iter()
+                .map(|vote| (vote.voter.0.into_owned(), vote.approve))
+                .collect(),
+        }
+    }
+}
+
+impl<'a> From<Proposal<'a>> for crate::storage::StoredValue<'a> {
+    fn from(proposal: Proposal<'a>) -> Self {
+        crate::storage::StoredValue::Governance(Value(ValueImpl::Proposal(proposal)))
+    }
+}
+
+impl<'a> TryFrom<crate::storage::StoredValue<'a>> for Proposal<'a> {
+    type Error = astria_eyre::eyre::Error;
+
+    fn try_from(value: crate::storage::StoredValue<'a>) -> Result<Self, Self::Error> {
+        let crate::storage::StoredValue::Governance(Value(ValueImpl::Proposal(proposal))) = value
+        else {
+            bail!("governance stored value type mismatch: expected proposal, found {value:?}");
+        };
+        Ok(proposal)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use insta::assert_snapshot;
+
+    use super::*;
+    use crate::test_utils::borsh_then_hex;
+
+    #[test]
+    fn value_impl_existing_variants_unchanged() {
+        assert_snapshot!(
+            "value_impl_parameters",
+            borsh_then_hex(&ValueImpl::Parameters(Parameters {
+                proposers: vec![AddressBytes(Cow::Owned([1; ADDRESS_LEN]))],
+                council: vec![AddressBytes(Cow::Owned([2; ADDRESS_LEN]))],
+                voting_period_blocks: 100,
+                approval_threshold_basis_points: 6_666,
+            }))
+        );
+        assert_snapshot!(
+            "value_impl_proposal_id",
+            borsh_then_hex(&ValueImpl::ProposalId(ProposalId(1)))
+        );
+        assert_snapshot!(
+            "value_impl_proposal_ids",
+            borsh_then_hex(&ValueImpl::ProposalIds(ProposalIds(Cow::Owned(vec![1, 2]))))
+        );
+        assert_snapshot!(
+            "value_impl_proposal",
+            borsh_then_hex(&ValueImpl::Proposal(Proposal {
+                proposer: AddressBytes(Cow::Owned([1; ADDRESS_LEN])),
+                action: Cow::Owned(vec![1, 2, 3]),
+                submitted_height: 10,
+                voting_end_height: 110,
+                status: ProposalStatus::Executed,
+                votes: vec![Vote {
+                    voter: AddressBytes(Cow::Owned([2; ADDRESS_LEN])),
+                    approve: true,
+                }],
+            }))
+        );
+    }
+
+    // Note: This test must be here instead of in `crate::storage` since `ValueImpl` is not
+    // re-exported.
+    #[test]
+    fn stored_value_governance_variant_unchanged() {
+        use crate::storage::StoredValue;
+        assert_snapshot!(
+            "stored_value_governance_variant",
+            borsh_then_hex(&StoredValue::Governance(Value(ValueImpl::ProposalId(
+                ProposalId(1)
+            ))))
+        );
+    }
+}
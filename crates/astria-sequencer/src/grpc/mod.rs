@@ -5,7 +5,10 @@ use std::{
 
 use astria_core::{
     generated::{
-        astria::sequencerblock::v1::sequencer_service_server::SequencerServiceServer,
+        astria::{
+            protocol::price_feed::v1::price_feed_service_server::PriceFeedServiceServer,
+            sequencerblock::v1::sequencer_service_server::SequencerServiceServer,
+        },
         price_feed::{
             marketmap::v2::query_server::QueryServer as MarketMapQueryServer,
             oracle::v2::query_server::QueryServer as OracleQueryServer,
@@ -113,12 +116,19 @@ pub(crate) async fn serve(
     use tower_http::cors::CorsLayer;
 
     let ibc = penumbra_ibc::component::rpc::IbcQuery::<AstriaHost>::new(storage.clone());
-    let sequencer_api = SequencerServer::new(storage.clone(), mempool, upgrades);
+    let mut background_tasks = BackgroundTasks::new();
+    let sequencer_api = SequencerServer::new(
+        storage.clone(),
+        mempool,
+        upgrades,
+        event_bus_subscription.clone(),
+        background_tasks.cancellation_token(),
+    );
     let market_map_api = price_feed::SequencerServer::new(storage.clone());
     let oracle_api = price_feed::SequencerServer::new(storage.clone());
+    let price_feed_api = price_feed::SequencerServer::new(storage.clone());
     let cors_layer: CorsLayer = CorsLayer::permissive();
 
-    let mut background_tasks = BackgroundTasks::new();
     let optimistic_block_service = if no_optimistic_blocks {
         None
     } else {
@@ -154,7 +164,8 @@ pub(crate) async fn serve(
         .add_service(SequencerServiceServer::new(sequencer_api))
         .add_optional_service(optimistic_block_service)
         .add_service(MarketMapQueryServer::new(market_map_api))
-        .add_service(OracleQueryServer::new(oracle_api));
+        .add_service(OracleQueryServer::new(oracle_api))
+        .add_service(PriceFeedServiceServer::new(price_feed_api));
 
     info!(grpc_addr = grpc_addr.to_string(), "starting grpc server");
 
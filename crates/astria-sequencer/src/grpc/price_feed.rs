@@ -4,29 +4,37 @@ use std::{
 };
 
 use astria_core::{
-    generated::price_feed::{
-        marketmap::v2::{
-            query_server::Query as MarketMapQueryService,
-            LastUpdatedRequest,
-            LastUpdatedResponse,
-            MarketMapRequest,
-            MarketMapResponse,
-            MarketRequest,
-            MarketResponse,
+    generated::{
+        astria::protocol::price_feed::v1::{
+            price_feed_service_server::PriceFeedService,
+            GetTwapRequest,
+            GetTwapResponse,
         },
-        oracle::v2::{
-            query_server::Query as OracleService,
-            GetAllCurrencyPairsRequest,
-            GetAllCurrencyPairsResponse,
-            GetCurrencyPairMappingRequest,
-            GetCurrencyPairMappingResponse,
-            GetPriceRequest,
-            GetPriceResponse,
-            GetPricesRequest,
-            GetPricesResponse,
+        price_feed::{
+            marketmap::v2::{
+                query_server::Query as MarketMapQueryService,
+                LastUpdatedRequest,
+                LastUpdatedResponse,
+                MarketMapRequest,
+                MarketMapResponse,
+                MarketRequest,
+                MarketResponse,
+            },
+            oracle::v2::{
+                query_server::Query as OracleService,
+                GetAllCurrencyPairsRequest,
+                GetAllCurrencyPairsResponse,
+                GetCurrencyPairMappingRequest,
+                GetCurrencyPairMappingResponse,
+                GetPriceRequest,
+                GetPriceResponse,
+                GetPricesRequest,
+                GetPricesResponse,
+            },
         },
     },
     oracles::price_feed::types::v2::CurrencyPair,
+    Timestamp,
 };
 use cnidarium::Storage;
 use futures::{
@@ -44,9 +52,12 @@ use crate::{
     app::StateReadExt as _,
     oracles::price_feed::{
         market_map::state_ext::StateReadExt as _,
-        oracle::state_ext::{
-            CurrencyPairWithId,
-            StateReadExt as _,
+        oracle::{
+            self,
+            state_ext::{
+                CurrencyPairWithId,
+                StateReadExt as _,
+            },
         },
     },
 };
@@ -306,3 +317,239 @@ impl OracleService for SequencerServer {
         }))
     }
 }
+
+#[async_trait::async_trait]
+impl PriceFeedService for SequencerServer {
+    #[instrument(skip_all)]
+    async fn get_twap(
+        self: Arc<Self>,
+        request: Request<GetTwapRequest>,
+    ) -> Result<Response<GetTwapResponse>, Status> {
+        let request = request.into_inner();
+
+        let currency_pair: CurrencyPair = request
+            .currency_pair
+            .parse()
+            .map_err(|e| Status::invalid_argument(format!("currency pair is invalid: {e:#}")))?;
+
+        let snapshot = self.storage.latest_snapshot();
+        let Some(twap) = snapshot
+            .get_currency_pair_twap(&currency_pair)
+            .await
+            .map_err(|e| {
+                Status::internal(format!(
+                    "failed to get currency pair twap from storage: {e:#}"
+                ))
+            })?
+        else {
+            return Err(Status::not_found(format!(
+                "no time-weighted average price tracked for {currency_pair}"
+            )));
+        };
+        let Some(parameters) = snapshot
+            .get_currency_pair_parameters(&currency_pair)
+            .await
+            .map_err(|e| {
+                Status::internal(format!(
+                    "failed to get currency pair parameters from storage: {e:#}"
+                ))
+            })?
+        else {
+            return Err(Status::internal(format!(
+                "currency pair parameters not found for {currency_pair}"
+            )));
+        };
+        let Some(price) = snapshot
+            .get_currency_pair_state(&currency_pair)
+            .await
+            .map_err(|e| {
+                Status::internal(format!(
+                    "failed to get currency pair state from storage: {e:#}"
+                ))
+            })?
+            .and_then(|state| state.price)
+        else {
+            return Err(Status::internal(format!(
+                "latest price not found for {currency_pair}"
+            )));
+        };
+
+        let Some(market_map) = snapshot.get_market_map().await.map_err(|e| {
+            Status::internal(format!(
+                "failed to get block market map from storage: {e:#}"
+            ))
+        })?
+        else {
+            return Err(Status::internal("market map not found"));
+        };
+        let Some(market) = market_map.markets.get(&currency_pair.to_string()) else {
+            return Err(Status::not_found(format!(
+                "market not found for {currency_pair}"
+            )));
+        };
+
+        let block_timestamp: tendermint_proto::google::protobuf::Timestamp = snapshot
+            .get_block_timestamp()
+            .await
+            .map_err(|e| {
+                Status::internal(format!("failed to get block timestamp from storage: {e:#}"))
+            })?
+            .into();
+        let stale = oracle::is_stale(
+            &price,
+            &Timestamp {
+                seconds: block_timestamp.seconds,
+                nanos: block_timestamp.nanos,
+            },
+            parameters.max_age_seconds,
+        );
+
+        Ok(Response::new(GetTwapResponse {
+            price: Some(price.into_raw()),
+            twap: twap.to_string(),
+            stale,
+            decimals: market.ticker.decimals.into(),
+            max_age_seconds: parameters.max_age_seconds,
+            max_deviation_basis_points: parameters.max_deviation_basis_points,
+            twap_window_seconds: parameters.twap_window_seconds,
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use astria_core::{
+        oracles::price_feed::{
+            market_map::v2::{
+                Market,
+                MarketMap,
+                Ticker,
+            },
+            oracle::v2::{
+                CurrencyPairState,
+                QuotePrice,
+            },
+            types::v2::{
+                CurrencyPairId,
+                CurrencyPairNonce,
+                Price,
+            },
+        },
+        protocol::transaction::v1::action::CurrencyPairParameters,
+    };
+    use cnidarium::StateDelta;
+    use indexmap::IndexMap;
+
+    use super::*;
+    use crate::{
+        app::StateWriteExt as _,
+        oracles::price_feed::{
+            market_map::state_ext::StateWriteExt as _,
+            oracle::state_ext::StateWriteExt as _,
+        },
+    };
+
+    fn get_twap_request(currency_pair: &str) -> Request<GetTwapRequest> {
+        Request::new(GetTwapRequest {
+            currency_pair: currency_pair.to_string(),
+        })
+    }
+
+    #[tokio::test]
+    async fn get_twap_ok() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let mut state = StateDelta::new(storage.latest_snapshot());
+
+        let currency_pair: CurrencyPair = "BTC/USD".parse().unwrap();
+        let quote_price = QuotePrice {
+            price: Price::new(100),
+            block_timestamp: Timestamp {
+                seconds: 10,
+                nanos: 0,
+            },
+            block_height: 1,
+        };
+        state
+            .put_currency_pair_state(
+                currency_pair.clone(),
+                CurrencyPairState {
+                    price: Some(quote_price.clone()),
+                    nonce: CurrencyPairNonce::new(1),
+                    id: CurrencyPairId::new(0),
+                },
+            )
+            .unwrap();
+        state
+            .put_currency_pair_parameters(&CurrencyPairParameters {
+                currency_pair: currency_pair.clone(),
+                max_age_seconds: 60,
+                max_deviation_basis_points: 500,
+                twap_window_seconds: 600,
+            })
+            .unwrap();
+        state
+            .put_currency_pair_twap(&currency_pair, Price::new(90))
+            .unwrap();
+        state
+            .put_market_map(MarketMap {
+                markets: IndexMap::from([(
+                    currency_pair.to_string(),
+                    Market {
+                        ticker: Ticker {
+                            currency_pair: currency_pair.clone(),
+                            decimals: 6,
+                            min_provider_count: 0,
+                            enabled: true,
+                            metadata_json: String::new(),
+                        },
+                        provider_configs: Vec::new(),
+                    },
+                )]),
+            })
+            .unwrap();
+        state
+            .put_block_timestamp(tendermint::Time::from_unix_timestamp(30, 0).unwrap())
+            .unwrap();
+        storage.commit(state).await.unwrap();
+
+        let server = Arc::new(SequencerServer::new(storage.clone()));
+        let response = server
+            .clone()
+            .get_twap(get_twap_request("BTC/USD"))
+            .await
+            .unwrap()
+            .into_inner();
+        assert_eq!(
+            response,
+            GetTwapResponse {
+                price: Some(quote_price.into_raw()),
+                twap: "90".to_string(),
+                stale: false,
+                decimals: 6,
+                max_age_seconds: 60,
+                max_deviation_basis_points: 500,
+                twap_window_seconds: 600,
+            }
+        );
+
+        // the latest price is older than the max age at the latest block
+        let mut state = StateDelta::new(storage.latest_snapshot());
+        state
+            .put_block_timestamp(tendermint::Time::from_unix_timestamp(71, 0).unwrap())
+            .unwrap();
+        storage.commit(state).await.unwrap();
+        let response = server
+            .clone()
+            .get_twap(get_twap_request("BTC/USD"))
+            .await
+            .unwrap()
+            .into_inner();
+        assert!(response.stale);
+
+        let status = server
+            .get_twap(get_twap_request("ETH/USD"))
+            .await
+            .unwrap_err();
+        assert_eq!(status.code(), tonic::Code::NotFound);
+    }
+}
@@ -1,4 +1,7 @@
-use std::sync::Arc;
+use std::{
+    pin::Pin,
+    sync::Arc,
+};
 
 use astria_core::{
     generated::{
@@ -10,6 +13,7 @@ use astria_core::{
             GetPendingNonceResponse,
             GetSequencerBlockRequest,
             SequencerBlock as RawSequencerBlock,
+            StreamFilteredSequencerBlocksRequest,
         },
         sequencerblock::v1::{
             GetUpgradesInfoRequest,
@@ -30,8 +34,13 @@ use astria_core::{
     Protobuf,
 };
 use bytes::Bytes;
-use cnidarium::Storage;
+use cnidarium::{
+    StateRead,
+    Storage,
+};
+use tokio_util::sync::CancellationToken;
 use tonic::{
+    codegen::tokio_stream::Stream,
     Request,
     Response,
     Status,
@@ -45,30 +54,50 @@ use tracing::{
 };
 
 use crate::{
-    app::StateReadExt as _,
+    app::{
+        event_bus::{
+            EventBusSubscription,
+            EventReceiver,
+        },
+        StateReadExt as _,
+    },
     authority::StateReadExt as _,
     grpc::StateReadExt as _,
     mempool::Mempool,
 };
 
+type GrpcStream<T> = Pin<Box<dyn Stream<Item = Result<T, Status>> + Send>>;
+
 pub(crate) struct SequencerServer {
     storage: Storage,
     mempool: Mempool,
     upgrades: Upgrades,
+    event_bus_subscription: EventBusSubscription,
+    cancellation_token: CancellationToken,
 }
 
 impl SequencerServer {
-    pub(crate) fn new(storage: Storage, mempool: Mempool, upgrades: Upgrades) -> Self {
+    pub(crate) fn new(
+        storage: Storage,
+        mempool: Mempool,
+        upgrades: Upgrades,
+        event_bus_subscription: EventBusSubscription,
+        cancellation_token: CancellationToken,
+    ) -> Self {
         Self {
             storage,
             mempool,
             upgrades,
+            event_bus_subscription,
+            cancellation_token,
         }
     }
 }
 
 #[async_trait::async_trait]
 impl SequencerService for SequencerServer {
+    type StreamFilteredSequencerBlocksStream = GrpcStream<RawFilteredSequencerBlock>;
+
     /// Given a block height, returns the sequencer block at that height.
     #[instrument(skip_all)]
     async fn get_sequencer_block(
@@ -125,95 +154,52 @@ impl SequencerService for SequencerServer {
             .collect::<Result<Vec<_>, _>>()
             .map_err(|e| Status::invalid_argument(format!("invalid rollup ID: {e}")))?;
 
-        let block_hash = snapshot
-            .get_block_hash_by_height(request.height)
-            .await
-            .map_err(|e| Status::internal(format!("failed to get block hash from storage: {e}")))?;
-
-        let header = snapshot
-            .get_sequencer_block_header_by_hash(&block_hash)
-            .await
-            .map_err(|e| {
-                Status::internal(format!(
-                    "failed to get sequencer block header from storage: {e}"
-                ))
-            })?;
-
-        let rollup_transactions_proof = snapshot
-            .get_rollup_transactions_proof_by_block_hash(&block_hash)
-            .await
-            .map_err(|e| {
-                Status::internal(format!(
-                    "failed to get rollup transactions proof from storage: {e}"
-                ))
-            })?;
+        let block = get_filtered_sequencer_block(&snapshot, request.height, rollup_ids).await?;
 
-        let rollup_ids_proof = snapshot
-            .get_rollup_ids_proof_by_block_hash(&block_hash)
-            .await
-            .map_err(|e| {
-                Status::internal(format!("failed to get rollup ids proof from storage: {e}"))
-            })?;
-
-        let upgrade_change_hashes = snapshot
-            .get_upgrade_change_hashes(&block_hash)
-            .await
-            .map_err(|e| {
-                Status::internal(format!(
-                    "failed to get upgrade change hashes from storage: {e}"
-                ))
-            })?;
+        Ok(Response::new(block))
+    }
 
-        let extended_commit_info_with_proof = snapshot
-            .get_extended_commit_info_with_proof(&block_hash)
-            .await
-            .map_err(|e| {
-                Status::internal(format!(
-                    "failed to get extended commit info with proof from storage: {e}"
-                ))
-            })?;
+    /// Given a set of rollup ids, streams a FilteredSequencerBlock for every block as it is
+    /// committed. Blocks starting at `start_height` are sent first to allow catching up.
+    #[instrument(skip_all)]
+    async fn stream_filtered_sequencer_blocks(
+        self: Arc<Self>,
+        request: Request<StreamFilteredSequencerBlocksRequest>,
+    ) -> Result<Response<Self::StreamFilteredSequencerBlocksStream>, Status> {
+        // subscribe before reading the current height so that no commit is missed in between.
+        let committed_block_heights = self.event_bus_subscription.committed_block_heights();
 
-        let mut all_rollup_ids = snapshot
-            .get_rollup_ids_by_block_hash(&block_hash)
-            .await
-            .map_err(|e| Status::internal(format!("failed to get rollup ids from storage: {e}")))?;
-        all_rollup_ids.sort_unstable();
+        let request = request.into_inner();
+        let rollup_ids = request
+            .rollup_ids
+            .iter()
+            .map(RollupId::try_from_raw_ref)
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| Status::invalid_argument(format!("invalid rollup ID: {e}")))?;
 
-        // Filter out the Rollup Ids requested which have no data before grabbing
-        // so as to not error because the block had no data for the requested rollup
-        let rollup_ids: Vec<RollupId> = rollup_ids
-            .into_iter()
-            .filter(|id| all_rollup_ids.binary_search(id).is_ok())
-            .collect();
-        let mut rollup_transactions = Vec::with_capacity(rollup_ids.len());
-        for rollup_id in rollup_ids {
-            let rollup_data = snapshot
-                .get_rollup_data(&block_hash, &rollup_id)
+        let next_height = if request.start_height == 0 {
+            self.storage
+                .latest_snapshot()
+                .get_block_height()
                 .await
                 .map_err(|e| {
-                    Status::internal(format!("failed to get rollup data from storage: {e}",))
-                })?;
-            rollup_transactions.push(rollup_data.into_raw());
-        }
-
-        let all_rollup_ids = all_rollup_ids.into_iter().map(RollupId::into_raw).collect();
-
-        let block = RawFilteredSequencerBlock {
-            block_hash: Bytes::copy_from_slice(block_hash.as_bytes()),
-            header: Some(header.into_raw()),
-            rollup_transactions,
-            rollup_transactions_proof: Some(rollup_transactions_proof.into_raw()),
-            rollup_ids_proof: Some(rollup_ids_proof.into_raw()),
-            all_rollup_ids,
-            upgrade_change_hashes: upgrade_change_hashes
-                .into_iter()
-                .map(|change_hash| Bytes::copy_from_slice(change_hash.as_bytes()))
-                .collect(),
-            extended_commit_info_with_proof: extended_commit_info_with_proof
-                .map(ExtendedCommitInfoWithProof::into_raw),
+                    Status::internal(format!("failed to get block height from storage: {e}"))
+                })?
+                .saturating_add(1)
+        } else {
+            request.start_height
         };
 
-        Ok(Response::new(block))
+        let stream = FilteredSequencerBlockStream {
+            storage: self.storage.clone(),
+            committed_block_heights,
+            cancellation_token: self.cancellation_token.clone(),
+            rollup_ids,
+            next_height,
+        };
+        let stream =
+            futures::stream::unfold(Some(stream), |stream| async move { stream?.next().await });
+        Ok(Response::new(Box::pin(stream)))
     }
 
     #[instrument(skip_all)]
@@ -331,6 +317,157 @@ impl SequencerService for SequencerServer {
     }
 }
 
+/// The state of a stream opened by [`SequencerServer::stream_filtered_sequencer_blocks`].
+struct FilteredSequencerBlockStream {
+    storage: Storage,
+    committed_block_heights: EventReceiver<u64>,
+    cancellation_token: CancellationToken,
+    rollup_ids: Vec<RollupId>,
+    next_height: u64,
+}
+
+impl FilteredSequencerBlockStream {
+    /// Waits until the block at `next_height` is committed and returns it filtered for the
+    /// stream's rollup IDs.
+    ///
+    /// Blocks that are already committed are returned immediately. Returns `None` if the stream
+    /// should end, which is the case if the sequencer is shutting down. After an error is
+    /// returned the stream state is dropped so that no further items are emitted.
+    async fn next(mut self) -> Option<(Result<RawFilteredSequencerBlock, Status>, Option<Self>)> {
+        loop {
+            let snapshot = self.storage.latest_snapshot();
+            let latest_height = match snapshot.get_block_height().await {
+                Ok(height) => height,
+                Err(e) => {
+                    let status =
+                        Status::internal(format!("failed to get block height from storage: {e}"));
+                    return Some((Err(status), None));
+                }
+            };
+            if self.next_height <= latest_height {
+                let block = get_filtered_sequencer_block(
+                    &snapshot,
+                    self.next_height,
+                    self.rollup_ids.clone(),
+                )
+                .await;
+                if block.is_err() {
+                    return Some((block, None));
+                }
+                self.next_height = self.next_height.saturating_add(1);
+                return Some((block, Some(self)));
+            }
+            tokio::select! {
+                biased;
+                () = self.cancellation_token.cancelled() => return None,
+                res = self.committed_block_heights.receive() => {
+                    if res.is_err() {
+                        // the app dropped the event bus, which only happens during shutdown.
+                        return None;
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Returns the block at `height` filtered to only contain the data of `rollup_ids`.
+///
+/// Rollup IDs without data in the block are ignored.
+async fn get_filtered_sequencer_block<S: StateRead>(
+    snapshot: &S,
+    height: u64,
+    rollup_ids: Vec<RollupId>,
+) -> Result<RawFilteredSequencerBlock, Status> {
+    let block_hash = snapshot
+        .get_block_hash_by_height(height)
+        .await
+        .map_err(|e| Status::internal(format!("failed to get block hash from storage: {e}")))?;
+
+    let header = snapshot
+        .get_sequencer_block_header_by_hash(&block_hash)
+        .await
+        .map_err(|e| {
+            Status::internal(format!(
+                "failed to get sequencer block header from storage: {e}"
+            ))
+        })?;
+
+    let rollup_transactions_proof = snapshot
+        .get_rollup_transactions_proof_by_block_hash(&block_hash)
+        .await
+        .map_err(|e| {
+            Status::internal(format!(
+                "failed to get rollup transactions proof from storage: {e}"
+            ))
+        })?;
+
+    let rollup_ids_proof = snapshot
+        .get_rollup_ids_proof_by_block_hash(&block_hash)
+        .await
+        .map_err(|e| {
+            Status::internal(format!("failed to get rollup ids proof from storage: {e}"))
+        })?;
+
+    let upgrade_change_hashes = snapshot
+        .get_upgrade_change_hashes(&block_hash)
+        .await
+        .map_err(|e| {
+            Status::internal(format!(
+                "failed to get upgrade change hashes from storage: {e}"
+            ))
+        })?;
+
+    let extended_commit_info_with_proof = snapshot
+        .get_extended_commit_info_with_proof(&block_hash)
+        .await
+        .map_err(|e| {
+            Status::internal(format!(
+                "failed to get extended commit info with proof from storage: {e}"
+            ))
+        })?;
+
+    let mut all_rollup_ids = snapshot
+        .get_rollup_ids_by_block_hash(&block_hash)
+        .await
+        .map_err(|e| Status::internal(format!("failed to get rollup ids from storage: {e}")))?;
+    all_rollup_ids.sort_unstable();
+
+    // Filter out the Rollup Ids requested which have no data before grabbing
+    // so as to not error because the block had no data for the requested rollup
+    let rollup_ids: Vec<RollupId> = rollup_ids
+        .into_iter()
+        .filter(|id| all_rollup_ids.binary_search(id).is_ok())
+        .collect();
+    let mut rollup_transactions = Vec::with_capacity(rollup_ids.len());
+    for rollup_id in rollup_ids {
+        let rollup_data = snapshot
+            .get_rollup_data(&block_hash, &rollup_id)
+            .await
+            .map_err(|e| {
+                Status::internal(format!("failed to get rollup data from storage: {e}",))
+            })?;
+        rollup_transactions.push(rollup_data.into_raw());
+    }
+
+    let all_rollup_ids = all_rollup_ids.into_iter().map(RollupId::into_raw).collect();
+
+    Ok(RawFilteredSequencerBlock {
+        block_hash: Bytes::copy_from_slice(block_hash.as_bytes()),
+        header: Some(header.into_raw()),
+        rollup_transactions,
+        rollup_transactions_proof: Some(rollup_transactions_proof.into_raw()),
+        rollup_ids_proof: Some(rollup_ids_proof.into_raw()),
+        all_rollup_ids,
+        upgrade_change_hashes: upgrade_change_hashes
+            .into_iter()
+            .map(|change_hash| Bytes::copy_from_slice(change_hash.as_bytes()))
+            .collect(),
+        extended_commit_info_with_proof: extended_commit_info_with_proof
+            .map(ExtendedCommitInfoWithProof::into_raw),
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use astria_core::{
@@ -338,9 +475,13 @@ mod tests {
             test_utils::ConfigureSequencerBlock,
             transaction::v1::action::ValidatorUpdate,
         },
-        sequencerblock::v1::SequencerBlock,
+        sequencerblock::v1::{
+            block,
+            SequencerBlock,
+        },
     };
     use cnidarium::StateDelta;
+    use futures::StreamExt as _;
     use telemetry::Metrics;
 
     use super::*;
@@ -350,6 +491,7 @@ mod tests {
                 mock_balances,
                 mock_tx_cost,
             },
+            event_bus::EventBus,
             test_utils::get_alice_signing_key,
             StateWriteExt as _,
         },
@@ -377,7 +519,7 @@ mod tests {
         let block = make_test_sequencer_block(1);
         let storage = cnidarium::TempStorage::new().await.unwrap();
         let metrics = Box::leak(Box::new(Metrics::noop_metrics(&()).unwrap()));
-        let mempool = Mempool::new(metrics, 100);
+        let mempool = Mempool::new(metrics, 100, 10);
         let mut state_tx = StateDelta::new(storage.latest_snapshot());
         state_tx.put_block_height(1).unwrap();
         state_tx.put_sequencer_block(block).unwrap();
@@ -387,6 +529,8 @@ mod tests {
             storage.clone(),
             mempool,
             Upgrades::default(),
+            EventBus::new().subscribe(),
+            CancellationToken::new(),
         ));
         let request = GetSequencerBlockRequest {
             height: 1,
@@ -396,11 +540,96 @@ mod tests {
         assert_eq!(response.into_inner().header.unwrap().height, 1);
     }
 
+    async fn commit_test_sequencer_block(storage: &cnidarium::Storage, height: u32) {
+        let mut state_tx = StateDelta::new(storage.latest_snapshot());
+        state_tx.put_block_height(height.into()).unwrap();
+        let block = ConfigureSequencerBlock {
+            block_hash: Some(block::Hash::new([height.to_le_bytes()[0]; 32])),
+            height,
+            ..Default::default()
+        }
+        .make();
+        state_tx.put_sequencer_block(block).unwrap();
+        storage.commit(state_tx).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn stream_filtered_sequencer_blocks_catches_up_then_follows_commits() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let metrics = Box::leak(Box::new(Metrics::noop_metrics(&()).unwrap()));
+        let mempool = Mempool::new(metrics, 100, 10);
+        commit_test_sequencer_block(&storage, 1).await;
+        commit_test_sequencer_block(&storage, 2).await;
+
+        let event_bus = EventBus::new();
+        let server = Arc::new(SequencerServer::new(
+            storage.clone(),
+            mempool,
+            Upgrades::default(),
+            event_bus.subscribe(),
+            CancellationToken::new(),
+        ));
+        let request = StreamFilteredSequencerBlocksRequest {
+            start_height: 1,
+            rollup_ids: vec![RollupId::new([1; 32]).into_raw()],
+        };
+        let mut stream = server
+            .stream_filtered_sequencer_blocks(Request::new(request))
+            .await
+            .unwrap()
+            .into_inner();
+
+        for expected_height in [1, 2] {
+            let block = stream.next().await.unwrap().unwrap();
+            assert_eq!(block.header.unwrap().height, expected_height);
+        }
+
+        commit_test_sequencer_block(&storage, 3).await;
+        event_bus.send_committed_block_height(3);
+        let block = stream.next().await.unwrap().unwrap();
+        assert_eq!(block.header.unwrap().height, 3);
+    }
+
+    #[tokio::test]
+    async fn stream_filtered_sequencer_blocks_without_start_height_only_sends_new_blocks() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let metrics = Box::leak(Box::new(Metrics::noop_metrics(&()).unwrap()));
+        let mempool = Mempool::new(metrics, 100, 10);
+        commit_test_sequencer_block(&storage, 1).await;
+
+        let event_bus = EventBus::new();
+        let cancellation_token = CancellationToken::new();
+        let server = Arc::new(SequencerServer::new(
+            storage.clone(),
+            mempool,
+            Upgrades::default(),
+            event_bus.subscribe(),
+            cancellation_token.clone(),
+        ));
+        let request = StreamFilteredSequencerBlocksRequest {
+            start_height: 0,
+            rollup_ids: vec![],
+        };
+        let mut stream = server
+            .stream_filtered_sequencer_blocks(Request::new(request))
+            .await
+            .unwrap()
+            .into_inner();
+
+        commit_test_sequencer_block(&storage, 2).await;
+        event_bus.send_committed_block_height(2);
+        let block = stream.next().await.unwrap().unwrap();
+        assert_eq!(block.header.unwrap().height, 2);
+
+        cancellation_token.cancel();
+        assert!(stream.next().await.is_none());
+    }
+
     #[tokio::test]
     async fn get_pending_nonce_in_mempool() {
         let storage = cnidarium::TempStorage::new().await.unwrap();
         let metrics = Box::leak(Box::new(Metrics::noop_metrics(&()).unwrap()));
-        let mempool = Mempool::new(metrics, 100);
+        let mempool = Mempool::new(metrics, 100, 10);
 
         let alice = get_alice_signing_key();
         let alice_address = astria_address(&alice.address_bytes());
@@ -410,7 +639,7 @@ mod tests {
             .nonce(gapped_nonce)
             .build();
         mempool
-            .insert(tx, 0, mock_balances(0, 0), mock_tx_cost(0, 0, 0))
+            .insert(tx, 0, mock_balances(0, 0), mock_tx_cost(0, 0, 0), 0)
             .await
             .unwrap();
 
@@ -421,7 +650,7 @@ mod tests {
             .build();
 
         mempool
-            .insert(tx, 0, mock_balances(0, 0), mock_tx_cost(0, 0, 0))
+            .insert(tx, 0, mock_balances(0, 0), mock_tx_cost(0, 0, 0), 0)
             .await
             .unwrap();
 
@@ -432,7 +661,7 @@ mod tests {
                 .nonce(sequential_nonce)
                 .build();
         mempool
-            .insert(tx, 0, mock_balances(0, 0), mock_tx_cost(0, 0, 0))
+            .insert(tx, 0, mock_balances(0, 0), mock_tx_cost(0, 0, 0), 0)
             .await
             .unwrap();
 
@@ -440,6 +669,8 @@ mod tests {
             storage.clone(),
             mempool,
             Upgrades::default(),
+            EventBus::new().subscribe(),
+            CancellationToken::new(),
         ));
         let request = GetPendingNonceRequest {
             address: Some(alice_address.into_raw()),
@@ -455,7 +686,7 @@ mod tests {
 
         let storage = cnidarium::TempStorage::new().await.unwrap();
         let metrics = Box::leak(Box::new(Metrics::noop_metrics(&()).unwrap()));
-        let mempool = Mempool::new(metrics, 100);
+        let mempool = Mempool::new(metrics, 100, 10);
         let mut state_tx = StateDelta::new(storage.latest_snapshot());
         let alice = get_alice_signing_key();
         let alice_address = astria_address(&alice.address_bytes());
@@ -466,6 +697,8 @@ mod tests {
             storage.clone(),
             mempool,
             Upgrades::default(),
+            EventBus::new().subscribe(),
+            CancellationToken::new(),
         ));
         let request = GetPendingNonceRequest {
             address: Some(alice_address.into_raw()),
@@ -478,7 +711,7 @@ mod tests {
     #[tokio::test]
     async fn get_validator_name_works_as_expected() {
         let metrics = Box::leak(Box::new(Metrics::noop_metrics(&()).unwrap()));
-        let mempool = Mempool::new(metrics, 100);
+        let mempool = Mempool::new(metrics, 100, 10);
         let storage = cnidarium::TempStorage::new().await.unwrap();
         let snapshot = storage.latest_snapshot();
         let mut state = StateDelta::new(snapshot);
@@ -500,6 +733,8 @@ mod tests {
             storage.clone(),
             mempool,
             Upgrades::default(),
+            EventBus::new().subscribe(),
+            CancellationToken::new(),
         ));
         let request = GetValidatorNameRequest {
             address: Some(astria_address(&key_address_bytes).into_raw()),
@@ -515,13 +750,15 @@ mod tests {
     #[tokio::test]
     async fn validator_name_request_fails_if_not_a_validator() {
         let metrics = Box::leak(Box::new(Metrics::noop_metrics(&()).unwrap()));
-        let mempool = Mempool::new(metrics, 100);
+        let mempool = Mempool::new(metrics, 100, 10);
         let storage = cnidarium::TempStorage::new().await.unwrap();
 
         let server = Arc::new(SequencerServer::new(
             storage.clone(),
             mempool,
             Upgrades::default(),
+            EventBus::new().subscribe(),
+            CancellationToken::new(),
         ));
         let request = GetValidatorNameRequest {
             address: Some(astria_address(&[0; 20]).into_raw()),
@@ -539,7 +776,7 @@ mod tests {
     #[tokio::test]
     async fn validator_name_request_fails_if_pre_aspen() {
         let metrics = Box::leak(Box::new(Metrics::noop_metrics(&()).unwrap()));
-        let mempool = Mempool::new(metrics, 100);
+        let mempool = Mempool::new(metrics, 100, 10);
         let storage = cnidarium::TempStorage::new().await.unwrap();
         let snapshot = storage.latest_snapshot();
         let mut state = StateDelta::new(snapshot);
@@ -561,6 +798,8 @@ mod tests {
             storage.clone(),
             mempool,
             Upgrades::default(),
+            EventBus::new().subscribe(),
+            CancellationToken::new(),
         ));
         let request = GetValidatorNameRequest {
             address: Some(astria_address(&key_address_bytes).into_raw()),
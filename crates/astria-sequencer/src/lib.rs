@@ -13,17 +13,21 @@ mod build_info;
 pub(crate) mod component;
 pub mod config;
 pub(crate) mod fees;
+pub(crate) mod governance;
 pub(crate) mod grpc;
 pub(crate) mod ibc;
 mod mempool;
 pub(crate) mod metrics;
+pub(crate) mod multisig;
 pub(crate) mod oracles;
 pub(crate) mod proposal;
 mod sequencer;
 pub(crate) mod service;
+mod state_sync;
 pub(crate) mod storage;
 #[cfg(test)]
 pub(crate) mod test_utils;
+pub(crate) mod timelock;
 pub(crate) mod transaction;
 pub(crate) mod upgrades;
 mod utils;
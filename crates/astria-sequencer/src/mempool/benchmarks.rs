@@ -105,7 +105,7 @@ fn init_mempool<T: MempoolSize>() -> Mempool {
         .build()
         .unwrap();
     let metrics = Box::leak(Box::new(Metrics::noop_metrics(&()).unwrap()));
-    let mempool = Mempool::new(metrics, T::size());
+    let mempool = Mempool::new(metrics, T::size(), 10);
     let account_mock_balance = mock_balances(0, 0);
     let tx_mock_cost = mock_tx_cost(0, 0, 0);
     runtime.block_on(async {
@@ -116,6 +116,7 @@ fn init_mempool<T: MempoolSize>() -> Mempool {
                     0,
                     account_mock_balance.clone(),
                     tx_mock_cost.clone(),
+                    0,
                 )
                 .await
                 .unwrap();
@@ -168,7 +169,7 @@ fn insert<T: MempoolSize>(bencher: divan::Bencher) {
         .bench_values(move |(mempool, tx, mock_balances, mock_tx_cost)| {
             runtime.block_on(async {
                 mempool
-                    .insert(tx, 0, mock_balances, mock_tx_cost)
+                    .insert(tx, 0, mock_balances, mock_tx_cost, 0)
                     .await
                     .unwrap();
             });
@@ -362,11 +363,7 @@ fn pending_nonce<T: MempoolSize>(bencher: divan::Bencher) {
         .unwrap();
     bencher
         .with_inputs(|| {
-            let address = transactions()
-                .first()
-                .unwrap()
-                .verification_key()
-                .address_bytes();
+            let address = transactions().first().unwrap().address_bytes();
             (init_mempool::<T>(), address)
         })
         .bench_values(move |(mempool, address)| {
@@ -51,6 +51,8 @@ pub(crate) enum RemovalReason {
     NonceStale,
     LowerNonceInvalidated,
     FailedPrepareProposal(String),
+    EvictedByHigherFee,
+    Replaced,
 }
 
 /// How long transactions are considered valid in the mempool.
@@ -154,16 +156,22 @@ impl ContainedTxLock<'_> {
 ///
 /// The mempool exposes the pending transactions through `builder_queue()`, which returns a copy of
 /// all pending transactions sorted in the order in which they should be executed. The sort order
-/// is firstly by the difference between the transaction nonce and the account's current nonce
-/// (ascending), and then by time first seen (ascending).
+/// is firstly by action group, then by effective fee per byte (descending), then by the difference
+/// between the transaction nonce and the account's current nonce (ascending), and then by time
+/// first seen (ascending). A transaction's effective fee per byte is the lowest fee per byte among
+/// it and the pending transactions of its account with lower nonces.
 ///
 /// The mempool implements the following policies:
-/// 1. Nonce replacement is not allowed.
+/// 1. A transaction replaces the contained transaction of the same account and nonce if it pays
+///    strictly more, and at least the configured percentage more, in fees.
 /// 2. Accounts cannot have more than `MAX_PARKED_TXS_PER_ACCOUNT` transactions in their parked
 ///    queues.
 /// 3. There is no account limit on pending transactions.
-/// 4. Transactions will expire and can be removed after `TX_TTL` time.
-/// 5. If an account has a transaction removed for being invalid or expired, all transactions for
+/// 4. If the parked queue is full, a new parked transaction evicts the highest-nonce parked
+///    transaction of another account with the lowest fee per byte, provided the new transaction
+///    pays a strictly higher fee per byte. Otherwise the new transaction is rejected.
+/// 5. Transactions will expire and can be removed after `TX_TTL` time.
+/// 6. If an account has a transaction removed for being invalid or expired, all transactions for
 ///    that account with a higher nonce will be removed as well. This is due to the fact that we do
 ///    not execute failing transactions, so a transaction 'failing' will mean that further account
 ///    nonces will not be able to execute either.
@@ -177,12 +185,17 @@ pub(crate) struct Mempool {
     parked: Arc<RwLock<ParkedTransactions<MAX_PARKED_TXS_PER_ACCOUNT>>>,
     comet_bft_removal_cache: Arc<RwLock<RemovalCache>>,
     contained_txs: Arc<RwLock<HashSet<[u8; 32]>>>,
+    replacement_fee_bump_percent: u32,
     metrics: &'static Metrics,
 }
 
 impl Mempool {
     #[must_use]
-    pub(crate) fn new(metrics: &'static Metrics, parked_max_tx_count: usize) -> Self {
+    pub(crate) fn new(
+        metrics: &'static Metrics,
+        parked_max_tx_count: usize,
+        replacement_fee_bump_percent: u32,
+    ) -> Self {
         Self {
             pending: Arc::new(RwLock::new(PendingTransactions::new(TX_TTL))),
             parked: Arc::new(RwLock::new(ParkedTransactions::new(
@@ -194,6 +207,7 @@ impl Mempool {
                     .expect("Removal cache cannot be zero sized"),
             ))),
             contained_txs: Arc::new(RwLock::new(HashSet::new())),
+            replacement_fee_bump_percent,
             metrics,
         }
     }
@@ -212,8 +226,12 @@ impl Mempool {
         }
     }
 
-    /// Inserts a transaction into the mempool and does not allow for transaction replacement.
-    /// Will return the reason for insertion failure if failure occurs.
+    /// Inserts a transaction into the mempool, replacing a contained transaction with the same
+    /// nonce if the new transaction pays enough in fees to do so. Will return the reason for
+    /// insertion failure if failure occurs.
+    ///
+    /// `transaction_fee` is the total fee paid by the transaction and is used to rank it against
+    /// other transactions.
     #[instrument(skip_all, fields(tx_hash = %tx.id(), current_account_nonce), err(level = Level::DEBUG))]
     pub(crate) async fn insert(
         &self,
@@ -221,8 +239,9 @@ impl Mempool {
         current_account_nonce: u32,
         current_account_balances: HashMap<IbcPrefixed, u128>,
         transaction_cost: HashMap<IbcPrefixed, u128>,
+        transaction_fee: u128,
     ) -> Result<(), InsertionError> {
-        let timemarked_tx = TimemarkedTransaction::new(tx, transaction_cost);
+        let timemarked_tx = TimemarkedTransaction::new(tx, transaction_cost, transaction_fee);
         let id = timemarked_tx.id();
         let (mut pending, mut parked) = self.acquire_both_locks().await;
 
@@ -235,30 +254,63 @@ impl Mempool {
             Err(InsertionError::NonceGap | InsertionError::AccountBalanceTooLow) => {
                 // Release the lock asap.
                 drop(pending);
-                // try to add to parked queue
-                match parked.add(
-                    timemarked_tx,
+                // try to add to parked queue, evicting a lower fee transaction if it is full
+                match parked.add_or_evict(
+                    timemarked_tx.clone(),
                     current_account_nonce,
                     &current_account_balances,
                 ) {
-                    Ok(()) => {
+                    Ok(evicted) => {
                         // log current size of parked
                         self.metrics
                             .set_transactions_in_mempool_parked(parked.len());
+                        drop(parked);
 
                         // track in contained txs
-                        self.lock_contained_txs().await.add(id);
+                        let mut contained_lock = self.lock_contained_txs().await;
+                        contained_lock.add(id);
+                        if let Some(evicted) = evicted {
+                            contained_lock.remove(evicted);
+                            drop(contained_lock);
+                            self.metrics.increment_mempool_evicted_by_higher_fee();
+                            self.comet_bft_removal_cache
+                                .write()
+                                .await
+                                .add(evicted, RemovalReason::EvictedByHigherFee);
+                        }
+                        Ok(())
+                    }
+                    Err(InsertionError::NonceTaken) => {
+                        let replaced = parked.replace(
+                            timemarked_tx,
+                            self.replacement_fee_bump_percent,
+                            &current_account_balances,
+                        )?;
+                        drop(parked);
+                        self.record_replacement(id, replaced).await;
                         Ok(())
                     }
                     Err(err) => Err(err),
                 }
             }
+            Err(InsertionError::NonceTaken) => {
+                drop(parked);
+                let replaced = pending.replace(
+                    timemarked_tx,
+                    self.replacement_fee_bump_percent,
+                    &current_account_balances,
+                )?;
+                drop(pending);
+                self.record_replacement(id, replaced).await;
+                Ok(())
+            }
             error @ Err(
                 InsertionError::AlreadyPresent
                 | InsertionError::NonceTooLow
-                | InsertionError::NonceTaken
                 | InsertionError::AccountSizeLimit
-                | InsertionError::ParkedSizeLimit,
+                | InsertionError::ParkedSizeLimit
+                | InsertionError::ReplacementFeeTooLow
+                | InsertionError::ReplacementBalanceTooLow,
             ) => error,
             Ok(()) => {
                 // check parked for txs able to be promoted
@@ -299,9 +351,24 @@ impl Mempool {
         }
     }
 
-    /// Returns a copy of all transactions and their hashes ready for execution, sorted first by the
-    /// difference between a transaction and the account's current nonce and then by the time that
-    /// the transaction was first seen by the appside mempool.
+    /// Tracks the transaction `id` in place of the transaction `replaced` and flags the latter for
+    /// removal from the `CometBFT` mempool.
+    async fn record_replacement(&self, id: [u8; 32], replaced: [u8; 32]) {
+        let mut contained_lock = self.lock_contained_txs().await;
+        contained_lock.remove(replaced);
+        contained_lock.add(id);
+        drop(contained_lock);
+        self.metrics.increment_mempool_replaced();
+        self.comet_bft_removal_cache
+            .write()
+            .await
+            .add(replaced, RemovalReason::Replaced);
+    }
+
+    /// Returns a copy of all transactions and their hashes ready for execution, sorted first by
+    /// action group, then by effective fee per byte, then by the difference between a transaction
+    /// and the account's current nonce and then by the time that the transaction was first seen by
+    /// the appside mempool.
     pub(crate) async fn builder_queue(&self) -> Vec<([u8; 32], Arc<Transaction>)> {
         self.pending.read().await.builder_queue()
     }
@@ -318,7 +385,7 @@ impl Mempool {
         reason: RemovalReason,
     ) {
         let tx_hash = signed_tx.id().get();
-        let address = *signed_tx.verification_key().address_bytes();
+        let address = *signed_tx.address_bytes();
 
         // Try to remove from pending.
         let removed_txs = match self.pending.write().await.remove(signed_tx) {
@@ -536,7 +603,7 @@ mod tests {
     #[tokio::test]
     async fn insert() {
         let metrics = Box::leak(Box::new(Metrics::noop_metrics(&()).unwrap()));
-        let mempool = Mempool::new(metrics, 100);
+        let mempool = Mempool::new(metrics, 100, 10);
         let account_balances = mock_balances(100, 100);
         let tx_cost = mock_tx_cost(10, 10, 0);
 
@@ -544,7 +611,7 @@ mod tests {
         let tx1 = MockTxBuilder::new().nonce(1).build();
         assert!(
             mempool
-                .insert(tx1.clone(), 0, account_balances.clone(), tx_cost.clone())
+                .insert(tx1.clone(), 0, account_balances.clone(), tx_cost.clone(), 0)
                 .await
                 .is_ok(),
             "should be able to insert nonce 1 transaction into mempool"
@@ -554,14 +621,14 @@ mod tests {
         // try to insert again
         assert_eq!(
             mempool
-                .insert(tx1.clone(), 0, account_balances.clone(), tx_cost.clone())
+                .insert(tx1.clone(), 0, account_balances.clone(), tx_cost.clone(), 0)
                 .await
                 .unwrap_err(),
             InsertionError::AlreadyPresent,
             "already present"
         );
 
-        // try to replace nonce
+        // try to replace nonce without paying a higher fee
         let tx1_replacement = MockTxBuilder::new()
             .nonce(1)
             .chain_id("test-chain-id")
@@ -573,18 +640,19 @@ mod tests {
                     0,
                     account_balances.clone(),
                     tx_cost.clone(),
+                    0,
                 )
                 .await
                 .unwrap_err(),
-            InsertionError::NonceTaken,
-            "nonce replace not allowed"
+            InsertionError::ReplacementFeeTooLow,
+            "nonce replace not allowed without a higher fee"
         );
 
         // add too low nonce
         let tx0 = MockTxBuilder::new().nonce(0).build();
         assert_eq!(
             mempool
-                .insert(tx0.clone(), 1, account_balances, tx_cost)
+                .insert(tx0.clone(), 1, account_balances, tx_cost, 0)
                 .await
                 .unwrap_err(),
             InsertionError::NonceTooLow,
@@ -599,7 +667,7 @@ mod tests {
         // nonce 4. This tests some of the odder edge cases that can be hit if a node goes offline
         // or fails to see some transactions that other nodes include into their proposed blocks.
         let metrics = Box::leak(Box::new(Metrics::noop_metrics(&()).unwrap()));
-        let mempool = Mempool::new(metrics, 100);
+        let mempool = Mempool::new(metrics, 100, 10);
         let account_balances = mock_balances(100, 100);
         let tx_cost = mock_tx_cost(10, 10, 0);
 
@@ -608,7 +676,7 @@ mod tests {
         let tx1 = MockTxBuilder::new().nonce(1).build();
         assert!(
             mempool
-                .insert(tx1.clone(), 0, account_balances.clone(), tx_cost.clone())
+                .insert(tx1.clone(), 0, account_balances.clone(), tx_cost.clone(), 0)
                 .await
                 .is_ok(),
             "should be able to insert nonce 1 transaction into mempool"
@@ -618,7 +686,7 @@ mod tests {
         let tx2 = MockTxBuilder::new().nonce(2).build();
         assert!(
             mempool
-                .insert(tx2.clone(), 0, account_balances.clone(), tx_cost.clone())
+                .insert(tx2.clone(), 0, account_balances.clone(), tx_cost.clone(), 0)
                 .await
                 .is_ok(),
             "should be able to insert nonce 2 transaction into mempool"
@@ -628,7 +696,7 @@ mod tests {
         let tx0 = MockTxBuilder::new().nonce(0).build();
         assert!(
             mempool
-                .insert(tx0.clone(), 0, account_balances.clone(), tx_cost.clone())
+                .insert(tx0.clone(), 0, account_balances.clone(), tx_cost.clone(), 0)
                 .await
                 .is_ok(),
             "should be able to insert nonce 0 transaction into mempool"
@@ -638,7 +706,7 @@ mod tests {
         let tx4 = MockTxBuilder::new().nonce(4).build();
         assert!(
             mempool
-                .insert(tx4.clone(), 0, account_balances.clone(), tx_cost.clone())
+                .insert(tx4.clone(), 0, account_balances.clone(), tx_cost.clone(), 0)
                 .await
                 .is_ok(),
             "should be able to insert nonce 4 transaction into mempool"
@@ -688,7 +756,7 @@ mod tests {
     #[tokio::test]
     async fn run_maintenance_promotion() {
         let metrics = Box::leak(Box::new(Metrics::noop_metrics(&()).unwrap()));
-        let mempool = Mempool::new(metrics, 100);
+        let mempool = Mempool::new(metrics, 100, 10);
 
         // create transaction setup to trigger promotions
         //
@@ -701,19 +769,19 @@ mod tests {
         let tx4 = MockTxBuilder::new().nonce(4).build();
 
         mempool
-            .insert(tx1.clone(), 1, initial_balances.clone(), tx_cost.clone())
+            .insert(tx1.clone(), 1, initial_balances.clone(), tx_cost.clone(), 0)
             .await
             .unwrap();
         mempool
-            .insert(tx2.clone(), 1, initial_balances.clone(), tx_cost.clone())
+            .insert(tx2.clone(), 1, initial_balances.clone(), tx_cost.clone(), 0)
             .await
             .unwrap();
         mempool
-            .insert(tx3.clone(), 1, initial_balances.clone(), tx_cost.clone())
+            .insert(tx3.clone(), 1, initial_balances.clone(), tx_cost.clone(), 0)
             .await
             .unwrap();
         mempool
-            .insert(tx4.clone(), 1, initial_balances.clone(), tx_cost.clone())
+            .insert(tx4.clone(), 1, initial_balances.clone(), tx_cost.clone(), 0)
             .await
             .unwrap();
 
@@ -755,7 +823,7 @@ mod tests {
     #[tokio::test]
     async fn run_maintenance_demotion() {
         let metrics = Box::leak(Box::new(Metrics::noop_metrics(&()).unwrap()));
-        let mempool = Mempool::new(metrics, 100);
+        let mempool = Mempool::new(metrics, 100, 10);
 
         // create transaction setup to trigger demotions
         //
@@ -768,19 +836,19 @@ mod tests {
         let tx4 = MockTxBuilder::new().nonce(4).build();
 
         mempool
-            .insert(tx1.clone(), 1, initial_balances.clone(), tx_cost.clone())
+            .insert(tx1.clone(), 1, initial_balances.clone(), tx_cost.clone(), 0)
             .await
             .unwrap();
         mempool
-            .insert(tx2.clone(), 1, initial_balances.clone(), tx_cost.clone())
+            .insert(tx2.clone(), 1, initial_balances.clone(), tx_cost.clone(), 0)
             .await
             .unwrap();
         mempool
-            .insert(tx3.clone(), 1, initial_balances.clone(), tx_cost.clone())
+            .insert(tx3.clone(), 1, initial_balances.clone(), tx_cost.clone(), 0)
             .await
             .unwrap();
         mempool
-            .insert(tx4.clone(), 1, initial_balances.clone(), tx_cost.clone())
+            .insert(tx4.clone(), 1, initial_balances.clone(), tx_cost.clone(), 0)
             .await
             .unwrap();
 
@@ -841,7 +909,7 @@ mod tests {
     #[tokio::test]
     async fn remove_invalid() {
         let metrics = Box::leak(Box::new(Metrics::noop_metrics(&()).unwrap()));
-        let mempool = Mempool::new(metrics, 100);
+        let mempool = Mempool::new(metrics, 100, 10);
         let account_balances = mock_balances(100, 100);
         let tx_cost = mock_tx_cost(10, 10, 10);
 
@@ -849,7 +917,7 @@ mod tests {
         let tx0 = MockTxBuilder::new().nonce(0).build();
         assert!(
             mempool
-                .insert(tx0.clone(), 0, account_balances.clone(), tx_cost.clone())
+                .insert(tx0.clone(), 0, account_balances.clone(), tx_cost.clone(), 0)
                 .await
                 .is_ok(),
             "should be able to insert nonce 0 transaction into mempool"
@@ -857,7 +925,7 @@ mod tests {
         let tx1 = MockTxBuilder::new().nonce(1).build();
         assert!(
             mempool
-                .insert(tx1.clone(), 0, account_balances.clone(), tx_cost.clone())
+                .insert(tx1.clone(), 0, account_balances.clone(), tx_cost.clone(), 0)
                 .await
                 .is_ok(),
             "should be able to insert nonce 1 transaction into mempool"
@@ -865,7 +933,7 @@ mod tests {
         let tx3 = MockTxBuilder::new().nonce(3).build();
         assert!(
             mempool
-                .insert(tx3.clone(), 0, account_balances.clone(), tx_cost.clone())
+                .insert(tx3.clone(), 0, account_balances.clone(), tx_cost.clone(), 0)
                 .await
                 .is_ok(),
             "should be able to insert nonce 3 transaction into mempool"
@@ -873,7 +941,7 @@ mod tests {
         let tx4 = MockTxBuilder::new().nonce(4).build();
         assert!(
             mempool
-                .insert(tx4.clone(), 0, account_balances.clone(), tx_cost.clone())
+                .insert(tx4.clone(), 0, account_balances.clone(), tx_cost.clone(), 0)
                 .await
                 .is_ok(),
             "should be able to insert nonce 4 transaction into mempool"
@@ -881,7 +949,7 @@ mod tests {
         let tx5 = MockTxBuilder::new().nonce(5).build();
         assert!(
             mempool
-                .insert(tx5.clone(), 0, account_balances.clone(), tx_cost.clone())
+                .insert(tx5.clone(), 0, account_balances.clone(), tx_cost.clone(), 0)
                 .await
                 .is_ok(),
             "should be able to insert nonce 5 transaction into mempool"
@@ -944,7 +1012,7 @@ mod tests {
     #[tokio::test]
     async fn should_get_pending_nonce() {
         let metrics = Box::leak(Box::new(Metrics::noop_metrics(&()).unwrap()));
-        let mempool = Mempool::new(metrics, 100);
+        let mempool = Mempool::new(metrics, 100, 10);
 
         let account_balances = mock_balances(100, 100);
         let tx_cost = mock_tx_cost(10, 10, 0);
@@ -953,7 +1021,7 @@ mod tests {
         let tx0 = MockTxBuilder::new().nonce(0).build();
         assert!(
             mempool
-                .insert(tx0.clone(), 0, account_balances.clone(), tx_cost.clone())
+                .insert(tx0.clone(), 0, account_balances.clone(), tx_cost.clone(), 0)
                 .await
                 .is_ok(),
             "should be able to insert nonce 0 transaction into mempool"
@@ -961,7 +1029,7 @@ mod tests {
         let tx1 = MockTxBuilder::new().nonce(1).build();
         assert!(
             mempool
-                .insert(tx1.clone(), 0, account_balances.clone(), tx_cost.clone())
+                .insert(tx1.clone(), 0, account_balances.clone(), tx_cost.clone(), 0)
                 .await
                 .is_ok(),
             "should be able to insert nonce 1 transaction into mempool"
@@ -979,6 +1047,7 @@ mod tests {
                     100,
                     account_balances.clone(),
                     tx_cost.clone(),
+                    0,
                 )
                 .await
                 .is_ok(),
@@ -995,6 +1064,7 @@ mod tests {
                     100,
                     account_balances.clone(),
                     tx_cost.clone(),
+                    0,
                 )
                 .await
                 .is_ok(),
@@ -1085,7 +1155,7 @@ mod tests {
     #[tokio::test]
     async fn tx_tracked_invalid_removal_removes_all() {
         let metrics = Box::leak(Box::new(Metrics::noop_metrics(&()).unwrap()));
-        let mempool = Mempool::new(metrics, 100);
+        let mempool = Mempool::new(metrics, 100, 10);
         let account_balances = mock_balances(100, 100);
         let tx_cost = mock_tx_cost(10, 10, 0);
 
@@ -1094,14 +1164,14 @@ mod tests {
 
         // check that the parked transaction is in the tracked set
         mempool
-            .insert(tx1.clone(), 0, account_balances.clone(), tx_cost.clone())
+            .insert(tx1.clone(), 0, account_balances.clone(), tx_cost.clone(), 0)
             .await
             .unwrap();
         assert!(mempool.is_tracked(tx1.id().get()).await);
 
         // check that the pending transaction is in the tracked set
         mempool
-            .insert(tx0.clone(), 0, account_balances.clone(), tx_cost.clone())
+            .insert(tx0.clone(), 0, account_balances.clone(), tx_cost.clone(), 0)
             .await
             .unwrap();
         assert!(mempool.is_tracked(tx0.id().get()).await);
@@ -1119,7 +1189,7 @@ mod tests {
     #[tokio::test]
     async fn tx_tracked_maintenance_removes_all() {
         let metrics = Box::leak(Box::new(Metrics::noop_metrics(&()).unwrap()));
-        let mempool = Mempool::new(metrics, 100);
+        let mempool = Mempool::new(metrics, 100, 10);
         let account_balances = mock_balances(100, 100);
         let tx_cost = mock_tx_cost(10, 10, 0);
 
@@ -1127,11 +1197,11 @@ mod tests {
         let tx1 = MockTxBuilder::new().nonce(1).build();
 
         mempool
-            .insert(tx1.clone(), 0, account_balances.clone(), tx_cost.clone())
+            .insert(tx1.clone(), 0, account_balances.clone(), tx_cost.clone(), 0)
             .await
             .unwrap();
         mempool
-            .insert(tx0.clone(), 0, account_balances.clone(), tx_cost.clone())
+            .insert(tx0.clone(), 0, account_balances.clone(), tx_cost.clone(), 0)
             .await
             .unwrap();
 
@@ -1152,7 +1222,7 @@ mod tests {
     #[tokio::test]
     async fn tx_tracked_reinsertion_ok() {
         let metrics = Box::leak(Box::new(Metrics::noop_metrics(&()).unwrap()));
-        let mempool = Mempool::new(metrics, 100);
+        let mempool = Mempool::new(metrics, 100, 10);
         let account_balances = mock_balances(100, 100);
         let tx_cost = mock_tx_cost(10, 10, 0);
 
@@ -1160,12 +1230,12 @@ mod tests {
         let tx1 = MockTxBuilder::new().nonce(1).build();
 
         mempool
-            .insert(tx1.clone(), 0, account_balances.clone(), tx_cost.clone())
+            .insert(tx1.clone(), 0, account_balances.clone(), tx_cost.clone(), 0)
             .await
             .unwrap();
 
         mempool
-            .insert(tx0.clone(), 0, account_balances.clone(), tx_cost.clone())
+            .insert(tx0.clone(), 0, account_balances.clone(), tx_cost.clone(), 0)
             .await
             .unwrap();
 
@@ -1179,11 +1249,11 @@ mod tests {
 
         // re-insert the transactions into the mempool
         mempool
-            .insert(tx0.clone(), 0, account_balances.clone(), tx_cost.clone())
+            .insert(tx0.clone(), 0, account_balances.clone(), tx_cost.clone(), 0)
             .await
             .unwrap();
         mempool
-            .insert(tx1.clone(), 0, account_balances.clone(), tx_cost.clone())
+            .insert(tx1.clone(), 0, account_balances.clone(), tx_cost.clone(), 0)
             .await
             .unwrap();
 
@@ -1195,7 +1265,7 @@ mod tests {
     #[tokio::test]
     async fn parked_limit_enforced() {
         let metrics = Box::leak(Box::new(Metrics::noop_metrics(&()).unwrap()));
-        let mempool = Mempool::new(metrics, 1);
+        let mempool = Mempool::new(metrics, 1, 10);
         let account_balances = mock_balances(100, 100);
         let tx_cost = mock_tx_cost(10, 10, 0);
 
@@ -1203,18 +1273,125 @@ mod tests {
         let tx1 = MockTxBuilder::new().nonce(2).build();
 
         mempool
-            .insert(tx1.clone(), 0, account_balances.clone(), tx_cost.clone())
+            .insert(tx1.clone(), 0, account_balances.clone(), tx_cost.clone(), 0)
             .await
             .unwrap();
 
         // size limit fails as expected
         assert_eq!(
             mempool
-                .insert(tx0.clone(), 0, account_balances.clone(), tx_cost.clone())
+                .insert(tx0.clone(), 0, account_balances.clone(), tx_cost.clone(), 0)
                 .await
                 .unwrap_err(),
             InsertionError::ParkedSizeLimit,
             "size limit should be enforced"
         );
     }
+
+    #[tokio::test]
+    async fn parked_full_evicts_lower_fee_transaction() {
+        let metrics = Box::leak(Box::new(Metrics::noop_metrics(&()).unwrap()));
+        let mempool = Mempool::new(metrics, 1, 10);
+        let account_balances = mock_balances(100, 100);
+        let tx_cost = mock_tx_cost(10, 10, 0);
+
+        let tx_low_fee = MockTxBuilder::new().nonce(1).build();
+        let tx_high_fee = MockTxBuilder::new()
+            .nonce(1)
+            .signer(get_bob_signing_key())
+            .build();
+
+        mempool
+            .insert(
+                tx_low_fee.clone(),
+                0,
+                account_balances.clone(),
+                tx_cost.clone(),
+                1,
+            )
+            .await
+            .unwrap();
+        mempool
+            .insert(
+                tx_high_fee.clone(),
+                0,
+                account_balances.clone(),
+                tx_cost.clone(),
+                1_000,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(mempool.len().await, 1);
+        assert!(mempool.is_tracked(tx_high_fee.id().get()).await);
+        assert!(!mempool.is_tracked(tx_low_fee.id().get()).await);
+        assert_eq!(
+            mempool.check_removed_comet_bft(tx_low_fee.id().get()).await,
+            Some(RemovalReason::EvictedByHigherFee),
+            "evicted transaction should be flagged for removal from cometbft's mempool"
+        );
+    }
+
+    #[tokio::test]
+    async fn replace_by_fee() {
+        let metrics = Box::leak(Box::new(Metrics::noop_metrics(&()).unwrap()));
+        let mempool = Mempool::new(metrics, 100, 10);
+        let account_balances = mock_balances(100, 100);
+        let tx_cost = mock_tx_cost(10, 10, 0);
+
+        let tx = MockTxBuilder::new().nonce(0).build();
+        let tx_underpriced = MockTxBuilder::new()
+            .nonce(0)
+            .chain_id("underpriced")
+            .build();
+        let tx_replacement = MockTxBuilder::new()
+            .nonce(0)
+            .chain_id("replacement")
+            .build();
+
+        mempool
+            .insert(
+                tx.clone(),
+                0,
+                account_balances.clone(),
+                tx_cost.clone(),
+                100,
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            mempool
+                .insert(
+                    tx_underpriced.clone(),
+                    0,
+                    account_balances.clone(),
+                    tx_cost.clone(),
+                    109,
+                )
+                .await
+                .unwrap_err(),
+            InsertionError::ReplacementFeeTooLow,
+        );
+        mempool
+            .insert(
+                tx_replacement.clone(),
+                0,
+                account_balances.clone(),
+                tx_cost.clone(),
+                110,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(mempool.len().await, 1);
+        assert!(mempool.is_tracked(tx_replacement.id().get()).await);
+        assert!(!mempool.is_tracked(tx.id().get()).await);
+        assert_eq!(
+            mempool.check_removed_comet_bft(tx.id().get()).await,
+            Some(RemovalReason::Replaced),
+        );
+        let builder_queue = mempool.builder_queue().await;
+        assert_eq!(builder_queue.len(), 1);
+        assert_eq!(builder_queue[0].0, tx_replacement.id().get());
+    }
 }
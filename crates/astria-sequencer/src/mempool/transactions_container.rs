@@ -16,11 +16,13 @@ use astria_core::{
         action::group::Group,
         Transaction,
     },
+    Protobuf as _,
 };
 use astria_eyre::eyre::{
     eyre,
     Result,
 };
+use prost::Message as _;
 use tokio::time::{
     Duration,
     Instant,
@@ -45,20 +47,37 @@ pub(super) struct TimemarkedTransaction {
     time_first_seen: Instant,
     address: [u8; 20],
     cost: HashMap<IbcPrefixed, u128>,
+    fee: u128,
+    size: usize,
 }
 
 impl TimemarkedTransaction {
-    pub(super) fn new(signed_tx: Arc<Transaction>, cost: HashMap<IbcPrefixed, u128>) -> Self {
+    pub(super) fn new(
+        signed_tx: Arc<Transaction>,
+        cost: HashMap<IbcPrefixed, u128>,
+        fee: u128,
+    ) -> Self {
         Self {
             tx_hash: signed_tx.id().get(),
-            address: *signed_tx.verification_key().address_bytes(),
+            address: *signed_tx.address_bytes(),
+            size: signed_tx.to_raw().encoded_len(),
             signed_tx,
             time_first_seen: Instant::now(),
             cost,
+            fee,
         }
     }
 
-    fn priority(&self, current_account_nonce: u32) -> Result<TransactionPriority> {
+    /// Returns the priority of the transaction in the builder queue.
+    ///
+    /// `effective_fee_per_byte` should be the lowest fee per byte among this transaction and all
+    /// pending transactions of the same account with lower nonces, as those have to execute
+    /// first.
+    fn priority(
+        &self,
+        current_account_nonce: u32,
+        effective_fee_per_byte: FeePerByte,
+    ) -> Result<TransactionPriority> {
         let Some(nonce_diff) = self.signed_tx.nonce().checked_sub(current_account_nonce) else {
             return Err(eyre!(
                 "transaction nonce {} is less than current account nonce {current_account_nonce}",
@@ -70,9 +89,27 @@ impl TimemarkedTransaction {
             nonce_diff,
             time_first_seen: self.time_first_seen,
             group: self.signed_tx.group(),
+            fee_per_byte: effective_fee_per_byte,
         })
     }
 
+    pub(super) fn fee_per_byte(&self) -> FeePerByte {
+        FeePerByte::new(self.fee, self.size)
+    }
+
+    /// Returns whether this transaction pays enough in fees to replace `existing`, i.e. strictly
+    /// more and at least `fee_bump_percent` percent more than it.
+    fn pays_replacement_fee(
+        &self,
+        existing: &TimemarkedTransaction,
+        fee_bump_percent: u32,
+    ) -> bool {
+        let required = existing
+            .fee
+            .saturating_mul(u128::from(fee_bump_percent).saturating_add(100));
+        self.fee > existing.fee && self.fee.saturating_mul(100) >= required
+    }
+
     pub(super) fn deduct_costs(
         &self,
         available_balances: &mut HashMap<IbcPrefixed, u128>,
@@ -96,6 +133,10 @@ impl TimemarkedTransaction {
         self.cost = cost_map;
     }
 
+    fn set_fee(&mut self, fee: u128) {
+        self.fee = fee;
+    }
+
     fn is_expired(&self, now: Instant, ttl: Duration) -> bool {
         now.saturating_duration_since(self.time_first_seen) > ttl
     }
@@ -119,12 +160,15 @@ impl TimemarkedTransaction {
 
 impl fmt::Display for TimemarkedTransaction {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let signer = self
+            .signed_tx
+            .verification_key()
+            .map_or_else(|| "multisig".to_string(), ToString::to_string);
         write!(
             f,
-            "tx_hash: {}, address: {}, signer: {}, nonce: {}, chain ID: {}, group: {}",
+            "tx_hash: {}, address: {}, signer: {signer}, nonce: {}, chain ID: {}, group: {}",
             telemetry::display::base64(&self.tx_hash),
             telemetry::display::base64(&self.address),
-            self.signed_tx.verification_key(),
             self.signed_tx.nonce(),
             self.signed_tx.chain_id(),
             self.signed_tx.group(),
@@ -132,11 +176,54 @@ impl fmt::Display for TimemarkedTransaction {
     }
 }
 
+/// The fees paid by a transaction per byte of its encoded size.
+///
+/// Rates are compared by cross-multiplying fees and sizes so that no precision is lost to integer
+/// division.
+#[derive(Clone, Copy, Debug)]
+pub(super) struct FeePerByte {
+    fee: u128,
+    size: u128,
+}
+
+impl FeePerByte {
+    fn new(fee: u128, size: usize) -> Self {
+        Self {
+            fee,
+            // encoded transactions are never empty, but guard against a zero denominator anyway
+            size: u128::try_from(size).unwrap_or(u128::MAX).max(1),
+        }
+    }
+}
+
+impl Ord for FeePerByte {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.fee
+            .saturating_mul(other.size)
+            .cmp(&other.fee.saturating_mul(self.size))
+    }
+}
+
+impl PartialOrd for FeePerByte {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for FeePerByte {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for FeePerByte {}
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 struct TransactionPriority {
     nonce_diff: u32,
     time_first_seen: Instant,
     group: Group,
+    fee_per_byte: FeePerByte,
 }
 
 impl Ord for TransactionPriority {
@@ -147,6 +234,12 @@ impl Ord for TransactionPriority {
             return group;
         }
 
+        // then by fee per byte where a higher fee per byte means higher priority
+        let fee_per_byte = self.fee_per_byte.cmp(&other.fee_per_byte);
+        if fee_per_byte != Ordering::Equal {
+            return fee_per_byte;
+        }
+
         // then by nonce difference where lower nonce diff means higher priority
         let nonce_diff = self.nonce_diff.cmp(&other.nonce_diff).reverse();
         if nonce_diff != Ordering::Equal {
@@ -173,6 +266,8 @@ pub(crate) enum InsertionError {
     AccountSizeLimit,
     AccountBalanceTooLow,
     ParkedSizeLimit,
+    ReplacementFeeTooLow,
+    ReplacementBalanceTooLow,
 }
 
 impl fmt::Display for InsertionError {
@@ -196,6 +291,15 @@ impl fmt::Display for InsertionError {
             InsertionError::ParkedSizeLimit => {
                 write!(f, "parked container size limit reached")
             }
+            InsertionError::ReplacementFeeTooLow => write!(
+                f,
+                "fee is not high enough to replace the transaction with the same nonce"
+            ),
+            InsertionError::ReplacementBalanceTooLow => write!(
+                f,
+                "account does not have enough balance to cover costs with the replacement \
+                 transaction"
+            ),
         }
     }
 }
@@ -477,6 +581,45 @@ pub(super) trait TransactionsForAccount: Default {
         Ok(())
     }
 
+    /// Replaces the transaction with the same nonce as `ttx` and returns the replaced
+    /// transaction.
+    ///
+    /// `ttx` must pay strictly more, and at least `fee_bump_percent` percent more, in fees than
+    /// the transaction it replaces, and the container must still satisfy its balance constraints
+    /// after the replacement. If it does not, the container is left unchanged.
+    ///
+    /// Note: a transaction with the same nonce is expected to be present. If it's absent, an
+    /// error is logged and [`InsertionError::NonceGap`] is returned.
+    fn replace(
+        &mut self,
+        ttx: TimemarkedTransaction,
+        fee_bump_percent: u32,
+        current_account_balances: &HashMap<IbcPrefixed, u128>,
+    ) -> Result<TimemarkedTransaction, InsertionError> {
+        let nonce = ttx.nonce();
+        let Some(existing) = self.txs().get(&nonce) else {
+            error!(nonce, "transaction to replace not found");
+            return Err(InsertionError::NonceGap);
+        };
+        if existing.tx_hash == ttx.tx_hash {
+            return Err(InsertionError::AlreadyPresent);
+        }
+        if !ttx.pays_replacement_fee(existing, fee_bump_percent) {
+            return Err(InsertionError::ReplacementFeeTooLow);
+        }
+
+        let replaced = self
+            .txs_mut()
+            .remove(&nonce)
+            .expect("transaction was found above");
+        if !self.has_balance_to_cover(&ttx, current_account_balances) {
+            self.txs_mut().insert(nonce, replaced);
+            return Err(InsertionError::ReplacementBalanceTooLow);
+        }
+        self.txs_mut().insert(nonce, ttx);
+        Ok(replaced)
+    }
+
     /// Removes transactions with the given nonce and higher.
     ///
     /// Note: the given nonce is expected to be present. If it's absent, an error is logged and no
@@ -605,8 +748,20 @@ pub(super) trait TransactionsContainer<T: TransactionsForAccount> {
                     continue;
                 }
             };
+            let new_fee = match transaction::get_total_transaction_fee(&tx.signed_tx, &state).await
+            {
+                Ok(res) => res,
+                Err(error) => {
+                    error!(
+                        address = %telemetry::display::base64(address),
+                        "failed to calculate new transaction fee when cleaning accounts: {error:#}"
+                    );
+                    continue;
+                }
+            };
 
             tx.set_cost_map(new_cost);
+            tx.set_fee(new_fee);
         }
     }
 
@@ -638,13 +793,36 @@ pub(super) trait TransactionsContainer<T: TransactionsForAccount> {
         Ok(())
     }
 
+    /// Replaces the transaction of the same account and nonce as `ttx`, returning the hash of the
+    /// replaced transaction.
+    ///
+    /// See [`TransactionsForAccount::replace`] for the replacement rules. Should only be called
+    /// after [`Self::add`] failed with [`InsertionError::NonceTaken`].
+    fn replace(
+        &mut self,
+        ttx: TimemarkedTransaction,
+        fee_bump_percent: u32,
+        current_account_balances: &HashMap<IbcPrefixed, u128>,
+    ) -> Result<[u8; 32], InsertionError> {
+        let Some(account_txs) = self.txs_mut().get_mut(ttx.address()) else {
+            error!(
+                address = %telemetry::display::base64(ttx.address()),
+                "account of transaction to replace not found"
+            );
+            return Err(InsertionError::NonceGap);
+        };
+        account_txs
+            .replace(ttx, fee_bump_percent, current_account_balances)
+            .map(|replaced| replaced.tx_hash)
+    }
+
     /// Removes the given transaction and any transactions with higher nonces for the relevant
     /// account.
     ///
     /// If `signed_tx` existed, returns `Ok` with the hashes of the removed transactions. If
     /// `signed_tx` was not in the collection, it is returned via `Err`.
     fn remove(&mut self, signed_tx: Arc<Transaction>) -> Result<Vec<[u8; 32]>, Arc<Transaction>> {
-        let address = signed_tx.verification_key().address_bytes();
+        let address = signed_tx.address_bytes();
 
         // Take the collection for this account out of `self` temporarily.
         let Some(mut account_txs) = self.txs_mut().remove(address) else {
@@ -782,8 +960,12 @@ impl PendingTransactions {
             .and_then(PendingTransactionsForAccount::pending_account_nonce)
     }
 
-    /// Returns a copy of transactions and their hashes sorted by nonce difference and then time
-    /// first seen.
+    /// Returns a copy of transactions and their hashes sorted by group, effective fee per byte,
+    /// nonce difference and then time first seen.
+    ///
+    /// The effective fee per byte of a transaction is the lowest fee per byte among it and the
+    /// account's transactions with lower nonces. This ranks accounts by what their transactions
+    /// pay while keeping each account's transactions in nonce order.
     pub(super) fn builder_queue(&self) -> Vec<([u8; 32], Arc<Transaction>)> {
         // Used to hold the values in Vec for sorting.
         struct QueueEntry {
@@ -803,8 +985,12 @@ impl PendingTransactions {
                 continue;
             };
 
+            let mut effective_fee_per_byte: Option<FeePerByte> = None;
             for ttx in account_txs.txs.values() {
-                let priority = match ttx.priority(current_account_nonce) {
+                let fee_per_byte = effective_fee_per_byte
+                    .map_or(ttx.fee_per_byte(), |lowest| lowest.min(ttx.fee_per_byte()));
+                effective_fee_per_byte = Some(fee_per_byte);
+                let priority = match ttx.priority(current_account_nonce, fee_per_byte) {
                     Ok(priority) => priority,
                     Err(error) => {
                         // mempool could be off due to node connectivity issues
@@ -843,6 +1029,88 @@ impl<const MAX_PARKED_TXS_PER_ACCOUNT: usize> ParkedTransactions<MAX_PARKED_TXS_
         }
     }
 
+    /// Adds the transaction to the container, evicting a parked transaction paying a lower fee per
+    /// byte if the container is full.
+    ///
+    /// Only the highest-nonce transaction of each other account is considered for eviction so that
+    /// evicting it does not leave that account's remaining parked transactions unexecutable. If
+    /// there is no transaction with a lower fee per byte than `ttx`, returns
+    /// [`InsertionError::ParkedSizeLimit`].
+    ///
+    /// Returns the evicted transaction's hash if one was evicted.
+    pub(super) fn add_or_evict(
+        &mut self,
+        ttx: TimemarkedTransaction,
+        current_account_nonce: u32,
+        current_account_balances: &HashMap<IbcPrefixed, u128>,
+    ) -> Result<Option<[u8; 32]>, InsertionError> {
+        match self.check_total_tx_count() {
+            Ok(()) => {
+                return self
+                    .add(ttx, current_account_nonce, current_account_balances)
+                    .map(|()| None);
+            }
+            Err(InsertionError::ParkedSizeLimit) => {}
+            Err(other) => return Err(other),
+        }
+
+        // replacing a transaction does not need space, so leave that to the caller
+        if self
+            .txs
+            .get(ttx.address())
+            .is_some_and(|account_txs| account_txs.txs().contains_key(&ttx.nonce()))
+        {
+            return Err(InsertionError::NonceTaken);
+        }
+
+        let Some((victim_address, victim_nonce)) = self
+            .txs
+            .iter()
+            .filter_map(|(address, account_txs)| {
+                account_txs
+                    .txs()
+                    .last_key_value()
+                    .map(|(nonce, victim)| (address, nonce, victim))
+            })
+            // an account's own transactions are never evicted in favor of each other
+            .filter(|(address, _, victim)| {
+                *address != ttx.address() && victim.fee_per_byte() < ttx.fee_per_byte()
+            })
+            // lowest fee per byte first, then the most recently seen
+            .min_by(|(_, _, a), (_, _, b)| {
+                a.fee_per_byte()
+                    .cmp(&b.fee_per_byte())
+                    .then_with(|| b.time_first_seen.cmp(&a.time_first_seen))
+            })
+            .map(|(address, nonce, _)| (*address, *nonce))
+        else {
+            return Err(InsertionError::ParkedSizeLimit);
+        };
+
+        let victim = self
+            .txs
+            .get_mut(&victim_address)
+            .and_then(|account_txs| account_txs.txs_mut().remove(&victim_nonce))
+            .expect("victim was found in the container above");
+        if let Err(error) = self.add(ttx, current_account_nonce, current_account_balances) {
+            // the new transaction was rejected for some other reason; restore the victim
+            self.txs
+                .entry(victim_address)
+                .or_default()
+                .txs_mut()
+                .insert(victim_nonce, victim);
+            return Err(error);
+        }
+        if self
+            .txs
+            .get(&victim_address)
+            .is_some_and(|account_txs| account_txs.txs().is_empty())
+        {
+            self.txs.remove(&victim_address);
+        }
+        Ok(Some(victim.tx_hash))
+    }
+
     /// Removes and returns the transactions that can be promoted from parked to pending for
     /// an account. Will only return sequential nonces from `target_nonce` whose costs are
     /// covered by the `available_balance`.
@@ -906,6 +1174,7 @@ mod tests {
         signer: SigningKey,
         chain_id: String,
         cost_map: HashMap<IbcPrefixed, u128>,
+        fee: u128,
         group: Group,
     }
 
@@ -918,7 +1187,7 @@ mod tests {
                 .group(self.group)
                 .build();
 
-            TimemarkedTransaction::new(tx, self.cost_map)
+            TimemarkedTransaction::new(tx, self.cost_map, self.fee)
         }
 
         fn chain_id(self, chain_id: &str) -> Self {
@@ -935,6 +1204,7 @@ mod tests {
                 chain_id: "test".to_string(),
                 group: Group::BundleableGeneral,
                 cost_map: mock_tx_cost(0, 0, 0),
+                fee: 0,
             }
         }
 
@@ -965,12 +1235,19 @@ mod tests {
                 ..self
             }
         }
+
+        fn fee(self, fee: u128) -> Self {
+            Self {
+                fee,
+                ..self
+            }
+        }
     }
 
     #[test]
     fn transaction_priority_should_error_if_invalid() {
         let ttx = MockTTXBuilder::new().nonce(0).build();
-        let priority = ttx.priority(1);
+        let priority = ttx.priority(1, ttx.fee_per_byte());
 
         assert!(priority
             .unwrap_err()
@@ -987,21 +1264,25 @@ mod tests {
             group: Group::BundleableGeneral,
             nonce_diff: 0,
             time_first_seen: instant,
+            fee_per_byte: FeePerByte::new(0, 1),
         };
         let unbundleable_general = TransactionPriority {
             group: Group::UnbundleableGeneral,
             nonce_diff: 0,
             time_first_seen: instant,
+            fee_per_byte: FeePerByte::new(0, 1),
         };
         let bundleable_sudo = TransactionPriority {
             group: Group::BundleableSudo,
             nonce_diff: 0,
             time_first_seen: instant,
+            fee_per_byte: FeePerByte::new(0, 1),
         };
         let unbundleable_sudo = TransactionPriority {
             group: Group::UnbundleableSudo,
             nonce_diff: 0,
             time_first_seen: instant,
+            fee_per_byte: FeePerByte::new(0, 1),
         };
 
         // partial_cmp
@@ -1098,11 +1379,13 @@ mod tests {
             group: Group::BundleableGeneral,
             nonce_diff: 0,
             time_first_seen: instant,
+            fee_per_byte: FeePerByte::new(0, 1),
         };
         let low = TransactionPriority {
             group: Group::BundleableGeneral,
             nonce_diff: 1,
             time_first_seen: instant,
+            fee_per_byte: FeePerByte::new(0, 1),
         };
 
         assert!(high.partial_cmp(&high) == Some(Ordering::Equal));
@@ -1147,11 +1430,13 @@ mod tests {
             group: Group::BundleableGeneral,
             nonce_diff: 0,
             time_first_seen: Instant::now(),
+            fee_per_byte: FeePerByte::new(0, 1),
         };
         let low = TransactionPriority {
             group: Group::BundleableGeneral,
             nonce_diff: 0,
             time_first_seen: Instant::now() + Duration::from_micros(10),
+            fee_per_byte: FeePerByte::new(0, 1),
         };
 
         assert!(high.partial_cmp(&high) == Some(Ordering::Equal));
@@ -2347,4 +2632,294 @@ mod tests {
         // adding should now be okay
         parked_txs.add(ttx_2, 0, &account_balances_full).unwrap();
     }
+
+    #[test]
+    fn parked_transactions_add_or_evict_evicts_lowest_fee_per_byte() {
+        let mut parked_txs = ParkedTransactions::<MAX_PARKED_TXS_PER_ACCOUNT>::new(TX_TTL, 2);
+
+        let ttx_alice = MockTTXBuilder::new().nonce(1).fee(10).build();
+        let ttx_bob = MockTTXBuilder::new()
+            .nonce(1)
+            .signer(get_bob_signing_key())
+            .fee(5)
+            .build();
+        let ttx_carol_low = MockTTXBuilder::new()
+            .nonce(1)
+            .signer(get_carol_signing_key())
+            .fee(1)
+            .build();
+        let ttx_carol_high = MockTTXBuilder::new()
+            .nonce(1)
+            .signer(get_carol_signing_key())
+            .fee(100)
+            .build();
+        let account_balances = mock_balances(100, 100);
+
+        // under limit nothing is evicted
+        assert_eq!(
+            parked_txs
+                .add_or_evict(ttx_alice.clone(), 0, &account_balances)
+                .unwrap(),
+            None
+        );
+        assert_eq!(
+            parked_txs
+                .add_or_evict(ttx_bob.clone(), 0, &account_balances)
+                .unwrap(),
+            None
+        );
+
+        // a full container rejects transactions which do not pay more than any parked one
+        assert_eq!(
+            parked_txs
+                .add_or_evict(ttx_carol_low, 0, &account_balances)
+                .unwrap_err(),
+            InsertionError::ParkedSizeLimit,
+        );
+
+        // a higher paying transaction evicts the lowest paying one
+        assert_eq!(
+            parked_txs
+                .add_or_evict(ttx_carol_high.clone(), 0, &account_balances)
+                .unwrap(),
+            Some(ttx_bob.tx_hash)
+        );
+        assert_eq!(parked_txs.len(), 2);
+        assert!(parked_txs.contains_tx(&ttx_alice.tx_hash));
+        assert!(parked_txs.contains_tx(&ttx_carol_high.tx_hash));
+        assert!(!parked_txs.contains_tx(&ttx_bob.tx_hash));
+    }
+
+    #[test]
+    fn parked_transactions_add_or_evict_does_not_evict_own_account() {
+        let mut parked_txs = ParkedTransactions::<MAX_PARKED_TXS_PER_ACCOUNT>::new(TX_TTL, 1);
+
+        let ttx_1 = MockTTXBuilder::new().nonce(1).fee(1).build();
+        let ttx_2 = MockTTXBuilder::new().nonce(2).fee(100).build();
+        let account_balances = mock_balances(100, 100);
+
+        parked_txs
+            .add_or_evict(ttx_1.clone(), 0, &account_balances)
+            .unwrap();
+        assert_eq!(
+            parked_txs
+                .add_or_evict(ttx_2, 0, &account_balances)
+                .unwrap_err(),
+            InsertionError::ParkedSizeLimit,
+        );
+        assert!(parked_txs.contains_tx(&ttx_1.tx_hash));
+    }
+
+    #[test]
+    fn parked_transactions_add_or_evict_restores_victim_on_failure() {
+        let mut parked_txs = ParkedTransactions::<MAX_PARKED_TXS_PER_ACCOUNT>::new(TX_TTL, 1);
+
+        let ttx_alice = MockTTXBuilder::new().nonce(1).fee(1).build();
+        // bob's nonce is below his account nonce, so insertion fails after the eviction
+        let ttx_bob = MockTTXBuilder::new()
+            .nonce(1)
+            .signer(get_bob_signing_key())
+            .fee(100)
+            .build();
+        let account_balances = mock_balances(100, 100);
+
+        parked_txs
+            .add_or_evict(ttx_alice.clone(), 0, &account_balances)
+            .unwrap();
+        assert_eq!(
+            parked_txs
+                .add_or_evict(ttx_bob, 2, &account_balances)
+                .unwrap_err(),
+            InsertionError::NonceTooLow,
+        );
+        assert!(parked_txs.contains_tx(&ttx_alice.tx_hash));
+        assert_eq!(parked_txs.len(), 1);
+    }
+
+    #[test]
+    fn fee_per_byte_comparisons_should_not_lose_precision() {
+        assert_eq!(FeePerByte::new(10, 100), FeePerByte::new(1, 10));
+        assert!(FeePerByte::new(11, 100) > FeePerByte::new(1, 10));
+        assert!(FeePerByte::new(1, 101) < FeePerByte::new(1, 100));
+        assert!(FeePerByte::new(0, 1) < FeePerByte::new(1, 1_000_000));
+        // zero sizes are treated as a single byte
+        assert_eq!(FeePerByte::new(5, 0), FeePerByte::new(5, 1));
+    }
+
+    #[test]
+    fn transaction_priority_fee_per_byte_outranks_nonce_diff_and_time() {
+        let instant = Instant::now();
+
+        let high_fee = TransactionPriority {
+            group: Group::BundleableGeneral,
+            nonce_diff: 1,
+            time_first_seen: instant + Duration::from_micros(10),
+            fee_per_byte: FeePerByte::new(2, 1),
+        };
+        let low_fee = TransactionPriority {
+            group: Group::BundleableGeneral,
+            nonce_diff: 0,
+            time_first_seen: instant,
+            fee_per_byte: FeePerByte::new(1, 1),
+        };
+        let other_group = TransactionPriority {
+            group: Group::UnbundleableGeneral,
+            nonce_diff: 0,
+            time_first_seen: instant,
+            fee_per_byte: FeePerByte::new(100, 1),
+        };
+
+        assert!(high_fee > low_fee);
+        assert!(low_fee < high_fee);
+        // group still takes precedence over fees
+        assert!(low_fee > other_group);
+    }
+
+    #[test]
+    fn builder_queue_should_be_sorted_by_effective_fee_per_byte() {
+        let mut pending_txs = PendingTransactions::new(TX_TTL);
+
+        // alice's second transaction pays a lot but must wait for her cheap first one
+        let ttx_alice_0 = MockTTXBuilder::new().nonce(0).fee(1).build();
+        let ttx_alice_1 = MockTTXBuilder::new().nonce(1).fee(1_000).build();
+        let ttx_bob_0 = MockTTXBuilder::new()
+            .nonce(0)
+            .signer(get_bob_signing_key())
+            .fee(100)
+            .build();
+        let ttx_bob_1 = MockTTXBuilder::new()
+            .nonce(1)
+            .signer(get_bob_signing_key())
+            .fee(10)
+            .build();
+        let account_balances = mock_balances(1, 1);
+
+        for ttx in [&ttx_alice_0, &ttx_alice_1, &ttx_bob_0, &ttx_bob_1] {
+            pending_txs.add(ttx.clone(), 0, &account_balances).unwrap();
+        }
+
+        let queue_hashes: Vec<_> = pending_txs
+            .builder_queue()
+            .into_iter()
+            .map(|(hash, _)| hash)
+            .collect();
+        assert_eq!(
+            queue_hashes,
+            vec![
+                ttx_bob_0.tx_hash,
+                ttx_bob_1.tx_hash,
+                ttx_alice_0.tx_hash,
+                ttx_alice_1.tx_hash,
+            ],
+            "expected bob's higher paying transactions first, and each account's transactions in \
+             nonce order"
+        );
+    }
+
+    #[test]
+    fn transactions_for_account_replace() {
+        let mut pending_txs = PendingTransactionsForAccount::new();
+
+        let ttx_0 = MockTTXBuilder::new().nonce(0).fee(100).build();
+        let ttx_1 = MockTTXBuilder::new().nonce(1).fee(100).build();
+        let account_balances = mock_balances(100, 100);
+        pending_txs
+            .add(ttx_0.clone(), 0, &account_balances)
+            .unwrap();
+        pending_txs
+            .add(ttx_1.clone(), 0, &account_balances)
+            .unwrap();
+
+        // re-submitting the same transaction is not a replacement
+        assert_eq!(
+            pending_txs
+                .replace(ttx_0.clone(), 10, &account_balances)
+                .unwrap_err(),
+            InsertionError::AlreadyPresent,
+        );
+
+        // paying less than the required bump fails
+        let ttx_0_underpriced = MockTTXBuilder::new()
+            .nonce(0)
+            .fee(109)
+            .chain_id("underpriced")
+            .build();
+        assert_eq!(
+            pending_txs
+                .replace(ttx_0_underpriced, 10, &account_balances)
+                .unwrap_err(),
+            InsertionError::ReplacementFeeTooLow,
+        );
+
+        // costing more than the account can cover fails
+        let ttx_0_unaffordable = MockTTXBuilder::new()
+            .nonce(0)
+            .fee(110)
+            .chain_id("unaffordable")
+            .cost_map(mock_tx_cost(1_000, 0, 0))
+            .build();
+        assert_eq!(
+            pending_txs
+                .replace(ttx_0_unaffordable, 10, &account_balances)
+                .unwrap_err(),
+            InsertionError::ReplacementBalanceTooLow,
+        );
+        assert!(pending_txs.contains_tx(&ttx_0.tx_hash));
+
+        // paying at least the required bump replaces the transaction, leaving others untouched
+        let ttx_0_replacement = MockTTXBuilder::new()
+            .nonce(0)
+            .fee(110)
+            .chain_id("replacement")
+            .build();
+        let replaced = pending_txs
+            .replace(ttx_0_replacement.clone(), 10, &account_balances)
+            .unwrap();
+        assert_eq!(replaced.tx_hash, ttx_0.tx_hash);
+        assert!(pending_txs.contains_tx(&ttx_0_replacement.tx_hash));
+        assert!(pending_txs.contains_tx(&ttx_1.tx_hash));
+        assert_eq!(pending_txs.txs().len(), 2);
+    }
+
+    #[test]
+    fn replacement_fee_must_strictly_increase() {
+        let existing = MockTTXBuilder::new().fee(0).build();
+        let same_fee = MockTTXBuilder::new().fee(0).chain_id("other").build();
+        let higher_fee = MockTTXBuilder::new().fee(1).chain_id("other").build();
+
+        assert!(!same_fee.pays_replacement_fee(&existing, 0));
+        assert!(higher_fee.pays_replacement_fee(&existing, 0));
+        assert!(higher_fee.pays_replacement_fee(&existing, 10));
+    }
+
+    #[test]
+    fn parked_transactions_add_or_evict_leaves_replacement_to_caller_when_full() {
+        let mut parked_txs = ParkedTransactions::<MAX_PARKED_TXS_PER_ACCOUNT>::new(TX_TTL, 1);
+
+        let ttx = MockTTXBuilder::new().nonce(1).fee(1).build();
+        let ttx_replacement = MockTTXBuilder::new()
+            .nonce(1)
+            .fee(100)
+            .chain_id("replacement")
+            .build();
+        let account_balances = mock_balances(100, 100);
+
+        parked_txs
+            .add_or_evict(ttx.clone(), 0, &account_balances)
+            .unwrap();
+        assert_eq!(
+            parked_txs
+                .add_or_evict(ttx_replacement.clone(), 0, &account_balances)
+                .unwrap_err(),
+            InsertionError::NonceTaken,
+        );
+        assert_eq!(
+            parked_txs
+                .replace(ttx_replacement.clone(), 10, &account_balances)
+                .unwrap(),
+            ttx.tx_hash
+        );
+        assert!(parked_txs.contains_tx(&ttx_replacement.tx_hash));
+        assert_eq!(parked_txs.len(), 1);
+    }
 }
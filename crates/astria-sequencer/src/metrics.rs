@@ -39,6 +39,8 @@ pub struct Metrics {
     transactions_in_mempool_total: Gauge,
     transactions_in_mempool_parked: Gauge,
     mempool_recosted: Counter,
+    mempool_evicted_by_higher_fee: Counter,
+    mempool_replaced: Counter,
     internal_logic_error: Counter,
     extended_commit_info_bytes: Histogram,
     extend_vote_duration_seconds: Histogram,
@@ -165,6 +167,14 @@ impl Metrics {
         self.mempool_recosted.increment(1);
     }
 
+    pub(crate) fn increment_mempool_evicted_by_higher_fee(&self) {
+        self.mempool_evicted_by_higher_fee.increment(1);
+    }
+
+    pub(crate) fn increment_mempool_replaced(&self) {
+        self.mempool_replaced.increment(1);
+    }
+
     pub(crate) fn increment_internal_logic_error(&self) {
         self.internal_logic_error.increment(1);
     }
@@ -367,6 +377,22 @@ impl telemetry::Metrics for Metrics {
             )?
             .register()?;
 
+        let mempool_evicted_by_higher_fee = builder
+            .new_counter_factory(
+                MEMPOOL_EVICTED_BY_HIGHER_FEE,
+                "The number of parked transactions evicted from the app mempool by a transaction \
+                 paying a higher fee per byte",
+            )?
+            .register()?;
+
+        let mempool_replaced = builder
+            .new_counter_factory(
+                MEMPOOL_REPLACED,
+                "The number of transactions in the app mempool replaced by a transaction with the \
+                 same nonce paying a higher fee",
+            )?
+            .register()?;
+
         let internal_logic_error = builder
             .new_counter_factory(
                 INTERNAL_LOGIC_ERROR,
@@ -430,6 +456,8 @@ impl telemetry::Metrics for Metrics {
             transactions_in_mempool_total,
             transactions_in_mempool_parked,
             mempool_recosted,
+            mempool_evicted_by_higher_fee,
+            mempool_replaced,
             internal_logic_error,
             extended_commit_info_bytes,
             extend_vote_duration_seconds,
@@ -463,6 +491,8 @@ metric_names!(const METRICS_NAMES:
     TRANSACTIONS_IN_MEMPOOL_TOTAL,
     TRANSACTIONS_IN_MEMPOOL_PARKED,
     MEMPOOL_RECOSTED,
+    MEMPOOL_EVICTED_BY_HIGHER_FEE,
+    MEMPOOL_REPLACED,
     INTERNAL_LOGIC_ERROR,
     EXTENDED_COMMIT_INFO_BYTES,
     EXTEND_VOTE_DURATION_SECONDS,
@@ -538,6 +568,11 @@ mod tests {
             "transactions_in_mempool_parked",
         );
         assert_const(MEMPOOL_RECOSTED, "mempool_recosted");
+        assert_const(
+            MEMPOOL_EVICTED_BY_HIGHER_FEE,
+            "mempool_evicted_by_higher_fee",
+        );
+        assert_const(MEMPOOL_REPLACED, "mempool_replaced");
         assert_const(INTERNAL_LOGIC_ERROR, "internal_logic_error");
         assert_const(EXTENDED_COMMIT_INFO_BYTES, "extended_commit_info_bytes");
         assert_const(EXTEND_VOTE_DURATION_SECONDS, "extend_vote_duration_seconds");
@@ -0,0 +1,45 @@
+mod state_ext;
+pub(crate) mod storage;
+
+use astria_core::{
+    crypto::VerificationKey,
+    protocol::transaction::v1::action::RegisterMultisig,
+};
+pub(crate) use state_ext::{
+    StateReadExt,
+    StateWriteExt,
+};
+
+/// The M-of-N signing policy of a multisig account.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct MultisigPolicy {
+    members: Vec<VerificationKey>,
+    threshold: u32,
+}
+
+impl MultisigPolicy {
+    pub(crate) fn new(members: Vec<VerificationKey>, threshold: u32) -> Self {
+        Self {
+            members,
+            threshold,
+        }
+    }
+
+    pub(crate) fn members(&self) -> &[VerificationKey] {
+        &self.members
+    }
+
+    pub(crate) fn threshold(&self) -> u32 {
+        self.threshold
+    }
+
+    pub(crate) fn is_member(&self, verification_key: &VerificationKey) -> bool {
+        self.members.contains(verification_key)
+    }
+}
+
+impl From<&RegisterMultisig> for MultisigPolicy {
+    fn from(action: &RegisterMultisig) -> Self {
+        Self::new(action.members().to_vec(), action.threshold())
+    }
+}
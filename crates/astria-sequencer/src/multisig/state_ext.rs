@@ -0,0 +1,129 @@
+use astria_eyre::{
+    anyhow_to_eyre,
+    eyre::{
+        Result,
+        WrapErr as _,
+    },
+};
+use async_trait::async_trait;
+use cnidarium::{
+    StateRead,
+    StateWrite,
+};
+use tracing::{
+    instrument,
+    Level,
+};
+
+use super::{
+    storage::{
+        self,
+        keys,
+    },
+    MultisigPolicy,
+};
+use crate::{
+    accounts::AddressBytes,
+    storage::StoredValue,
+};
+
+#[async_trait]
+pub(crate) trait StateReadExt: StateRead {
+    /// Returns the multisig policy registered for `address`, or `None` if the account is not a
+    /// multisig account.
+    #[instrument(skip_all, fields(address = %address.display_address()), err(level = Level::WARN))]
+    async fn get_multisig_policy<T: AddressBytes>(
+        &self,
+        address: &T,
+    ) -> Result<Option<MultisigPolicy>> {
+        let Some(bytes) = self
+            .get_raw(&keys::policy(address))
+            .await
+            .map_err(anyhow_to_eyre)
+            .wrap_err("failed reading raw multisig policy from state")?
+        else {
+            return Ok(None);
+        };
+        StoredValue::deserialize(&bytes)
+            .and_then(|value| {
+                storage::MultisigPolicy::try_from(value)
+                    .map(|policy| Some(MultisigPolicy::from(policy)))
+            })
+            .wrap_err("invalid multisig policy bytes")
+    }
+
+    #[instrument(skip_all, fields(address = %address.display_address()), err(level = Level::WARN))]
+    async fn is_a_multisig_account<T: AddressBytes>(&self, address: &T) -> Result<bool> {
+        Ok(self.get_multisig_policy(address).await?.is_some())
+    }
+}
+
+impl<T: StateRead + ?Sized> StateReadExt for T {}
+
+#[async_trait]
+pub(crate) trait StateWriteExt: StateWrite {
+    #[instrument(skip_all)]
+    fn put_multisig_policy<T: AddressBytes>(
+        &mut self,
+        address: &T,
+        policy: &MultisigPolicy,
+    ) -> Result<()> {
+        let bytes = StoredValue::from(storage::MultisigPolicy::from(policy))
+            .serialize()
+            .wrap_err("failed to serialize multisig policy")?;
+        self.put_raw(keys::policy(address), bytes);
+        Ok(())
+    }
+}
+
+impl<T: StateWrite> StateWriteExt for T {}
+
+#[cfg(test)]
+mod tests {
+    use astria_core::crypto::SigningKey;
+    use cnidarium::StateDelta;
+
+    use super::*;
+    use crate::benchmark_and_test_utils::astria_address;
+
+    #[tokio::test]
+    async fn get_multisig_policy_uninitialized_ok() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let state = StateDelta::new(snapshot);
+
+        let address = astria_address(&[42u8; 20]);
+        assert_eq!(state.get_multisig_policy(&address).await.unwrap(), None);
+        assert!(!state.is_a_multisig_account(&address).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn put_multisig_policy() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = StateDelta::new(snapshot);
+
+        let address = astria_address(&[42u8; 20]);
+        let policy = MultisigPolicy::new(
+            vec![
+                SigningKey::from([1; 32]).verification_key(),
+                SigningKey::from([2; 32]).verification_key(),
+            ],
+            2,
+        );
+        state.put_multisig_policy(&address, &policy).unwrap();
+        assert_eq!(
+            state.get_multisig_policy(&address).await.unwrap(),
+            Some(policy)
+        );
+        assert!(state.is_a_multisig_account(&address).await.unwrap());
+
+        // can overwrite the existing policy
+        let policy = MultisigPolicy::new(vec![SigningKey::from([3; 32]).verification_key()], 1);
+        state.put_multisig_policy(&address, &policy).unwrap();
+        assert_eq!(
+            state.get_multisig_policy(&address).await.unwrap(),
+            Some(policy)
+        );
+    }
+}
@@ -0,0 +1,32 @@
+use crate::accounts::AddressBytes;
+
+pub(in crate::multisig) const POLICY_PREFIX: &str = "multisig/policy/";
+
+pub(in crate::multisig) fn policy<TAddress: AddressBytes>(address: &TAddress) -> String {
+    format!("{POLICY_PREFIX}{}", hex::encode(address.address_bytes()))
+}
+
+#[cfg(test)]
+mod tests {
+    use astria_core::primitive::v1::Address;
+
+    use super::*;
+
+    const COMPONENT_PREFIX: &str = "multisig/";
+
+    fn address() -> Address {
+        "astria1rsxyjrcm255ds9euthjx6yc3vrjt9sxrm9cfgm"
+            .parse()
+            .unwrap()
+    }
+
+    #[test]
+    fn keys_should_not_change() {
+        insta::assert_snapshot!("policy_key", policy(&address()));
+    }
+
+    #[test]
+    fn keys_should_have_component_prefix() {
+        assert!(policy(&address()).starts_with(COMPONENT_PREFIX));
+    }
+}
@@ -0,0 +1,5 @@
+pub(super) mod keys;
+mod values;
+
+pub(super) use values::MultisigPolicy;
+pub(crate) use values::Value;
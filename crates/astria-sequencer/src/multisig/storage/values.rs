@@ -0,0 +1,127 @@
+use std::{
+    borrow::Cow,
+    fmt::{
+        self,
+        Debug,
+        Formatter,
+    },
+};
+
+use astria_core::crypto::VerificationKey as DomainVerificationKey;
+use astria_eyre::eyre::bail;
+use borsh::{
+    BorshDeserialize,
+    BorshSerialize,
+};
+use telemetry::display::base64;
+
+use crate::multisig::MultisigPolicy as DomainMultisigPolicy;
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub(crate) struct Value<'a>(ValueImpl<'a>);
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+enum ValueImpl<'a> {
+    MultisigPolicy(MultisigPolicy<'a>),
+}
+
+#[derive(BorshSerialize, BorshDeserialize)]
+struct VerificationKey<'a>(Cow<'a, [u8; 32]>);
+
+impl Debug for VerificationKey<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", base64(self.0.as_slice()))
+    }
+}
+
+impl<'a> From<VerificationKey<'a>> for DomainVerificationKey {
+    fn from(value: VerificationKey<'a>) -> Self {
+        DomainVerificationKey::try_from(value.0.into_owned())
+            .expect("verification key in storage must be valid")
+    }
+}
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub(in crate::multisig) struct MultisigPolicy<'a> {
+    members: Vec<VerificationKey<'a>>,
+    threshold: u32,
+}
+
+impl<'a> From<&'a DomainMultisigPolicy> for MultisigPolicy<'a> {
+    fn from(policy: &'a DomainMultisigPolicy) -> Self {
+        MultisigPolicy {
+            members: policy
+                .members()
+                .iter()
+                .map(|key| VerificationKey(Cow::Borrowed(key.as_bytes())))
+                .collect(),
+            threshold: policy.threshold(),
+        }
+    }
+}
+
+impl<'a> From<MultisigPolicy<'a>> for DomainMultisigPolicy {
+    fn from(policy: MultisigPolicy<'a>) -> Self {
+        DomainMultisigPolicy::new(
+            policy
+                .members
+                .into_iter()
+                .map(DomainVerificationKey::from)
+                .collect(),
+            policy.threshold,
+        )
+    }
+}
+
+impl<'a> From<MultisigPolicy<'a>> for crate::storage::StoredValue<'a> {
+    fn from(policy: MultisigPolicy<'a>) -> Self {
+        crate::storage::StoredValue::Multisig(Value(ValueImpl::MultisigPolicy(policy)))
+    }
+}
+
+impl<'a> TryFrom<crate::storage::StoredValue<'a>> for MultisigPolicy<'a> {
+    type Error = astria_eyre::eyre::Error;
+
+    fn try_from(value: crate::storage::StoredValue<'a>) -> Result<Self, Self::Error> {
+        let crate::storage::StoredValue::Multisig(Value(ValueImpl::MultisigPolicy(policy))) = value
+        else {
+            bail!("multisig stored value type mismatch: expected policy, found {value:?}");
+        };
+        Ok(policy)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use insta::assert_snapshot;
+
+    use super::*;
+    use crate::test_utils::borsh_then_hex;
+
+    #[test]
+    fn value_impl_existing_variants_unchanged() {
+        assert_snapshot!(
+            "value_impl_multisig_policy",
+            borsh_then_hex(&ValueImpl::MultisigPolicy(MultisigPolicy {
+                members: vec![VerificationKey(Cow::Borrowed(&[0; 32]))],
+                threshold: 1,
+            }))
+        );
+    }
+
+    // Note: This test must be here instead of in `crate::storage` since `ValueImpl` is not
+    // re-exported.
+    #[test]
+    fn stored_value_multisig_variant_unchanged() {
+        use crate::storage::StoredValue;
+        assert_snapshot!(
+            "stored_value_multisig_variant",
+            borsh_then_hex(&StoredValue::Multisig(Value(ValueImpl::MultisigPolicy(
+                MultisigPolicy {
+                    members: vec![],
+                    threshold: 0,
+                }
+            ))))
+        );
+    }
+}
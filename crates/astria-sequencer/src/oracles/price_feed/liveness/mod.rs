@@ -0,0 +1,678 @@
+pub(crate) mod query;
+mod state_ext;
+pub(crate) mod storage;
+
+use astria_core::{
+    primitive::v1::ADDRESS_LEN,
+    protocol::{
+        genesis::v1::{
+            OracleLivenessParameters,
+            MAX_MIN_PARTICIPATION_BASIS_POINTS,
+        },
+        transaction::v1::action::ValidatorUpdate,
+    },
+};
+use astria_eyre::eyre::{
+    Result,
+    WrapErr as _,
+};
+use cnidarium::StateWrite;
+pub(crate) use state_ext::{
+    StateReadExt,
+    StateWriteExt,
+};
+use tendermint::abci::{
+    types::{
+        BlockSignatureInfo::Flag,
+        ExtendedCommitInfo,
+    },
+    Event,
+};
+use tracing::{
+    debug,
+    instrument,
+    warn,
+    Level,
+};
+
+use crate::authority::{
+    StateReadExt as _,
+    StateWriteExt as _,
+};
+
+/// The oracle participation of a validator over the current window.
+///
+/// Participation is recorded once per block carrying vote extensions. The `n`-th such block is
+/// recorded in slot `n % window_blocks` of `missed_bitmap`, overwriting the block that left the
+/// window.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub(crate) struct ValidatorLiveness {
+    /// The index of the last block in which the validator's participation was recorded.
+    pub(crate) last_recorded_block: u64,
+    /// The number of blocks in the window in which the validator's participation was recorded.
+    pub(crate) blocks_tracked: u64,
+    /// The number of blocks in the window in which the validator did not submit a vote extension.
+    pub(crate) blocks_missed: u64,
+    /// One bit per slot of the window, set if the validator missed the block recorded in it.
+    pub(crate) missed_bitmap: Vec<u8>,
+}
+
+impl ValidatorLiveness {
+    fn record(&mut self, block: u64, window_blocks: u64, missed: bool) {
+        let slot = block
+            .checked_rem(window_blocks)
+            .expect("window must not be empty");
+        let byte = usize::try_from(slot / 8).expect("window fits in memory");
+        let mask = 1u8 << (slot % 8);
+        if self.missed_bitmap.len() <= byte {
+            self.missed_bitmap.resize(byte.saturating_add(1), 0);
+        }
+        let missed_before = self.missed_bitmap[byte] & mask != 0;
+        if missed {
+            self.missed_bitmap[byte] |= mask;
+        } else {
+            self.missed_bitmap[byte] &= !mask;
+        }
+        match (missed_before, missed) {
+            (false, true) => self.blocks_missed = self.blocks_missed.saturating_add(1),
+            (true, false) => self.blocks_missed = self.blocks_missed.saturating_sub(1),
+            _ => {}
+        }
+        self.blocks_tracked = self.blocks_tracked.saturating_add(1).min(window_blocks);
+        self.last_recorded_block = block;
+    }
+
+    /// Returns the share of tracked blocks in which the validator submitted a vote extension, in
+    /// basis points.
+    pub(crate) fn participation_basis_points(&self) -> u32 {
+        if self.blocks_tracked == 0 {
+            return MAX_MIN_PARTICIPATION_BASIS_POINTS;
+        }
+        let submitted = self.blocks_tracked.saturating_sub(self.blocks_missed);
+        let basis_points = u128::from(submitted)
+            .saturating_mul(u128::from(MAX_MIN_PARTICIPATION_BASIS_POINTS))
+            .checked_div(u128::from(self.blocks_tracked))
+            .expect("blocks tracked is not zero");
+        u32::try_from(basis_points).expect("participation never exceeds the maximum")
+    }
+
+    fn is_inactive(&self, params: &OracleLivenessParameters) -> bool {
+        self.blocks_tracked >= params.window_blocks
+            && self.participation_basis_points() < params.min_participation_basis_points
+    }
+}
+
+/// The voting power removed from the validator set for low oracle participation within one
+/// window of `window_blocks` blocks.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct WindowRemovals {
+    /// The index of the block in which the window started.
+    pub(crate) window_start_block: u64,
+    /// The total voting power of the extended commit in which the window started.
+    pub(crate) total_power: u64,
+    /// The voting power removed so far in the window.
+    pub(crate) removed_power: u64,
+}
+
+impl WindowRemovals {
+    /// Returns whether removing a validator with `power` keeps the power removed in the window
+    /// at or below a third of the total power at its start.
+    fn can_remove(&self, power: u64) -> bool {
+        u128::from(self.removed_power)
+            .saturating_add(u128::from(power))
+            .saturating_mul(3)
+            <= u128::from(self.total_power)
+    }
+}
+
+/// Records which validators submitted a vote extension to the extended commit info of a block.
+///
+/// Does nothing if liveness tracking is not configured, or if validators holding less than 2/3 of
+/// the voting power submitted a non-empty vote extension (in which case the oracle as a whole is
+/// down rather than any one validator).
+///
+/// If configured, validators found inactive over a full window are removed from the validator
+/// set, lowest participation first. At most 1/3 of the voting power is removed per window, and
+/// the last validator is never removed.
+#[instrument(skip_all, err(level = Level::WARN))]
+pub(crate) async fn record_participation<S: StateWrite>(
+    state: &mut S,
+    extended_commit_info: &ExtendedCommitInfo,
+) -> Result<()> {
+    let Some(params) = state
+        .get_oracle_liveness_parameters()
+        .await
+        .wrap_err("failed to get oracle liveness parameters")?
+    else {
+        return Ok(());
+    };
+    let (total_power, submitted_power) =
+        extended_commit_info
+            .votes
+            .iter()
+            .fold((0u64, 0u64), |(total, submitted), vote| {
+                let power = vote.validator.power.value();
+                let submitted = if submitted_vote_extension(vote.sig_info, &vote.vote_extension) {
+                    submitted.saturating_add(power)
+                } else {
+                    submitted
+                };
+                (total.saturating_add(power), submitted)
+            });
+    if u128::from(submitted_power).saturating_mul(3) < u128::from(total_power).saturating_mul(2) {
+        debug!(
+            total_power,
+            submitted_power,
+            "validators with less than 2/3 of the voting power submitted a vote extension; \
+             skipping oracle liveness tracking"
+        );
+        return Ok(());
+    }
+
+    let block = state
+        .get_oracle_liveness_blocks_recorded()
+        .await
+        .wrap_err("failed to get number of blocks with recorded oracle liveness")?;
+    let mut inactive = Vec::new();
+    for vote in &extended_commit_info.votes {
+        let address = vote.validator.address;
+        // validators that were not part of the set in the previous block start over
+        let mut liveness = state
+            .get_validator_oracle_liveness(&address)
+            .await
+            .wrap_err("failed to get validator oracle liveness")?
+            .filter(|liveness| liveness.last_recorded_block.checked_add(1) == Some(block))
+            .unwrap_or_default();
+        let missed = !submitted_vote_extension(vote.sig_info, &vote.vote_extension);
+        liveness.record(block, params.window_blocks, missed);
+        if liveness.is_inactive(&params) {
+            inactive.push((address, liveness.participation_basis_points()));
+        }
+        state
+            .put_validator_oracle_liveness(&address, &liveness)
+            .wrap_err("failed to put validator oracle liveness")?;
+    }
+    state
+        .put_oracle_liveness_blocks_recorded(block.saturating_add(1))
+        .wrap_err("failed to put number of blocks with recorded oracle liveness")?;
+
+    if params.remove_inactive_validators && !inactive.is_empty() {
+        inactive.sort_unstable_by_key(|(address, participation_basis_points)| {
+            (*participation_basis_points, *address)
+        });
+        let mut removals = state
+            .get_oracle_liveness_window_removals()
+            .await
+            .wrap_err("failed to get oracle liveness window removals")?
+            .filter(|removals| {
+                removals
+                    .window_start_block
+                    .checked_add(params.window_blocks)
+                    .is_some_and(|window_end| block < window_end)
+            })
+            .unwrap_or(WindowRemovals {
+                window_start_block: block,
+                total_power,
+                removed_power: 0,
+            });
+        for (address, participation_basis_points) in inactive {
+            remove_inactive_validator(state, &address, participation_basis_points, &mut removals)
+                .await
+                .wrap_err("failed to remove inactive validator")?;
+        }
+        state
+            .put_oracle_liveness_window_removals(removals)
+            .wrap_err("failed to put oracle liveness window removals")?;
+    }
+    Ok(())
+}
+
+fn submitted_vote_extension(
+    sig_info: tendermint::abci::types::BlockSignatureInfo,
+    vote_extension: &[u8],
+) -> bool {
+    sig_info == Flag(tendermint::block::BlockIdFlag::Commit) && !vote_extension.is_empty()
+}
+
+async fn remove_inactive_validator<S: StateWrite>(
+    state: &mut S,
+    address: &[u8; ADDRESS_LEN],
+    participation_basis_points: u32,
+    removals: &mut WindowRemovals,
+) -> Result<()> {
+    let Some(validator) = state
+        .get_validator(address)
+        .await
+        .wrap_err("failed to get validator")?
+    else {
+        return Ok(());
+    };
+    let power = u64::from(validator.power);
+    if !removals.can_remove(power) {
+        warn!(
+            address = %hex::encode(address),
+            removed_power = removals.removed_power,
+            total_power = removals.total_power,
+            "not removing inactive validator as it would remove more than 1/3 of the voting \
+             power in this window"
+        );
+        return Ok(());
+    }
+    let validator_count = state
+        .get_validator_count()
+        .await
+        .wrap_err("failed to get validator count")?;
+    if validator_count <= 1 {
+        warn!(
+            address = %hex::encode(address),
+            "not removing inactive validator as it is the last validator"
+        );
+        return Ok(());
+    }
+    state
+        .remove_validator(address)
+        .await
+        .wrap_err("failed to remove validator")?;
+    state
+        .put_validator_count(validator_count.saturating_sub(1))
+        .wrap_err("failed to put validator count")?;
+
+    // add validator update in non-consensus state to be used in end_block
+    let mut validator_updates = state
+        .get_block_validator_updates()
+        .await
+        .wrap_err("failed getting validator updates from state")?;
+    validator_updates.insert(ValidatorUpdate {
+        power: 0,
+        ..validator
+    });
+    state
+        .put_block_validator_updates(validator_updates)
+        .wrap_err("failed to put validator updates in state")?;
+    removals.removed_power = removals.removed_power.saturating_add(power);
+
+    state.delete_validator_oracle_liveness(address);
+    state.record(construct_validator_removed_event(
+        address,
+        participation_basis_points,
+    ));
+    debug!(
+        address = %hex::encode(address),
+        participation_basis_points,
+        "removed validator for low oracle participation"
+    );
+    Ok(())
+}
+
+/// Creates `abci::Event` of kind `oracle_liveness.validator_removed` for a validator removed from
+/// the validator set for low oracle participation.
+fn construct_validator_removed_event(
+    address: &[u8; ADDRESS_LEN],
+    participation_basis_points: u32,
+) -> Event {
+    Event::new(
+        "oracle_liveness.validator_removed",
+        [
+            ("validator", hex::encode(address)),
+            (
+                "participationBasisPoints",
+                participation_basis_points.to_string(),
+            ),
+        ],
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use astria_core::protocol::transaction::v1::action::ValidatorUpdate;
+    use bytes::Bytes;
+    use cnidarium::StateDelta;
+    use tendermint::{
+        abci::types::{
+            ExtendedVoteInfo,
+            Validator,
+        },
+        block::{
+            BlockIdFlag,
+            Round,
+        },
+    };
+
+    use super::*;
+    use crate::benchmark_and_test_utils::verification_key;
+
+    const WINDOW_BLOCKS: u64 = 4;
+
+    fn params(remove_inactive_validators: bool) -> OracleLivenessParameters {
+        OracleLivenessParameters {
+            window_blocks: WINDOW_BLOCKS,
+            min_participation_basis_points: 5_000,
+            remove_inactive_validators,
+        }
+    }
+
+    fn validator(seed: u64) -> ValidatorUpdate {
+        ValidatorUpdate {
+            name: "test".parse().unwrap(),
+            power: 10,
+            verification_key: verification_key(seed),
+        }
+    }
+
+    fn vote(validator: &ValidatorUpdate, submitted: bool) -> ExtendedVoteInfo {
+        ExtendedVoteInfo {
+            validator: Validator {
+                address: *validator.verification_key.address_bytes(),
+                power: validator.power.into(),
+            },
+            sig_info: Flag(if submitted {
+                BlockIdFlag::Commit
+            } else {
+                BlockIdFlag::Absent
+            }),
+            vote_extension: if submitted {
+                Bytes::from_static(&[1])
+            } else {
+                Bytes::new()
+            },
+            extension_signature: None,
+        }
+    }
+
+    fn extended_commit_info(votes: Vec<ExtendedVoteInfo>) -> ExtendedCommitInfo {
+        ExtendedCommitInfo {
+            round: Round::default(),
+            votes,
+        }
+    }
+
+    fn state_with_validators(
+        storage: &cnidarium::TempStorage,
+        params: OracleLivenessParameters,
+        validators: &[ValidatorUpdate],
+    ) -> StateDelta<cnidarium::Snapshot> {
+        let mut state = StateDelta::new(storage.latest_snapshot());
+        state.put_oracle_liveness_parameters(&params).unwrap();
+        for validator in validators {
+            state.put_validator(validator).unwrap();
+        }
+        state
+            .put_validator_count(validators.len().try_into().unwrap())
+            .unwrap();
+        state
+    }
+
+    async fn is_in_set(
+        state: &StateDelta<cnidarium::Snapshot>,
+        validator: &ValidatorUpdate,
+    ) -> bool {
+        state
+            .get_validator(validator.verification_key.address_bytes())
+            .await
+            .unwrap()
+            .is_some()
+    }
+
+    #[test]
+    fn record_tracks_missed_blocks_over_sliding_window() {
+        let mut liveness = ValidatorLiveness::default();
+        assert_eq!(liveness.participation_basis_points(), 10_000);
+
+        for (block, missed) in [true, false, true, true].into_iter().enumerate() {
+            liveness.record(block as u64, WINDOW_BLOCKS, missed);
+        }
+        assert_eq!(liveness.blocks_tracked, WINDOW_BLOCKS);
+        assert_eq!(liveness.blocks_missed, 3);
+        assert_eq!(liveness.participation_basis_points(), 2_500);
+
+        // block 4 overwrites the missed block 0
+        liveness.record(4, WINDOW_BLOCKS, false);
+        assert_eq!(liveness.blocks_tracked, WINDOW_BLOCKS);
+        assert_eq!(liveness.blocks_missed, 2);
+        assert_eq!(liveness.participation_basis_points(), 5_000);
+        assert_eq!(liveness.last_recorded_block, 4);
+    }
+
+    #[tokio::test]
+    async fn record_participation_does_nothing_if_not_configured() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let mut state = StateDelta::new(storage.latest_snapshot());
+        let validator = validator(0);
+
+        record_participation(
+            &mut state,
+            &extended_commit_info(vec![vote(&validator, true)]),
+        )
+        .await
+        .unwrap();
+        assert_eq!(
+            state.get_oracle_liveness_blocks_recorded().await.unwrap(),
+            0
+        );
+    }
+
+    #[tokio::test]
+    async fn record_participation_skips_blocks_without_any_vote_extension() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let validators = [validator(0), validator(1)];
+        let mut state = state_with_validators(&storage, params(true), &validators);
+
+        let info = extended_commit_info(validators.iter().map(|v| vote(v, false)).collect());
+        record_participation(&mut state, &info).await.unwrap();
+        assert_eq!(
+            state.get_oracle_liveness_blocks_recorded().await.unwrap(),
+            0
+        );
+        assert_eq!(
+            state
+                .get_validator_oracle_liveness(validators[0].verification_key.address_bytes())
+                .await
+                .unwrap(),
+            None
+        );
+    }
+
+    #[tokio::test]
+    async fn record_participation_skips_blocks_with_less_than_two_thirds_of_power_submitting() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let validators = [validator(0), validator(1), validator(2)];
+        let mut state = state_with_validators(&storage, params(true), &validators);
+
+        // an oracle-wide outage in which only one validator keeps submitting extensions
+        for _ in 0..WINDOW_BLOCKS.checked_mul(2).unwrap() {
+            let info = extended_commit_info(vec![
+                vote(&validators[0], true),
+                vote(&validators[1], false),
+                vote(&validators[2], false),
+            ]);
+            record_participation(&mut state, &info).await.unwrap();
+        }
+        assert_eq!(
+            state.get_oracle_liveness_blocks_recorded().await.unwrap(),
+            0
+        );
+        assert_eq!(state.get_validator_count().await.unwrap(), 3);
+        assert_eq!(state.get_block_validator_updates().await.unwrap().len(), 0);
+    }
+
+    #[tokio::test]
+    async fn record_participation_tracks_validators() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let validators = [validator(0), validator(1), validator(2)];
+        let mut state = state_with_validators(&storage, params(false), &validators);
+
+        for _ in 0..WINDOW_BLOCKS {
+            let info = extended_commit_info(vec![
+                vote(&validators[0], true),
+                vote(&validators[1], false),
+                vote(&validators[2], true),
+            ]);
+            record_participation(&mut state, &info).await.unwrap();
+        }
+
+        assert_eq!(
+            state.get_oracle_liveness_blocks_recorded().await.unwrap(),
+            WINDOW_BLOCKS
+        );
+        let active = state
+            .get_validator_oracle_liveness(validators[0].verification_key.address_bytes())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(active.participation_basis_points(), 10_000);
+        let inactive = state
+            .get_validator_oracle_liveness(validators[1].verification_key.address_bytes())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(inactive.participation_basis_points(), 0);
+        // removal is not configured
+        assert_eq!(state.get_validator_count().await.unwrap(), 3);
+        assert_eq!(state.get_block_validator_updates().await.unwrap().len(), 0);
+    }
+
+    #[tokio::test]
+    async fn record_participation_removes_validator_inactive_over_full_window() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let validators = [validator(0), validator(1), validator(2)];
+        let mut state = state_with_validators(&storage, params(true), &validators);
+        let inactive_address = *validators[1].verification_key.address_bytes();
+        let votes = || {
+            vec![
+                vote(&validators[0], true),
+                vote(&validators[1], false),
+                vote(&validators[2], true),
+            ]
+        };
+
+        for _ in 0..WINDOW_BLOCKS.checked_sub(1).unwrap() {
+            record_participation(&mut state, &extended_commit_info(votes()))
+                .await
+                .unwrap();
+        }
+        // the window is not yet full
+        assert_eq!(state.get_validator_count().await.unwrap(), 3);
+
+        record_participation(&mut state, &extended_commit_info(votes()))
+            .await
+            .unwrap();
+
+        assert_eq!(state.get_validator_count().await.unwrap(), 2);
+        assert_eq!(state.get_validator(&inactive_address).await.unwrap(), None);
+        let updates = state.get_block_validator_updates().await.unwrap();
+        assert_eq!(updates.get(&inactive_address).unwrap().power, 0);
+        assert_eq!(
+            state
+                .get_validator_oracle_liveness(&inactive_address)
+                .await
+                .unwrap(),
+            None
+        );
+    }
+
+    #[tokio::test]
+    async fn record_participation_removes_at_most_a_third_of_voting_power_per_window() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let validators: Vec<_> = (0..6).map(validator).collect();
+        // any missed block makes a validator inactive
+        let params = OracleLivenessParameters {
+            min_participation_basis_points: 10_000,
+            ..params(true)
+        };
+        let mut state = state_with_validators(&storage, params, &validators);
+
+        // validators 3 and 4 miss two blocks and validator 5 one, with at least 2/3 of the power
+        // submitting in every block
+        let missing: [&[usize]; 4] = [&[3, 4], &[3, 4], &[5], &[]];
+        for missing in missing {
+            let votes = validators
+                .iter()
+                .enumerate()
+                .map(|(index, v)| vote(v, !missing.contains(&index)))
+                .collect();
+            record_participation(&mut state, &extended_commit_info(votes))
+                .await
+                .unwrap();
+        }
+        // all three are inactive, but only 1/3 of the power may be removed, lowest participation
+        // first
+        assert_eq!(state.get_validator_count().await.unwrap(), 4);
+        assert!(!is_in_set(&state, &validators[3]).await);
+        assert!(!is_in_set(&state, &validators[4]).await);
+        assert!(is_in_set(&state, &validators[5]).await);
+
+        // validator 5 keeps missing blocks, but is only removed once the window has passed
+        let remaining = [0, 1, 2, 5];
+        let last_block = WINDOW_BLOCKS
+            .checked_mul(2)
+            .and_then(|blocks| blocks.checked_sub(1))
+            .unwrap();
+        for block in WINDOW_BLOCKS..=last_block {
+            let votes = remaining
+                .iter()
+                .map(|&index| vote(&validators[index], index != 5))
+                .collect();
+            record_participation(&mut state, &extended_commit_info(votes))
+                .await
+                .unwrap();
+            assert_eq!(is_in_set(&state, &validators[5]).await, block < last_block);
+        }
+        assert_eq!(state.get_validator_count().await.unwrap(), 3);
+    }
+
+    #[tokio::test]
+    async fn record_participation_does_not_remove_last_validator() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let validators = [validator(0), validator(1), validator(2)];
+        let mut state = state_with_validators(&storage, params(true), &validators[..1]);
+
+        for _ in 0..WINDOW_BLOCKS {
+            // the other validators are not in the validator set, but submit extensions so that
+            // tracking is not skipped
+            let info = extended_commit_info(vec![
+                vote(&validators[0], false),
+                vote(&validators[1], true),
+                vote(&validators[2], true),
+            ]);
+            record_participation(&mut state, &info).await.unwrap();
+        }
+        assert_eq!(state.get_validator_count().await.unwrap(), 1);
+        assert!(state
+            .get_validator(validators[0].verification_key.address_bytes())
+            .await
+            .unwrap()
+            .is_some());
+    }
+
+    #[tokio::test]
+    async fn record_participation_restarts_validators_that_left_the_set() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let validators = [validator(0), validator(1), validator(2)];
+        let mut state = state_with_validators(&storage, params(false), &validators);
+        let address = *validators[1].verification_key.address_bytes();
+
+        let info = extended_commit_info(vec![
+            vote(&validators[0], true),
+            vote(&validators[1], false),
+            vote(&validators[2], true),
+        ]);
+        record_participation(&mut state, &info).await.unwrap();
+        let info =
+            extended_commit_info(vec![vote(&validators[0], true), vote(&validators[2], true)]);
+        record_participation(&mut state, &info).await.unwrap();
+        let info = extended_commit_info(vec![
+            vote(&validators[0], true),
+            vote(&validators[1], true),
+            vote(&validators[2], true),
+        ]);
+        record_participation(&mut state, &info).await.unwrap();
+
+        let liveness = state
+            .get_validator_oracle_liveness(&address)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(liveness.blocks_tracked, 1);
+        assert_eq!(liveness.blocks_missed, 0);
+    }
+}
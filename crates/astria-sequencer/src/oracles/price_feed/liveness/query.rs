@@ -0,0 +1,269 @@
+use astria_core::protocol::{
+    abci::AbciErrorCode,
+    genesis::v1::OracleLivenessParameters,
+};
+use astria_eyre::eyre::{
+    Result,
+    WrapErr as _,
+};
+use cnidarium::{
+    StateRead,
+    Storage,
+};
+use futures::TryStreamExt as _;
+use tendermint::abci::{
+    request,
+    response,
+    Code,
+};
+use tracing::{
+    instrument,
+    Level,
+};
+
+use super::StateReadExt as _;
+use crate::{
+    address::StateReadExt as _,
+    app::StateReadExt as _,
+    authority::StateReadExt as _,
+};
+
+#[derive(serde::Serialize)]
+struct LivenessResponse {
+    height: u64,
+    parameters: Option<ParametersInner>,
+    blocks_recorded: u64,
+    validators: Vec<ValidatorLivenessResponse>,
+}
+
+#[derive(serde::Serialize)]
+struct ParametersInner {
+    window_blocks: u64,
+    min_participation_basis_points: u32,
+    remove_inactive_validators: bool,
+}
+
+impl From<OracleLivenessParameters> for ParametersInner {
+    fn from(params: OracleLivenessParameters) -> Self {
+        Self {
+            window_blocks: params.window_blocks,
+            min_participation_basis_points: params.min_participation_basis_points,
+            remove_inactive_validators: params.remove_inactive_validators,
+        }
+    }
+}
+
+#[derive(serde::Serialize)]
+struct ValidatorLivenessResponse {
+    address: String,
+    name: String,
+    blocks_tracked: u64,
+    blocks_missed: u64,
+    participation_basis_points: u32,
+}
+
+#[instrument(skip_all, err(level = Level::DEBUG))]
+async fn validators_inner<S: StateRead>(state: &S) -> Result<Vec<ValidatorLivenessResponse>> {
+    let validators: Vec<_> = state
+        .get_validators()
+        .try_collect()
+        .await
+        .wrap_err("failed to get validators")?;
+    let mut responses = Vec::with_capacity(validators.len());
+    for validator in validators {
+        let address_bytes = *validator.verification_key.address_bytes();
+        let liveness = state
+            .get_validator_oracle_liveness(&address_bytes)
+            .await
+            .wrap_err("failed to get validator oracle liveness")?
+            .unwrap_or_default();
+        let address = state
+            .try_base_prefixed(&address_bytes)
+            .await
+            .wrap_err("failed to construct bech32m address of a validator")?;
+        responses.push(ValidatorLivenessResponse {
+            address: address.to_string(),
+            name: validator.name.to_string(),
+            blocks_tracked: liveness.blocks_tracked,
+            blocks_missed: liveness.blocks_missed,
+            participation_basis_points: liveness.participation_basis_points(),
+        });
+    }
+    Ok(responses)
+}
+
+fn internal_error(log: String) -> response::Query {
+    response::Query {
+        code: Code::Err(AbciErrorCode::INTERNAL_ERROR.value()),
+        info: AbciErrorCode::INTERNAL_ERROR.info(),
+        log,
+        ..response::Query::default()
+    }
+}
+
+/// Returns the oracle liveness parameters and the oracle participation of every current validator
+/// as JSON.
+///
+/// The `parameters` field of the response is `null` if liveness tracking was not configured at
+/// genesis.
+#[instrument(skip_all)]
+pub(crate) async fn liveness_request(
+    storage: Storage,
+    request: request::Query,
+    _params: Vec<(String, String)>,
+) -> response::Query {
+    let snapshot = storage.latest_snapshot();
+    let height = match snapshot.get_block_height().await {
+        Ok(height) => height,
+        Err(err) => return internal_error(format!("failed to get block height: {err:#}")),
+    };
+    let parameters = match snapshot.get_oracle_liveness_parameters().await {
+        Ok(params) => params.map(ParametersInner::from),
+        Err(err) => {
+            return internal_error(format!("failed to get oracle liveness parameters: {err:#}"));
+        }
+    };
+    let blocks_recorded = match snapshot.get_oracle_liveness_blocks_recorded().await {
+        Ok(blocks_recorded) => blocks_recorded,
+        Err(err) => {
+            return internal_error(format!(
+                "failed to get number of blocks with recorded oracle liveness: {err:#}"
+            ));
+        }
+    };
+    let validators = match validators_inner(&snapshot).await {
+        Ok(validators) => validators,
+        Err(err) => {
+            return internal_error(format!(
+                "failed to construct validator oracle liveness response: {err:#}"
+            ));
+        }
+    };
+
+    let payload = LivenessResponse {
+        height,
+        parameters,
+        blocks_recorded,
+        validators,
+    };
+    let height = tendermint::block::Height::try_from(height).expect("height must fit into an i64");
+    response::Query {
+        code: 0.into(),
+        key: request.path.clone().into_bytes().into(),
+        value: serde_json::to_vec(&payload)
+            .expect("object does not contain keys that don't map to json keys")
+            .into(),
+        height,
+        ..response::Query::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use astria_core::protocol::transaction::v1::action::ValidatorUpdate;
+    use cnidarium::StateDelta;
+
+    use super::*;
+    use crate::{
+        address::StateWriteExt as _,
+        app::StateWriteExt as _,
+        authority::StateWriteExt as _,
+        benchmark_and_test_utils::{
+            astria_address,
+            verification_key,
+            ASTRIA_PREFIX,
+        },
+        oracles::price_feed::liveness::{
+            StateWriteExt as _,
+            ValidatorLiveness,
+        },
+    };
+
+    fn request() -> request::Query {
+        request::Query {
+            data: vec![].into(),
+            path: "oracles/liveness".to_string(),
+            height: 0u32.into(),
+            prove: false,
+        }
+    }
+
+    fn ok_json(response: &response::Query) -> serde_json::Value {
+        assert!(response.code.is_ok(), "{}", response.log);
+        serde_json::from_slice(&response.value).unwrap()
+    }
+
+    #[tokio::test]
+    async fn liveness_request_ok() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let mut state = StateDelta::new(storage.latest_snapshot());
+        state.put_base_prefix(ASTRIA_PREFIX.to_string()).unwrap();
+        state.put_block_height(1).unwrap();
+        let validator = ValidatorUpdate {
+            name: "test".parse().unwrap(),
+            power: 10,
+            verification_key: verification_key(1),
+        };
+        state.put_validator(&validator).unwrap();
+        storage.commit(state).await.unwrap();
+
+        let address = astria_address(validator.verification_key.address_bytes()).to_string();
+        assert_eq!(
+            ok_json(&liveness_request(Storage::clone(&storage), request(), vec![]).await),
+            serde_json::json!({
+                "height": 1,
+                "parameters": null,
+                "blocks_recorded": 0,
+                "validators": [{
+                    "address": address,
+                    "name": "test",
+                    "blocks_tracked": 0,
+                    "blocks_missed": 0,
+                    "participation_basis_points": 10_000,
+                }],
+            })
+        );
+
+        let mut state = StateDelta::new(storage.latest_snapshot());
+        state
+            .put_oracle_liveness_parameters(&OracleLivenessParameters {
+                window_blocks: 4,
+                min_participation_basis_points: 5_000,
+                remove_inactive_validators: false,
+            })
+            .unwrap();
+        state.put_oracle_liveness_blocks_recorded(4).unwrap();
+        state
+            .put_validator_oracle_liveness(
+                validator.verification_key.address_bytes(),
+                &ValidatorLiveness {
+                    last_recorded_block: 3,
+                    blocks_tracked: 4,
+                    blocks_missed: 1,
+                    missed_bitmap: vec![0b0000_0010],
+                },
+            )
+            .unwrap();
+        storage.commit(state).await.unwrap();
+
+        assert_eq!(
+            ok_json(&liveness_request(Storage::clone(&storage), request(), vec![]).await),
+            serde_json::json!({
+                "height": 1,
+                "parameters": {
+                    "window_blocks": 4,
+                    "min_participation_basis_points": 5_000,
+                    "remove_inactive_validators": false,
+                },
+                "blocks_recorded": 4,
+                "validators": [{
+                    "address": address,
+                    "name": "test",
+                    "blocks_tracked": 4,
+                    "blocks_missed": 1,
+                    "participation_basis_points": 7_500,
+                }],
+            })
+        );
+    }
+}
@@ -0,0 +1,258 @@
+use astria_core::{
+    primitive::v1::ADDRESS_LEN,
+    protocol::genesis::v1::OracleLivenessParameters,
+};
+use astria_eyre::{
+    anyhow_to_eyre,
+    eyre::{
+        Result,
+        WrapErr as _,
+    },
+};
+use async_trait::async_trait;
+use cnidarium::{
+    StateRead,
+    StateWrite,
+};
+use tracing::{
+    instrument,
+    Level,
+};
+
+use super::{
+    storage::{
+        self,
+        keys,
+    },
+    ValidatorLiveness,
+    WindowRemovals,
+};
+use crate::storage::StoredValue;
+
+#[async_trait]
+pub(crate) trait StateReadExt: StateRead {
+    /// Returns the oracle liveness parameters, or `None` if liveness tracking was not configured
+    /// at genesis.
+    #[instrument(skip_all, err(level = Level::WARN))]
+    async fn get_oracle_liveness_parameters(&self) -> Result<Option<OracleLivenessParameters>> {
+        let Some(bytes) = self
+            .get_raw(keys::PARAMETERS)
+            .await
+            .map_err(anyhow_to_eyre)
+            .wrap_err("failed reading raw oracle liveness parameters from state")?
+        else {
+            return Ok(None);
+        };
+        StoredValue::deserialize(&bytes)
+            .and_then(|value| {
+                storage::Parameters::try_from(value)
+                    .map(|params| Some(OracleLivenessParameters::from(params)))
+            })
+            .wrap_err("invalid oracle liveness parameters bytes")
+    }
+
+    /// Returns the number of blocks in which oracle participation was recorded so far.
+    #[instrument(skip_all, err(level = Level::WARN))]
+    async fn get_oracle_liveness_blocks_recorded(&self) -> Result<u64> {
+        let Some(bytes) = self
+            .get_raw(keys::BLOCKS_RECORDED)
+            .await
+            .map_err(anyhow_to_eyre)
+            .wrap_err("failed reading raw oracle liveness blocks recorded from state")?
+        else {
+            return Ok(0);
+        };
+        StoredValue::deserialize(&bytes)
+            .and_then(|value| storage::BlocksRecorded::try_from(value).map(u64::from))
+            .wrap_err("invalid oracle liveness blocks recorded bytes")
+    }
+
+    /// Returns the oracle participation of the validator with `address`, or `None` if none was
+    /// recorded yet.
+    #[instrument(skip_all, fields(address = %hex::encode(address)), err(level = Level::WARN))]
+    async fn get_validator_oracle_liveness(
+        &self,
+        address: &[u8; ADDRESS_LEN],
+    ) -> Result<Option<ValidatorLiveness>> {
+        let Some(bytes) = self
+            .get_raw(&keys::validator(address))
+            .await
+            .map_err(anyhow_to_eyre)
+            .wrap_err("failed reading raw validator oracle liveness from state")?
+        else {
+            return Ok(None);
+        };
+        StoredValue::deserialize(&bytes)
+            .and_then(|value| {
+                storage::ValidatorLiveness::try_from(value)
+                    .map(|liveness| Some(ValidatorLiveness::from(liveness)))
+            })
+            .wrap_err("invalid validator oracle liveness bytes")
+    }
+
+    /// Returns the voting power removed for low oracle participation in the current removal
+    /// window, or `None` if no validator was removed yet.
+    #[instrument(skip_all, err(level = Level::WARN))]
+    async fn get_oracle_liveness_window_removals(&self) -> Result<Option<WindowRemovals>> {
+        let Some(bytes) = self
+            .get_raw(keys::WINDOW_REMOVALS)
+            .await
+            .map_err(anyhow_to_eyre)
+            .wrap_err("failed reading raw oracle liveness window removals from state")?
+        else {
+            return Ok(None);
+        };
+        StoredValue::deserialize(&bytes)
+            .and_then(|value| {
+                storage::WindowRemovals::try_from(value)
+                    .map(|removals| Some(WindowRemovals::from(removals)))
+            })
+            .wrap_err("invalid oracle liveness window removals bytes")
+    }
+}
+
+impl<T: StateRead + ?Sized> StateReadExt for T {}
+
+#[async_trait]
+pub(crate) trait StateWriteExt: StateWrite {
+    #[instrument(skip_all)]
+    fn put_oracle_liveness_parameters(&mut self, params: &OracleLivenessParameters) -> Result<()> {
+        let bytes = StoredValue::from(storage::Parameters::from(params))
+            .serialize()
+            .wrap_err("failed to serialize oracle liveness parameters")?;
+        self.put_raw(keys::PARAMETERS.to_string(), bytes);
+        Ok(())
+    }
+
+    #[instrument(skip_all)]
+    fn put_oracle_liveness_blocks_recorded(&mut self, count: u64) -> Result<()> {
+        let bytes = StoredValue::from(storage::BlocksRecorded::from(count))
+            .serialize()
+            .wrap_err("failed to serialize oracle liveness blocks recorded")?;
+        self.put_raw(keys::BLOCKS_RECORDED.to_string(), bytes);
+        Ok(())
+    }
+
+    #[instrument(skip_all)]
+    fn put_oracle_liveness_window_removals(&mut self, removals: WindowRemovals) -> Result<()> {
+        let bytes = StoredValue::from(storage::WindowRemovals::from(removals))
+            .serialize()
+            .wrap_err("failed to serialize oracle liveness window removals")?;
+        self.put_raw(keys::WINDOW_REMOVALS.to_string(), bytes);
+        Ok(())
+    }
+
+    #[instrument(skip_all, fields(address = %hex::encode(address)))]
+    fn put_validator_oracle_liveness(
+        &mut self,
+        address: &[u8; ADDRESS_LEN],
+        liveness: &ValidatorLiveness,
+    ) -> Result<()> {
+        let bytes = StoredValue::from(storage::ValidatorLiveness::from(liveness))
+            .serialize()
+            .wrap_err("failed to serialize validator oracle liveness")?;
+        self.put_raw(keys::validator(address), bytes);
+        Ok(())
+    }
+
+    #[instrument(skip_all, fields(address = %hex::encode(address)))]
+    fn delete_validator_oracle_liveness(&mut self, address: &[u8; ADDRESS_LEN]) {
+        self.delete(keys::validator(address));
+    }
+}
+
+impl<T: StateWrite> StateWriteExt for T {}
+
+#[cfg(test)]
+mod tests {
+    use cnidarium::StateDelta;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn oracle_liveness_parameters_uninitialized_ok() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let state = StateDelta::new(snapshot);
+
+        assert_eq!(state.get_oracle_liveness_parameters().await.unwrap(), None);
+        assert_eq!(
+            state.get_oracle_liveness_blocks_recorded().await.unwrap(),
+            0
+        );
+        assert_eq!(
+            state.get_validator_oracle_liveness(&[1; 20]).await.unwrap(),
+            None
+        );
+        assert_eq!(
+            state.get_oracle_liveness_window_removals().await.unwrap(),
+            None
+        );
+    }
+
+    #[tokio::test]
+    async fn put_oracle_liveness_parameters() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = StateDelta::new(snapshot);
+
+        let params = OracleLivenessParameters {
+            window_blocks: 100,
+            min_participation_basis_points: 5_000,
+            remove_inactive_validators: true,
+        };
+        state.put_oracle_liveness_parameters(&params).unwrap();
+        assert_eq!(
+            state.get_oracle_liveness_parameters().await.unwrap(),
+            Some(params)
+        );
+
+        state.put_oracle_liveness_blocks_recorded(42).unwrap();
+        assert_eq!(
+            state.get_oracle_liveness_blocks_recorded().await.unwrap(),
+            42
+        );
+
+        let removals = WindowRemovals {
+            window_start_block: 8,
+            total_power: 40,
+            removed_power: 10,
+        };
+        state.put_oracle_liveness_window_removals(removals).unwrap();
+        assert_eq!(
+            state.get_oracle_liveness_window_removals().await.unwrap(),
+            Some(removals)
+        );
+    }
+
+    #[tokio::test]
+    async fn put_and_delete_validator_oracle_liveness() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = StateDelta::new(snapshot);
+
+        let liveness = ValidatorLiveness {
+            last_recorded_block: 3,
+            blocks_tracked: 4,
+            blocks_missed: 1,
+            missed_bitmap: vec![0b0000_0100],
+        };
+        state
+            .put_validator_oracle_liveness(&[1; 20], &liveness)
+            .unwrap();
+        assert_eq!(
+            state.get_validator_oracle_liveness(&[1; 20]).await.unwrap(),
+            Some(liveness)
+        );
+        assert_eq!(
+            state.get_validator_oracle_liveness(&[2; 20]).await.unwrap(),
+            None
+        );
+
+        state.delete_validator_oracle_liveness(&[1; 20]);
+        assert_eq!(
+            state.get_validator_oracle_liveness(&[1; 20]).await.unwrap(),
+            None
+        );
+    }
+}
@@ -0,0 +1,42 @@
+use astria_core::primitive::v1::ADDRESS_LEN;
+
+pub(in crate::oracles::price_feed::liveness) const PARAMETERS: &str =
+    "price_feed/liveness/parameters";
+pub(in crate::oracles::price_feed::liveness) const BLOCKS_RECORDED: &str =
+    "price_feed/liveness/blocks_recorded";
+pub(in crate::oracles::price_feed::liveness) const WINDOW_REMOVALS: &str =
+    "price_feed/liveness/window_removals";
+pub(in crate::oracles::price_feed::liveness) const VALIDATOR_PREFIX: &str =
+    "price_feed/liveness/validator/";
+
+pub(in crate::oracles::price_feed::liveness) fn validator(address: &[u8; ADDRESS_LEN]) -> String {
+    format!("{VALIDATOR_PREFIX}{}", hex::encode(address))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const COMPONENT_PREFIX: &str = "price_feed/liveness/";
+
+    #[test]
+    fn keys_should_not_change() {
+        insta::assert_snapshot!("parameters_key", PARAMETERS);
+        insta::assert_snapshot!("blocks_recorded_key", BLOCKS_RECORDED);
+        insta::assert_snapshot!("window_removals_key", WINDOW_REMOVALS);
+        insta::assert_snapshot!("validator_key", validator(&[1; ADDRESS_LEN]));
+    }
+
+    #[test]
+    fn keys_should_have_component_prefix() {
+        assert!(PARAMETERS.starts_with(COMPONENT_PREFIX));
+        assert!(BLOCKS_RECORDED.starts_with(COMPONENT_PREFIX));
+        assert!(WINDOW_REMOVALS.starts_with(COMPONENT_PREFIX));
+        assert!(validator(&[1; ADDRESS_LEN]).starts_with(COMPONENT_PREFIX));
+    }
+
+    #[test]
+    fn prefixes_should_be_prefixes_of_relevant_keys() {
+        assert!(validator(&[1; ADDRESS_LEN]).starts_with(VALIDATOR_PREFIX));
+    }
+}
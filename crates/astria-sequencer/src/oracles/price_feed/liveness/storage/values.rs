@@ -0,0 +1,269 @@
+use std::borrow::Cow;
+
+use astria_core::protocol::genesis::v1::OracleLivenessParameters as DomainParameters;
+use astria_eyre::eyre::bail;
+use borsh::{
+    BorshDeserialize,
+    BorshSerialize,
+};
+
+use crate::oracles::price_feed::liveness::{
+    ValidatorLiveness as DomainValidatorLiveness,
+    WindowRemovals as DomainWindowRemovals,
+};
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub(crate) struct Value<'a>(ValueImpl<'a>);
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+enum ValueImpl<'a> {
+    Parameters(Parameters),
+    BlocksRecorded(BlocksRecorded),
+    ValidatorLiveness(ValidatorLiveness<'a>),
+    WindowRemovals(WindowRemovals),
+}
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub(in crate::oracles::price_feed::liveness) struct Parameters {
+    window_blocks: u64,
+    min_participation_basis_points: u32,
+    remove_inactive_validators: bool,
+}
+
+impl From<&DomainParameters> for Parameters {
+    fn from(params: &DomainParameters) -> Self {
+        Parameters {
+            window_blocks: params.window_blocks,
+            min_participation_basis_points: params.min_participation_basis_points,
+            remove_inactive_validators: params.remove_inactive_validators,
+        }
+    }
+}
+
+impl From<Parameters> for DomainParameters {
+    fn from(params: Parameters) -> Self {
+        DomainParameters {
+            window_blocks: params.window_blocks,
+            min_participation_basis_points: params.min_participation_basis_points,
+            remove_inactive_validators: params.remove_inactive_validators,
+        }
+    }
+}
+
+impl From<Parameters> for crate::storage::StoredValue<'_> {
+    fn from(params: Parameters) -> Self {
+        crate::storage::StoredValue::PriceFeedLiveness(Value(ValueImpl::Parameters(params)))
+    }
+}
+
+impl<'a> TryFrom<crate::storage::StoredValue<'a>> for Parameters {
+    type Error = astria_eyre::eyre::Error;
+
+    fn try_from(value: crate::storage::StoredValue<'a>) -> Result<Self, Self::Error> {
+        let crate::storage::StoredValue::PriceFeedLiveness(Value(ValueImpl::Parameters(params))) =
+            value
+        else {
+            bail!(
+                "price feed liveness stored value type mismatch: expected parameters, found \
+                 {value:?}"
+            );
+        };
+        Ok(params)
+    }
+}
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub(in crate::oracles::price_feed::liveness) struct BlocksRecorded(u64);
+
+impl From<u64> for BlocksRecorded {
+    fn from(count: u64) -> Self {
+        BlocksRecorded(count)
+    }
+}
+
+impl From<BlocksRecorded> for u64 {
+    fn from(count: BlocksRecorded) -> Self {
+        count.0
+    }
+}
+
+impl From<BlocksRecorded> for crate::storage::StoredValue<'_> {
+    fn from(count: BlocksRecorded) -> Self {
+        crate::storage::StoredValue::PriceFeedLiveness(Value(ValueImpl::BlocksRecorded(count)))
+    }
+}
+
+impl<'a> TryFrom<crate::storage::StoredValue<'a>> for BlocksRecorded {
+    type Error = astria_eyre::eyre::Error;
+
+    fn try_from(value: crate::storage::StoredValue<'a>) -> Result<Self, Self::Error> {
+        let crate::storage::StoredValue::PriceFeedLiveness(Value(ValueImpl::BlocksRecorded(count))) =
+            value
+        else {
+            bail!(
+                "price feed liveness stored value type mismatch: expected blocks recorded, found \
+                 {value:?}"
+            );
+        };
+        Ok(count)
+    }
+}
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub(in crate::oracles::price_feed::liveness) struct ValidatorLiveness<'a> {
+    last_recorded_block: u64,
+    blocks_tracked: u64,
+    blocks_missed: u64,
+    missed_bitmap: Cow<'a, [u8]>,
+}
+
+impl<'a> From<&'a DomainValidatorLiveness> for ValidatorLiveness<'a> {
+    fn from(liveness: &'a DomainValidatorLiveness) -> Self {
+        ValidatorLiveness {
+            last_recorded_block: liveness.last_recorded_block,
+            blocks_tracked: liveness.blocks_tracked,
+            blocks_missed: liveness.blocks_missed,
+            missed_bitmap: Cow::Borrowed(&liveness.missed_bitmap),
+        }
+    }
+}
+
+impl<'a> From<ValidatorLiveness<'a>> for DomainValidatorLiveness {
+    fn from(liveness: ValidatorLiveness<'a>) -> Self {
+        DomainValidatorLiveness {
+            last_recorded_block: liveness.last_recorded_block,
+            blocks_tracked: liveness.blocks_tracked,
+            blocks_missed: liveness.blocks_missed,
+            missed_bitmap: liveness.missed_bitmap.into_owned(),
+        }
+    }
+}
+
+impl<'a> From<ValidatorLiveness<'a>> for crate::storage::StoredValue<'a> {
+    fn from(liveness: ValidatorLiveness<'a>) -> Self {
+        crate::storage::StoredValue::PriceFeedLiveness(Value(ValueImpl::ValidatorLiveness(
+            liveness,
+        )))
+    }
+}
+
+impl<'a> TryFrom<crate::storage::StoredValue<'a>> for ValidatorLiveness<'a> {
+    type Error = astria_eyre::eyre::Error;
+
+    fn try_from(value: crate::storage::StoredValue<'a>) -> Result<Self, Self::Error> {
+        let crate::storage::StoredValue::PriceFeedLiveness(Value(ValueImpl::ValidatorLiveness(
+            liveness,
+        ))) = value
+        else {
+            bail!(
+                "price feed liveness stored value type mismatch: expected validator liveness, \
+                 found {value:?}"
+            );
+        };
+        Ok(liveness)
+    }
+}
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub(in crate::oracles::price_feed::liveness) struct WindowRemovals {
+    window_start_block: u64,
+    total_power: u64,
+    removed_power: u64,
+}
+
+impl From<DomainWindowRemovals> for WindowRemovals {
+    fn from(removals: DomainWindowRemovals) -> Self {
+        WindowRemovals {
+            window_start_block: removals.window_start_block,
+            total_power: removals.total_power,
+            removed_power: removals.removed_power,
+        }
+    }
+}
+
+impl From<WindowRemovals> for DomainWindowRemovals {
+    fn from(removals: WindowRemovals) -> Self {
+        DomainWindowRemovals {
+            window_start_block: removals.window_start_block,
+            total_power: removals.total_power,
+            removed_power: removals.removed_power,
+        }
+    }
+}
+
+impl From<WindowRemovals> for crate::storage::StoredValue<'_> {
+    fn from(removals: WindowRemovals) -> Self {
+        crate::storage::StoredValue::PriceFeedLiveness(Value(ValueImpl::WindowRemovals(removals)))
+    }
+}
+
+impl<'a> TryFrom<crate::storage::StoredValue<'a>> for WindowRemovals {
+    type Error = astria_eyre::eyre::Error;
+
+    fn try_from(value: crate::storage::StoredValue<'a>) -> Result<Self, Self::Error> {
+        let crate::storage::StoredValue::PriceFeedLiveness(Value(ValueImpl::WindowRemovals(
+            removals,
+        ))) = value
+        else {
+            bail!(
+                "price feed liveness stored value type mismatch: expected window removals, found \
+                 {value:?}"
+            );
+        };
+        Ok(removals)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use insta::assert_snapshot;
+
+    use super::*;
+    use crate::test_utils::borsh_then_hex;
+
+    #[test]
+    fn value_impl_existing_variants_unchanged() {
+        assert_snapshot!(
+            "value_impl_parameters",
+            borsh_then_hex(&ValueImpl::Parameters(Parameters {
+                window_blocks: 1_000,
+                min_participation_basis_points: 5_000,
+                remove_inactive_validators: true,
+            }))
+        );
+        assert_snapshot!(
+            "value_impl_blocks_recorded",
+            borsh_then_hex(&ValueImpl::BlocksRecorded(BlocksRecorded(1)))
+        );
+        assert_snapshot!(
+            "value_impl_validator_liveness",
+            borsh_then_hex(&ValueImpl::ValidatorLiveness(ValidatorLiveness {
+                last_recorded_block: 10,
+                blocks_tracked: 11,
+                blocks_missed: 2,
+                missed_bitmap: Cow::Owned(vec![0b0000_0101, 0]),
+            }))
+        );
+        assert_snapshot!(
+            "value_impl_window_removals",
+            borsh_then_hex(&ValueImpl::WindowRemovals(WindowRemovals {
+                window_start_block: 8,
+                total_power: 40,
+                removed_power: 10,
+            }))
+        );
+    }
+
+    // Note: This test must be here instead of in `crate::storage` since `ValueImpl` is not
+    // re-exported.
+    #[test]
+    fn stored_value_price_feed_liveness_variant_unchanged() {
+        use crate::storage::StoredValue;
+        assert_snapshot!(
+            "stored_value_price_feed_liveness_variant",
+            borsh_then_hex(&StoredValue::PriceFeedLiveness(Value(
+                ValueImpl::BlocksRecorded(BlocksRecorded(1))
+            )))
+        );
+    }
+}
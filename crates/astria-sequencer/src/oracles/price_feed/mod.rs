@@ -1,2 +1,3 @@
+pub(crate) mod liveness;
 pub(crate) mod market_map;
 pub(crate) mod oracle;
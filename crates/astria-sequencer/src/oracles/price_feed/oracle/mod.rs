@@ -2,15 +2,43 @@ pub(crate) mod currency_pair_strategy;
 pub(crate) mod state_ext;
 pub(crate) mod storage;
 
-use astria_core::oracles::price_feed::oracle::v2::{
-    CurrencyPairState,
-    GenesisState,
+use astria_core::{
+    oracles::price_feed::{
+        oracle::v2::{
+            CurrencyPairState,
+            GenesisState,
+            QuotePrice,
+        },
+        types::v2::{
+            CurrencyPair,
+            Price,
+        },
+    },
+    Timestamp,
 };
 use astria_eyre::eyre::{
     Result,
     WrapErr as _,
 };
-use state_ext::StateWriteExt;
+use state_ext::{
+    StateReadExt as _,
+    StateWriteExt,
+};
+use tracing::warn;
+
+const NANOS_PER_SECOND: i128 = 1_000_000_000;
+const BASIS_POINTS_PER_UNIT: u128 = 10_000;
+
+/// The outcome of [`apply_price`].
+#[derive(Debug, PartialEq)]
+pub(crate) enum PriceUpdate {
+    Accepted,
+    /// The new price deviated from the latest non-stale price by more than the currency pair's
+    /// `max_deviation_basis_points`.
+    Rejected {
+        previous: Price,
+    },
+}
 
 pub(crate) fn handle_genesis<S: StateWriteExt>(
     mut state: S,
@@ -34,3 +62,357 @@ pub(crate) fn handle_genesis<S: StateWriteExt>(
         .put_num_currency_pairs(oracle_genesis.currency_pair_genesis.len() as u64)
         .wrap_err("failed to put number of currency pairs")
 }
+
+/// Writes `quote_price` as the latest price of `currency_pair`, subject to the pair's
+/// parameters.
+///
+/// If the latest price of the pair is not stale, a new price deviating from it by more than
+/// `max_deviation_basis_points` is rejected and leaves the state unchanged.
+///
+/// If `twap_window_seconds` is set, the rolling time-weighted average price (TWAP) is moved
+/// towards the price that was in effect since the previous update, weighted by the fraction of
+/// the window for which it was in effect. The average restarts at the new price if there is no
+/// previous non-stale price.
+pub(crate) async fn apply_price<S: StateWriteExt>(
+    state: &mut S,
+    currency_pair: CurrencyPair,
+    quote_price: QuotePrice,
+) -> Result<PriceUpdate> {
+    let Some(parameters) = state
+        .get_currency_pair_parameters(&currency_pair)
+        .await
+        .wrap_err("failed to get currency pair parameters")?
+    else {
+        state
+            .put_price_for_currency_pair(currency_pair, quote_price)
+            .await
+            .wrap_err("failed to put price")?;
+        return Ok(PriceUpdate::Accepted);
+    };
+
+    let previous = state
+        .get_currency_pair_state(&currency_pair)
+        .await
+        .wrap_err("failed to get currency pair state")?
+        .and_then(|currency_pair_state| currency_pair_state.price)
+        .filter(|previous| {
+            !is_stale(
+                previous,
+                &quote_price.block_timestamp,
+                parameters.max_age_seconds,
+            )
+        });
+
+    if let Some(previous) = &previous {
+        if parameters.max_deviation_basis_points != 0
+            && exceeds_deviation(
+                previous.price,
+                quote_price.price,
+                parameters.max_deviation_basis_points,
+            )
+        {
+            return Ok(PriceUpdate::Rejected {
+                previous: previous.price,
+            });
+        }
+    }
+
+    if parameters.twap_window_seconds != 0 {
+        let twap = state
+            .get_currency_pair_twap(&currency_pair)
+            .await
+            .wrap_err("failed to get currency pair twap")?;
+        let new_twap = match (previous, twap) {
+            (Some(previous), Some(twap)) => update_twap(
+                twap,
+                &previous,
+                &quote_price.block_timestamp,
+                parameters.twap_window_seconds,
+            )
+            .unwrap_or_else(|| {
+                warn!(
+                    %currency_pair,
+                    "overflow updating time-weighted average price; restarting it at the new price"
+                );
+                quote_price.price
+            }),
+            _ => quote_price.price,
+        };
+        state
+            .put_currency_pair_twap(&currency_pair, new_twap)
+            .wrap_err("failed to put currency pair twap")?;
+    }
+
+    state
+        .put_price_for_currency_pair(currency_pair, quote_price)
+        .await
+        .wrap_err("failed to put price")?;
+    Ok(PriceUpdate::Accepted)
+}
+
+/// Returns whether `price` is older than `max_age_seconds` at `now`.
+///
+/// Prices never go stale if `max_age_seconds` is zero.
+pub(crate) fn is_stale(price: &QuotePrice, now: &Timestamp, max_age_seconds: u64) -> bool {
+    max_age_seconds != 0
+        && nanos_between(&price.block_timestamp, now)
+            > i128::from(max_age_seconds).saturating_mul(NANOS_PER_SECOND)
+}
+
+fn nanos_between(earlier: &Timestamp, later: &Timestamp) -> i128 {
+    i128::from(later.seconds)
+        .saturating_sub(i128::from(earlier.seconds))
+        .saturating_mul(NANOS_PER_SECOND)
+        .saturating_add(i128::from(later.nanos))
+        .saturating_sub(i128::from(earlier.nanos))
+}
+
+fn exceeds_deviation(previous: Price, new: Price, max_deviation_basis_points: u32) -> bool {
+    let change = previous.get().abs_diff(new.get());
+    change.saturating_mul(BASIS_POINTS_PER_UNIT)
+        > previous
+            .get()
+            .unsigned_abs()
+            .saturating_mul(u128::from(max_deviation_basis_points))
+}
+
+/// Moves `twap` towards the price of `previous` by the fraction of the window for which
+/// `previous` was in effect until `now`.
+///
+/// Returns `None` on overflow.
+fn update_twap(
+    twap: Price,
+    previous: &QuotePrice,
+    now: &Timestamp,
+    twap_window_seconds: u64,
+) -> Option<Price> {
+    let window = i128::from(twap_window_seconds).checked_mul(NANOS_PER_SECOND)?;
+    let held_for = nanos_between(&previous.block_timestamp, now).clamp(0, window);
+    let difference = previous.price.get().checked_sub(twap.get())?;
+    // Equivalent to `difference * held_for / window`, but without the intermediate product
+    // overflowing for large prices.
+    let whole = difference.checked_div(window)?.checked_mul(held_for)?;
+    let remainder = difference
+        .checked_rem(window)?
+        .checked_mul(held_for)?
+        .checked_div(window)?;
+    twap.get()
+        .checked_add(whole)?
+        .checked_add(remainder)
+        .map(Price::new)
+}
+
+#[cfg(test)]
+mod tests {
+    use astria_core::{
+        oracles::price_feed::types::v2::{
+            CurrencyPairId,
+            CurrencyPairNonce,
+        },
+        protocol::transaction::v1::action::CurrencyPairParameters,
+    };
+    use cnidarium::StateDelta;
+
+    use super::*;
+
+    fn eth_usd() -> CurrencyPair {
+        "ETH/USD".parse().unwrap()
+    }
+
+    fn quote_price(price: i128, seconds: i64) -> QuotePrice {
+        QuotePrice {
+            price: Price::new(price),
+            block_timestamp: Timestamp {
+                seconds,
+                nanos: 0,
+            },
+            block_height: 1,
+        }
+    }
+
+    fn state_with_eth_usd<S: StateWriteExt>(state: &mut S, parameters: Option<(u64, u32, u64)>) {
+        state
+            .put_currency_pair_state(
+                eth_usd(),
+                CurrencyPairState {
+                    price: None,
+                    nonce: CurrencyPairNonce::new(0),
+                    id: CurrencyPairId::new(0),
+                },
+            )
+            .unwrap();
+        if let Some((max_age_seconds, max_deviation_basis_points, twap_window_seconds)) = parameters
+        {
+            state
+                .put_currency_pair_parameters(&CurrencyPairParameters {
+                    currency_pair: eth_usd(),
+                    max_age_seconds,
+                    max_deviation_basis_points,
+                    twap_window_seconds,
+                })
+                .unwrap();
+        }
+    }
+
+    async fn latest_price<S: StateWriteExt>(state: &S) -> Price {
+        state
+            .get_currency_pair_state(&eth_usd())
+            .await
+            .unwrap()
+            .unwrap()
+            .price
+            .unwrap()
+            .price
+    }
+
+    #[tokio::test]
+    async fn prices_are_unbounded_without_parameters() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let mut state = StateDelta::new(storage.latest_snapshot());
+        state_with_eth_usd(&mut state, None);
+
+        for (price, seconds) in [(100, 1), (1_000_000, 2), (1, 3)] {
+            assert_eq!(
+                apply_price(&mut state, eth_usd(), quote_price(price, seconds))
+                    .await
+                    .unwrap(),
+                PriceUpdate::Accepted,
+            );
+            assert_eq!(latest_price(&state).await, Price::new(price));
+        }
+        assert!(state
+            .get_currency_pair_twap(&eth_usd())
+            .await
+            .unwrap()
+            .is_none());
+    }
+
+    #[tokio::test]
+    async fn outliers_are_rejected_until_latest_price_is_stale() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let mut state = StateDelta::new(storage.latest_snapshot());
+        // max age of 10 seconds, max deviation of 10%
+        state_with_eth_usd(&mut state, Some((10, 1_000, 0)));
+
+        // the first price is accepted regardless of its value
+        assert_eq!(
+            apply_price(&mut state, eth_usd(), quote_price(100, 0))
+                .await
+                .unwrap(),
+            PriceUpdate::Accepted,
+        );
+        // deviations within the bound are accepted
+        assert_eq!(
+            apply_price(&mut state, eth_usd(), quote_price(110, 1))
+                .await
+                .unwrap(),
+            PriceUpdate::Accepted,
+        );
+        assert_eq!(
+            apply_price(&mut state, eth_usd(), quote_price(99, 2))
+                .await
+                .unwrap(),
+            PriceUpdate::Accepted,
+        );
+        // outliers are rejected and leave the latest price unchanged
+        assert_eq!(
+            apply_price(&mut state, eth_usd(), quote_price(200, 3))
+                .await
+                .unwrap(),
+            PriceUpdate::Rejected {
+                previous: Price::new(99)
+            },
+        );
+        assert_eq!(latest_price(&state).await, Price::new(99));
+        // once the latest price is stale, any price is accepted
+        assert_eq!(
+            apply_price(&mut state, eth_usd(), quote_price(200, 13))
+                .await
+                .unwrap(),
+            PriceUpdate::Accepted,
+        );
+        assert_eq!(latest_price(&state).await, Price::new(200));
+    }
+
+    #[tokio::test]
+    async fn twap_weights_prices_by_time_in_effect() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let mut state = StateDelta::new(storage.latest_snapshot());
+        // max age of 100 seconds, no deviation bound, twap window of 10 seconds
+        state_with_eth_usd(&mut state, Some((100, 0, 10)));
+
+        apply_price(&mut state, eth_usd(), quote_price(100, 0))
+            .await
+            .unwrap();
+        assert_eq!(
+            state.get_currency_pair_twap(&eth_usd()).await.unwrap(),
+            Some(Price::new(100)),
+        );
+
+        // 100 was in effect for 2 of 10 seconds, but the average already equals it
+        apply_price(&mut state, eth_usd(), quote_price(200, 2))
+            .await
+            .unwrap();
+        assert_eq!(
+            state.get_currency_pair_twap(&eth_usd()).await.unwrap(),
+            Some(Price::new(100)),
+        );
+
+        // 200 was in effect for 5 of 10 seconds, moving the average halfway towards it
+        apply_price(&mut state, eth_usd(), quote_price(300, 7))
+            .await
+            .unwrap();
+        assert_eq!(
+            state.get_currency_pair_twap(&eth_usd()).await.unwrap(),
+            Some(Price::new(150)),
+        );
+
+        // 300 was in effect for longer than the window, so the average catches up with it
+        apply_price(&mut state, eth_usd(), quote_price(400, 50))
+            .await
+            .unwrap();
+        assert_eq!(
+            state.get_currency_pair_twap(&eth_usd()).await.unwrap(),
+            Some(Price::new(300)),
+        );
+
+        // the previous price is stale, so the average restarts at the new price
+        apply_price(&mut state, eth_usd(), quote_price(500, 200))
+            .await
+            .unwrap();
+        assert_eq!(
+            state.get_currency_pair_twap(&eth_usd()).await.unwrap(),
+            Some(Price::new(500)),
+        );
+    }
+
+    #[test]
+    fn deviation_is_relative_to_previous_price() {
+        assert!(!exceeds_deviation(Price::new(100), Price::new(105), 500));
+        assert!(!exceeds_deviation(Price::new(100), Price::new(95), 500));
+        assert!(exceeds_deviation(Price::new(100), Price::new(106), 500));
+        assert!(exceeds_deviation(Price::new(100), Price::new(94), 500));
+        assert!(exceeds_deviation(Price::new(0), Price::new(1), 500));
+    }
+
+    #[test]
+    fn update_twap_does_not_overflow_for_large_prices() {
+        let previous = QuotePrice {
+            price: Price::new(i128::MAX / 2),
+            block_timestamp: Timestamp {
+                seconds: 0,
+                nanos: 0,
+            },
+            block_height: 1,
+        };
+        let now = Timestamp {
+            seconds: 1,
+            nanos: 0,
+        };
+        assert_eq!(
+            update_twap(Price::new(0), &previous, &now, 2),
+            Some(Price::new(i128::MAX / 4)),
+        );
+        assert_eq!(update_twap(Price::new(i128::MIN), &previous, &now, 2), None,);
+    }
+}
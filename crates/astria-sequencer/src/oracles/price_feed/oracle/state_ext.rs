@@ -7,15 +7,19 @@ use std::{
     },
 };
 
-use astria_core::oracles::price_feed::{
-    oracle::v2::{
-        CurrencyPairState,
-        QuotePrice,
-    },
-    types::v2::{
-        CurrencyPair,
-        CurrencyPairId,
+use astria_core::{
+    oracles::price_feed::{
+        oracle::v2::{
+            CurrencyPairState,
+            QuotePrice,
+        },
+        types::v2::{
+            CurrencyPair,
+            CurrencyPairId,
+            Price,
+        },
     },
+    protocol::transaction::v1::action::CurrencyPairParameters,
 };
 use astria_eyre::{
     anyhow_to_eyre,
@@ -214,6 +218,44 @@ pub(crate) trait StateReadExt: StateRead {
             .wrap_err("invalid currency pair state bytes")
     }
 
+    #[instrument(skip_all)]
+    async fn get_currency_pair_parameters(
+        &self,
+        currency_pair: &CurrencyPair,
+    ) -> Result<Option<CurrencyPairParameters>> {
+        let Some(bytes) = self
+            .get_raw(&keys::currency_pair_parameters(currency_pair))
+            .await
+            .map_err(anyhow_to_eyre)
+            .wrap_err("failed reading currency pair parameters from state")?
+        else {
+            return Ok(None);
+        };
+        StoredValue::deserialize(&bytes)
+            .and_then(|value| {
+                storage::CurrencyPairParameters::try_from(value)
+                    .map(|parameters| Some(parameters.into_domain(currency_pair.clone())))
+            })
+            .wrap_err("invalid currency pair parameters bytes")
+    }
+
+    /// Returns the time-weighted average price of `currency_pair` as of its latest accepted
+    /// price, or `None` if no average is tracked for it.
+    #[instrument(skip_all)]
+    async fn get_currency_pair_twap(&self, currency_pair: &CurrencyPair) -> Result<Option<Price>> {
+        let Some(bytes) = self
+            .get_raw(&keys::currency_pair_twap(currency_pair))
+            .await
+            .map_err(anyhow_to_eyre)
+            .wrap_err("failed reading currency pair twap from state")?
+        else {
+            return Ok(None);
+        };
+        StoredValue::deserialize(&bytes)
+            .and_then(|value| storage::Twap::try_from(value).map(|twap| Some(Price::from(twap))))
+            .wrap_err("invalid currency pair twap bytes")
+    }
+
     #[instrument(skip_all)]
     async fn get_next_currency_pair_id(&self) -> Result<CurrencyPairId> {
         let Some(bytes) = self
@@ -270,6 +312,32 @@ pub(crate) trait StateWriteExt: StateWrite {
         Ok(())
     }
 
+    #[instrument(skip_all)]
+    fn put_currency_pair_parameters(&mut self, parameters: &CurrencyPairParameters) -> Result<()> {
+        let bytes = StoredValue::from(storage::CurrencyPairParameters::from(parameters))
+            .serialize()
+            .wrap_err("failed to serialize currency pair parameters")?;
+        self.put_raw(
+            keys::currency_pair_parameters(&parameters.currency_pair),
+            bytes,
+        );
+        Ok(())
+    }
+
+    #[instrument(skip_all)]
+    fn put_currency_pair_twap(&mut self, currency_pair: &CurrencyPair, twap: Price) -> Result<()> {
+        let bytes = StoredValue::from(storage::Twap::from(twap))
+            .serialize()
+            .wrap_err("failed to serialize currency pair twap")?;
+        self.put_raw(keys::currency_pair_twap(currency_pair), bytes);
+        Ok(())
+    }
+
+    #[instrument(skip_all)]
+    fn delete_currency_pair_twap(&mut self, currency_pair: &CurrencyPair) {
+        self.delete(keys::currency_pair_twap(currency_pair));
+    }
+
     #[instrument(skip_all)]
     async fn put_price_for_currency_pair(
         &mut self,
@@ -303,6 +371,8 @@ pub(crate) trait StateWriteExt: StateWrite {
         self.delete(keys::currency_pair_to_id(currency_pair));
         self.delete(keys::id_to_currency_pair(id));
         self.delete(keys::currency_pair_state(currency_pair));
+        self.delete(keys::currency_pair_parameters(currency_pair));
+        self.delete(keys::currency_pair_twap(currency_pair));
         Ok(true)
     }
 }
@@ -500,6 +570,93 @@ mod tests {
         assert!(collected.contains(&btc_usd()));
     }
 
+    #[tokio::test]
+    async fn should_put_and_get_currency_pair_parameters_and_twap() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = StateDelta::new(snapshot);
+
+        // Getting should return `None` when nothing is stored.
+        assert!(state
+            .get_currency_pair_parameters(&eth_usd())
+            .await
+            .unwrap()
+            .is_none());
+        assert!(state
+            .get_currency_pair_twap(&eth_usd())
+            .await
+            .unwrap()
+            .is_none());
+
+        let parameters = CurrencyPairParameters {
+            currency_pair: eth_usd(),
+            max_age_seconds: 60,
+            max_deviation_basis_points: 500,
+            twap_window_seconds: 600,
+        };
+        state.put_currency_pair_parameters(&parameters).unwrap();
+        state
+            .put_currency_pair_twap(&eth_usd(), Price::new(100))
+            .unwrap();
+        assert_eq!(
+            state
+                .get_currency_pair_parameters(&eth_usd())
+                .await
+                .unwrap(),
+            Some(parameters),
+        );
+        assert_eq!(
+            state.get_currency_pair_twap(&eth_usd()).await.unwrap(),
+            Some(Price::new(100)),
+        );
+        assert!(state
+            .get_currency_pair_parameters(&btc_usd())
+            .await
+            .unwrap()
+            .is_none());
+
+        state.delete_currency_pair_twap(&eth_usd());
+        assert!(state
+            .get_currency_pair_twap(&eth_usd())
+            .await
+            .unwrap()
+            .is_none());
+    }
+
+    #[tokio::test]
+    async fn removing_currency_pair_removes_parameters_and_twap() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = StateDelta::new(snapshot);
+
+        state
+            .put_currency_pair_state(eth_usd(), eth_usd_state(1))
+            .unwrap();
+        state
+            .put_currency_pair_parameters(&CurrencyPairParameters {
+                currency_pair: eth_usd(),
+                max_age_seconds: 60,
+                max_deviation_basis_points: 500,
+                twap_window_seconds: 600,
+            })
+            .unwrap();
+        state
+            .put_currency_pair_twap(&eth_usd(), Price::new(100))
+            .unwrap();
+
+        assert!(state.remove_currency_pair(&eth_usd()).await.unwrap());
+        assert!(state
+            .get_currency_pair_parameters(&eth_usd())
+            .await
+            .unwrap()
+            .is_none());
+        assert!(state
+            .get_currency_pair_twap(&eth_usd())
+            .await
+            .unwrap()
+            .is_none());
+    }
+
     #[tokio::test]
     async fn should_put_and_get_num_currency_pairs() {
         let storage = cnidarium::TempStorage::new().await.unwrap();
@@ -14,6 +14,8 @@ pub(in crate::oracles::price_feed::oracle) const CURRENCY_PAIR_TO_ID_PREFIX: &st
 const ID_TO_CURRENCY_PAIR_PREFIX: &str = "price_feed/oracle/id_to_currency_pair/";
 pub(in crate::oracles::price_feed::oracle) const CURRENCY_PAIR_STATE_PREFIX: &str =
     "price_feed/oracle/currency_pair_state/";
+const CURRENCY_PAIR_PARAMETERS_PREFIX: &str = "price_feed/oracle/currency_pair_parameters/";
+const CURRENCY_PAIR_TWAP_PREFIX: &str = "price_feed/oracle/currency_pair_twap/";
 
 pub(in crate::oracles::price_feed::oracle) const NUM_CURRENCY_PAIRS: &str =
     "price_feed/oracle/num_currency_pairs";
@@ -40,6 +42,18 @@ pub(in crate::oracles::price_feed::oracle) fn currency_pair_state(
     format!("{CURRENCY_PAIR_STATE_PREFIX}{currency_pair}")
 }
 
+pub(in crate::oracles::price_feed::oracle) fn currency_pair_parameters(
+    currency_pair: &CurrencyPair,
+) -> String {
+    format!("{CURRENCY_PAIR_PARAMETERS_PREFIX}{currency_pair}")
+}
+
+pub(in crate::oracles::price_feed::oracle) fn currency_pair_twap(
+    currency_pair: &CurrencyPair,
+) -> String {
+    format!("{CURRENCY_PAIR_TWAP_PREFIX}{currency_pair}")
+}
+
 pub(in crate::oracles::price_feed::oracle) fn extract_currency_pair_from_pair_to_id_key(
     key: &str,
 ) -> Result<CurrencyPair> {
@@ -99,6 +113,14 @@ mod tests {
             "currency_pair_state_key",
             currency_pair_state(&currency_pair())
         );
+        insta::assert_snapshot!(
+            "currency_pair_parameters_key",
+            currency_pair_parameters(&currency_pair())
+        );
+        insta::assert_snapshot!(
+            "currency_pair_twap_key",
+            currency_pair_twap(&currency_pair())
+        );
     }
 
     #[test]
@@ -108,6 +130,8 @@ mod tests {
         assert!(currency_pair_to_id(&currency_pair()).starts_with(COMPONENT_PREFIX));
         assert!(id_to_currency_pair(CurrencyPairId::new(9)).starts_with(COMPONENT_PREFIX));
         assert!(currency_pair_state(&currency_pair()).starts_with(COMPONENT_PREFIX));
+        assert!(currency_pair_parameters(&currency_pair()).starts_with(COMPONENT_PREFIX));
+        assert!(currency_pair_twap(&currency_pair()).starts_with(COMPONENT_PREFIX));
     }
 
     #[test]
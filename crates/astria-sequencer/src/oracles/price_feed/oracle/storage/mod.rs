@@ -3,7 +3,9 @@ pub(super) use values::{
     Count,
     CurrencyPair,
     CurrencyPairId,
+    CurrencyPairParameters,
     CurrencyPairState,
+    Twap,
 };
 
 pub(super) mod keys;
@@ -0,0 +1,72 @@
+use astria_core::{
+    oracles::price_feed::types::v2::CurrencyPair as DomainCurrencyPair,
+    protocol::transaction::v1::action::CurrencyPairParameters as DomainCurrencyPairParameters,
+};
+use astria_eyre::eyre::bail;
+use borsh::{
+    BorshDeserialize,
+    BorshSerialize,
+};
+
+use super::{
+    Value,
+    ValueImpl,
+};
+
+/// The parameters of a currency pair, without the currency pair itself as that is part of the
+/// storage key.
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub(in crate::oracles::price_feed::oracle) struct CurrencyPairParameters {
+    max_age_seconds: u64,
+    max_deviation_basis_points: u32,
+    twap_window_seconds: u64,
+}
+
+impl CurrencyPairParameters {
+    pub(in crate::oracles::price_feed::oracle) fn into_domain(
+        self,
+        currency_pair: DomainCurrencyPair,
+    ) -> DomainCurrencyPairParameters {
+        DomainCurrencyPairParameters {
+            currency_pair,
+            max_age_seconds: self.max_age_seconds,
+            max_deviation_basis_points: self.max_deviation_basis_points,
+            twap_window_seconds: self.twap_window_seconds,
+        }
+    }
+}
+
+impl From<&DomainCurrencyPairParameters> for CurrencyPairParameters {
+    fn from(parameters: &DomainCurrencyPairParameters) -> Self {
+        CurrencyPairParameters {
+            max_age_seconds: parameters.max_age_seconds,
+            max_deviation_basis_points: parameters.max_deviation_basis_points,
+            twap_window_seconds: parameters.twap_window_seconds,
+        }
+    }
+}
+
+impl From<CurrencyPairParameters> for crate::storage::StoredValue<'_> {
+    fn from(parameters: CurrencyPairParameters) -> Self {
+        crate::storage::StoredValue::PriceFeedOracle(Value(ValueImpl::CurrencyPairParameters(
+            parameters,
+        )))
+    }
+}
+
+impl<'a> TryFrom<crate::storage::StoredValue<'a>> for CurrencyPairParameters {
+    type Error = astria_eyre::eyre::Error;
+
+    fn try_from(value: crate::storage::StoredValue<'a>) -> Result<Self, Self::Error> {
+        let crate::storage::StoredValue::PriceFeedOracle(Value(ValueImpl::CurrencyPairParameters(
+            parameters,
+        ))) = value
+        else {
+            bail!(
+                "price feed oracle stored value type mismatch: expected currency pair parameters, \
+                 found {value:?}"
+            );
+        };
+        Ok(parameters)
+    }
+}
@@ -7,13 +7,17 @@ pub(in crate::oracles::price_feed::oracle) use self::{
     count::Count,
     currency_pair::CurrencyPair,
     currency_pair_id::CurrencyPairId,
+    currency_pair_parameters::CurrencyPairParameters,
     currency_pair_state::CurrencyPairState,
+    twap::Twap,
 };
 
 mod count;
 mod currency_pair;
 mod currency_pair_id;
+mod currency_pair_parameters;
 mod currency_pair_state;
+mod twap;
 
 #[derive(Debug, BorshSerialize, BorshDeserialize)]
 pub(crate) struct Value<'a>(ValueImpl<'a>);
@@ -24,4 +28,6 @@ enum ValueImpl<'a> {
     CurrencyPair(CurrencyPair<'a>),
     Count(Count),
     CurrencyPairState(CurrencyPairState),
+    CurrencyPairParameters(CurrencyPairParameters),
+    Twap(Twap),
 }
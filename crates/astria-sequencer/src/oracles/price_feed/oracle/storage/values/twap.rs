@@ -0,0 +1,44 @@
+use astria_core::oracles::price_feed::types::v2::Price;
+use astria_eyre::eyre::bail;
+use borsh::{
+    BorshDeserialize,
+    BorshSerialize,
+};
+
+use super::{
+    Value,
+    ValueImpl,
+};
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub(in crate::oracles::price_feed::oracle) struct Twap(i128);
+
+impl From<Price> for Twap {
+    fn from(price: Price) -> Self {
+        Twap(price.get())
+    }
+}
+
+impl From<Twap> for Price {
+    fn from(twap: Twap) -> Self {
+        Price::new(twap.0)
+    }
+}
+
+impl From<Twap> for crate::storage::StoredValue<'_> {
+    fn from(twap: Twap) -> Self {
+        crate::storage::StoredValue::PriceFeedOracle(Value(ValueImpl::Twap(twap)))
+    }
+}
+
+impl<'a> TryFrom<crate::storage::StoredValue<'a>> for Twap {
+    type Error = astria_eyre::eyre::Error;
+
+    fn try_from(value: crate::storage::StoredValue<'a>) -> Result<Self, Self::Error> {
+        let crate::storage::StoredValue::PriceFeedOracle(Value(ValueImpl::Twap(twap))) = value
+        else {
+            bail!("price feed oracle stored value type mismatch: expected twap, found {value:?}");
+        };
+        Ok(twap)
+    }
+}
@@ -59,6 +59,7 @@ use crate::{
     mempool::Mempool,
     metrics::Metrics,
     service,
+    state_sync,
     upgrades::UpgradesHandler,
 };
 
@@ -68,6 +69,7 @@ pub struct Sequencer;
 
 type GrpcServerHandle = JoinHandle<Result<(), tonic::transport::Error>>;
 type AbciServerHandle = JoinHandle<()>;
+type SnapshotProducerHandle = JoinHandle<()>;
 
 struct RunningGrpcServer {
     pub handle: GrpcServerHandle,
@@ -84,6 +86,7 @@ enum InitializationOutcome {
     Initialized {
         grpc_server: RunningGrpcServer,
         abci_server: RunningAbciServer,
+        snapshot_producer: Option<SnapshotProducerHandle>,
     },
     ShutDownForUpgrade,
 }
@@ -110,8 +113,13 @@ impl Sequencer {
 
             result = initialize_fut => {
                 match result? {
-                    InitializationOutcome::Initialized{grpc_server,abci_server  } => {
-                        Self::run_until_stopped(grpc_server, abci_server, &mut signals).await
+                    InitializationOutcome::Initialized{grpc_server,abci_server,snapshot_producer} => {
+                        Self::run_until_stopped(
+                            grpc_server,
+                            abci_server,
+                            snapshot_producer,
+                            &mut signals,
+                        ).await
                     }
                     InitializationOutcome::ShutDownForUpgrade => { Ok(()) }
                 }
@@ -122,6 +130,7 @@ impl Sequencer {
     async fn run_until_stopped(
         grpc_server: RunningGrpcServer,
         abci_server: RunningAbciServer,
+        snapshot_producer: Option<SnapshotProducerHandle>,
         signals: &mut SignalReceiver,
     ) -> Result<()> {
         select! {
@@ -150,6 +159,9 @@ impl Sequencer {
             .wrap_err("grpc server task failed")?
             .wrap_err("grpc server failed")?;
         abci_server.handle.abort();
+        if let Some(snapshot_producer) = snapshot_producer {
+            snapshot_producer.abort();
+        }
         Ok(())
     }
 
@@ -162,18 +174,13 @@ impl Sequencer {
         register_histogram_global("cnidarium_get_raw_duration_seconds");
         register_histogram_global("cnidarium_nonverifiable_get_raw_duration_seconds");
 
-        let substore_prefixes = vec![penumbra_ibc::IBC_SUBSTORE_PREFIX];
+        let substore_prefixes = vec![penumbra_ibc::IBC_SUBSTORE_PREFIX.to_string()];
 
-        let storage = cnidarium::Storage::load(
-            config.db_filepath.clone(),
-            substore_prefixes
-                .into_iter()
-                .map(ToString::to_string)
-                .collect(),
-        )
-        .await
-        .map_err(anyhow_to_eyre)
-        .wrap_err("failed to load storage backing chain state")?;
+        let storage =
+            cnidarium::Storage::load(config.db_filepath.clone(), substore_prefixes.clone())
+                .await
+                .map_err(anyhow_to_eyre)
+                .wrap_err("failed to load storage backing chain state")?;
         let snapshot = storage.latest_snapshot();
 
         let upgrades_handler =
@@ -216,7 +223,11 @@ impl Sequencer {
                 .context("failed to query state for base prefix")?;
         }
 
-        let mempool = Mempool::new(metrics, config.mempool_parked_max_tx_count);
+        let mempool = Mempool::new(
+            metrics,
+            config.mempool_parked_max_tx_count,
+            config.mempool_replacement_fee_bump_percent,
+        );
         let price_feed_client = new_price_feed_client(&config)
             .await
             .wrap_err("failed to create connected price feed client")?;
@@ -256,12 +267,27 @@ impl Sequencer {
             grpc_shutdown_rx,
         ));
 
+        let snapshot_store = state_sync::SnapshotStore::new(config.snapshot_dir);
+        let snapshot_producer = (config.snapshot_interval > 0).then(|| {
+            tokio::spawn(
+                state_sync::Producer::new(
+                    storage.clone(),
+                    snapshot_store.clone(),
+                    substore_prefixes,
+                    config.snapshot_interval,
+                    config.snapshot_keep_recent,
+                )
+                .run(),
+            )
+        });
+
         debug!(%config.abci_listen_url, "starting sequencer");
         let consensus_cancellation_token = tokio_util::sync::CancellationToken::new();
         let abci_server_handle = start_abci_server(
             &storage,
             app,
             mempool_service,
+            snapshot_store,
             config.abci_listen_url,
             abci_shutdown_tx,
             consensus_cancellation_token.clone(),
@@ -281,6 +307,7 @@ impl Sequencer {
         Ok(InitializationOutcome::Initialized {
             grpc_server,
             abci_server,
+            snapshot_producer,
         })
     }
 }
@@ -289,6 +316,7 @@ fn start_abci_server(
     storage: &cnidarium::Storage,
     app: App,
     mempool_service: service::Mempool,
+    snapshot_store: state_sync::SnapshotStore,
     listen_url: AbciListenUrl,
     abci_shutdown_tx: oneshot::Sender<()>,
     consensus_cancellation_token: tokio_util::sync::CancellationToken,
@@ -307,7 +335,7 @@ fn start_abci_server(
         }));
     let info_service =
         service::Info::new(storage.clone()).wrap_err("failed initializing info service")?;
-    let snapshot_service = service::Snapshot;
+    let snapshot_service = service::Snapshot::new(storage.clone(), snapshot_store);
 
     let server = Server::builder()
         .consensus(consensus_service)
@@ -459,7 +487,11 @@ mod tests {
             price_feed_grpc_addr: "http://127.0.0.1:8081".to_string(),
             price_feed_client_timeout_milliseconds: 1,
             mempool_parked_max_tx_count: 1,
+            mempool_replacement_fee_bump_percent: 10,
             no_optimistic_blocks: false,
+            snapshot_dir: PathBuf::new(),
+            snapshot_interval: 0,
+            snapshot_keep_recent: 0,
         };
 
         let start = tokio::time::Instant::now();
@@ -422,6 +422,7 @@ mod tests {
                 0,
                 mock_balances(0, 0),
                 mock_tx_cost(0, 0, 0),
+                0,
             )
             .await
             .unwrap();
@@ -571,6 +572,7 @@ mod tests {
                 0,
                 mock_balances(0, 0),
                 mock_tx_cost(0, 0, 0),
+                0,
             )
             .await
             .unwrap();
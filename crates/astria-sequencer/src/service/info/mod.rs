@@ -49,6 +49,9 @@ pub(crate) struct Info {
 
 const ACCOUNT_BALANCE: &str = "accounts/balance/:account";
 const ACCOUNT_NONCE: &str = "accounts/nonce/:account";
+const ACCOUNT_BALANCE_HISTORY: &str = "accounts/balance_history/:account";
+const ACCOUNT_TRANSACTIONS: &str = "accounts/transactions/:account";
+const ACCOUNT_TRANSACTIONS_PAGE: &str = "accounts/transactions/:account/:page";
 const ASSET_DENOM: &str = "asset/denom/:id";
 const FEE_ALLOWED_ASSETS: &str = "asset/allowed_fee_assets";
 
@@ -58,6 +61,14 @@ const BRIDGE_ACCOUNT_INFO: &str = "bridge/account_info/:address";
 const TRANSACTION_FEE: &str = "transaction/fee";
 
 const FEES_COMPONENTS: &str = "fees/components";
+const FEES_DISTRIBUTION: &str = "fees/distribution";
+
+const TIMELOCK_TRANSFER: &str = "timelock/transfer/:id";
+
+const GOVERNANCE_PARAMETERS: &str = "governance/parameters";
+const GOVERNANCE_PROPOSAL: &str = "governance/proposal/:id";
+
+const ORACLES_LIVENESS: &str = "oracles/liveness";
 
 impl Info {
     pub(crate) fn new(storage: Storage) -> Result<Self> {
@@ -66,6 +77,18 @@ impl Info {
         // NOTE: Skipping error context because `InsertError` contains all required information.
         query_router.insert(ACCOUNT_BALANCE, crate::accounts::query::balance_request)?;
         query_router.insert(ACCOUNT_NONCE, crate::accounts::query::nonce_request)?;
+        query_router.insert(
+            ACCOUNT_BALANCE_HISTORY,
+            crate::accounts::query::balance_history_request,
+        )?;
+        query_router.insert(
+            ACCOUNT_TRANSACTIONS,
+            crate::accounts::query::transactions_request,
+        )?;
+        query_router.insert(
+            ACCOUNT_TRANSACTIONS_PAGE,
+            crate::accounts::query::transactions_request,
+        )?;
         query_router.insert(ASSET_DENOM, crate::assets::query::denom_request)?;
         query_router.insert(
             FEE_ALLOWED_ASSETS,
@@ -81,6 +104,20 @@ impl Info {
         )?;
         query_router.insert(TRANSACTION_FEE, crate::fees::query::transaction_fee_request)?;
         query_router.insert(FEES_COMPONENTS, crate::fees::query::components)?;
+        query_router.insert(FEES_DISTRIBUTION, crate::fees::query::distribution_request)?;
+        query_router.insert(TIMELOCK_TRANSFER, crate::timelock::query::transfer_request)?;
+        query_router.insert(
+            GOVERNANCE_PARAMETERS,
+            crate::governance::query::parameters_request,
+        )?;
+        query_router.insert(
+            GOVERNANCE_PROPOSAL,
+            crate::governance::query::proposal_request,
+        )?;
+        query_router.insert(
+            ORACLES_LIVENESS,
+            crate::oracles::price_feed::liveness::query::liveness_request,
+        )?;
         Ok(Self {
             storage,
             query_router,
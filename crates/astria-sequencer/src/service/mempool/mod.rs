@@ -99,6 +99,22 @@ impl IntoCheckTxResponse for RemovalReason {
                     .into(),
                 ..response::CheckTx::default()
             },
+            RemovalReason::Replaced => response::CheckTx {
+                code: Code::Err(AbciErrorCode::NONCE_TAKEN.value()),
+                info: AbciErrorCode::NONCE_TAKEN.info(),
+                log: "transaction replaced in app mempool by a transaction with the same nonce \
+                      paying a higher fee"
+                    .into(),
+                ..response::CheckTx::default()
+            },
+            RemovalReason::EvictedByHigherFee => response::CheckTx {
+                code: Code::Err(AbciErrorCode::PARKED_FULL.value()),
+                info: AbciErrorCode::PARKED_FULL.info(),
+                log: "transaction evicted from app mempool by a transaction paying a higher fee \
+                      per byte"
+                    .into(),
+                ..response::CheckTx::default()
+            },
         }
     }
 }
@@ -124,6 +140,18 @@ impl IntoCheckTxResponse for InsertionError {
                 log: InsertionError::NonceTaken.to_string(),
                 ..response::CheckTx::default()
             },
+            InsertionError::ReplacementFeeTooLow => response::CheckTx {
+                code: Code::Err(AbciErrorCode::NONCE_TAKEN.value()),
+                info: AbciErrorCode::NONCE_TAKEN.to_string(),
+                log: InsertionError::ReplacementFeeTooLow.to_string(),
+                ..response::CheckTx::default()
+            },
+            InsertionError::ReplacementBalanceTooLow => response::CheckTx {
+                code: Code::Err(AbciErrorCode::INSUFFICIENT_FUNDS.value()),
+                info: AbciErrorCode::INSUFFICIENT_FUNDS.to_string(),
+                log: InsertionError::ReplacementBalanceTooLow.to_string(),
+                ..response::CheckTx::default()
+            },
             InsertionError::AccountSizeLimit => response::CheckTx {
                 code: Code::Err(AbciErrorCode::ACCOUNT_SIZE_LIMIT.value()),
                 info: AbciErrorCode::ACCOUNT_SIZE_LIMIT.to_string(),
@@ -133,7 +161,9 @@ impl IntoCheckTxResponse for InsertionError {
             InsertionError::ParkedSizeLimit => response::CheckTx {
                 code: Code::Err(AbciErrorCode::PARKED_FULL.value()),
                 info: AbciErrorCode::PARKED_FULL.info(),
-                log: "transaction failed insertion because parked container is full".into(),
+                log: "transaction failed insertion because parked container is full and no parked \
+                      transaction pays a lower fee per byte"
+                    .into(),
                 ..response::CheckTx::default()
             },
             InsertionError::AccountBalanceTooLow | InsertionError::NonceGap => {
@@ -380,6 +410,13 @@ async fn stateless_checks<S: StateRead>(
 
     metrics.record_check_tx_duration_seconds_check_chain_id(finished_check_stateless.elapsed());
 
+    if let Err(e) = transaction::check_multisig_authorization(&signed_tx, &state).await {
+        return Err(error_response(
+            AbciErrorCode::INVALID_PARAMETER,
+            format!("transaction is not authorized by its multisig policy: {e:#}"),
+        ));
+    }
+
     // NOTE: decide if worth moving to post-insertion, would have to recalculate cost
     metrics.record_transaction_in_mempool_size_bytes(tx_len);
 
@@ -402,7 +439,7 @@ async fn insert_into_mempool<S: StateRead>(
     // TODO: just use address bytes directly https://github.com/astriaorg/astria/issues/1620
     // generate address for the signed transaction
     let address = match state
-        .try_base_prefixed(signed_tx.verification_key().address_bytes())
+        .try_base_prefixed(signed_tx.address_bytes())
         .await
         .context("failed to generate address for signed transaction")
     {
@@ -453,6 +490,18 @@ async fn insert_into_mempool<S: StateRead>(
         }
         Ok(transaction_cost) => transaction_cost,
     };
+    let transaction_fee = match transaction::get_total_transaction_fee(&signed_tx, &state)
+        .await
+        .context("failed fetching fee of the transaction")
+    {
+        Err(err) => {
+            return Err(error_response(
+                AbciErrorCode::INTERNAL_ERROR,
+                format!("failed to fetch fee of the transaction because: {err:#}"),
+            ));
+        }
+        Ok(transaction_fee) => transaction_fee,
+    };
 
     let finished_fetch_tx_cost = Instant::now();
     metrics.record_check_tx_duration_seconds_fetch_tx_cost(
@@ -487,6 +536,7 @@ async fn insert_into_mempool<S: StateRead>(
             current_account_nonce,
             current_account_balance,
             transaction_cost,
+            transaction_fee,
         )
         .await
     {
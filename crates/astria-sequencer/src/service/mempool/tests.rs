@@ -33,7 +33,7 @@ async fn future_nonces_are_accepted() {
     let snapshot = storage.latest_snapshot();
 
     let metrics = Box::leak(Box::new(Metrics::noop_metrics(&()).unwrap()));
-    let mut mempool = Mempool::new(metrics, 100);
+    let mut mempool = Mempool::new(metrics, 100, 10);
     let ve_handler = crate::app::vote_extension::Handler::new(None);
     let mut app = App::new(
         snapshot,
@@ -76,7 +76,7 @@ async fn rechecks_pass() {
     let snapshot = storage.latest_snapshot();
 
     let metrics = Box::leak(Box::new(Metrics::noop_metrics(&()).unwrap()));
-    let mut mempool = Mempool::new(metrics, 100);
+    let mut mempool = Mempool::new(metrics, 100, 10);
     let ve_handler = crate::app::vote_extension::Handler::new(None);
     let mut app = App::new(
         snapshot,
@@ -127,7 +127,7 @@ async fn can_reinsert_after_recheck_fail() {
     let snapshot = storage.latest_snapshot();
 
     let metrics = Box::leak(Box::new(Metrics::noop_metrics(&()).unwrap()));
-    let mut mempool = Mempool::new(metrics, 100);
+    let mut mempool = Mempool::new(metrics, 100, 10);
     let ve_handler = crate::app::vote_extension::Handler::new(None);
     let mut app = App::new(
         snapshot,
@@ -188,7 +188,7 @@ async fn recheck_adds_non_tracked_tx() {
     let snapshot = storage.latest_snapshot();
 
     let metrics = Box::leak(Box::new(Metrics::noop_metrics(&()).unwrap()));
-    let mut mempool = Mempool::new(metrics, 100);
+    let mut mempool = Mempool::new(metrics, 100, 10);
     let ve_handler = crate::app::vote_extension::Handler::new(None);
     let mut app = App::new(
         snapshot,
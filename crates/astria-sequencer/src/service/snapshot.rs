@@ -1,32 +1,195 @@
 use std::{
     pin::Pin,
+    sync::Arc,
     task::{
         Context,
         Poll,
     },
 };
 
+use cnidarium::Storage;
 use futures::{
     Future,
     FutureExt,
 };
 use penumbra_tower_trace::v038::RequestExt as _;
-use tendermint::v0_38::abci::{
-    response::{
-        ApplySnapshotChunk,
-        ListSnapshots,
-        LoadSnapshotChunk,
-        OfferSnapshot,
+use tendermint::{
+    abci::response::ApplySnapshotChunkResult,
+    v0_38::abci::{
+        request,
+        response::{
+            ApplySnapshotChunk,
+            ListSnapshots,
+            LoadSnapshotChunk,
+            OfferSnapshot,
+        },
+        SnapshotRequest,
+        SnapshotResponse,
     },
-    SnapshotRequest,
-    SnapshotResponse,
 };
+use tokio::sync::Mutex;
 use tower::Service;
 use tower_abci::BoxError;
-use tracing::Instrument as _;
+use tracing::{
+    info,
+    instrument,
+    warn,
+    Instrument as _,
+};
+
+use crate::state_sync::{
+    InvalidChunk,
+    InvalidSnapshot,
+    Restorer,
+    SnapshotStore,
+};
+
+/// The ABCI snapshot service, serving state sync snapshots to peers and restoring from them.
+#[derive(Clone)]
+pub(crate) struct Snapshot {
+    storage: Storage,
+    store: SnapshotStore,
+    // the snapshot currently being restored, if any.
+    restorer: Arc<Mutex<Option<Restorer>>>,
+}
 
-#[derive(Clone, Default)]
-pub(crate) struct Snapshot;
+impl Snapshot {
+    pub(crate) fn new(storage: Storage, store: SnapshotStore) -> Self {
+        Self {
+            storage,
+            store,
+            restorer: Arc::new(Mutex::new(None)),
+        }
+    }
+
+    #[instrument(skip_all)]
+    async fn handle_request(self, request: SnapshotRequest) -> SnapshotResponse {
+        match request {
+            SnapshotRequest::ListSnapshots => {
+                SnapshotResponse::ListSnapshots(self.list_snapshots().await)
+            }
+            SnapshotRequest::OfferSnapshot(offer) => {
+                SnapshotResponse::OfferSnapshot(self.offer_snapshot(offer).await)
+            }
+            SnapshotRequest::LoadSnapshotChunk(load) => {
+                SnapshotResponse::LoadSnapshotChunk(self.load_snapshot_chunk(load).await)
+            }
+            SnapshotRequest::ApplySnapshotChunk(apply) => {
+                SnapshotResponse::ApplySnapshotChunk(self.apply_snapshot_chunk(apply).await)
+            }
+        }
+    }
+
+    async fn list_snapshots(&self) -> ListSnapshots {
+        match self.store.list().await {
+            Ok(snapshots) => ListSnapshots {
+                snapshots,
+            },
+            Err(error) => {
+                warn!(
+                    error = AsRef::<dyn std::error::Error>::as_ref(&error),
+                    "failed to list state sync snapshots; returning none"
+                );
+                ListSnapshots::default()
+            }
+        }
+    }
+
+    async fn load_snapshot_chunk(&self, load: request::LoadSnapshotChunk) -> LoadSnapshotChunk {
+        match self
+            .store
+            .load_chunk(load.height.value(), load.format, load.chunk)
+            .await
+        {
+            Ok(Some(chunk)) => LoadSnapshotChunk {
+                chunk,
+            },
+            Ok(None) => LoadSnapshotChunk::default(),
+            Err(error) => {
+                warn!(
+                    height = %load.height,
+                    chunk = load.chunk,
+                    error = AsRef::<dyn std::error::Error>::as_ref(&error),
+                    "failed to load state sync snapshot chunk; returning empty chunk"
+                );
+                LoadSnapshotChunk::default()
+            }
+        }
+    }
+
+    async fn offer_snapshot(&self, offer: request::OfferSnapshot) -> OfferSnapshot {
+        if self.storage.latest_version() != u64::MAX {
+            warn!("received snapshot offer, but storage is already initialized; aborting");
+            return OfferSnapshot::Abort;
+        }
+        let height = offer.snapshot.height;
+        match Restorer::new(offer.snapshot, offer.app_hash) {
+            Ok(restorer) => {
+                info!(%height, "accepted state sync snapshot offer");
+                *self.restorer.lock().await = Some(restorer);
+                OfferSnapshot::Accept
+            }
+            Err(error @ InvalidSnapshot::UnsupportedFormat(_)) => {
+                warn!(%height, %error, "rejecting state sync snapshot offer");
+                OfferSnapshot::RejectFormat
+            }
+            Err(error) => {
+                warn!(%height, %error, "rejecting state sync snapshot offer");
+                OfferSnapshot::Reject
+            }
+        }
+    }
+
+    async fn apply_snapshot_chunk(&self, apply: request::ApplySnapshotChunk) -> ApplySnapshotChunk {
+        let mut guard = self.restorer.lock().await;
+        let Some(restorer) = guard.as_mut() else {
+            warn!("received snapshot chunk without an accepted snapshot offer; aborting");
+            return apply_chunk_response(ApplySnapshotChunkResult::Abort);
+        };
+        if let Err(error) = restorer.add_chunk(apply.index, apply.chunk) {
+            warn!(%error, sender = %apply.sender, "received invalid snapshot chunk");
+            return match error {
+                InvalidChunk::HashMismatch {
+                    ..
+                } => ApplySnapshotChunk {
+                    result: ApplySnapshotChunkResult::Retry,
+                    refetch_chunks: vec![apply.index],
+                    reject_senders: vec![apply.sender],
+                },
+                InvalidChunk::IndexOutOfRange {
+                    ..
+                } => {
+                    *guard = None;
+                    apply_chunk_response(ApplySnapshotChunkResult::RejectSnapshot)
+                }
+            };
+        }
+        if !restorer.is_complete() {
+            return apply_chunk_response(ApplySnapshotChunkResult::Accept);
+        }
+
+        let restorer = guard
+            .take()
+            .expect("restorer was just accessed under the same lock");
+        let height = restorer.snapshot().height;
+        if let Err(error) = restorer.restore(&self.storage).await {
+            warn!(
+                %height,
+                error = AsRef::<dyn std::error::Error>::as_ref(&error),
+                "failed to restore state from snapshot; rejecting snapshot"
+            );
+            return apply_chunk_response(ApplySnapshotChunkResult::RejectSnapshot);
+        }
+        apply_chunk_response(ApplySnapshotChunkResult::Accept)
+    }
+}
+
+fn apply_chunk_response(result: ApplySnapshotChunkResult) -> ApplySnapshotChunk {
+    ApplySnapshotChunk {
+        result,
+        ..ApplySnapshotChunk::default()
+    }
+}
 
 impl Service<SnapshotRequest> for Snapshot {
     type Error = BoxError;
@@ -41,26 +204,160 @@ impl Service<SnapshotRequest> for Snapshot {
     fn call(&mut self, req: SnapshotRequest) -> Self::Future {
         let span = req.create_span();
 
-        async move {
-            Ok(match req {
-                SnapshotRequest::ListSnapshots => {
-                    SnapshotResponse::ListSnapshots(ListSnapshots::default())
-                }
+        self.clone()
+            .handle_request(req)
+            .map(Ok)
+            .instrument(span)
+            .boxed()
+    }
+}
 
-                SnapshotRequest::OfferSnapshot(_) => {
-                    SnapshotResponse::OfferSnapshot(OfferSnapshot::default())
-                }
+#[cfg(test)]
+mod tests {
+    use bytes::Bytes;
+    use cnidarium::{
+        StateDelta,
+        TempStorage,
+    };
+    use tendermint::AppHash;
 
-                SnapshotRequest::LoadSnapshotChunk(_) => {
-                    SnapshotResponse::LoadSnapshotChunk(LoadSnapshotChunk::default())
-                }
+    use super::*;
+    use crate::{
+        app::StateWriteExt as _,
+        state_sync::export,
+    };
 
-                SnapshotRequest::ApplySnapshotChunk(_) => {
-                    SnapshotResponse::ApplySnapshotChunk(ApplySnapshotChunk::default())
-                }
+    struct Fixture {
+        source: TempStorage,
+        source_store: SnapshotStore,
+        target: TempStorage,
+        service: Snapshot,
+        _snapshot_dirs: (tempfile::TempDir, tempfile::TempDir),
+    }
+
+    impl Fixture {
+        async fn new() -> Self {
+            let source = TempStorage::new().await.unwrap();
+            let mut state = StateDelta::new(source.latest_snapshot());
+            state.put_block_height(5).unwrap();
+            source.commit(state).await.unwrap();
+            let source_dir = tempfile::tempdir().unwrap();
+            let source_store = SnapshotStore::new(source_dir.path().to_path_buf());
+            export(&source.latest_snapshot(), &[], 5, &source_store)
+                .await
+                .unwrap();
+
+            let target = TempStorage::new().await.unwrap();
+            let target_dir = tempfile::tempdir().unwrap();
+            let service = Snapshot::new(
+                (*target).clone(),
+                SnapshotStore::new(target_dir.path().to_path_buf()),
+            );
+            Self {
+                source,
+                source_store,
+                target,
+                service,
+                _snapshot_dirs: (source_dir, target_dir),
+            }
+        }
+
+        async fn source_app_hash(&self) -> AppHash {
+            let root_hash = self.source.latest_snapshot().root_hash().await.unwrap();
+            root_hash.0.to_vec().try_into().unwrap()
+        }
+
+        async fn offer(
+            &self,
+            app_hash: AppHash,
+        ) -> (tendermint::abci::types::Snapshot, OfferSnapshot) {
+            let snapshot = self.source_store.list().await.unwrap().remove(0);
+            let response = self
+                .service
+                .offer_snapshot(request::OfferSnapshot {
+                    snapshot: snapshot.clone(),
+                    app_hash,
+                })
+                .await;
+            (snapshot, response)
+        }
+
+        async fn apply(&self, index: u32, chunk: Bytes) -> ApplySnapshotChunk {
+            self.service
+                .apply_snapshot_chunk(request::ApplySnapshotChunk {
+                    index,
+                    chunk,
+                    sender: "peer".to_string(),
+                })
+                .await
+        }
+    }
+
+    #[tokio::test]
+    async fn offered_snapshot_is_restored() {
+        let fixture = Fixture::new().await;
+        let (snapshot, response) = fixture.offer(fixture.source_app_hash().await).await;
+        assert_eq!(OfferSnapshot::Accept, response);
+
+        for index in 0..snapshot.chunks {
+            let chunk = fixture
+                .source_store
+                .load_chunk(5, snapshot.format, index)
+                .await
+                .unwrap()
+                .unwrap();
+            let response = fixture.apply(index, chunk).await;
+            assert_eq!(ApplySnapshotChunkResult::Accept, response.result);
+        }
+        assert_eq!(
+            fixture.source.latest_snapshot().root_hash().await.unwrap(),
+            fixture.target.latest_snapshot().root_hash().await.unwrap(),
+        );
+    }
+
+    #[tokio::test]
+    async fn snapshot_with_unknown_format_is_rejected() {
+        let fixture = Fixture::new().await;
+        let mut snapshot = fixture.source_store.list().await.unwrap().remove(0);
+        snapshot.format = snapshot.format.wrapping_add(1);
+        let response = fixture
+            .service
+            .offer_snapshot(request::OfferSnapshot {
+                snapshot,
+                app_hash: fixture.source_app_hash().await,
             })
+            .await;
+        assert_eq!(OfferSnapshot::RejectFormat, response);
+    }
+
+    #[tokio::test]
+    async fn corrupted_chunk_is_refetched_from_other_sender() {
+        let fixture = Fixture::new().await;
+        let _ = fixture.offer(fixture.source_app_hash().await).await;
+        let response = fixture.apply(0, Bytes::from_static(b"garbage")).await;
+        assert_eq!(ApplySnapshotChunkResult::Retry, response.result);
+        assert_eq!(vec![0], response.refetch_chunks);
+        assert_eq!(vec!["peer".to_string()], response.reject_senders);
+    }
+
+    #[tokio::test]
+    async fn snapshot_not_matching_trusted_app_hash_is_rejected() {
+        let fixture = Fixture::new().await;
+        let (snapshot, _) = fixture.offer(AppHash::default()).await;
+        let mut last_response = None;
+        for index in 0..snapshot.chunks {
+            let chunk = fixture
+                .source_store
+                .load_chunk(5, snapshot.format, index)
+                .await
+                .unwrap()
+                .unwrap();
+            last_response = Some(fixture.apply(index, chunk).await);
         }
-        .instrument(span)
-        .boxed()
+        assert_eq!(
+            ApplySnapshotChunkResult::RejectSnapshot,
+            last_response.unwrap().result
+        );
+        assert_eq!(u64::MAX, fixture.target.latest_version());
     }
 }
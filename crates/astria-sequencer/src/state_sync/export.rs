@@ -0,0 +1,169 @@
+use astria_eyre::{
+    anyhow_to_eyre,
+    eyre::{
+        Result,
+        WrapErr as _,
+    },
+};
+use cnidarium::{
+    Snapshot,
+    StateRead as _,
+};
+use futures::TryStreamExt as _;
+use tracing::{
+    info,
+    instrument,
+};
+
+use super::{
+    store::SnapshotWriter,
+    Entry,
+    SnapshotStore,
+    MAX_CHUNK_SIZE,
+};
+use crate::app::STORAGE_VERSION_BY_HEIGHT_PREFIX;
+
+/// Exports the full contents of `snapshot` as a state sync snapshot at `height` into `store`.
+///
+/// `substore_prefixes` must contain the prefixes of all substores of the storage `snapshot` was
+/// taken from, as the contents of each substore are exported separately from the main store.
+#[instrument(skip_all, fields(height), err)]
+pub(crate) async fn export(
+    snapshot: &Snapshot,
+    substore_prefixes: &[String],
+    height: u64,
+    store: &SnapshotStore,
+) -> Result<()> {
+    let mut chunker = Chunker::new(
+        store
+            .writer(height)
+            .await
+            .wrap_err("failed to create snapshot writer")?,
+    );
+
+    // The main store contains the root hashes of all substores keyed by their prefix. These are
+    // recomputed when the substores are written to on restore, so must not be exported.
+    let mut main_store = snapshot.prefix_raw("");
+    while let Some((key, value)) = main_store
+        .try_next()
+        .await
+        .map_err(anyhow_to_eyre)
+        .wrap_err("failed to read verifiable entry from main store")?
+    {
+        if substore_prefixes.contains(&key) {
+            continue;
+        }
+        chunker
+            .push(Entry::Verifiable {
+                key,
+                value,
+            })
+            .await?;
+    }
+    for prefix in substore_prefixes {
+        let mut substore = snapshot.prefix_raw(&format!("{prefix}/"));
+        while let Some((key, value)) = substore
+            .try_next()
+            .await
+            .map_err(anyhow_to_eyre)
+            .wrap_err_with(|| format!("failed to read verifiable entry from substore `{prefix}`"))?
+        {
+            chunker
+                .push(Entry::Verifiable {
+                    key,
+                    value,
+                })
+                .await?;
+        }
+    }
+
+    // Storage versions are local to each node and are rewritten on restore.
+    let mut main_store = snapshot.nonverifiable_prefix_raw(b"");
+    while let Some((key, value)) = main_store
+        .try_next()
+        .await
+        .map_err(anyhow_to_eyre)
+        .wrap_err("failed to read nonverifiable entry from main store")?
+    {
+        if key.starts_with(STORAGE_VERSION_BY_HEIGHT_PREFIX.as_bytes()) {
+            continue;
+        }
+        chunker
+            .push(Entry::Nonverifiable {
+                key,
+                value,
+            })
+            .await?;
+    }
+    for prefix in substore_prefixes {
+        let mut substore = snapshot.nonverifiable_prefix_raw(format!("{prefix}/").as_bytes());
+        while let Some((key, value)) = substore
+            .try_next()
+            .await
+            .map_err(anyhow_to_eyre)
+            .wrap_err_with(|| {
+                format!("failed to read nonverifiable entry from substore `{prefix}`")
+            })?
+        {
+            chunker
+                .push(Entry::Nonverifiable {
+                    key,
+                    value,
+                })
+                .await?;
+        }
+    }
+
+    let chunks = chunker
+        .finish()
+        .await
+        .wrap_err("failed to finish writing snapshot")?;
+    info!(height, chunks, "exported state sync snapshot");
+    Ok(())
+}
+
+/// Groups entries into chunks of at most [`MAX_CHUNK_SIZE`] bytes and writes them to disk.
+struct Chunker {
+    writer: SnapshotWriter,
+    entries: Vec<Entry>,
+    size: usize,
+}
+
+impl Chunker {
+    fn new(writer: SnapshotWriter) -> Self {
+        Self {
+            writer,
+            entries: vec![],
+            size: 0,
+        }
+    }
+
+    async fn push(&mut self, entry: Entry) -> Result<()> {
+        let entry_len = entry.encoded_len();
+        if !self.entries.is_empty() && self.size.saturating_add(entry_len) > MAX_CHUNK_SIZE {
+            self.flush().await?;
+        }
+        self.size = self.size.saturating_add(entry_len);
+        self.entries.push(entry);
+        Ok(())
+    }
+
+    async fn flush(&mut self) -> Result<()> {
+        let entries = std::mem::take(&mut self.entries);
+        self.size = 0;
+        let chunk = borsh::to_vec(&entries).wrap_err("failed to encode snapshot chunk")?;
+        self.writer
+            .write_chunk(chunk)
+            .await
+            .wrap_err("failed to write snapshot chunk")
+    }
+
+    /// Writes any remaining entries and the snapshot manifest, returning the number of chunks.
+    async fn finish(mut self) -> Result<u32> {
+        // CometBFT requires at least one chunk, so always flush even if there are no entries.
+        if !self.entries.is_empty() || self.writer.chunk_count() == 0 {
+            self.flush().await?;
+        }
+        self.writer.finish().await
+    }
+}
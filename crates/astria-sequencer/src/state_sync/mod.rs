@@ -0,0 +1,225 @@
+//! State sync snapshots of the sequencer's chain state.
+//!
+//! Every `snapshot_interval` blocks the [`Producer`] exports the verifiable and non-verifiable
+//! contents of the committed cnidarium storage into a series of chunks which are persisted to
+//! disk by the [`SnapshotStore`]. These are served to CometBFT peers via the ABCI snapshot
+//! connection, and a fresh node can rebuild its storage from them using a [`Restorer`].
+//!
+//! A snapshot consists of:
+//! - chunks, each being a borsh-encoded list of [`Entry`]s no larger than [`MAX_CHUNK_SIZE`]
+//!   (unless a single entry exceeds that size);
+//! - [`Metadata`] containing the sha256 hash of every chunk, so that each chunk can be verified as
+//!   soon as it is received;
+//! - a snapshot hash, which is the sha256 hash of the concatenated chunk hashes.
+
+mod export;
+mod producer;
+mod restorer;
+mod store;
+
+use borsh::{
+    BorshDeserialize,
+    BorshSerialize,
+};
+use sha2::{
+    Digest as _,
+    Sha256,
+};
+
+pub(crate) use self::{
+    export::export,
+    producer::Producer,
+    restorer::{
+        InvalidChunk,
+        InvalidSnapshot,
+        Restorer,
+    },
+    store::SnapshotStore,
+};
+
+/// The snapshot format version. Must be bumped on any change to the encoding of snapshots.
+pub(crate) const SNAPSHOT_FORMAT: u32 = 1;
+
+/// The target maximum size of a single snapshot chunk.
+///
+/// CometBFT limits chunk messages to 16MB including metadata, and recommends 10MB.
+const MAX_CHUNK_SIZE: usize = 10 * 1024 * 1024;
+
+/// A single key-value pair of the exported state.
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub(crate) enum Entry {
+    Verifiable { key: String, value: Vec<u8> },
+    Nonverifiable { key: Vec<u8>, value: Vec<u8> },
+}
+
+impl Entry {
+    fn encoded_len(&self) -> usize {
+        match self {
+            Self::Verifiable {
+                key,
+                value,
+            } => key.len().saturating_add(value.len()),
+            Self::Nonverifiable {
+                key,
+                value,
+            } => key.len().saturating_add(value.len()),
+        }
+    }
+}
+
+/// Application-specific snapshot metadata, sent to peers alongside the snapshot.
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub(crate) struct Metadata {
+    pub(crate) chunk_hashes: Vec<[u8; 32]>,
+}
+
+impl Metadata {
+    /// Returns the snapshot hash, i.e. the sha256 hash of the concatenated chunk hashes.
+    pub(crate) fn snapshot_hash(&self) -> [u8; 32] {
+        let mut hasher = Sha256::new();
+        for chunk_hash in &self.chunk_hashes {
+            hasher.update(chunk_hash);
+        }
+        hasher.finalize().into()
+    }
+}
+
+fn chunk_hash(chunk: &[u8]) -> [u8; 32] {
+    Sha256::digest(chunk).into()
+}
+
+#[cfg(test)]
+mod tests {
+    use cnidarium::{
+        StateDelta,
+        StateRead as _,
+        StateWrite as _,
+        TempStorage,
+    };
+    use tendermint::{
+        abci::types::Snapshot,
+        AppHash,
+    };
+
+    use super::*;
+    use crate::app::{
+        StateReadExt as _,
+        StateWriteExt as _,
+    };
+
+    const SUBSTORE_PREFIX: &str = "ibc";
+
+    async fn storage_with_state() -> TempStorage {
+        let storage = TempStorage::new_with_prefixes(vec![SUBSTORE_PREFIX.to_string()])
+            .await
+            .unwrap();
+        let mut state = StateDelta::new(storage.latest_snapshot());
+        state.put_block_height(9).unwrap();
+        state.put_storage_version_by_height(9, 42).unwrap();
+        state.put_raw("verifiable/key".to_string(), b"value".to_vec());
+        state.put_raw(format!("{SUBSTORE_PREFIX}/substore/key"), b"value".to_vec());
+        state.nonverifiable_put_raw(b"nonverifiable/key".to_vec(), b"value".to_vec());
+        state.nonverifiable_put_raw(
+            format!("{SUBSTORE_PREFIX}/nonverifiable/key").into_bytes(),
+            b"value".to_vec(),
+        );
+        storage.commit(state).await.unwrap();
+        storage
+    }
+
+    async fn app_hash(storage: &TempStorage) -> AppHash {
+        let root_hash = storage.latest_snapshot().root_hash().await.unwrap();
+        root_hash.0.to_vec().try_into().unwrap()
+    }
+
+    #[tokio::test]
+    async fn exported_snapshot_is_restored_with_same_app_hash() {
+        let source = storage_with_state().await;
+        let snapshot_dir = tempfile::tempdir().unwrap();
+        let store = SnapshotStore::new(snapshot_dir.path().to_path_buf());
+        export(
+            &source.latest_snapshot(),
+            &[SUBSTORE_PREFIX.to_string()],
+            9,
+            &store,
+        )
+        .await
+        .unwrap();
+
+        let snapshots = store.list().await.unwrap();
+        assert_eq!(1, snapshots.len());
+        let snapshot: Snapshot = snapshots[0].clone();
+        assert_eq!(9, snapshot.height.value());
+
+        let target = TempStorage::new_with_prefixes(vec![SUBSTORE_PREFIX.to_string()])
+            .await
+            .unwrap();
+        let mut restorer = Restorer::new(snapshot.clone(), app_hash(&source).await).unwrap();
+        for index in 0..snapshot.chunks {
+            let chunk = store
+                .load_chunk(9, SNAPSHOT_FORMAT, index)
+                .await
+                .unwrap()
+                .unwrap();
+            restorer.add_chunk(index, chunk).unwrap();
+        }
+        restorer.restore(&target).await.unwrap();
+
+        assert_eq!(app_hash(&source).await, app_hash(&target).await);
+        let restored_state = target.latest_snapshot();
+        assert_eq!(9, restored_state.get_block_height().await.unwrap());
+        assert_eq!(
+            Some(b"value".to_vec()),
+            restored_state
+                .get_raw(&format!("{SUBSTORE_PREFIX}/substore/key"))
+                .await
+                .unwrap()
+        );
+        assert_eq!(
+            Some(b"value".to_vec()),
+            restored_state
+                .nonverifiable_get_raw(format!("{SUBSTORE_PREFIX}/nonverifiable/key").as_bytes())
+                .await
+                .unwrap()
+        );
+        // the storage version of the source node must not leak into the restored node
+        assert_eq!(
+            0,
+            restored_state
+                .get_storage_version_by_height(9)
+                .await
+                .unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn restore_with_mismatched_app_hash_leaves_storage_untouched() {
+        let source = storage_with_state().await;
+        let snapshot_dir = tempfile::tempdir().unwrap();
+        let store = SnapshotStore::new(snapshot_dir.path().to_path_buf());
+        export(
+            &source.latest_snapshot(),
+            &[SUBSTORE_PREFIX.to_string()],
+            9,
+            &store,
+        )
+        .await
+        .unwrap();
+        let snapshot = store.list().await.unwrap().remove(0);
+
+        let target = TempStorage::new_with_prefixes(vec![SUBSTORE_PREFIX.to_string()])
+            .await
+            .unwrap();
+        let mut restorer = Restorer::new(snapshot.clone(), AppHash::default()).unwrap();
+        for index in 0..snapshot.chunks {
+            let chunk = store
+                .load_chunk(9, SNAPSHOT_FORMAT, index)
+                .await
+                .unwrap()
+                .unwrap();
+            restorer.add_chunk(index, chunk).unwrap();
+        }
+        let _ = restorer.restore(&target).await.unwrap_err();
+        assert_eq!(u64::MAX, target.latest_version());
+    }
+}
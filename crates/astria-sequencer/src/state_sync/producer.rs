@@ -0,0 +1,118 @@
+use astria_eyre::eyre::{
+    OptionExt as _,
+    Result,
+    WrapErr as _,
+};
+use cnidarium::{
+    Snapshot,
+    Storage,
+};
+use tracing::{
+    info,
+    instrument,
+    warn,
+};
+
+use super::{
+    export,
+    SnapshotStore,
+};
+use crate::app::StateReadExt as _;
+
+/// Periodically exports state sync snapshots of the committed chain state.
+pub(crate) struct Producer {
+    storage: Storage,
+    store: SnapshotStore,
+    substore_prefixes: Vec<String>,
+    interval: u64,
+    keep_recent: usize,
+}
+
+impl Producer {
+    pub(crate) fn new(
+        storage: Storage,
+        store: SnapshotStore,
+        substore_prefixes: Vec<String>,
+        interval: u64,
+        keep_recent: usize,
+    ) -> Self {
+        Self {
+            storage,
+            store,
+            substore_prefixes,
+            interval,
+            keep_recent,
+        }
+    }
+
+    /// Runs until the storage is dropped, exporting a snapshot at every height which is a
+    /// multiple of the configured interval.
+    ///
+    /// If exporting takes longer than the time between blocks, intermediate committed states
+    /// are skipped. A missed snapshot height is picked up on the next committed state as long
+    /// as its storage version is still retained.
+    pub(crate) async fn run(self) {
+        let mut snapshots = self.storage.subscribe();
+        let mut last_exported_height = None;
+        while snapshots.changed().await.is_ok() {
+            let latest = snapshots.borrow_and_update().clone();
+            let height = match latest.get_block_height().await {
+                Ok(height) => height,
+                Err(error) => {
+                    warn!(
+                        error = AsRef::<dyn std::error::Error>::as_ref(&error),
+                        "failed to read block height of latest snapshot; skipping"
+                    );
+                    continue;
+                }
+            };
+            let Some(target_height) = height
+                .checked_rem(self.interval)
+                .map(|offset| height.saturating_sub(offset))
+            else {
+                continue;
+            };
+            if target_height == 0 || last_exported_height >= Some(target_height) {
+                continue;
+            }
+            if let Err(error) = self.export_at(&latest, height, target_height).await {
+                warn!(
+                    target_height,
+                    error = AsRef::<dyn std::error::Error>::as_ref(&error),
+                    "failed to export state sync snapshot"
+                );
+            }
+            last_exported_height = Some(target_height);
+        }
+        info!("storage was dropped; stopping state sync snapshot producer");
+    }
+
+    #[instrument(skip_all, fields(target_height), err)]
+    async fn export_at(&self, latest: &Snapshot, height: u64, target_height: u64) -> Result<()> {
+        if self.store.contains(target_height).await {
+            return Ok(());
+        }
+        let snapshot = if height == target_height {
+            latest.clone()
+        } else {
+            let version = latest
+                .get_storage_version_by_height(target_height)
+                .await
+                .wrap_err("failed to get storage version of missed snapshot height")?;
+            self.storage
+                .snapshot(version)
+                .ok_or_eyre("storage no longer retains the state at the missed snapshot height")?
+        };
+        export(
+            &snapshot,
+            &self.substore_prefixes,
+            target_height,
+            &self.store,
+        )
+        .await?;
+        self.store
+            .prune(self.keep_recent)
+            .await
+            .wrap_err("failed to prune old snapshots")
+    }
+}
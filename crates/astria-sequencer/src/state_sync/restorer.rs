@@ -0,0 +1,199 @@
+use astria_eyre::{
+    anyhow_to_eyre,
+    eyre::{
+        bail,
+        ensure,
+        Result,
+        WrapErr as _,
+    },
+};
+use bytes::Bytes;
+use cnidarium::{
+    StateDelta,
+    StateWrite as _,
+    Storage,
+};
+use tendermint::{
+    abci::types::Snapshot,
+    AppHash,
+};
+use tracing::{
+    info,
+    instrument,
+};
+
+use super::{
+    chunk_hash,
+    Entry,
+    Metadata,
+    SNAPSHOT_FORMAT,
+};
+use crate::app::{
+    StateReadExt as _,
+    StateWriteExt as _,
+};
+
+#[derive(Debug, thiserror::Error)]
+pub(crate) enum InvalidSnapshot {
+    #[error("snapshot format `{0}` is not supported; only format `{SNAPSHOT_FORMAT}` is")]
+    UnsupportedFormat(u32),
+    #[error("failed to decode snapshot metadata")]
+    Metadata(#[source] std::io::Error),
+    #[error(
+        "snapshot metadata contains `{in_metadata}` chunk hashes, but snapshot has `{chunks}` \
+         chunks"
+    )]
+    ChunkCountMismatch { in_metadata: usize, chunks: u32 },
+    #[error("snapshot hash does not match the hash of its metadata")]
+    HashMismatch,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub(crate) enum InvalidChunk {
+    #[error("chunk index `{index}` is out of range for a snapshot with `{chunks}` chunks")]
+    IndexOutOfRange { index: u32, chunks: usize },
+    #[error("hash of chunk `{index}` does not match the hash in the snapshot metadata")]
+    HashMismatch { index: u32 },
+}
+
+/// Accumulates the chunks of a snapshot offered by CometBFT and restores storage from them.
+pub(crate) struct Restorer {
+    snapshot: Snapshot,
+    trusted_app_hash: AppHash,
+    metadata: Metadata,
+    chunks: Vec<Option<Bytes>>,
+}
+
+impl Restorer {
+    /// Validates the offered `snapshot` and prepares to receive its chunks.
+    ///
+    /// `trusted_app_hash` is the light client verified app hash for the snapshot height, against
+    /// which the restored state is checked before being committed.
+    pub(crate) fn new(
+        snapshot: Snapshot,
+        trusted_app_hash: AppHash,
+    ) -> Result<Self, InvalidSnapshot> {
+        if snapshot.format != SNAPSHOT_FORMAT {
+            return Err(InvalidSnapshot::UnsupportedFormat(snapshot.format));
+        }
+        let metadata: Metadata =
+            borsh::from_slice(&snapshot.metadata).map_err(InvalidSnapshot::Metadata)?;
+        if usize::try_from(snapshot.chunks).ok() != Some(metadata.chunk_hashes.len()) {
+            return Err(InvalidSnapshot::ChunkCountMismatch {
+                in_metadata: metadata.chunk_hashes.len(),
+                chunks: snapshot.chunks,
+            });
+        }
+        if snapshot.hash.as_ref() != metadata.snapshot_hash().as_slice() {
+            return Err(InvalidSnapshot::HashMismatch);
+        }
+        let chunks = vec![None; metadata.chunk_hashes.len()];
+        Ok(Self {
+            snapshot,
+            trusted_app_hash,
+            metadata,
+            chunks,
+        })
+    }
+
+    pub(crate) fn snapshot(&self) -> &Snapshot {
+        &self.snapshot
+    }
+
+    /// Verifies `chunk` against its hash in the snapshot metadata and stores it.
+    pub(crate) fn add_chunk(&mut self, index: u32, chunk: Bytes) -> Result<(), InvalidChunk> {
+        let Some((expected_hash, slot)) = usize::try_from(index).ok().and_then(|index| {
+            self.metadata
+                .chunk_hashes
+                .get(index)
+                .zip(self.chunks.get_mut(index))
+        }) else {
+            return Err(InvalidChunk::IndexOutOfRange {
+                index,
+                chunks: self.chunks.len(),
+            });
+        };
+        if chunk_hash(&chunk) != *expected_hash {
+            return Err(InvalidChunk::HashMismatch {
+                index,
+            });
+        }
+        *slot = Some(chunk);
+        Ok(())
+    }
+
+    /// Returns whether all chunks of the snapshot have been received.
+    pub(crate) fn is_complete(&self) -> bool {
+        self.chunks.iter().all(Option::is_some)
+    }
+
+    /// Writes the contents of the snapshot to `storage`.
+    ///
+    /// The state is only committed if its root hash matches the trusted app hash and its block
+    /// height matches the snapshot height. Otherwise an error is returned and `storage` is left
+    /// untouched.
+    #[instrument(skip_all, fields(height = %self.snapshot.height), err)]
+    pub(crate) async fn restore(self, storage: &Storage) -> Result<()> {
+        ensure!(
+            storage.latest_version() == u64::MAX,
+            "storage is already initialized; refusing to restore snapshot over existing state"
+        );
+        let mut state = StateDelta::new(storage.latest_snapshot());
+        for (index, chunk) in self.chunks.into_iter().enumerate() {
+            let Some(chunk) = chunk else {
+                bail!("chunk `{index}` is missing");
+            };
+            let entries: Vec<Entry> = borsh::from_slice(&chunk)
+                .wrap_err_with(|| format!("failed to decode entries of chunk `{index}`"))?;
+            for entry in entries {
+                match entry {
+                    Entry::Verifiable {
+                        key,
+                        value,
+                    } => state.put_raw(key, value),
+                    Entry::Nonverifiable {
+                        key,
+                        value,
+                    } => state.nonverifiable_put_raw(key, value),
+                }
+            }
+        }
+
+        let height = state
+            .get_block_height()
+            .await
+            .wrap_err("failed to read block height from restored state")?;
+        ensure!(
+            height == self.snapshot.height.value(),
+            "block height `{height}` of restored state does not match snapshot height `{}`",
+            self.snapshot.height,
+        );
+        let new_version = storage.latest_version().wrapping_add(1);
+        state
+            .put_storage_version_by_height(height, new_version)
+            .wrap_err("failed to put storage version by height")?;
+
+        let write_batch = storage
+            .prepare_commit(state)
+            .await
+            .map_err(anyhow_to_eyre)
+            .wrap_err("failed to prepare commit of restored state")?;
+        let app_hash: AppHash = write_batch
+            .root_hash()
+            .0
+            .to_vec()
+            .try_into()
+            .wrap_err("failed to convert app hash")?;
+        ensure!(
+            app_hash == self.trusted_app_hash,
+            "app hash of restored state `{app_hash}` does not match trusted app hash `{}`",
+            self.trusted_app_hash,
+        );
+        storage
+            .commit_batch(write_batch)
+            .map_err(anyhow_to_eyre)
+            .wrap_err("failed to commit restored state")?;
+        info!(%app_hash, "restored state from snapshot");
+        Ok(())
+    }
+}
@@ -0,0 +1,331 @@
+use std::path::{
+    Path,
+    PathBuf,
+};
+
+use astria_eyre::eyre::{
+    ensure,
+    Result,
+    WrapErr as _,
+};
+use borsh::{
+    BorshDeserialize,
+    BorshSerialize,
+};
+use bytes::Bytes;
+use tendermint::abci::types::Snapshot;
+use tracing::{
+    debug,
+    instrument,
+    warn,
+};
+
+use super::{
+    chunk_hash,
+    Metadata,
+    SNAPSHOT_FORMAT,
+};
+
+const MANIFEST_FILE_NAME: &str = "manifest";
+const IN_PROGRESS_SUFFIX: &str = ".tmp";
+
+/// Describes a snapshot persisted to disk.
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+struct Manifest {
+    height: u64,
+    format: u32,
+    metadata: Metadata,
+}
+
+impl Manifest {
+    fn to_abci_snapshot(&self) -> Result<Snapshot> {
+        let chunks = u32::try_from(self.metadata.chunk_hashes.len())
+            .wrap_err("number of chunks does not fit in u32")?;
+        Ok(Snapshot {
+            height: self
+                .height
+                .try_into()
+                .wrap_err("snapshot height is not a valid cometbft height")?,
+            format: self.format,
+            chunks,
+            hash: self.metadata.snapshot_hash().to_vec().into(),
+            metadata: borsh::to_vec(&self.metadata)
+                .wrap_err("failed to encode snapshot metadata")?
+                .into(),
+        })
+    }
+}
+
+/// The on-disk store of state sync snapshots.
+///
+/// Each snapshot lives in its own directory named after the snapshot height, containing one file
+/// per chunk (named by chunk index) and a manifest. Snapshots are written to a temporary
+/// directory first and only moved into place once complete, so partially written snapshots are
+/// never served.
+#[derive(Clone, Debug)]
+pub(crate) struct SnapshotStore {
+    dir: PathBuf,
+}
+
+impl SnapshotStore {
+    pub(crate) fn new(dir: PathBuf) -> Self {
+        Self {
+            dir,
+        }
+    }
+
+    fn snapshot_dir(&self, height: u64) -> PathBuf {
+        self.dir.join(height.to_string())
+    }
+
+    /// Returns whether a complete snapshot exists at `height`.
+    pub(crate) async fn contains(&self, height: u64) -> bool {
+        tokio::fs::try_exists(self.snapshot_dir(height).join(MANIFEST_FILE_NAME))
+            .await
+            .unwrap_or(false)
+    }
+
+    /// Returns all complete snapshots, ordered from highest to lowest height.
+    #[instrument(skip_all, err)]
+    pub(crate) async fn list(&self) -> Result<Vec<Snapshot>> {
+        let mut manifests = self.read_manifests().await?;
+        manifests.sort_unstable_by(|a, b| b.height.cmp(&a.height));
+        manifests.iter().map(Manifest::to_abci_snapshot).collect()
+    }
+
+    /// Returns the chunk at `index` of the snapshot at `height`, or `None` if no such chunk exists.
+    #[instrument(skip(self), err)]
+    pub(crate) async fn load_chunk(
+        &self,
+        height: u64,
+        format: u32,
+        index: u32,
+    ) -> Result<Option<Bytes>> {
+        let Some(manifest) = read_manifest(&self.snapshot_dir(height)).await? else {
+            return Ok(None);
+        };
+        if manifest.format != format {
+            return Ok(None);
+        }
+        let Some(expected_hash) = usize::try_from(index)
+            .ok()
+            .and_then(|index| manifest.metadata.chunk_hashes.get(index))
+        else {
+            return Ok(None);
+        };
+        let chunk = tokio::fs::read(self.snapshot_dir(height).join(index.to_string()))
+            .await
+            .wrap_err("failed to read snapshot chunk from disk")?;
+        ensure!(
+            chunk_hash(&chunk) == *expected_hash,
+            "snapshot chunk on disk does not match its hash in the snapshot manifest"
+        );
+        Ok(Some(chunk.into()))
+    }
+
+    /// Returns a writer for a new snapshot at `height`.
+    ///
+    /// Any leftover partially written snapshot at `height` is removed.
+    pub(crate) async fn writer(&self, height: u64) -> Result<SnapshotWriter> {
+        let dir = self.dir.join(format!("{height}{IN_PROGRESS_SUFFIX}"));
+        if tokio::fs::try_exists(&dir).await.unwrap_or(false) {
+            tokio::fs::remove_dir_all(&dir)
+                .await
+                .wrap_err("failed to remove partially written snapshot")?;
+        }
+        tokio::fs::create_dir_all(&dir)
+            .await
+            .wrap_err_with(|| format!("failed to create snapshot directory `{}`", dir.display()))?;
+        Ok(SnapshotWriter {
+            dir,
+            destination: self.snapshot_dir(height),
+            height,
+            chunk_hashes: vec![],
+        })
+    }
+
+    /// Removes all but the `keep_recent` most recent snapshots.
+    #[instrument(skip(self), err)]
+    pub(crate) async fn prune(&self, keep_recent: usize) -> Result<()> {
+        let mut manifests = self.read_manifests().await?;
+        manifests.sort_unstable_by(|a, b| b.height.cmp(&a.height));
+        for manifest in manifests.iter().skip(keep_recent) {
+            debug!(height = manifest.height, "removing state sync snapshot");
+            tokio::fs::remove_dir_all(self.snapshot_dir(manifest.height))
+                .await
+                .wrap_err("failed to remove snapshot directory")?;
+        }
+        Ok(())
+    }
+
+    async fn read_manifests(&self) -> Result<Vec<Manifest>> {
+        if !tokio::fs::try_exists(&self.dir).await.unwrap_or(false) {
+            return Ok(vec![]);
+        }
+        let mut entries = tokio::fs::read_dir(&self.dir)
+            .await
+            .wrap_err("failed to read snapshot directory")?;
+        let mut manifests = vec![];
+        while let Some(entry) = entries
+            .next_entry()
+            .await
+            .wrap_err("failed to read snapshot directory entry")?
+        {
+            let is_snapshot_dir = entry
+                .file_name()
+                .to_str()
+                .is_some_and(|name| name.parse::<u64>().is_ok());
+            if !is_snapshot_dir {
+                continue;
+            }
+            match read_manifest(&entry.path()).await {
+                Ok(Some(manifest)) => manifests.push(manifest),
+                Ok(None) => {}
+                Err(error) => warn!(
+                    path = %entry.path().display(),
+                    error = AsRef::<dyn std::error::Error>::as_ref(&error),
+                    "failed to read snapshot manifest; skipping snapshot"
+                ),
+            }
+        }
+        Ok(manifests)
+    }
+}
+
+async fn read_manifest(snapshot_dir: &Path) -> Result<Option<Manifest>> {
+    let path = snapshot_dir.join(MANIFEST_FILE_NAME);
+    if !tokio::fs::try_exists(&path).await.unwrap_or(false) {
+        return Ok(None);
+    }
+    let bytes = tokio::fs::read(&path)
+        .await
+        .wrap_err("failed to read snapshot manifest")?;
+    let manifest = borsh::from_slice(&bytes).wrap_err("failed to decode snapshot manifest")?;
+    Ok(Some(manifest))
+}
+
+/// Writes the chunks of a single snapshot to disk.
+pub(crate) struct SnapshotWriter {
+    dir: PathBuf,
+    destination: PathBuf,
+    height: u64,
+    chunk_hashes: Vec<[u8; 32]>,
+}
+
+impl SnapshotWriter {
+    pub(super) fn chunk_count(&self) -> usize {
+        self.chunk_hashes.len()
+    }
+
+    pub(super) async fn write_chunk(&mut self, chunk: Vec<u8>) -> Result<()> {
+        let path = self.dir.join(self.chunk_hashes.len().to_string());
+        let hash = chunk_hash(&chunk);
+        tokio::fs::write(&path, chunk)
+            .await
+            .wrap_err_with(|| format!("failed to write chunk to `{}`", path.display()))?;
+        self.chunk_hashes.push(hash);
+        Ok(())
+    }
+
+    /// Writes the manifest and moves the snapshot into place, returning the number of chunks.
+    pub(super) async fn finish(self) -> Result<u32> {
+        let chunks = u32::try_from(self.chunk_hashes.len())
+            .wrap_err("number of chunks does not fit in u32")?;
+        let manifest = Manifest {
+            height: self.height,
+            format: SNAPSHOT_FORMAT,
+            metadata: Metadata {
+                chunk_hashes: self.chunk_hashes,
+            },
+        };
+        let bytes = borsh::to_vec(&manifest).wrap_err("failed to encode snapshot manifest")?;
+        tokio::fs::write(self.dir.join(MANIFEST_FILE_NAME), bytes)
+            .await
+            .wrap_err("failed to write snapshot manifest")?;
+        if tokio::fs::try_exists(&self.destination)
+            .await
+            .unwrap_or(false)
+        {
+            tokio::fs::remove_dir_all(&self.destination)
+                .await
+                .wrap_err("failed to remove existing snapshot")?;
+        }
+        tokio::fs::rename(&self.dir, &self.destination)
+            .await
+            .wrap_err("failed to move snapshot into place")?;
+        Ok(chunks)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn write_snapshot(store: &SnapshotStore, height: u64, chunks: &[&[u8]]) {
+        let mut writer = store.writer(height).await.unwrap();
+        for chunk in chunks {
+            writer.write_chunk(chunk.to_vec()).await.unwrap();
+        }
+        writer.finish().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn snapshots_are_listed_from_highest_to_lowest() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = SnapshotStore::new(dir.path().to_path_buf());
+        write_snapshot(&store, 10, &[b"a"]).await;
+        write_snapshot(&store, 30, &[b"a", b"b"]).await;
+        write_snapshot(&store, 20, &[b"a"]).await;
+        // a partially written snapshot must not be listed
+        let mut writer = store.writer(40).await.unwrap();
+        writer.write_chunk(b"a".to_vec()).await.unwrap();
+
+        let heights: Vec<_> = store
+            .list()
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|snapshot| snapshot.height.value())
+            .collect();
+        assert_eq!(vec![30, 20, 10], heights);
+        assert!(store.contains(30).await);
+        assert!(!store.contains(40).await);
+    }
+
+    #[tokio::test]
+    async fn chunks_are_loaded_by_index() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = SnapshotStore::new(dir.path().to_path_buf());
+        write_snapshot(&store, 10, &[b"first", b"second"]).await;
+
+        assert_eq!(
+            Some(Bytes::from_static(b"second")),
+            store.load_chunk(10, SNAPSHOT_FORMAT, 1).await.unwrap()
+        );
+        assert_eq!(
+            None,
+            store.load_chunk(10, SNAPSHOT_FORMAT, 2).await.unwrap()
+        );
+        assert_eq!(
+            None,
+            store.load_chunk(10, SNAPSHOT_FORMAT + 1, 0).await.unwrap()
+        );
+        assert_eq!(
+            None,
+            store.load_chunk(11, SNAPSHOT_FORMAT, 0).await.unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn prune_keeps_most_recent_snapshots() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = SnapshotStore::new(dir.path().to_path_buf());
+        for height in [10, 20, 30] {
+            write_snapshot(&store, height, &[b"a"]).await;
+        }
+        store.prune(2).await.unwrap();
+        assert!(!store.contains(10).await);
+        assert!(store.contains(20).await);
+        assert!(store.contains(30).await);
+    }
+}
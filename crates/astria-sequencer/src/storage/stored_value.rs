@@ -22,6 +22,10 @@ pub(crate) enum StoredValue<'a> {
     Upgrades(crate::upgrades::storage::Value<'a>),
     PriceFeedMarketMap(crate::oracles::price_feed::market_map::storage::Value<'a>),
     PriceFeedOracle(crate::oracles::price_feed::oracle::storage::Value<'a>),
+    Multisig(crate::multisig::storage::Value<'a>),
+    Timelock(crate::timelock::storage::Value<'a>),
+    Governance(crate::governance::storage::Value<'a>),
+    PriceFeedLiveness(crate::oracles::price_feed::liveness::storage::Value<'a>),
 }
 
 impl StoredValue<'_> {
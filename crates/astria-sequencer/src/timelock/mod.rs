@@ -0,0 +1,72 @@
+pub(crate) mod query;
+mod state_ext;
+pub(crate) mod storage;
+
+use astria_core::{
+    primitive::v1::{
+        asset,
+        ADDRESS_LEN,
+    },
+    protocol::transaction::v1::action::TimelockUnlock,
+};
+pub(crate) use state_ext::{
+    StateReadExt,
+    StateWriteExt,
+};
+use tendermint::Time;
+
+/// Funds escrowed by a `TimelockedTransfer` action until they are claimed by the recipient or
+/// returned to the sender.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct TimelockedTransferEscrow {
+    pub(crate) from: [u8; ADDRESS_LEN],
+    pub(crate) to: [u8; ADDRESS_LEN],
+    pub(crate) asset: asset::IbcPrefixed,
+    pub(crate) amount: u128,
+    pub(crate) unlock: TimelockUnlock,
+}
+
+impl TimelockedTransferEscrow {
+    /// Returns whether the escrowed funds are unlocked in a block at `height` with timestamp
+    /// `time`.
+    pub(crate) fn is_unlocked(&self, height: u64, time: Time) -> bool {
+        match self.unlock {
+            TimelockUnlock::Height(unlock_height) => height >= unlock_height,
+            TimelockUnlock::Time(unlock_time) => time >= unlock_time,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn escrow(unlock: TimelockUnlock) -> TimelockedTransferEscrow {
+        TimelockedTransferEscrow {
+            from: [1; ADDRESS_LEN],
+            to: [2; ADDRESS_LEN],
+            asset: asset::IbcPrefixed::new([3; 32]),
+            amount: 100,
+            unlock,
+        }
+    }
+
+    #[test]
+    fn height_unlock_is_inclusive() {
+        let escrow = escrow(TimelockUnlock::Height(10));
+        let time = Time::from_unix_timestamp(0, 0).unwrap();
+        assert!(!escrow.is_unlocked(9, time));
+        assert!(escrow.is_unlocked(10, time));
+        assert!(escrow.is_unlocked(11, time));
+    }
+
+    #[test]
+    fn time_unlock_is_inclusive() {
+        let escrow = escrow(TimelockUnlock::Time(
+            Time::from_unix_timestamp(100, 0).unwrap(),
+        ));
+        assert!(!escrow.is_unlocked(u64::MAX, Time::from_unix_timestamp(99, 0).unwrap()));
+        assert!(escrow.is_unlocked(0, Time::from_unix_timestamp(100, 0).unwrap()));
+        assert!(escrow.is_unlocked(0, Time::from_unix_timestamp(101, 0).unwrap()));
+    }
+}
@@ -0,0 +1,275 @@
+use astria_core::protocol::{
+    abci::AbciErrorCode,
+    transaction::v1::action::TimelockUnlock,
+};
+use astria_eyre::eyre::{
+    Result,
+    WrapErr as _,
+};
+use cnidarium::{
+    StateRead,
+    Storage,
+};
+use tendermint::abci::{
+    request,
+    response,
+    Code,
+};
+use tracing::{
+    instrument,
+    Level,
+};
+
+use super::{
+    StateReadExt as _,
+    TimelockedTransferEscrow,
+};
+use crate::{
+    address::StateReadExt as _,
+    app::StateReadExt as _,
+    assets::StateReadExt as _,
+};
+
+#[derive(serde::Serialize)]
+struct TimelockedTransferResponse {
+    height: u64,
+    transfer: Option<EscrowResponse>,
+}
+
+#[derive(serde::Serialize)]
+struct EscrowResponse {
+    id: u64,
+    from: String,
+    to: String,
+    asset: String,
+    amount: String,
+    unlock: UnlockResponse,
+}
+
+#[derive(serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+enum UnlockResponse {
+    Height(u64),
+    Time(String),
+}
+
+impl From<TimelockUnlock> for UnlockResponse {
+    fn from(unlock: TimelockUnlock) -> Self {
+        match unlock {
+            TimelockUnlock::Height(height) => Self::Height(height),
+            TimelockUnlock::Time(time) => Self::Time(time.to_rfc3339()),
+        }
+    }
+}
+
+#[instrument(skip_all, fields(id), err(level = Level::DEBUG))]
+async fn escrow_response<S: StateRead>(
+    state: &S,
+    id: u64,
+    escrow: TimelockedTransferEscrow,
+) -> Result<EscrowResponse> {
+    let from = state
+        .try_base_prefixed(&escrow.from)
+        .await
+        .wrap_err("failed to construct bech32m address of the sender")?;
+    let to = state
+        .try_base_prefixed(&escrow.to)
+        .await
+        .wrap_err("failed to construct bech32m address of the recipient")?;
+    // report the trace-prefixed form of the asset if it is known, as that is what users submit
+    let asset = match state
+        .map_ibc_to_trace_prefixed_asset(&escrow.asset)
+        .await
+        .wrap_err("failed to map ibc prefixed asset to trace prefixed asset")?
+    {
+        Some(trace_prefixed) => trace_prefixed.to_string(),
+        None => escrow.asset.to_string(),
+    };
+    Ok(EscrowResponse {
+        id,
+        from: from.to_string(),
+        to: to.to_string(),
+        asset,
+        amount: escrow.amount.to_string(),
+        unlock: escrow.unlock.into(),
+    })
+}
+
+/// Returns the escrow of the timelocked transfer with the `id` path parameter as JSON.
+///
+/// The `transfer` field of the response is `null` if no transfer with that ID is held in escrow,
+/// either because it never existed or because it was already claimed or cancelled.
+#[instrument(skip_all)]
+pub(crate) async fn transfer_request(
+    storage: Storage,
+    request: request::Query,
+    params: Vec<(String, String)>,
+) -> response::Query {
+    let id = match parse_id(&params) {
+        Ok(id) => id,
+        Err(err_rsp) => return err_rsp,
+    };
+
+    let snapshot = storage.latest_snapshot();
+    let height = match snapshot.get_block_height().await {
+        Ok(height) => height,
+        Err(err) => {
+            return response::Query {
+                code: Code::Err(AbciErrorCode::INTERNAL_ERROR.value()),
+                info: AbciErrorCode::INTERNAL_ERROR.info(),
+                log: format!("failed to get block height: {err:#}"),
+                ..response::Query::default()
+            };
+        }
+    };
+
+    let transfer = match snapshot.get_timelocked_transfer(id).await {
+        Ok(Some(escrow)) => match escrow_response(&snapshot, id, escrow).await {
+            Ok(escrow) => Some(escrow),
+            Err(err) => {
+                return response::Query {
+                    code: Code::Err(AbciErrorCode::INTERNAL_ERROR.value()),
+                    info: AbciErrorCode::INTERNAL_ERROR.info(),
+                    log: format!("failed to construct timelocked transfer response: {err:#}"),
+                    ..response::Query::default()
+                };
+            }
+        },
+        Ok(None) => None,
+        Err(err) => {
+            return response::Query {
+                code: Code::Err(AbciErrorCode::INTERNAL_ERROR.value()),
+                info: AbciErrorCode::INTERNAL_ERROR.info(),
+                log: format!("failed to get timelocked transfer: {err:#}"),
+                ..response::Query::default()
+            };
+        }
+    };
+
+    let payload = TimelockedTransferResponse {
+        height,
+        transfer,
+    };
+    let height = tendermint::block::Height::try_from(height).expect("height must fit into an i64");
+    response::Query {
+        code: 0.into(),
+        key: request.path.clone().into_bytes().into(),
+        value: serde_json::to_vec(&payload)
+            .expect("object does not contain keys that don't map to json keys")
+            .into(),
+        height,
+        ..response::Query::default()
+    }
+}
+
+fn parse_id(params: &[(String, String)]) -> Result<u64, response::Query> {
+    let Some(id) = params.iter().find_map(|(k, v)| (k == "id").then_some(v)) else {
+        return Err(response::Query {
+            code: Code::Err(AbciErrorCode::INVALID_PARAMETER.value()),
+            info: AbciErrorCode::INVALID_PARAMETER.info(),
+            log: "path did not contain id parameter".into(),
+            ..response::Query::default()
+        });
+    };
+    id.parse().map_err(|err| response::Query {
+        code: Code::Err(AbciErrorCode::INVALID_PARAMETER.value()),
+        info: AbciErrorCode::INVALID_PARAMETER.info(),
+        log: format!("id could not be parsed from provided parameter: {err:#}"),
+        ..response::Query::default()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use astria_core::primitive::v1::asset;
+    use cnidarium::StateDelta;
+
+    use super::*;
+    use crate::{
+        address::StateWriteExt as _,
+        app::StateWriteExt as _,
+        assets::StateWriteExt as _,
+        benchmark_and_test_utils::{
+            astria_address,
+            nria,
+            ASTRIA_PREFIX,
+        },
+        timelock::StateWriteExt as _,
+    };
+
+    async fn query(storage: &cnidarium::TempStorage, id: &str) -> serde_json::Value {
+        let request = request::Query {
+            data: vec![].into(),
+            path: format!("timelock/transfer/{id}"),
+            height: 0u32.into(),
+            prove: false,
+        };
+        let params = vec![("id".to_string(), id.to_string())];
+        let response = transfer_request(Storage::clone(storage), request, params).await;
+        assert!(response.code.is_ok(), "{}", response.log);
+        serde_json::from_slice(&response.value).unwrap()
+    }
+
+    #[tokio::test]
+    async fn transfer_request_ok() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = StateDelta::new(snapshot);
+
+        state.put_base_prefix(ASTRIA_PREFIX.to_string()).unwrap();
+        state.put_block_height(1).unwrap();
+        state.put_ibc_asset(nria()).unwrap();
+        state
+            .put_timelocked_transfer(
+                3,
+                &TimelockedTransferEscrow {
+                    from: [1; 20],
+                    to: [2; 20],
+                    asset: asset::IbcPrefixed::from(&nria()),
+                    amount: 100,
+                    unlock: TimelockUnlock::Height(10),
+                },
+            )
+            .unwrap();
+        storage.commit(state).await.unwrap();
+
+        assert_eq!(
+            query(&storage, "3").await,
+            serde_json::json!({
+                "height": 1,
+                "transfer": {
+                    "id": 3,
+                    "from": astria_address(&[1; 20]).to_string(),
+                    "to": astria_address(&[2; 20]).to_string(),
+                    "asset": "nria",
+                    "amount": "100",
+                    "unlock": { "height": 10 },
+                },
+            })
+        );
+        assert_eq!(
+            query(&storage, "4").await,
+            serde_json::json!({
+                "height": 1,
+                "transfer": null,
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn transfer_request_rejects_invalid_id() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let request = request::Query {
+            data: vec![].into(),
+            path: "timelock/transfer/abc".to_string(),
+            height: 0u32.into(),
+            prove: false,
+        };
+        let params = vec![("id".to_string(), "abc".to_string())];
+        let response = transfer_request(Storage::clone(&storage), request, params).await;
+        assert_eq!(
+            response.code,
+            Code::Err(AbciErrorCode::INVALID_PARAMETER.value())
+        );
+    }
+}
@@ -0,0 +1,173 @@
+use astria_eyre::{
+    anyhow_to_eyre,
+    eyre::{
+        Result,
+        WrapErr as _,
+    },
+};
+use async_trait::async_trait;
+use cnidarium::{
+    StateRead,
+    StateWrite,
+};
+use tracing::{
+    instrument,
+    Level,
+};
+
+use super::{
+    storage::{
+        self,
+        keys,
+    },
+    TimelockedTransferEscrow,
+};
+use crate::storage::StoredValue;
+
+#[async_trait]
+pub(crate) trait StateReadExt: StateRead {
+    /// Returns the ID that will be assigned to the next timelocked transfer.
+    #[instrument(skip_all, err(level = Level::WARN))]
+    async fn get_next_timelocked_transfer_id(&self) -> Result<u64> {
+        let Some(bytes) = self
+            .get_raw(keys::NEXT_TRANSFER_ID)
+            .await
+            .map_err(anyhow_to_eyre)
+            .wrap_err("failed reading raw next timelocked transfer id from state")?
+        else {
+            return Ok(0);
+        };
+        StoredValue::deserialize(&bytes)
+            .and_then(|value| storage::TransferId::try_from(value).map(u64::from))
+            .wrap_err("invalid next timelocked transfer id bytes")
+    }
+
+    /// Returns the escrow of the timelocked transfer with `id`, or `None` if no such transfer
+    /// exists or it was already claimed or cancelled.
+    #[instrument(skip_all, fields(id), err(level = Level::WARN))]
+    async fn get_timelocked_transfer(&self, id: u64) -> Result<Option<TimelockedTransferEscrow>> {
+        let Some(bytes) = self
+            .get_raw(&keys::transfer(id))
+            .await
+            .map_err(anyhow_to_eyre)
+            .wrap_err("failed reading raw timelocked transfer from state")?
+        else {
+            return Ok(None);
+        };
+        StoredValue::deserialize(&bytes)
+            .and_then(|value| {
+                storage::TimelockedTransferEscrow::try_from(value)
+                    .map(|escrow| Some(TimelockedTransferEscrow::from(escrow)))
+            })
+            .wrap_err("invalid timelocked transfer bytes")
+    }
+}
+
+impl<T: StateRead + ?Sized> StateReadExt for T {}
+
+#[async_trait]
+pub(crate) trait StateWriteExt: StateWrite {
+    #[instrument(skip_all)]
+    fn put_next_timelocked_transfer_id(&mut self, id: u64) -> Result<()> {
+        let bytes = StoredValue::from(storage::TransferId::from(id))
+            .serialize()
+            .wrap_err("failed to serialize next timelocked transfer id")?;
+        self.put_raw(keys::NEXT_TRANSFER_ID.to_string(), bytes);
+        Ok(())
+    }
+
+    #[instrument(skip_all, fields(id))]
+    fn put_timelocked_transfer(
+        &mut self,
+        id: u64,
+        escrow: &TimelockedTransferEscrow,
+    ) -> Result<()> {
+        let bytes = StoredValue::from(storage::TimelockedTransferEscrow::from(escrow))
+            .serialize()
+            .wrap_err("failed to serialize timelocked transfer")?;
+        self.put_raw(keys::transfer(id), bytes);
+        Ok(())
+    }
+
+    #[instrument(skip_all, fields(id))]
+    fn delete_timelocked_transfer(&mut self, id: u64) {
+        self.delete(keys::transfer(id));
+    }
+}
+
+impl<T: StateWrite> StateWriteExt for T {}
+
+#[cfg(test)]
+mod tests {
+    use astria_core::{
+        primitive::v1::asset,
+        protocol::transaction::v1::action::TimelockUnlock,
+    };
+    use cnidarium::StateDelta;
+
+    use super::*;
+
+    fn escrow(unlock: TimelockUnlock) -> TimelockedTransferEscrow {
+        TimelockedTransferEscrow {
+            from: [1; 20],
+            to: [2; 20],
+            asset: asset::IbcPrefixed::new([3; 32]),
+            amount: 100,
+            unlock,
+        }
+    }
+
+    #[tokio::test]
+    async fn get_next_timelocked_transfer_id_uninitialized_ok() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let state = StateDelta::new(snapshot);
+
+        assert_eq!(state.get_next_timelocked_transfer_id().await.unwrap(), 0);
+    }
+
+    #[tokio::test]
+    async fn put_next_timelocked_transfer_id() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = StateDelta::new(snapshot);
+
+        state.put_next_timelocked_transfer_id(7).unwrap();
+        assert_eq!(state.get_next_timelocked_transfer_id().await.unwrap(), 7);
+    }
+
+    #[tokio::test]
+    async fn get_timelocked_transfer_uninitialized_ok() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let state = StateDelta::new(snapshot);
+
+        assert_eq!(state.get_timelocked_transfer(0).await.unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn put_and_delete_timelocked_transfer() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = StateDelta::new(snapshot);
+
+        let by_height = escrow(TimelockUnlock::Height(10));
+        let by_time = escrow(TimelockUnlock::Time(
+            tendermint::Time::from_unix_timestamp(1_577_836_800, 1).unwrap(),
+        ));
+        state.put_timelocked_transfer(0, &by_height).unwrap();
+        state.put_timelocked_transfer(1, &by_time).unwrap();
+        assert_eq!(
+            state.get_timelocked_transfer(0).await.unwrap(),
+            Some(by_height)
+        );
+        assert_eq!(
+            state.get_timelocked_transfer(1).await.unwrap(),
+            Some(by_time)
+        );
+
+        state.delete_timelocked_transfer(0);
+        assert_eq!(state.get_timelocked_transfer(0).await.unwrap(), None);
+        assert!(state.get_timelocked_transfer(1).await.unwrap().is_some());
+    }
+}
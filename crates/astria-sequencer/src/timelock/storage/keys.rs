@@ -0,0 +1,30 @@
+pub(in crate::timelock) const NEXT_TRANSFER_ID: &str = "timelock/next_transfer_id";
+pub(in crate::timelock) const TRANSFER_PREFIX: &str = "timelock/transfer/";
+
+pub(in crate::timelock) fn transfer(id: u64) -> String {
+    format!("{TRANSFER_PREFIX}{id}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const COMPONENT_PREFIX: &str = "timelock/";
+
+    #[test]
+    fn keys_should_not_change() {
+        insta::assert_snapshot!("next_transfer_id_key", NEXT_TRANSFER_ID);
+        insta::assert_snapshot!("transfer_key", transfer(42));
+    }
+
+    #[test]
+    fn keys_should_have_component_prefix() {
+        assert!(NEXT_TRANSFER_ID.starts_with(COMPONENT_PREFIX));
+        assert!(transfer(42).starts_with(COMPONENT_PREFIX));
+    }
+
+    #[test]
+    fn prefixes_should_be_prefixes_of_relevant_keys() {
+        assert!(transfer(42).starts_with(TRANSFER_PREFIX));
+    }
+}
@@ -0,0 +1,8 @@
+pub(super) mod keys;
+mod values;
+
+pub(crate) use values::Value;
+pub(super) use values::{
+    TimelockedTransferEscrow,
+    TransferId,
+};
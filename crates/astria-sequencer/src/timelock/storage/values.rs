@@ -0,0 +1,234 @@
+use std::{
+    borrow::Cow,
+    fmt::{
+        self,
+        Debug,
+        Formatter,
+    },
+};
+
+use astria_core::{
+    primitive::v1::{
+        asset::IbcPrefixed as DomainIbcPrefixed,
+        ADDRESS_LEN,
+    },
+    protocol::transaction::v1::action::TimelockUnlock as DomainTimelockUnlock,
+};
+use astria_eyre::eyre::bail;
+use borsh::{
+    BorshDeserialize,
+    BorshSerialize,
+};
+use telemetry::display::base64;
+
+use crate::timelock::TimelockedTransferEscrow as DomainTimelockedTransferEscrow;
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub(crate) struct Value<'a>(ValueImpl<'a>);
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+enum ValueImpl<'a> {
+    TransferId(TransferId),
+    TimelockedTransferEscrow(TimelockedTransferEscrow<'a>),
+}
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub(in crate::timelock) struct TransferId(u64);
+
+impl From<u64> for TransferId {
+    fn from(id: u64) -> Self {
+        TransferId(id)
+    }
+}
+
+impl From<TransferId> for u64 {
+    fn from(id: TransferId) -> Self {
+        id.0
+    }
+}
+
+impl From<TransferId> for crate::storage::StoredValue<'_> {
+    fn from(id: TransferId) -> Self {
+        crate::storage::StoredValue::Timelock(Value(ValueImpl::TransferId(id)))
+    }
+}
+
+impl<'a> TryFrom<crate::storage::StoredValue<'a>> for TransferId {
+    type Error = astria_eyre::eyre::Error;
+
+    fn try_from(value: crate::storage::StoredValue<'a>) -> Result<Self, Self::Error> {
+        let crate::storage::StoredValue::Timelock(Value(ValueImpl::TransferId(id))) = value else {
+            bail!("timelock stored value type mismatch: expected transfer id, found {value:?}");
+        };
+        Ok(id)
+    }
+}
+
+#[derive(BorshSerialize, BorshDeserialize)]
+struct AddressBytes<'a>(Cow<'a, [u8; ADDRESS_LEN]>);
+
+impl Debug for AddressBytes<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", base64(self.0.as_slice()))
+    }
+}
+
+#[derive(BorshSerialize, BorshDeserialize)]
+struct IbcPrefixedDenom<'a>(Cow<'a, [u8; 32]>);
+
+impl Debug for IbcPrefixedDenom<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", base64(self.0.as_slice()))
+    }
+}
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+enum TimelockUnlock {
+    Height(u64),
+    Time { seconds: i64, nanos: i32 },
+}
+
+impl From<DomainTimelockUnlock> for TimelockUnlock {
+    fn from(unlock: DomainTimelockUnlock) -> Self {
+        match unlock {
+            DomainTimelockUnlock::Height(height) => TimelockUnlock::Height(height),
+            DomainTimelockUnlock::Time(time) => {
+                let timestamp = tendermint_proto::google::protobuf::Timestamp::from(time);
+                TimelockUnlock::Time {
+                    seconds: timestamp.seconds,
+                    nanos: timestamp.nanos,
+                }
+            }
+        }
+    }
+}
+
+impl From<TimelockUnlock> for DomainTimelockUnlock {
+    fn from(unlock: TimelockUnlock) -> Self {
+        match unlock {
+            TimelockUnlock::Height(height) => DomainTimelockUnlock::Height(height),
+            TimelockUnlock::Time {
+                seconds,
+                nanos,
+            } => DomainTimelockUnlock::Time(
+                tendermint::Time::try_from(tendermint_proto::google::protobuf::Timestamp {
+                    seconds,
+                    nanos,
+                })
+                .expect("unlock time in storage must be valid"),
+            ),
+        }
+    }
+}
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub(in crate::timelock) struct TimelockedTransferEscrow<'a> {
+    from: AddressBytes<'a>,
+    to: AddressBytes<'a>,
+    asset: IbcPrefixedDenom<'a>,
+    amount: u128,
+    unlock: TimelockUnlock,
+}
+
+impl<'a> From<&'a DomainTimelockedTransferEscrow> for TimelockedTransferEscrow<'a> {
+    fn from(escrow: &'a DomainTimelockedTransferEscrow) -> Self {
+        TimelockedTransferEscrow {
+            from: AddressBytes(Cow::Borrowed(&escrow.from)),
+            to: AddressBytes(Cow::Borrowed(&escrow.to)),
+            asset: IbcPrefixedDenom(Cow::Borrowed(escrow.asset.as_bytes())),
+            amount: escrow.amount,
+            unlock: escrow.unlock.into(),
+        }
+    }
+}
+
+impl<'a> From<TimelockedTransferEscrow<'a>> for DomainTimelockedTransferEscrow {
+    fn from(escrow: TimelockedTransferEscrow<'a>) -> Self {
+        DomainTimelockedTransferEscrow {
+            from: escrow.from.0.into_owned(),
+            to: escrow.to.0.into_owned(),
+            asset: DomainIbcPrefixed::new(escrow.asset.0.into_owned()),
+            amount: escrow.amount,
+            unlock: escrow.unlock.into(),
+        }
+    }
+}
+
+impl<'a> From<TimelockedTransferEscrow<'a>> for crate::storage::StoredValue<'a> {
+    fn from(escrow: TimelockedTransferEscrow<'a>) -> Self {
+        crate::storage::StoredValue::Timelock(Value(ValueImpl::TimelockedTransferEscrow(escrow)))
+    }
+}
+
+impl<'a> TryFrom<crate::storage::StoredValue<'a>> for TimelockedTransferEscrow<'a> {
+    type Error = astria_eyre::eyre::Error;
+
+    fn try_from(value: crate::storage::StoredValue<'a>) -> Result<Self, Self::Error> {
+        let crate::storage::StoredValue::Timelock(Value(ValueImpl::TimelockedTransferEscrow(
+            escrow,
+        ))) = value
+        else {
+            bail!(
+                "timelock stored value type mismatch: expected timelocked transfer escrow, found \
+                 {value:?}"
+            );
+        };
+        Ok(escrow)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use insta::assert_snapshot;
+
+    use super::*;
+    use crate::test_utils::borsh_then_hex;
+
+    #[test]
+    fn value_impl_existing_variants_unchanged() {
+        assert_snapshot!(
+            "value_impl_transfer_id",
+            borsh_then_hex(&ValueImpl::TransferId(TransferId(1)))
+        );
+        assert_snapshot!(
+            "value_impl_timelocked_transfer_escrow_height",
+            borsh_then_hex(&ValueImpl::TimelockedTransferEscrow(
+                TimelockedTransferEscrow {
+                    from: AddressBytes(Cow::Owned([1; ADDRESS_LEN])),
+                    to: AddressBytes(Cow::Owned([2; ADDRESS_LEN])),
+                    asset: IbcPrefixedDenom(Cow::Owned([3; 32])),
+                    amount: 100,
+                    unlock: TimelockUnlock::Height(10),
+                }
+            ))
+        );
+        assert_snapshot!(
+            "value_impl_timelocked_transfer_escrow_time",
+            borsh_then_hex(&ValueImpl::TimelockedTransferEscrow(
+                TimelockedTransferEscrow {
+                    from: AddressBytes(Cow::Owned([1; ADDRESS_LEN])),
+                    to: AddressBytes(Cow::Owned([2; ADDRESS_LEN])),
+                    asset: IbcPrefixedDenom(Cow::Owned([3; 32])),
+                    amount: 100,
+                    unlock: TimelockUnlock::Time {
+                        seconds: 1_577_836_800,
+                        nanos: 1,
+                    },
+                }
+            ))
+        );
+    }
+
+    // Note: This test must be here instead of in `crate::storage` since `ValueImpl` is not
+    // re-exported.
+    #[test]
+    fn stored_value_timelock_variant_unchanged() {
+        use crate::storage::StoredValue;
+        assert_snapshot!(
+            "stored_value_timelock_variant",
+            borsh_then_hex(&StoredValue::Timelock(Value(ValueImpl::TransferId(
+                TransferId(1)
+            ))))
+        );
+    }
+}
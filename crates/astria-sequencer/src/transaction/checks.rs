@@ -11,6 +11,7 @@ use astria_core::{
 };
 use astria_eyre::eyre::{
     ensure,
+    OptionExt as _,
     Result,
     WrapErr as _,
 };
@@ -25,6 +26,7 @@ use crate::{
     app::StateReadExt as _,
     bridge::StateReadExt as _,
     fees::query::get_fees_for_transaction,
+    multisig::StateReadExt as _,
 };
 
 #[instrument(skip_all, fields(tx_hash = %tx.id()), err(level = Level::DEBUG))]
@@ -40,6 +42,44 @@ pub(crate) async fn check_chain_id_mempool<S: StateRead>(
     Ok(())
 }
 
+// Checks that the transaction is authorized by the multisig policy of its account, if any.
+//
+// Multisig transactions must target a registered multisig account, be signed only by its
+// members, and carry at least as many member signatures as its threshold. Single-signature
+// transactions must not act on behalf of a multisig account.
+#[instrument(skip_all, fields(tx_hash = %tx.id()), err(level = Level::DEBUG))]
+pub(crate) async fn check_multisig_authorization<S: StateRead>(
+    tx: &Transaction,
+    state: &S,
+) -> Result<()> {
+    let policy = state
+        .get_multisig_policy(tx.address_bytes())
+        .await
+        .wrap_err("failed to get multisig policy")?;
+    let Some(multisig) = tx.multisig_signatures() else {
+        ensure!(
+            policy.is_none(),
+            "account is a multisig account and requires member signatures"
+        );
+        return Ok(());
+    };
+    let policy = policy
+        .ok_or_eyre("transaction has multisig signatures but account is not a multisig account")?;
+    for signer in multisig.signers() {
+        ensure!(
+            policy.is_member(signer),
+            "signer is not a member of the multisig account"
+        );
+    }
+    let signature_count = u32::try_from(multisig.signatures().len()).unwrap_or(u32::MAX);
+    ensure!(
+        signature_count >= policy.threshold(),
+        "multisig account requires {} member signatures, but {signature_count} provided",
+        policy.threshold(),
+    );
+    Ok(())
+}
+
 // Checks that the account has enough balance to cover the total fees and transferred values
 // for all actions in the transaction.
 #[instrument(skip_all, fields(tx_hash = %tx.id()), err(level = Level::DEBUG))]
@@ -84,6 +124,23 @@ pub(crate) async fn get_total_transaction_cost<S: StateRead>(
     Ok(cost_by_asset)
 }
 
+// Returns the total fees paid by the transaction, summed over all fee assets.
+//
+// Fee amounts in different assets are added up as-is without converting between them. This is
+// used by the mempool to rank transactions against each other, not for any balance checks.
+#[instrument(skip_all, fields(tx_hash = %tx.id()), err(level = Level::DEBUG))]
+pub(crate) async fn get_total_transaction_fee<S: StateRead>(
+    tx: &Transaction,
+    state: &S,
+) -> Result<u128> {
+    let fees_by_asset = get_fees_for_transaction(tx.unsigned_transaction(), state)
+        .await
+        .context("failed to get fees for transaction")?;
+    Ok(fees_by_asset
+        .into_values()
+        .fold(0u128, u128::saturating_add))
+}
+
 async fn add_total_transfers_for_transaction<S: StateRead>(
     tx: &Transaction,
     state: &S,
@@ -130,6 +187,12 @@ async fn add_total_transfers_for_transaction<S: StateRead>(
                     .and_modify(|amt| *amt = amt.saturating_add(act.amount))
                     .or_insert(act.amount);
             }
+            Action::TimelockedTransfer(act) => {
+                cost_by_asset
+                    .entry(act.asset.to_ibc_prefixed())
+                    .and_modify(|amt| *amt = amt.saturating_add(act.amount))
+                    .or_insert(act.amount);
+            }
             Action::ValidatorUpdate(_)
             | Action::SudoAddressChange(_)
             | Action::IbcSudoChange(_)
@@ -142,7 +205,13 @@ async fn add_total_transfers_for_transaction<S: StateRead>(
             | Action::FeeChange(_)
             | Action::RecoverIbcClient(_)
             | Action::CurrencyPairsChange(_)
-            | Action::MarketsChange(_) => {
+            | Action::MarketsChange(_)
+            | Action::RegisterMultisig(_)
+            | Action::ClaimTimelockedTransfer(_)
+            | Action::CancelTimelockedTransfer(_)
+            | Action::SubmitProposal(_)
+            | Action::VoteOnProposal(_)
+            | Action::FeeDistributionChange(_) => {
                 continue;
             }
         }
@@ -432,4 +501,50 @@ mod tests {
             .await
             .unwrap();
     }
+
+    #[tokio::test]
+    async fn get_total_transaction_fee_sums_fees_of_all_actions() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state_tx = StateDelta::new(snapshot);
+
+        state_tx.put_base_prefix(ASTRIA_PREFIX.to_string()).unwrap();
+        state_tx.put_native_asset(nria()).unwrap();
+        state_tx
+            .put_fees(FeeComponents::<Transfer>::new(12, 0))
+            .unwrap();
+        state_tx
+            .put_fees(FeeComponents::<RollupDataSubmission>::new(0, 1))
+            .unwrap();
+
+        let other_asset = "other".parse::<Denom>().unwrap();
+        let data = Bytes::from_static(&[0; 32]);
+        let actions = vec![
+            Action::Transfer(Transfer {
+                asset: other_asset.clone(),
+                amount: 100,
+                fee_asset: nria().into(),
+                to: state_tx.try_base_prefixed(&[0; ADDRESS_LEN]).await.unwrap(),
+            }),
+            Action::RollupDataSubmission(RollupDataSubmission {
+                rollup_id: RollupId::from_unhashed_bytes([0; 32]),
+                data: data.clone(),
+                fee_asset: other_asset,
+            }),
+        ];
+        let signed_tx = TransactionBody::builder()
+            .actions(actions)
+            .chain_id("test-chain-id")
+            .try_build()
+            .unwrap()
+            .sign(&get_alice_signing_key());
+
+        // transferred amounts are not fees
+        assert_eq!(
+            get_total_transaction_fee(&signed_tx, &state_tx)
+                .await
+                .unwrap(),
+            12 + calculate_rollup_data_submission_fee_from_state(&data, &state_tx).await,
+        );
+    }
 }
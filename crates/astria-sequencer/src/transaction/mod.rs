@@ -4,13 +4,12 @@ mod state_ext;
 pub(crate) use checks::{
     check_balance_for_total_fees_and_transfers,
     check_chain_id_mempool,
+    check_multisig_authorization,
     get_total_transaction_cost,
+    get_total_transaction_fee,
 };
-// Conditional to quiet warnings. This object is used throughout the codebase,
-// but is never explicitly named - hence Rust warns about it being unused.
-#[cfg(test)]
-pub(crate) use state_ext::TransactionContext;
 pub(crate) use state_ext::{
     StateReadExt,
     StateWriteExt,
+    TransactionContext,
 };